@@ -47,7 +47,7 @@ mul_end:
 end_loop:
     # Stocker le résultat
     MOVI r6, RESULT_ADDR
-    STOREW r2, r6, 0      # Stocker la factorielle à l'adresse résultat
+    STOREW r6, r2, 0      # Stocker la factorielle à l'adresse résultat
     
     # Fin du programme
     HALT
\ No newline at end of file
@@ -20,24 +20,24 @@ _start:
     MOVI r2, DEST_ADDR    # Adresse destination
     MOVI r3, BLOCK_SIZE   # Taille du bloc
     MOVI r4, 0            # Index courant
+    MOVI r5, SRC_ADDR     # Adresse source de l'élément courant
+    MOVI r6, DEST_ADDR    # Adresse destination de l'élément courant
 
 loop:
     # Vérifier si on a copié tout le bloc
     CMP r4, r3            # Comparer index et taille
     BRANCH GE, end_loop   # Si index >= taille, sortir de la boucle
     
-    # Calculer les adresses source et destination pour l'élément courant
-    ADD r5, r1, r4        # r5 = adresse source + index
-    ADD r6, r2, r4        # r6 = adresse destination + index
-    
     # Charger l'élément depuis la source
     LOADW r7, r5, 0       # r7 = source[index]
     
     # Stocker l'élément dans la destination
-    STOREW r7, r6, 0      # destination[index] = r7
+    STOREW r6, r7, 0      # destination[index] = r7
     
-    # Incrémenter l'index
+    # Incrémenter l'index et passer au Mot suivant (8 trytes)
     ADDI r4, r4, 1        # index++
+    ADDI r5, r5, 8
+    ADDI r6, r6, 8
     
     # Retour au début de la boucle
     BRANCH ALWAYS, loop
@@ -1,12 +1,16 @@
 # Benchmark: Optimized Memory Access
-# Démonstration des accès mémoire optimisés avec LOADT3/STORET3 et LOADTM/STORETM
+# Démonstration des accès mémoire optimisés avec LOADT3/STORET3 et TMEMCPY
 # Ce benchmark compare les performances des instructions mémoire standard vs optimisées
+# Les quatre copies portent sur le même bloc: avec les caches L1 activés, les cycles
+# d'attente mesurent l'effet de la granularité des accès et de la politique d'écriture
+#   prismchrono_sim optimized_memory.tbin --icache --dcache --metrics
+#   prismchrono_sim optimized_memory.tbin --icache --dcache --write-policy write-through --metrics
 
 # Définition des constantes
-.equ ARRAY_SIZE, 100      # Taille du tableau
+.equ ARRAY_SIZE, 96       # Taille du bloc en trytes (multiple de 3 et de 8)
+.equ WORD_COUNT, 12       # Taille du bloc en Mots (ARRAY_SIZE / 8)
 .equ SRC_ADDR, 0x1000     # Adresse source
 .equ DST_ADDR, 0x1400     # Adresse destination
-.equ MASK_ADDR, 0x1800    # Adresse des masques
 
 # Section de données
 .section .data
@@ -20,81 +24,77 @@ _start:
     # Initialisation des registres
     MOVI r1, SRC_ADDR      # Adresse source
     MOVI r2, DST_ADDR      # Adresse destination
-    MOVI r3, ARRAY_SIZE    # Taille du tableau
-    MOVI r4, MASK_ADDR     # Adresse des masques
+    MOVI r3, ARRAY_SIZE    # Taille du bloc
 
 # Partie 1: Copie standard (tryte par tryte)
 standard_copy:
-    MOVI r5, 0             # Index courant
+    MOVI r4, 0             # Index courant
 
 standard_loop:
-    # Vérifier si on a copié tout le tableau
-    CMP r5, r3
+    # Vérifier si on a copié tout le bloc
+    CMP r4, r3
     BRANCH GE, optimized_copy # Si index >= taille, passer à la partie suivante
-    
+
     # Calculer les adresses courantes
-    ADD r6, r1, r5        # r6 = adresse_source + index
-    ADD r7, r2, r5        # r7 = adresse_destination + index
-    
+    ADD r5, r1, r4        # r5 = adresse_source + index
+    ADD r6, r2, r4        # r6 = adresse_destination + index
+
     # Copier un tryte à la fois
-    LOADT r8, r6, 0       # r8 = source[index]
-    STORET r8, r7, 0      # destination[index] = r8
-    
+    LOADT r7, r5, 0       # r7 = source[index]
+    STORET r6, r7, 0      # destination[index] = r7
+
     # Incrémenter l'index
-    ADDI r5, r5, 1
+    ADDI r4, r4, 1
     BRANCH AL, standard_loop # Continuer la boucle
 
 # Partie 2: Copie optimisée avec LOADT3/STORET3
 optimized_copy:
-    MOVI r5, 0             # Index courant
+    MOVI r4, 0             # Index courant
 
 optimized_loop:
-    # Vérifier si on a copié tout le tableau
-    CMP r5, r3
-    BRANCH GE, masked_copy # Si index >= taille, passer à la partie suivante
-    
+    # Vérifier si on a copié tout le bloc
+    CMP r4, r3
+    BRANCH GE, word_copy  # Si index >= taille, passer à la partie suivante
+
     # Calculer les adresses courantes
-    ADD r6, r1, r5        # r6 = adresse_source + index
-    ADD r7, r2, r5        # r7 = adresse_destination + index
-    
+    ADD r5, r1, r4        # r5 = adresse_source + index
+    ADD r6, r2, r4        # r6 = adresse_destination + index
+
     # Copier 3 trytes à la fois avec LOADT3/STORET3
-    LOADT3 r8, r6, 0      # r8 = source[index..index+2]
-    STORET3 r8, r7, 0     # destination[index..index+2] = r8
-    
+    LOADT3 r7, r5, 0      # r7 = source[index..index+2]
+    STORET3 r6, r7, 0     # destination[index..index+2] = r7
+
     # Incrémenter l'index de 3
-    ADDI r5, r5, 3
+    ADDI r4, r4, 3
     BRANCH AL, optimized_loop # Continuer la boucle
 
-# Partie 3: Copie avec masque utilisant LOADTM/STORETM
-masked_copy:
-    MOVI r5, 0             # Index courant
+# Partie 3: Copie par Mots avec LOADW/STOREW
+word_copy:
+    MOVI r3, WORD_COUNT    # Nombre de Mots
+    MOVI r4, 0             # Index courant
+    MOVI r5, SRC_ADDR      # Adresse source courante
+    MOVI r6, DST_ADDR      # Adresse destination courante
 
-masked_loop:
-    # Vérifier si on a copié tout le tableau
-    CMP r5, r3
-    BRANCH GE, tmemcpy_test # Si index >= taille, passer à la partie suivante
-    
-    # Calculer les adresses courantes
-    ADD r6, r1, r5        # r6 = adresse_source + index
-    ADD r7, r2, r5        # r7 = adresse_destination + index
-    ADD r8, r4, r5        # r8 = adresse_masque + index
-    
-    # Charger le masque
-    LOADW r9, r8, 0       # r9 = masque[index]
-    
-    # Copier avec masque utilisant LOADTM/STORETM
-    LOADTM r10, r9, r6, 0  # r10 = source[index] avec masque r9
-    STORETM r10, r9, r7, 0 # destination[index] = r10 avec masque r9
-    
-    # Incrémenter l'index
-    ADDI r5, r5, 1
-    BRANCH AL, masked_loop # Continuer la boucle
+word_loop:
+    # Vérifier si on a copié tout le bloc
+    CMP r4, r3
+    BRANCH GE, tmemcpy_test # Si index >= nombre de Mots, passer à la partie suivante
+
+    # Copier un Mot (8 trytes)
+    LOADW r7, r5, 0       # r7 = source[index]
+    STOREW r6, r7, 0      # destination[index] = r7
+
+    # Avancer d'un Mot
+    ADDI r5, r5, 8
+    ADDI r6, r6, 8
+    ADDI r4, r4, 1
+    BRANCH AL, word_loop  # Continuer la boucle
 
 # Partie 4: Test de TMEMCPY (copie mémoire ternaire optimisée)
 tmemcpy_test:
-    # Utiliser TMEMCPY pour copier un bloc de mémoire
-    MOVI r5, 50           # Nombre de trytes à copier
+    # Utiliser TMEMCPY pour copier le bloc en une instruction
+    MOVI r5, ARRAY_SIZE   # Nombre de trytes à copier
     TMEMCPY r2, r1, r5    # Copier r5 trytes de r1 vers r2
-    
+
     # Fin du programme
-    HALT
\ No newline at end of file
+    HALT
@@ -1,14 +1,18 @@
 # Benchmark: Predictive Cache
 # Évaluation des performances du cache prédictif ternaire
-# Ce benchmark compare les accès mémoire avec cache prédictif ternaire aux approches conventionnelles
+# Trois parcours d'un tableau aux motifs d'accès différents:
+#   1. séquentiel (Mots consécutifs): le prédicteur retrouve le pas d'une ligne
+#   2. à pas constant (un Mot sur 8): pas régulier entre les lignes, préchargé après deux échecs
+#   3. arbre binaire implicite (fils gauche 2i+1): pas croissant, non prédictible
+# Les chiffres n'ont de sens qu'avec le cache de données, à comparer sans préchargement:
+#   prismchrono_sim predictive_cache.tbin --dcache --metrics
+#   prismchrono_sim predictive_cache.tbin --dcache 16:2:4:0 --metrics
 
 # Définition des constantes
-.equ ARRAY_SIZE, 1024     # Taille du tableau principal
-.equ ACCESS_COUNT, 100    # Nombre d'accès mémoire à effectuer
+.equ ACCESS_COUNT, 100    # Nombre d'accès mémoire par parcours
 .equ DATA_ADDR, 0x1000    # Adresse des données
-.equ PATTERN_ADDR, 0x2000 # Adresse des motifs d'accès
 .equ RESULT_ADDR, 0x3000  # Adresse des résultats
-.equ CACHE_SIZE, 64       # Taille du cache simulé
+.equ TREE_SIZE, 511       # Nombre de nœuds de l'arbre (9 niveaux)
 
 # Section de données
 .section .data
@@ -21,202 +25,83 @@
 _start:
     # Initialisation des registres
     MOVI r1, DATA_ADDR     # Adresse des données
-    MOVI r2, PATTERN_ADDR  # Adresse des motifs d'accès
-    MOVI r3, RESULT_ADDR   # Adresse des résultats
-    MOVI r4, ACCESS_COUNT  # Nombre d'accès à effectuer
-    MOVI r5, 0             # Index courant
+    MOVI r2, RESULT_ADDR   # Adresse du prochain résultat
+    MOVI r3, ACCESS_COUNT  # Nombre d'accès à effectuer
 
-# Partie 1: Accès mémoire avec cache prédictif ternaire
-predictive_cache_access:
-    # Vérifier si on a effectué tous les accès
-    CMP r5, r4
-    BRANCH GE, tree_traversal_init  # Si index >= nombre d'accès, passer à la partie suivante
-    
-    # Charger le motif d'accès courant
-    ADD r6, r2, r5         # r6 = adresse_motifs + index
-    LOADW r7, r6, 0        # r7 = motif d'accès
-    
-    # Calculer l'adresse à accéder
-    ADD r8, r1, r7         # r8 = adresse_données + offset
-    
-    # Activer le mode cache prédictif ternaire
-    # Dans un cas réel, cela serait fait via un registre de contrôle spécial
-    # Ici, on simule l'activation avec une instruction spéciale
-    SETCACHEMODE r0, 1     # Activer le mode cache prédictif
-    
-    # Effectuer l'accès mémoire avec prédiction ternaire
-    # Le cache prédictif va anticiper les accès futurs en utilisant la logique ternaire
-    LOADW r9, r8, 0        # r9 = données[offset]
-    
-    # Stocker le résultat
-    ADD r10, r3, r5        # r10 = adresse_résultats + index
-    STOREW r9, r10, 0      # résultats[index] = valeur
-    
-    # Incrémenter l'index
-    ADDI r5, r5, 1
-    BRANCH AL, predictive_cache_access  # Continuer la boucle
+# Partie 1: Parcours séquentiel
+sequential_init:
+    MOVI r4, 0             # Index courant
+    MOVI r5, DATA_ADDR     # Adresse courante
 
-# Partie 2: Traversée d'arbre avec cache prédictif
-tree_traversal_init:
-    # Réinitialiser l'index
-    MOVI r5, 0
-    
-    # Calculer la nouvelle adresse de destination pour les résultats
-    MOVI r6, RESULT_ADDR
-    ADDI r6, r6, ACCESS_COUNT  # Décaler pour ne pas écraser les résultats précédents
-    
-    # Adresse de la racine de l'arbre (simulée dans la zone de données)
-    MOVI r7, DATA_ADDR
-    ADDI r7, r7, ARRAY_SIZE / 2  # Milieu du tableau comme racine
-
-tree_traversal_loop:
+sequential_loop:
     # Vérifier si on a effectué tous les accès
-    CMP r5, r4
-    BRANCH GE, graph_traversal_init  # Si index >= nombre d'accès, passer à la partie suivante
-    
-    # Charger le motif d'accès courant (direction de traversée)
-    ADD r8, r2, r5         # r8 = adresse_motifs + index
-    LOADW r9, r8, 0        # r9 = motif d'accès
-    
-    # Activer le mode cache prédictif ternaire
-    SETCACHEMODE r0, 1     # Activer le mode cache prédictif
-    
-    # Traverser l'arbre en fonction de la direction
-    # r9 < 0: aller à gauche, r9 = 0: rester, r9 > 0: aller à droite
-    MOVI r10, 0
-    CMP r9, r10
-    BRANCH3 r9, go_left, stay_node, go_right
-
-go_left:
-    # Calculer l'adresse du nœud enfant gauche
-    LOADW r11, r7, 0       # r11 = adresse du nœud courant
-    SUBI r11, r11, 1       # r11 -= 1 (aller à gauche)
-    ADD r7, r1, r11        # r7 = adresse_données + offset
-    BRANCH AL, process_node
-
-stay_node:
-    # Rester au nœud courant
-    BRANCH AL, process_node
-
-go_right:
-    # Calculer l'adresse du nœud enfant droit
-    LOADW r11, r7, 0       # r11 = adresse du nœud courant
-    ADDI r11, r11, 1       # r11 += 1 (aller à droite)
-    ADD r7, r1, r11        # r7 = adresse_données + offset
-
-process_node:
-    # Charger la valeur du nœud
-    LOADW r12, r7, 0       # r12 = valeur du nœud
-    
-    # Stocker le résultat
-    ADD r13, r6, r5        # r13 = adresse_résultats + index
-    STOREW r12, r13, 0     # résultats[index] = valeur
-    
-    # Incrémenter l'index
-    ADDI r5, r5, 1
-    BRANCH AL, tree_traversal_loop  # Continuer la boucle
+    CMP r4, r3
+    BRANCH GE, strided_init # Si index >= nombre d'accès, passer à la partie suivante
 
-# Partie 3: Parcours de graphe avec cache prédictif
-graph_traversal_init:
-    # Réinitialiser l'index
-    MOVI r5, 0
-    
-    # Calculer la nouvelle adresse de destination pour les résultats
-    MOVI r6, RESULT_ADDR
-    ADDI r6, r6, ACCESS_COUNT * 2  # Décaler pour ne pas écraser les résultats précédents
-    
-    # Adresse du premier nœud du graphe
-    MOVI r7, DATA_ADDR
-
-graph_traversal_loop:
+    # Charger l'élément courant et le stocker dans les résultats
+    LOADW r6, r5, 0        # r6 = données[index]
+    STOREW r2, r6, 0       # résultats[n] = r6
+
+    # Avancer d'un Mot
+    ADDI r5, r5, 8
+    ADDI r2, r2, 8
+    ADDI r4, r4, 1
+    BRANCH AL, sequential_loop # Continuer la boucle
+
+# Partie 2: Parcours à pas constant (64 trytes: une ligne sur deux du cache par défaut)
+strided_init:
+    MOVI r4, 0             # Index courant
+    MOVI r5, DATA_ADDR     # Adresse courante
+
+strided_loop:
     # Vérifier si on a effectué tous les accès
-    CMP r5, r4
-    BRANCH GE, conventional_init  # Si index >= nombre d'accès, passer à la partie suivante
-    
-    # Activer le mode cache prédictif ternaire avec niveau de confiance
-    # Le niveau de confiance est utilisé pour prédire les accès futurs
-    # -1: improbable, 0: incertain, 1: probable
-    SETCACHEMODE r0, 2     # Activer le mode cache prédictif avec niveau de confiance
-    
-    # Charger le nœud courant
-    LOADW r8, r7, 0        # r8 = valeur du nœud
-    
-    # Charger les adresses des voisins (simulées)
-    LOADW r9, r7, 1        # r9 = adresse du voisin 1
-    LOADW r10, r7, 2       # r10 = adresse du voisin 2
-    LOADW r11, r7, 3       # r11 = adresse du voisin 3
-    
-    # Charger le motif d'accès courant (choix du voisin)
-    ADD r12, r2, r5        # r12 = adresse_motifs + index
-    LOADW r13, r12, 0      # r13 = motif d'accès
-    
-    # Choisir le voisin en fonction du motif
-    MOVI r14, 3
-    MOD r13, r13, r14      # r13 = r13 % 3 (pour avoir une valeur entre 0 et 2)
-    
-    MOVI r14, 0
-    CMP r13, r14
-    BRANCH EQ, choose_neighbor1
-    
-    MOVI r14, 1
-    CMP r13, r14
-    BRANCH EQ, choose_neighbor2
-    
-    # Sinon, choisir le voisin 3
-    ADD r7, r1, r11        # r7 = adresse_données + offset_voisin3
-    BRANCH AL, store_graph_result
-
-choose_neighbor1:
-    ADD r7, r1, r9         # r7 = adresse_données + offset_voisin1
-    BRANCH AL, store_graph_result
-
-choose_neighbor2:
-    ADD r7, r1, r10        # r7 = adresse_données + offset_voisin2
-
-store_graph_result:
-    # Stocker le résultat
-    ADD r14, r6, r5        # r14 = adresse_résultats + index
-    STOREW r8, r14, 0      # résultats[index] = valeur du nœud
-    
-    # Incrémenter l'index
-    ADDI r5, r5, 1
-    BRANCH AL, graph_traversal_loop  # Continuer la boucle
+    CMP r4, r3
+    BRANCH GE, tree_init   # Si index >= nombre d'accès, passer à la partie suivante
 
-# Partie 4: Approche conventionnelle pour comparaison
-conventional_init:
-    # Réinitialiser l'index
-    MOVI r5, 0
-    
-    # Calculer la nouvelle adresse de destination pour les résultats conventionnels
-    MOVI r6, RESULT_ADDR
-    ADDI r6, r6, ACCESS_COUNT * 3  # Décaler pour ne pas écraser les résultats précédents
+    # Charger l'élément courant et le stocker dans les résultats
+    LOADW r6, r5, 0        # r6 = données[8 * index]
+    STOREW r2, r6, 0       # résultats[n] = r6
+
+    # Avancer de 8 Mots
+    ADDI r5, r5, 64
+    ADDI r2, r2, 8
+    ADDI r4, r4, 1
+    BRANCH AL, strided_loop # Continuer la boucle
 
-conventional_access:
+# Partie 3: Descente dans un arbre binaire implicite (nœud i en données[i])
+tree_init:
+    MOVI r4, 0             # Index courant
+    MOVI r5, 0             # Nœud courant (racine)
+
+tree_loop:
     # Vérifier si on a effectué tous les accès
-    CMP r5, r4
+    CMP r4, r3
     BRANCH GE, done        # Si index >= nombre d'accès, terminer
-    
-    # Désactiver le cache prédictif
-    SETCACHEMODE r0, 0     # Désactiver le mode cache prédictif
-    
-    # Charger le motif d'accès courant
-    ADD r7, r2, r5         # r7 = adresse_motifs + index
-    LOADW r8, r7, 0        # r8 = motif d'accès
-    
-    # Calculer l'adresse à accéder
-    ADD r9, r1, r8         # r9 = adresse_données + offset
-    
-    # Effectuer l'accès mémoire conventionnel
-    LOADW r10, r9, 0       # r10 = données[offset]
-    
-    # Stocker le résultat
-    ADD r11, r6, r5        # r11 = adresse_résultats_conv + index
-    STOREW r10, r11, 0     # résultats_conv[index] = valeur
-    
-    # Incrémenter l'index
+
+    # Adresse du nœud: données + 8 * i
+    ADD r7, r5, r5         # r7 = 2i
+    ADD r7, r7, r7         # r7 = 4i
+    ADD r7, r7, r7         # r7 = 8i
+    ADD r7, r1, r7         # r7 = adresse_données + 8i
+
+    # Charger la valeur du nœud et la stocker dans les résultats
+    LOADW r6, r7, 0        # r6 = données[i]
+    STOREW r2, r6, 0       # résultats[n] = r6
+    ADDI r2, r2, 8
+
+    # Descendre vers le fils gauche (2i + 1), revenir à la racine sous la dernière feuille
+    ADD r5, r5, r5
     ADDI r5, r5, 1
-    BRANCH AL, conventional_access  # Continuer la boucle
+    MOVI r6, TREE_SIZE
+    CMP r5, r6
+    BRANCH LT, tree_next
+    MOVI r5, 0
+
+tree_next:
+    # Incrémenter l'index
+    ADDI r4, r4, 1
+    BRANCH AL, tree_loop   # Continuer la boucle
 
 done:
     # Fin du benchmark
-    HALT
\ No newline at end of file
+    HALT
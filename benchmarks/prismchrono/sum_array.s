@@ -20,23 +20,22 @@ _start:
     MOVI r2, ARRAY_SIZE   # Taille du tableau
     MOVI r3, 0            # Accumulateur (somme)
     MOVI r4, 0            # Index courant
+    MOVI r5, ARRAY_ADDR   # Adresse de l'élément courant
 
 loop:
     # Vérifier si on a parcouru tout le tableau
     CMP r4, r2            # Comparer index et taille
     BRANCH GE, end_loop   # Si index >= taille, sortir de la boucle
     
-    # Calculer l'adresse de l'élément courant
-    ADD r5, r1, r4        # r5 = adresse de base + index
-    
     # Charger l'élément courant
     LOADW r6, r5, 0       # r6 = tableau[index]
     
     # Ajouter à la somme
     ADD r3, r3, r6        # somme += tableau[index]
     
-    # Incrémenter l'index
+    # Incrémenter l'index et passer au Mot suivant (8 trytes)
     ADDI r4, r4, 1        # index++
+    ADDI r5, r5, 8
     
     # Retour au début de la boucle
     BRANCH ALWAYS, loop
@@ -44,7 +43,7 @@ loop:
 end_loop:
     # Stocker le résultat
     MOVI r7, RESULT_ADDR
-    STOREW r3, r7, 0      # Stocker la somme à l'adresse résultat
+    STOREW r7, r3, 0      # Stocker la somme à l'adresse résultat
     
     # Fin du programme
     HALT
\ No newline at end of file
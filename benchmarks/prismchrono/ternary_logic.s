@@ -1,6 +1,7 @@
 # Benchmark: Ternary Logic
 # Implémentation d'un système de vote à trois états (Positif/Zéro/Négatif)
-# Ce benchmark est conçu pour mettre en évidence les avantages de la logique ternaire
+# Ce benchmark est conçu pour mettre en évidence les avantages de la logique ternaire:
+# un seul BRANCH3 classe chaque vote selon son signe
 
 # Définition des constantes
 .equ ARRAY_SIZE, 50      # Nombre de votes
@@ -12,9 +13,15 @@
 .equ ZERO, 0             # Abstention
 .equ POSITIVE, 1         # Vote positif
 
+# Bloc de résultats (offsets en trytes, un Mot chacun)
+.equ RESULT, 0           # Résultat du vote
+.equ POSITIVE_COUNT, 8   # Nombre de votes positifs
+.equ NEGATIVE_COUNT, 16  # Nombre de votes négatifs
+.equ ZERO_COUNT, 24      # Nombre d'abstentions
+
 # Section de données
 .section .data
-# Le tableau de votes sera initialisé par le simulateur ou le script d'exécution
+# Le tableau de votes est initialisé par le fichier ternary_logic.data (--preload-file)
 
 # Section de code
 .section .text
@@ -22,81 +29,69 @@
 
 _start:
     # Initialisation des registres
-    MOVI r1, ARRAY_ADDR   # Adresse du tableau
-    MOVI r2, ARRAY_SIZE   # Taille du tableau
+    LI r1, ARRAY_ADDR     # Adresse du vote courant
+    MOVI r2, ARRAY_SIZE   # Votes restants
     MOVI r3, 0            # Compteur de votes positifs
     MOVI r4, 0            # Compteur de votes négatifs
     MOVI r5, 0            # Compteur d'abstentions
-    MOVI r6, 0            # Index courant
 
 count_loop:
     # Vérifier si on a parcouru tout le tableau
-    CMP r6, r2            # Comparer index et taille
-    BRANCH GE, end_count  # Si index >= taille, sortir de la boucle
-    
-    # Calculer l'adresse de l'élément courant
-    ADD r7, r1, r6        # r7 = adresse de base + index
-    
+    BEQZ r2, end_count
+
     # Charger le vote courant
-    LOADW r8, r7, 0       # r8 = tableau[index]
-    
-    # Déterminer le type de vote (utilisation de la logique ternaire)
-    # Avantage PrismChrono: peut utiliser COMPARE3 ou équivalent pour tester en une seule instruction
-    
-    # Vérifier si c'est un vote positif
-    MOVI r9, POSITIVE
-    CMP r8, r9
-    BRANCH NE, check_negative
-    ADDI r3, r3, 1        # Incrémenter compteur de votes positifs
-    BRANCH ALWAYS, next_vote
-    
-check_negative:
-    # Vérifier si c'est un vote négatif
-    MOVI r9, NEGATIVE
-    CMP r8, r9
-    BRANCH NE, check_zero
+    LOADW r6, r1, 0       # r6 = tableau[index]
+
+    # Déterminer le type de vote en une seule instruction selon le signe de r6
+    BRANCH3 r6, negative_vote, zero_vote, positive_vote
+
+negative_vote:
     ADDI r4, r4, 1        # Incrémenter compteur de votes négatifs
-    BRANCH ALWAYS, next_vote
-    
-check_zero:
-    # Si ce n'est ni positif ni négatif, c'est une abstention
+    J next_vote
+
+zero_vote:
     ADDI r5, r5, 1        # Incrémenter compteur d'abstentions
-    
+    J next_vote
+
+positive_vote:
+    ADDI r3, r3, 1        # Incrémenter compteur de votes positifs
+
 next_vote:
-    # Passer au vote suivant
-    ADDI r6, r6, 1        # index++
-    BRANCH ALWAYS, count_loop
+    # Passer au vote suivant (un Mot = 8 trytes)
+    ADDI r1, r1, 8
+    SUBI r2, r2, 1
+    J count_loop
 
 end_count:
     # Déterminer le résultat du vote
     # Règle: majorité simple (plus de votes positifs que négatifs = accepté)
     CMP r3, r4            # Comparer votes positifs et négatifs
     BRANCH LE, vote_rejected
-    
+
     # Vote accepté
-    MOVI r10, POSITIVE
-    BRANCH ALWAYS, store_result
-    
+    MOVI r6, POSITIVE
+    J store_result
+
 vote_rejected:
     # Vérifier s'il y a égalité
     CMP r3, r4
     BRANCH NE, vote_negative
-    
+
     # Égalité
-    MOVI r10, ZERO
-    BRANCH ALWAYS, store_result
-    
+    MOVI r6, ZERO
+    J store_result
+
 vote_negative:
     # Plus de votes négatifs
-    MOVI r10, NEGATIVE
-    
+    MOVI r6, NEGATIVE
+
 store_result:
     # Stocker le résultat et les compteurs
-    MOVI r11, RESULT_ADDR
-    STOREW r10, r11, 0    # Résultat du vote
-    STOREW r3, r11, 1     # Nombre de votes positifs
-    STOREW r4, r11, 2     # Nombre de votes négatifs
-    STOREW r5, r11, 3     # Nombre d'abstentions
-    
+    LI r7, RESULT_ADDR
+    STOREW r7, r6, RESULT
+    STOREW r7, r3, POSITIVE_COUNT
+    STOREW r7, r4, NEGATIVE_COUNT
+    STOREW r7, r5, ZERO_COUNT
+
     # Fin du programme
-    HALT
\ No newline at end of file
+    HALT
@@ -1,18 +1,13 @@
 # Benchmark: TVPU Operations
 # Évaluation des performances des instructions vectorielles ternaires
 # Ce benchmark compare les opérations vectorielles aux opérations scalaires équivalentes
+# sur BLOCKS blocs de 8 mots: addition, produit scalaire, somme, minimum, maximum et moyenne
 
 # Définition des constantes
-.equ VECTOR_SIZE, 8       # Nombre d'éléments dans un vecteur ternaire
-.equ ARRAY_SIZE, 32       # Nombre total d'éléments à traiter
-.equ DATA_ADDR, 0x1000    # Adresse des données d'entrée
-.equ VECTOR_A_ADDR, 0x1200 # Adresse du vecteur A
-.equ VECTOR_B_ADDR, 0x1300 # Adresse du vecteur B
-.equ RESULT_ADDR, 0x1400  # Adresse des résultats
-
-# Section de données
-.section .data
-# Les données seront initialisées par le simulateur ou le script d'exécution
+.equ BLOCKS, 2            # Nombre de blocs de 8 mots à traiter
+.equ ARRAY_SIZE, 16       # Nombre total d'éléments (BLOCKS * 8)
+.equ VECTOR_TRYTES, 64    # Taille d'un vecteur en mémoire (8 mots de 8 trytes)
+.equ RESULT_TRYTES, 104   # Résultats d'un bloc: vecteur A + B puis 5 scalaires
 
 # Section de code
 .section .text
@@ -20,158 +15,126 @@
 
 _start:
     # Initialisation des registres
-    MOVI r1, DATA_ADDR     # Adresse des données
-    MOVI r2, VECTOR_A_ADDR # Adresse du vecteur A
-    MOVI r3, VECTOR_B_ADDR # Adresse du vecteur B
-    MOVI r4, RESULT_ADDR   # Adresse des résultats
-    MOVI r5, ARRAY_SIZE    # Nombre total d'éléments
-    MOVI r6, 0             # Index courant
-
-# Partie 1: Traitement vectoriel avec TVPU
+    LA R1, vector_a        # Adresse du bloc courant de A
+    LA R2, vector_b        # Adresse du bloc courant de B
+    LA R3, vector_results  # Adresse des résultats du bloc courant
+    MOVI R4, BLOCKS        # Nombre de blocs restants
+
+# Partie 1: Traitement vectoriel avec la TVPU
 vector_processing:
-    # Vérifier si on a traité tous les éléments
-    CMP r6, r5
-    BRANCH GE, scalar_init # Si index >= nombre d'éléments, passer à la partie suivante
-    
-    # Charger les vecteurs A et B depuis la mémoire
-    # Dans un cas réel, on utiliserait des instructions spécifiques pour charger des vecteurs
-    # Ici, on simule le chargement en copiant les données
-    MOVI r7, 0             # Index de vecteur
-    
-load_vectors:
-    CMP r7, VECTOR_SIZE
-    BRANCH GE, process_vectors
-    
-    # Calculer les adresses source
-    ADD r8, r1, r6         # r8 = adresse_données + index_global
-    ADD r8, r8, r7         # r8 += index_vecteur
-    
-    # Calculer les adresses destination
-    ADD r9, r2, r7         # r9 = adresse_vecteur_A + index_vecteur
-    ADD r10, r3, r7        # r10 = adresse_vecteur_B + index_vecteur
-    
-    # Charger les données
-    LOADW r11, r8, 0       # r11 = données[index_global + index_vecteur]
-    STOREW r11, r9, 0      # vecteur_A[index_vecteur] = r11
-    
-    # Pour le vecteur B, on utilise les données suivantes
-    LOADW r11, r8, VECTOR_SIZE  # r11 = données[index_global + index_vecteur + VECTOR_SIZE]
-    STOREW r11, r10, 0     # vecteur_B[index_vecteur] = r11
-    
-    ADDI r7, r7, 1         # Incrémenter l'index de vecteur
-    BRANCH AL, load_vectors
-
-process_vectors:
-    # Effectuer les opérations vectorielles
-    # 1. Addition vectorielle (TVADD)
-    TVADD V0, V1, V2       # V0 = V1 + V2 (registres vectoriels)
-    
+    # Charger les vecteurs A et B (8 mots consécutifs chacun)
+    TVLOAD V1, R1
+    TVLOAD V2, R2
+
+    # 1. Addition vectorielle (TVADD), stockée en tête des résultats du bloc
+    TVADD V0, V1, V2       # V0 = V1 + V2
+    TVSTORE V0, R3
+
     # 2. Produit scalaire (TVDOT)
-    TVDOT r12, V1, V2      # r12 = V1 · V2
-    
+    TVDOT R5, V1, V2       # R5 = V1 · V2
+    STOREW R3, R5, 64
+
     # 3. Somme des éléments (TVSUM)
-    TVSUM r13, V0          # r13 = somme des éléments de V0
-    
+    TVSUM R5, V0           # R5 = somme des éléments de V0
+    STOREW R3, R5, 72
+
     # 4. Minimum et maximum (TVMIN, TVMAX)
-    TVMIN r14, V1          # r14 = min(V1)
-    TVMAX r15, V2          # r15 = max(V2)
-    
+    TVMIN R5, V1           # R5 = min(V1)
+    STOREW R3, R5, 80
+    TVMAX R5, V2           # R5 = max(V2)
+    STOREW R3, R5, 88
+
     # 5. Moyenne (TVAVG)
-    TVAVG r16, V0          # r16 = moyenne(V0)
-    
-    # Stocker les résultats
-    ADD r17, r4, r6        # r17 = adresse_résultats + index_global
-    STOREW r12, r17, 0     # résultat[index] = produit_scalaire
-    STOREW r13, r17, 1     # résultat[index+1] = somme
-    STOREW r14, r17, 2     # résultat[index+2] = minimum
-    STOREW r15, r17, 3     # résultat[index+3] = maximum
-    STOREW r16, r17, 4     # résultat[index+4] = moyenne
-    
-    # Passer aux éléments suivants
-    ADDI r6, r6, VECTOR_SIZE
-    BRANCH AL, vector_processing
+    TVAVG R5, V0           # R5 = moyenne(V0)
+    STOREW R3, R5, 96
+
+    # Passer au bloc suivant
+    ADDI R1, R1, VECTOR_TRYTES
+    ADDI R2, R2, VECTOR_TRYTES
+    ADDI R3, R3, RESULT_TRYTES
+    SUBI R4, R4, 1
+    BNEZ R4, vector_processing
 
 # Partie 2: Traitement scalaire équivalent pour comparaison
+# (produit scalaire et somme de A + B sur l'ensemble des éléments)
 scalar_init:
-    MOVI r6, 0             # Réinitialiser l'index
-    ADDI r4, r4, ARRAY_SIZE # Décaler l'adresse de résultat
-
-scalar_processing:
-    # Vérifier si on a traité tous les éléments
-    CMP r6, r5
-    BRANCH GE, end         # Si index >= nombre d'éléments, terminer
-    
-    # Initialiser les accumulateurs pour les opérations scalaires
-    MOVI r7, 0             # Index de vecteur
-    MOVI r12, 0            # Accumulateur pour le produit scalaire
-    MOVI r13, 0            # Accumulateur pour la somme
-    MOVI r14, 0            # Valeur pour le minimum (sera initialisée au premier élément)
-    MOVI r15, 0            # Valeur pour le maximum (sera initialisée au premier élément)
-    MOVI r18, 1            # Indicateur d'initialisation pour min/max
-    
+    LA R1, vector_a        # Élément courant de A
+    LA R2, vector_b        # Élément courant de B
+    MOVI R3, ARRAY_SIZE    # Nombre d'éléments restants
+    MOVI R4, 0             # Accumulateur pour le produit scalaire
+    MOVI R5, 0             # Accumulateur pour la somme
+
 scalar_loop:
-    CMP r7, VECTOR_SIZE
-    BRANCH GE, store_scalar_results
-    
-    # Calculer les adresses source
-    ADD r8, r1, r6         # r8 = adresse_données + index_global
-    ADD r8, r8, r7         # r8 += index_vecteur
-    
-    # Charger les données A et B
-    LOADW r9, r8, 0                # r9 = données[index_global + index_vecteur]
-    LOADW r10, r8, VECTOR_SIZE     # r10 = données[index_global + index_vecteur + VECTOR_SIZE]
-    
-    # 1. Addition scalaire
-    ADD r11, r9, r10       # r11 = A + B
-    
-    # 2. Produit scalaire (multiplication puis accumulation)
-    MUL r19, r9, r10       # r19 = A * B
-    ADD r12, r12, r19      # Accumuler le produit
-    
-    # 3. Somme des éléments (accumulation de A + B)
-    ADD r13, r13, r11      # Accumuler la somme
-    
-    # 4. Minimum et maximum
-    CMP r18, 0
-    BRANCH EQ, update_min_max
-    
-    # Initialiser min et max au premier élément
-    MOV r14, r9            # min = premier élément de A
-    MOV r15, r9            # max = premier élément de A
-    MOVI r18, 0            # Désactiver l'indicateur d'initialisation
-    BRANCH AL, continue_scalar
-    
-update_min_max:
-    # Mettre à jour le minimum
-    CMP r9, r14
-    BRANCH GE, check_max
-    MOV r14, r9            # Nouveau minimum trouvé
-    
-check_max:
-    # Mettre à jour le maximum
-    CMP r9, r15
-    BRANCH LE, continue_scalar
-    MOV r15, r9            # Nouveau maximum trouvé
-    
-continue_scalar:
-    ADDI r7, r7, 1         # Incrémenter l'index de vecteur
-    BRANCH AL, scalar_loop
-
-store_scalar_results:
-    # Calculer la moyenne
-    DIV r16, r13, VECTOR_SIZE  # r16 = somme / taille
-    
-    # Stocker les résultats
-    ADD r17, r4, r6        # r17 = adresse_résultats + index_global
-    STOREW r12, r17, 0     # résultat[index] = produit_scalaire
-    STOREW r13, r17, 1     # résultat[index+1] = somme
-    STOREW r14, r17, 2     # résultat[index+2] = minimum
-    STOREW r15, r17, 3     # résultat[index+3] = maximum
-    STOREW r16, r17, 4     # résultat[index+4] = moyenne
-    
-    # Passer aux éléments suivants
-    ADDI r6, r6, VECTOR_SIZE
-    BRANCH AL, scalar_processing
+    LOADW R6, R1, 0        # R6 = A[i]
+    LOADW R7, R2, 0        # R7 = B[i]
+
+    # Somme des éléments de A + B
+    ADD R5, R5, R6
+    ADD R5, R5, R7
+
+    # Produit scalaire (multiplication puis accumulation)
+    MUL R7, R6, R7         # R7 = A[i] * B[i]
+    ADD R4, R4, R7
+
+    ADDI R1, R1, 8         # Mot suivant
+    ADDI R2, R2, 8
+    SUBI R3, R3, 1
+    BNEZ R3, scalar_loop
+
+    # Stocker les résultats scalaires
+    LA R6, scalar_results
+    STOREW R6, R4, 0       # Produit scalaire
+    STOREW R6, R5, 8       # Somme
 
 end:
-    HALT
\ No newline at end of file
+    HALT
+
+# Section de données
+.section .data
+.align 8
+
+# Vecteur A (BLOCKS blocs de 8 mots)
+vector_a:
+    .word 1
+    .word 2
+    .word 3
+    .word 4
+    .word 5
+    .word 6
+    .word 7
+    .word 8
+    .word 9
+    .word 10
+    .word 11
+    .word 12
+    .word 13
+    .word 14
+    .word 15
+    .word 16
+
+# Vecteur B (BLOCKS blocs de 8 mots)
+vector_b:
+    .word 3
+    .word -1
+    .word 4
+    .word 1
+    .word -5
+    .word 9
+    .word 2
+    .word -6
+    .word 5
+    .word 3
+    .word -5
+    .word 8
+    .word 9
+    .word -7
+    .word 9
+    .word 3
+
+# Résultats de la partie scalaire: produit scalaire puis somme
+scalar_results:
+    .word 0
+    .word 0
+
+# Résultats de la partie vectorielle (RESULT_TRYTES trytes par bloc, au-delà de l'image)
+vector_results:
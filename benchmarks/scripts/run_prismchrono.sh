@@ -15,8 +15,8 @@ if [ ! -f "$CONFIG_FILE" ]; then
 fi
 
 # Extraire les chemins des outils PrismChrono (à adapter selon la structure réelle du projet)
-ASM_PATH="$(dirname "$BENCHMARK_DIR")/prismchrono_asm/target/release/prismchrono_asm"
-SIM_PATH="$(dirname "$BENCHMARK_DIR")/prismChrono_sim/target/release/prismchrono_sim"
+ASM_PATH="$(dirname "$BENCHMARK_DIR")/target/release/prismchrono_asm"
+SIM_PATH="$(dirname "$BENCHMARK_DIR")/target/release/prismchrono_sim"
 
 # Vérifier que les outils existent
 if [ ! -f "$ASM_PATH" ]; then
@@ -34,12 +34,14 @@ fi
 # Créer le répertoire de résultats s'il n'existe pas
 mkdir -p "$RESULTS_DIR"
 
-# Fonction pour exécuter un benchmark
+# Fonction pour exécuter un benchmark (arguments suivants: options du simulateur)
 run_benchmark() {
     local benchmark=$1
+    shift
     local source_file="$PRISMCHRONO_DIR/${benchmark}.s"
     local binary_file="$PRISMCHRONO_DIR/${benchmark}.tbin"
     local output_file="$RESULTS_DIR/${benchmark}.json"
+    local data_file="$PRISMCHRONO_DIR/${benchmark}.data"
     
     echo "Exécution du benchmark: $benchmark"
     
@@ -57,29 +59,50 @@ run_benchmark() {
         return 1
     fi
     
-    # Exécuter le binaire sur le simulateur avec instrumentation
+    # Données d'entrée du benchmark (tableaux initialisés avant l'exécution)
+    local data_args=()
+    if [ -f "$data_file" ]; then
+        echo "  Données d'entrée: $data_file"
+        data_args=(--preload-file "$data_file")
+    fi
+
+    # Exécuter le binaire sur le simulateur avec instrumentation (métriques JSON)
     echo "  Exécution sur le simulateur avec instrumentation"
-    "$SIM_PATH" "$binary_file" --metrics > "$output_file"
+    "$SIM_PATH" "$binary_file" --metrics "${data_args[@]}" "$@" > "$output_file"
     if [ $? -ne 0 ]; then
         echo "Erreur lors de l'exécution de $binary_file sur le simulateur"
         return 1
     fi
     
+    # Un benchmark qui ne s'arrête pas sur HALT (erreur, limite d'instructions) est un échec
+    if ! grep -q '"stop_reason": "halted"' "$output_file"; then
+        echo "Erreur: $benchmark ne s'est pas arrêté sur HALT ($(grep -o '"stop_reason": "[a-z_]*"' "$output_file"))"
+        return 1
+    fi
+
     echo "  Métriques sauvegardées dans: $output_file"
     return 0
 }
 
 # Exécuter les benchmarks standard
+# (linear_search, insertion_sort et function_call n'ont pas encore de version PrismChrono)
 echo "Exécution des benchmarks standard..."
-for benchmark in sum_array memcpy factorial linear_search insertion_sort function_call; do
+for benchmark in sum_array memcpy factorial; do
     run_benchmark "$benchmark"
 done
 
 # Exécuter les benchmarks spécifiques ternaires
+# (les sources qui utilisent des registres au-delà de R7 ne sont pas lancées, voir prismchrono/README.md)
 echo "Exécution des benchmarks spécifiques ternaires..."
-for benchmark in ternary_logic trit_operations branch3_decision branch3_predictor tvpu_operations tvpu_astro_benchmark compact_format optimized_memory base24_arithmetic special_states base60_arithmetic; do
+for benchmark in ternary_logic branch3_decision tvpu_operations special_states; do
     run_benchmark "$benchmark"
 done
 
+# Exécuter les benchmarks mémoire avec les caches L1 (cycles d'attente et taux de succès)
+echo "Exécution des benchmarks mémoire avec caches..."
+for benchmark in optimized_memory predictive_cache; do
+    run_benchmark "$benchmark" --icache --dcache
+done
+
 echo "=== Exécution des benchmarks PrismChrono terminée ==="
 exit 0
\ No newline at end of file
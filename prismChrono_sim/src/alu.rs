@@ -1,35 +1,33 @@
 // src/alu.rs
 // Implémentation des opérations logiques et arithmétiques de l'ALU
-
-use crate::core::{Trit, Tryte, Word};
+//
+// Sémantique (vérifiée par tests/alu_model.rs contre un modèle entier):
+// - Les opérations trit-à-trit (INV, MIN, MAX) et les décalages agissent sur les 24 trits
+//   tels quels, quel que soit le tryte qu'ils forment (le résultat peut donc contenir un
+//   motif UNDEF/NULL/NaN).
+// - L'arithmétique travaille sur les chiffres B24 des trytes (Bal3 -13..+10, poids 24^i).
+//   ADD/SUB additionnent tryte par tryte et ramènent chaque chiffre dans -13..+10 avec une
+//   retenue sur le tryte suivant: un résultat numérique ne contient jamais d'état spécial.
+// - Un tryte Bal3 +11/+12/+13 d'un opérande est un état spécial. ADD/SUB le propagent tryte
+//   par tryte (NaN > NULL > UNDEF) en comptant ces trytes pour zéro dans le calcul des autres;
+//   MUL/DIV/MOD rendent le mot entier de l'état spécial dominant. XF est levé exactement
+//   quand le résultat contient un état spécial.
+// - Les résultats sont ceux de l'arithmétique entière, repliés modulo 24^8 dans
+//   [WORD_MIN, WORD_MAX].
+
+use crate::core::{Trit, Tryte, WORD_MIN, WORD_MODULUS, Word};
 use crate::cpu::Flags;
 
 /// Inverse tous les trits d'un mot (24 trits)
 /// Chaque trit est inversé selon la règle: N -> P, Z -> Z, P -> N
+/// Chaque tryte voit sa valeur Bal3 changer de signe (NaN, P,P,P, devient -13, N,N,N, et
+/// inversement), ce qui garantit INV(INV(a)) = a. Ce n'est la négation du mot que si aucun
+/// chiffre ne vaut -13..-11: la négation arithmétique est `neg_24_trits`.
 pub fn trit_inv_word(a: Word) -> Word {
-    let mut result = Word::default_undefined();
+    let mut result = Word::zero();
 
-    // Parcourir chaque tryte du mot
-    for i in 0..8 {
-        if let (Some(tryte_a), Some(tryte_result)) = (a.tryte(i), result.tryte_mut(i)) {
-            // Pour les états spéciaux, on les préserve
-            match tryte_a {
-                Tryte::Undefined => *tryte_result = Tryte::Undefined,
-                Tryte::Null => *tryte_result = Tryte::Null,
-                Tryte::NaN => *tryte_result = Tryte::NaN,
-                Tryte::Digit(_) => {
-                    // Convertir le tryte en trits, inverser chaque trit, puis reconvertir en tryte
-                    let trits_a = tryte_a.to_trits();
-                    let mut inverted_trits = [Trit::Z; 3];
-
-                    for j in 0..3 {
-                        inverted_trits[j] = trits_a[j].inv();
-                    }
-
-                    *tryte_result = Tryte::from_trits(inverted_trits);
-                }
-            }
-        }
+    for i in 0..24 {
+        result.set_trit(i, a.get_trit(i).inv());
     }
 
     result
@@ -120,7 +118,7 @@ pub fn ternary_full_adder(a: Trit, b: Trit, cin: Trit) -> (Trit, Trit) {
     // Déterminer le trit résultat et la retenue
     match total {
         -3 => (Trit::Z, Trit::N),                       // -3 = 0 + (-1 * 3)
-        -2 => (Trit::N, Trit::N),                       // -2 = -1 + (-1 * 1)
+        -2 => (Trit::P, Trit::N),                       // -2 = 1 + (-1 * 3)
         -1 => (Trit::N, Trit::Z),                       // -1 = -1 + (0 * 3)
         0 => (Trit::Z, Trit::Z),                        // 0 = 0 + (0 * 3)
         1 => (Trit::P, Trit::Z),                        // 1 = 1 + (0 * 3)
@@ -130,160 +128,116 @@ pub fn ternary_full_adder(a: Trit, b: Trit, cin: Trit) -> (Trit, Trit) {
     }
 }
 
-/// Implémente l'addition de deux mots de 24 trits (8 trytes)
-/// Prend deux mots a et b, ainsi qu'une retenue d'entrée cin
-/// Retourne un tuple (result, cout, flags) où result est le mot résultat,
-/// cout est la retenue de sortie et flags contient les drapeaux mis à jour
-pub fn add_24_trits(a: Word, b: Word, cin: Trit) -> (Word, Trit, Flags) {
-    let mut result = Word::default_undefined();
-    let mut carry = cin;
-    let mut flags = Flags::new();
-    let mut all_zeros = true;
+/// Rang d'un état spécial dans l'ordre de priorité NaN > NULL > UNDEF (0 pour un chiffre)
+fn special_rank(tryte: Tryte) -> u8 {
+    match tryte {
+        Tryte::NaN => 3,
+        Tryte::Null => 2,
+        Tryte::Undefined => 1,
+        Tryte::Digit(_) => 0,
+    }
+}
+
+/// État spécial dominant parmi des trytes, s'il y en a un
+fn dominant_special(trytes: impl IntoIterator<Item = Tryte>) -> Option<Tryte> {
+    trytes.into_iter().filter(|t| special_rank(*t) > 0).max_by_key(|t| special_rank(*t))
+}
+
+/// Chiffre B24 d'un tryte pour l'arithmétique: un tryte spécial compte pour zéro
+fn digit_value(tryte: Tryte) -> i8 {
+    match tryte {
+        Tryte::Digit(_) => tryte.bal3_value(),
+        _ => 0,
+    }
+}
+
+/// Additionne deux chiffres B24 et une retenue, puis ramène la somme dans -13..+10 (comme
+/// l'ajustement décimal d'un additionneur BCD): retourne (chiffre, retenue sortante)
+/// La somme reste dans -24..+24, une seule correction de ±24 suffit.
+fn add_digits(a: i8, b: i8, carry: i8) -> (i8, i8) {
+    match a + b + carry {
+        sum if sum > 10 => (sum - 24, 1),
+        sum if sum < -13 => (sum + 24, -1),
+        sum => (sum, 0),
+    }
+}
+
+/// Additionne a, b (changé de signe si `negate_b`) et la retenue d'entrée, tryte par tryte
+/// Retourne le mot résultat, la retenue sortant du tryte 7, et la présence d'un état spécial
+/// (les trytes spéciaux des opérandes sont recopiés dans le résultat)
+fn add_trytes(a: Word, b: Word, negate_b: bool, cin: Trit) -> (Word, Trit, bool) {
+    let mut result = Word::zero();
+    let mut carry = cin.value();
     let mut has_special = false;
 
     // Parcourir chaque tryte des mots (de poids faible à poids fort)
     for i in 0..8 {
-        if let (Some(tryte_a), Some(tryte_b), Some(tryte_result)) =
-            (a.tryte(i), b.tryte(i), result.tryte_mut(i))
-        {
-            // Gestion des états spéciaux
-            match (tryte_a, tryte_b) {
-                // Si l'un des opérandes est NaN, le résultat est NaN
-                (Tryte::NaN, _) | (_, Tryte::NaN) => {
-                    *tryte_result = Tryte::NaN;
-                    has_special = true;
-                }
-                // Si l'un des opérandes est Null et l'autre n'est pas NaN, le résultat est Null
-                (Tryte::Null, _) | (_, Tryte::Null) => {
-                    *tryte_result = Tryte::Null;
-                    has_special = true;
-                }
-                // Si l'un des opérandes est Undefined et l'autre n'est ni NaN ni Null, le résultat est Undefined
-                (Tryte::Undefined, _) | (_, Tryte::Undefined) => {
-                    *tryte_result = Tryte::Undefined;
-                    has_special = true;
-                }
-                // Sinon, effectuer l'addition normale
-                (Tryte::Digit(_), Tryte::Digit(_)) => {
-                    // Convertir les trytes en trits
-                    let trits_a = tryte_a.to_trits();
-                    let trits_b = tryte_b.to_trits();
-                    let mut sum_trits = [Trit::Z; 3];
-
-                    // Additionner chaque trit avec propagation de la retenue
-                    for j in 0..3 {
-                        let (sum, cout) = ternary_full_adder(trits_a[j], trits_b[j], carry);
-                        sum_trits[j] = sum;
-                        carry = cout;
-                    }
-
-                    // Convertir le résultat en tryte
-                    *tryte_result = Tryte::from_trits(sum_trits);
-
-                    // Vérifier si le tryte résultat est non-nul pour le flag ZF
-                    if *tryte_result != Tryte::Digit(13) {
-                        // 13 = (Z,Z,Z) = 0
-                        all_zeros = false;
-                    }
-                }
+        let (tryte_a, tryte_b) = (a.0[i], b.0[i]);
+        let digit_b = if negate_b { -digit_value(tryte_b) } else { digit_value(tryte_b) };
+        let (digit, cout) = add_digits(digit_value(tryte_a), digit_b, carry);
+        carry = cout;
+
+        result.0[i] = match dominant_special([tryte_a, tryte_b]) {
+            Some(special) => {
+                has_special = true;
+                special
             }
-        }
+            None => Tryte::Digit((digit + 13) as u8),
+        };
     }
 
-    // Mettre à jour les flags
-    flags.zf = all_zeros && !has_special; // ZF = 1 si tous les trits sont Z et pas d'état spécial
+    (result, Trit::from_value(carry).unwrap_or(Trit::Z), has_special)
+}
 
-    // SF = 1 si le trit de poids fort est N (négatif)
-    if let Some(msb_tryte) = result.tryte(7) {
-        // Tryte de poids fort
-        if let Tryte::Digit(_) = msb_tryte {
-            let msb_trits = msb_tryte.to_trits();
-            flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-        }
+/// Flags d'une addition/soustraction à partir du résultat replié et de la retenue sortante
+/// Une retenue sortante non nulle signale exactement le débordement; SF donne le signe du
+/// résultat exact (un débordement vers le haut replie en négatif)
+fn arithmetic_flags(result: &Word, carry: Trit, has_special: bool) -> Flags {
+    let mut flags = Flags::new();
+
+    // XF = 1 si des états spéciaux sont présents (le résultat n'a alors ni valeur ni signe)
+    if has_special {
+        flags.xf = true;
+        return flags;
     }
 
-    // XF = 1 si des états spéciaux sont présents
-    flags.xf = has_special;
+    flags.zf = *result == Word::zero();
+    flags.sf = match carry {
+        Trit::N => true,
+        Trit::P => false,
+        Trit::Z => result.is_negative(),
+    };
+    flags.of = carry != Trit::Z;
+    flags.cf = carry == Trit::P;
+
+    flags
+}
+
+/// Implémente l'addition de deux mots de 24 trits (8 trytes)
+/// Prend deux mots a et b, ainsi qu'une retenue d'entrée cin
+/// Retourne un tuple (result, cout, flags) où result est le mot résultat,
+/// cout est la retenue de sortie et flags contient les drapeaux mis à jour
+pub fn add_24_trits(a: Word, b: Word, cin: Trit) -> (Word, Trit, Flags) {
+    let (result, carry, has_special) = add_trytes(a, b, false, cin);
+    let flags = arithmetic_flags(&result, carry, has_special);
 
-    (result, carry, flags)
+    // Pas de retenue significative en présence d'un état spécial
+    let cout = if has_special { Trit::Z } else { carry };
+    (result, cout, flags)
 }
 
 /// Implémente la soustraction de deux mots de 24 trits (8 trytes)
 /// Prend deux mots a et b, ainsi qu'un emprunt d'entrée bin
 /// Retourne un tuple (result, bout, flags) où result est le mot résultat,
 /// bout est l'emprunt de sortie et flags contient les drapeaux mis à jour
+/// (CF est levé quand l'emprunt sortant vaut P)
 pub fn sub_24_trits(a: Word, b: Word, bin: Trit) -> (Word, Trit, Flags) {
-    let mut result = Word::default_undefined();
-    let mut borrow = bin;
-    let mut flags = Flags::new();
-    let mut all_zeros = true;
-    let mut has_special = false;
+    // a - b - bin = a + (-b) + INV(bin); l'emprunt est l'inverse de la retenue
+    let (result, carry, has_special) = add_trytes(a, b, true, bin.inv());
+    let borrow = if has_special { Trit::Z } else { carry.inv() };
 
-    // Parcourir chaque tryte des mots (de poids faible à poids fort)
-    for i in 0..8 {
-        if let (Some(tryte_a), Some(tryte_b), Some(tryte_result)) =
-            (a.tryte(i), b.tryte(i), result.tryte_mut(i))
-        {
-            // Gestion des états spéciaux
-            match (tryte_a, tryte_b) {
-                // Si l'un des opérandes est NaN, le résultat est NaN
-                (Tryte::NaN, _) | (_, Tryte::NaN) => {
-                    *tryte_result = Tryte::NaN;
-                    has_special = true;
-                }
-                // Si l'un des opérandes est Null et l'autre n'est pas NaN, le résultat est Null
-                (Tryte::Null, _) | (_, Tryte::Null) => {
-                    *tryte_result = Tryte::Null;
-                    has_special = true;
-                }
-                // Si l'un des opérandes est Undefined et l'autre n'est ni NaN ni Null, le résultat est Undefined
-                (Tryte::Undefined, _) | (_, Tryte::Undefined) => {
-                    *tryte_result = Tryte::Undefined;
-                    has_special = true;
-                }
-                // Sinon, effectuer la soustraction normale
-                (Tryte::Digit(_), Tryte::Digit(_)) => {
-                    // Convertir les trytes en trits
-                    let trits_a = tryte_a.to_trits();
-                    let trits_b = tryte_b.to_trits();
-                    let mut diff_trits = [Trit::Z; 3];
-
-                    // Soustraire chaque trit avec propagation de l'emprunt
-                    for j in 0..3 {
-                        // Inverser le trit de b et ajouter
-                        let neg_b_trit = trits_b[j].inv();
-                        let (diff, cout) = ternary_full_adder(trits_a[j], neg_b_trit, borrow.inv());
-                        diff_trits[j] = diff;
-                        borrow = cout.inv(); // L'emprunt est l'inverse de la retenue
-                    }
-
-                    // Convertir le résultat en tryte
-                    *tryte_result = Tryte::from_trits(diff_trits);
-
-                    // Vérifier si le tryte résultat est non-nul pour le flag ZF
-                    if *tryte_result != Tryte::Digit(13) {
-                        // 13 = (Z,Z,Z) = 0
-                        all_zeros = false;
-                    }
-                }
-            }
-        }
-    }
-
-    // Mettre à jour les flags
-    flags.zf = all_zeros && !has_special; // ZF = 1 si tous les trits sont Z et pas d'état spécial
-
-    // SF = 1 si le trit de poids fort est N (négatif)
-    if let Some(msb_tryte) = result.tryte(7) {
-        // Tryte de poids fort
-        if let Tryte::Digit(_) = msb_tryte {
-            let msb_trits = msb_tryte.to_trits();
-            flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-        }
-    }
-
-    // XF = 1 si des états spéciaux sont présents
-    flags.xf = has_special;
+    let mut flags = arithmetic_flags(&result, carry, has_special);
+    flags.cf = borrow == Trit::P;
 
     (result, borrow, flags)
 }
@@ -298,215 +252,63 @@ pub fn compare_24_trits(a: Word, b: Word) -> Flags {
     flags
 }
 
-/// Implémente la multiplication de deux mots de 24 trits (8 trytes)
-/// Retourne le résultat de la multiplication
-pub fn mul_24_trits(a: Word, b: Word) -> Word {
-    let mut result = Word::zero();
-
-    // Cas spéciaux: si l'un des opérandes est zéro, le résultat est zéro
-    if a == Word::zero() || b == Word::zero() {
-        return result;
-    }
-
-    // Multiplication par addition répétée (algorithme simple)
-    // Pour chaque bit de b, si le bit est 1, ajouter a décalé à la position appropriée
-    let mut temp_a = a;
-
-    for i in 0..8 {
-        // Pour chaque tryte de b
-        if let Some(tryte_b) = b.tryte(i) {
-            if let Tryte::Digit(val_b) = tryte_b {
-                // Convertir la valeur du tryte en valeur signée (-13 à +13)
-                let signed_val_b = (*val_b as i8) - 13;
-
-                // Si la valeur est non nulle, ajouter a décalé
-                if signed_val_b != 0 {
-                    // Créer une copie de a
-                    let temp = temp_a;
-
-                    // Multiplier par la valeur absolue
-                    let abs_val_b = signed_val_b.abs() as u8;
-                    for _ in 0..abs_val_b {
-                        let (new_result, _, _) = add_24_trits(result, temp, Trit::Z);
-                        result = new_result;
-                    }
-
-                    // Si la valeur est négative, inverser le résultat
-                    if signed_val_b < 0 {
-                        result = trit_inv_word(result);
-                        let (new_result, _, _) = add_24_trits(result, Word::one(), Trit::Z);
-                        result = new_result;
-                    }
-                }
-            }
-        }
+/// Implémente la négation d'un mot de 24 trits: 0 - a (WORD_MIN se replie sur lui-même
+/// moins un tour, comme toute soustraction qui déborde)
+pub fn neg_24_trits(a: Word) -> Word {
+    sub_24_trits(Word::zero(), a, Trit::Z).0
+}
 
-        // Décaler temp_a de 3 positions (1 tryte) vers la gauche pour la prochaine itération
-        if i < 7 {
-            // Pas besoin de décaler pour la dernière itération
-            temp_a = shl_24_trits(temp_a, Word::from_int(3));
-        }
-    }
+/// Replie une valeur entière dans la plage d'un Mot (modulo 24^8)
+fn wrap_word_value(value: i128) -> i64 {
+    ((value - WORD_MIN as i128).rem_euclid(WORD_MODULUS as i128) + WORD_MIN as i128) as i64
+}
 
-    result
+/// Implémente la multiplication de deux mots de 24 trits (8 trytes)
+/// Retourne les 24 trits de poids faible du produit
+pub fn mul_24_trits(a: Word, b: Word) -> Word {
+    mul_words(a, b).0
 }
 
 /// Implémente la division de deux mots de 24 trits (8 trytes)
-/// Retourne le quotient de la division
+/// Retourne le quotient tronqué vers zéro (zéro pour une division par zéro)
 pub fn div_24_trits(a: Word, b: Word) -> Word {
-    // Cas spéciaux
-    if b == Word::zero() {
-        // Division par zéro - retourner un mot avec tous les trits à Z
-        return Word::zero();
-    }
-
-    if a == Word::zero() {
-        // 0 divisé par n'importe quoi donne 0
-        return Word::zero();
-    }
-
-    // Algorithme de division par soustraction répétée
-    let mut quotient = Word::zero();
-    let mut remainder = a;
-
-    // Déterminer le signe du résultat
-    let a_negative = a.is_negative();
-    let b_negative = b.is_negative();
-    let result_negative = a_negative != b_negative;
-
-    // Travailler avec des valeurs absolues
-    let abs_b = if b_negative { trit_inv_word(b) } else { b };
-
-    // Division par soustraction répétée
-    while compare_24_trits(remainder, abs_b).sf == false {
-        // Tant que remainder >= abs_b
-        let (new_remainder, _, _) = sub_24_trits(remainder, abs_b, Trit::Z);
-        remainder = new_remainder;
-
-        // Incrémenter le quotient
-        let (new_quotient, _, _) = add_24_trits(quotient, Word::one(), Trit::Z);
-        quotient = new_quotient;
-    }
-
-    // Appliquer le signe au résultat si nécessaire
-    if result_negative {
-        quotient = trit_inv_word(quotient);
-        let (new_quotient, _, _) = add_24_trits(quotient, Word::one(), Trit::Z);
-        quotient = new_quotient;
-    }
-
-    quotient
+    div_words(a, b).0
 }
 
 /// Implémente le modulo de deux mots de 24 trits (8 trytes)
-/// Retourne le reste de la division
+/// Retourne le reste de la division, du signe du dividende (zéro pour une division par zéro)
 pub fn mod_24_trits(a: Word, b: Word) -> Word {
-    // Cas spéciaux
-    if b == Word::zero() {
-        // Modulo par zéro - retourner un mot avec tous les trits à Z
-        return Word::zero();
-    }
-
-    if a == Word::zero() {
-        // 0 modulo n'importe quoi donne 0
-        return Word::zero();
-    }
-
-    // Algorithme de modulo par soustraction répétée
-    // Déterminer le signe du résultat (le signe du reste est le même que celui du dividende)
-    let a_negative = a.is_negative();
-
-    // Travailler avec des valeurs absolues
-    let abs_a = if a_negative { trit_inv_word(a) } else { a };
-    let abs_b = if b.is_negative() { trit_inv_word(b) } else { b };
-
-    let mut remainder = abs_a;
-
-    // Modulo par soustraction répétée
-    while compare_24_trits(remainder, abs_b).sf == false {
-        // Tant que remainder >= abs_b
-        let (new_remainder, _, _) = sub_24_trits(remainder, abs_b, Trit::Z);
-        remainder = new_remainder;
-    }
-
-    // Appliquer le signe au résultat si nécessaire
-    if a_negative && remainder != Word::zero() {
-        remainder = trit_inv_word(remainder);
-        let (new_remainder, _, _) = add_24_trits(remainder, Word::one(), Trit::Z);
-        remainder = new_remainder;
-    }
-
-    remainder
+    div_words(a, b).1
 }
 
-/// Implémente le décalage à gauche d'un mot de 24 trits
-/// Décale les trits de a vers la gauche de la valeur spécifiée par b
-pub fn shl_24_trits(a: Word, b: Word) -> Word {
+/// Décale les trits de a de `shift` positions vers les poids forts (vers les poids faibles si
+/// négatif); les trits sortants sont perdus, les trits entrants valent Z
+fn shift_trits(a: Word, shift: i64) -> Word {
     let mut result = Word::zero();
 
-    // Convertir b en un entier non signé pour le nombre de positions à décaler
-    let mut shift_amount: i32 = 0;
-    if let Some(tryte) = b.tryte(0) {
-        if let Tryte::Digit(val) = tryte {
-            // Convertir la valeur du tryte en nombre de positions (0-26)
-            shift_amount = (*val as i32) % 24; // Limiter à 24 positions max
-        }
-    }
-
-    // Si le décalage est nul, retourner a inchangé
-    if shift_amount == 0 {
-        return a;
-    }
-
-    // Décaler les trits vers la gauche
     for i in 0..24 {
-        let src_pos = i as i32 - shift_amount;
-        if src_pos >= 0 && src_pos < 24 {
-            if let Some(tryte) = a.tryte((src_pos / 3) as usize) {
-                let trit = tryte.get_trit((src_pos % 3) as usize);
-                result.set_trit(i, trit);
-            }
-        } else {
-            result.set_trit(i, Trit::Z);
+        let src_pos = i as i64 - shift;
+        if (0..24).contains(&src_pos) {
+            result.set_trit(i, a.get_trit(src_pos as usize));
         }
     }
 
     result
 }
 
+/// Implémente le décalage à gauche d'un mot de 24 trits
+/// Décale les trits de a vers la gauche de la valeur de b (un décalage de 3 trits déplace
+/// les trytes d'un rang et multiplie par 24); une valeur négative décale vers la droite
+pub fn shl_24_trits(a: Word, b: Word) -> Word {
+    shift_trits(a, b.to_i64())
+}
+
 /// Implémente le décalage à droite d'un mot de 24 trits
-/// Décale les trits de a vers la droite de la valeur spécifiée par b
+/// Décale les trits de a vers la droite de la valeur de b (un décalage de 3 trits déplace
+/// les trytes d'un rang et perd le chiffre de poids faible); une valeur négative décale
+/// vers la gauche
 pub fn shr_24_trits(a: Word, b: Word) -> Word {
-    let mut result = Word::zero();
-
-    // Convertir b en un entier non signé pour le nombre de positions à décaler
-    let mut shift_amount: i32 = 0;
-    if let Some(tryte) = b.tryte(0) {
-        if let Tryte::Digit(val) = tryte {
-            // Convertir la valeur du tryte en nombre de positions (0-26)
-            shift_amount = (*val as i32) % 24; // Limiter à 24 positions max
-        }
-    }
-
-    // Si le décalage est nul, retourner a inchangé
-    if shift_amount == 0 {
-        return a;
-    }
-
-    // Décaler les trits vers la droite
-    for i in 0..24 {
-        let src_pos = i as i32 + shift_amount;
-        if src_pos >= 0 && src_pos < 24 {
-            if let Some(tryte) = a.tryte((src_pos / 3) as usize) {
-                let trit = tryte.get_trit((src_pos % 3) as usize);
-                result.set_trit(i, trit);
-            }
-        } else {
-            result.set_trit(i, Trit::Z);
-        }
-    }
-
-    result
+    shift_trits(a, b.to_i64().saturating_neg())
 }
 
 /// Wrapper pour l'addition de deux mots ternaires
@@ -538,14 +340,17 @@ pub fn sub_words(a: Word, b: Word, bin: bool) -> (Word, Trit) {
 /// Wrapper pour la multiplication de deux mots ternaires
 /// Prend deux mots a et b
 /// Retourne un tuple (result, overflow) où result est le mot résultat
-/// et overflow indique si un débordement s'est produit
+/// et overflow indique si le produit exact sort de la plage d'un Mot
 pub fn mul_words(a: Word, b: Word) -> (Word, bool) {
-    // Utiliser la fonction mul_24_trits existante
-    let result = mul_24_trits(a, b);
-    
-    // Pour simplifier, on suppose qu'il n'y a pas de débordement
-    // Dans une implémentation plus complète, il faudrait vérifier
-    (result, false)
+    // Un état spécial dans un opérande contamine tout le produit
+    if let Some(special) = dominant_special(a.0.into_iter().chain(b.0)) {
+        return (Word([special; 8]), false);
+    }
+
+    let product = a.to_i64() as i128 * b.to_i64() as i128;
+    let wrapped = wrap_word_value(product);
+
+    (Word::from_i64(wrapped), wrapped as i128 != product)
 }
 
 /// Wrapper pour la division de deux mots ternaires
@@ -553,14 +358,22 @@ pub fn mul_words(a: Word, b: Word) -> (Word, bool) {
 /// Retourne un tuple (result, remainder) où result est le quotient
 /// et remainder est le reste de la division
 pub fn div_words(a: Word, b: Word) -> (Word, Word) {
-    // Utiliser la fonction div_24_trits existante pour le quotient
-    let quotient = div_24_trits(a, b);
-    
-    // Calculer le reste: remainder = a - (quotient * b)
-    let product = mul_24_trits(quotient, b);
-    let (remainder, _, _) = sub_24_trits(a, product, Trit::Z);
-    
-    (quotient, remainder)
+    // Un état spécial dans un opérande contamine le quotient et le reste
+    if let Some(special) = dominant_special(a.0.into_iter().chain(b.0)) {
+        return (Word([special; 8]), Word([special; 8]));
+    }
+
+    // Division par zéro - retourner des mots avec tous les trits à Z
+    // (l'exécution lève DivisionByZero avant d'appeler l'ALU)
+    let divisor = b.to_i64();
+    if divisor == 0 {
+        return (Word::zero(), Word::zero());
+    }
+
+    // |a / b| <= |a|: seul WORD_MIN / -1 sort de la plage (qui n'est pas symétrique) et se
+    // replie comme un produit
+    let dividend = a.to_i64();
+    (Word::from_i64(dividend / divisor), Word::from_i64(dividend % divisor))
 }
 
 #[cfg(test)]
@@ -718,7 +531,7 @@ mod tests {
                     // Vérifier que la somme est correcte
                     let total = a.value() + b.value() + cin.value();
                     let expected_sum = match total % 3 {
-                        -2 => Trit::N,
+                        -2 => Trit::P, // -2 = 1 + (-1 * 3)
                         -1 => Trit::N,
                         0 => Trit::Z,
                         1 => Trit::P,
@@ -728,7 +541,7 @@ mod tests {
 
                     let expected_cout = match total {
                         -3 | -2 => Trit::N,
-                        -1 | 0 | 1 => Trit::Z,
+                        -1..=1 => Trit::Z,
                         2 | 3 => Trit::P,
                         _ => panic!("Unexpected total"),
                     };
@@ -743,6 +556,7 @@ mod tests {
                         "Failed for a={:?}, b={:?}, cin={:?}",
                         a, b, cin
                     );
+                    assert_eq!(sum.value() + 3 * cout.value(), total);
                 }
             }
         }
@@ -765,9 +579,9 @@ mod tests {
         assert_eq!(cout, Trit::Z);
 
         // Vérifier les flags
-        assert_eq!(flags.zf, false); // Résultat non nul
-        assert_eq!(flags.sf, false); // Résultat positif
-        assert_eq!(flags.xf, false); // Pas d'état spécial
+        assert!(!flags.zf); // Résultat non nul
+        assert!(!flags.sf); // Résultat positif
+        assert!(!flags.xf); // Pas d'état spécial
     }
 
     #[test]
@@ -782,14 +596,12 @@ mod tests {
         assert_eq!(cout, Trit::P);
 
         // Vérifier que le résultat a bien débordé
-        // L'addition de deux valeurs maximales (P,P,P) + (P,P,P) donne (N,P,P) ou (P,N,N) avec une retenue
-        // Vérifions simplement que le résultat n'est pas égal aux opérandes
-        for i in 0..7 {
-            assert_ne!(result.tryte(i), Some(&Tryte::Digit(23))); // Le résultat ne doit pas être (P,P,P)
+        // 10 + 10 = 20 = 24 - 4: chiffre -4 et retenue vers le tryte suivant
+        assert_eq!(result.tryte(0), Some(&Tryte::Digit(9))); // 9 = -4
+        // Les trytes suivants reçoivent la retenue: 10 + 10 + 1 = 21 = 24 - 3
+        for i in 1..8 {
+            assert_eq!(result.tryte(i), Some(&Tryte::Digit(10))); // 10 = -3
         }
-
-        // Le dernier tryte doit être différent à cause de la retenue
-        assert_eq!(result.tryte(7), Some(&Tryte::Digit(6))); // 6 = (N,P,P) = 7
     }
 
     #[test]
@@ -809,9 +621,9 @@ mod tests {
         assert_eq!(cout, Trit::Z);
 
         // Vérifier les flags
-        assert_eq!(flags.zf, true); // Résultat nul
-        assert_eq!(flags.sf, false); // Résultat non négatif
-        assert_eq!(flags.xf, false); // Pas d'état spécial
+        assert!(flags.zf); // Résultat nul
+        assert!(!flags.sf); // Résultat non négatif
+        assert!(!flags.xf); // Pas d'état spécial
     }
 
     #[test]
@@ -820,7 +632,7 @@ mod tests {
         let a = create_word([Tryte::Digit(0); 8]); // Tous les trytes sont -13 (N,N,N)
         let b = create_word([Tryte::Digit(13); 8]); // Tous les trytes sont 0 (Z,Z,Z)
 
-        let (result, cout, flags) = add_24_trits(a, b, Trit::Z);
+        let (result, _cout, flags) = add_24_trits(a, b, Trit::Z);
 
         // Vérifier que le résultat est -13 dans chaque tryte
         for i in 0..8 {
@@ -828,7 +640,7 @@ mod tests {
         }
 
         // Vérifier les flags
-        assert_eq!(flags.sf, true); // Résultat négatif
+        assert!(flags.sf); // Résultat négatif
     }
 
     #[test]
@@ -856,7 +668,7 @@ mod tests {
         assert_eq!(result.tryte(6), Some(&Tryte::Undefined)); // Undefined a priorité sur Digit
 
         // Vérifier le flag XF
-        assert_eq!(flags.xf, true); // États spéciaux présents
+        assert!(flags.xf); // États spéciaux présents
 
         // Test de priorité des états spéciaux: NaN > Null > Undefined > Digit
         let mut c = create_word([Tryte::Digit(13); 8]);
@@ -905,8 +717,8 @@ mod tests {
         assert_eq!(bout, Trit::Z);
 
         // Vérifier les flags
-        assert_eq!(flags.zf, false); // Résultat non nul
-        assert_eq!(flags.sf, false); // Résultat positif
+        assert!(!flags.zf); // Résultat non nul
+        assert!(!flags.sf); // Résultat positif
     }
 
     #[test]
@@ -926,7 +738,7 @@ mod tests {
         assert_eq!(bout, Trit::Z);
 
         // Vérifier les flags
-        assert_eq!(flags.zf, true); // Résultat nul
+        assert!(flags.zf); // Résultat nul
     }
 
     #[test]
@@ -935,7 +747,7 @@ mod tests {
         let a = create_word([Tryte::Digit(13); 8]); // Tous les trytes sont 0
         let b = create_word([Tryte::Digit(14); 8]); // Tous les trytes sont 1
 
-        let (result, bout, flags) = sub_24_trits(a, b, Trit::Z);
+        let (result, _bout, flags) = sub_24_trits(a, b, Trit::Z);
 
         // Vérifier que le résultat est -1 dans chaque tryte
         for i in 0..8 {
@@ -943,7 +755,7 @@ mod tests {
         }
 
         // Vérifier les flags
-        assert_eq!(flags.sf, true); // Résultat négatif
+        assert!(flags.sf); // Résultat négatif
     }
 
     #[test]
@@ -953,18 +765,18 @@ mod tests {
         let b = create_word([Tryte::Digit(14); 8]); // Tous les trytes sont 1
 
         let flags = compare_24_trits(a, b);
-        assert_eq!(flags.zf, false); // a != b
-        assert_eq!(flags.sf, false); // a > b
+        assert!(!flags.zf); // a != b
+        assert!(!flags.sf); // a > b
 
         // Test de comparaison: a < b
         let flags = compare_24_trits(b, a);
-        assert_eq!(flags.zf, false); // b != a
-        assert_eq!(flags.sf, true); // b < a
+        assert!(!flags.zf); // b != a
+        assert!(flags.sf); // b < a
 
         // Test de comparaison: a == b
         let flags = compare_24_trits(a, a);
-        assert_eq!(flags.zf, true); // a == a
-        assert_eq!(flags.sf, false); // a >= a
+        assert!(flags.zf); // a == a
+        assert!(!flags.sf); // a >= a
 
         // Test avec des valeurs plus complexes
         let c = create_word([
@@ -990,8 +802,8 @@ mod tests {
 
         // d > c (différence seulement dans le dernier tryte)
         let flags = compare_24_trits(c, d);
-        assert_eq!(flags.zf, false); // c != d
-        assert_eq!(flags.sf, true); // c < d
+        assert!(!flags.zf); // c != d
+        assert!(flags.sf); // c < d
 
         // Test avec des états spéciaux
         let mut e = create_word([Tryte::Digit(13); 8]);
@@ -1002,7 +814,7 @@ mod tests {
         }
 
         let flags = compare_24_trits(e, f);
-        assert_eq!(flags.xf, true); // Présence d'états spéciaux
+        assert!(flags.xf); // Présence d'états spéciaux
     }
 
     #[test]
@@ -1054,14 +866,12 @@ mod tests {
         assert_eq!(cout, Trit::P);
 
         // Vérifier que le résultat a bien débordé
-        // L'addition de deux valeurs maximales (P,P,P) + (P,P,P) donne (N,P,P) ou (P,N,N) avec une retenue
-        // Vérifions simplement que le résultat n'est pas égal aux opérandes
-        for i in 0..7 {
-            assert_ne!(result.tryte(i), Some(&Tryte::Digit(23))); // Le résultat ne doit pas être (P,P,P)
+        // 10 + 10 = 20 = 24 - 4: chiffre -4 et retenue vers le tryte suivant
+        assert_eq!(result.tryte(0), Some(&Tryte::Digit(9))); // 9 = -4
+        // Les trytes suivants reçoivent la retenue: 10 + 10 + 1 = 21 = 24 - 3
+        for i in 1..8 {
+            assert_eq!(result.tryte(i), Some(&Tryte::Digit(10))); // 10 = -3
         }
-
-        // Le dernier tryte doit être différent à cause de la retenue
-        assert_eq!(result.tryte(7), Some(&Tryte::Digit(6))); // 6 = (N,P,P) = 7
     }
 
     #[test]
@@ -1074,13 +884,13 @@ mod tests {
         let (_result, _bout, flags) = sub_24_trits(max_val, min_val, Trit::Z);
 
         // La différence devrait être très grande et positive
-        assert_eq!(flags.sf, false); // Résultat positif
+        assert!(!flags.sf); // Résultat positif
 
         // Soustraire la valeur maximale de la valeur minimale
         let (_result2, bout2, flags2) = sub_24_trits(min_val, max_val, Trit::Z);
 
         // La différence devrait être très grande et négative
-        assert_eq!(flags2.sf, true); // Résultat négatif
+        assert!(flags2.sf); // Résultat négatif
         assert_eq!(bout2, Trit::P); // Emprunt de sortie
     }
 
@@ -1105,14 +915,14 @@ mod tests {
 
         // Mettre le premier tryte à la valeur maximale
         if let Some(tryte) = c.tryte_mut(0) {
-            *tryte = Tryte::Digit(23); // 23 = (P,P,P) = 13
+            *tryte = Tryte::Digit(23); // 23 = +10, plus grand chiffre B24
         }
 
         let d = create_word([Tryte::Digit(14); 8]); // Tous 1
 
         let (result2, _, _) = add_24_trits(c, d, Trit::Z);
 
-        // Le premier tryte devrait être 14 (13+1), avec une retenue vers le deuxième tryte
+        // Le premier tryte devrait être 11 (10+1) = 24 - 13, avec une retenue vers le deuxième tryte
         assert_eq!(result2.tryte(0), Some(&Tryte::Digit(0))); // 0 = (N,N,N) = -13 (overflow)
         assert_eq!(result2.tryte(1), Some(&Tryte::Digit(15))); // 15 = (Z,P,N) = 2 (1 + retenue)
     }
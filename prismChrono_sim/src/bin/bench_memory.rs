@@ -0,0 +1,160 @@
+// src/bin/bench_memory.rs
+// Banc de mesure du stockage de la mémoire: pages allouées à la demande et trytes compacts
+// (un octet) comparés au stockage d'origine, un Vec<Tryte> de MAX_ADDRESS éléments
+//
+// À lancer en mode release: cargo run --release --bin bench_memory
+
+use prismchrono_sim::Cpu;
+use prismchrono_sim::branch_predictor::PredictorRng;
+use prismchrono_sim::core::{Address, MAX_ADDRESS, Tryte, Word, is_valid_address};
+use prismchrono_sim::memory::{Memory, STORE_PAGE_SIZE};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Stockage d'origine, pour comparaison: un tryte par élément, tout alloué à la création
+// Les vérifications sont celles de `Memory` (alignement, périphériques, limites) et les
+// accès ne sont pas intégrés à l'appelant, comme ceux de `Memory` depuis un autre crate
+struct VecMemory {
+    trytes: Vec<Tryte>,
+    devices: Vec<(Address, usize)>,
+}
+
+impl VecMemory {
+    fn new() -> Self {
+        VecMemory {
+            trytes: vec![Tryte::Undefined; MAX_ADDRESS],
+            devices: Vec::new(),
+        }
+    }
+
+    fn is_device(&self, addr: Address) -> bool {
+        self.devices.iter().any(|&(base, size)| addr >= base && addr < base + size)
+    }
+
+    #[inline(never)]
+    fn read_tryte(&self, addr: Address) -> Option<Tryte> {
+        if self.is_device(addr) || !is_valid_address(addr) {
+            return None;
+        }
+        self.trytes.get(addr).copied()
+    }
+
+    #[inline(never)]
+    fn write_tryte(&mut self, addr: Address, tryte: Tryte) -> Option<()> {
+        if self.is_device(addr) || !is_valid_address(addr) {
+            return None;
+        }
+        *self.trytes.get_mut(addr)? = tryte;
+        Some(())
+    }
+
+    #[inline(never)]
+    fn read_word(&self, addr: Address) -> Option<Word> {
+        if !addr.is_multiple_of(8) || self.is_device(addr) || !is_valid_address(addr) {
+            return None;
+        }
+        let trytes = self.trytes.get(addr..addr + 8)?;
+        Some(Word(trytes.try_into().ok()?))
+    }
+
+    #[inline(never)]
+    fn write_word(&mut self, addr: Address, word: Word) -> Option<()> {
+        if !addr.is_multiple_of(8) || self.is_device(addr) || !is_valid_address(addr) {
+            return None;
+        }
+        self.trytes.get_mut(addr..addr + 8)?.copy_from_slice(word.trytes());
+        Some(())
+    }
+}
+
+// Exécute `work` `runs` fois et retourne la durée moyenne d'une exécution
+fn measure(runs: u32, mut work: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..runs {
+        work();
+    }
+    start.elapsed() / runs
+}
+
+// Affiche une ligne du tableau de résultats
+fn report(name: &str, before: Duration, after: Duration) {
+    println!(
+        "{:<32} {:>12.3?} {:>12.3?} {:>9.1}x",
+        name,
+        before,
+        after,
+        before.as_secs_f64() / after.as_secs_f64().max(1e-12)
+    );
+}
+
+fn main() {
+    const RUNS: u32 = 100;
+    const WORKING_SET: usize = 64 * 1024; // Zone parcourue par les accès séquentiels
+    const RANDOM_ACCESSES: usize = 100_000;
+
+    println!("Stockage de la mémoire: Vec<Tryte> d'origine / pages compactes à la demande");
+    println!("{:<32} {:>12} {:>12} {:>10}", "Mesure", "Vec<Tryte>", "Pages", "Gain");
+
+    // 1. Création d'une mémoire (chaque CPU en crée une)
+    report(
+        "Création d'une mémoire",
+        measure(RUNS, || drop(black_box(VecMemory::new()))),
+        measure(RUNS, || drop(black_box(Memory::new()))),
+    );
+    report(
+        "Création d'un CPU",
+        // CPU d'origine: le CPU actuel plus l'allocation de la mémoire d'origine
+        measure(RUNS, || drop(black_box((Cpu::new(), VecMemory::new())))),
+        measure(RUNS, || drop(black_box(Cpu::new()))),
+    );
+
+    // 2. Accès Mot séquentiels (écriture puis relecture d'une zone de travail)
+    let mut vec_memory = VecMemory::new();
+    let mut memory = Memory::new();
+    let word = Word::from(-12345);
+    report(
+        "Mots séquentiels (64 Ktrytes)",
+        measure(RUNS, || {
+            for addr in (0..WORKING_SET).step_by(8) {
+                vec_memory.write_word(addr, word).unwrap();
+                black_box(vec_memory.read_word(addr).unwrap());
+            }
+        }),
+        measure(RUNS, || {
+            for addr in (0..WORKING_SET).step_by(8) {
+                memory.write_word(addr, word).unwrap();
+                black_box(memory.read_word(addr).unwrap());
+            }
+        }),
+    );
+
+    let working_pages = memory.allocated_pages();
+
+    // 3. Accès Tryte aléatoires sur tout l'espace d'adressage
+    let mut rng = PredictorRng::new(1);
+    let addresses: Vec<Address> = (0..RANDOM_ACCESSES).map(|_| rng.next_u64() as usize % MAX_ADDRESS).collect();
+    report(
+        "Trytes aléatoires (100 000)",
+        measure(RUNS, || {
+            for &addr in &addresses {
+                vec_memory.write_tryte(addr, Tryte::Digit(7)).unwrap();
+                black_box(vec_memory.read_tryte(addr ^ 1).unwrap());
+            }
+        }),
+        measure(RUNS, || {
+            for &addr in &addresses {
+                memory.write_tryte(addr, Tryte::Digit(7)).unwrap();
+                black_box(memory.read_tryte(addr ^ 1).unwrap());
+            }
+        }),
+    );
+
+    // 4. Occupation mémoire (pages: après les accès séquentiels, puis après les aléatoires)
+    println!();
+    println!("Occupation Vec<Tryte>                  : {} Ko", MAX_ADDRESS * std::mem::size_of::<Tryte>() / 1024);
+    println!("Occupation pages, zone de travail      : {} Ko", working_pages * STORE_PAGE_SIZE / 1024);
+    println!(
+        "Occupation pages, tout l'espace touché : {} Ko",
+        memory.allocated_pages() * STORE_PAGE_SIZE / 1024
+    );
+}
@@ -43,13 +43,11 @@ fn update_flags_from_word(word: &Word) -> Flags {
     }
 
     // Vérifier si le mot est négatif (SF)
-    if let Some(msb_tryte) = word.tryte(7) {
-        // Tryte de poids fort
-        if let Tryte::Digit(val) = msb_tryte {
-            // Vérifier si le tryte de poids fort est négatif
-            // Les valeurs de 0 à 12 sont négatives (N dans le trit de poids fort)
-            flags.sf = *val <= 12;
-        }
+    // Tryte de poids fort
+    if let Some(&Tryte::Digit(val)) = word.tryte(7) {
+        // Vérifier si le tryte de poids fort est négatif
+        // Les valeurs de 0 à 12 sont négatives (N dans le trit de poids fort)
+        flags.sf = val <= 12;
     }
 
     // Mettre à jour les flags
@@ -165,13 +163,13 @@ fn test_arithmetic_operations_flags() {
     println!("  Test d'addition...");
 
     // 0 + 0 = 0 (ZF=true, SF=false, XF=false)
-    let (result, _, flags) = add_24_trits(word_zero, word_zero, Trit::Z);
+    let (_, _, flags) = add_24_trits(word_zero, word_zero, Trit::Z);
     assert!(flags.zf, "ZF devrait être true pour 0 + 0");
     assert!(!flags.sf, "SF devrait être false pour 0 + 0");
     assert!(!flags.xf, "XF devrait être false pour 0 + 0");
 
     // 0 + 1 = 1 (ZF=false, SF=false, XF=false)
-    let (result, _, flags) = add_24_trits(word_zero, word_one, Trit::Z);
+    let (_, _, flags) = add_24_trits(word_zero, word_one, Trit::Z);
     assert!(!flags.zf, "ZF devrait être false pour 0 + 1");
     assert!(!flags.sf, "SF devrait être false pour 0 + 1");
     assert!(!flags.xf, "XF devrait être false pour 0 + 1");
@@ -193,13 +191,13 @@ fn test_arithmetic_operations_flags() {
     println!("  Test de soustraction...");
 
     // 0 - 0 = 0 (ZF=true, SF=false, XF=false)
-    let (result, _, flags) = sub_24_trits(word_zero, word_zero, Trit::Z);
+    let (_, _, flags) = sub_24_trits(word_zero, word_zero, Trit::Z);
     assert!(flags.zf, "ZF devrait être true pour 0 - 0");
     assert!(!flags.sf, "SF devrait être false pour 0 - 0");
     assert!(!flags.xf, "XF devrait être false pour 0 - 0");
 
     // 1 - 1 = 0 (ZF=true, SF=false, XF=false)
-    let (result, _, flags) = sub_24_trits(word_one, word_one, Trit::Z);
+    let (_, _, flags) = sub_24_trits(word_one, word_one, Trit::Z);
     assert!(flags.zf, "ZF devrait être true pour 1 - 1");
     assert!(!flags.sf, "SF devrait être false pour 1 - 1");
     assert!(!flags.xf, "XF devrait être false pour 1 - 1");
@@ -215,7 +213,7 @@ fn test_arithmetic_operations_flags() {
     assert!(!flags.xf, "XF devrait être false pour 0 - 1");
 
     // 1 - 0 = 1 (ZF=false, SF=false, XF=false)
-    let (result, _, flags) = sub_24_trits(word_one, word_zero, Trit::Z);
+    let (_, _, flags) = sub_24_trits(word_one, word_zero, Trit::Z);
     assert!(!flags.zf, "ZF devrait être false pour 1 - 0");
     assert!(!flags.sf, "SF devrait être false pour 1 - 0");
     assert!(!flags.xf, "XF devrait être false pour 1 - 0");
@@ -224,7 +222,7 @@ fn test_arithmetic_operations_flags() {
     println!("  Test avec des états spéciaux...");
 
     // Créer un mot avec un état spécial
-    let mut word_special = word_zero.clone();
+    let mut word_special = word_zero;
     if let Some(tryte) = word_special.tryte_mut(3) {
         *tryte = Tryte::NaN;
     }
@@ -300,7 +298,7 @@ fn test_compare_operations() {
     println!("  Test de comparaison avec des états spéciaux...");
 
     // Créer un mot avec un état spécial
-    let mut word_special = word_zero.clone();
+    let mut word_special = word_zero;
     if let Some(tryte) = word_special.tryte_mut(3) {
         *tryte = Tryte::NaN;
     }
@@ -10,7 +10,7 @@ fn main() {
     println!("Test des instructions de branchement conditionnel");
 
     // Créer une instance du CPU avec une mémoire de taille suffisante
-    let mut cpu = prismchrono_sim::cpu::Cpu::new();
+    let mut cpu = prismchrono_sim::Cpu::new();
 
     // Programme de test pour les instructions de branchement
     // Ce programme teste toutes les conditions de branchement
@@ -432,7 +432,7 @@ fn main() {
                         match tryte {
                             Tryte::Digit(digit) => {
                                 // Convertir le digit en valeur ternaire équilibrée (-13 à +13)
-                                let val = (*digit as i32 - 13) as i32;
+                                let val = *digit as i32 - 13;
                                 // Ajouter la contribution de ce tryte (base 27)
                                 current_pc = current_pc.wrapping_add(
                                     (val as usize).wrapping_mul(27usize.pow(i as u32)),
@@ -442,19 +442,19 @@ fn main() {
                         }
                     }
                 }
-                if current_pc % 4 == 0 && current_pc < addr {
+                if current_pc.is_multiple_of(4) && current_pc < addr {
                     let mut instr_trits = [Trit::Z; 12];
                     for i in 0..4 {
-                        if let Ok(tryte) = cpu.memory.read_tryte(current_pc + i) {
-                            if let Tryte::Digit(digit) = tryte {
-                                instr_trits[i * 3] =
-                                    Trit::from_value((digit as i8 - 13) / 9).unwrap_or(Trit::Z);
-                                instr_trits[i * 3 + 1] =
-                                    Trit::from_value(((digit as i8 - 13) % 9) / 3)
-                                        .unwrap_or(Trit::Z);
-                                instr_trits[i * 3 + 2] =
-                                    Trit::from_value((digit as i8 - 13) % 3).unwrap_or(Trit::Z);
-                            }
+                        if let Ok(tryte) = cpu.memory.read_tryte(current_pc + i)
+                            && let Tryte::Digit(digit) = tryte
+                        {
+                            instr_trits[i * 3] =
+                                Trit::from_value((digit as i8 - 13) / 9).unwrap_or(Trit::Z);
+                            instr_trits[i * 3 + 1] =
+                                Trit::from_value(((digit as i8 - 13) % 9) / 3)
+                                    .unwrap_or(Trit::Z);
+                            instr_trits[i * 3 + 2] =
+                                Trit::from_value((digit as i8 - 13) % 3).unwrap_or(Trit::Z);
                         }
                     }
 
@@ -474,8 +474,8 @@ fn main() {
     println!("\nRésultats des tests:");
     println!(
         "Test BRANCH EQ: {}",
-        if cpu.state.read_gpr(Register::R5) == Word::from_int(1)
-            && cpu.state.read_gpr(Register::R4) == Word::from_int(0)
+        if cpu.state.read_gpr(Register::R5) == Word::from(1)
+            && cpu.state.read_gpr(Register::R4) == Word::from(0)
         {
             "RÉUSSI"
         } else {
@@ -484,8 +484,8 @@ fn main() {
     );
     println!(
         "Test BRANCH NE: {}",
-        if cpu.state.read_gpr(Register::R7) == Word::from_int(1)
-            && cpu.state.read_gpr(Register::R6) == Word::from_int(0)
+        if cpu.state.read_gpr(Register::R7) == Word::from(1)
+            && cpu.state.read_gpr(Register::R6) == Word::from(0)
         {
             "RÉUSSI"
         } else {
@@ -494,7 +494,7 @@ fn main() {
     );
     println!(
         "Test BRANCH LT: {}",
-        if cpu.state.read_gpr(Register::R1) == Word::from_int(200) {
+        if cpu.state.read_gpr(Register::R1) == Word::from(200) {
             "RÉUSSI"
         } else {
             "ÉCHOUÉ"
@@ -502,7 +502,7 @@ fn main() {
     );
     println!(
         "Test BRANCH GE: {}",
-        if cpu.state.read_gpr(Register::R2) == Word::from_int(200) {
+        if cpu.state.read_gpr(Register::R2) == Word::from(200) {
             "RÉUSSI"
         } else {
             "ÉCHOUÉ"
@@ -510,7 +510,7 @@ fn main() {
     );
     println!(
         "Test BRANCH LTU: {}",
-        if cpu.state.read_gpr(Register::R1) == Word::from_int(55) {
+        if cpu.state.read_gpr(Register::R1) == Word::from(55) {
             "RÉUSSI"
         } else {
             "ÉCHOUÉ"
@@ -518,7 +518,7 @@ fn main() {
     );
     println!(
         "Test BRANCH GEU: {}",
-        if cpu.state.read_gpr(Register::R2) == Word::from_int(55) {
+        if cpu.state.read_gpr(Register::R2) == Word::from(55) {
             "RÉUSSI"
         } else {
             "ÉCHOUÉ"
@@ -526,7 +526,7 @@ fn main() {
     );
     println!(
         "Test BRANCH SPECIAL: {}",
-        if cpu.state.read_gpr(Register::R1) == Word::from_int(65) {
+        if cpu.state.read_gpr(Register::R1) == Word::from(65) {
             "RÉUSSI"
         } else {
             "ÉCHOUÉ"
@@ -534,7 +534,7 @@ fn main() {
     );
     println!(
         "Test BRANCH ALWAYS: {}",
-        if cpu.state.read_gpr(Register::R2) == Word::from_int(65) {
+        if cpu.state.read_gpr(Register::R2) == Word::from(65) {
             "RÉUSSI"
         } else {
             "ÉCHOUÉ"
@@ -546,288 +546,7 @@ fn main() {
     );
 }
 
-// Fonction simplifiée pour encoder une instruction en trits
-// Note: Cette fonction est une version simplifiée et ne couvre pas tous les cas
+// Encode une instruction en trits avec l'encodeur partagé de l'ISA
 fn encode_instruction(instr: &Instruction) -> [Trit; 12] {
-    let mut trits = [Trit::Z; 12];
-
-    match instr {
-        Instruction::AluReg { op, rs1, rs2, rd } => {
-            // Opcode ALU (format R)
-            trits[0] = Trit::N;
-            trits[1] = Trit::N;
-            trits[2] = Trit::N;
-
-            // Fonction ALU
-            match op {
-                AluOp::Add => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::N;
-                }
-                AluOp::Sub => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::Z;
-                }
-                AluOp::TritMin => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::P;
-                }
-                AluOp::TritMax => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::N;
-                }
-                AluOp::Cmp => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::P;
-                }
-                AluOp::TritInv => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::Z;
-                }
-                _ => { /* Non implémenté */ }
-            }
-
-            // Registres
-            let rd_idx = rd.to_index() as i8;
-            let rs1_idx = rs1.to_index() as i8;
-            let rs2_idx = rs2.to_index() as i8;
-
-            // Encoder rd (2 trits)
-            trits[6] = match rd_idx & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[7] = if rd_idx & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-
-            // Encoder rs1 (2 trits)
-            trits[8] = match rs1_idx & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[9] = if rs1_idx & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-
-            // Encoder rs2 (2 trits)
-            trits[10] = match rs2_idx & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[11] = if rs2_idx & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-        }
-        Instruction::AluImm { op, rs1, rd, imm } => {
-            // Opcode ALUI (format I)
-            trits[0] = Trit::N;
-            trits[1] = Trit::N;
-            trits[2] = Trit::Z;
-
-            // Fonction ALU
-            match op {
-                AluOp::Add => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::N;
-                }
-                AluOp::Sub => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::Z;
-                }
-                AluOp::TritMin => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::P;
-                }
-                AluOp::TritMax => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::N;
-                }
-                AluOp::Cmp => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::P;
-                }
-                _ => { /* Non implémenté */ }
-            }
-
-            // Registres
-            let rd_idx = rd.to_index() as i8;
-            let rs1_idx = rs1.to_index() as i8;
-
-            // Encoder rd (2 trits)
-            trits[6] = match rd_idx & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[7] = if rd_idx & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-
-            // Encoder rs1 (2 trits)
-            trits[8] = match rs1_idx & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[9] = if rs1_idx & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-
-            // Encoder imm (2 trits)
-            // Simplification: on ne prend que les 2 bits de poids faible
-            let imm_val = *imm as i8;
-            trits[10] = match imm_val & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[11] = if imm_val & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-        }
-        Instruction::Branch { rs1, cond, offset } => {
-            // Opcode BRANCH (format B)
-            trits[0] = Trit::N;
-            trits[1] = Trit::Z;
-            trits[2] = Trit::N;
-
-            // Condition
-            match cond {
-                BranchCondition::Zero => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::N;
-                }
-                BranchCondition::NonZero => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::Z;
-                }
-                BranchCondition::Negative => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::N;
-                    trits[5] = Trit::P;
-                }
-                BranchCondition::Positive => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::N;
-                }
-                BranchCondition::Overflow => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::Z;
-                }
-                BranchCondition::Carry => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::Z;
-                    trits[5] = Trit::P;
-                }
-                BranchCondition::True => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::P;
-                    trits[5] = Trit::N;
-                }
-                BranchCondition::False => {
-                    trits[3] = Trit::N;
-                    trits[4] = Trit::P;
-                    trits[5] = Trit::Z;
-                }
-            }
-
-            // Registre rs1
-            let rs1_idx = rs1.to_index() as i8;
-            trits[6] = match rs1_idx & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[7] = if rs1_idx & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-
-            // Offset (4 trits)
-            // Simplification: on ne prend que les 4 bits de poids faible
-            let offset_val = *offset;
-            trits[8] = match offset_val & 0b11 {
-                0 => Trit::N,
-                1 => Trit::Z,
-                2 => Trit::P,
-                _ => Trit::N,
-            };
-            trits[9] = if offset_val & 0b100 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-            trits[10] = if offset_val & 0b1000 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-            trits[11] = if offset_val & 0b10000 != 0 {
-                Trit::P
-            } else {
-                Trit::N
-            };
-        }
-        Instruction::Halt => {
-            // Opcode SYSTEM (format I)
-            trits[0] = Trit::P;
-            trits[1] = Trit::P;
-            trits[2] = Trit::P;
-
-            // Fonction HALT
-            trits[3] = Trit::Z;
-            trits[4] = Trit::Z;
-            trits[5] = Trit::Z;
-            trits[6] = Trit::Z;
-            trits[7] = Trit::Z;
-            trits[8] = Trit::Z;
-            trits[9] = Trit::Z;
-            trits[10] = Trit::Z;
-            trits[11] = Trit::Z;
-        }
-        _ => {
-            // Non implémenté
-        }
-    }
-
-    trits
+    prismchrono_isa::encode(instr).expect("instruction non encodable")
 }
@@ -1,8 +1,8 @@
 // src/bin/test_branch_predictor.rs
 // Programme de test pour le prédicteur de branchement ternaire avancé
 
-use prismchrono_sim::branch_predictor::{TernaryBranchPredictor, BranchPrediction, Branch3Hint};
-use prismchrono_sim::core::{Trit, Tryte, Word};
+use prismchrono_sim::branch_predictor::{TernaryBranchPredictor, BranchPrediction, PredictorRng, DEFAULT_PREDICTOR_SEED};
+use prismchrono_sim::core::Trit;
 use std::time::Instant;
 
 fn main() {
@@ -86,7 +86,7 @@ fn test_basic_prediction(predictor: &mut TernaryBranchPredictor) {
     println!("Taux de succès global: {}", global);
     println!("Taux de succès local: {}", local);
     println!("Précision globale: {:.2}%", acc * 100.0);
-    println!("");
+    println!();
 }
 
 /// Test de détection de motifs de boucle
@@ -154,7 +154,7 @@ fn test_loop_pattern_detection(predictor: &mut TernaryBranchPredictor) {
     let accuracy = (correct_predictions as f64 / total_predictions as f64) * 100.0;
     println!("\nPrécision après apprentissage: {:.2}% ({} correctes sur {})", 
              accuracy, correct_predictions, total_predictions);
-    println!("");
+    println!();
 }
 
 /// Test de fusion de branchements
@@ -263,7 +263,7 @@ fn test_branch_fusion(predictor: &mut TernaryBranchPredictor) {
     let accuracy = (correct_predictions as f64 / total_predictions as f64) * 100.0;
     println!("\nPrécision après fusion: {:.2}% ({} correctes sur {})", 
              accuracy, correct_predictions, total_predictions);
-    println!("");
+    println!();
 }
 
 /// Test avec des séquences aléatoires
@@ -277,16 +277,11 @@ fn test_random_sequences(predictor: &mut TernaryBranchPredictor) {
     // Adresse de test pour le branchement
     let branch_addr = 0x4000;
 
-    // Générer une séquence aléatoire de résultats
+    // Générer une séquence aléatoire (reproductible) de résultats
+    let mut rng = PredictorRng::new(DEFAULT_PREDICTOR_SEED);
     let mut sequence = Vec::with_capacity(100);
     for _ in 0..100 {
-        let random_value = rand::random::<u8>() % 3;
-        let trit = match random_value {
-            0 => Trit::N,
-            1 => Trit::Z,
-            _ => Trit::P,
-        };
-        sequence.push(trit);
+        sequence.push(rng.next_trit());
     }
 
     println!("Séquence aléatoire de 100 résultats générée");
@@ -331,7 +326,7 @@ fn test_random_sequences(predictor: &mut TernaryBranchPredictor) {
             } else {
                 correct_predictions.min(10)
             };
-            println!("Tests {} à {}: {} correctes", i+1, i+10.min(50), correct_in_group);
+            println!("Tests {} à {}: {} correctes", i+1, i+10, correct_in_group);
         }
 
         // Mettre à jour le prédicteur avec le résultat réel
@@ -352,7 +347,7 @@ fn test_random_sequences(predictor: &mut TernaryBranchPredictor) {
     println!("Taux de succès global: {}", global);
     println!("Taux de succès local: {}", local);
     println!("Précision globale: {:.2}%", acc * 100.0);
-    println!("");
+    println!();
 }
 
 /// Benchmark de performance
@@ -366,23 +361,18 @@ fn benchmark_performance() {
     for &size in &sizes {
         println!("\nTest avec un prédicteur de taille {}", size);
         let mut predictor = TernaryBranchPredictor::new(size);
+        let mut rng = PredictorRng::new(DEFAULT_PREDICTOR_SEED);
         
         // Générer des adresses de branchement aléatoires
         let mut branch_addresses = Vec::with_capacity(1000);
         for _ in 0..1000 {
-            branch_addresses.push(rand::random::<u32>() % 10000);
+            branch_addresses.push((rng.next_u64() % 10000) as u32);
         }
         
         // Générer des résultats aléatoires
         let mut results = Vec::with_capacity(10000);
         for _ in 0..10000 {
-            let random_value = rand::random::<u8>() % 3;
-            let trit = match random_value {
-                0 => Trit::N,
-                1 => Trit::Z,
-                _ => Trit::P,
-            };
-            results.push(trit);
+            results.push(rng.next_trit());
         }
         
         // Mesurer le temps pour 10000 prédictions et mises à jour
@@ -51,23 +51,23 @@ fn main() {
     );
 
     // Charger NOP 1
-    for i in 0..4 {
+    for (i, &tryte) in nop_trytes.iter().enumerate() {
         cpu.memory
-            .write_tryte(start_addr + i, nop_trytes[i])
+            .write_tryte(start_addr + i, tryte)
             .expect("Erreur d'écriture en mémoire");
     }
 
     // Charger NOP 2
-    for i in 0..4 {
+    for (i, &tryte) in nop_trytes.iter().enumerate() {
         cpu.memory
-            .write_tryte(start_addr + 4 + i, nop_trytes[i])
+            .write_tryte(start_addr + 4 + i, tryte)
             .expect("Erreur d'écriture en mémoire");
     }
 
     // Charger HALT
-    for i in 0..4 {
+    for (i, &tryte) in halt_trytes.iter().enumerate() {
         cpu.memory
-            .write_tryte(start_addr + 8 + i, halt_trytes[i])
+            .write_tryte(start_addr + 8 + i, tryte)
             .expect("Erreur d'écriture en mémoire");
     }
 
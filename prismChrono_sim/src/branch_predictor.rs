@@ -2,8 +2,49 @@
 // Implémentation du prédicteur de branchement ternaire avancé hybride à trois niveaux
 
 use crate::core::{Trit, Tryte, Word};
-use std::collections::HashMap;
-use rand; // Pour la fonction random utilisée dans update_choice_table
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+/// Graine par défaut du générateur pseudo-aléatoire des prédicteurs
+pub const DEFAULT_PREDICTOR_SEED: u64 = 0x5052_4953_4D43_4852;
+
+/// Générateur pseudo-aléatoire déterministe (SplitMix64)
+/// Une même graine donne toujours la même suite: les évaluations sont reproductibles
+#[derive(Debug, Clone)]
+pub struct PredictorRng {
+    state: u64,
+}
+
+impl PredictorRng {
+    /// Crée un générateur à partir d'une graine
+    pub fn new(seed: u64) -> Self {
+        PredictorRng { state: seed }
+    }
+
+    /// Retourne le prochain entier pseudo-aléatoire
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Retourne un flottant pseudo-aléatoire dans [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Retourne un trit pseudo-aléatoire équiprobable
+    pub fn next_trit(&mut self) -> Trit {
+        match self.next_u64() % 3 {
+            0 => Trit::N,
+            1 => Trit::Z,
+            _ => Trit::P,
+        }
+    }
+}
 
 /// États possibles du prédicteur de branchement ternaire
 /// Utilise un système à états multiples optimisé pour la logique ternaire
@@ -112,6 +153,8 @@ pub struct TernaryBranchPredictor {
     total_predictions: u32,
     /// Compteur de prédictions correctes
     correct_predictions: u32,
+    /// Générateur de la table de choix (apprentissage stochastique)
+    rng: PredictorRng,
 }
 
 impl BranchPredictorEntry {
@@ -169,6 +212,7 @@ impl BranchPredictorEntry {
             (BranchPredictionState::ModeratelyPositive, Trit::N) => BranchPredictionState::WeaklyPositive,
             (BranchPredictionState::StronglyPositive, Trit::N) => BranchPredictionState::ModeratelyPositive,
             (BranchPredictionState::Maybe, Trit::N) => BranchPredictionState::NeutralNegative,
+            (BranchPredictionState::Merged, Trit::N) => BranchPredictionState::NeutralNegative,
             
             // Transitions pour résultat neutre (Z)
             (BranchPredictionState::StronglyNegative, Trit::Z) => BranchPredictionState::ModeratelyNegative,
@@ -181,6 +225,7 @@ impl BranchPredictorEntry {
             (BranchPredictionState::ModeratelyPositive, Trit::Z) => BranchPredictionState::WeaklyPositive,
             (BranchPredictionState::StronglyPositive, Trit::Z) => BranchPredictionState::ModeratelyPositive,
             (BranchPredictionState::Maybe, Trit::Z) => BranchPredictionState::Neutral,
+            (BranchPredictionState::Merged, Trit::Z) => BranchPredictionState::Neutral,
             
             // Transitions pour résultat positif (P)
             (BranchPredictionState::StronglyNegative, Trit::P) => BranchPredictionState::ModeratelyNegative,
@@ -193,6 +238,7 @@ impl BranchPredictorEntry {
             (BranchPredictionState::ModeratelyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
             (BranchPredictionState::StronglyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
             (BranchPredictionState::Maybe, Trit::P) => BranchPredictionState::NeutralPositive,
+            (BranchPredictionState::Merged, Trit::P) => BranchPredictionState::NeutralPositive,
         };
 
         // Mise à jour du niveau de confiance
@@ -237,13 +283,13 @@ impl BranchPredictorEntry {
             
             // Vérifier les répétitions complètes du motif
             for r in 1..=(self.prediction_history.len() / pattern_length) - 1 {
-                for i in 0..pattern_length {
+                for (i, &expected) in potential_pattern.iter().enumerate() {
                     let idx = r * pattern_length + i;
                     if idx >= self.prediction_history.len() {
                         break;
                     }
                     
-                    if self.prediction_history[idx] != potential_pattern[i] {
+                    if self.prediction_history[idx] != expected {
                         is_repeating = false;
                         break;
                     }
@@ -331,10 +377,10 @@ impl BranchPredictorEntry {
         }
 
         // Utiliser le motif de boucle si disponible et confiant
-        if let Some(pattern) = &self.loop_pattern {
-            if pattern.pattern_confidence > 200 {
-                return pattern.sequence[pattern.current_position];
-            }
+        if let Some(pattern) = &self.loop_pattern
+            && pattern.pattern_confidence > 200
+        {
+            return pattern.sequence[pattern.current_position];
         }
 
         match self.state {
@@ -347,7 +393,7 @@ impl BranchPredictorEntry {
             BranchPredictionState::WeaklyPositive | 
             BranchPredictionState::ModeratelyPositive | 
             BranchPredictionState::StronglyPositive => Trit::P,
-            BranchPredictionState::Maybe => {
+            BranchPredictionState::Maybe | BranchPredictionState::Merged => {
                 // Pour l'état spéculatif, utiliser le niveau de confiance
                 if self.confidence > 200 {
                     match self.state {
@@ -375,6 +421,11 @@ impl BranchPredictorEntry {
 impl TernaryBranchPredictor {
     /// Crée un nouveau prédicteur de branchement ternaire hybride
     pub fn new(capacity: usize) -> Self {
+        Self::with_seed(capacity, DEFAULT_PREDICTOR_SEED)
+    }
+
+    /// Crée un prédicteur hybride dont l'apprentissage de la table de choix suit la graine donnée
+    pub fn with_seed(capacity: usize, seed: u64) -> Self {
         TernaryBranchPredictor {
             table: Vec::with_capacity(capacity),
             capacity,
@@ -388,6 +439,7 @@ impl TernaryBranchPredictor {
             per_instr_success_counter: 0,
             total_predictions: 0,
             correct_predictions: 0,
+            rng: PredictorRng::new(seed),
         }
     }
     
@@ -471,7 +523,7 @@ impl TernaryBranchPredictor {
     }
 
     /// Détecte et met à jour les motifs de boucle pour une instruction
-    fn update_loop_pattern(&mut self, entry: &mut BranchPredictorEntry, actual_result: Trit) {
+    fn update_loop_pattern(entry: &mut BranchPredictorEntry, actual_result: Trit) {
         if let Some(pattern) = &mut entry.loop_pattern {
             // Vérifier si le résultat actuel correspond au motif attendu
             if pattern.sequence[pattern.current_position] == actual_result {
@@ -489,8 +541,7 @@ impl TernaryBranchPredictor {
             }
         } else {
             // Tenter de détecter un nouveau motif de boucle
-            let mut sequence = Vec::new();
-            sequence.push(actual_result);
+            let sequence = vec![actual_result];
             entry.loop_pattern = Some(LoopPattern {
                 iterations: 1,
                 sequence,
@@ -525,7 +576,9 @@ impl TernaryBranchPredictor {
         let choice = self.choice_table.get(&branch_address).cloned().unwrap_or(Trit::Z);
         
         // 5. Sélectionner la prédiction finale en fonction de la table de choix
-        let final_prediction = match choice {
+        
+        
+        match choice {
             Trit::N => {
                 // Favoriser la prédiction par instruction
                 if per_instr_prediction == BranchPrediction::Speculative {
@@ -553,9 +606,7 @@ impl TernaryBranchPredictor {
                     local_prediction
                 }
             },
-        };
-        
-        final_prediction
+        }
     }
     
     /// Prédit le résultat d'un branchement en utilisant l'historique spécifique à cette instruction
@@ -638,7 +689,7 @@ impl TernaryBranchPredictor {
                     BranchPredictionState::WeaklyPositive => BranchPrediction::Positive,
                     BranchPredictionState::ModeratelyPositive => BranchPrediction::Positive,
                     BranchPredictionState::StronglyPositive => BranchPrediction::Positive,
-                    BranchPredictionState::Maybe => BranchPrediction::Speculative,
+                    BranchPredictionState::Maybe | BranchPredictionState::Merged => BranchPrediction::Speculative,
                 }
             } else {
                 // Si pas d'entrée dans la table, utiliser une prédiction par défaut
@@ -684,10 +735,10 @@ impl TernaryBranchPredictor {
         if let Some(entry_idx) = self.table.iter().position(|e| e.address == branch_address) {
             // Mettre à jour les motifs de boucle pour cette entrée
             let entry = &mut self.table[entry_idx];
-            self.update_loop_pattern(entry, actual_result);
+            Self::update_loop_pattern(entry, actual_result);
             
             // Optimiser les motifs de boucle périodiquement
-            if entry.usage_counter % 10 == 0 {
+            if entry.usage_counter.is_multiple_of(10) {
                 entry.optimize_loop_pattern();
             }
             
@@ -713,7 +764,7 @@ impl TernaryBranchPredictor {
         }
         
         // 6. Analyse statistique périodique pour optimiser le prédicteur
-        if self.total_predictions % 1000 == 0 {
+        if self.total_predictions.is_multiple_of(1000) {
             // Calculer les taux de succès relatifs
             let per_instr_rate = self.per_instr_success_counter as f64 / self.total_predictions as f64;
             let global_rate = self.global_success_counter as f64 / self.total_predictions as f64;
@@ -762,6 +813,7 @@ impl TernaryBranchPredictor {
                     (BranchPredictionState::ModeratelyPositive, Trit::N) => BranchPredictionState::WeaklyPositive,
                     (BranchPredictionState::StronglyPositive, Trit::N) => BranchPredictionState::ModeratelyPositive,
                     (BranchPredictionState::Maybe, Trit::N) => BranchPredictionState::NeutralNegative,
+                    (BranchPredictionState::Merged, Trit::N) => BranchPredictionState::NeutralNegative,
                     
                     // Transitions pour résultat neutre (Z)
                     (BranchPredictionState::StronglyNegative, Trit::Z) => BranchPredictionState::ModeratelyNegative,
@@ -774,6 +826,7 @@ impl TernaryBranchPredictor {
                     (BranchPredictionState::ModeratelyPositive, Trit::Z) => BranchPredictionState::WeaklyPositive,
                     (BranchPredictionState::StronglyPositive, Trit::Z) => BranchPredictionState::ModeratelyPositive,
                     (BranchPredictionState::Maybe, Trit::Z) => BranchPredictionState::Neutral,
+                    (BranchPredictionState::Merged, Trit::Z) => BranchPredictionState::Neutral,
                     
                     // Transitions pour résultat positif (P)
                     (BranchPredictionState::StronglyNegative, Trit::P) => BranchPredictionState::ModeratelyNegative,
@@ -786,6 +839,7 @@ impl TernaryBranchPredictor {
                     (BranchPredictionState::ModeratelyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
                     (BranchPredictionState::StronglyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
                     (BranchPredictionState::Maybe, Trit::P) => BranchPredictionState::NeutralPositive,
+                    (BranchPredictionState::Merged, Trit::P) => BranchPredictionState::NeutralPositive,
                 };
                 
                 // Incrémenter le compteur d'utilisation
@@ -843,8 +897,11 @@ impl TernaryBranchPredictor {
             (BranchPredictionState::StronglyPositive, _) => BranchPredictionState::ModeratelyPositive,
             
             (BranchPredictionState::Maybe, Trit::N) => BranchPredictionState::WeaklyNegative,
+            (BranchPredictionState::Merged, Trit::N) => BranchPredictionState::WeaklyNegative,
             (BranchPredictionState::Maybe, Trit::Z) => BranchPredictionState::Neutral,
+            (BranchPredictionState::Merged, Trit::Z) => BranchPredictionState::Neutral,
             (BranchPredictionState::Maybe, Trit::P) => BranchPredictionState::WeaklyPositive,
+            (BranchPredictionState::Merged, Trit::P) => BranchPredictionState::WeaklyPositive,
         };
         
         *state = new_state;
@@ -900,15 +957,18 @@ impl TernaryBranchPredictor {
             (BranchPredictionState::StronglyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
             
             (BranchPredictionState::Maybe, Trit::N) => BranchPredictionState::NeutralNegative,
+            (BranchPredictionState::Merged, Trit::N) => BranchPredictionState::NeutralNegative,
             (BranchPredictionState::Maybe, Trit::Z) => BranchPredictionState::Neutral,
+            (BranchPredictionState::Merged, Trit::Z) => BranchPredictionState::Neutral,
             (BranchPredictionState::Maybe, Trit::P) => BranchPredictionState::NeutralPositive,
+            (BranchPredictionState::Merged, Trit::P) => BranchPredictionState::NeutralPositive,
         };
 
         // Mettre à jour l'état
         *self.local_pattern_table.entry(history_value).or_insert(BranchPredictionState::Neutral) = new_state;
         
         // Calculer la nouvelle valeur d'historique local
-        let new_history = (history_value << 2) & 0xFFFFFFFF;
+        let new_history = history_value << 2;
         let history_bits = match actual_result {
             Trit::N => 0b00,
             Trit::Z => 0b01,
@@ -952,11 +1012,11 @@ impl TernaryBranchPredictor {
             },
             (Trit::N, _, s2, s3) if s2 > s3 => {
                 // Passer au prédicteur global s'il est meilleur
-                if rand::random::<f32>() < learning_rate { Trit::Z } else { Trit::N }
+                if self.rng.next_f32() < learning_rate { Trit::Z } else { Trit::N }
             },
             (Trit::N, _, _, _) => {
                 // Passer au prédicteur local s'il est meilleur
-                if rand::random::<f32>() < learning_rate { Trit::Z } else { Trit::N }
+                if self.rng.next_f32() < learning_rate { Trit::Z } else { Trit::N }
             },
             
             // Si le choix actuel est Z (favoriser prédicteur global)
@@ -966,11 +1026,11 @@ impl TernaryBranchPredictor {
             },
             (Trit::Z, s1, _, s3) if s1 > s3 => {
                 // Passer au prédicteur par instruction s'il est meilleur
-                if rand::random::<f32>() < learning_rate { Trit::N } else { Trit::Z }
+                if self.rng.next_f32() < learning_rate { Trit::N } else { Trit::Z }
             },
             (Trit::Z, _, _, _) => {
                 // Passer au prédicteur local s'il est meilleur
-                if rand::random::<f32>() < learning_rate { Trit::P } else { Trit::Z }
+                if self.rng.next_f32() < learning_rate { Trit::P } else { Trit::Z }
             },
             
             // Si le choix actuel est P (favoriser prédicteur local)
@@ -980,11 +1040,11 @@ impl TernaryBranchPredictor {
             },
             (Trit::P, s1, s2, _) if s1 > s2 => {
                 // Passer au prédicteur par instruction s'il est meilleur
-                if rand::random::<f32>() < learning_rate { Trit::N } else { Trit::P }
+                if self.rng.next_f32() < learning_rate { Trit::N } else { Trit::P }
             },
             (Trit::P, _, _, _) => {
                 // Passer au prédicteur global s'il est meilleur
-                if rand::random::<f32>() < learning_rate { Trit::Z } else { Trit::P }
+                if self.rng.next_f32() < learning_rate { Trit::Z } else { Trit::P }
             },
         };
         
@@ -993,47 +1053,6 @@ impl TernaryBranchPredictor {
         if global_correct { self.global_success_counter += 1; }
         if local_correct { self.local_success_counter += 1; }
     }
-    
-    /// Fonction de transition d'état générique pour les compteurs à saturation ternaires
-    fn transition_state(&self, current_state: BranchPredictionState, result: Trit) -> BranchPredictionState {
-        match (current_state, result) {
-            // Transitions pour résultat négatif (N)
-            (BranchPredictionState::StronglyNegative, Trit::N) => BranchPredictionState::StronglyNegative,
-            (BranchPredictionState::ModeratelyNegative, Trit::N) => BranchPredictionState::StronglyNegative,
-            (BranchPredictionState::WeaklyNegative, Trit::N) => BranchPredictionState::ModeratelyNegative,
-            (BranchPredictionState::NeutralNegative, Trit::N) => BranchPredictionState::WeaklyNegative,
-            (BranchPredictionState::Neutral, Trit::N) => BranchPredictionState::NeutralNegative,
-            (BranchPredictionState::NeutralPositive, Trit::N) => BranchPredictionState::Neutral,
-            (BranchPredictionState::WeaklyPositive, Trit::N) => BranchPredictionState::NeutralPositive,
-            (BranchPredictionState::ModeratelyPositive, Trit::N) => BranchPredictionState::WeaklyPositive,
-            (BranchPredictionState::StronglyPositive, Trit::N) => BranchPredictionState::ModeratelyPositive,
-            (BranchPredictionState::Maybe, Trit::N) => BranchPredictionState::NeutralNegative,
-            
-            // Transitions pour résultat neutre (Z)
-            (BranchPredictionState::StronglyNegative, Trit::Z) => BranchPredictionState::ModeratelyNegative,
-            (BranchPredictionState::ModeratelyNegative, Trit::Z) => BranchPredictionState::WeaklyNegative,
-            (BranchPredictionState::WeaklyNegative, Trit::Z) => BranchPredictionState::NeutralNegative,
-            (BranchPredictionState::NeutralNegative, Trit::Z) => BranchPredictionState::Neutral,
-            (BranchPredictionState::Neutral, Trit::Z) => BranchPredictionState::Neutral,
-            (BranchPredictionState::NeutralPositive, Trit::Z) => BranchPredictionState::Neutral,
-            (BranchPredictionState::WeaklyPositive, Trit::Z) => BranchPredictionState::NeutralPositive,
-            (BranchPredictionState::ModeratelyPositive, Trit::Z) => BranchPredictionState::WeaklyPositive,
-            (BranchPredictionState::StronglyPositive, Trit::Z) => BranchPredictionState::ModeratelyPositive,
-            (BranchPredictionState::Maybe, Trit::Z) => BranchPredictionState::Neutral,
-            
-            // Transitions pour résultat positif (P)
-            (BranchPredictionState::StronglyNegative, Trit::P) => BranchPredictionState::ModeratelyNegative,
-            (BranchPredictionState::ModeratelyNegative, Trit::P) => BranchPredictionState::WeaklyNegative,
-            (BranchPredictionState::WeaklyNegative, Trit::P) => BranchPredictionState::NeutralNegative,
-            (BranchPredictionState::NeutralNegative, Trit::P) => BranchPredictionState::Neutral,
-            (BranchPredictionState::Neutral, Trit::P) => BranchPredictionState::NeutralPositive,
-            (BranchPredictionState::NeutralPositive, Trit::P) => BranchPredictionState::WeaklyPositive,
-            (BranchPredictionState::WeaklyPositive, Trit::P) => BranchPredictionState::ModeratelyPositive,
-            (BranchPredictionState::ModeratelyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
-            (BranchPredictionState::StronglyPositive, Trit::P) => BranchPredictionState::StronglyPositive,
-            (BranchPredictionState::Maybe, Trit::P) => BranchPredictionState::NeutralPositive,
-        }
-    }
 
     /// Ajoute une nouvelle entrée dans la table de prédiction
     fn add_entry(&mut self, branch_address: u32, actual_result: Trit) {
@@ -1139,6 +1158,291 @@ impl TernaryBranchPredictor {
     }
 }
 
+/// Interface commune des prédicteurs de branchement, pour les comparer sur une même exécution
+/// L'issue d'un branchement est un trit: la voie prise par un BRANCH3 (signe du registre),
+/// P (pris) ou Z (non pris) pour un branchement conditionnel binaire
+pub trait BranchPredictor {
+    /// Nom du prédicteur (rapports)
+    fn name(&self) -> &'static str;
+
+    /// Prédit l'issue du branchement situé à `pc`
+    fn predict(&mut self, pc: u32) -> Trit;
+
+    /// Met à jour le prédicteur avec l'issue réelle du branchement situé à `pc`
+    fn update(&mut self, pc: u32, outcome: Trit);
+}
+
+impl BranchPredictor for TernaryBranchPredictor {
+    fn name(&self) -> &'static str {
+        "hybride"
+    }
+
+    fn predict(&mut self, pc: u32) -> Trit {
+        // Une prédiction spéculative suit la voie zéro (instruction suivante)
+        match TernaryBranchPredictor::predict(self, pc) {
+            BranchPrediction::Negative => Trit::N,
+            BranchPrediction::Positive => Trit::P,
+            BranchPrediction::Neutral | BranchPrediction::Speculative => Trit::Z,
+        }
+    }
+
+    fn update(&mut self, pc: u32, outcome: Trit) {
+        TernaryBranchPredictor::update(self, pc, outcome);
+    }
+}
+
+/// Prédicteur statique: prédit toujours la même issue
+#[derive(Debug, Clone)]
+pub struct StaticPredictor {
+    outcome: Trit,
+}
+
+impl StaticPredictor {
+    /// Crée un prédicteur qui prédit toujours `outcome` (Z: non pris)
+    pub fn new(outcome: Trit) -> Self {
+        StaticPredictor { outcome }
+    }
+}
+
+impl BranchPredictor for StaticPredictor {
+    fn name(&self) -> &'static str {
+        "statique"
+    }
+
+    fn predict(&mut self, _pc: u32) -> Trit {
+        self.outcome
+    }
+
+    fn update(&mut self, _pc: u32, _outcome: Trit) {}
+}
+
+/// Compteur ternaire à hystérésis: la prédiction ne change qu'après deux échecs consécutifs
+/// (équivalent au compteur saturant 2 bits pour les branchements binaires)
+#[derive(Debug, Clone, Copy)]
+struct TernaryCounter {
+    prediction: Trit,
+    strong: bool,
+}
+
+impl TernaryCounter {
+    const INITIAL: TernaryCounter = TernaryCounter { prediction: Trit::Z, strong: false };
+
+    fn update(&mut self, outcome: Trit) {
+        if outcome == self.prediction {
+            self.strong = true;
+        } else if self.strong {
+            self.strong = false;
+        } else {
+            self.prediction = outcome;
+        }
+    }
+}
+
+/// Nombre d'entrées par défaut des tables bimodale et gshare
+pub const PREDICTOR_TABLE_ENTRIES: usize = 729;
+
+/// Longueur par défaut de l'historique global du gshare (en branchements)
+pub const GSHARE_HISTORY_LENGTH: u32 = 6;
+
+/// Prédicteur bimodal: un compteur par branchement, indexé par l'adresse
+#[derive(Debug, Clone)]
+pub struct BimodalPredictor {
+    counters: Vec<TernaryCounter>,
+}
+
+impl BimodalPredictor {
+    /// Crée un prédicteur bimodal de `entries` compteurs
+    pub fn new(entries: usize) -> Self {
+        BimodalPredictor { counters: vec![TernaryCounter::INITIAL; entries.max(1)] }
+    }
+
+    fn index(&self, pc: u32) -> usize {
+        (pc / 4) as usize % self.counters.len()
+    }
+}
+
+impl BranchPredictor for BimodalPredictor {
+    fn name(&self) -> &'static str {
+        "bimodal"
+    }
+
+    fn predict(&mut self, pc: u32) -> Trit {
+        self.counters[self.index(pc)].prediction
+    }
+
+    fn update(&mut self, pc: u32, outcome: Trit) {
+        let index = self.index(pc);
+        self.counters[index].update(outcome);
+    }
+}
+
+/// Prédicteur gshare: l'adresse est combinée à l'historique global des issues,
+/// mémorisé comme un nombre en base 3 des `history_length` dernières issues
+#[derive(Debug, Clone)]
+pub struct GsharePredictor {
+    counters: Vec<TernaryCounter>,
+    history: u64,
+    history_modulus: u64,
+}
+
+impl GsharePredictor {
+    /// Crée un prédicteur gshare de `entries` compteurs et d'historique `history_length`
+    pub fn new(entries: usize, history_length: u32) -> Self {
+        GsharePredictor {
+            counters: vec![TernaryCounter::INITIAL; entries.max(1)],
+            history: 0,
+            history_modulus: 3u64.pow(history_length),
+        }
+    }
+
+    fn index(&self, pc: u32) -> usize {
+        ((pc / 4) as u64 ^ self.history) as usize % self.counters.len()
+    }
+}
+
+impl BranchPredictor for GsharePredictor {
+    fn name(&self) -> &'static str {
+        "gshare"
+    }
+
+    fn predict(&mut self, pc: u32) -> Trit {
+        self.counters[self.index(pc)].prediction
+    }
+
+    fn update(&mut self, pc: u32, outcome: Trit) {
+        let index = self.index(pc);
+        self.counters[index].update(outcome);
+        let digit = (outcome.value() + 1) as u64;
+        self.history = (self.history * 3 + digit) % self.history_modulus;
+    }
+}
+
+/// Prédicteurs disponibles (sélection en ligne de commande)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredictorKind {
+    Static,
+    Bimodal,
+    Gshare,
+    Hybrid,
+}
+
+impl PredictorKind {
+    /// Construit le prédicteur avec ses paramètres par défaut
+    /// La graine n'influence que le prédicteur hybride (apprentissage stochastique)
+    pub fn build(self, seed: u64) -> Box<dyn BranchPredictor> {
+        match self {
+            PredictorKind::Static => Box::new(StaticPredictor::new(Trit::Z)),
+            PredictorKind::Bimodal => Box::new(BimodalPredictor::new(PREDICTOR_TABLE_ENTRIES)),
+            PredictorKind::Gshare => {
+                Box::new(GsharePredictor::new(PREDICTOR_TABLE_ENTRIES, GSHARE_HISTORY_LENGTH))
+            }
+            PredictorKind::Hybrid => Box::new(TernaryBranchPredictor::with_seed(64, seed)),
+        }
+    }
+}
+
+impl FromStr for PredictorKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "static" | "statique" => Ok(PredictorKind::Static),
+            "bimodal" => Ok(PredictorKind::Bimodal),
+            "gshare" => Ok(PredictorKind::Gshare),
+            "hybrid" | "hybride" => Ok(PredictorKind::Hybrid),
+            _ => Err(format!(
+                "Prédicteur inconnu '{}' (attendu: static, bimodal, gshare ou hybrid)",
+                s
+            )),
+        }
+    }
+}
+
+/// Statistiques de prédiction d'un branchement (ou de l'ensemble des branchements)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BranchSiteStats {
+    /// Exécutions du branchement
+    pub executed: u64,
+    /// Prédictions correctes
+    pub correct: u64,
+    /// Exécutions qui ont quitté la voie zéro (branchement pris)
+    pub taken: u64,
+}
+
+impl BranchSiteStats {
+    /// Taux de prédictions correctes (0 si jamais exécuté)
+    pub fn accuracy(&self) -> f64 {
+        if self.executed == 0 {
+            0.0
+        } else {
+            self.correct as f64 / self.executed as f64
+        }
+    }
+}
+
+impl fmt::Display for BranchSiteStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} ({:.1}%), pris {}",
+            self.correct,
+            self.executed,
+            self.accuracy() * 100.0,
+            self.taken
+        )
+    }
+}
+
+/// Évalue un prédicteur sur les branchements réellement exécutés par le CPU
+/// Chaque branchement est prédit puis mis à jour; la précision est tenue par adresse
+pub struct BranchProfiler {
+    predictor: Box<dyn BranchPredictor>,
+    sites: BTreeMap<u32, BranchSiteStats>,
+}
+
+impl BranchProfiler {
+    /// Crée un profileur autour d'un prédicteur
+    pub fn new(predictor: Box<dyn BranchPredictor>) -> Self {
+        BranchProfiler { predictor, sites: BTreeMap::new() }
+    }
+
+    /// Nom du prédicteur évalué
+    pub fn name(&self) -> &'static str {
+        self.predictor.name()
+    }
+
+    /// Prédit le branchement à `pc`, compare à l'issue réelle et met à jour le prédicteur
+    /// Retourne la prédiction
+    pub fn observe(&mut self, pc: u32, outcome: Trit) -> Trit {
+        let prediction = self.predictor.predict(pc);
+        self.predictor.update(pc, outcome);
+
+        let site = self.sites.entry(pc).or_default();
+        site.executed += 1;
+        if prediction == outcome {
+            site.correct += 1;
+        }
+        if outcome != Trit::Z {
+            site.taken += 1;
+        }
+        prediction
+    }
+
+    /// Statistiques par adresse de branchement, dans l'ordre des adresses
+    pub fn sites(&self) -> &BTreeMap<u32, BranchSiteStats> {
+        &self.sites
+    }
+
+    /// Statistiques cumulées sur tous les branchements
+    pub fn totals(&self) -> BranchSiteStats {
+        self.sites.values().fold(BranchSiteStats::default(), |total, site| BranchSiteStats {
+            executed: total.executed + site.executed,
+            correct: total.correct + site.correct,
+            taken: total.taken + site.taken,
+        })
+    }
+}
+
 /// Instruction de branchement ternaire avec indice de prédiction
 pub struct Branch3Hint {
     /// Registre source contenant la condition
@@ -1219,4 +1523,59 @@ pub fn execute_branch3_hint(
     // Retourner le PC réel (dans un pipeline réel, nous aurions besoin de gérer
     // les mauvaises prédictions et les vidages de pipeline ici)
     actual_pc
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Nombre de prédictions correctes sur `rounds` répétitions d'une séquence d'issues
+    fn correct(predictor: &mut dyn BranchPredictor, pattern: &[Trit], rounds: usize) -> usize {
+        let mut correct = 0;
+        for _ in 0..rounds {
+            for &outcome in pattern {
+                if predictor.predict(0x40) == outcome {
+                    correct += 1;
+                }
+                predictor.update(0x40, outcome);
+            }
+        }
+        correct
+    }
+
+    #[test]
+    fn test_predictors_learn_patterns() {
+        // Boucle de 4 itérations: le bimodal ne se trompe qu'à la sortie de boucle
+        let pattern = [Trit::P, Trit::P, Trit::P, Trit::Z];
+        assert_eq!(correct(&mut StaticPredictor::new(Trit::Z), &pattern, 10), 10);
+        assert!(correct(&mut BimodalPredictor::new(PREDICTOR_TABLE_ENTRIES), &pattern, 10) >= 28);
+
+        // Alternance N/P: le compteur bimodal oscille, seul l'historique global la capture
+        let pattern = [Trit::N, Trit::P];
+        let bimodal = correct(&mut BimodalPredictor::new(PREDICTOR_TABLE_ENTRIES), &pattern, 20);
+        let gshare = correct(
+            &mut GsharePredictor::new(PREDICTOR_TABLE_ENTRIES, GSHARE_HISTORY_LENGTH),
+            &pattern,
+            20,
+        );
+        assert_eq!(bimodal, 0);
+        assert!(gshare >= 30); // erreurs limitées au remplissage de l'historique
+    }
+
+    #[test]
+    fn test_seeded_predictors_are_reproducible() {
+        let mut rng = PredictorRng::new(7);
+        let outcomes: Vec<(u32, Trit)> = (0..2000).map(|i| ((i % 13) * 4, rng.next_trit())).collect();
+
+        let run = |seed: u64| {
+            let mut profiler = BranchProfiler::new(PredictorKind::Hybrid.build(seed));
+            let predictions: Vec<Trit> = outcomes.iter().map(|&(pc, outcome)| profiler.observe(pc, outcome)).collect();
+            (predictions, profiler.totals())
+        };
+        assert_eq!(run(42), run(42));
+
+        let (_, totals) = run(42);
+        assert_eq!(totals.executed, 2000);
+        assert_eq!(PredictorKind::from_str("gshare"), Ok(PredictorKind::Gshare));
+        assert!(PredictorKind::from_str("oracle").is_err());
+    }
+}
@@ -1,633 +1,560 @@
 // src/cache.rs
 // Implémentation du cache prédictif ternaire
+//
+// Modèle temporel des caches L1 d'instructions et de données, placés entre le processeur
+// (fetch, CpuState::read_word/write_word) et la mémoire. Le cache ne conserve que les
+// étiquettes des lignes: les données restent dans `Memory`, toujours à jour pour la TVPU,
+// le débogueur et les périphériques. La politique d'écriture et le préchargement ne
+// changent que le coût des accès (cycles d'attente) et les statistiques.
+//
+// Adresses physiques: ligne = adresse / (8 * mots par ligne), ensemble = ligne mod ensembles,
+// étiquette = ligne / ensembles. Remplacement LRU dans l'ensemble.
+//
+// Coût d'un accès, en cycles d'attente ajoutés à ceux de l'instruction:
+//   - succès ou ligne trouvée dans le tampon de préchargement: 0
+//   - échec: `miss_penalty` (transfert d'une ligne), plus `miss_penalty` si la victime est modifiée
+//   - écriture en write-through: un Mot transmis à la mémoire, miss_penalty / mots par ligne
+//     (au moins 1); un échec en écriture n'alloue pas de ligne
+//
+// Le prédicteur observe les lignes demandées hors du cache: un pas répété entre deux
+// échecs donne une prédiction Probable, un premier pas une prédiction Incertaine.
+// La ligne prédite est chargée dans le tampon de préchargement sans coût d'attente.
 
-use crate::core::{Trit, Tryte, Word};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::str::FromStr;
 
-/// Taille d'une ligne de cache en mots
-const CACHE_LINE_SIZE: usize = 4;
+use crate::core::Address;
+use crate::memory::Memory;
+
+/// Taille d'un Mot en trytes (unité d'une ligne de cache)
+const WORD_TRYTES: Address = 8;
 
 /// Niveaux de confiance pour la prédiction d'accès
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AccessConfidence {
-    /// Accès probable (haute confiance)
-    Probable,
-    /// Accès incertain (confiance moyenne)
-    Uncertain,
     /// Accès improbable (faible confiance)
     Improbable,
+    /// Accès incertain (confiance moyenne)
+    Uncertain,
+    /// Accès probable (haute confiance)
+    Probable,
+}
+
+/// Politique d'écriture du cache de données
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WritePolicy {
+    /// Écriture dans le cache seul, la ligne modifiée est recopiée à son éviction
+    WriteBack,
+    /// Écriture transmise à la mémoire à chaque stockage, sans allocation sur échec
+    WriteThrough,
+}
+
+impl FromStr for WritePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "write-back" | "wb" => Ok(WritePolicy::WriteBack),
+            "write-through" | "wt" => Ok(WritePolicy::WriteThrough),
+            _ => Err(format!("Politique d'écriture inconnue: {} (write-back ou write-through)", s)),
+        }
+    }
+}
+
+/// Nature d'un accès au cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheAccess {
+    Read,
+    Write,
+}
+
+/// Géométrie et coûts d'un cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// Nombre d'ensembles
+    pub sets: usize,
+    /// Nombre de voies (associativité)
+    pub ways: usize,
+    /// Taille d'une ligne en Mots
+    pub line_words: usize,
+    /// Nombre d'entrées du tampon de préchargement (0: pas de préchargement)
+    pub prefetch_entries: usize,
+    /// Politique d'écriture
+    pub policy: WritePolicy,
+    /// Cycles d'attente pour transférer une ligne depuis ou vers la mémoire
+    pub miss_penalty: u64,
+}
+
+impl Default for CacheConfig {
+    /// 16 ensembles de 2 voies, lignes de 4 Mots: 128 Mots (1024 trytes)
+    fn default() -> Self {
+        CacheConfig {
+            sets: 16,
+            ways: 2,
+            line_words: 4,
+            prefetch_entries: 4,
+            policy: WritePolicy::WriteBack,
+            miss_penalty: 10,
+        }
+    }
+}
+
+impl FromStr for CacheConfig {
+    type Err = String;
+
+    /// Lit une géométrie `ensembles:voies:mots[:préchargement]`, les coûts restant par défaut
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s
+            .split(':')
+            .map(|field| field.trim().parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Géométrie de cache invalide '{}': {}", s, e))?;
+        let mut config = CacheConfig::default();
+        match fields.as_slice() {
+            [sets, ways, line_words] => {
+                (config.sets, config.ways, config.line_words) = (*sets, *ways, *line_words);
+            }
+            [sets, ways, line_words, prefetch] => {
+                (config.sets, config.ways, config.line_words) = (*sets, *ways, *line_words);
+                config.prefetch_entries = *prefetch;
+            }
+            _ => {
+                return Err(format!(
+                    "Géométrie de cache invalide '{}' (attendu ensembles:voies:mots[:préchargement])",
+                    s
+                ));
+            }
+        }
+        if config.sets == 0 || config.ways == 0 || config.line_words == 0 {
+            return Err(format!("Géométrie de cache invalide '{}': valeurs nulles", s));
+        }
+        Ok(config)
+    }
+}
+
+/// Compteurs d'un cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Accès en lecture (fetch compris)
+    pub reads: u64,
+    /// Accès en écriture
+    pub writes: u64,
+    /// Accès servis par le cache
+    pub hits: u64,
+    /// Accès servis par la mémoire (y compris ceux trouvés dans le tampon de préchargement)
+    pub misses: u64,
+    /// Lignes chargées par le prédicteur
+    pub prefetches: u64,
+    /// Échecs évités grâce au tampon de préchargement
+    pub prefetch_hits: u64,
+    /// Lignes modifiées recopiées en mémoire
+    pub writebacks: u64,
+    /// Cycles d'attente imputés au processeur
+    pub stall_cycles: u64,
+}
+
+impl CacheStats {
+    /// Taux de succès (0 si aucun accès)
+    pub fn hit_rate(&self) -> f64 {
+        let accesses = self.hits + self.misses;
+        if accesses == 0 {
+            0.0
+        } else {
+            self.hits as f64 / accesses as f64
+        }
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} ({:.1}%), préchargements {}/{}, write-backs {}",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.prefetch_hits,
+            self.prefetches,
+            self.writebacks
+        )
+    }
 }
 
-/// Structure d'une ligne de cache
+/// Structure d'une ligne de cache (étiquette et état, les données restent en mémoire)
+#[derive(Debug, Clone, Copy, Default)]
 pub struct CacheLine {
     /// Tag de la ligne
-    tag: u32,
-    /// Données de la ligne
-    data: [Word; CACHE_LINE_SIZE],
+    tag: Address,
     /// Indique si la ligne est valide
     valid: bool,
     /// Indique si la ligne est modifiée (dirty)
     dirty: bool,
-    /// Compteur d'utilisation pour la politique de remplacement
-    usage_counter: u32,
-    /// Niveau de confiance pour le préchargement
-    confidence: AccessConfidence,
-    /// Historique des accès (pour la prédiction)
-    access_history: u32,
-}
-
-impl CacheLine {
-    /// Crée une nouvelle ligne de cache vide
-    pub fn new() -> Self {
-        CacheLine {
-            tag: 0,
-            data: [Word::default_zero(); CACHE_LINE_SIZE],
-            valid: false,
-            dirty: false,
-            usage_counter: 0,
-            confidence: AccessConfidence::Uncertain,
-            access_history: 0,
-        }
-    }
+    /// Date du dernier accès pour la politique de remplacement
+    last_used: u64,
 }
 
 /// Structure d'un ensemble de cache
 pub struct CacheSet {
     /// Lignes de l'ensemble
     lines: Vec<CacheLine>,
-    /// Nombre de voies (associativité)
-    ways: usize,
 }
 
 impl CacheSet {
     /// Crée un nouvel ensemble de cache
     pub fn new(ways: usize) -> Self {
-        let mut lines = Vec::with_capacity(ways);
-        for _ in 0..ways {
-            lines.push(CacheLine::new());
-        }
-        
         CacheSet {
-            lines,
-            ways,
+            lines: vec![CacheLine::default(); ways],
         }
     }
-    
+
     /// Recherche une ligne dans l'ensemble
-    pub fn find_line(&self, tag: u32) -> Option<usize> {
-        for (i, line) in self.lines.iter().enumerate() {
-            if line.valid && line.tag == tag {
-                return Some(i);
-            }
-        }
-        
-        None
+    pub fn find_line(&self, tag: Address) -> Option<usize> {
+        self.lines.iter().position(|line| line.valid && line.tag == tag)
     }
-    
-    /// Trouve la ligne la moins récemment utilisée
+
+    /// Trouve une voie libre, ou à défaut la ligne la moins récemment utilisée
     pub fn find_lru(&self) -> usize {
-        let mut lru_index = 0;
-        let mut min_counter = u32::MAX;
-        
-        for (i, line) in self.lines.iter().enumerate() {
-            if !line.valid {
-                return i;
-            }
-            
-            if line.usage_counter < min_counter {
-                min_counter = line.usage_counter;
-                lru_index = i;
-            }
+        if let Some(free) = self.lines.iter().position(|line| !line.valid) {
+            return free;
         }
-        
-        lru_index
+        self.lines
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, line)| line.last_used)
+            .map_or(0, |(way, _)| way)
     }
 }
 
 /// Cache prédictif ternaire
 pub struct TernaryPredictiveCache {
+    /// Géométrie et coûts
+    config: CacheConfig,
     /// Ensembles du cache
     sets: Vec<CacheSet>,
-    /// Nombre d'ensembles
-    num_sets: usize,
-    /// Nombre de voies (associativité)
-    ways: usize,
-    /// Taille d'une ligne en mots
-    line_size: usize,
-    /// Nombre de bits pour l'index
-    index_bits: usize,
-    /// Nombre de bits pour l'offset
-    offset_bits: usize,
-    /// Compteur global pour la politique de remplacement
-    global_counter: u32,
-    /// Tampon de préchargement
-    prefetch_buffer: Vec<(u32, AccessConfidence)>,
-    /// Taille du tampon de préchargement
-    prefetch_buffer_size: usize,
+    /// Horloge des accès pour la politique de remplacement
+    clock: u64,
+    /// Tampon de préchargement: numéros de ligne prédits et leur confiance
+    prefetch_buffer: Vec<(Address, AccessConfidence)>,
+    /// Dernière ligne demandée hors du cache
+    last_miss: Option<Address>,
+    /// Pas observé entre les deux derniers échecs
+    last_stride: Option<i64>,
+    /// Compteurs
+    stats: CacheStats,
 }
 
 impl TernaryPredictiveCache {
-    /// Crée un nouveau cache prédictif ternaire
-    pub fn new(num_sets: usize, ways: usize, line_size: usize, prefetch_buffer_size: usize) -> Self {
-        let mut sets = Vec::with_capacity(num_sets);
-        for _ in 0..num_sets {
-            sets.push(CacheSet::new(ways));
-        }
-        
-        // Calculer le nombre de bits pour l'index et l'offset
-        let offset_bits = (line_size * 4).trailing_zeros() as usize;
-        let index_bits = num_sets.trailing_zeros() as usize;
-        
+    /// Crée un cache vide
+    pub fn new(config: CacheConfig) -> Self {
         TernaryPredictiveCache {
-            sets,
-            num_sets,
-            ways,
-            line_size,
-            index_bits,
-            offset_bits,
-            global_counter: 0,
-            prefetch_buffer: Vec::with_capacity(prefetch_buffer_size),
-            prefetch_buffer_size,
+            config,
+            sets: (0..config.sets).map(|_| CacheSet::new(config.ways)).collect(),
+            clock: 0,
+            prefetch_buffer: Vec::with_capacity(config.prefetch_entries),
+            last_miss: None,
+            last_stride: None,
+            stats: CacheStats::default(),
         }
     }
-    
-    /// Lire les données d'une ligne de cache sans modifier l'historique
-    fn read_line_data(&self, line: &CacheLine, offset: usize) -> Word {
-        line.data[offset]
+
+    /// Géométrie et coûts du cache
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
     }
-    
-    /// Mettre à jour une ligne après un hit et retourner les données
-    fn process_cache_access(&mut self, index: usize, line_index: usize, offset: usize, address: u32, memory: &mut [Word]) -> Word {
-        // Copier les données pour éviter d'avoir un emprunt mutable lors de l'appel à d'autres méthodes
-        let data;
-        {
-            let line = &mut self.sets[index].lines[line_index];
-            data = line.data[offset];
-            line.usage_counter = self.global_counter;
-            
-            // Mise à jour de l'historique d'accès sans utiliser self
-            line.access_history = (line.access_history << 3) | (offset as u32);
-        }
-        
-        // Prédiction basée sur l'adresse plutôt que sur la ligne
-        self.simple_prefetch(address, offset, memory);
-        
-        data
-    }
-    
-    /// Prédiction et préchargement simplifié sans emprunts multiples
-    fn simple_prefetch(&mut self, address: u32, _current_offset: usize, memory: &mut [Word]) {
-        // Calculer l'adresse suivante la plus probable
-        let stride = 1; // Stride simple
-        let prefetch_addr = address + (stride << (self.offset_bits as u32));
-        
-        // Décoder l'adresse à l'avance pour éviter les emprunts mutuels
-        let (pfetch_tag, pfetch_index, _pfetch_offset) = self.decode_address(prefetch_addr);
-        
-        // Précharger seulement si pas déjà dans le tampon
-        if !self.prefetch_buffer.iter().any(|entry| entry.0 == prefetch_addr) {
-            if let Some(entry) = self.prefetch_buffer.iter_mut().find(|e| e.0 == 0) {
-                entry.0 = prefetch_addr;
-                entry.1 = AccessConfidence::Probable;
-                
-                // Précharger dans la mémoire (simulation simplifiée)
-                let base_addr = prefetch_addr & !(self.line_size as u32 - 1);
-                for i in 0..self.line_size {
-                    let addr = base_addr + i as u32;
-                    if addr < memory.len() as u32 {
-                        // Ne rien faire, juste simuler une lecture
-                    }
-                }
-            }
-        }
+
+    /// Compteurs du cache
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    /// Numéro de ligne contenant une adresse physique
+    fn line_of(&self, addr: Address) -> Address {
+        addr / (WORD_TRYTES * self.config.line_words)
     }
-    
-    /// Accède au cache pour une lecture
-    pub fn read(&mut self, address: u32, memory: &mut [Word]) -> Word {
-        self.global_counter = self.global_counter.wrapping_add(1);
-        
-        // Décomposer l'adresse
-        let (tag, index, offset) = self.decode_address(address);
-        
-        // Rechercher dans le cache
-        if let Some(line_index) = self.sets[index].find_line(tag) {
-            // Hit de cache
-            let result = self.process_cache_access(index, line_index, offset, address, memory);
-            
-            // Préchargement prédictif
-            self.simple_prefetch(address, offset, memory);
-            
-            return result;
+
+    /// Décompose un numéro de ligne en (ensemble, étiquette)
+    fn decode_line(&self, line: Address) -> (usize, Address) {
+        (line % self.config.sets, line / self.config.sets)
+    }
+
+    /// Indique si la ligne contenant `addr` est présente (sans effet sur le LRU)
+    pub fn contains(&self, addr: Address) -> bool {
+        let (index, tag) = self.decode_line(self.line_of(addr));
+        self.sets[index].find_line(tag).is_some()
+    }
+
+    /// Accède au cache et retourne les cycles d'attente de l'accès
+    pub fn access(&mut self, addr: Address, kind: CacheAccess) -> u64 {
+        self.clock += 1;
+        let write = kind == CacheAccess::Write;
+        if write {
+            self.stats.writes += 1;
         } else {
-            // Miss de cache
-            
-            // Avant de remplacer une ligne, vérifier si elle est modifiée (dirty)
-            let victim_index = self.sets[index].find_lru();
-            let victim_line = &self.sets[index].lines[victim_index];
-            
-            if victim_line.valid && victim_line.dirty {
-                // Écrire la ligne victime en mémoire
-                self.write_back_line_simple(index, victim_index, memory);
-            }
-            
-            // Vérifier si l'adresse est dans le tampon de préchargement
-            let prefetch_index = self.prefetch_buffer.iter().position(|entry| {
-                let (entry_tag, entry_index, _) = self.decode_address(entry.0);
-                entry_tag == tag && entry_index == index
-            });
-            
-            if let Some((buffer_index, _confidence)) = prefetch_index.map(|i| (i, self.prefetch_buffer[i].1)) {
-                // L'adresse était dans le tampon de préchargement, marque comme succès
-                self.prefetch_buffer.remove(buffer_index);
-            }
-            
-            // Charger la ligne depuis la mémoire
-            self.load_line_from_memory(address, memory);
-            
-            // Trouver à nouveau la ligne (maintenant chargée)
-            if let Some(line_index) = self.sets[index].find_line(tag) {
-                return self.sets[index].lines[line_index].data[offset];
-            }
+            self.stats.reads += 1;
         }
-        
-        // En cas d'erreur, retourner une valeur par défaut
-        Word::default_zero()
-    }
-    
-    /// Écrire dans le cache et gérer le hit/miss
-    pub fn write(&mut self, address: u32, value: Word, memory: &mut [Word]) {
-        self.global_counter = self.global_counter.wrapping_add(1);
-        
-        // Décomposer l'adresse
-        let (tag, index, offset) = self.decode_address(address);
-        
-        // Rechercher dans le cache
-        if let Some(line_index) = self.sets[index].find_line(tag) {
-            // Hit de cache, mettre à jour la ligne
-            let line = &mut self.sets[index].lines[line_index];
-            line.data[offset] = value;
-            line.dirty = true;
-            line.usage_counter = self.global_counter;
-            
-            // Préchargement prédictif
-            self.simple_prefetch(address, offset, memory);
+
+        let line = self.line_of(addr);
+        let (index, tag) = self.decode_line(line);
+        let write_through = write && self.config.policy == WritePolicy::WriteThrough;
+        let mut stall = if write_through { self.word_write_cost() } else { 0 };
+
+        if let Some(way) = self.sets[index].find_line(tag) {
+            self.stats.hits += 1;
+            let entry = &mut self.sets[index].lines[way];
+            entry.last_used = self.clock;
+            entry.dirty |= write && !write_through;
         } else {
-            // Miss de cache
-            
-            // Trouver une ligne victime à remplacer
-            let victim_index = self.sets[index].find_lru();
-            let line = &mut self.sets[index].lines[victim_index];
-            
-            // Si la ligne est modifiée, la sauvegarder en mémoire
-            if line.valid && line.dirty {
-                // Écrire la ligne victime en mémoire
-                self.write_back_line_simple(index, victim_index, memory);
-            }
-            
-            // Charger la ligne depuis la mémoire
-            self.load_line_from_memory(address, memory);
-            
-            // Rechercher à nouveau la ligne (maintenant chargée)
-            if let Some(line_index) = self.sets[index].find_line(tag) {
-                // Mettre à jour avec la nouvelle valeur
-                let line = &mut self.sets[index].lines[line_index];
-                line.data[offset] = value;
-                line.dirty = true;
+            self.stats.misses += 1;
+            let prefetched = self.take_prefetched(line);
+            // Write-through sans allocation: le Mot part directement en mémoire
+            if !write_through {
+                if prefetched {
+                    self.stats.prefetch_hits += 1;
+                } else {
+                    stall += self.config.miss_penalty;
+                }
+                stall += self.fill(index, tag, write);
             }
+            self.predict(line);
         }
+
+        self.stats.stall_cycles += stall;
+        stall
     }
-    
-    /// Prédit les accès futurs et précharge les données
-    fn predict_and_prefetch(&mut self, address: u32, line: &CacheLine, _memory: &mut [Word]) {
-        // Historique des accès pour la prédiction
-        let history = line.access_history;
-        
-        // Motifs pour la prédiction
-        // Séquentiel : accès consécutifs (001, 010, 100)
-        let sequential_pattern = 0b100010001;
-        
-        // Analyse des motifs pour prédiction
-        let mut next_addresses = Vec::new();
-        
-        // Pour l'exemple, on ne fait qu'une prédiction simple
-        if (history & sequential_pattern) == sequential_pattern {
-            // Motif séquentiel détecté, précharger la ligne suivante
-            let next_address = address + (self.line_size as u32);
-            next_addresses.push((next_address, AccessConfidence::Probable));
-        }
-        
-        // Ajouter les adresses prédites au tampon de préchargement
-        for (addr, confidence) in next_addresses {
-            self.add_to_prefetch_buffer(addr, confidence);
-        }
+
+    /// Coût d'un Mot transmis à la mémoire en write-through
+    fn word_write_cost(&self) -> u64 {
+        (self.config.miss_penalty / self.config.line_words as u64).max(1)
     }
-    
-    /// Lance un préchargement en fonction d'une adresse
-    fn prefetch_address(&mut self, address: u32, memory: &mut [Word]) {
-        // Décomposer l'adresse
-        let (tag, index, _offset) = self.decode_address(address);
-        
-        // Vérifier si la ligne est déjà dans le cache
-        if let Some(_line_index) = self.sets[index].find_line(tag) {
-            // Ligne déjà dans le cache, rien à faire
-            return;
-        }
-        
-        // Chercher une ligne libre ou à remplacer
-        let victim_index = self.sets[index].find_lru();
-        
-        // Si la ligne est modifiée, la sauvegarder en mémoire
-        {
-            let victim_line = &self.sets[index].lines[victim_index];
-            if victim_line.valid && victim_line.dirty {
-                // Écrire la ligne victime en mémoire sans accéder directement à self
-                let base_addr = (victim_line.tag << self.index_bits as u32) | (index as u32);
-                
-                // Écrire les données en mémoire
-                for i in 0..self.line_size {
-                    let addr = base_addr + i as u32;
-                    if addr < memory.len() as u32 {
-                        memory[addr as usize] = victim_line.data[i];
-                    }
-                }
-                
-                // Marquons la ligne comme non-modifiée après l'emprunt
-                self.sets[index].lines[victim_index].dirty = false;
-            }
+
+    /// Installe une ligne dans son ensemble; retourne le coût de l'éviction
+    fn fill(&mut self, index: usize, tag: Address, dirty: bool) -> u64 {
+        let way = self.sets[index].find_lru();
+        let victim = self.sets[index].lines[way];
+        self.sets[index].lines[way] = CacheLine {
+            tag,
+            valid: true,
+            dirty,
+            last_used: self.clock,
+        };
+        if victim.valid && victim.dirty {
+            self.stats.writebacks += 1;
+            self.config.miss_penalty
+        } else {
+            0
         }
-        
-        // Charger la ligne depuis la mémoire
-        // Base de la ligne
-        let base_addr = address & !(self.line_size as u32 - 1);
-        
-        // Mise à jour de la ligne
-        {
-            let line = &mut self.sets[index].lines[victim_index];
-            line.tag = tag;
-            line.valid = true;
-            line.dirty = false;
-            line.usage_counter = self.global_counter - 1; // Priorité basse pour préchargement
-            
-            // Chargement des données
-            for i in 0..self.line_size {
-                let addr = base_addr + i as u32;
-                if addr < memory.len() as u32 {
-                    line.data[i] = memory[addr as usize];
-                }
+    }
+
+    /// Retire une ligne du tampon de préchargement; indique si elle y était
+    fn take_prefetched(&mut self, line: Address) -> bool {
+        match self.prefetch_buffer.iter().position(|&(entry, _)| entry == line) {
+            Some(position) => {
+                self.prefetch_buffer.remove(position);
+                true
             }
+            None => false,
         }
     }
-    
-    /// Décompose une adresse en tag, index et offset
-    fn decode_address(&self, address: u32) -> (u32, usize, usize) {
-        let word_address = address / 4;
-        let offset = (word_address % (self.line_size as u32)) as usize;
-        let index = ((word_address / (self.line_size as u32)) % (self.num_sets as u32)) as usize;
-        let tag = word_address / ((self.line_size * self.num_sets) as u32);
-        
-        (tag, index, offset)
-    }
-    
-    /// Charge une ligne depuis la mémoire
-    fn load_line_from_memory(&mut self, address: u32, memory: &mut [Word]) {
-        // Décomposer l'adresse
-        let (tag, index, _) = self.decode_address(address);
-        
-        // Trouver une ligne à remplacer
-        let line_index = self.sets[index].find_lru();
-        
-        // Si la ligne est dirty, écrire en mémoire (write-back) sans utiliser self et line simultanément
-        {
-            let line = &self.sets[index].lines[line_index];
-            if line.valid && line.dirty {
-                // Copier les données nécessaires pour le write-back
-                let line_tag = line.tag;
-                let line_data = line.data.clone();
-                
-                // Calculer l'adresse de base pour l'écriture
-                let base_address = ((line_tag as u32) << self.index_bits) | (index as u32);
-                
-                // Écrire les données en mémoire
-                for i in 0..self.line_size {
-                    let addr = base_address + i as u32;
-                    if let Some(word) = memory.get_mut(addr as usize) {
-                        *word = line_data[i];
-                    }
-                }
-            }
+
+    /// Prédit la prochaine ligne demandée hors du cache et la précharge
+    fn predict(&mut self, line: Address) {
+        let stride = self.last_miss.map(|previous| line as i64 - previous as i64);
+        let confidence = match (stride, self.last_stride) {
+            (Some(stride), Some(last)) if stride == last => AccessConfidence::Probable,
+            (Some(_), _) => AccessConfidence::Uncertain,
+            (None, _) => AccessConfidence::Improbable,
+        };
+        self.last_miss = Some(line);
+        if stride.is_some() {
+            self.last_stride = stride;
         }
-        
-        // Maintenant on peut emprunter de façon mutable
-        let line = &mut self.sets[index].lines[line_index];
-        
-        // Charger la nouvelle ligne
-        let base_address = (address / 4) & !(self.line_size as u32 - 1);
-        for i in 0..self.line_size {
-            let mem_index = (base_address + i as u32) as usize;
-            if mem_index < memory.len() {
-                line.data[i] = memory[mem_index];
-            } else {
-                line.data[i] = Word::zero();
-            }
+
+        let (Some(stride), true) = (stride, confidence > AccessConfidence::Improbable) else {
+            return;
+        };
+        if stride == 0 {
+            return;
         }
-        
-        // Mettre à jour les métadonnées
-        line.tag = tag;
-        line.valid = true;
-        line.dirty = false;
-        line.usage_counter = self.global_counter;
-        line.confidence = AccessConfidence::Uncertain;
-        line.access_history = 0;
-    }
-    
-    /// Écrit une ligne en mémoire (write-back)
-    fn write_back_line(&self, line: &CacheLine, index: usize, memory: &mut [Word]) {
-        let base_address = ((line.tag * (self.num_sets as u32) + index as u32) * (self.line_size as u32)) * 4;
-        
-        for i in 0..self.line_size {
-            let mem_index = (base_address / 4 + i as u32) as usize;
-            if mem_index < memory.len() {
-                memory[mem_index] = line.data[i];
-            }
+        if let Some(target) = line.checked_add_signed(stride as isize) {
+            self.prefetch(target, confidence);
         }
     }
-    
-    /// Version simplifiée de write_back_line sans prendre self et line simultanément
-    fn write_back_line_simple(&mut self, index: usize, line_index: usize, memory: &mut [Word]) {
-        let line = &self.sets[index].lines[line_index];
-        // Calculer l'adresse de base de la ligne
-        let base_addr = (line.tag << self.index_bits as u32) | (index as u32);
-        
-        // Écrire les données en mémoire
-        for i in 0..self.line_size {
-            let addr = base_addr + i as u32;
-            if addr < memory.len() as u32 {
-                memory[addr as usize] = line.data[i];
-            }
+
+    /// Ajoute une ligne au tampon de préchargement, en remplaçant au besoin l'entrée
+    /// de plus faible confiance (si elle ne l'est pas davantage que la nouvelle)
+    fn prefetch(&mut self, line: Address, confidence: AccessConfidence) {
+        if self.config.prefetch_entries == 0 {
+            return;
         }
-        
-        // Marquer la ligne comme non-modifiée
-        self.sets[index].lines[line_index].dirty = false;
-    }
-    
-    /// Ajoute une adresse au tampon de préchargement
-    fn add_to_prefetch_buffer(&mut self, address: u32, confidence: AccessConfidence) {
-        // Vérifier si l'adresse est déjà dans le cache
-        let (tag, index, _) = self.decode_address(address);
+        let (index, tag) = self.decode_line(line);
         if self.sets[index].find_line(tag).is_some() {
             return;
         }
-        
-        // Vérifier si l'adresse est déjà dans le tampon
-        for (i, (addr, _)) in self.prefetch_buffer.iter().enumerate() {
-            if *addr == address {
-                // Mettre à jour la confiance si nécessaire
-                if confidence == AccessConfidence::Probable {
-                    self.prefetch_buffer[i].1 = confidence;
-                }
-                return;
-            }
+        if let Some(entry) = self.prefetch_buffer.iter_mut().find(|(entry, _)| *entry == line) {
+            entry.1 = entry.1.max(confidence);
+            return;
         }
-        
-        // Ajouter au tampon
-        if self.prefetch_buffer.len() >= self.prefetch_buffer_size {
-            // Supprimer l'entrée avec la confiance la plus faible
-            let mut lowest_confidence_index = 0;
-            let mut lowest_confidence = AccessConfidence::Probable;
-            
-            for (i, (_, conf)) in self.prefetch_buffer.iter().enumerate() {
-                if *conf < lowest_confidence {
-                    lowest_confidence = *conf;
-                    lowest_confidence_index = i;
-                }
-            }
-            
-            if lowest_confidence < confidence {
-                self.prefetch_buffer[lowest_confidence_index] = (address, confidence);
-            }
+
+        if self.prefetch_buffer.len() < self.config.prefetch_entries {
+            self.prefetch_buffer.push((line, confidence));
+        } else if let Some(weakest) = self
+            .prefetch_buffer
+            .iter_mut()
+            .min_by_key(|(_, entry_confidence)| *entry_confidence)
+            .filter(|(_, entry_confidence)| *entry_confidence <= confidence)
+        {
+            *weakest = (line, confidence);
         } else {
-            self.prefetch_buffer.push((address, confidence));
+            return;
         }
+        self.stats.prefetches += 1;
     }
-    
-    /// Recherche une adresse dans le tampon de préchargement
-    fn find_in_prefetch_buffer(&self, address: u32) -> Option<(usize, AccessConfidence)> {
-        for (i, (addr, confidence)) in self.prefetch_buffer.iter().enumerate() {
-            if *addr == address {
-                return Some((i, *confidence));
-            }
+
+    /// Invalide toutes les lignes et vide le tampon de préchargement (compteurs conservés)
+    pub fn invalidate(&mut self) {
+        for set in &mut self.sets {
+            set.lines.fill(CacheLine::default());
         }
-        
-        None
-    }
-    
-    /// Effectue le préchargement des données dans le tampon
-    pub fn perform_prefetch(&mut self, memory: &mut [Word]) {
-        // Trier le tampon par niveau de confiance
-        self.prefetch_buffer.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-        
-        // Précharger les données avec la plus haute confiance
-        let mut prefetched = 0;
-        let max_prefetch = 2; // Limiter le nombre de préchargements par cycle
-        
-        let mut i = 0;
-        while i < self.prefetch_buffer.len() && prefetched < max_prefetch {
-            let (address, confidence) = self.prefetch_buffer[i];
-            
-            // Ne précharger que les données avec une confiance suffisante
-            if confidence == AccessConfidence::Probable {
-                // Vérifier si l'adresse est déjà dans le cache
-                let (tag, index, _) = self.decode_address(address);
-                if self.sets[index].find_line(tag).is_none() {
-                    // Précharger la ligne
-                    self.load_line_from_memory(address, memory);
-                    prefetched += 1;
-                }
-                
-                // Retirer du tampon
-                self.prefetch_buffer.remove(i);
-            } else {
-                i += 1;
-            }
+        self.prefetch_buffer.clear();
+        self.last_miss = None;
+        self.last_stride = None;
+    }
+}
+
+/// Caches L1 du processeur et cycles d'attente de l'instruction en cours
+/// Les accès sont faits depuis `CpuState::read_word` (`&self`): l'état est mutable en interne
+#[derive(Default)]
+pub struct MemoryCaches {
+    /// Cache d'instructions (None: fetch sans cache)
+    pub icache: Option<RefCell<TernaryPredictiveCache>>,
+    /// Cache de données (None: accès sans cache)
+    pub dcache: Option<RefCell<TernaryPredictiveCache>>,
+    /// Cycles d'attente accumulés depuis le dernier `take_stall`
+    stall: Cell<u64>,
+}
+
+impl MemoryCaches {
+    /// Aucun cache: les accès mémoire ne coûtent aucun cycle d'attente
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Caches d'instructions et de données selon les configurations données
+    pub fn with_config(icache: Option<CacheConfig>, dcache: Option<CacheConfig>) -> Self {
+        MemoryCaches {
+            icache: icache.map(|config| RefCell::new(TernaryPredictiveCache::new(config))),
+            dcache: dcache.map(|config| RefCell::new(TernaryPredictiveCache::new(config))),
+            stall: Cell::new(0),
         }
     }
-    
-    /// Compresse une ligne de cache
-    pub fn compress_line(&self, line: &CacheLine) -> Word {
-        // Implémentation simplifiée de la compression
-        // Dans une implémentation réelle, on utiliserait un algorithme de compression adapté aux données ternaires
-        
-        // Pour cet exemple, on calcule simplement une signature de la ligne
-        let mut signature = Word::zero();
-        
-        for (i, word) in line.data.iter().enumerate() {
-            // XOR avec décalage
-            let shifted_word = self.shift_word(*word, i);
-            signature = self.ternary_xor(signature, shifted_word);
+
+    /// Fetch d'une instruction occupant les adresses physiques `first` à `last`
+    pub fn fetch(&self, first: Address, last: Address) {
+        if let Some(cache) = &self.icache {
+            self.access(cache, first, last, CacheAccess::Read);
         }
-        
-        signature
-    }
-    
-    /// Décompresse une ligne de cache
-    pub fn decompress_line(&self, compressed: Word, original: &CacheLine) -> [Word; CACHE_LINE_SIZE] {
-        // Dans une implémentation réelle, on utiliserait l'algorithme de décompression correspondant
-        // Pour cet exemple, on retourne simplement la ligne originale
-        original.data
-    }
-    
-    /// Décale un mot ternaire
-    fn shift_word(&self, word: Word, shift: usize) -> Word {
-        let mut result = Word::zero();
-        
-        // Convertir le mot en trytes
-        let mut trytes = [Tryte::default(); 8];
-        for i in 0..8 {
-            if let Some(tryte) = word.tryte(i) {
-                trytes[i] = *tryte;
-            }
+    }
+
+    /// Accès aux données des adresses physiques `first` à `last`
+    /// Les registres de périphériques ne sont pas mis en cache
+    pub fn data(&self, memory: &Memory, first: Address, last: Address, kind: CacheAccess) {
+        if let Some(cache) = &self.dcache
+            && !memory.is_device(first)
+        {
+            self.access(cache, first, last, kind);
         }
-        
-        // Effectuer le décalage
-        for i in 0..8 {
-            if let Some(tryte_result) = result.tryte_mut(i) {
-                *tryte_result = trytes[(i + shift) % 8];
-            }
+    }
+
+    /// Accède à la ou aux deux lignes couvrant la plage (un Mot peut chevaucher deux lignes
+    /// quand la pagination le place à une adresse physique non alignée)
+    fn access(&self, cache: &RefCell<TernaryPredictiveCache>, first: Address, last: Address, kind: CacheAccess) {
+        let mut cache = cache.borrow_mut();
+        let mut stall = cache.access(first, kind);
+        if cache.line_of(last) != cache.line_of(first) {
+            stall += cache.access(last, kind);
         }
-        
-        result
-    }
-    
-    /// XOR ternaire entre deux mots
-    fn ternary_xor(&self, a: Word, b: Word) -> Word {
-        let mut result = Word::zero();
-        
-        for i in 0..8 {
-            if let (Some(tryte_a), Some(tryte_b), Some(tryte_result)) = 
-                (a.tryte(i), b.tryte(i), result.tryte_mut(i)) {
-                // Convertir les trytes en trits
-                let trits_a = tryte_a.to_trits();
-                let trits_b = tryte_b.to_trits();
-                let mut xor_trits = [Trit::Z; 3];
-                
-                // XOR ternaire pour chaque trit
-                for j in 0..3 {
-                    xor_trits[j] = match (trits_a[j], trits_b[j]) {
-                        (Trit::N, Trit::N) => Trit::P,
-                        (Trit::N, Trit::Z) => Trit::N,
-                        (Trit::N, Trit::P) => Trit::Z,
-                        (Trit::Z, Trit::N) => Trit::N,
-                        (Trit::Z, Trit::Z) => Trit::Z,
-                        (Trit::Z, Trit::P) => Trit::P,
-                        (Trit::P, Trit::N) => Trit::Z,
-                        (Trit::P, Trit::Z) => Trit::P,
-                        (Trit::P, Trit::P) => Trit::N,
-                    };
-                }
-                
-                *tryte_result = Tryte::from_trits(xor_trits);
-            }
+        self.stall.set(self.stall.get() + stall);
+    }
+
+    /// Retourne et remet à zéro les cycles d'attente accumulés
+    pub fn take_stall(&self) -> u64 {
+        self.stall.replace(0)
+    }
+
+    /// Invalide les deux caches (contenu de la mémoire remplacé, ex: restauration d'un instantané)
+    pub fn invalidate(&self) {
+        for cache in [&self.icache, &self.dcache].into_iter().flatten() {
+            cache.borrow_mut().invalidate();
+        }
+    }
+
+    /// Compteurs des caches présents: (cache d'instructions, cache de données)
+    pub fn stats(&self) -> (Option<CacheStats>, Option<CacheStats>) {
+        (
+            self.icache.as_ref().map(|cache| *cache.borrow().stats()),
+            self.dcache.as_ref().map(|cache| *cache.borrow().stats()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(policy: WritePolicy, prefetch_entries: usize) -> CacheConfig {
+        CacheConfig {
+            sets: 4,
+            ways: 2,
+            line_words: 2,
+            prefetch_entries,
+            policy,
+            miss_penalty: 10,
         }
-        
-        result
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_sequential_stream_is_prefetched() {
+        // Parcours séquentiel de 64 Mots: 32 lignes de 2 Mots
+        let run = |prefetch_entries| {
+            let mut cache = TernaryPredictiveCache::new(config(WritePolicy::WriteBack, prefetch_entries));
+            let stall: u64 = (0..64).map(|word| cache.access(word * 8, CacheAccess::Read)).sum();
+            (stall, *cache.stats())
+        };
+
+        let (stall, stats) = run(0);
+        assert_eq!((stats.hits, stats.misses, stats.prefetches), (32, 32, 0));
+        assert_eq!(stall, 320);
+
+        // Les deux premiers échecs établissent le pas, les suivants sont préchargés
+        let (stall, stats) = run(4);
+        assert_eq!((stats.hits, stats.misses), (32, 32));
+        assert_eq!(stats.prefetch_hits, 30);
+        assert_eq!(stall, 20);
+        assert_eq!(stats.stall_cycles, stall);
+    }
+
+    #[test]
+    fn test_write_policies() {
+        // 3 lignes du même ensemble (ensemble 0) dans un cache à 2 voies
+        let lines = [0, 4 * 16, 8 * 16];
+
+        let mut cache = TernaryPredictiveCache::new(config(WritePolicy::WriteBack, 0));
+        assert_eq!(cache.access(lines[0], CacheAccess::Write), 10);
+        assert_eq!(cache.access(lines[0] + 8, CacheAccess::Write), 0);
+        assert_eq!(cache.access(lines[1], CacheAccess::Read), 10);
+        // La ligne 0 modifiée est la moins récente: son éviction coûte un write-back
+        assert_eq!(cache.access(lines[2], CacheAccess::Read), 20);
+        assert!(!cache.contains(lines[0]) && cache.contains(lines[1]));
+        assert_eq!(cache.stats().writebacks, 1);
+
+        // Write-through: chaque Mot écrit coûte 10 / 2 cycles, sans allocation sur échec
+        let mut cache = TernaryPredictiveCache::new(config(WritePolicy::WriteThrough, 0));
+        assert_eq!(cache.access(lines[0], CacheAccess::Write), 5);
+        assert!(!cache.contains(lines[0]));
+        assert_eq!(cache.access(lines[0], CacheAccess::Read), 10);
+        assert_eq!(cache.access(lines[0], CacheAccess::Write), 5);
+        assert_eq!(cache.stats().writebacks, 0);
+        assert_eq!((cache.stats().reads, cache.stats().writes), (1, 2));
+    }
+}
@@ -2,4 +2,8 @@
 pub mod types; // Rend le module 'types' public dans le crate
 
 // Ré-exporte les types principaux pour un accès plus facile
-pub use types::{Address, MAX_ADDRESS, Trit, Tryte, Word, is_valid_address};
+pub use types::{
+    ADDRESS_TRITS, Address, AddressError, ConversionError, MAX_ADDRESS, Trit, Tryte, WORD_MAX,
+    WORD_MIN, WORD_MODULUS, Word, address_from_i64, address_from_trits, address_from_word,
+    address_to_trits, address_to_word, is_valid_address,
+};
@@ -164,11 +164,12 @@ fn decode_cbranch(instr_trits: &[Trit]) -> Result<CompactInstruction, DecodeErro
 pub fn compact_to_standard(instr: CompactInstruction) -> Instruction {
     match instr {
         CompactInstruction::CMov { rd, rs } => {
-            Instruction::AluReg {
-                op: AluOp::Or,
+            // Copie par ADDI rd, rs, 0 (comme le pseudo MV): R0 n'est pas câblé à zéro
+            Instruction::AluImm {
+                op: AluOp::Add,
                 rs1: rs,
-                rs2: Register::R0, // Registre zéro
                 rd,
+                imm: 0,
             }
         }
         CompactInstruction::CAdd { rd, rs } => {
@@ -205,8 +206,8 @@ mod tests {
     #[test]
     fn test_decode_cmov() {
         // Créer une instruction CMOV rd=1, rs=2
-        // [op=CMOV(NN) | rd=1(NP) | rs=2(NNPN)]
-        let instr_trits = [Trit::N, Trit::N, Trit::N, Trit::P, Trit::N, Trit::N, Trit::P, Trit::N];
+        // [op=CMOV(NN) | rd=1(ZP) | rs=2(ZZPN)], champs de poids fort en premier
+        let instr_trits = [Trit::N, Trit::N, Trit::Z, Trit::P, Trit::Z, Trit::Z, Trit::P, Trit::N];
         
         let result = decode_compact(&instr_trits).unwrap();
         
@@ -222,8 +223,8 @@ mod tests {
     #[test]
     fn test_decode_cadd() {
         // Créer une instruction CADD rd=2, rs=3
-        // [op=CADD(NZ) | rd=2(PN) | rs=3(NNPP)]
-        let instr_trits = [Trit::N, Trit::Z, Trit::P, Trit::N, Trit::N, Trit::N, Trit::P, Trit::P];
+        // [op=CADD(NZ) | rd=2(PN) | rs=3(ZZPZ)]
+        let instr_trits = [Trit::N, Trit::Z, Trit::P, Trit::N, Trit::Z, Trit::Z, Trit::P, Trit::Z];
         
         let result = decode_compact(&instr_trits).unwrap();
         
@@ -239,8 +240,8 @@ mod tests {
     #[test]
     fn test_decode_csub() {
         // Créer une instruction CSUB rd=3, rs=1
-        // [op=CSUB(NP) | rd=3(PP) | rs=1(NNNP)]
-        let instr_trits = [Trit::N, Trit::P, Trit::P, Trit::P, Trit::N, Trit::N, Trit::N, Trit::P];
+        // [op=CSUB(NP) | rd=3(PZ) | rs=1(ZZZP)]
+        let instr_trits = [Trit::N, Trit::P, Trit::P, Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::P];
         
         let result = decode_compact(&instr_trits).unwrap();
         
@@ -256,15 +257,15 @@ mod tests {
     #[test]
     fn test_decode_cbranch() {
         // Créer une instruction CBRANCH cond=Eq, offset=5
-        // [op=CBRANCH(NN) | cond=Eq(NN) | offset=5(NNNP)]
-        let instr_trits = [Trit::N, Trit::N, Trit::N, Trit::N, Trit::N, Trit::N, Trit::N, Trit::P];
+        // [op=CBRANCH(ZN) | cond=Eq(ZZ) | offset=5(ZPNN)]
+        let instr_trits = [Trit::Z, Trit::N, Trit::Z, Trit::Z, Trit::Z, Trit::P, Trit::N, Trit::N];
         
         let result = decode_compact(&instr_trits).unwrap();
         
         match result {
             CompactInstruction::CBranch { cond, offset } => {
-                assert_eq!(cond, 0); // Valeur simplifiée pour le test
-                assert_eq!(offset, 1); // Valeur simplifiée pour le test
+                assert_eq!(cond, 0);
+                assert_eq!(offset, 5);
             },
             _ => panic!("Expected CBranch instruction"),
         }
@@ -277,13 +278,13 @@ mod tests {
         let std_instr = compact_to_standard(cmov);
         
         match std_instr {
-            Instruction::AluReg { op, rs1, rs2, rd } => {
-                assert_eq!(op, AluOp::Or);
+            Instruction::AluImm { op, rs1, rd, imm } => {
+                assert_eq!(op, AluOp::Add);
                 assert_eq!(rs1, Register::R2);
-                assert_eq!(rs2, Register::R0);
                 assert_eq!(rd, Register::R1);
+                assert_eq!(imm, 0);
             },
-            _ => panic!("Expected AluReg instruction"),
+            _ => panic!("Expected AluImm instruction"),
         }
         
         // Tester la conversion de CAdd en instruction standard
@@ -3,7 +3,7 @@
 // Ce fichier réexporte les fonctionnalités des modules spécialisés
 
 // Réexporter les structures et erreurs principales
-pub use crate::cpu::execute_core::{Cpu, ExecuteError};
+pub use crate::cpu::execute_core::{Cpu, ExecuteError, StopReason};
 
 // Réexporter les traits pour les différentes catégories d'instructions
 
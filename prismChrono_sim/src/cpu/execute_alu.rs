@@ -2,12 +2,16 @@
 // Implémentation des instructions ALU pour l'architecture PrismChrono
 
 use crate::alu::{
-    add_24_trits, compare_24_trits, div_24_trits, mod_24_trits, mul_24_trits, shl_24_trits,
+    add_24_trits, compare_24_trits, div_24_trits, mod_24_trits, mul_words, shl_24_trits,
     shr_24_trits, sub_24_trits,
 };
 use crate::alu::{trit_inv_word, trit_max_word, trit_min_word};
+use crate::ternary_instructions::{
+    abs_t, checkw_valid, extract_tryte, is_special_tryte, signum_t, tcmp3,
+};
 use crate::core::{Trit, Tryte, Word};
 use crate::cpu::execute::ExecuteError;
+use crate::cpu::execute_core::is_special_state;
 use crate::cpu::isa::AluOp;
 use crate::cpu::registers::{Flags, Register};
 
@@ -58,8 +62,9 @@ impl<T: CpuState> AluOperations for T {
                 (res, flags)
             }
             AluOp::Mul => {
-                let res = mul_24_trits(val1, val2);
+                let (res, overflow) = mul_words(val1, val2);
                 let mut flags = Flags::new();
+                flags.of = overflow; // Produit exact hors de la plage d'un Mot
 
                 // Mettre à jour les flags
                 flags.zf = res.trytes().iter().all(|t| match t {
@@ -68,16 +73,21 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
             }
             AluOp::Div => {
+                // Un opérande NaN/UNDEF ne peut pas être divisé
+                if is_special_state(&val1) || is_special_state(&val2) {
+                    return Err(ExecuteError::SpecialStateFault);
+                }
+
                 // Vérifier la division par zéro
                 let is_zero = val2.trytes().iter().all(|t| match t {
                     Tryte::Digit(13) => true, // 13 = 0 en ternaire équilibré
@@ -98,16 +108,21 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
             }
             AluOp::Mod => {
+                // Un opérande NaN/UNDEF ne peut pas être divisé
+                if is_special_state(&val1) || is_special_state(&val2) {
+                    return Err(ExecuteError::SpecialStateFault);
+                }
+
                 // Vérifier la division par zéro
                 let is_zero = val2.trytes().iter().all(|t| match t {
                     Tryte::Digit(13) => true, // 13 = 0 en ternaire équilibré
@@ -128,11 +143,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -148,11 +163,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -168,11 +183,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -188,11 +203,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -208,11 +223,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -228,11 +243,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -278,11 +293,11 @@ impl<T: CpuState> AluOperations for T {
                 });
                 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
                 
                 (res, flags)
@@ -322,11 +337,11 @@ impl<T: CpuState> AluOperations for T {
                 });
                 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
                 
                 (res, flags)
@@ -371,15 +386,20 @@ impl<T: CpuState> AluOperations for T {
                 });
                 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
                 
                 (res, flags)
             }
+            // Instructions spécialisées ternaires
+            _ => {
+                let res = execute_ternary_alu_op(&op, val1, val2)?;
+                (res, result_flags(&res))
+            }
         };
 
         // 3. Écrire le résultat dans le registre de destination (sauf pour CMP)
@@ -406,7 +426,7 @@ impl<T: CpuState> AluOperations for T {
         let val1 = self.read_gpr(rs1);
 
         // 2. Convertir l'immédiat en Word
-        let val2 = Word::from_int(imm as i32);
+        let val2 = Word::from(imm as i32);
 
         // 3. Effectuer l'opération ALU appropriée (similaire à execute_alu_reg)
         let (result, flags) = match op {
@@ -419,8 +439,9 @@ impl<T: CpuState> AluOperations for T {
                 (res, flags)
             }
             AluOp::Mul => {
-                let res = mul_24_trits(val1, val2);
+                let (res, overflow) = mul_words(val1, val2);
                 let mut flags = Flags::new();
+                flags.of = overflow; // Produit exact hors de la plage d'un Mot
 
                 // Mettre à jour les flags
                 flags.zf = res.trytes().iter().all(|t| match t {
@@ -429,16 +450,21 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
             }
             AluOp::Div => {
+                // Un opérande NaN/UNDEF ne peut pas être divisé
+                if is_special_state(&val1) || is_special_state(&val2) {
+                    return Err(ExecuteError::SpecialStateFault);
+                }
+
                 // Vérifier la division par zéro
                 let is_zero = val2.trytes().iter().all(|t| match t {
                     Tryte::Digit(13) => true, // 13 = 0 en ternaire équilibré
@@ -459,16 +485,21 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
             }
             AluOp::Mod => {
+                // Un opérande NaN/UNDEF ne peut pas être divisé
+                if is_special_state(&val1) || is_special_state(&val2) {
+                    return Err(ExecuteError::SpecialStateFault);
+                }
+
                 // Vérifier la division par zéro
                 let is_zero = val2.trytes().iter().all(|t| match t {
                     Tryte::Digit(13) => true, // 13 = 0 en ternaire équilibré
@@ -489,11 +520,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -509,11 +540,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -529,11 +560,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -549,11 +580,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -569,11 +600,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -589,11 +620,11 @@ impl<T: CpuState> AluOperations for T {
                 });
 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
 
                 (res, flags)
@@ -639,11 +670,11 @@ impl<T: CpuState> AluOperations for T {
                 });
                 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
                 
                 (res, flags)
@@ -683,11 +714,11 @@ impl<T: CpuState> AluOperations for T {
                 });
                 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
                 
                 (res, flags)
@@ -732,15 +763,20 @@ impl<T: CpuState> AluOperations for T {
                 });
                 
                 // Vérifier le signe du résultat
-                if let Some(msb_tryte) = res.tryte(7) {
-                    if let Tryte::Digit(_) = msb_tryte {
-                        let msb_trits = msb_tryte.to_trits();
-                        flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
-                    }
+                if let Some(msb_tryte) = res.tryte(7)
+                    && let Tryte::Digit(_) = msb_tryte
+                {
+                    let msb_trits = msb_tryte.to_trits();
+                    flags.sf = msb_trits[2] == Trit::N; // Trit de poids fort = N?
                 }
                 
                 (res, flags)
             }
+            // Instructions spécialisées ternaires
+            _ => {
+                let res = execute_ternary_alu_op(&op, val1, val2)?;
+                (res, result_flags(&res))
+            }
         };
 
         // 4. Écrire le résultat dans le registre de destination (sauf pour CMP)
@@ -755,5 +791,35 @@ impl<T: CpuState> AluOperations for T {
     }
 }
 
+/// Calcule le résultat des instructions ALU spécialisées ternaires
+/// Les opérations non encore supportées par l'ALU renvoient `Unimplemented`
+fn execute_ternary_alu_op(op: &AluOp, val1: Word, val2: Word) -> Result<Word, ExecuteError> {
+    match op {
+        AluOp::Compare3 => Ok(tcmp3(val1, val2)),
+        AluOp::Abs => Ok(abs_t(val1)),
+        AluOp::Signum => Ok(signum_t(val1)),
+        AluOp::CheckW => Ok(checkw_valid(val1)),
+        AluOp::ExtractTryte => Ok(extract_tryte(val1, tryte_index(val2)?)),
+        AluOp::IsSpecialTryte => Ok(is_special_tryte(val1, tryte_index(val2)?)),
+        _ => Err(ExecuteError::Unimplemented),
+    }
+}
+
+/// Interprète un mot comme un index de tryte (0-7)
+fn tryte_index(word: Word) -> Result<usize, ExecuteError> {
+    match word.to_i64() {
+        idx @ 0..=7 => Ok(idx as usize),
+        _ => Err(ExecuteError::InvalidOperation),
+    }
+}
+
+/// Calcule les flags ZF/SF d'un résultat d'ALU
+fn result_flags(res: &Word) -> Flags {
+    let mut flags = Flags::new();
+    flags.zf = res.trytes().iter().all(|t| *t == Tryte::Digit(13)); // 13 = 0 en ternaire équilibré
+    flags.sf = res.is_negative();
+    flags
+}
+
 // Le trait CpuState est maintenant importé depuis le module state
 use crate::cpu::state::CpuState;
@@ -1,8 +1,7 @@
 // src/cpu/execute_branch.rs
 // Implémentation des instructions de branchement et saut pour l'architecture PrismChrono
 
-use crate::alu::add_24_trits;
-use crate::core::{Trit, Tryte, Word, is_valid_address};
+use crate::core::{Trit, Word, address_from_i64, address_from_word, address_to_word};
 use crate::cpu::execute::ExecuteError;
 use crate::cpu::isa::BranchCondition;
 use crate::cpu::registers::Register;
@@ -55,6 +54,8 @@ impl<T: CpuState> BranchOperations for T {
             BranchCondition::NonZero => !flags.zf,          // Non égal (ZF = 0)
             BranchCondition::Negative => flags.sf,          // Inférieur (SF = 1)
             BranchCondition::Positive => !flags.sf,         // Supérieur ou égal (SF = 0)
+            BranchCondition::Greater => !flags.sf && !flags.zf, // Strictement supérieur (SF = 0 et ZF = 0)
+            BranchCondition::LessEqual => flags.sf || flags.zf, // Inférieur ou égal (SF = 1 ou ZF = 1)
             BranchCondition::Overflow => flags.of,          // Débordement (OF = 1)
             BranchCondition::Carry => flags.cf,             // Retenue (CF = 1)
             BranchCondition::XS => flags.xf,                // État spécial (XF = 1)
@@ -63,6 +64,11 @@ impl<T: CpuState> BranchOperations for T {
             BranchCondition::False => false,                // Toujours faux
         };
 
+        // Issue soumise au prédicteur: P si pris, Z sinon
+        if let Some(cpu) = self.as_cpu_mut() {
+            cpu.observe_branch(if condition_met { Trit::P } else { Trit::Z });
+        }
+
         // 3. Si la condition est remplie, calculer la nouvelle adresse PC
         if condition_met {
             // Incrémenter le compteur de branchements pris
@@ -70,52 +76,9 @@ impl<T: CpuState> BranchOperations for T {
                 cpu.branches_taken += 1;
             }
             
-            // Lire le PC actuel
-            let current_pc = self.read_pc();
-
-            // Calculer l'offset multiplié par 4 (taille d'une instruction)
-            let offset_word = Word::from_int((offset as i32) * 4);
-
-            // Calculer PC = PC + offset * 4
-            let (new_pc, _, _) = add_24_trits(current_pc, offset_word, Trit::Z);
-
-            // Convertir Word en entier pour vérifier la validité de l'adresse
-            // Nous allons calculer la valeur en utilisant les trytes individuels
-            let mut addr_value: i32 = 0;
-            let mut power: i32 = 1;
-
-            for i in 0..8 {
-                if let Some(tryte) = new_pc.tryte(i) {
-                    match tryte {
-                        Tryte::Digit(digit) => {
-                            // Convertir le digit en valeur ternaire équilibrée (-13 à +13)
-                            let val = *digit as i32 - 13;
-                            // Ajouter la contribution de ce tryte (base 27)
-                            addr_value += val * power;
-                            power *= 27;
-                        }
-                        _ => return Err(ExecuteError::InvalidAddress), // Adresse invalide si ce n'est pas un digit
-                    }
-                }
-            }
-
-            // Convertir en usize pour la vérification d'adresse
-            // S'assurer que l'adresse est positive
-            if addr_value < 0 {
-                return Err(ExecuteError::InvalidAddress); // Adresse négative invalide
-            }
-
-            let addr_value = addr_value as usize;
-
-            // Vérifier que l'adresse est valide
-            if !is_valid_address(addr_value) {
-                return Err(ExecuteError::InvalidAddress);
-            }
-
-            // Vérifier que l'adresse est alignée (multiple de 4)
-            if addr_value % 4 != 0 {
-                return Err(ExecuteError::UnalignedAddress);
-            }
+            // Cible relative à l'instruction suivante, comme la calcule l'assembleur:
+            // PC + 4 + offset * 4
+            let new_pc = relative_target(&self.read_pc(), offset as i64)?;
 
             // Mettre à jour le PC
             self.write_pc(new_pc);
@@ -127,17 +90,14 @@ impl<T: CpuState> BranchOperations for T {
     /// Exécute une instruction de saut (Jump)
     /// Format J: [opcode(3t) | rd(2t) | offset(7t)]
     fn execute_jump(&mut self, rd: Register, offset: i16) -> Result<(), ExecuteError> {
-        // 1. Sauvegarder le PC actuel dans rd (si rd != R0)
-        if rd != Register::R0 {
-            let current_pc = self.read_pc();
-            self.write_gpr(rd, current_pc);
-        }
-
-        // 2. Calculer le nouveau PC = PC + offset
+        // 1. Calculer le nouveau PC = PC + 4 + offset * 4 (avant d'écrire le lien)
         let current_pc = self.read_pc();
-        let offset_word = Word::from_int(offset as i32);
+        let new_pc = relative_target(&current_pc, offset as i64)?;
 
-        let (new_pc, _, _) = add_24_trits(current_pc, offset_word, Trit::Z);
+        // 2. Sauvegarder l'adresse de retour PC + 4 dans rd (si rd != R0)
+        if rd != Register::R0 {
+            self.write_gpr(rd, return_address(&current_pc)?);
+        }
 
         // 3. Mettre à jour le PC
         self.write_pc(new_pc);
@@ -160,21 +120,52 @@ impl<T: CpuState> BranchOperations for T {
         rs1: Register,
         offset: i8,
     ) -> Result<(), ExecuteError> {
-        // 1. Sauvegarder le PC actuel dans rd (si rd != R0)
+        // 1. Calculer le nouveau PC = rs1 + offset (rs1 est lu avant l'écriture du lien,
+        // rd peut valoir rs1)
+        let base_addr = self.read_gpr(rs1).to_i64();
+        let new_pc = jump_target(base_addr + offset as i64)?;
+
+        // 2. Sauvegarder l'adresse de retour PC + 4 dans rd (si rd != R0)
         if rd != Register::R0 {
             let current_pc = self.read_pc();
-            self.write_gpr(rd, current_pc);
+            self.write_gpr(rd, return_address(&current_pc)?);
         }
 
-        // 2. Calculer le nouveau PC = rs1 + offset
-        let base_addr = self.read_gpr(rs1);
-        let offset_word = Word::from_int(offset as i32);
-
-        let (new_pc, _, _) = add_24_trits(base_addr, offset_word, Trit::Z);
-
         // 3. Mettre à jour le PC
         self.write_pc(new_pc);
 
         Ok(())
     }
 }
+
+/// Adresse visée par un BRANCH, JAL ou CALL situé en `pc`: instruction suivante (PC + 4)
+/// + `offset` instructions (partagée avec la prédiction du modèle de pipeline)
+pub fn relative_target_address(pc: i64, offset: i64) -> i64 {
+    pc + 4 + offset * 4
+}
+
+/// Adresse visée par une voie d'un BRANCH3 situé en `pc`: ses offsets sont relatifs au
+/// BRANCH3 lui-même (PC + `offset` instructions), comme les calcule l'assembleur
+pub fn branch3_target_address(pc: i64, offset: i64) -> i64 {
+    pc + offset * 4
+}
+
+/// Cible d'un saut relatif: instruction suivante (PC + 4) + `offset` instructions
+fn relative_target(pc: &Word, offset: i64) -> Result<Word, ExecuteError> {
+    jump_target(relative_target_address(pc.to_i64(), offset))
+}
+
+/// Valide une adresse de saut calculée en entier exact (l'ALU traiterait un tryte valant
+/// +11..+13, comme dans 0x28, en état spécial) et la convertit en Mot
+fn jump_target(value: i64) -> Result<Word, ExecuteError> {
+    let addr = address_from_i64(value)?;
+    if addr % 4 != 0 {
+        return Err(ExecuteError::UnalignedAddress);
+    }
+    Ok(address_to_word(addr)?)
+}
+
+/// Adresse de retour rangée par JAL/JALR: l'instruction qui suit le saut
+fn return_address(pc: &Word) -> Result<Word, ExecuteError> {
+    Ok(address_to_word(address_from_word(pc)? + 4)?)
+}
@@ -1,11 +1,21 @@
 // src/cpu/execute_core.rs
 // Implémentation du cœur d'exécution pour l'architecture PrismChrono
 
-use crate::alu::add_24_trits;
-use crate::core::{Address, Trit, Tryte, Word, is_valid_address};
+use crate::branch_predictor::BranchProfiler;
+use crate::cache::{CacheAccess, MemoryCaches};
+use crate::core::{Address, AddressError, Trit, Tryte, Word, address_from_word, is_valid_address};
 use crate::cpu::isa::Instruction;
-use crate::cpu::registers::{Flags, ProcessorState, Register, RegisterError, PrivilegeLevel, TrapCause};
-use crate::memory::{Memory, MemoryError};
+use crate::cpu::registers::{Flags, Interrupt, ProcessorState, Register, RegisterError, PrivilegeLevel, TrapCause, flag_trit};
+use crate::memory::{InterruptLine, Memory, MemoryError};
+use crate::metrics::OpcodeCounts;
+use crate::mmu::{AccessType, Mmu, MmuError};
+use crate::pipeline::PipelineModel;
+use crate::semihost::Semihost;
+use crate::snapshot::{Snapshot, SnapshotError};
+use crate::trace::{TraceEvent, TraceSink, TraceSnapshot};
+use crate::tvpu_hardware::{TVPUError, TVPUHardware};
+use std::fs;
+use std::path::Path;
 
 // Importer les traits des modules d'exécution spécialisés
 use crate::cpu::execute_alu::AluOperations;
@@ -13,7 +23,8 @@ use crate::cpu::execute_branch::BranchOperations;
 use crate::cpu::execute_mem::MemoryOperations;
 use crate::cpu::execute_system::SystemOperations;
 use crate::cpu::execute_system::CsrOperations;
-use crate::cpu::execute_ternary::TernaryOperations;
+use crate::cpu::execute_system::InterruptOperations;
+use crate::cpu::execute_ternary::ExecuteTernary;
 use crate::cpu::state::CpuState;
 
 /// Erreurs possibles lors de l'exécution d'une instruction
@@ -30,6 +41,36 @@ pub enum ExecuteError {
     Halted,                       // Processeur arrêté (HALT)
     Breakpoint,                   // Point d'arrêt
     IllegalCsrAccess,             // Accès illégal à un CSR
+    SpecialStateFault,            // Opérande NaN/UNDEF utilisé comme adresse ou diviseur
+    PageFault(AccessType),        // Traduction d'adresse refusée par la MMU
+    UnhandledTrap(TrapCause),     // Trap levé par l'instruction du vecteur de trap
+}
+
+impl ExecuteError {
+    /// Cause de trap correspondant à une faute synchrone de l'instruction en cours
+    /// `store` indique que l'instruction écrit en mémoire (StoreFault plutôt que LoadFault)
+    /// Retourne None pour les événements traités par l'hôte (HALT, EBREAK, trap non géré)
+    pub fn trap_cause(&self, store: bool) -> Option<TrapCause> {
+        let access_fault = if store { TrapCause::StoreFault } else { TrapCause::LoadFault };
+        match self {
+            ExecuteError::MemoryError(MemoryError::Misaligned) => Some(TrapCause::Misaligned),
+            ExecuteError::MemoryError(MemoryError::DeviceConflict) => None,
+            ExecuteError::MemoryError(_) => Some(access_fault),
+            ExecuteError::InvalidAddress => Some(access_fault),
+            ExecuteError::UnalignedAddress => Some(TrapCause::Misaligned),
+            ExecuteError::DivisionByZero => Some(TrapCause::DivByZero),
+            ExecuteError::SpecialStateFault => Some(TrapCause::SpecialState),
+            ExecuteError::PageFault(AccessType::Fetch) => Some(TrapCause::InstrPageFault),
+            ExecuteError::PageFault(AccessType::Load) => Some(TrapCause::LoadPageFault),
+            ExecuteError::PageFault(AccessType::Store) => Some(TrapCause::StorePageFault),
+            ExecuteError::RegisterError(_)
+            | ExecuteError::InvalidInstruction
+            | ExecuteError::Unimplemented
+            | ExecuteError::InvalidOperation
+            | ExecuteError::IllegalCsrAccess => Some(TrapCause::IllegalInstr),
+            ExecuteError::Halted | ExecuteError::Breakpoint | ExecuteError::UnhandledTrap(_) => None,
+        }
+    }
 }
 
 // Conversion des erreurs mémoire en erreurs d'exécution
@@ -39,6 +80,37 @@ impl From<MemoryError> for ExecuteError {
     }
 }
 
+// Conversion des erreurs de traduction en erreurs d'exécution
+// Une entrée de table hors de la mémoire physique est une faute d'accès
+impl From<MmuError> for ExecuteError {
+    fn from(error: MmuError) -> Self {
+        match error {
+            MmuError::PageFault(access) => ExecuteError::PageFault(access),
+            MmuError::Memory(_) => ExecuteError::MemoryError(MemoryError::OutOfBounds),
+        }
+    }
+}
+
+// Conversion des erreurs de l'unité vectorielle en erreurs d'exécution
+impl From<TVPUError> for ExecuteError {
+    fn from(error: TVPUError) -> Self {
+        match error {
+            TVPUError::InvalidRegister => ExecuteError::InvalidInstruction,
+            TVPUError::InvalidAddress => ExecuteError::InvalidAddress,
+            TVPUError::AlignmentError => ExecuteError::UnalignedAddress,
+            TVPUError::UnsupportedOperation => ExecuteError::Unimplemented,
+            TVPUError::Overflow => ExecuteError::InvalidOperation,
+        }
+    }
+}
+
+// Conversion des erreurs de conversion d'adresse en erreurs d'exécution
+impl From<AddressError> for ExecuteError {
+    fn from(_: AddressError) -> Self {
+        ExecuteError::InvalidAddress
+    }
+}
+
 // Conversion des erreurs de registre en erreurs d'exécution
 impl From<RegisterError> for ExecuteError {
     fn from(error: RegisterError) -> Self {
@@ -46,42 +118,97 @@ impl From<RegisterError> for ExecuteError {
     }
 }
 
+/// Raison de l'arrêt d'une exécution par `Cpu::run`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Halted,      // Instruction HALT exécutée
+    Breakpoint,  // Instruction EBREAK rencontrée
+    StepLimit,   // Limite d'instructions atteinte
+    Exited(i64), // Appel EXIT du semihosting, avec son code de sortie
+}
+
+/// Indique si un mot est dans un état spécial (NaN ou UNDEF)
+/// Seul le tryte de poids fort est examiné: aucune adresse valide ni aucun diviseur
+/// raisonnable ne l'utilise, alors qu'un registre non initialisé ou un résultat de
+/// SETNAN/SETUNDEF y porte l'état spécial
+pub(crate) fn is_special_state(word: &Word) -> bool {
+    matches!(word.tryte(7), Some(Tryte::NaN | Tryte::Undefined))
+}
+
+/// Convertit un mot (valeur ternaire équilibrée) en adresse mémoire
+/// Les valeurs négatives ou hors de l'espace d'adressage sont invalides
+pub(crate) fn word_to_address(word: &Word) -> Result<Address, ExecuteError> {
+    if is_special_state(word) {
+        return Err(ExecuteError::SpecialStateFault);
+    }
+    Ok(address_from_word(word)?)
+}
+
+/// Indique si une instruction écrit en mémoire (une faute d'accès y est une StoreFault,
+/// compteur `memory_writes`)
+fn writes_memory(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Store { .. }
+            | Instruction::StoreT { .. }
+            | Instruction::StoreT3 { .. }
+            | Instruction::TMemCpy { .. }
+            | Instruction::TMemSet { .. }
+            | Instruction::VectorStore { .. }
+    )
+}
+
+/// Indique si une instruction lit la mémoire (compteur `memory_reads`)
+fn reads_memory(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Load { .. }
+            | Instruction::LoadT { .. }
+            | Instruction::LoadTU { .. }
+            | Instruction::LoadT3 { .. }
+            | Instruction::TMemCpy { .. }
+            | Instruction::VectorLoad { .. }
+    )
+}
+
+/// Indique si les adresses physiques d'un Mot forment un Mot aligné (accès Mot direct,
+/// nécessaire pour les registres de périphérique)
+fn is_word_span(physical: &[Address]) -> bool {
+    physical[0].is_multiple_of(8) && physical.windows(2).all(|pair| pair[1] == pair[0] + 1)
+}
+
+/// Issue d'une étape, transmise au modèle du pipeline
+enum StepOutcome {
+    Retired(Instruction), // Instruction exécutée jusqu'au bout
+    Trap,                 // Trap ou interruption pris à la place de l'instruction
+}
+
 /// Structure principale du CPU qui contient l'état du processeur et la mémoire
 pub struct Cpu {
     pub state: ProcessorState, // État du processeur (registres, flags)
     pub memory: Memory,        // Mémoire principale
+    pub mmu: Mmu,              // Traduction d'adresses (pagination Sv18_T, TLB)
+    pub caches: MemoryCaches,  // Caches L1 d'instructions et de données (modèle temporel)
+    pub tvpu: TVPUHardware,    // Unité de traitement vectoriel (latences, compteurs)
+    pub pipeline: Option<PipelineModel>, // Modèle temporel du pipeline 5 étages (optionnel)
+    pub branch_profiler: Option<BranchProfiler>, // Prédicteur évalué sur chaque branchement (optionnel)
+    pub opcode_counts: Option<OpcodeCounts>, // Instructions exécutées par mnémonique (optionnel)
+    pub semihost: Option<Semihost>, // Appels système servis par l'hôte (optionnel)
+    pub trace: Option<Box<dyn TraceSink>>, // Destination de la trace d'exécution (optionnelle)
     pub halted: bool,          // Indique si le processeur est arrêté
     
     // Compteurs pour les métriques d'exécution
+    pub cycles: u64,                // Nombre de cycles simulés (horloge des périphériques)
     pub instructions_executed: u64, // Nombre total d'instructions exécutées
     pub memory_reads: u64,          // Nombre d'opérations de lecture mémoire
     pub memory_writes: u64,         // Nombre d'opérations d'écriture mémoire
     pub branches_total: u64,        // Nombre total d'instructions de branchement
     pub branches_taken: u64,        // Nombre de branchements effectivement pris
-    /// Affiche un rapport des métriques d'exécution
-    pub fn report_metrics(&self) {
-        println!("--- Rapport d'exécution PrismChrono ---");
-        println!("Instructions exécutées : {}", self.instructions_executed);
-        println!("Lectures mémoire       : {}", self.memory_reads);
-        println!("Écritures mémoire      : {}", self.memory_writes);
-        println!("Branchements totaux    : {}", self.branches_total);
-        println!("Branchements pris      : {}", self.branches_taken);
-        println!("---------------------------------------");
-        /// Affiche l'état des registres et des flags
-        pub fn dump_state(&self) {
-            println!("--- État CPU PrismChrono ---");
-            for i in 0..8 {
-                let reg_val = self.read_gpr(i).unwrap_or_default();
-                println!("R{}: {:?}", i, reg_val);
-            }
-            let pc = self.read_pc();
-            println!("PC: {:?}", pc);
-            let sp = self.read_sp();
-            println!("SP: {:?}", sp);
-            let flags = self.read_flags();
-            println!("Flags: ZF={} SF={} XF={} CF={} OF={}", flags.zf, flags.sf, flags.xf, flags.cf, flags.of);
-            println!("----------------------------");
-        }
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -91,7 +218,16 @@ impl Cpu {
         Cpu {
             state: ProcessorState::new(),
             memory: Memory::new(),
+            mmu: Mmu::new(),
+            caches: MemoryCaches::new(),
+            tvpu: TVPUHardware::new(),
+            pipeline: None,
+            branch_profiler: None,
+            opcode_counts: None,
+            semihost: None,
+            trace: None,
             halted: false,
+            cycles: 0,
             instructions_executed: 0,
             memory_reads: 0,
             memory_writes: 0,
@@ -105,7 +241,16 @@ impl Cpu {
         Cpu {
             state: ProcessorState::new(),
             memory: Memory::with_size(size),
+            mmu: Mmu::new(),
+            caches: MemoryCaches::new(),
+            tvpu: TVPUHardware::new(),
+            pipeline: None,
+            branch_profiler: None,
+            opcode_counts: None,
+            semihost: None,
+            trace: None,
             halted: false,
+            cycles: 0,
             instructions_executed: 0,
             memory_reads: 0,
             memory_writes: 0,
@@ -114,50 +259,140 @@ impl Cpu {
         }
     }
 
+    /// Affiche un rapport des métriques d'exécution
+    pub fn report_metrics(&self) {
+        println!("--- Rapport d'exécution PrismChrono ---");
+        println!("Instructions exécutées : {}", self.instructions_executed);
+        println!("Cycles simulés         : {}", self.cycles);
+        println!("Lectures mémoire       : {}", self.memory_reads);
+        println!("Écritures mémoire      : {}", self.memory_writes);
+        println!("Branchements totaux    : {}", self.branches_total);
+        println!("Branchements pris      : {}", self.branches_taken);
+        if let Some(counts) = &self.opcode_counts {
+            println!("Instructions par mnémonique:");
+            for (name, count) in counts.iter() {
+                println!("  {:<21}: {}", name, count);
+            }
+        }
+        if let Some(profiler) = &self.branch_profiler {
+            println!("{:<23}: {}", format!("Prédicteur {}", profiler.name()), profiler.totals());
+            for (pc, stats) in profiler.sites() {
+                println!("  0x{:<19x}: {}", pc, stats);
+            }
+        }
+        let (tlb_hits, tlb_misses, page_walks) = self.mmu.stats();
+        if page_walks > 0 {
+            println!("TLB (succès/échecs)    : {}/{}", tlb_hits, tlb_misses);
+            println!("Parcours de tables     : {}", page_walks);
+        }
+        let (icache, dcache) = self.caches.stats();
+        if let Some(stats) = icache {
+            println!("Cache I (succès/échecs): {}", stats);
+        }
+        if let Some(stats) = dcache {
+            println!("Cache D (succès/échecs): {}", stats);
+        }
+        if icache.is_some() || dcache.is_some() {
+            let stall = icache.map_or(0, |stats| stats.stall_cycles) + dcache.map_or(0, |stats| stats.stall_cycles);
+            println!("Cycles d'attente cache : {}", stall);
+        }
+        if self.tvpu.operation_count > 0 {
+            println!("Opérations vectorielles: {}", self.tvpu.operation_count);
+            println!("Cycles TVPU            : {}", self.tvpu.cycle_count);
+        }
+        if let Some(pipeline) = &self.pipeline {
+            let stats = pipeline.stats();
+            println!("Cycles pipeline        : {} (CPI {:.2})", pipeline.cycles(), pipeline.cpi());
+            println!("Bulles (total)         : {}", stats.bubbles());
+            println!("  load-use             : {}", stats.load_use_stalls);
+            println!("  multi-cycles         : {}", stats.multicycle_stalls);
+            println!("  attente mémoire      : {}", stats.memory_stalls);
+            println!("  vidages              : {}", stats.flush_bubbles);
+            println!("Mauvaises prédictions  : {}/{}", stats.mispredictions, stats.branches);
+            println!("Traps                  : {}", stats.traps);
+            println!("Forwarding EX/MEM      : {}", stats.forwards_ex_mem);
+            println!("Forwarding MEM/WB      : {}", stats.forwards_mem_wb);
+        }
+        println!("---------------------------------------");
+    }
+
+    /// Enregistre l'état complet de la machine dans un fichier (voir snapshot.rs)
+    pub fn save_snapshot(&self, path: &Path) -> Result<(), SnapshotError> {
+        fs::write(path, Snapshot::capture(self).encode()).map_err(|e| SnapshotError::Io(e.to_string()))
+    }
+
+    /// Restaure l'état complet de la machine depuis un fichier (voir `Snapshot::restore`)
+    pub fn load_snapshot(&mut self, path: &Path) -> Result<(), SnapshotError> {
+        let bytes = fs::read(path).map_err(|e| SnapshotError::Io(e.to_string()))?;
+        Snapshot::decode(&bytes)?.restore(self)
+    }
+
+    /// Affiche l'état des registres et des flags
+    pub fn dump_state(&self) {
+        println!("--- État CPU PrismChrono ---");
+        for i in 0..8 {
+            if let Ok(reg) = Register::from_index(i) {
+                println!("{}: {}", reg, self.state.read_gpr(reg));
+            }
+        }
+        println!("PC: {}", self.state.read_pc());
+        println!("SP: {}", self.state.read_sp());
+        let flags = self.state.read_flags();
+        println!(
+            "Flags: ZF={} SF={} XF={} CF={} OF={}",
+            flags.zf, flags.sf, flags.xf, flags.cf, flags.of
+        );
+        println!("----------------------------");
+    }
+
     /// Récupère l'instruction à l'adresse pointée par le PC
+    /// Le PC est une adresse virtuelle, traduite par la MMU si la pagination est active
     pub fn fetch(&self) -> Result<[Trit; 12], ExecuteError> {
         // Récupérer la valeur du PC
         let pc_value = self.state.read_pc();
 
-        // Extraire l'adresse à partir du PC (les 16 trits de poids faible)
-        let mut pc_addr: Address = 0;
-
-        // Extraire les 5 premiers trytes (15 trits)
-        for i in 0..5 {
-            if let Some(tryte) = pc_value.tryte(i) {
-                match tryte {
-                    Tryte::Digit(val) => {
-                        // Convertir la valeur du tryte en adresse
-                        // et la décaler à la position appropriée
-                        pc_addr += (*val as Address) * (3_i32.pow((i * 3) as u32) as Address);
-                    }
-                    _ => return Err(ExecuteError::InvalidAddress),
-                }
-            }
+        // Convertir le PC en adresse mémoire
+        let pc_addr = word_to_address(&pc_value)?;
+        if !self.paging_active() {
+            let instr_trits = self.fetch_at(pc_addr)?;
+            self.caches.fetch(pc_addr, pc_addr + 3);
+            return Ok(instr_trits);
         }
+        if pc_addr % 4 != 0 {
+            return Err(ExecuteError::InvalidAddress); // Adresse non alignée sur 4 trytes
+        }
+        let physical = self.translate_span(pc_addr, 4, AccessType::Fetch)?;
+        let instr_trits = self.fetch_trytes(&physical)?;
+        self.caches.fetch(physical[0], physical[3]);
+        Ok(instr_trits)
+    }
 
+    /// Récupère les 12 trits de l'instruction stockée à une adresse physique donnée
+    /// Utilisé par `fetch` et par le débogueur pour désassembler sans exécuter (sans passer par le cache)
+    pub fn fetch_at(&self, pc_addr: Address) -> Result<[Trit; 12], ExecuteError> {
         // Vérifier que l'adresse est valide et alignée sur 4 trytes
         if !is_valid_address(pc_addr) {
             return Err(ExecuteError::InvalidAddress);
         }
-        if pc_addr % 4 != 0 {
+        if !pc_addr.is_multiple_of(4) {
             return Err(ExecuteError::InvalidAddress); // Adresse non alignée sur 4 trytes
         }
 
-        // Lire 4 trytes consécutifs (12 trits) à partir de l'adresse PC
+        self.fetch_trytes(&[pc_addr, pc_addr + 1, pc_addr + 2, pc_addr + 3])
+    }
+
+    /// Lit les 4 trytes (12 trits) d'une instruction à leurs adresses physiques
+    fn fetch_trytes(&self, addresses: &[Address]) -> Result<[Trit; 12], ExecuteError> {
         let mut instr_trits = [Trit::Z; 12];
         let mut trit_index = 0;
 
-        for i in 0..4 {
-            let tryte = self
-                .memory
-                .read_tryte(pc_addr + i)
-                .map_err(ExecuteError::from)?;
+        for &addr in addresses {
+            let tryte = self.memory.read_tryte(addr).map_err(ExecuteError::from)?;
 
             // Extraire les 3 trits du tryte et les ajouter à l'instruction
             let tryte_trits = tryte.to_trits();
-            for j in 0..3 {
-                instr_trits[trit_index] = tryte_trits[j];
+            for trit in tryte_trits {
+                instr_trits[trit_index] = trit;
                 trit_index += 1;
             }
         }
@@ -165,28 +400,196 @@ impl Cpu {
         Ok(instr_trits)
     }
 
+    /// Indique si les accès du niveau de privilège courant sont traduits par la MMU
+    pub fn paging_active(&self) -> bool {
+        Mmu::is_active(&self.state.satp_t, self.state.current_privilege)
+    }
+
+    /// Traduit une adresse virtuelle au niveau de privilège courant
+    fn translate(&self, addr: Address, access: AccessType) -> Result<Address, ExecuteError> {
+        self.mmu
+            .translate(&self.memory, &self.state.satp_t, self.state.current_privilege, addr, access)
+            .map_err(ExecuteError::from)
+    }
+
+    /// Traduit `len` trytes consécutifs: adresse physique de chacun
+    fn translate_span(&self, addr: Address, len: usize, access: AccessType) -> Result<Vec<Address>, ExecuteError> {
+        self.mmu
+            .translate_span(&self.memory, &self.state.satp_t, self.state.current_privilege, addr, len, access)
+            .map_err(ExecuteError::from)
+    }
+
+    /// Traduit une plage qui doit rester physiquement contiguë (accès vectoriels de la TVPU)
+    /// Une plage à cheval sur deux pages physiques non consécutives n'est pas supportée
+    pub(crate) fn translate_contiguous(&self, addr: Address, len: usize, access: AccessType) -> Result<Address, ExecuteError> {
+        let physical = self.translate_span(addr, len, access)?;
+        if physical.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+            return Err(ExecuteError::Unimplemented);
+        }
+        Ok(physical[0])
+    }
+
+    /// Lit un Mot à une adresse virtuelle traduite
+    /// Une page ne contenant pas un nombre entier de Mots, un Mot peut être réparti sur deux
+    /// pages physiques, ou ne pas être aligné physiquement: il est alors lu tryte par tryte
+    fn read_word_paged(&self, addr: Address) -> Result<Word, ExecuteError> {
+        if !addr.is_multiple_of(8) {
+            return Err(ExecuteError::MemoryError(MemoryError::Misaligned));
+        }
+        let physical = self.translate_span(addr, 8, AccessType::Load)?;
+        if is_word_span(&physical) {
+            return self.read_word_physical(physical[0]);
+        }
+        let mut word = Word::zero();
+        for (tryte, &pa) in word.trytes_mut().iter_mut().zip(&physical) {
+            *tryte = self.memory.read_tryte(pa)?;
+        }
+        self.caches.data(&self.memory, physical[0], physical[7], CacheAccess::Read);
+        Ok(word)
+    }
+
+    /// Écrit un Mot à une adresse virtuelle traduite (voir `read_word_paged`)
+    fn write_word_paged(&mut self, addr: Address, value: Word) -> Result<(), ExecuteError> {
+        if !addr.is_multiple_of(8) {
+            return Err(ExecuteError::MemoryError(MemoryError::Misaligned));
+        }
+        let physical = self.translate_span(addr, 8, AccessType::Store)?;
+        if is_word_span(&physical) {
+            return self.write_word_physical(physical[0], value);
+        }
+        for (tryte, &pa) in value.trytes().iter().zip(&physical) {
+            self.memory.write_tryte(pa, *tryte)?;
+        }
+        self.caches.data(&self.memory, physical[0], physical[7], CacheAccess::Write);
+        Ok(())
+    }
+
+    /// Lit un Mot à une adresse physique alignée, au travers du cache de données
+    fn read_word_physical(&self, addr: Address) -> Result<Word, ExecuteError> {
+        let word = self.memory.read_word(addr)?;
+        self.caches.data(&self.memory, addr, addr + 7, CacheAccess::Read);
+        Ok(word)
+    }
+
+    /// Écrit un Mot à une adresse physique alignée, au travers du cache de données
+    fn write_word_physical(&mut self, addr: Address, value: Word) -> Result<(), ExecuteError> {
+        self.memory.write_word(addr, value)?;
+        self.caches.data(&self.memory, addr, addr + 7, CacheAccess::Write);
+        Ok(())
+    }
+
     /// Exécute une étape du cycle d'instruction (fetch-decode-execute)
+    /// Les fautes synchrones sont converties en traps (voir `raise_exception`): seules
+    /// les fautes au vecteur de trap même et les événements de l'hôte remontent à l'appelant
     pub fn step(&mut self) -> Result<(), ExecuteError> {
         // Si le processeur est arrêté, ne rien faire
         if self.halted {
             return Err(ExecuteError::Halted);
         }
 
+        // Une instruction dure un cycle, une opération vectorielle sa latence TVPU, plus
+        // les cycles d'attente des caches; les périphériques avancent du même nombre de cycles
+        let pc = self.state.read_pc().to_i64();
+        let snapshot = self.trace.is_some().then(|| TraceSnapshot::capture(self));
+        let tvpu_cycles = self.tvpu.cycle_count;
+        let outcome = self.step_instruction();
+        let ex_cycles = self.tvpu.cycle_count - tvpu_cycles;
+        let stall = self.caches.take_stall();
+        let cycles = ex_cycles.max(1) + stall;
+        self.cycles += cycles;
+        self.memory.tick(cycles);
+
+        // Le modèle du pipeline observe l'instruction sans modifier l'exécution
+        if let (Some(pipeline), Ok(outcome)) = (self.pipeline.as_mut(), &outcome) {
+            match outcome {
+                StepOutcome::Retired(instruction) => {
+                    let next_pc = self.state.read_pc().to_i64();
+                    pipeline.retire(pc, instruction, next_pc, ex_cycles, stall);
+                }
+                StepOutcome::Trap => pipeline.trap(stall),
+            }
+        }
+
+        // Trace: effets de l'étape (les étapes qui arrêtent l'exécution ne sont pas tracées)
+        match (snapshot, &outcome) {
+            (Some(snapshot), Ok(step)) => {
+                let event = match step {
+                    StepOutcome::Retired(instruction) => TraceEvent::Instruction(instruction.clone()),
+                    StepOutcome::Trap => TraceEvent::Trap,
+                };
+                let record = snapshot.finish(self, event);
+                if let Some(sink) = self.trace.as_mut() {
+                    sink.record(&record);
+                }
+            }
+            (Some(_), Err(_)) => {
+                self.memory.take_write_log();
+            }
+            (None, _) => {}
+        }
+
+        outcome.map(|_| ())
+    }
+
+    /// Recopie dans mip_t les lignes d'interruption levées par les périphériques
+    /// Les trits timer et externe de mip_t reflètent le matériel: le logiciel les
+    /// acquitte auprès du périphérique (MTIMECMP, CLAIM), pas en écrivant mip_t
+    fn sync_interrupt_lines(&mut self) {
+        for (interrupt, line) in [
+            (Interrupt::Timer, InterruptLine::Timer),
+            (Interrupt::External, InterruptLine::External),
+        ] {
+            let level = if self.memory.interrupt_pending(line) { Trit::P } else { Trit::Z };
+            self.state.mip_t.set_trit(flag_trit(interrupt.to_code()).unwrap(), level);
+        }
+    }
+
+    /// Exécute une instruction, ou prend l'interruption en attente à sa place
+    fn step_instruction(&mut self) -> Result<StepOutcome, ExecuteError> {
+        // 0. Interruptions asynchrones, échantillonnées à la frontière d'instruction:
+        // mepc_t/sepc_t désigne l'instruction qui n'a pas été exécutée
+        self.sync_interrupt_lines();
+        if let Some(interrupt) = self.pending_interrupt() {
+            self.take_interrupt(interrupt);
+            return Ok(StepOutcome::Trap);
+        }
+
         // 1. Récupérer l'instruction (fetch)
-        let instr_trits = self.fetch()?;
+        let instr_trits = match self.fetch() {
+            Ok(trits) => trits,
+            Err(error) => return self.raise_exception(error, false).map(|()| StepOutcome::Trap),
+        };
 
         // 2. Décoder l'instruction
-        let instruction = crate::cpu::decode::decode(instr_trits)
-            .map_err(|_| ExecuteError::InvalidInstruction)?;
+        let instruction = match crate::cpu::decode::decode(instr_trits) {
+            Ok(instruction) => instruction,
+            Err(_) => {
+                return self
+                    .raise_exception(ExecuteError::InvalidInstruction, false)
+                    .map(|()| StepOutcome::Trap)
+            }
+        };
 
-        // Incrémenter le compteur d'instructions exécutées
+        // Incrémenter le compteur d'instructions exécutées (et celui de son mnémonique)
         self.instructions_executed += 1;
+        if let Some(counts) = self.opcode_counts.as_mut() {
+            counts.record(&instruction);
+        }
 
         // Sauvegarder le PC actuel avant exécution
         let old_pc = self.state.read_pc();
+        let load = reads_memory(&instruction);
+        let store = writes_memory(&instruction);
 
         // 3. Exécuter l'instruction
-        self.execute(instruction)?;
+        // Exception précise: mepc_t désigne l'instruction fautive, même si elle a déjà modifié le PC
+        let retired = instruction.clone();
+        if let Err(error) = self.execute(instruction) {
+            self.state.write_pc(old_pc);
+            return self.raise_exception(error, store).map(|()| StepOutcome::Trap);
+        }
+        self.memory_reads += load as u64;
+        self.memory_writes += store as u64;
 
         // 4. Incrémenter le PC (sauf si modifié par l'instruction)
         // Les instructions de saut (JAL, JALR) et de branchement modifient déjà le PC
@@ -196,21 +599,83 @@ impl Cpu {
 
             // Si le PC n'a pas été modifié par l'instruction, l'incrémenter de 4
             if current_pc == old_pc {
-                // Créer un Word pour l'incrément de PC (4 trytes)
-                let mut inc_word = Word::zero();
-                if let Some(tryte) = inc_word.tryte_mut(0) {
-                    *tryte = Tryte::Digit(17); // 4 en ternaire équilibré (13+4=17)
-                }
+                self.advance_pc();
+            }
+        }
+
+        Ok(StepOutcome::Retired(retired))
+    }
+
+    /// Convertit une faute synchrone en trap vers le gestionnaire du système invité
+    /// Le PC doit désigner l'instruction fautive. Pour une faute au vecteur de trap même,
+    /// ou pour une erreur sans cause de trap (HALT, EBREAK), l'erreur d'origine est retournée.
+    fn raise_exception(&mut self, error: ExecuteError, store: bool) -> Result<(), ExecuteError> {
+        let Some(cause) = error.trap_cause(store) else {
+            return Err(error);
+        };
+
+        match self.handle_trap(cause) {
+            Err(ExecuteError::UnhandledTrap(_)) => Err(error),
+            result => result,
+        }
+    }
 
-                // Calculer PC+4
-                let (new_pc, _, _) = add_24_trits(current_pc, inc_word, Trit::Z);
+    /// Soumet l'issue d'un branchement en cours d'exécution au prédicteur évalué
+    /// Appelé par BRANCH et BRANCH3 avant la mise à jour du PC
+    pub(crate) fn observe_branch(&mut self, outcome: Trit) {
+        if let Some(profiler) = self.branch_profiler.as_mut() {
+            let pc = self.state.read_pc().to_i64() as u32;
+            profiler.observe(pc, outcome);
+        }
+    }
 
-                // Mettre à jour le PC
-                self.state.write_pc(new_pc);
+    /// Avance le PC à l'instruction suivante (PC + 4)
+    /// Utilisé par `step` et par le débogueur pour reprendre après un EBREAK
+    pub fn advance_pc(&mut self) {
+        // Calcul entier exact, réduit modulo 24^8 comme un compteur matériel; fetch refuse
+        // ensuite une adresse hors de la mémoire.
+        let next_pc = self.state.read_pc().to_i64() + 4;
+        self.state.write_pc(Word::from_i64(next_pc));
+    }
+
+    /// Exécute des instructions jusqu'à HALT, EBREAK ou la limite d'instructions
+    pub fn run(&mut self, max_steps: Option<u64>) -> Result<StopReason, ExecuteError> {
+        let mut steps: u64 = 0;
+        loop {
+            if max_steps.is_some_and(|max| steps >= max) {
+                return Ok(StopReason::StepLimit);
+            }
+
+            match self.step() {
+                Ok(()) if self.halted => return Ok(self.halt_reason()),
+                Ok(()) => {}
+                Err(ExecuteError::Halted) => {
+                    self.halted = true;
+                    return Ok(StopReason::Halted);
+                }
+                Err(ExecuteError::Breakpoint) => return Ok(StopReason::Breakpoint),
+                Err(e) => return Err(e),
             }
+            steps += 1;
         }
+    }
 
-        Ok(())
+    /// Raison de l'arrêt du processeur: EXIT du semihosting ou HALT
+    fn halt_reason(&self) -> StopReason {
+        match self.semihost.as_ref().and_then(Semihost::exit_status) {
+            Some(status) => StopReason::Exited(status),
+            None => StopReason::Halted,
+        }
+    }
+
+    /// Sert un ECALL par le semihosting (voir semihost.rs)
+    fn execute_semihost_call(&mut self) -> Result<(), ExecuteError> {
+        let Some(mut semihost) = self.semihost.take() else {
+            return Err(ExecuteError::InvalidOperation);
+        };
+        let result = semihost.call(self);
+        self.semihost = Some(semihost);
+        result
     }
 
     /// Exécute une instruction décodée
@@ -234,6 +699,15 @@ impl Cpu {
             
             Instruction::Store { rs1, rs2, offset } => 
                 self.execute_store(rs1, rs2, offset.try_into().unwrap()),
+
+            Instruction::LoadT { rd, rs1, offset } =>
+                self.execute_load_tryte(rd, rs1, offset.try_into().unwrap()),
+
+            Instruction::LoadTU { rd, rs1, offset } =>
+                self.execute_load_tryte_unsigned(rd, rs1, offset.try_into().unwrap()),
+
+            Instruction::StoreT { rs1, rs2, offset } =>
+                self.execute_store_tryte(rs1, rs2, offset.try_into().unwrap()),
             
             // Format B: Branch
             Instruction::Branch { rs1, cond, offset } => 
@@ -241,9 +715,11 @@ impl Cpu {
             
             Instruction::Jump { rd, offset } => self.execute_jump(rd, offset),
             Instruction::Call { rd, offset } => self.execute_call(rd, offset),
-            Instruction::CsrRw { csr, rs1, rd } => self.execute_csrrw(rd, csr as i8, rs1),
-            Instruction::CsrRs { csr, rs1, rd } => self.execute_csrrs(rd, csr as i8, rs1),
+            Instruction::CsrRw { csr, rs1, rd } => self.execute_csrrw(rd, csr, rs1),
+            Instruction::CsrRs { csr, rs1, rd } => self.execute_csrrs(rd, csr, rs1),
             Instruction::CsrRc { csr, rs1, rd } => self.execute_csrrc(rd, csr as i8, rs1),
+            // ECALL (fonction 2) servi par l'hôte si le semihosting est activé
+            Instruction::System { func: 2 } if self.semihost.is_some() => self.execute_semihost_call(),
             Instruction::System { func } => self.execute_system(func),
             Instruction::Lui { rd, imm } => self.execute_lui(rd, imm),
             Instruction::Auipc { rd, imm } => self.execute_auipc(rd, imm),
@@ -254,6 +730,29 @@ impl Cpu {
             
             // Instructions spéciales
             Instruction::MRet => self.execute_mret(),
+
+            // Extension ternaire
+            Instruction::TernaryOp { op, rs1, rs2, rd } => self.execute_ternary(op, rs1, rs2, rd),
+            Instruction::TernaryShift { op, rs1, rd, imm } =>
+                self.execute_ternary_shift(op, rs1, rd, imm as i32),
+            Instruction::Branch3 { rs1, offset_neg, offset_zero, offset_pos } =>
+                self.execute_branch3(rs1, offset_neg as i32, offset_zero as i32, offset_pos as i32),
+            Instruction::LoadT3 { rd, rs1, offset } => self.execute_load_tryte3(rd, rs1, offset as i32),
+            Instruction::StoreT3 { rs1, rs2, offset } => self.execute_store_tryte3(rs1, rs2, offset as i32),
+            Instruction::TMemCpy { rd, rs1, rs2 } => self.execute_tmemcpy(rd, rs1, rs2),
+            Instruction::TMemSet { rd, rs1, rs2 } => self.execute_tmemset(rd, rs1, rs2),
+            Instruction::MAddW { rd, rs1, rs2, rs3 } => self.execute_maddw(rd, rs1, rs2, rs3),
+            Instruction::MSubW { rd, rs1, rs2, rs3 } => self.execute_msubw(rd, rs1, rs2, rs3),
+            Instruction::TSel { rd, rs1, rs2, rs3 } => self.execute_tsel(rd, rs1, rs2, rs3),
+            Instruction::SpecialStateOp { op, rs1, rd } => self.execute_special_state(op, rs1, rd),
+            Instruction::Base24Op { op, rs1, rs2, rd } => self.execute_base24(op, rs1, rs2, rd),
+
+            // Extension vectorielle (TVPU)
+            vector @ (Instruction::VectorOp { .. }
+            | Instruction::VectorDot { .. }
+            | Instruction::VectorReduce { .. }
+            | Instruction::VectorLoad { .. }
+            | Instruction::VectorStore { .. }) => self.execute_tvpu_instruction(vector),
         }
     }
 }
@@ -293,23 +792,31 @@ impl CpuState for Cpu {
     }
 
     fn read_tryte(&self, addr: Address) -> Result<Tryte, ExecuteError> {
-        self.memory.read_tryte(addr).map_err(ExecuteError::from)
+        let addr = self.translate(addr, AccessType::Load)?;
+        let tryte = self.memory.read_tryte(addr)?;
+        self.caches.data(&self.memory, addr, addr, CacheAccess::Read);
+        Ok(tryte)
     }
 
     fn write_tryte(&mut self, addr: Address, value: Tryte) -> Result<(), ExecuteError> {
-        self.memory
-            .write_tryte(addr, value)
-            .map_err(ExecuteError::from)
+        let addr = self.translate(addr, AccessType::Store)?;
+        self.memory.write_tryte(addr, value)?;
+        self.caches.data(&self.memory, addr, addr, CacheAccess::Write);
+        Ok(())
     }
 
     fn read_word(&self, addr: Address) -> Result<Word, ExecuteError> {
-        self.memory.read_word(addr).map_err(ExecuteError::from)
+        if self.paging_active() {
+            return self.read_word_paged(addr);
+        }
+        self.read_word_physical(addr)
     }
 
     fn write_word(&mut self, addr: Address, value: Word) -> Result<(), ExecuteError> {
-        self.memory
-            .write_word(addr, value)
-            .map_err(ExecuteError::from)
+        if self.paging_active() {
+            return self.write_word_paged(addr, value);
+        }
+        self.write_word_physical(addr, value)
     }
 
     fn state_read_csr(&self, csr: i8) -> Result<Word, RegisterError> {
@@ -317,6 +824,10 @@ impl CpuState for Cpu {
     }
 
     fn state_write_csr(&mut self, csr: i8, value: Word) -> Result<(), RegisterError> {
+        // Changer satp_t invalide les traductions en cache (tient lieu de SFENCE.VMA)
+        if csr == 12 {
+            self.mmu.flush();
+        }
         self.state.write_csr(csr, value)
     }
 
@@ -325,52 +836,7 @@ impl CpuState for Cpu {
     }
 
     fn state_clear_csr(&mut self, csr: i8, value: Word) -> Result<(), RegisterError> {
-        // Lire la valeur actuelle du CSR
-        let current = self.state_read_csr(csr)?;
-        
-        // Créer une nouvelle valeur en effectuant un AND avec la négation de value
-        let mut result = Word::zero();
-        
-        // Pour chaque tryte, effectuer l'opération de clear (AND avec NOT value)
-        for i in 0..8 {
-            if let (Some(curr_tryte), Some(val_tryte)) = (current.tryte(i), value.tryte(i)) {
-                // Calculer la négation du tryte de value
-                let not_val = match val_tryte {
-                    Tryte::Digit(v) => {
-                        if *v == 0 {
-                            Tryte::Digit(0)
-                        } else {
-                            // Calculer la négation (attention, v est un u8, pas un i8)
-                            let neg_v = 23 - *v; // Négation dans l'intervalle [0, 23]
-                            Tryte::Digit(neg_v)
-                        }
-                    },
-                    _ => Tryte::Digit(0),
-                };
-                
-                // Effectuer l'opération AND entre le tryte actuel et la négation
-                let result_tryte = match (curr_tryte, &not_val) {
-                    (Tryte::Digit(a), Tryte::Digit(b)) => {
-                        if *b == 0 {
-                            // Si b est zéro, mettre à zéro
-                            Tryte::Digit(0)
-                        } else {
-                            // Sinon, garder a
-                            Tryte::Digit(*a)
-                        }
-                    },
-                    _ => Tryte::Digit(0),
-                };
-                
-                // Mettre à jour le tryte dans le résultat
-                if let Some(res_tryte) = result.tryte_mut(i) {
-                    *res_tryte = result_tryte;
-                }
-            }
-        }
-        
-        // Écrire la nouvelle valeur dans le CSR
-        self.state_write_csr(csr, result)
+        self.state.clear_csr(csr, value)
     }
 
     fn state_get_privilege(&self) -> PrivilegeLevel {
@@ -393,3 +859,408 @@ impl CpuState for Cpu {
         self.state.set_trap_cause(cause);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::execute_system::DelegationOperations;
+    use crate::branch_predictor::{BranchSiteStats, StaticPredictor};
+    use crate::cpu::isa::{AluOp, BranchCondition};
+    use crate::cache::{CacheConfig, WritePolicy};
+    use crate::cpu::registers::{MSTATUS_MIE, MSTATUS_MPIE};
+    use crate::devices::timer::{MTIMECMP, TIMER_BASE, Timer};
+    use crate::loader::instruction_to_trytes;
+    use crate::memory::MemoryWrite;
+    use crate::mmu::{PAGE_SIZE, PTE_SIZE, PagePermissions, PageTableEntry, PagingMode, Satp};
+    use crate::pipeline::BRANCH_PENALTY;
+    use crate::trace::TraceRecord;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const PROGRAM_ADDR: i32 = 8;
+    const MACHINE_HANDLER: i32 = 400;
+    const SUPERVISOR_HANDLER: i32 = 600;
+
+    /// CPU exécutant `instruction` à PROGRAM_ADDR, avec un MRET au gestionnaire Machine
+    fn cpu_with(instruction: Instruction) -> Cpu {
+        let mut cpu = Cpu::with_memory_size(1024);
+        for (addr, instr) in [(PROGRAM_ADDR, instruction), (MACHINE_HANDLER, Instruction::MRet)] {
+            let trits = prismchrono_isa::encode(&instr).unwrap();
+            for (i, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                cpu.memory.write_tryte(addr as Address + i, *tryte).unwrap();
+            }
+        }
+        cpu.state.write_pc(Word::from(PROGRAM_ADDR));
+        cpu.state.write_gpr(Register::R1, Word::from(7));
+        cpu.state.write_gpr(Register::R2, Word::from(2000)); // Hors de la mémoire
+        cpu.state.write_gpr(Register::R3, Word::from(4)); // Non aligné pour un Mot
+        cpu.state.write_gpr(Register::R6, Word::zero());
+        // R5 reste non initialisé (UNDEF)
+        cpu
+    }
+
+    #[test]
+    fn test_faults_trap_to_handler() {
+        let cases = [
+            (Instruction::Load { rd: Register::R1, rs1: Register::R2, offset: 0 }, TrapCause::LoadFault),
+            (Instruction::Store { rs1: Register::R2, rs2: Register::R1, offset: 0 }, TrapCause::StoreFault),
+            (Instruction::Load { rd: Register::R1, rs1: Register::R3, offset: 0 }, TrapCause::Misaligned),
+            (Instruction::Load { rd: Register::R1, rs1: Register::R5, offset: 0 }, TrapCause::SpecialState),
+            (
+                Instruction::AluReg { op: AluOp::Div, rs1: Register::R1, rs2: Register::R6, rd: Register::R4 },
+                TrapCause::DivByZero,
+            ),
+        ];
+
+        for (instruction, cause) in cases {
+            let mut cpu = cpu_with(instruction.clone());
+            cpu.state.write_csr(1, Word::from(MACHINE_HANDLER)).unwrap();
+
+            assert_eq!(cpu.step(), Ok(()), "{:?}", instruction);
+            assert_eq!(cpu.state.read_pc().to_i64(), MACHINE_HANDLER as i64);
+            assert_eq!(cpu.state.read_csr(2).unwrap().to_i64(), PROGRAM_ADDR as i64);
+            assert_eq!(cpu.state.get_trap_cause(), Some(cause));
+            assert_eq!(cpu.state.current_privilege, PrivilegeLevel::Machine);
+        }
+    }
+
+    #[test]
+    fn test_trace_records_step_effects() {
+        struct Shared(Rc<RefCell<Vec<TraceRecord>>>);
+        impl TraceSink for Shared {
+            fn record(&mut self, record: &TraceRecord) {
+                self.0.borrow_mut().push(record.clone());
+            }
+        }
+        let traced = |instruction: Instruction| {
+            let records = Rc::new(RefCell::new(Vec::new()));
+            let mut cpu = cpu_with(instruction);
+            cpu.trace = Some(Box::new(Shared(records.clone())));
+            (cpu, records)
+        };
+
+        // Écriture mémoire d'un Mot
+        let (mut cpu, records) = traced(Instruction::Store { rs1: Register::R6, rs2: Register::R1, offset: 0 });
+        cpu.step().unwrap();
+        assert_eq!(records.borrow()[0].memory, vec![MemoryWrite::Word(0, Word::from(7))]);
+        assert_eq!(records.borrow()[0].next_pc, PROGRAM_ADDR as i64 + 4);
+
+        // Faute en mode User: trap vers le mode Machine, puis MRET vers l'instruction fautive
+        let (mut cpu, records) = traced(Instruction::Load { rd: Register::R1, rs1: Register::R2, offset: 0 });
+        cpu.state.write_csr(1, Word::from(MACHINE_HANDLER)).unwrap();
+        cpu.state.current_privilege = PrivilegeLevel::User;
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+
+        let records = records.borrow();
+        assert_eq!(records[0].event, TraceEvent::Trap);
+        assert_eq!(records[0].next_pc, MACHINE_HANDLER as i64);
+        assert_eq!(records[0].privilege, Some(PrivilegeLevel::Machine));
+        assert!(records[0].csrs.contains(&(2, Word::from(PROGRAM_ADDR))));
+        assert!(records[0].registers.is_empty());
+        assert_eq!(records[1].event, TraceEvent::Instruction(Instruction::MRet));
+        assert_eq!(records[1].next_pc, PROGRAM_ADDR as i64);
+        assert_eq!(records[1].privilege, Some(PrivilegeLevel::User));
+    }
+
+    #[test]
+    fn test_zero_vector_and_fault_at_vector() {
+        let mut cpu = cpu_with(Instruction::Load { rd: Register::R1, rs1: Register::R3, offset: 0 });
+
+        // mtvec_t nul: l'adresse 0 est un vecteur comme un autre
+        assert_eq!(cpu.step(), Ok(()));
+        assert_eq!(cpu.state.read_pc().to_i64(), 0);
+        assert_eq!(cpu.state.read_csr(2).unwrap().to_i64(), PROGRAM_ADDR as i64);
+        assert_eq!(cpu.state.get_trap_cause(), Some(TrapCause::Misaligned));
+
+        // Aucun gestionnaire à l'adresse 0: la faute du vecteur même remonte à l'appelant,
+        // sans modifier l'état
+        assert!(cpu.step().is_err());
+        assert_eq!(cpu.state.read_pc().to_i64(), 0);
+        assert_eq!(cpu.state.read_csr(2).unwrap().to_i64(), PROGRAM_ADDR as i64);
+        assert_eq!(cpu.state.get_trap_cause(), Some(TrapCause::Misaligned));
+    }
+
+    #[test]
+    fn test_trap_return_and_delegation() {
+        let load_fault = Instruction::Load { rd: Register::R1, rs1: Register::R2, offset: 0 };
+
+        // Faute en mode User traitée en mode Machine, puis MRET vers l'instruction fautive
+        let mut cpu = cpu_with(load_fault.clone());
+        cpu.state.write_csr(1, Word::from(MACHINE_HANDLER)).unwrap();
+        cpu.state.current_privilege = PrivilegeLevel::User;
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.current_privilege, PrivilegeLevel::Machine);
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.read_pc().to_i64(), PROGRAM_ADDR as i64);
+        assert_eq!(cpu.state.current_privilege, PrivilegeLevel::User);
+
+        // Faute déléguée par medeleg_t au mode Supervisor
+        let mut cpu = cpu_with(load_fault);
+        cpu.state.write_csr(1, Word::from(MACHINE_HANDLER)).unwrap();
+        cpu.state.write_csr(5, Word::from(SUPERVISOR_HANDLER)).unwrap();
+        cpu.set_trap_delegation(TrapCause::LoadFault, true).unwrap();
+        cpu.state.current_privilege = PrivilegeLevel::User;
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.read_pc().to_i64(), SUPERVISOR_HANDLER as i64);
+        assert_eq!(cpu.state.read_csr(6).unwrap().to_i64(), PROGRAM_ADDR as i64);
+        assert_eq!(cpu.state.current_privilege, PrivilegeLevel::Supervisor);
+    }
+
+    #[test]
+    fn test_timer_interrupt() {
+        let mut cpu = cpu_with(Instruction::Nop);
+        cpu.memory.map_device(TIMER_BASE, Box::new(Timer::new())).unwrap();
+        cpu.memory.write_word(TIMER_BASE + MTIMECMP, Word::from(1)).unwrap();
+        cpu.state.write_csr(1, Word::from(MACHINE_HANDLER)).unwrap();
+        cpu.state.mie_t.set_trit(flag_trit(Interrupt::Timer.to_code()).unwrap(), Trit::P);
+        cpu.state.mstatus_t.set_trit(MSTATUS_MIE, Trit::P);
+
+        // Le NOP s'exécute, puis l'interruption est prise à la frontière suivante
+        cpu.step().unwrap();
+        let resume = cpu.state.read_pc();
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.read_pc().to_i64(), MACHINE_HANDLER as i64);
+        assert_eq!(cpu.state.read_csr(2).unwrap(), resume);
+        assert_eq!(cpu.state.read_csr(3).unwrap(), Word::from_bal3(-1));
+        assert_eq!(cpu.state.mstatus_t.get_trit(MSTATUS_MIE), Trit::Z);
+        assert_eq!(cpu.state.mstatus_t.get_trit(MSTATUS_MPIE), Trit::P);
+        assert_eq!(cpu.cycles, 2);
+
+        // MIE masque l'interruption dans le gestionnaire: MRET s'exécute et la réautorise
+        cpu.memory.write_word(TIMER_BASE + MTIMECMP, Word::from(100)).unwrap();
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.read_pc(), resume);
+        assert_eq!(cpu.state.mstatus_t.get_trit(MSTATUS_MIE), Trit::P);
+
+        // Déléguée par mideleg_t, l'interruption levée en mode User va au mode Supervisor,
+        // mais reste masquée en mode Machine
+        let mut cpu = cpu_with(Instruction::Nop);
+        cpu.memory.map_device(TIMER_BASE, Box::new(Timer::new())).unwrap();
+        cpu.memory.write_word(TIMER_BASE + MTIMECMP, Word::from(0)).unwrap();
+        cpu.state.write_csr(5, Word::from(SUPERVISOR_HANDLER)).unwrap();
+        cpu.state.mie_t.set_trit(flag_trit(Interrupt::Timer.to_code()).unwrap(), Trit::P);
+        cpu.set_interrupt_delegation(Interrupt::Timer.to_code(), true).unwrap();
+        cpu.state.mstatus_t.set_trit(MSTATUS_MIE, Trit::P);
+        cpu.step().unwrap();
+        let resume = cpu.state.read_pc();
+        assert_ne!(resume.to_i64(), SUPERVISOR_HANDLER as i64);
+        cpu.state.current_privilege = PrivilegeLevel::User;
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.read_pc().to_i64(), SUPERVISOR_HANDLER as i64);
+        assert_eq!(cpu.state.read_csr(6).unwrap(), resume);
+        assert_eq!(cpu.state.read_csr(7).unwrap(), Word::from_bal3(-1));
+        assert_eq!(cpu.state.current_privilege, PrivilegeLevel::Supervisor);
+    }
+
+    /// Écrit une entrée de table de pages (tryte par tryte: une table n'est pas alignée sur 8)
+    fn write_pte(cpu: &mut Cpu, table: usize, index: usize, ppn: i64, permissions: PagePermissions) {
+        let entry = PageTableEntry { ppn, valid: true, permissions }.to_word();
+        for (i, tryte) in entry.trytes().iter().enumerate() {
+            cpu.memory.write_tryte(table * PAGE_SIZE + index * PTE_SIZE + i, *tryte).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_paged_user_program() {
+        const DATA_VA: i32 = PAGE_SIZE as i32 + 7; // Mot aligné de la page virtuelle 1
+        let program = [
+            Instruction::Load { rd: Register::R3, rs1: Register::R1, offset: 0 },
+            Instruction::Store { rs1: Register::R1, rs2: Register::R3, offset: 0 },
+        ];
+
+        // Code en page physique 10 (X, U), données en page 11 (R, U), tables en pages 1 à 3
+        let mut cpu = Cpu::with_memory_size(12 * PAGE_SIZE);
+        let pointer = PagePermissions { read: false, write: false, execute: false, user: false };
+        write_pte(&mut cpu, 1, 0, 2, pointer);
+        write_pte(&mut cpu, 2, 0, 3, pointer);
+        write_pte(&mut cpu, 3, 0, 10, PagePermissions { execute: true, user: true, ..pointer });
+        write_pte(&mut cpu, 3, 1, 11, PagePermissions { read: true, user: true, ..pointer });
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                cpu.memory.write_tryte(10 * PAGE_SIZE + PROGRAM_ADDR as usize + 4 * i + j, *tryte).unwrap();
+            }
+        }
+        // Le Mot n'est pas aligné physiquement: il est lu tryte par tryte
+        for (i, tryte) in Word::from(-42).trytes().iter().enumerate() {
+            cpu.memory.write_tryte(11 * PAGE_SIZE + 7 + i, *tryte).unwrap();
+        }
+
+        cpu.state.write_pc(Word::from(PROGRAM_ADDR));
+        cpu.state.write_gpr(Register::R1, Word::from(DATA_VA));
+        cpu.state.write_csr(1, Word::from(MACHINE_HANDLER)).unwrap();
+        cpu.state_write_csr(12, Satp { mode: PagingMode::Sv18T, root_ppn: 1 }.to_word()).unwrap();
+        cpu.state.current_privilege = PrivilegeLevel::User;
+
+        // La lecture passe par la MMU, l'écriture dans la page en lecture seule est une faute de page
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.read_gpr(Register::R3).to_i64(), -42);
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.get_trap_cause(), Some(TrapCause::StorePageFault));
+        assert_eq!(cpu.state.read_csr(2).unwrap().to_i64(), PROGRAM_ADDR as i64 + 4);
+        assert_eq!(cpu.state.read_pc().to_i64(), MACHINE_HANDLER as i64);
+        assert_eq!(cpu.state.current_privilege, PrivilegeLevel::Machine);
+
+        // Sans projection exécutable, le fetch est une faute de page
+        cpu.state.write_pc(Word::from(DATA_VA));
+        cpu.state.current_privilege = PrivilegeLevel::User;
+        cpu.step().unwrap();
+        assert_eq!(cpu.state.get_trap_cause(), Some(TrapCause::InstrPageFault));
+        assert_eq!(cpu.state.read_csr(2).unwrap().to_i64(), DATA_VA as i64);
+    }
+
+    #[test]
+    fn test_caches_add_stall_cycles() {
+        const CODE_ADDR: i32 = 32;
+        const DATA_ADDR: i32 = 512;
+        let program = [
+            Instruction::Load { rd: Register::R3, rs1: Register::R4, offset: 0 },
+            Instruction::Load { rd: Register::R3, rs1: Register::R4, offset: 8 },
+            Instruction::Store { rs1: Register::R4, rs2: Register::R3, offset: 0 },
+        ];
+        let mut cpu = Cpu::with_memory_size(1024);
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                cpu.memory.write_tryte(CODE_ADDR as usize + 4 * i + j, *tryte).unwrap();
+            }
+        }
+        cpu.state.write_pc(Word::from(CODE_ADDR));
+        cpu.state.write_gpr(Register::R4, Word::from(DATA_ADDR));
+
+        // Une ligne de 4 Mots couvre le programme entier, puis les deux Mots de données
+        let config = CacheConfig {
+            sets: 4,
+            ways: 1,
+            line_words: 4,
+            prefetch_entries: 0,
+            policy: WritePolicy::WriteBack,
+            miss_penalty: 10,
+        };
+        cpu.caches = MemoryCaches::with_config(Some(config), Some(config));
+
+        // Échecs du fetch et de la lecture sur la première instruction, succès ensuite
+        cpu.step().unwrap();
+        assert_eq!(cpu.cycles, 21);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+        assert_eq!(cpu.cycles, 23);
+
+        let (icache, dcache) = cpu.caches.stats();
+        let (icache, dcache) = (icache.unwrap(), dcache.unwrap());
+        assert_eq!((icache.hits, icache.misses), (2, 1));
+        assert_eq!((dcache.reads, dcache.writes), (2, 1));
+        assert_eq!((dcache.hits, dcache.misses), (2, 1));
+        assert_eq!(icache.stall_cycles + dcache.stall_cycles, 20);
+    }
+
+    #[test]
+    fn test_pipeline_model_observes_execution() {
+        const CODE_ADDR: i32 = 16;
+        const DATA_ADDR: i32 = 512;
+        let program = [
+            Instruction::Load { rd: Register::R3, rs1: Register::R4, offset: 0 },
+            Instruction::AluReg { op: AluOp::Add, rs1: Register::R3, rs2: Register::R3, rd: Register::R5 },
+            Instruction::AluReg { op: AluOp::Add, rs1: Register::R5, rs2: Register::R3, rd: Register::R6 },
+            Instruction::Jump { rd: Register::R0, offset: 1 }, // Saute le NOP
+            Instruction::Nop,
+            Instruction::Halt,
+        ];
+        let build = || {
+            let mut cpu = Cpu::with_memory_size(1024);
+            for (i, instruction) in program.iter().enumerate() {
+                let trits = prismchrono_isa::encode(instruction).unwrap();
+                for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                    cpu.memory.write_tryte(CODE_ADDR as usize + 4 * i + j, *tryte).unwrap();
+                }
+            }
+            cpu.memory.write_word(DATA_ADDR as Address, Word::from(5)).unwrap();
+            cpu.state.write_pc(Word::from(CODE_ADDR));
+            cpu.state.write_gpr(Register::R4, Word::from(DATA_ADDR));
+            cpu
+        };
+
+        let mut reference = build();
+        let mut cpu = build();
+        cpu.pipeline = Some(PipelineModel::new());
+        assert_eq!(reference.run(Some(10)).unwrap(), StopReason::Halted);
+        assert_eq!(cpu.run(Some(10)).unwrap(), StopReason::Halted);
+
+        // Le modèle ne change ni les résultats ni l'horloge des périphériques
+        for index in 0..8 {
+            let register = Register::from_index(index).unwrap();
+            assert_eq!(cpu.state.read_gpr(register), reference.state.read_gpr(register));
+        }
+        assert_eq!(cpu.state.read_pc(), reference.state.read_pc());
+        assert_eq!(cpu.cycles, reference.cycles);
+
+        // LOAD -> ADD: une bulle load-use; la bulle du JUMP (résolu en ID) ne retarde que le HALT
+        let pipeline = cpu.pipeline.as_ref().unwrap();
+        let stats = pipeline.stats();
+        assert_eq!(stats.instructions, 4);
+        assert_eq!(stats.load_use_stalls, 1);
+        assert_eq!((stats.forwards_ex_mem, stats.forwards_mem_wb), (1, 1));
+        assert_eq!((stats.jumps, stats.flush_bubbles), (1, 1));
+        assert_eq!(pipeline.cycles(), 9);
+    }
+
+    #[test]
+    fn test_pipeline_predicts_backward_loop() {
+        const CODE_ADDR: i32 = 16;
+        // R1 = 9; boucle: R1 -= 1, recommencer tant que R1 != 0 (BRANCH revient sur l'ADDI)
+        let program = [
+            Instruction::AluImm { op: AluOp::Add, rs1: Register::R1, rd: Register::R1, imm: -1 },
+            Instruction::Branch { rs1: Register::R0, cond: BranchCondition::NonZero, offset: -2 },
+            Instruction::Halt,
+        ];
+        let mut cpu = Cpu::with_memory_size(1024);
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                cpu.memory.write_tryte(CODE_ADDR as usize + 4 * i + j, *tryte).unwrap();
+            }
+        }
+        cpu.state.write_pc(Word::from(CODE_ADDR));
+        cpu.state.write_gpr(Register::R1, Word::from(9));
+        cpu.pipeline = Some(PipelineModel::new());
+
+        assert_eq!(cpu.run(Some(100)).unwrap(), StopReason::Halted);
+        assert_eq!(cpu.state.read_gpr(Register::R1), Word::zero());
+
+        // Prédit pris vers l'ADDI à chaque tour: seule la sortie de boucle est mal prédite
+        let stats = cpu.pipeline.as_ref().unwrap().stats();
+        assert_eq!((stats.branches, stats.mispredictions), (9, 1));
+        assert_eq!(stats.flush_bubbles, BRANCH_PENALTY);
+    }
+
+    #[test]
+    fn test_branches_feed_predictor() {
+        const CODE_ADDR: i32 = 16;
+        let program = [
+            Instruction::Branch { rs1: Register::R0, cond: BranchCondition::False, offset: 2 },
+            Instruction::Branch3 { rs1: Register::R1, offset_neg: 1, offset_zero: 2, offset_pos: 3 },
+            Instruction::Halt,
+        ];
+        let mut cpu = Cpu::with_memory_size(1024);
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                cpu.memory.write_tryte(CODE_ADDR as usize + 4 * i + j, *tryte).unwrap();
+            }
+        }
+        cpu.state.write_pc(Word::from(CODE_ADDR));
+        cpu.state.write_gpr(Register::R1, Word::from(-5));
+        cpu.branch_profiler = Some(BranchProfiler::new(Box::new(StaticPredictor::new(Trit::Z))));
+        assert_eq!(cpu.run(Some(10)).unwrap(), StopReason::Halted);
+
+        // BRANCH non pris: prédit; BRANCH3 vers la voie négative: mal prédit
+        let profiler = cpu.branch_profiler.as_ref().unwrap();
+        let sites: Vec<_> = profiler.sites().iter().map(|(&pc, &stats)| (pc, stats)).collect();
+        assert_eq!(sites, vec![
+            (16, BranchSiteStats { executed: 1, correct: 1, taken: 0 }),
+            (20, BranchSiteStats { executed: 1, correct: 0, taken: 1 }),
+        ]);
+        assert_eq!(profiler.totals().accuracy(), 0.5);
+    }
+}
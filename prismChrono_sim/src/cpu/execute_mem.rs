@@ -1,8 +1,9 @@
 // src/cpu/execute_mem.rs
 // Implémentation des instructions de chargement/stockage pour l'architecture PrismChrono
 
-use crate::core::{Address, Trit, Tryte, Word, is_valid_address};
+use crate::core::{Address, Tryte, Word, is_valid_address};
 use crate::cpu::execute::ExecuteError;
+use crate::cpu::execute_core::word_to_address;
 use crate::cpu::registers::Register;
 use crate::memory::MemoryError;
 
@@ -58,21 +59,8 @@ impl<T: CpuState> MemoryOperations for T {
         // 1. Calculer l'adresse effective = Rs1 + offset
         let base_addr_word = self.read_gpr(rs1);
 
-        // Convertir le Word en adresse (utiliser les premiers trytes)
-        let mut base_addr: Address = 0;
-        for i in 0..5 {
-            // Utiliser les 5 premiers trytes (15 trits)
-            if let Some(tryte) = base_addr_word.tryte(i) {
-                match tryte {
-                    Tryte::Digit(val) => {
-                        // Convertir la valeur du tryte en adresse
-                        // et la décaler à la position appropriée
-                        base_addr += (*val as Address) * (3_i32.pow((i * 3) as u32) as Address);
-                    }
-                    _ => return Err(ExecuteError::InvalidAddress),
-                }
-            }
-        }
+        // Convertir le Word en adresse
+        let base_addr = word_to_address(&base_addr_word)?;
 
         // Ajouter l'offset (avec extension de signe)
         let effective_addr = base_addr.wrapping_add(offset as Address);
@@ -107,21 +95,8 @@ impl<T: CpuState> MemoryOperations for T {
         // 1. Calculer l'adresse effective = Rs1 + offset
         let base_addr_word = self.read_gpr(rs1);
 
-        // Convertir le Word en adresse (utiliser les premiers trytes)
-        let mut base_addr: Address = 0;
-        for i in 0..5 {
-            // Utiliser les 5 premiers trytes (15 trits)
-            if let Some(tryte) = base_addr_word.tryte(i) {
-                match tryte {
-                    Tryte::Digit(val) => {
-                        // Convertir la valeur du tryte en adresse
-                        // et la décaler à la position appropriée
-                        base_addr += (*val as Address) * (3_i32.pow((i * 3) as u32) as Address);
-                    }
-                    _ => return Err(ExecuteError::InvalidAddress),
-                }
-            }
-        }
+        // Convertir le Word en adresse
+        let base_addr = word_to_address(&base_addr_word)?;
 
         // Ajouter l'offset (avec extension de signe)
         let effective_addr = base_addr.wrapping_add(offset as Address);
@@ -135,6 +110,8 @@ impl<T: CpuState> MemoryOperations for T {
         let tryte = self.read_tryte(effective_addr)?;
 
         // 4. Créer un Word avec extension de signe
+        // En base 24 équilibrée, un tryte est un chiffre signé (-13 à +10): le Mot dont il est
+        // le tryte de poids faible, les autres trytes à 0, a déjà la valeur signée du tryte
         let mut word = Word::zero();
 
         // Placer le tryte lu dans le premier tryte du Word
@@ -142,26 +119,6 @@ impl<T: CpuState> MemoryOperations for T {
             *first_tryte = tryte;
         }
 
-        // Extension de signe: si le tryte est négatif, remplir les autres trytes avec -1
-        // Sinon, ils restent à 0 (déjà fait par Word::zero())
-        let is_negative = match tryte {
-            Tryte::Digit(val) => {
-                // Vérifier si le trit de poids fort est négatif
-                let trits = Tryte::Digit(val).to_trits();
-                trits[2] == Trit::N
-            }
-            _ => false, // Pour les autres types de trytes, pas d'extension de signe
-        };
-
-        if is_negative {
-            // Remplir les autres trytes avec -1 (tous les trits à N)
-            for i in 1..8 {
-                if let Some(t) = word.tryte_mut(i) {
-                    *t = Tryte::Digit(0); // 0 en ternaire équilibré = -13 en décimal
-                }
-            }
-        }
-
         // 5. Écrire le Word dans le registre de destination
         self.write_gpr(rd, word);
 
@@ -169,7 +126,8 @@ impl<T: CpuState> MemoryOperations for T {
     }
 
     /// Exécute une instruction de chargement d'un tryte sans extension de signe (LOADTU)
-    /// Charge un seul tryte depuis la mémoire sans extension de signe
+    /// Charge un seul tryte depuis la mémoire et rend l'indice de son chiffre (0 à 23):
+    /// -13 se charge en 0, -5 en 8, +10 en 23. Un état spécial est chargé tel quel
     fn execute_load_tryte_unsigned(
         &mut self,
         rd: Register,
@@ -179,21 +137,8 @@ impl<T: CpuState> MemoryOperations for T {
         // 1. Calculer l'adresse effective = Rs1 + offset
         let base_addr_word = self.read_gpr(rs1);
 
-        // Convertir le Word en adresse (utiliser les premiers trytes)
-        let mut base_addr: Address = 0;
-        for i in 0..5 {
-            // Utiliser les 5 premiers trytes (15 trits)
-            if let Some(tryte) = base_addr_word.tryte(i) {
-                match tryte {
-                    Tryte::Digit(val) => {
-                        // Convertir la valeur du tryte en adresse
-                        // et la décaler à la position appropriée
-                        base_addr += (*val as Address) * (3_i32.pow((i * 3) as u32) as Address);
-                    }
-                    _ => return Err(ExecuteError::InvalidAddress),
-                }
-            }
-        }
+        // Convertir le Word en adresse
+        let base_addr = word_to_address(&base_addr_word)?;
 
         // Ajouter l'offset (avec extension de signe)
         let effective_addr = base_addr.wrapping_add(offset as Address);
@@ -207,12 +152,18 @@ impl<T: CpuState> MemoryOperations for T {
         let tryte = self.read_tryte(effective_addr)?;
 
         // 4. Créer un Word avec le tryte lu (sans extension de signe)
-        let mut word = Word::zero(); // Tous les trytes à 0
-
-        // Placer le tryte lu dans le premier tryte du Word
-        if let Some(first_tryte) = word.tryte_mut(0) {
-            *first_tryte = tryte;
-        }
+        // Un chiffre B24 est signé: sa version non signée est son indice 0 à 23, qui occupe
+        // deux trytes au-delà de +10
+        let word = match tryte {
+            Tryte::Digit(index) => Word::from(index as i32),
+            special => {
+                let mut word = Word::zero();
+                if let Some(first_tryte) = word.tryte_mut(0) {
+                    *first_tryte = special;
+                }
+                word
+            }
+        };
 
         // 5. Écrire le Word dans le registre de destination
         self.write_gpr(rd, word);
@@ -231,21 +182,8 @@ impl<T: CpuState> MemoryOperations for T {
         // 1. Calculer l'adresse effective = Rs1 + offset
         let base_addr_word = self.read_gpr(rs1);
 
-        // Convertir le Word en adresse (utiliser les premiers trytes)
-        let mut base_addr: Address = 0;
-        for i in 0..5 {
-            // Utiliser les 5 premiers trytes (15 trits)
-            if let Some(tryte) = base_addr_word.tryte(i) {
-                match tryte {
-                    Tryte::Digit(val) => {
-                        // Convertir la valeur du tryte en adresse
-                        // et la décaler à la position appropriée
-                        base_addr += (*val as Address) * (3_i32.pow((i * 3) as u32) as Address);
-                    }
-                    _ => return Err(ExecuteError::InvalidAddress),
-                }
-            }
-        }
+        // Convertir le Word en adresse
+        let base_addr = word_to_address(&base_addr_word)?;
 
         // Ajouter l'offset (avec extension de signe)
         let effective_addr = base_addr.wrapping_add(offset as Address);
@@ -280,21 +218,8 @@ impl<T: CpuState> MemoryOperations for T {
         // 1. Calculer l'adresse effective = Rs1 + offset
         let base_addr_word = self.read_gpr(rs1);
 
-        // Convertir le Word en adresse (utiliser les premiers trytes)
-        let mut base_addr: Address = 0;
-        for i in 0..5 {
-            // Utiliser les 5 premiers trytes (15 trits)
-            if let Some(tryte) = base_addr_word.tryte(i) {
-                match tryte {
-                    Tryte::Digit(val) => {
-                        // Convertir la valeur du tryte en adresse
-                        // et la décaler à la position appropriée
-                        base_addr += (*val as Address) * (3_i32.pow((i * 3) as u32) as Address);
-                    }
-                    _ => return Err(ExecuteError::InvalidAddress),
-                }
-            }
-        }
+        // Convertir le Word en adresse
+        let base_addr = word_to_address(&base_addr_word)?;
 
         // Ajouter l'offset (avec extension de signe)
         let effective_addr = base_addr.wrapping_add(offset as Address);
@@ -307,7 +232,7 @@ impl<T: CpuState> MemoryOperations for T {
         // 3. Lire la valeur du registre source (prendre seulement le premier tryte)
         let word = self.read_gpr(rs2);
         let tryte = match word.tryte(0) {
-            Some(t) => t.clone(),
+            Some(t) => *t,
             None => Tryte::Undefined,
         };
 
@@ -9,16 +9,18 @@
 // Chaque niveau de privilège dispose de ses propres registres CSR :
 // - CSRs Machine (0-3) : mstatus_t, mtvec_t, mepc_t, mcause_t
 // - CSRs Supervisor (4-7) : sstatus_t, stvec_t, sepc_t, scause_t
+// - Délégation (8-9) : medeleg_t, mideleg_t ; interruptions (10-11) : mie_t, mip_t
 //
 // Les traps (exceptions, interruptions, appels système) peuvent être délégués
 // du mode Machine au mode Supervisor selon certaines conditions.
 
-use crate::alu::add_24_trits;
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Trit, Word};
 use crate::cpu::execute::ExecuteError;
-use crate::cpu::registers::{PrivilegeLevel, Register, TrapCause};
+use crate::cpu::registers::{Interrupt, PrivilegeLevel, Register, TrapCause, flag_trit};
+use crate::cpu::registers::{MSTATUS_MIE, MSTATUS_MPIE, SSTATUS_SIE, SSTATUS_SPIE};
 use crate::cpu::state::CpuState;
 use crate::cpu::supervisor_privilege::SupervisorPrivilegeOperations;
+use prismchrono_isa::isa_defs::imm_limits;
 
 /// Fonctions utilitaires pour la gestion des registres de délégation
 pub trait DelegationOperations {
@@ -39,141 +41,52 @@ pub trait DelegationOperations {
 impl<T: CpuState> DelegationOperations for T {
     /// Vérifie si une cause de trap est déléguée au mode Supervisor
     fn is_trap_delegated(&self, cause: TrapCause) -> bool {
-        // Lire medeleg_t (registre de délégation des exceptions)
-        let medeleg = match self.state_read_csr(8) {
-            Ok(value) => value,
-            Err(_) => return false,
-        };
-        
-        // Vérifier si le bit correspondant à la cause est activé
-        let cause_code = cause.to_code();
-        if cause_code >= 0 && cause_code < 24 {
-            // Extraire le trit correspondant à la cause
-            let tryte_index = cause_code / 3;
-            let trit_index = cause_code % 3;
-            
-            if let Some(tryte) = medeleg.tryte(tryte_index as usize) {
-                if let Tryte::Digit(val) = tryte {
-                    let trits = Tryte::Digit(*val).to_trits();
-                    // Si le trit est P, la délégation est activée
-                    return trits[trit_index as usize] == Trit::P;
-                }
-            }
-        }
-        
-        false
+        // Drapeau de la cause dans medeleg_t (registre de délégation des exceptions)
+        self.state_read_csr(8)
+            .is_ok_and(|medeleg| read_flag(&medeleg, cause.to_code()) == Some(true))
     }
-    
+
     /// Vérifie si une interruption est déléguée au mode Supervisor
     fn is_interrupt_delegated(&self, interrupt_code: i8) -> bool {
-        // Lire mideleg_t (registre de délégation des interruptions)
-        let mideleg = match self.state_read_csr(9) {
-            Ok(value) => value,
-            Err(_) => return false,
-        };
-        
-        // Vérifier si le bit correspondant à l'interruption est activé
-        if interrupt_code >= 0 && interrupt_code < 24 {
-            // Extraire le trit correspondant à l'interruption
-            let tryte_index = interrupt_code / 3;
-            let trit_index = interrupt_code % 3;
-            
-            if let Some(tryte) = mideleg.tryte(tryte_index as usize) {
-                if let Tryte::Digit(val) = tryte {
-                    let trits = Tryte::Digit(*val).to_trits();
-                    // Si le trit est P, la délégation est activée
-                    return trits[trit_index as usize] == Trit::P;
-                }
-            }
-        }
-        
-        false
+        // Drapeau de l'interruption dans mideleg_t (registre de délégation des interruptions)
+        self.state_read_csr(9)
+            .is_ok_and(|mideleg| read_flag(&mideleg, interrupt_code) == Some(true))
     }
-    
+
     /// Configure la délégation d'une cause de trap au mode Supervisor
     fn set_trap_delegation(&mut self, cause: TrapCause, delegated: bool) -> Result<(), ExecuteError> {
-        // Vérifier que nous sommes en mode Machine
-        if self.get_privilege() != PrivilegeLevel::Machine {
-            return self.handle_trap(TrapCause::IllegalInstr);
-        }
-        
-        // Lire medeleg_t
-        let mut medeleg = match self.state_read_csr(8) {
-            Ok(value) => value,
-            Err(_) => Word::zero(),
-        };
-        
-        // Modifier le bit correspondant à la cause
-        let cause_code = cause.to_code();
-        if cause_code >= 0 && cause_code < 24 {
-            // Calculer l'index du tryte et du trit
-            let tryte_index = cause_code / 3;
-            let trit_index = cause_code % 3;
-            
-            if let Some(tryte) = medeleg.tryte(tryte_index as usize) {
-                if let Tryte::Digit(val) = tryte {
-                    let mut trits = Tryte::Digit(*val).to_trits();
-                    
-                    // Modifier le trit correspondant
-                    trits[trit_index as usize] = if delegated { Trit::P } else { Trit::Z };
-                    
-                    // Convertir les trits en tryte et mettre à jour medeleg_t
-                    let new_tryte = Tryte::from_trits(trits);
-                    if let Some(tryte_mut) = medeleg.tryte_mut(tryte_index as usize) {
-                        *tryte_mut = new_tryte;
-                    }
-                }
-            }
-            
-            // Écrire la nouvelle valeur dans medeleg_t
-            self.write_csr(8, medeleg)
-        } else {
-            Err(ExecuteError::InvalidInstruction)
-        }
+        set_delegation_flag(self, 8, cause.to_code(), delegated)
     }
-    
+
     /// Configure la délégation d'une interruption au mode Supervisor
     fn set_interrupt_delegation(&mut self, interrupt_code: i8, delegated: bool) -> Result<(), ExecuteError> {
-        // Vérifier que nous sommes en mode Machine
-        if self.get_privilege() != PrivilegeLevel::Machine {
-            return self.handle_trap(TrapCause::IllegalInstr);
-        }
-        
-        // Lire mideleg_t
-        let mut mideleg = match self.state_read_csr(9) {
-            Ok(value) => value,
-            Err(_) => Word::zero(),
-        };
-        
-        // Modifier le bit correspondant à l'interruption
-        if interrupt_code >= 0 && interrupt_code < 24 {
-            // Calculer l'index du tryte et du trit
-            let tryte_index = interrupt_code / 3;
-            let trit_index = interrupt_code % 3;
-            
-            if let Some(tryte) = mideleg.tryte(tryte_index as usize) {
-                if let Tryte::Digit(val) = tryte {
-                    let mut trits = Tryte::Digit(*val).to_trits();
-                    
-                    // Modifier le trit correspondant
-                    trits[trit_index as usize] = if delegated { Trit::P } else { Trit::Z };
-                    
-                    // Convertir les trits en tryte et mettre à jour mideleg_t
-                    let new_tryte = Tryte::from_trits(trits);
-                    if let Some(tryte_mut) = mideleg.tryte_mut(tryte_index as usize) {
-                        *tryte_mut = new_tryte;
-                    }
-                }
-            }
-            
-            // Écrire la nouvelle valeur dans mideleg_t
-            self.write_csr(9, mideleg)
-        } else {
-            Err(ExecuteError::InvalidInstruction)
-        }
+        set_delegation_flag(self, 9, interrupt_code, delegated)
     }
 }
 
+/// Lit le drapeau `code` d'un CSR à drapeaux (None si le code est hors du registre)
+fn read_flag(csr: &Word, code: i8) -> Option<bool> {
+    flag_trit(code).map(|trit| csr.get_trit(trit) == Trit::P)
+}
+
+/// Écrit un drapeau de medeleg_t (CSR 8) ou mideleg_t (CSR 9), réservé au mode Machine
+fn set_delegation_flag<T: CsrOperations + ?Sized>(
+    cpu: &mut T,
+    csr: i8,
+    code: i8,
+    delegated: bool,
+) -> Result<(), ExecuteError> {
+    // Vérifier que nous sommes en mode Machine
+    if cpu.get_privilege() != PrivilegeLevel::Machine {
+        return cpu.handle_trap(TrapCause::IllegalInstr);
+    }
+
+    let trit = flag_trit(code).ok_or(ExecuteError::InvalidInstruction)?;
+    let mut value = cpu.read_csr(csr).unwrap_or_default();
+    value.set_trit(trit, if delegated { Trit::P } else { Trit::Z });
+    cpu.write_csr(csr, value)
+}
+
 /// Trait pour les opérations système et spéciales
 pub trait SystemOperations {
     /// Exécute une instruction système
@@ -243,41 +156,71 @@ pub trait CsrOperations: CpuState + SupervisorPrivilegeOperations {
     fn write_sepc(&mut self, value: Word);
 
     /// Gère un trap (exception/syscall)
-    fn handle_trap(&mut self, _cause: TrapCause) -> Result<(), ExecuteError>;
+    /// Un trap levé en mode User et délégué par medeleg_t est traité en mode Supervisor,
+    /// tous les autres en mode Machine
+    fn handle_trap(&mut self, cause: TrapCause) -> Result<(), ExecuteError>;
     
     /// Gère un trap en mode Supervisor
     /// Cette fonction est similaire à handle_trap mais utilise les CSR du mode Supervisor
     fn handle_supervisor_trap(&mut self, cause: TrapCause) -> Result<(), ExecuteError> {
-        // 1. Sauvegarder PC dans sepc_t
-        let pc = self.read_pc();
-        self.write_sepc(pc);
-
-        // 2. Sauvegarder la cause dans scause_t
-        // Utiliser CSR 7 (scause_t)
-        let mut cause_word = Word::zero();
-        if let Some(tryte) = cause_word.tryte_mut(0) {
-            *tryte = Tryte::from_bal3(cause.to_code()).unwrap_or(Tryte::Digit(13)); // 13 = 0 en ternaire équilibré
-        }
-        let _ = self.state_write_csr(7, cause_word);
-
-        // 3. Sauvegarder le privilège actuel dans sstatus_t.SPP_t
-        let current_privilege = self.get_privilege();
-        self.set_supervisor_previous_privilege(current_privilege);
-
-        // 4. Passer en mode Supervisor
-        self.set_privilege(PrivilegeLevel::Supervisor);
+        // Sauvegarder la cause dans scause_t (CSR 7)
+        let _ = self.state_write_csr(7, Word::from_bal3(cause.to_code()));
 
-        // 5. Sauter à l'adresse contenue dans stvec_t
+        // Sauter à l'adresse contenue dans stvec_t (CSR 5)
         let stvec = match self.state_read_csr(5) {
             Ok(value) => value,
             Err(_) => Word::zero(), // En cas d'erreur, utiliser une adresse par défaut
         };
-        self.write_pc(stvec);
+        enter_trap(self, PrivilegeLevel::Supervisor, stvec);
 
         Ok(())
     }
 }
 
+/// Entre dans un trap (exception ou interruption) traité au niveau `target`
+/// La cause doit déjà être écrite dans mcause_t/scause_t. Sauvegarde le PC (mepc_t/sepc_t)
+/// et le privilège courant (MPP_t/SPP_t), empile l'autorisation des interruptions
+/// (MIE -> MPIE ou SIE -> SPIE, puis MIE/SIE = 0), change de privilège et saute au vecteur.
+fn enter_trap<T: CsrOperations + ?Sized>(cpu: &mut T, target: PrivilegeLevel, vector: Word) {
+    let pc = cpu.read_pc();
+    let privilege = cpu.get_privilege();
+
+    if target == PrivilegeLevel::Supervisor {
+        cpu.write_sepc(pc);
+        cpu.set_supervisor_previous_privilege(privilege);
+        update_status(cpu, 4, |status| push_interrupt_enable(status, SSTATUS_SIE, SSTATUS_SPIE));
+    } else {
+        cpu.write_mepc(pc);
+        cpu.set_previous_privilege(privilege);
+        update_status(cpu, 0, |status| push_interrupt_enable(status, MSTATUS_MIE, MSTATUS_MPIE));
+    }
+
+    cpu.set_privilege(target);
+    cpu.write_pc(vector);
+}
+
+/// Modifie un registre d'état (mstatus_t ou sstatus_t)
+fn update_status<T: CpuState + ?Sized>(cpu: &mut T, csr: i8, update: impl FnOnce(&mut Word)) {
+    if let Ok(mut status) = cpu.state_read_csr(csr) {
+        update(&mut status);
+        let _ = cpu.state_write_csr(csr, status);
+    }
+}
+
+/// Entrée de trap: xPIE reçoit xIE, puis xIE passe à 0
+fn push_interrupt_enable(status: &mut Word, ie: usize, pie: usize) {
+    let enabled = status.get_trit(ie);
+    status.set_trit(pie, enabled);
+    status.set_trit(ie, Trit::Z);
+}
+
+/// Retour de trap (MRET/SRET): xIE reprend la valeur de xPIE, puis xPIE passe à P
+fn pop_interrupt_enable(status: &mut Word, ie: usize, pie: usize) {
+    let enabled = status.get_trit(pie);
+    status.set_trit(ie, enabled);
+    status.set_trit(pie, Trit::P);
+}
+
 /// Implémentation des opérations système pour le CPU
 impl<T: CpuState> SystemOperations for T {
     /// Exécute une instruction système
@@ -339,6 +282,9 @@ impl<T: CpuState> SystemOperations for T {
         let previous_privilege = self.get_previous_privilege();
         self.set_privilege(previous_privilege);
 
+        // Restaurer l'autorisation des interruptions (MIE = MPIE)
+        update_status(self, 0, |status| pop_interrupt_enable(status, MSTATUS_MIE, MSTATUS_MPIE));
+
         // Restaurer le PC depuis mepc_t
         let mepc = self.read_mepc();
         self.write_pc(mepc);
@@ -353,13 +299,16 @@ impl<T: CpuState> SystemOperations for T {
         match self.get_privilege() {
             PrivilegeLevel::User => {
                 // En mode User, SRET est une instruction illégale
-                return self.handle_trap(TrapCause::IllegalInstr);
+                self.handle_trap(TrapCause::IllegalInstr)
             }
             PrivilegeLevel::Supervisor | PrivilegeLevel::Machine => {
                 // En mode Supervisor ou Machine, SRET est autorisé
                 // Restaurer le niveau de privilège depuis sstatus_t.SPP_t
                 let previous_privilege = self.get_supervisor_previous_privilege();
                 self.set_privilege(previous_privilege);
+
+                // Restaurer l'autorisation des interruptions (SIE = SPIE)
+                update_status(self, 4, |status| pop_interrupt_enable(status, SSTATUS_SIE, SSTATUS_SPIE));
                 
                 // Restaurer le PC depuis sepc_t
                 let sepc = self.read_sepc();
@@ -380,36 +329,14 @@ impl<T: CpuState> SystemOperations for T {
 
     /// Exécute une instruction LUI (Load Upper Immediate)
     /// Format U: [opcode(3t) | rd(2t) | immediate(7t)]
-    /// Charge un immédiat dans les trytes supérieurs d'un registre
+    /// Charge imm × 3^5 dans rd: les 5 trits inférieurs restent à zéro pour un ADDI ultérieur
     fn execute_lui(&mut self, rd: Register, imm: i16) -> Result<(), ExecuteError> {
         // Ne rien faire si rd est R0
         if rd == Register::R0 {
             return Ok(());
         }
 
-        // Créer un Word avec l'immédiat dans les trytes supérieurs
-        let mut word = Word::zero();
-
-        // Convertir l'immédiat en trytes et les placer dans les positions supérieures
-        // L'immédiat est sur 7 trits, donc on peut le convertir en 3 trytes environ
-        let imm_abs = imm.abs() as u16;
-        let sign = if imm < 0 { -1 } else { 1 };
-
-        // Placer les trytes de l'immédiat dans les positions 4, 5, 6 du Word
-        // (les positions 0, 1, 2, 3 restent à zéro)
-        for i in 0..3 {
-            if let Some(tryte) = word.tryte_mut(i + 4) {
-                // Extraire 3 trits (1 tryte) de l'immédiat
-                let tryte_val = ((imm_abs >> (i * 3)) & 0x7) as i8;
-                // Appliquer le signe
-                let signed_val = tryte_val * sign as i8;
-                // Convertir en Tryte::Digit (ajouter 13 pour le ternaire équilibré)
-                *tryte = Tryte::Digit((signed_val + 13) as u8);
-            }
-        }
-
-        // Écrire le Word dans le registre de destination
-        self.write_gpr(rd, word);
+        self.write_gpr(rd, upper_immediate(imm));
 
         Ok(())
     }
@@ -426,29 +353,11 @@ impl<T: CpuState> SystemOperations for T {
         // 1. Lire le PC actuel
         let current_pc = self.read_pc();
 
-        // 2. Créer un Word avec l'immédiat dans les trytes supérieurs
-        let mut imm_word = Word::zero();
-
-        // Convertir l'immédiat en trytes et les placer dans les positions supérieures
-        // L'immédiat est sur 7 trits, donc on peut le convertir en 3 trytes environ
-        let imm_abs = imm.abs() as u16;
-        let sign = if imm < 0 { -1 } else { 1 };
-
-        // Placer les trytes de l'immédiat dans les positions 4, 5, 6 du Word
-        // (les positions 0, 1, 2, 3 restent à zéro)
-        for i in 0..3 {
-            if let Some(tryte) = imm_word.tryte_mut(i + 4) {
-                // Extraire 3 trits (1 tryte) de l'immédiat
-                let tryte_val = ((imm_abs >> (i * 3)) & 0x7) as i8;
-                // Appliquer le signe
-                let signed_val = tryte_val * sign as i8;
-                // Convertir en Tryte::Digit (ajouter 13 pour le ternaire équilibré)
-                *tryte = Tryte::Digit((signed_val + 13) as u8);
-            }
-        }
+        // 2. Décaler l'immédiat comme pour LUI
+        let imm_word = upper_immediate(imm);
 
-        // 3. Ajouter l'immédiat au PC
-        let (result, _, _) = add_24_trits(current_pc, imm_word, Trit::Z);
+        // 3. Ajouter l'immédiat au PC en entier exact, sans toucher aux flags
+        let result = Word::from_i64(current_pc.to_i64() + imm_word.to_i64());
 
         // 4. Écrire le résultat dans le registre de destination
         self.write_gpr(rd, result);
@@ -457,6 +366,11 @@ impl<T: CpuState> SystemOperations for T {
     }
 }
 
+/// Valeur d'un immédiat U une fois décalé de `U_SHIFT_TRITS` trits (LUI/AUIPC)
+fn upper_immediate(imm: i16) -> Word {
+    Word::from(imm as i32 * 3i32.pow(imm_limits::U_SHIFT_TRITS))
+}
+
 /// Implémentation des opérations CSR pour le CPU
 impl<T: CpuState> CsrOperations for T {
     /// Lit la valeur d'un CSR
@@ -471,8 +385,10 @@ impl<T: CpuState> CsrOperations for T {
 
     /// Exécute une instruction CSRRW (CSR Read & Write)
     fn execute_csrrw(&mut self, rd: Register, csr: i8, rs1: Register) -> Result<(), ExecuteError> {
-        // Vérifier l'accès au CSR
-        self.check_csr_access(csr, true)?;
+        // Vérifier l'accès au CSR: un accès refusé est une instruction illégale
+        if self.check_csr_access(csr, true).is_err() {
+            return self.handle_trap(TrapCause::IllegalInstr);
+        }
 
         // Lire la valeur actuelle du CSR
         let old_value = self.read_csr(csr)?;
@@ -493,9 +409,11 @@ impl<T: CpuState> CsrOperations for T {
 
     /// Exécute une instruction CSRRS (CSR Read & Set)
     fn execute_csrrs(&mut self, rd: Register, csr: i8, rs1: Register) -> Result<(), ExecuteError> {
-        // Vérifier l'accès au CSR
+        // Vérifier l'accès au CSR: un accès refusé est une instruction illégale
         let write = rs1 != Register::R0;
-        self.check_csr_access(csr, write)?;
+        if self.check_csr_access(csr, write).is_err() {
+            return self.handle_trap(TrapCause::IllegalInstr);
+        }
 
         // Lire la valeur actuelle du CSR
         let old_value = self.read_csr(csr)?;
@@ -519,9 +437,11 @@ impl<T: CpuState> CsrOperations for T {
 
     /// Exécute une instruction CSRRC (CSR Read & Clear)
     fn execute_csrrc(&mut self, rd: Register, csr: i8, rs1: Register) -> Result<(), ExecuteError> {
-        // Vérifier l'accès au CSR
+        // Vérifier l'accès au CSR: un accès refusé est une instruction illégale
         let write = rs1 != Register::R0;
-        self.check_csr_access(csr, write)?;
+        if self.check_csr_access(csr, write).is_err() {
+            return self.handle_trap(TrapCause::IllegalInstr);
+        }
 
         // Lire la valeur actuelle du CSR
         let old_value = self.read_csr(csr)?;
@@ -545,9 +465,11 @@ impl<T: CpuState> CsrOperations for T {
 
     /// Exécute une instruction CSR avec un immédiat
     fn execute_csr_imm(&mut self, csr: u8, rd: Register, imm: i16) -> Result<(), ExecuteError> {
-        // Vérifier l'accès au CSR
+        // Vérifier l'accès au CSR: un accès refusé est une instruction illégale
         let write = imm != 0;
-        self.check_csr_access(csr as i8, write)?;
+        if self.check_csr_access(csr as i8, write).is_err() {
+            return self.handle_trap(TrapCause::IllegalInstr);
+        }
 
         // Lire la valeur actuelle du CSR
         let old_value = self.read_csr(csr as i8)?;
@@ -560,20 +482,25 @@ impl<T: CpuState> CsrOperations for T {
         // Si imm n'est pas 0, effectuer l'opération de set
         if imm != 0 {
             // Créer une valeur Word à partir de l'immédiat
-            let imm_value = Word::from_i16(imm);
+            let imm_value = Word::from(imm);
 
             // Effectuer un OR bit à bit entre la valeur actuelle du CSR et imm_value
-            self.write_csr(csr as i8, imm_value);
+            self.write_csr(csr as i8, imm_value)?;
         }
 
         Ok(())
     }
 
     /// Vérifie si l'accès à un CSR est autorisé
+    /// sstatus_t, stvec_t, sepc_t, scause_t (4-7) et satp_t (12) sont accessibles dès le
+    /// mode Supervisor, tous les autres CSR sont réservés au mode Machine
     fn check_csr_access(&self, csr: i8, write: bool) -> Result<(), ExecuteError> {
         // Vérifier le niveau de privilège requis pour accéder au CSR
-        let csr_privilege = (csr >> 6) & 0x3;
-        
+        let csr_privilege = match csr {
+            4..=7 | 12 => PrivilegeLevel::Supervisor,
+            _ => PrivilegeLevel::Machine,
+        } as i8;
+
         // Vérifier si l'accès en écriture est autorisé
         let read_only = ((csr >> 5) & 0x1) == 1;
         
@@ -610,29 +537,109 @@ impl<T: CpuState> CsrOperations for T {
         self.state_set_previous_privilege(privilege);
     }
 
-    /// Lit la valeur de mepc_t
+    /// Lit la valeur de mepc_t (CSR 2)
     fn read_mepc(&self) -> Word {
-        self.state_read_csr(0).unwrap_or_default()
+        self.state_read_csr(2).unwrap_or_default()
     }
 
-    /// Écrit une valeur dans mepc_t
+    /// Écrit une valeur dans mepc_t (CSR 2)
     fn write_mepc(&mut self, value: Word) {
-        self.state_write_csr(0, value);
+        let _ = self.state_write_csr(2, value);
     }
 
-    /// Lit la valeur de sepc_t
+    /// Lit la valeur de sepc_t (CSR 6)
     fn read_sepc(&self) -> Word {
-        self.state_read_csr(4).unwrap_or_default()
+        self.state_read_csr(6).unwrap_or_default()
     }
 
-    /// Écrit une valeur dans sepc_t
+    /// Écrit une valeur dans sepc_t (CSR 6)
     fn write_sepc(&mut self, value: Word) {
-        self.state_write_csr(4, value);
+        let _ = self.state_write_csr(6, value);
     }
 
     /// Gère un trap (exception/syscall)
-    fn handle_trap(&mut self, _cause: TrapCause) -> Result<(), ExecuteError> {
-        // Implémentation de la gestion des traps
+    /// Sauvegarde le PC dans mepc_t et la cause dans mcause_t, mémorise le privilège courant
+    /// dans mstatus_t.MPP_t, passe en mode Machine et saute à l'adresse contenue dans mtvec_t.
+    /// Tout vecteur est valide, y compris l'adresse 0. Seul un trap levé par l'instruction
+    /// même du vecteur remonte à l'appelant (ExecuteError::UnhandledTrap) sans modifier l'état
+    /// du processeur: le gestionnaire y reviendrait indéfiniment (par exemple mtvec_t nul
+    /// sans gestionnaire chargé à l'adresse 0).
+    fn handle_trap(&mut self, cause: TrapCause) -> Result<(), ExecuteError> {
+        let privilege = self.get_privilege();
+        let delegated = privilege == PrivilegeLevel::User && self.is_trap_delegated(cause);
+
+        // Vecteur de trap du mode cible: stvec_t (CSR 5) si délégué, mtvec_t (CSR 1) sinon
+        let vector = self
+            .state_read_csr(if delegated { 5 } else { 1 })
+            .unwrap_or_else(|_| Word::zero());
+        if vector == self.read_pc() {
+            return Err(ExecuteError::UnhandledTrap(cause));
+        }
+
+        if delegated {
+            return self.handle_supervisor_trap(cause);
+        }
+
+        self.state_set_trap_cause(cause);
+        enter_trap(self, PrivilegeLevel::Machine, vector);
+
         Ok(())
     }
 }
+
+/// Prise des interruptions asynchrones, aux frontières d'instruction
+pub trait InterruptOperations {
+    /// Interruption à prendre avant la prochaine instruction, s'il y en a une
+    fn pending_interrupt(&self) -> Option<Interrupt>;
+
+    /// Prend une interruption: trap vers le mode Machine, ou Supervisor si déléguée
+    fn take_interrupt(&mut self, interrupt: Interrupt);
+}
+
+impl<T: CpuState> InterruptOperations for T {
+    /// Une interruption est candidate si elle est en attente (mip_t) et autorisée (mie_t).
+    /// Déléguée par mideleg_t, elle est traitée en mode Supervisor: jamais prise en mode
+    /// Machine, toujours en mode User, en mode Supervisor seulement si sstatus_t.SIE.
+    /// Sinon elle est traitée en mode Machine: toujours prise depuis un mode moins
+    /// privilégié, en mode Machine seulement si mstatus_t.MIE.
+    /// Priorité: externe, puis logicielle, puis timer.
+    fn pending_interrupt(&self) -> Option<Interrupt> {
+        let mie = self.state_read_csr(10).ok()?;
+        let mip = self.state_read_csr(11).ok()?;
+        let mstatus = self.state_read_csr(0).unwrap_or_default();
+        let sstatus = self.state_read_csr(4).unwrap_or_default();
+        let privilege = self.get_privilege();
+
+        [Interrupt::External, Interrupt::Software, Interrupt::Timer]
+            .into_iter()
+            .find(|interrupt| {
+                let code = interrupt.to_code();
+                if read_flag(&mip, code) != Some(true) || read_flag(&mie, code) != Some(true) {
+                    return false;
+                }
+
+                if self.is_interrupt_delegated(interrupt.to_code()) {
+                    match privilege {
+                        PrivilegeLevel::User => true,
+                        PrivilegeLevel::Supervisor => sstatus.get_trit(SSTATUS_SIE) == Trit::P,
+                        PrivilegeLevel::Machine => false,
+                    }
+                } else {
+                    privilege != PrivilegeLevel::Machine || mstatus.get_trit(MSTATUS_MIE) == Trit::P
+                }
+            })
+    }
+
+    fn take_interrupt(&mut self, interrupt: Interrupt) {
+        let cause = Word::from_bal3(interrupt.cause_code());
+        let (target, cause_csr, vector_csr) = if self.is_interrupt_delegated(interrupt.to_code()) {
+            (PrivilegeLevel::Supervisor, 7, 5)
+        } else {
+            (PrivilegeLevel::Machine, 3, 1)
+        };
+
+        let _ = self.state_write_csr(cause_csr, cause);
+        let vector = self.state_read_csr(vector_csr).unwrap_or_default();
+        enter_trap(self, target, vector);
+    }
+}
@@ -1,9 +1,11 @@
 // src/cpu/execute_ternary.rs
 // Implémentation des instructions ternaires spécialisées pour l'architecture PrismChrono
 
-use crate::core::{Trit, Word};
+use crate::core::{Trit, Tryte, Word, address_from_i64, address_from_word, address_to_word};
+use crate::cpu::execute_branch::branch3_target_address;
 use crate::cpu::registers::Register;
-use crate::cpu::execute_core::ExecuteError;
+use crate::cpu::execute_core::{ExecuteError, word_to_address};
+use crate::cpu::isa::{Instruction, VectorOp, VectorReduceOp};
 use crate::cpu::state::CpuState;
 use crate::cpu::isa_extensions::{TernaryOp, TernaryShiftOp, SpecialStateOp, Base24Op};
 use crate::cpu::isa_extensions::{execute_ternary_op as ext_execute_ternary_op, 
@@ -12,6 +14,8 @@ use crate::cpu::isa_extensions::{execute_ternary_op as ext_execute_ternary_op,
                                execute_tsel as ext_execute_tsel, 
                                execute_base24_op as ext_execute_base24_op};
 use crate::alu::{add_24_trits, sub_24_trits, mul_24_trits};
+use crate::tvpu_hardware::{TVPUOperation, VECTOR_LENGTH};
+use crate::mmu::AccessType;
 
 /// Trait pour l'exécution des instructions ternaires spécialisées
 pub trait ExecuteTernary {
@@ -92,18 +96,21 @@ impl<T: CpuState> ExecuteTernary for T {
         // Lire la valeur du registre
         let value = self.read_gpr(rs1);
         
-        // Déterminer l'offset en fonction de la valeur du registre
-        let offset = if value.is_negative() {
-            offset_n
-        } else if value == Word::zero() {
-            offset_z
-        } else {
-            offset_p
+        // Déterminer l'offset en fonction du signe de la valeur du registre
+        let (outcome, offset) = match value.to_i64().signum() {
+            -1 => (Trit::N, offset_n),
+            0 => (Trit::Z, offset_z),
+            _ => (Trit::P, offset_p),
         };
+
+        // La voie prise est soumise au prédicteur
+        if let Some(cpu) = self.as_cpu_mut() {
+            cpu.observe_branch(outcome);
+        }
         
-        // Mettre à jour le PC
-        let pc = self.read_pc();
-        let new_pc = Word::from_i32((pc.to_i32() + offset) as i32);
+        // Mettre à jour le PC (les offsets sont exprimés en instructions, depuis le BRANCH3)
+        let pc = self.read_pc().to_i64();
+        let new_pc = address_to_word(address_from_i64(branch3_target_address(pc, offset as i64))?)?;
         self.write_pc(new_pc);
         
         Ok(())
@@ -112,8 +119,8 @@ impl<T: CpuState> ExecuteTernary for T {
     /// Charge 3 trytes consécutifs depuis la mémoire
     fn execute_load_tryte3(&mut self, rd: Register, rs1: Register, offset: i32) -> Result<(), ExecuteError> {
         // Calculer l'adresse de base
-        let base = self.read_gpr(rs1).to_i32();
-        let addr = (base + offset) as u32;
+        let base = self.read_gpr(rs1).to_i64();
+        let addr = address_from_i64(base + offset as i64)?;
         
         // Créer un nouveau mot pour stocker les trytes chargés
         let mut result = Word::zero();
@@ -121,7 +128,7 @@ impl<T: CpuState> ExecuteTernary for T {
         // Charger 3 trytes consécutifs
         for i in 0..3 {
             // Lire le tryte depuis la mémoire
-            let loaded_tryte = self.read_tryte(addr as usize + i as usize)?;
+            let loaded_tryte = self.read_tryte(addr + i)?;
             
             // Stocker le tryte dans le mot résultat
             if let Some(tryte) = result.tryte_mut(i) {
@@ -137,8 +144,8 @@ impl<T: CpuState> ExecuteTernary for T {
     /// Stocke 3 trytes consécutifs en mémoire
     fn execute_store_tryte3(&mut self, rs1: Register, rs2: Register, offset: i32) -> Result<(), ExecuteError> {
         // Calculer l'adresse de base
-        let base = self.read_gpr(rs1).to_i32();
-        let addr = (base + offset) as usize;
+        let base = self.read_gpr(rs1).to_i64();
+        let addr = address_from_i64(base + offset as i64)?;
         
         // Lire la valeur à stocker
         let value = self.read_gpr(rs2);
@@ -157,8 +164,8 @@ impl<T: CpuState> ExecuteTernary for T {
     /// Exécute une instruction de chargement avec masque de trytes
     fn execute_load_tm(&mut self, rd: Register, rs1: Register, mask: u8, offset: i32) -> Result<(), ExecuteError> {
         // Lire l'adresse de base
-        let base = self.read_gpr(rs1).to_i32();
-        let addr = (base + offset) as usize;
+        let base = self.read_gpr(rs1).to_i64();
+        let addr = address_from_i64(base + offset as i64)?;
         
         // Créer un nouveau mot pour stocker les trytes chargés
         let mut result = Word::zero();
@@ -185,8 +192,8 @@ impl<T: CpuState> ExecuteTernary for T {
     /// Exécute une instruction de stockage avec masque de trytes
     fn execute_store_tm(&mut self, rs1: Register, rs2: Register, mask: u8, offset: i32) -> Result<(), ExecuteError> {
         // Lire l'adresse de base
-        let base = self.read_gpr(rs1).to_i32();
-        let addr = (base + offset) as usize;
+        let base = self.read_gpr(rs1).to_i64();
+        let addr = address_from_i64(base + offset as i64)?;
         
         // Lire la valeur à stocker
         let value = self.read_gpr(rs2);
@@ -208,13 +215,13 @@ impl<T: CpuState> ExecuteTernary for T {
     /// Exécute une instruction de copie mémoire ternaire
     fn execute_tmemcpy(&mut self, rd: Register, rs1: Register, rs2: Register) -> Result<(), ExecuteError> {
         // Lire l'adresse source
-        let src_addr = self.read_gpr(rs1).to_i32() as usize;
+        let src_addr = address_from_word(&self.read_gpr(rs1))?;
         
         // Lire l'adresse destination
-        let dst_addr = self.read_gpr(rd).to_i32() as usize;
+        let dst_addr = address_from_word(&self.read_gpr(rd))?;
         
         // Lire la taille à copier (en trytes)
-        let size = self.read_gpr(rs2).to_i32() as usize;
+        let size = usize::try_from(self.read_gpr(rs2).to_i64()).map_err(|_| ExecuteError::InvalidOperation)?;
         
         // Copier les trytes
         for i in 0..size {
@@ -228,13 +235,13 @@ impl<T: CpuState> ExecuteTernary for T {
     /// Exécute une instruction d'initialisation mémoire ternaire
     fn execute_tmemset(&mut self, rd: Register, rs1: Register, rs2: Register) -> Result<(), ExecuteError> {
         // Lire l'adresse destination
-        let dst_addr = self.read_gpr(rd).to_i32() as usize;
+        let dst_addr = address_from_word(&self.read_gpr(rd))?;
         
         // Lire la valeur à écrire
         let value = self.read_gpr(rs1);
         
         // Lire la taille à initialiser (en trytes)
-        let size = self.read_gpr(rs2).to_i32() as usize;
+        let size = usize::try_from(self.read_gpr(rs2).to_i64()).map_err(|_| ExecuteError::InvalidOperation)?;
         
         // Initialiser les trytes
         for i in 0..size {
@@ -324,89 +331,182 @@ impl<T: CpuState> ExecuteTernary for T {
 
 /// Implémentation des méthodes spécifiques pour le CPU
 impl crate::cpu::execute::Cpu {
-    /// Exécute une instruction de manipulation de vecteur ternaire
-    pub fn execute_tvpu_instruction(&mut self, _opcode: u8, _rs1: Register, _rs2: Register, _rd: Register) -> Result<(), ExecuteError> {
-        // Implémentation à venir
+    /// Adresse physique d'un vecteur (8 Mots consécutifs) désigné par rs1
+    /// Avec la pagination, le vecteur doit être physiquement contigu (la TVPU accède
+    /// directement à la mémoire physique)
+    fn vector_address(&self, rs1: Register, access: AccessType) -> Result<usize, ExecuteError> {
+        let addr = word_to_address(&self.read_gpr(rs1))?;
+        if !self.paging_active() {
+            return Ok(addr);
+        }
+        self.translate_contiguous(addr, VECTOR_LENGTH * 8, access)
+    }
+
+    /// Exécute une instruction vectorielle (TV*) sur l'unité TVPU
+    /// Les vecteurs sont lus et écrits dans le banc V0-V7 de l'état du processeur,
+    /// les réductions écrivent leur résultat scalaire dans le registre général rd
+    pub fn execute_tvpu_instruction(&mut self, instruction: Instruction) -> Result<(), ExecuteError> {
+        let (operation, rd) = match instruction {
+            Instruction::VectorOp { op, vd, vs1, vs2 } => {
+                let (vd, vs1, vs2) = (vd.to_index(), vs1.to_index(), vs2.to_index());
+                let operation = match op {
+                    VectorOp::TVADD => TVPUOperation::Add { vd, vs1, vs2 },
+                    VectorOp::TVSUB => TVPUOperation::Sub { vd, vs1, vs2 },
+                    VectorOp::TVMUL => TVPUOperation::Mul { vd, vs1, vs2 },
+                    // Accumulation dans le registre destination: vd = vs1 * vs2 + vd
+                    VectorOp::TVMAC => TVPUOperation::Mac { vd, vs1, vs2, vs3: vd },
+                };
+                (operation, None)
+            }
+            Instruction::VectorDot { rd, vs1, vs2 } => {
+                (TVPUOperation::Dot { vs1: vs1.to_index(), vs2: vs2.to_index() }, Some(rd))
+            }
+            Instruction::VectorReduce { op, rd, vs } => {
+                let vs = vs.to_index();
+                let operation = match op {
+                    VectorReduceOp::TVSUM => TVPUOperation::Sum { vs },
+                    VectorReduceOp::TVMIN => TVPUOperation::Min { vs },
+                    VectorReduceOp::TVMAX => TVPUOperation::Max { vs },
+                    VectorReduceOp::TVAVG => TVPUOperation::Avg { vs },
+                };
+                (operation, Some(rd))
+            }
+            Instruction::VectorLoad { vd, rs1 } => {
+                let addr = self.vector_address(rs1, AccessType::Load)?;
+                (TVPUOperation::Load { vd: vd.to_index(), addr, stride: 1 }, None)
+            }
+            Instruction::VectorStore { vs, rs1 } => {
+                let addr = self.vector_address(rs1, AccessType::Store)?;
+                (TVPUOperation::Store { vs: vs.to_index(), addr, stride: 1 }, None)
+            }
+            _ => return Err(ExecuteError::InvalidInstruction),
+        };
+
+        let result = self
+            .tvpu
+            .execute_operation(operation, self.state.vector_registers_mut(), &mut self.memory)?;
+
+        if let (Some(rd), Some(value)) = (rd, result) {
+            self.write_gpr(rd, value);
+            self.update_flags_from_result(value);
+        }
         Ok(())
     }
     
     /// Met à jour les flags en fonction du résultat d'une opération
-    fn update_flags_from_result(&mut self, _result: Word) {
-        // Implémentation à venir
+    fn update_flags_from_result(&mut self, result: Word) {
+        let mut flags = self.read_flags();
+        flags.zf = result.trytes().iter().all(|tryte| matches!(tryte, Tryte::Digit(13)));
+        flags.sf = result.is_negative();
+        flags.xf = result.trytes().iter().any(|tryte| !matches!(tryte, Tryte::Digit(_)));
+        self.write_flags(flags);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::memory::SimpleMemory;
-    use crate::cpu::CPU;
-    
-    // Fonction utilitaire pour convertir un Word en i32
-    fn word_to_i32(word: Word) -> i32 {
-        word.trytes().iter().enumerate().fold(0, |acc, (i, tryte)| {
-            let val = tryte.bal3_value() as i32;
-            acc + val * 27i32.pow(i as u32)
-        })
-    }
+    use crate::cpu::execute::Cpu;
     
     #[test]
     fn test_ternary_instruction() {
-        let mut memory = SimpleMemory::new(1024);
-        let mut cpu = CPU::new(memory);
+        let mut cpu = Cpu::with_memory_size(1024);
         
         // Initialiser les registres
-        let a = Word::from_int(5);
-        let b = Word::from_int(3);
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(5);
+        let b = Word::from(3);
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TMIN
-        cpu.execute_ternary(TernaryOp::TMIN, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TMIN, Register::R1, Register::R2, Register::R3).unwrap();
         
         // Vérifier le résultat
-        let result = cpu.registers.read(3);
-        assert_eq!(word_to_i32(result), 3); // min(5, 3) = 3
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(i64::from(result), -4); // Trit à trit: min(+--, 0+0) = 0--
         
         // Exécuter l'instruction TMAX
-        cpu.execute_ternary(TernaryOp::TMAX, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TMAX, Register::R1, Register::R2, Register::R3).unwrap();
         
         // Vérifier le résultat
-        let result = cpu.registers.read(3);
-        assert_eq!(word_to_i32(result), 5); // max(5, 3) = 5
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(i64::from(result), 12); // Trit à trit: max(+--, 0+0) = ++0
     }
     
     #[test]
     fn test_branch3() {
-        let mut memory = SimpleMemory::new(1024);
-        let mut cpu = CPU::new(memory);
+        let mut cpu = Cpu::with_memory_size(1024);
         
         // Initialiser le PC
-        cpu.registers.write_pc(100);
+        cpu.write_pc(Word::from(100));
         
         // Cas 1: Valeur négative
-        let neg_value = Word::from_int(-1);
-        cpu.registers.write(1, neg_value);
-        cpu.execute_branch3(1, 10, 20, 30).unwrap();
-        let pc_val = word_to_i32(cpu.registers.read_pc());
+        let neg_value = Word::from(-1);
+        cpu.write_gpr(Register::R1, neg_value);
+        cpu.execute_branch3(Register::R1, 10, 20, 30).unwrap();
+        let pc_val = i64::from(cpu.read_pc());
         assert_eq!(pc_val, 100 + 10 * 4);
         
         // Cas 2: Valeur zéro
-        let zero_value = Word::from_int(0);
-        cpu.registers.write(1, zero_value);
-        cpu.registers.write_pc(100); // Réinitialiser le PC
-        cpu.execute_branch3(1, 10, 20, 30).unwrap();
-        let pc_val = word_to_i32(cpu.registers.read_pc());
+        let zero_value = Word::from(0);
+        cpu.write_gpr(Register::R1, zero_value);
+        cpu.write_pc(Word::from(100)); // Réinitialiser le PC
+        cpu.execute_branch3(Register::R1, 10, 20, 30).unwrap();
+        let pc_val = i64::from(cpu.read_pc());
         assert_eq!(pc_val, 100 + 20 * 4);
         
         // Cas 3: Valeur positive
-        let pos_value = Word::from_int(1);
-        cpu.registers.write(1, pos_value);
-        cpu.registers.write_pc(100); // Réinitialiser le PC
-        cpu.execute_branch3(1, 10, 20, 30).unwrap();
-        let pc_val = word_to_i32(cpu.registers.read_pc());
+        let pos_value = Word::from(1);
+        cpu.write_gpr(Register::R1, pos_value);
+        cpu.write_pc(Word::from(100)); // Réinitialiser le PC
+        cpu.execute_branch3(Register::R1, 10, 20, 30).unwrap();
+        let pc_val = i64::from(cpu.read_pc());
         assert_eq!(pc_val, 100 + 30 * 4);
     }
     
-    // Autres tests pour les instructions ternaires spécialisées...
-}
\ No newline at end of file
+    #[test]
+    fn test_tvpu_instructions() {
+        use crate::cpu::registers::VectorRegister::{V1, V2, V3};
+
+        let mut cpu = Cpu::with_memory_size(1024);
+
+        // Vecteur [1, 2, ..., 8] à l'adresse 216 et vecteur de 1 à l'adresse 288
+        for i in 0..8 {
+            cpu.memory.write_word(216 + i * 8, Word::from(i as i32 + 1)).unwrap();
+            cpu.memory.write_word(288 + i * 8, Word::from(1)).unwrap();
+        }
+        cpu.write_gpr(Register::R1, Word::from(216));
+        cpu.write_gpr(Register::R2, Word::from(288));
+        cpu.write_gpr(Register::R3, Word::from(0));
+
+        let program = [
+            Instruction::VectorLoad { vd: V1, rs1: Register::R1 },
+            Instruction::VectorLoad { vd: V2, rs1: Register::R2 },
+            Instruction::VectorOp { op: VectorOp::TVADD, vd: V3, vs1: V1, vs2: V2 },
+            Instruction::VectorOp { op: VectorOp::TVMAC, vd: V3, vs1: V2, vs2: V2 },
+            Instruction::VectorStore { vs: V3, rs1: Register::R3 },
+            Instruction::VectorReduce { op: VectorReduceOp::TVSUM, rd: Register::R4, vs: V2 },
+        ];
+        for instruction in program {
+            cpu.execute(instruction).unwrap();
+        }
+
+        // V3 = V1 + 1 puis V3 += 1 * 1, relu depuis la mémoire dans l'ordre des éléments
+        for i in 0..8 {
+            assert_eq!(cpu.memory.read_word(i * 8).unwrap().to_i64(), i as i64 + 3);
+        }
+        // Somme des éléments de V2 écrite dans le registre général R4
+        assert_eq!(cpu.read_gpr(Register::R4).to_i64(), 8);
+
+        // Latences: 10 + 10 + 2 + 5 + 10 + 6 cycles
+        assert_eq!(cpu.tvpu.operation_count, 6);
+        assert_eq!(cpu.tvpu.cycle_count, 43);
+
+        // Accès vectoriel non aligné
+        cpu.write_gpr(Register::R1, Word::from(4));
+        assert_eq!(
+            cpu.execute(Instruction::VectorLoad { vd: V1, rs1: Register::R1 }),
+            Err(ExecuteError::UnalignedAddress)
+        );
+    }
+}
@@ -5,45 +5,9 @@
 use crate::core::{Trit, Word, Tryte};
 use crate::cpu::registers::Register;
 
-/// Opérations ternaires spécialisées
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TernaryOp {
-    TMIN,   // Minimum ternaire (par trit)
-    TMAX,   // Maximum ternaire (par trit)
-    TSUM,   // Somme ternaire (par trit)
-    TCMP3,  // Comparaison ternaire à 3 états
-}
-
-/// Opérations de rotation et décalage ternaires
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TernaryShiftOp {
-    TROTL,  // Rotation ternaire à gauche
-    TROTR,  // Rotation ternaire à droite
-    TSHIFTL, // Décalage ternaire à gauche
-    TSHIFTR, // Décalage ternaire à droite
-}
-
-/// Opérations pour les états spéciaux
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum SpecialStateOp {
-    ISNULL,   // Teste si un registre contient NULL
-    ISNAN,    // Teste si un registre contient NaN
-    ISUNDEF,  // Teste si un registre contient UNDEF
-    SETNULL,  // Définit un registre à NULL
-    SETNAN,   // Définit un registre à NaN
-    SETUNDEF, // Définit un registre à UNDEF
-}
-
-/// Opérations arithmétiques en base 24
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Base24Op {
-    ADDB24,  // Addition en base 24
-    SUBB24,  // Soustraction en base 24
-    MULB24,  // Multiplication en base 24
-    DIVB24,  // Division en base 24
-    CVTB24,  // Conversion en base 24
-    CVTFRB24, // Conversion depuis la base 24
-}
+// Les opérations des extensions font partie de l'ISA partagée, afin qu'elles
+// puissent être encodées par l'assembleur et décodées par le simulateur
+pub use crate::cpu::isa::{Base24Op, SpecialStateOp, TernaryOp, TernaryShiftOp};
 
 /// Extensions du jeu d'instructions
 #[derive(Debug, Clone, PartialEq)]
@@ -186,7 +150,7 @@ pub fn execute_ternary_op(op: TernaryOp, a: Word, b: Word) -> Word {
 /// Implémentation des opérations de rotation et décalage ternaires
 pub fn execute_ternary_shift(op: TernaryShiftOp, a: Word, shift: i32) -> Word {
     let mut result = Word::zero();
-    let shift_abs = shift.abs() as usize % 24;
+    let shift_abs = shift.unsigned_abs() as usize % 24;
     
     match op {
         TernaryShiftOp::TROTL => {
@@ -393,8 +357,8 @@ pub fn execute_base24_op(op: Base24Op, a: Word, b: Word) -> Word {
             let mut carry = 0;
             
             for i in 0..8 {
-                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
-                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
+                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 
                 let sum = digit_a + digit_b + carry;
                 carry = sum / 24;
@@ -408,8 +372,8 @@ pub fn execute_base24_op(op: Base24Op, a: Word, b: Word) -> Word {
             let mut borrow = 0;
             
             for i in 0..8 {
-                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
-                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
+                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 
                 let mut diff = digit_a - digit_b - borrow;
                 
@@ -425,13 +389,13 @@ pub fn execute_base24_op(op: Base24Op, a: Word, b: Word) -> Word {
         },
         Base24Op::MULB24 => {
             // Multiplication en base 24 (simplifiée)
-            let mut temp_result = vec![0; 16]; // Résultat temporaire avec espace pour le débordement
+            let mut temp_result = [0; 16]; // Résultat temporaire avec espace pour le débordement
             
             for i in 0..8 {
-                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 
                 for j in 0..8 {
-                    let digit_b = tryte_to_base24(&b.tryte(j).map_or(Tryte::Digit(13), |t| t.clone()));
+                    let digit_b = tryte_to_base24(&b.tryte(j).map_or(Tryte::Digit(13), |t| *t));
                     let product = digit_a * digit_b;
                     
                     // Ajouter le produit à la position appropriée
@@ -446,8 +410,8 @@ pub fn execute_base24_op(op: Base24Op, a: Word, b: Word) -> Word {
             }
             
             // Copier les 8 premiers chiffres dans le résultat
-            for i in 0..8 {
-                result.set_tryte(i, base24_to_tryte(temp_result[i]));
+            for (i, &digit) in temp_result.iter().take(8).enumerate() {
+                result.set_tryte(i, base24_to_tryte(digit));
             }
         },
         Base24Op::DIVB24 => {
@@ -459,10 +423,10 @@ pub fn execute_base24_op(op: Base24Op, a: Word, b: Word) -> Word {
         Base24Op::CVTB24 => {
             // Conversion en base 24 (d'un entier standard)
             // Pour le POC, on suppose que a contient un entier en représentation standard
-            let mut value = a.to_i32(); // Méthode à implémenter
+            let mut value = a.to_i64();
             
             for i in 0..8 {
-                let digit = value % 24;
+                let digit = (value % 24) as i32;
                 value /= 24;
                 
                 result.set_tryte(i, base24_to_tryte(digit));
@@ -475,13 +439,13 @@ pub fn execute_base24_op(op: Base24Op, a: Word, b: Word) -> Word {
             let mut multiplier = 1;
             
             for i in 0..8 {
-                let digit = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 value += digit * multiplier;
                 multiplier *= 24;
             }
             
             // Convertir l'entier en représentation Word
-            result = Word::from_i32(value); // Méthode à implémenter
+            result = Word::from(value); // Méthode à implémenter
         },
     }
     
@@ -500,7 +464,7 @@ fn tryte_to_base24(tryte: &Tryte) -> i32 {
 
 /// Fonction auxiliaire pour convertir un chiffre base 24 en tryte
 fn base24_to_tryte(digit: i32) -> Tryte {
-    if digit < 0 || digit >= 24 {
+    if !(0..24).contains(&digit) {
         // Valeur invalide, retourner un tryte nul
         return Tryte::Digit(13); // 13 représente zéro en ternaire équilibré
     }
@@ -537,8 +501,8 @@ pub fn base24_op(a: Word, b: Word, op: fn(i32, i32) -> i32) -> Word {
             let mut carry = 0;
             
             for i in 0..8 {
-                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
-                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
+                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 
                 let sum = digit_a + digit_b + carry;
                 carry = sum / 24;
@@ -551,8 +515,8 @@ pub fn base24_op(a: Word, b: Word, op: fn(i32, i32) -> i32) -> Word {
             let mut borrow = 0;
             
             for i in 0..8 {
-                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
-                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
+                let digit_b = tryte_to_base24(&b.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 
                 let mut diff = digit_a - digit_b - borrow;
                 
@@ -568,13 +532,13 @@ pub fn base24_op(a: Word, b: Word, op: fn(i32, i32) -> i32) -> Word {
         },
         // Multiplication base 24
         _ if op(2, 2) == 4 => { // Multiplication
-            let mut temp_result = vec![0; 16]; // Résultat temporaire avec espace pour le débordement
+            let mut temp_result = [0; 16]; // Résultat temporaire avec espace pour le débordement
             
             for i in 0..8 {
-                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
+                let digit_a = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| *t));
                 
                 for j in 0..8 {
-                    let digit_b = tryte_to_base24(&b.tryte(j).map_or(Tryte::Digit(13), |t| t.clone()));
+                    let digit_b = tryte_to_base24(&b.tryte(j).map_or(Tryte::Digit(13), |t| *t));
                     let product = digit_a * digit_b;
                     
                     // Ajouter le produit à la position appropriée
@@ -592,8 +556,8 @@ pub fn base24_op(a: Word, b: Word, op: fn(i32, i32) -> i32) -> Word {
             }
             
             // Copier le résultat dans le mot de sortie (tronqué aux 8 premiers trytes)
-            for i in 0..8 {
-                result.set_tryte(i, base24_to_tryte(temp_result[i]));
+            for (i, &digit) in temp_result.iter().take(8).enumerate() {
+                result.set_tryte(i, base24_to_tryte(digit));
             }
         },
         // Division base 24 (non implémentée ici, retourne simplement a)
@@ -602,17 +566,3 @@ pub fn base24_op(a: Word, b: Word, op: fn(i32, i32) -> i32) -> Word {
     
     result
 }
-
-/// Convertit un mot de 8 trytes en entier base 24
-pub fn word_to_int24(a: Word) -> i32 {
-    let mut value = 0;
-    let mut multiplier = 1;
-    
-    for i in 0..8 {
-        let digit = tryte_to_base24(&a.tryte(i).map_or(Tryte::Digit(13), |t| t.clone()));
-        value += digit * multiplier;
-        multiplier *= 24;
-    }
-    
-    value
-}
\ No newline at end of file
@@ -1,9 +1,14 @@
 // src/cpu/registers.rs
 
-use crate::core::Word;
+use crate::core::{MAX_ADDRESS, Word};
 use crate::core::{Trit, Tryte};
+use crate::tvpu::TernaryVector;
+use crate::tvpu_hardware::VECTOR_REGISTERS;
 use std::fmt;
 
+/// Valeur initiale de SP: dernier Mot aligné de la mémoire (pile descendante)
+pub const INITIAL_SP: usize = MAX_ADDRESS - 8;
+
 /// Représente les drapeaux (flags) du processeur
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Flags {
@@ -56,62 +61,8 @@ impl fmt::Display for Flags {
     }
 }
 
-/// Énumération des registres généraux du processeur
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum Register {
-    R0,
-    R1,
-    R2,
-    R3,
-    R4,
-    R5,
-    R6,
-    R7,
-}
-
-impl Register {
-    /// Convertit un registre en son index (0-7)
-    pub fn to_index(&self) -> usize {
-        match self {
-            Register::R0 => 0,
-            Register::R1 => 1,
-            Register::R2 => 2,
-            Register::R3 => 3,
-            Register::R4 => 4,
-            Register::R5 => 5,
-            Register::R6 => 6,
-            Register::R7 => 7,
-        }
-    }
-
-    /// Crée un registre à partir d'un index (0-7)
-    pub fn from_index(index: usize) -> Result<Self, RegisterError> {
-        match index {
-            0 => Ok(Register::R0),
-            1 => Ok(Register::R1),
-            2 => Ok(Register::R2),
-            3 => Ok(Register::R3),
-            4 => Ok(Register::R4),
-            5 => Ok(Register::R5),
-            6 => Ok(Register::R6),
-            7 => Ok(Register::R7),
-            _ => Err(RegisterError::InvalidIndex),
-        }
-    }
-}
-
-impl fmt::Display for Register {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "R{}", self.to_index())
-    }
-}
-
-/// Erreurs possibles lors de l'accès aux registres
-#[derive(Debug, PartialEq, Eq)]
-pub enum RegisterError {
-    InvalidIndex,       // Index de registre invalide (hors de la plage 0-7)
-    PrivilegeViolation, // Tentative d'accès à un registre privilégié depuis un mode non privilégié
-}
+// Les registres généraux et leurs erreurs sont définis dans le crate partagé prismchrono_isa
+pub use prismchrono_isa::{Register, RegisterError, VectorRegister};
 
 /// Niveaux de privilège du processeur
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -131,6 +82,12 @@ pub enum TrapCause {
     LoadFault,    // Erreur d'accès mémoire en lecture
     StoreFault,   // Erreur d'accès mémoire en écriture
     BreakPoint,   // Point d'arrêt pour le débogage (EBREAK)
+    Misaligned,   // Accès mémoire ou cible de saut non alignés
+    DivByZero,    // Division ou modulo par zéro
+    SpecialState, // Opérande dans un état spécial (NaN/UNDEF) utilisé comme adresse ou diviseur
+    InstrPageFault, // Faute de page lors de la lecture d'une instruction
+    LoadPageFault,  // Faute de page lors d'une lecture
+    StorePageFault, // Faute de page lors d'une écriture
 }
 
 impl TrapCause {
@@ -144,6 +101,12 @@ impl TrapCause {
             TrapCause::LoadFault => 4,
             TrapCause::StoreFault => 5,
             TrapCause::BreakPoint => 6,
+            TrapCause::Misaligned => 7,
+            TrapCause::DivByZero => 8,
+            TrapCause::SpecialState => 9,
+            TrapCause::InstrPageFault => 10,
+            TrapCause::LoadPageFault => 11,
+            TrapCause::StorePageFault => 12,
         }
     }
 
@@ -157,15 +120,67 @@ impl TrapCause {
             4 => Some(TrapCause::LoadFault),
             5 => Some(TrapCause::StoreFault),
             6 => Some(TrapCause::BreakPoint),
+            7 => Some(TrapCause::Misaligned),
+            8 => Some(TrapCause::DivByZero),
+            9 => Some(TrapCause::SpecialState),
+            10 => Some(TrapCause::InstrPageFault),
+            11 => Some(TrapCause::LoadPageFault),
+            12 => Some(TrapCause::StorePageFault),
             _ => None,
         }
     }
 }
 
+/// Trit du drapeau `code` (interruption ou cause de trap) dans mie_t, mip_t, medeleg_t et
+/// mideleg_t, None si le code dépasse le registre
+/// Les trits 1 et 2 d'un tryte ne peuvent être tous deux à P (valeurs 11 à 13, réservées aux
+/// états spéciaux): chaque tryte porte deux drapeaux, sur ses trits 0 et 1.
+pub fn flag_trit(code: i8) -> Option<usize> {
+    let code = usize::try_from(code).ok().filter(|&code| code < 16)?;
+    Some(3 * (code / 2) + code % 2)
+}
+
+/// Sources d'interruption asynchrones
+/// Le code d'une interruption désigne son drapeau dans mie_t, mip_t et mideleg_t (flag_trit).
+/// mcause_t/scause_t reçoivent `-1 - code`: une cause négative désigne une interruption,
+/// une cause positive ou nulle une exception (TrapCause).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Interrupt {
+    Timer,    // Interruption du timer machine
+    External, // Interruption externe (contrôleur d'interruptions)
+    Software, // Interruption logicielle (levée en écrivant mip_t)
+}
+
+impl Interrupt {
+    /// Convertit une interruption en code (indice de trit)
+    pub fn to_code(&self) -> i8 {
+        match self {
+            Interrupt::Timer => 0,
+            Interrupt::External => 1,
+            Interrupt::Software => 2,
+        }
+    }
+
+    /// Valeur écrite dans mcause_t/scause_t lors de la prise de l'interruption
+    pub fn cause_code(&self) -> i8 {
+        -1 - self.to_code()
+    }
+}
+
+/// Trits d'autorisation globale des interruptions dans mstatus_t (MPP_t occupe les trits 0 et 1)
+pub const MSTATUS_MIE: usize = 3; // Interruptions autorisées en mode Machine
+pub const MSTATUS_MPIE: usize = 4; // Valeur de MIE avant le dernier trap en mode Machine
+
+/// Trits d'autorisation globale des interruptions dans sstatus_t (SPP_t occupe le trit 0)
+/// SPIE n'occupe pas le trit 2: SIE et SPIE doivent pouvoir être levés ensemble (voir flag_trit)
+pub const SSTATUS_SIE: usize = 1; // Interruptions autorisées en mode Supervisor
+pub const SSTATUS_SPIE: usize = 3; // Valeur de SIE avant le dernier trap en mode Supervisor
+
 /// Représente l'état complet du processeur
 #[derive(Debug, Clone)]
 pub struct ProcessorState {
     gpr: [Word; 8], // Registres généraux (General Purpose Registers)
+    vregs: [TernaryVector; VECTOR_REGISTERS], // Registres vectoriels de la TVPU (V0-V7)
     pc: Word,       // Compteur de programme (Program Counter)
     sp: Word,       // Pointeur de pile (Stack Pointer)
     fr: Flags,      // Drapeaux (Flags Register)
@@ -180,6 +195,9 @@ pub struct ProcessorState {
     pub mcause_t: Word,  // Machine Cause Register
     pub medeleg_t: Word, // Machine Exception Delegation Register
     pub mideleg_t: Word, // Machine Interrupt Delegation Register
+    pub mie_t: Word,     // Machine Interrupt Enable Register (trit i = P: interruption i autorisée)
+    pub mip_t: Word,     // Machine Interrupt Pending Register (trit i = P: interruption i en attente)
+    pub satp_t: Word,    // Supervisor Address Translation and Protection (mode et table racine, cf. mmu.rs)
     
     // CSRs ternaires pour le mode Supervisor
     pub sstatus_t: Word, // Supervisor Status Register
@@ -191,10 +209,11 @@ pub struct ProcessorState {
 impl ProcessorState {
     /// Crée un nouvel état de processeur avec des valeurs par défaut
     pub fn new() -> Self {
-        let state = ProcessorState {
+        ProcessorState {
             gpr: [Word::default_undefined(); 8],
+            vregs: std::array::from_fn(|_| TernaryVector::new()), // Vecteurs à zéro
             pc: Word::zero(),                           // PC commence à 0
-            sp: Word::default_undefined(),              // SP sera initialisé à MAX_ADDRESS
+            sp: Word::from(INITIAL_SP as i32),          // Dernier Mot de la mémoire (pile descendante)
             fr: Flags::new(),                           // Flags à 0
             current_privilege: PrivilegeLevel::Machine, // Démarre en mode Machine
             
@@ -205,28 +224,21 @@ impl ProcessorState {
             mcause_t: Word::zero(),                     // Machine Cause Register initialisé à 0
             medeleg_t: Word::zero(),                    // Machine Exception Delegation Register initialisé à 0
             mideleg_t: Word::zero(),                    // Machine Interrupt Delegation Register initialisé à 0
+            mie_t: Word::zero(),                        // Aucune interruption autorisée
+            mip_t: Word::zero(),                        // Aucune interruption en attente
+            satp_t: Word::zero(),                       // Mode Bare: pas de traduction
             
             // CSRs du mode Supervisor
             sstatus_t: Word::zero(),                    // Supervisor Status Register initialisé à 0
             stvec_t: Word::zero(),                      // Supervisor Trap Vector Register initialisé à 0
             sepc_t: Word::zero(),                       // Supervisor Exception Program Counter initialisé à 0
             scause_t: Word::zero(),                     // Supervisor Cause Register initialisé à 0
-        };
-
-        // Initialise SP à MAX_ADDRESS (haut de la mémoire)
-        // Note: Ceci est une simplification, dans un système réel,
-        // nous devrions convertir MAX_ADDRESS en Word correctement
-        // Pour l'instant, on utilise un Word par défaut
-
-        // TODO: Implémenter la conversion de MAX_ADDRESS en Word
-        // state.sp = Word::from_value(MAX_ADDRESS);
-
-        state
+        }
     }
 
     /// Lit la valeur d'un registre général
     pub fn read_gpr(&self, reg: Register) -> Word {
-        self.gpr[reg.to_index()].clone()
+        self.gpr[reg.to_index()]
     }
 
     /// Écrit une valeur dans un registre général
@@ -234,9 +246,29 @@ impl ProcessorState {
         self.gpr[reg.to_index()] = value;
     }
 
+    /// Lit la valeur d'un registre vectoriel
+    pub fn read_vreg(&self, reg: VectorRegister) -> TernaryVector {
+        self.vregs[reg.to_index()].clone()
+    }
+
+    /// Écrit une valeur dans un registre vectoriel
+    pub fn write_vreg(&mut self, reg: VectorRegister, value: TernaryVector) {
+        self.vregs[reg.to_index()] = value;
+    }
+
+    /// Banc de registres vectoriels complet, en lecture
+    pub fn vector_registers(&self) -> &[TernaryVector] {
+        &self.vregs
+    }
+
+    /// Banc de registres vectoriels complet (utilisé par la TVPU)
+    pub fn vector_registers_mut(&mut self) -> &mut [TernaryVector] {
+        &mut self.vregs
+    }
+
     /// Lit la valeur du compteur de programme (PC)
     pub fn read_pc(&self) -> Word {
-        self.pc.clone()
+        self.pc
     }
 
     /// Écrit une valeur dans le compteur de programme (PC)
@@ -246,7 +278,7 @@ impl ProcessorState {
 
     /// Lit la valeur du pointeur de pile (SP)
     pub fn read_sp(&self) -> Word {
-        self.sp.clone()
+        self.sp
     }
 
     /// Écrit une valeur dans le pointeur de pile (SP)
@@ -256,7 +288,7 @@ impl ProcessorState {
 
     /// Lit les drapeaux
     pub fn read_flags(&self) -> Flags {
-        self.fr.clone()
+        self.fr
     }
 
     /// Écrit de nouvelles valeurs dans les drapeaux
@@ -273,20 +305,27 @@ impl ProcessorState {
     pub fn read_csr(&self, csr: i8) -> Result<Word, RegisterError> {
         match csr {
             // CSRs du mode Machine (0-3)
-            0 => Ok(self.mstatus_t.clone()),
-            1 => Ok(self.mtvec_t.clone()),
-            2 => Ok(self.mepc_t.clone()),
-            3 => Ok(self.mcause_t.clone()),
+            0 => Ok(self.mstatus_t),
+            1 => Ok(self.mtvec_t),
+            2 => Ok(self.mepc_t),
+            3 => Ok(self.mcause_t),
             
             // CSRs du mode Supervisor (4-7)
-            4 => Ok(self.sstatus_t.clone()),
-            5 => Ok(self.stvec_t.clone()),
-            6 => Ok(self.sepc_t.clone()),
-            7 => Ok(self.scause_t.clone()),
+            4 => Ok(self.sstatus_t),
+            5 => Ok(self.stvec_t),
+            6 => Ok(self.sepc_t),
+            7 => Ok(self.scause_t),
             
             // Registres de délégation (8-9)
-            8 => Ok(self.medeleg_t.clone()),
-            9 => Ok(self.mideleg_t.clone()),
+            8 => Ok(self.medeleg_t),
+            9 => Ok(self.mideleg_t),
+
+            // Registres d'interruption (10-11)
+            10 => Ok(self.mie_t),
+            11 => Ok(self.mip_t),
+
+            // Traduction d'adresses (12)
+            12 => Ok(self.satp_t),
             
             _ => Err(RegisterError::InvalidIndex),
         }
@@ -340,6 +379,22 @@ impl ProcessorState {
                 self.mideleg_t = value;
                 Ok(())
             }
+
+            // Registres d'interruption (10-11)
+            10 => {
+                self.mie_t = value;
+                Ok(())
+            }
+            11 => {
+                self.mip_t = value;
+                Ok(())
+            }
+
+            // Traduction d'adresses (12)
+            12 => {
+                self.satp_t = value;
+                Ok(())
+            }
             
             _ => Err(RegisterError::InvalidIndex),
         }
@@ -348,12 +403,12 @@ impl ProcessorState {
     /// Effectue un OR bit à bit entre la valeur actuelle d'un CSR et une nouvelle valeur
     pub fn set_csr(&mut self, csr: i8, value: Word) -> Result<(), RegisterError> {
         let current = self.read_csr(csr)?;
-        let mut result = current.clone();
+        let mut result = current;
 
         // Effectuer un OR bit à bit entre les trytes
         for i in 0..8 {
-            if let (Some(current_tryte), Some(value_tryte)) = (current.tryte(i), value.tryte(i)) {
-                if let (Tryte::Digit(current_val), Tryte::Digit(value_val)) =
+            if let (Some(current_tryte), Some(value_tryte)) = (current.tryte(i), value.tryte(i))
+                && let (Tryte::Digit(current_val), Tryte::Digit(value_val)) =
                     (current_tryte, value_tryte)
                 {
                     // Convertir en valeurs Bal3
@@ -381,27 +436,34 @@ impl ProcessorState {
                         *tryte = result_tryte;
                     }
                 }
-            }
         }
 
         self.write_csr(csr, result)
     }
 
+    /// Remet à zéro les trits d'un CSR désignés par les trits positifs de `value` (CSRRC)
+    /// Les autres trits sont conservés
+    pub fn clear_csr(&mut self, csr: i8, value: Word) -> Result<(), RegisterError> {
+        let mut result = self.read_csr(csr)?;
+        for i in 0..24 {
+            if value.get_trit(i) == Trit::P {
+                result.set_trit(i, Trit::Z);
+            }
+        }
+        self.write_csr(csr, result)
+    }
+
     /// Obtient le niveau de privilège précédent à partir de mstatus_t.MPP_t
     pub fn get_previous_privilege(&self) -> PrivilegeLevel {
         // Le champ MPP_t est stocké dans les 2 premiers trits du premier tryte de mstatus_t
-        if let Some(tryte) = self.mstatus_t.tryte(0) {
-            if let Tryte::Digit(val) = tryte {
-                let trits = Tryte::Digit(*val).to_trits();
-                // Utiliser les 2 premiers trits pour déterminer le niveau de privilège
-                match (trits[0], trits[1]) {
-                    (Trit::Z, Trit::Z) => PrivilegeLevel::User,       // 00 = User
-                    (Trit::Z, Trit::P) => PrivilegeLevel::Supervisor, // 01 = Supervisor
-                    (Trit::P, Trit::Z) => PrivilegeLevel::Machine,    // 10 = Machine
-                    _ => PrivilegeLevel::User, // Par défaut, retourner User pour les combinaisons non définies
-                }
-            } else {
-                PrivilegeLevel::User // Par défaut, retourner User
+        if let Some(&Tryte::Digit(val)) = self.mstatus_t.tryte(0) {
+            let trits = Tryte::Digit(val).to_trits();
+            // Utiliser les 2 premiers trits pour déterminer le niveau de privilège
+            match (trits[0], trits[1]) {
+                (Trit::Z, Trit::Z) => PrivilegeLevel::User,       // 00 = User
+                (Trit::Z, Trit::P) => PrivilegeLevel::Supervisor, // 01 = Supervisor
+                (Trit::P, Trit::Z) => PrivilegeLevel::Machine,    // 10 = Machine
+                _ => PrivilegeLevel::User, // Par défaut, retourner User pour les combinaisons non définies
             }
         } else {
             PrivilegeLevel::User // Par défaut, retourner User
@@ -411,31 +473,31 @@ impl ProcessorState {
     /// Définit le niveau de privilège précédent dans mstatus_t.MPP_t
     pub fn set_previous_privilege(&mut self, privilege: PrivilegeLevel) {
         // Le champ MPP_t est stocké dans les 2 premiers trits du premier tryte de mstatus_t
-        if let Some(tryte) = self.mstatus_t.tryte(0) {
-            if let Tryte::Digit(val) = tryte {
-                let mut trits = Tryte::Digit(*val).to_trits();
-
-                // Définir les 2 premiers trits en fonction du niveau de privilège
-                match privilege {
-                    PrivilegeLevel::User => {
-                        trits[0] = Trit::Z;
-                        trits[1] = Trit::Z;
-                    }
-                    PrivilegeLevel::Supervisor => {
-                        trits[0] = Trit::Z;
-                        trits[1] = Trit::P;
-                    }
-                    PrivilegeLevel::Machine => {
-                        trits[0] = Trit::P;
-                        trits[1] = Trit::Z;
-                    }
-                }
+        if let Some(tryte) = self.mstatus_t.tryte(0)
+            && let Tryte::Digit(val) = tryte
+        {
+            let mut trits = Tryte::Digit(*val).to_trits();
 
-                // Convertir les trits en tryte et mettre à jour mstatus_t
-                let new_tryte = Tryte::from_trits(trits);
-                if let Some(tryte_mut) = self.mstatus_t.tryte_mut(0) {
-                    *tryte_mut = new_tryte;
+            // Définir les 2 premiers trits en fonction du niveau de privilège
+            match privilege {
+                PrivilegeLevel::User => {
+                    trits[0] = Trit::Z;
+                    trits[1] = Trit::Z;
+                }
+                PrivilegeLevel::Supervisor => {
+                    trits[0] = Trit::Z;
+                    trits[1] = Trit::P;
                 }
+                PrivilegeLevel::Machine => {
+                    trits[0] = Trit::P;
+                    trits[1] = Trit::Z;
+                }
+            }
+
+            // Convertir les trits en tryte et mettre à jour mstatus_t
+            let new_tryte = Tryte::from_trits(trits);
+            if let Some(tryte_mut) = self.mstatus_t.tryte_mut(0) {
+                *tryte_mut = new_tryte;
             }
         }
     }
@@ -458,14 +520,10 @@ impl ProcessorState {
     /// Obtient la cause du trap à partir de mcause_t
     pub fn get_trap_cause(&self) -> Option<TrapCause> {
         // Lire le code de cause à partir du premier tryte de mcause_t
-        if let Some(tryte) = self.mcause_t.tryte(0) {
-            if let Tryte::Digit(val) = tryte {
-                let bal3 = (*val as i8) - 13;
-                return TrapCause::from_code(bal3);
-            }
-        }
-
-        None
+        // Les codes 11 et 12 occupent les valeurs Bal3 des états spéciaux: seuls les trits comptent
+        self.mcause_t
+            .tryte(0)
+            .and_then(|tryte| TrapCause::from_code(tryte.bal3_value()))
     }
 }
 
@@ -480,6 +538,20 @@ mod tests {
     use super::*;
     use crate::core::Tryte;
 
+    #[test]
+    fn test_initial_sp_addresses_last_word() {
+        use crate::core::address_from_word;
+        use crate::memory::Memory;
+
+        // SP désigne un Mot aligné entièrement dans la mémoire: on peut y empiler et relire
+        let state = ProcessorState::new();
+        let sp = address_from_word(&state.read_sp()).unwrap();
+        assert_eq!(sp + 8, MAX_ADDRESS);
+        let mut memory = Memory::new();
+        memory.write_word(sp, Word::from(-42)).unwrap();
+        assert_eq!(memory.read_word(sp), Ok(Word::from(-42)));
+    }
+
     #[test]
     fn test_register_conversion() {
         // Test de conversion Register -> index -> Register
@@ -537,17 +609,18 @@ mod tests {
 
         // Test des registres généraux
         let test_word = Word([Tryte::Digit(5); 8]);
-        state.write_gpr(Register::R3, test_word.clone());
+        state.write_gpr(Register::R3, test_word);
         assert_eq!(state.read_gpr(Register::R3), test_word);
 
         // Test du PC
         let pc_value = Word([Tryte::Digit(10); 8]);
-        state.write_pc(pc_value.clone());
+        state.write_pc(pc_value);
         assert_eq!(state.read_pc(), pc_value);
 
-        // Test du SP
+        // Test du SP (initialisé au dernier Mot de la mémoire)
+        assert_eq!(state.read_sp().to_i64(), INITIAL_SP as i64);
         let sp_value = Word([Tryte::Digit(20); 8]);
-        state.write_sp(sp_value.clone());
+        state.write_sp(sp_value);
         assert_eq!(state.read_sp(), sp_value);
 
         // Test des flags
@@ -556,7 +629,7 @@ mod tests {
         flags.sf = false;
         flags.of = true;
         flags.cf = true;
-        state.write_flags(flags.clone());
+        state.write_flags(flags);
         assert_eq!(state.read_flags(), flags);
 
         state.reset_flags();
@@ -566,4 +639,19 @@ mod tests {
         assert!(!state.fr.of);
         assert!(!state.fr.cf);
     }
+
+    #[test]
+    fn test_set_and_clear_csr() {
+        let mut state = ProcessorState::new();
+
+        // CSRRS: trits 1 et 3 levés (OR ternaire)
+        state.write_csr(10, Word::from(1)).unwrap();
+        state.set_csr(10, Word::from(3 + 24)).unwrap();
+        assert_eq!(state.read_csr(10).unwrap().to_i64(), 1 + 3 + 24);
+
+        // CSRRC: seuls les trits positifs de la valeur sont remis à zéro
+        state.write_csr(10, Word::from(-1 + 3 + 24)).unwrap();
+        state.clear_csr(10, Word::from(24 - 1)).unwrap();
+        assert_eq!(state.read_csr(10).unwrap().to_i64(), -1 + 3);
+    }
 }
@@ -5,21 +5,22 @@
 mod tests {
     use crate::core::Trit;
     use crate::cpu::compact_format::{CompactInstruction, decode_compact, compact_to_standard};
-    use crate::cpu::isa::{AluOp, Condition, Instruction};
-    use crate::cpu::decode::DecodeError;
+    use crate::cpu::isa::{AluOp, BranchCondition, Instruction};
+    use crate::cpu::registers::Register;
+    
 
     #[test]
     fn test_decode_compact_cmov() {
         // Créer une instruction CMOV rd=1, rs=2
-        // [op=CMOV(NN) | rd=1(NP) | rs=2(NNPN)]
-        let instr_trits = [Trit::N, Trit::N, Trit::N, Trit::P, Trit::N, Trit::N, Trit::P, Trit::N];
+        // [op=CMOV(NN) | rd=1(ZP) | rs=2(ZZPN)], champs de poids fort en premier
+        let instr_trits = [Trit::N, Trit::N, Trit::Z, Trit::P, Trit::Z, Trit::Z, Trit::P, Trit::N];
         
         let result = decode_compact(&instr_trits).unwrap();
         
         match result {
             CompactInstruction::CMov { rd, rs } => {
-                assert_eq!(rd, 1);
-                assert_eq!(rs, 2);
+                assert_eq!(rd, Register::R1);
+                assert_eq!(rs, Register::R2);
             },
             _ => panic!("Expected CMov instruction"),
         }
@@ -28,15 +29,15 @@ mod tests {
     #[test]
     fn test_decode_compact_cadd() {
         // Créer une instruction CADD rd=2, rs=3
-        // [op=CADD(NZ) | rd=2(PN) | rs=3(NNPP)]
-        let instr_trits = [Trit::N, Trit::Z, Trit::P, Trit::N, Trit::N, Trit::N, Trit::P, Trit::P];
+        // [op=CADD(NZ) | rd=2(PN) | rs=3(ZZPZ)]
+        let instr_trits = [Trit::N, Trit::Z, Trit::P, Trit::N, Trit::Z, Trit::Z, Trit::P, Trit::Z];
         
         let result = decode_compact(&instr_trits).unwrap();
         
         match result {
             CompactInstruction::CAdd { rd, rs } => {
-                assert_eq!(rd, 2);
-                assert_eq!(rs, 3);
+                assert_eq!(rd, Register::R2);
+                assert_eq!(rs, Register::R3);
             },
             _ => panic!("Expected CAdd instruction"),
         }
@@ -45,15 +46,15 @@ mod tests {
     #[test]
     fn test_decode_compact_csub() {
         // Créer une instruction CSUB rd=3, rs=1
-        // [op=CSUB(NP) | rd=3(PP) | rs=1(NNNP)]
-        let instr_trits = [Trit::N, Trit::P, Trit::P, Trit::P, Trit::N, Trit::N, Trit::N, Trit::P];
+        // [op=CSUB(NP) | rd=3(PZ) | rs=1(ZZZP)]
+        let instr_trits = [Trit::N, Trit::P, Trit::P, Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::P];
         
         let result = decode_compact(&instr_trits).unwrap();
         
         match result {
             CompactInstruction::CSub { rd, rs } => {
-                assert_eq!(rd, 3);
-                assert_eq!(rs, 1);
+                assert_eq!(rd, Register::R3);
+                assert_eq!(rs, Register::R1);
             },
             _ => panic!("Expected CSub instruction"),
         }
@@ -62,15 +63,15 @@ mod tests {
     #[test]
     fn test_decode_compact_cbranch() {
         // Créer une instruction CBRANCH cond=Eq, offset=5
-        // [op=CBRANCH(NN) | cond=Eq(NN) | offset=5(NNNP)]
-        let instr_trits = [Trit::N, Trit::N, Trit::N, Trit::N, Trit::N, Trit::N, Trit::N, Trit::P];
+        // [op=CBRANCH(ZN) | cond=Eq(ZZ) | offset=5(ZPNN)]
+        let instr_trits = [Trit::Z, Trit::N, Trit::Z, Trit::Z, Trit::Z, Trit::P, Trit::N, Trit::N];
         
         let result = decode_compact(&instr_trits).unwrap();
         
         match result {
             CompactInstruction::CBranch { cond, offset } => {
-                assert_eq!(cond, Condition::Eq);
-                assert!(offset > 0); // La valeur exacte peut varier selon l'implémentation
+                assert_eq!(cond, 0);
+                assert_eq!(offset, 5);
             },
             _ => panic!("Expected CBranch instruction"),
         }
@@ -79,58 +80,76 @@ mod tests {
     #[test]
     fn test_compact_to_standard_conversion() {
         // Tester la conversion de CMov en instruction standard
-        let cmov = CompactInstruction::CMov { rd: 1, rs: 2 };
+        let cmov = CompactInstruction::CMov { rd: Register::R1, rs: Register::R2 };
         let std_instr = compact_to_standard(cmov);
         
         match std_instr {
-            Instruction::AluReg { op, rs1, rs2, rd } => {
+            Instruction::AluImm { op, rs1, rd, imm } => {
                 assert_eq!(op, AluOp::Add);
-                assert_eq!(rs1, 2);
-                assert_eq!(rs2, 0);
-                assert_eq!(rd, 1);
+                assert_eq!(rs1, Register::R2);
+                assert_eq!(rd, Register::R1);
+                assert_eq!(imm, 0);
             },
-            _ => panic!("Expected AluReg instruction"),
+            _ => panic!("Expected AluImm instruction"),
         }
         
         // Tester la conversion de CAdd en instruction standard
-        let cadd = CompactInstruction::CAdd { rd: 2, rs: 3 };
+        let cadd = CompactInstruction::CAdd { rd: Register::R2, rs: Register::R3 };
         let std_instr = compact_to_standard(cadd);
         
         match std_instr {
             Instruction::AluReg { op, rs1, rs2, rd } => {
                 assert_eq!(op, AluOp::Add);
-                assert_eq!(rs1, 2);
-                assert_eq!(rs2, 3);
-                assert_eq!(rd, 2);
+                assert_eq!(rs1, Register::R2);
+                assert_eq!(rs2, Register::R3);
+                assert_eq!(rd, Register::R2);
             },
             _ => panic!("Expected AluReg instruction"),
         }
         
         // Tester la conversion de CSub en instruction standard
-        let csub = CompactInstruction::CSub { rd: 3, rs: 1 };
+        let csub = CompactInstruction::CSub { rd: Register::R3, rs: Register::R1 };
         let std_instr = compact_to_standard(csub);
         
         match std_instr {
             Instruction::AluReg { op, rs1, rs2, rd } => {
                 assert_eq!(op, AluOp::Sub);
-                assert_eq!(rs1, 3);
-                assert_eq!(rs2, 1);
-                assert_eq!(rd, 3);
+                assert_eq!(rs1, Register::R3);
+                assert_eq!(rs2, Register::R1);
+                assert_eq!(rd, Register::R3);
             },
             _ => panic!("Expected AluReg instruction"),
         }
         
         // Tester la conversion de CBranch en instruction standard
-        let cbranch = CompactInstruction::CBranch { cond: Condition::Eq, offset: 10 };
+        let cbranch = CompactInstruction::CBranch { cond: 0, offset: 10 };
         let std_instr = compact_to_standard(cbranch);
         
         match std_instr {
             Instruction::Branch { cond, rs1, offset } => {
-                assert_eq!(cond, Condition::Eq);
-                assert_eq!(rs1, 0);
+                assert_eq!(cond, BranchCondition::Zero);
+                assert_eq!(rs1, Register::R0);
                 assert_eq!(offset, 10);
             },
             _ => panic!("Expected Branch instruction"),
         }
     }
+
+    #[test]
+    fn test_cmov_copies_register_with_nonzero_r0() {
+        use crate::cpu::decode::decode;
+        use crate::cpu::execute::Cpu;
+        use crate::cpu::state::CpuState;
+        use crate::core::Word;
+
+        // CMOV R1, R2 ([NN | ZP | ZZPN]) avec R0 non nul: R1 reçoit R2, pas max(R2, R0)
+        let mut cpu = Cpu::with_memory_size(1024);
+        cpu.write_gpr(Register::R0, Word::from(9));
+        cpu.write_gpr(Register::R2, Word::from(-4));
+        let instr_trits = [Trit::N, Trit::N, Trit::Z, Trit::P, Trit::Z, Trit::Z, Trit::P, Trit::N];
+        cpu.execute(decode(instr_trits).unwrap()).unwrap();
+
+        assert_eq!(cpu.read_gpr(Register::R1).to_i64(), -4);
+        assert_eq!(cpu.read_gpr(Register::R0).to_i64(), 9);
+    }
 }
\ No newline at end of file
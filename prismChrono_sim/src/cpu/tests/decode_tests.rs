@@ -1,35 +1,25 @@
 // src/cpu/tests/decode_tests.rs
 // Tests unitaires pour le décodeur d'instructions
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::Trit;
 use crate::cpu::decode::{DecodeError, decode};
-use crate::cpu::isa::{AluOp, Condition, Instruction, InstructionFormat, Opcode};
+use crate::cpu::isa::{AluOp, BranchCondition, Instruction};
 use crate::cpu::registers::Register;
 
 #[test]
 fn test_decode_alu_reg() {
     // Créer une instruction ALU format R (ADD R3, R1, R2)
-    // Opcode ALU = [N,N,N] (-13)
-    // rd = R3 = [N,Z,N] (-10)
-    // rs1 = R1 = [N,N,Z] (-12)
-    // rs2 = R2 = [N,N,P] (-11)
-    // func = ADD = [N,N,N] (-13)
+    // Opcode R_TYPE = [Z,P,Z] (3)
+    // rd = R3 = [N,Z] (-1)
+    // rs1 = R1 = [Z,N] (-3)
+    // rs2 = R2 = [P,N] (-2)
+    // func = ADD = [Z,Z,Z] (0)
     let instr_trits = [
-        Trit::N,
-        Trit::N,
-        Trit::N, // Opcode ALU
-        Trit::N,
-        Trit::Z,
-        Trit::N, // rd = R3
-        Trit::N,
-        Trit::N,
-        Trit::Z, // rs1 = R1
-        Trit::N,
-        Trit::N,
-        Trit::P, // rs2 = R2
-        Trit::N,
-        Trit::N,
-        Trit::N, // func = ADD
+        Trit::Z, Trit::P, Trit::Z, // Opcode R_TYPE
+        Trit::N, Trit::Z, // rd = R3
+        Trit::Z, Trit::N, // rs1 = R1
+        Trit::P, Trit::N, // rs2 = R2
+        Trit::Z, Trit::Z, Trit::Z, // func = ADD
     ];
 
     let result = decode(instr_trits);
@@ -48,33 +38,22 @@ fn test_decode_alu_reg() {
 #[test]
 fn test_decode_alu_imm() {
     // Créer une instruction ALU format I (ADDI R3, R1, 5)
-    // Opcode AluI = [N,N,Z] (-12)
-    // rd = R3 = [N,Z,N] (-10)
-    // rs1 = R1 = [N,N,Z] (-12)
-    // imm = 5 = [P,P,N,Z,Z] (5)
+    // Opcode ADDI = [Z,P,N] (-6)
+    // rd = R3 = [N,Z] (-1)
+    // rs1 = R1 = [Z,N] (-3)
+    // imm = 5 = [N,N,P,Z,Z] (5)
     let instr_trits = [
-        Trit::N,
-        Trit::N,
-        Trit::Z, // Opcode AluI
-        Trit::N,
-        Trit::Z,
-        Trit::N, // rd = R3
-        Trit::N,
-        Trit::N,
-        Trit::Z, // rs1 = R1
-        Trit::P,
-        Trit::P,
-        Trit::N, // imm = 5
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // imm (suite)
+        Trit::Z, Trit::P, Trit::N, // Opcode ADDI
+        Trit::N, Trit::Z, // rd = R3
+        Trit::Z, Trit::N, // rs1 = R1
+        Trit::N, Trit::N, Trit::P, Trit::Z, Trit::Z, // imm = 5
     ];
 
     let result = decode(instr_trits);
     assert!(result.is_ok());
 
     if let Ok(Instruction::AluImm { op, rs1, rd, imm }) = result {
-        assert_eq!(op, AluOp::Add); // Par défaut pour ADDI
+        assert_eq!(op, AluOp::Add);
         assert_eq!(rs1, Register::R1);
         assert_eq!(rd, Register::R3);
         assert_eq!(imm, 5);
@@ -86,26 +65,15 @@ fn test_decode_alu_imm() {
 #[test]
 fn test_decode_load() {
     // Créer une instruction Load (LOADW R4, 3(R2))
-    // Opcode Load = [N,N,P] (-11)
-    // rd = R4 = [N,Z,P] (-9)
-    // rs1 = R2 = [N,N,P] (-11)
-    // offset = 3 = [P,Z,Z,Z,Z] (3)
+    // Opcode LOADW = [N,Z,Z] (-1)
+    // rd = R4 = [Z,Z] (0)
+    // rs1 = R2 = [P,N] (-2)
+    // offset = 3 = [Z,P,Z,Z,Z] (3)
     let instr_trits = [
-        Trit::N,
-        Trit::N,
-        Trit::P, // Opcode Load
-        Trit::N,
-        Trit::Z,
-        Trit::P, // rd = R4
-        Trit::N,
-        Trit::N,
-        Trit::P, // rs1 = R2
-        Trit::P,
-        Trit::Z,
-        Trit::Z, // offset = 3
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // offset (suite)
+        Trit::N, Trit::Z, Trit::Z, // Opcode LOADW
+        Trit::Z, Trit::Z, // rd = R4
+        Trit::P, Trit::N, // rs1 = R2
+        Trit::Z, Trit::P, Trit::Z, Trit::Z, Trit::Z, // offset = 3
     ];
 
     let result = decode(instr_trits);
@@ -122,27 +90,16 @@ fn test_decode_load() {
 
 #[test]
 fn test_decode_store() {
-    // Créer une instruction Store (STOREW R2, R5, 7)
-    // Opcode Store = [N,Z,N] (-10)
-    // rs2 = R5 = [N,P,N] (-8)
-    // rs1 = R2 = [N,N,P] (-11)
-    // offset = 7 = [P,Z,P,Z,Z] (7)
+    // Créer une instruction Store (STOREW R5, 7(R2))
+    // Opcode STOREW = [N,P,N] (-7)
+    // src = rs2 = R5 = [P,Z] (1)
+    // base = rs1 = R2 = [P,N] (-2)
+    // offset = 7 = [P,N,P,Z,Z] (7)
     let instr_trits = [
-        Trit::N,
-        Trit::Z,
-        Trit::N, // Opcode Store
-        Trit::N,
-        Trit::P,
-        Trit::N, // rs2 = R5
-        Trit::N,
-        Trit::N,
-        Trit::P, // rs1 = R2
-        Trit::P,
-        Trit::Z,
-        Trit::P, // offset = 7
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // offset (suite)
+        Trit::N, Trit::P, Trit::N, // Opcode STOREW
+        Trit::P, Trit::Z, // rs2 = R5
+        Trit::P, Trit::N, // rs1 = R2
+        Trit::P, Trit::N, Trit::P, Trit::Z, Trit::Z, // offset = 7
     ];
 
     let result = decode(instr_trits);
@@ -160,26 +117,15 @@ fn test_decode_store() {
 #[test]
 fn test_decode_branch() {
     // Créer une instruction Branch (BRANCH EQ, R3, 4)
-    // Opcode Branch = [N,Z,P] (-9)
-    // cond = EQ = [N,N,N] (-13)
-    // rs1 = R3 = [N,Z,N] (-10)
+    // Opcode BRANCH = [N,N,Z] (-4)
+    // cond = EQ = [Z,Z,Z] (0)
+    // rs1 = R3 = [N,Z] (-1)
     // offset = 4 = [P,P,Z,Z] (4)
     let instr_trits = [
-        Trit::N,
-        Trit::Z,
-        Trit::P, // Opcode Branch
-        Trit::N,
-        Trit::N,
-        Trit::N, // cond = EQ
-        Trit::N,
-        Trit::Z,
-        Trit::N, // rs1 = R3
-        Trit::P,
-        Trit::P,
-        Trit::Z, // offset = 4
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // offset (suite)
+        Trit::N, Trit::N, Trit::Z, // Opcode BRANCH
+        Trit::Z, Trit::Z, Trit::Z, // cond = EQ
+        Trit::N, Trit::Z, // rs1 = R3
+        Trit::P, Trit::P, Trit::Z, Trit::Z, // offset = 4
     ];
 
     let result = decode(instr_trits);
@@ -187,7 +133,7 @@ fn test_decode_branch() {
 
     if let Ok(Instruction::Branch { rs1, cond, offset }) = result {
         assert_eq!(rs1, Register::R3);
-        assert_eq!(cond, Condition::Eq);
+        assert_eq!(cond, BranchCondition::Zero);
         assert_eq!(offset, 4);
     } else {
         panic!("Expected Branch instruction");
@@ -197,22 +143,13 @@ fn test_decode_branch() {
 #[test]
 fn test_decode_jump() {
     // Créer une instruction Jump (JAL R7, 42)
-    // Opcode Jump = [N,Z,P] (-8)
-    // rd = R7 = [N,P,P] (-6)
-    // offset = 42 (valeur à encoder en ternaire)
+    // Opcode JAL = [P,N,N] (-11)
+    // rd = R7 = [P,P] (4)
+    // offset = 42 = 81 - 27 - 9 - 3 = [Z,N,N,N,P,Z,Z]
     let instr_trits = [
-        Trit::N,
-        Trit::Z,
-        Trit::P, // Opcode Jump
-        Trit::N,
-        Trit::P,
-        Trit::P, // rd = R7
-        Trit::P,
-        Trit::P,
-        Trit::P, // offset (partie haute)
-        Trit::P,
-        Trit::P,
-        Trit::P, // offset (partie basse)
+        Trit::P, Trit::N, Trit::N, // Opcode JAL
+        Trit::P, Trit::P, // rd = R7
+        Trit::Z, Trit::N, Trit::N, Trit::N, Trit::P, Trit::Z, Trit::Z, // offset = 42
     ];
 
     let result = decode(instr_trits);
@@ -220,8 +157,7 @@ fn test_decode_jump() {
 
     if let Ok(Instruction::Jump { rd, offset }) = result {
         assert_eq!(rd, Register::R7);
-        // Vérifier que l'offset est une valeur non nulle
-        assert_ne!(offset, 0);
+        assert_eq!(offset, 42);
     } else {
         panic!("Expected Jump instruction");
     }
@@ -230,22 +166,13 @@ fn test_decode_jump() {
 #[test]
 fn test_decode_lui() {
     // Créer une instruction LUI (LUI R5, 100)
-    // Opcode LUI = [N,P,N] (-5)
-    // rd = R5 = [N,P,N] (-8)
-    // imm = 100 (valeur à encoder en ternaire)
+    // Opcode LUI = [P,Z,N] (-8)
+    // rd = R5 = [P,Z] (1)
+    // imm = 100 = 81 + 27 - 9 + 1 = [P,Z,N,P,P,Z,Z]
     let instr_trits = [
-        Trit::N,
-        Trit::P,
-        Trit::N, // Opcode LUI
-        Trit::N,
-        Trit::P,
-        Trit::N, // rd = R5
-        Trit::P,
-        Trit::Z,
-        Trit::P, // imm (partie haute)
-        Trit::P,
-        Trit::Z,
-        Trit::P, // imm (partie basse)
+        Trit::P, Trit::Z, Trit::N, // Opcode LUI
+        Trit::P, Trit::Z, // rd = R5
+        Trit::P, Trit::Z, Trit::N, Trit::P, Trit::P, Trit::Z, Trit::Z, // imm = 100
     ];
 
     let result = decode(instr_trits);
@@ -253,36 +180,47 @@ fn test_decode_lui() {
 
     if let Ok(Instruction::Lui { rd, imm }) = result {
         assert_eq!(rd, Register::R5);
-        // Vérifier que l'immédiat est une valeur non nulle
-        assert_ne!(imm, 0);
+        assert_eq!(imm, 100);
     } else {
         panic!("Expected Lui instruction");
     }
 }
 
+#[test]
+fn test_decode_auipc() {
+    // Créer une instruction AUIPC (AUIPC R6, -5)
+    // Opcode AUIPC = [P,Z,Z] (1)
+    // rd = R6 = [N,P] (2)
+    // imm = -5 = -9 + 3 + 1 = [P,P,N,Z,Z,Z,Z]
+    let instr_trits = [
+        Trit::P, Trit::Z, Trit::Z, // Opcode AUIPC
+        Trit::N, Trit::P, // rd = R6
+        Trit::P, Trit::P, Trit::N, Trit::Z, Trit::Z, Trit::Z, Trit::Z, // imm = -5
+    ];
+
+    let result = decode(instr_trits);
+    assert!(result.is_ok());
+
+    if let Ok(Instruction::Auipc { rd, imm }) = result {
+        assert_eq!(rd, Register::R6);
+        assert_eq!(imm, -5);
+    } else {
+        panic!("Expected Auipc instruction");
+    }
+}
+
 #[test]
 fn test_decode_jalr() {
     // Créer une instruction JALR (JALR R4, -2(R2))
-    // Opcode JALR = [N,P,P] (-3)
-    // rd = R4 = [N,Z,P] (-9)
-    // rs1 = R2 = [N,N,P] (-11)
-    // offset = -2 = [N,N,Z,Z,Z] (-2)
+    // Opcode JALR = [P,N,P] (7)
+    // rd = R4 = [Z,Z] (0)
+    // rs1 = R2 = [P,N] (-2)
+    // offset = -2 = [P,N,Z,Z,Z] (-2)
     let instr_trits = [
-        Trit::N,
-        Trit::P,
-        Trit::P, // Opcode JALR
-        Trit::N,
-        Trit::Z,
-        Trit::P, // rd = R4
-        Trit::N,
-        Trit::N,
-        Trit::P, // rs1 = R2
-        Trit::N,
-        Trit::N,
-        Trit::Z, // offset = -2
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // offset (suite)
+        Trit::P, Trit::N, Trit::P, // Opcode JALR
+        Trit::Z, Trit::Z, // rd = R4
+        Trit::P, Trit::N, // rs1 = R2
+        Trit::P, Trit::N, Trit::Z, Trit::Z, Trit::Z, // offset = -2
     ];
 
     let result = decode(instr_trits);
@@ -299,21 +237,13 @@ fn test_decode_jalr() {
 
 #[test]
 fn test_invalid_opcode() {
-    // Créer une instruction avec un opcode invalide
-    // Opcode invalide = [P,P,P] (13)
+    // Créer une instruction avec un opcode réservé
+    // Opcode SLTI = [P,Z,P] (10, réservé)
     let instr_trits = [
-        Trit::P,
-        Trit::P,
-        Trit::P, // Opcode invalide
-        Trit::N,
-        Trit::N,
-        Trit::Z, // rd = R1
-        Trit::N,
-        Trit::N,
-        Trit::P, // rs1 = R2
-        Trit::N,
-        Trit::Z,
-        Trit::N, // rs2 = R3
+        Trit::P, Trit::Z, Trit::P, // Opcode réservé
+        Trit::Z, Trit::N, // rd = R1
+        Trit::P, Trit::N, // rs1 = R2
+        Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::Z, // imm = 0
     ];
 
     let result = decode(instr_trits);
@@ -324,23 +254,14 @@ fn test_invalid_opcode() {
 #[test]
 fn test_invalid_register() {
     // Créer une instruction avec un registre invalide
-    // Opcode ALU = [N,N,N] (-13)
-    // rd = Invalide = [P,P,P] (13)
-    // rs1 = R2 = [N,N,P] (-11)
-    // rs2 = R3 = [N,Z,N] (-10)
+    // Opcode R_TYPE = [Z,P,Z] (3)
+    // rd = Invalide = [Z,P] (3)
     let instr_trits = [
-        Trit::N,
-        Trit::N,
-        Trit::N, // Opcode ALU
-        Trit::P,
-        Trit::P,
-        Trit::P, // rd = Invalide
-        Trit::N,
-        Trit::N,
-        Trit::P, // rs1 = R2
-        Trit::N,
-        Trit::Z,
-        Trit::N, // rs2 = R3
+        Trit::Z, Trit::P, Trit::Z, // Opcode R_TYPE
+        Trit::Z, Trit::P, // rd = Invalide
+        Trit::P, Trit::N, // rs1 = R2
+        Trit::N, Trit::Z, // rs2 = R3
+        Trit::Z, Trit::Z, Trit::Z, // func = ADD
     ];
 
     let result = decode(instr_trits);
@@ -350,85 +271,47 @@ fn test_invalid_register() {
 
 #[test]
 fn test_invalid_format() {
-    // Créer une instruction avec un format invalide
-    // Opcode ALU = [N,N,N] (-13) mais avec des champs incorrects
-    let instr_trits = [
-        Trit::N,
-        Trit::N,
-        Trit::N, // Opcode ALU
-        Trit::P,
-        Trit::P,
-        Trit::P, // Champs incorrects
-        Trit::P,
-        Trit::P,
-        Trit::P, // pour le format R
-        Trit::P,
-        Trit::P,
-        Trit::P, //
-    ];
+    // Une instruction de 10 trits n'est ni compacte (8) ni standard (12)
+    let instr_trits = [Trit::Z; 10];
 
     let result = decode(instr_trits);
     assert!(result.is_err());
-    // Le type d'erreur exact dépend de l'implémentation
-    // Cela pourrait être InvalidRegister ou InvalidFormat
-    assert!(matches!(
-        result.unwrap_err(),
-        DecodeError::InvalidRegister | DecodeError::InvalidFormat
-    ));
+    assert_eq!(result.unwrap_err(), DecodeError::InvalidFormat);
 }
 
 #[test]
 fn test_decode_system() {
     // Créer une instruction System (HALT)
-    // Opcode System = [N,Z,N] (-6)
-    // func = HALT = [N,N,N] (-13)
+    // Opcode SYSTEM = [N,N,N] (-13)
+    // func = HALT = [Z,Z,Z] (0)
     let instr_trits = [
-        Trit::N,
-        Trit::Z,
-        Trit::N, // Opcode System
-        Trit::N,
-        Trit::N,
-        Trit::N, // func = HALT
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // unused
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // unused
+        Trit::N, Trit::N, Trit::N, // Opcode SYSTEM
+        Trit::Z, Trit::Z, Trit::Z, // func = HALT
+        Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::Z, // inutilisé
     ];
 
     let result = decode(instr_trits);
     assert!(result.is_ok());
 
     if let Ok(Instruction::System { func }) = result {
-        assert_eq!(func, -13); // HALT a la valeur -13
+        assert_eq!(func, 0); // HALT a la valeur 0
     } else {
         panic!("Expected System instruction");
     }
 
     // Créer une instruction System (NOP)
-    // Opcode System = [N,Z,N] (-6)
-    // func = NOP = [N,N,Z] (-12)
+    // func = NOP = [P,Z,Z] (1)
     let instr_trits = [
-        Trit::N,
-        Trit::Z,
-        Trit::N, // Opcode System
-        Trit::N,
-        Trit::N,
-        Trit::Z, // func = NOP
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // unused
-        Trit::Z,
-        Trit::Z,
-        Trit::Z, // unused
+        Trit::N, Trit::N, Trit::N, // Opcode SYSTEM
+        Trit::P, Trit::Z, Trit::Z, // func = NOP
+        Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::Z, Trit::Z, // inutilisé
     ];
 
     let result = decode(instr_trits);
     assert!(result.is_ok());
 
     if let Ok(Instruction::System { func }) = result {
-        assert_eq!(func, -12); // NOP a la valeur -12
+        assert_eq!(func, 1); // NOP a la valeur 1
     } else {
         panic!("Expected System instruction");
     }
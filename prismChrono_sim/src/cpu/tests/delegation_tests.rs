@@ -1,11 +1,12 @@
 // src/cpu/tests/delegation_tests.rs
 // Tests pour les fonctionnalités de délégation des traps et des interruptions
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Address, Trit, Tryte, Word};
+use crate::memory::MemoryError;
 use crate::cpu::execute::ExecuteError;
-use crate::cpu::execute_system::{CpuState, CsrOperations, DelegationOperations, SystemOperations};
-use crate::cpu::registers::{PrivilegeLevel, Register, TrapCause};
-use crate::cpu::supervisor_privilege::SupervisorPrivilegeOperations;
+use crate::cpu::state::CpuState;
+use crate::cpu::execute_system::{DelegationOperations, SystemOperations};
+use crate::cpu::registers::{PrivilegeLevel, Register, TrapCause, flag_trit};
 
 // Structure de test qui implémente CpuState pour tester les opérations de délégation
 struct TestCpu {
@@ -21,8 +22,8 @@ struct TestCpu {
 impl TestCpu {
     fn new() -> Self {
         TestCpu {
-            pc: Word::from_int(0),
-            registers: [Word::from_int(0); 8],
+            pc: Word::from(0),
+            registers: [Word::from(0); 8],
             halted: false,
             privilege: PrivilegeLevel::Machine,
             previous_privilege: PrivilegeLevel::User,
@@ -60,8 +61,8 @@ impl CpuState for TestCpu {
     }
 
     fn state_read_csr(&self, csr: i8) -> Result<Word, crate::cpu::registers::RegisterError> {
-        if csr >= 0 && csr < 10 {
-            Ok(self.csrs[csr as usize].clone())
+        if (0..10).contains(&csr) {
+            Ok(self.csrs[csr as usize])
         } else {
             Err(crate::cpu::registers::RegisterError::InvalidIndex)
         }
@@ -72,7 +73,7 @@ impl CpuState for TestCpu {
         csr: i8,
         value: Word,
     ) -> Result<(), crate::cpu::registers::RegisterError> {
-        if csr >= 0 && csr < 10 {
+        if (0..10).contains(&csr) {
             self.csrs[csr as usize] = value;
             Ok(())
         } else {
@@ -85,20 +86,19 @@ impl CpuState for TestCpu {
         csr: i8,
         value: Word,
     ) -> Result<(), crate::cpu::registers::RegisterError> {
-        if csr >= 0 && csr < 10 {
-            let current = self.csrs[csr as usize].clone();
-            let mut result = current.clone();
+        if (0..10).contains(&csr) {
+            let current = self.csrs[csr as usize];
+            let mut result = current;
 
             // Effectuer un OR bit à bit entre les trytes
             for i in 0..8 {
                 if let (Some(current_tryte), Some(value_tryte)) = (current.tryte(i), value.tryte(i))
-                {
-                    if let (Tryte::Digit(current_val), Tryte::Digit(value_val)) =
+                    && let (Tryte::Digit(current_val), Tryte::Digit(value_val)) =
                         (current_tryte, value_tryte)
                     {
                         // Convertir en valeurs Bal3
-                        let current_bal3 = (*current_val as i8) - 13;
-                        let value_bal3 = (*value_val as i8) - 13;
+                        let _current_bal3 = (*current_val as i8) - 13;
+                        let _value_bal3 = (*value_val as i8) - 13;
 
                         // Effectuer l'opération OR sur les trits individuels
                         let mut result_trits = [Trit::Z; 3];
@@ -121,7 +121,6 @@ impl CpuState for TestCpu {
                             *tryte = result_tryte;
                         }
                     }
-                }
             }
 
             self.csrs[csr as usize] = result;
@@ -163,6 +162,47 @@ impl CpuState for TestCpu {
     fn read_flags(&self) -> crate::cpu::registers::Flags {
         crate::cpu::registers::Flags::new() // Simplifié pour les tests
     }
+
+    fn write_flags(&mut self, _flags: crate::cpu::registers::Flags) {
+        // Flags ignorés dans ce test
+    }
+
+    fn read_tryte(&self, _addr: Address) -> Result<Tryte, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds)) // Pas de mémoire dans ce test
+    }
+
+    fn write_tryte(&mut self, _addr: Address, _value: Tryte) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn read_word(&self, _addr: Address) -> Result<Word, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn write_word(&mut self, _addr: Address, _value: Word) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn state_clear_csr(
+        &mut self,
+        csr: i8,
+        value: Word,
+    ) -> Result<(), crate::cpu::registers::RegisterError> {
+        if (0..10).contains(&csr) {
+            // AND ternaire avec la négation: min(a, inv(b))
+            let current = self.csrs[csr as usize];
+            let mut result = Word::zero();
+            for i in 0..24 {
+                let a = current.get_trit(i);
+                let b = value.get_trit(i).inv();
+                result.set_trit(i, if a.value() < b.value() { a } else { b });
+            }
+            self.csrs[csr as usize] = result;
+            Ok(())
+        } else {
+            Err(crate::cpu::registers::RegisterError::InvalidIndex)
+        }
+    }
 }
 
 #[test]
@@ -176,11 +216,7 @@ fn test_is_trap_delegated() {
     
     // Configurer medeleg_t pour déléguer EcallU (code 0)
     let mut medeleg = Word::zero();
-    if let Some(tryte) = medeleg.tryte_mut(0) {
-        let mut trits = [Trit::Z; 3];
-        trits[0] = Trit::P; // Activer la délégation pour EcallU (code 0)
-        *tryte = Tryte::from_trits(trits);
-    }
+    medeleg.set_trit(flag_trit(TrapCause::EcallU.to_code()).unwrap(), Trit::P);
     cpu.state_write_csr(8, medeleg).unwrap();
     
     // Vérifier que EcallU est maintenant délégué
@@ -192,11 +228,7 @@ fn test_is_trap_delegated() {
     
     // Configurer medeleg_t pour déléguer aussi IllegalInstr (code 3)
     let mut medeleg = cpu.state_read_csr(8).unwrap();
-    if let Some(tryte) = medeleg.tryte_mut(1) { // 3 / 3 = 1 (index du tryte)
-        let mut trits = [Trit::Z; 3];
-        trits[0] = Trit::P; // 3 % 3 = 0 (index du trit)
-        *tryte = Tryte::from_trits(trits);
-    }
+    medeleg.set_trit(flag_trit(TrapCause::IllegalInstr.to_code()).unwrap(), Trit::P); // Trit 4
     cpu.state_write_csr(8, medeleg).unwrap();
     
     // Vérifier que EcallU et IllegalInstr sont maintenant délégués
@@ -232,11 +264,7 @@ fn test_is_interrupt_delegated() {
     
     // Configurer mideleg_t pour déléguer aussi l'interruption externe (code 1)
     let mut mideleg = cpu.state_read_csr(9).unwrap();
-    if let Some(tryte) = mideleg.tryte_mut(0) { // 1 / 3 = 0 (index du tryte)
-        let mut trits = Tryte::Digit(*mideleg.tryte(0).unwrap().as_digit().unwrap()).to_trits();
-        trits[1] = Trit::P; // 1 % 3 = 1 (index du trit)
-        *tryte = Tryte::from_trits(trits);
-    }
+    mideleg.set_trit(flag_trit(1).unwrap(), Trit::P); // Trit 1
     cpu.state_write_csr(9, mideleg).unwrap();
     
     // Vérifier que les interruptions timer et externe sont maintenant déléguées
@@ -302,6 +330,7 @@ fn test_set_interrupt_delegation() {
 #[test]
 fn test_privilege_violation_delegation() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
+    cpu.state_write_csr(1, Word::from(0x1000)).unwrap(); // Gestionnaire de trap Machine
     
     // Tenter de configurer la délégation depuis le mode User (devrait échouer)
     let result = cpu.set_trap_delegation(TrapCause::EcallU, true);
@@ -312,6 +341,7 @@ fn test_privilege_violation_delegation() {
     
     // Réinitialiser pour le prochain test
     cpu = TestCpu::with_privilege(PrivilegeLevel::Supervisor);
+    cpu.state_write_csr(1, Word::from(0x1000)).unwrap();
     
     // Tenter de configurer la délégation depuis le mode Supervisor (devrait échouer)
     let result = cpu.set_trap_delegation(TrapCause::EcallU, true);
@@ -326,16 +356,16 @@ fn test_ecall_delegation_chain() {
     let mut cpu = TestCpu::new();
     
     // Configurer mtvec_t (adresse de traitement des exceptions en mode Machine)
-    let machine_trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
+    let machine_trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, machine_trap_handler).unwrap();
     
     // Configurer stvec_t (adresse de traitement des exceptions en mode Supervisor)
-    let supervisor_trap_handler = Word::from_int(0x2000);
-    cpu.state_write_csr(5, supervisor_trap_handler.clone()).unwrap();
+    let supervisor_trap_handler = Word::from(0x2000);
+    cpu.state_write_csr(5, supervisor_trap_handler).unwrap();
     
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
     
     // Configurer medeleg_t pour déléguer EcallU au mode Supervisor
     cpu.set_trap_delegation(TrapCause::EcallU, true).unwrap();
@@ -349,8 +379,8 @@ fn test_ecall_delegation_chain() {
     assert_eq!(cpu.read_pc(), supervisor_trap_handler);
     
     // Exécuter ECALL depuis le mode Supervisor
-    let new_pc = Word::from_int(0x200);
-    cpu.write_pc(new_pc.clone());
+    let new_pc = Word::from(0x200);
+    cpu.write_pc(new_pc);
     cpu.execute_system(2).unwrap(); // ECALL
     
     // Vérifier que le trap a été traité en mode Machine
@@ -364,15 +394,15 @@ fn test_delegation_with_supervisor_return() {
     let mut cpu = TestCpu::new();
     
     // Configurer mtvec_t et stvec_t
-    let machine_trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
+    let machine_trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, machine_trap_handler).unwrap();
     
-    let supervisor_trap_handler = Word::from_int(0x2000);
-    cpu.state_write_csr(5, supervisor_trap_handler.clone()).unwrap();
+    let supervisor_trap_handler = Word::from(0x2000);
+    cpu.state_write_csr(5, supervisor_trap_handler).unwrap();
     
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
     
     // Configurer medeleg_t pour déléguer EcallU au mode Supervisor
     cpu.set_trap_delegation(TrapCause::EcallU, true).unwrap();
@@ -386,8 +416,8 @@ fn test_delegation_with_supervisor_return() {
     assert_eq!(cpu.read_pc(), supervisor_trap_handler);
     
     // Configurer sepc_t pour le retour
-    let return_addr = Word::from_int(0x300);
-    cpu.state_write_csr(6, return_addr.clone()).unwrap();
+    let return_addr = Word::from(0x300);
+    cpu.state_write_csr(6, return_addr).unwrap();
     
     // Exécuter SRET pour retourner au mode User
     cpu.execute_sret().unwrap();
@@ -1,19 +1,14 @@
 // src/cpu/tests/execute_alu_tests.rs
 // Tests pour les instructions ALU
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Tryte, Word};
 use crate::cpu::execute::ExecuteError;
 use crate::cpu::execute_alu::AluOperations;
 use crate::cpu::isa::AluOp;
-use crate::cpu::registers::{Flags, Register};
-
-// Trait CpuState pour les opérations ALU
-pub trait CpuState {
-    fn read_gpr(&self, reg: Register) -> Word;
-    fn write_gpr(&mut self, reg: Register, value: Word);
-    fn read_flags(&self) -> Flags;
-    fn write_flags(&mut self, flags: Flags);
-}
+use crate::core::Address;
+use crate::cpu::registers::{Flags, PrivilegeLevel, Register, RegisterError, TrapCause};
+use crate::cpu::state::CpuState;
+use crate::memory::MemoryError;
 
 // Structure de test qui implémente CpuState pour tester les opérations ALU
 struct TestCpu {
@@ -24,7 +19,7 @@ struct TestCpu {
 impl TestCpu {
     fn new() -> Self {
         TestCpu {
-            registers: [Word::from_int(0); 8],
+            registers: [Word::from(0); 8],
             flags: Flags::new(),
         }
     }
@@ -46,6 +41,60 @@ impl CpuState for TestCpu {
     fn write_flags(&mut self, flags: Flags) {
         self.flags = flags;
     }
+
+    fn read_pc(&self) -> Word {
+        Word::zero()
+    }
+
+    fn write_pc(&mut self, _value: Word) {}
+
+    fn set_halted(&mut self, _halted: bool) {}
+
+    fn read_tryte(&self, _addr: Address) -> Result<Tryte, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds)) // Pas de mémoire dans ce test
+    }
+
+    fn write_tryte(&mut self, _addr: Address, _value: Tryte) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn read_word(&self, _addr: Address) -> Result<Word, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn write_word(&mut self, _addr: Address, _value: Word) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn state_read_csr(&self, _csr: i8) -> Result<Word, RegisterError> {
+        Err(RegisterError::InvalidIndex) // Pas de CSR dans ce test
+    }
+
+    fn state_write_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_set_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_clear_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_get_privilege(&self) -> PrivilegeLevel {
+        PrivilegeLevel::Machine
+    }
+
+    fn state_set_privilege(&mut self, _privilege: PrivilegeLevel) {}
+
+    fn state_get_previous_privilege(&self) -> PrivilegeLevel {
+        PrivilegeLevel::Machine
+    }
+
+    fn state_set_previous_privilege(&mut self, _privilege: PrivilegeLevel) {}
+
+    fn state_set_trap_cause(&mut self, _cause: TrapCause) {}
 }
 
 #[test]
@@ -53,20 +102,20 @@ fn test_add_operation() {
     let mut cpu = TestCpu::new();
 
     // Configurer les registres source
-    cpu.write_gpr(Register::R1, Word::from_int(5));
-    cpu.write_gpr(Register::R2, Word::from_int(3));
+    cpu.write_gpr(Register::R1, Word::from(5));
+    cpu.write_gpr(Register::R2, Word::from(3));
 
     // Exécuter ADD R1, R2, R3
     cpu.execute_alu_reg(AluOp::Add, Register::R1, Register::R2, Register::R3)
         .unwrap();
 
     // Vérifier le résultat
-    assert_eq!(cpu.read_gpr(Register::R3), Word::from_int(8));
+    assert_eq!(cpu.read_gpr(Register::R3), Word::from(8));
 
     // Vérifier les flags
     let flags = cpu.read_flags();
-    assert_eq!(flags.zf, false); // Le résultat n'est pas zéro
-    assert_eq!(flags.sf, false); // Le résultat n'est pas négatif
+    assert!(!flags.zf); // Le résultat n'est pas zéro
+    assert!(!flags.sf); // Le résultat n'est pas négatif
 }
 
 #[test]
@@ -74,20 +123,20 @@ fn test_sub_operation() {
     let mut cpu = TestCpu::new();
 
     // Configurer les registres source
-    cpu.write_gpr(Register::R1, Word::from_int(10));
-    cpu.write_gpr(Register::R2, Word::from_int(7));
+    cpu.write_gpr(Register::R1, Word::from(10));
+    cpu.write_gpr(Register::R2, Word::from(7));
 
     // Exécuter SUB R1, R2, R3
     cpu.execute_alu_reg(AluOp::Sub, Register::R1, Register::R2, Register::R3)
         .unwrap();
 
     // Vérifier le résultat
-    assert_eq!(cpu.read_gpr(Register::R3), Word::from_int(3));
+    assert_eq!(cpu.read_gpr(Register::R3), Word::from(3));
 
     // Vérifier les flags
     let flags = cpu.read_flags();
-    assert_eq!(flags.zf, false); // Le résultat n'est pas zéro
-    assert_eq!(flags.sf, false); // Le résultat n'est pas négatif
+    assert!(!flags.zf); // Le résultat n'est pas zéro
+    assert!(!flags.sf); // Le résultat n'est pas négatif
 }
 
 #[test]
@@ -95,14 +144,14 @@ fn test_add_immediate() {
     let mut cpu = TestCpu::new();
 
     // Configurer le registre source
-    cpu.write_gpr(Register::R1, Word::from_int(5));
+    cpu.write_gpr(Register::R1, Word::from(5));
 
     // Exécuter ADDI R1, R2, 10
     cpu.execute_alu_imm(AluOp::Add, Register::R1, Register::R2, 10)
         .unwrap();
 
     // Vérifier le résultat
-    assert_eq!(cpu.read_gpr(Register::R2), Word::from_int(15));
+    assert_eq!(cpu.read_gpr(Register::R2), Word::from(15));
 }
 
 #[test]
@@ -110,14 +159,14 @@ fn test_sub_immediate() {
     let mut cpu = TestCpu::new();
 
     // Configurer le registre source
-    cpu.write_gpr(Register::R1, Word::from_int(20));
+    cpu.write_gpr(Register::R1, Word::from(20));
 
     // Exécuter SUBI R1, R2, 8
     cpu.execute_alu_imm(AluOp::Sub, Register::R1, Register::R2, 8)
         .unwrap();
 
     // Vérifier le résultat
-    assert_eq!(cpu.read_gpr(Register::R2), Word::from_int(12));
+    assert_eq!(cpu.read_gpr(Register::R2), Word::from(12));
 }
 
 #[test]
@@ -125,20 +174,20 @@ fn test_zero_flag() {
     let mut cpu = TestCpu::new();
 
     // Configurer les registres source pour obtenir un résultat zéro
-    cpu.write_gpr(Register::R1, Word::from_int(5));
-    cpu.write_gpr(Register::R2, Word::from_int(5));
+    cpu.write_gpr(Register::R1, Word::from(5));
+    cpu.write_gpr(Register::R2, Word::from(5));
 
     // Exécuter SUB R1, R2, R3 (5 - 5 = 0)
     cpu.execute_alu_reg(AluOp::Sub, Register::R1, Register::R2, Register::R3)
         .unwrap();
 
     // Vérifier le résultat
-    assert_eq!(cpu.read_gpr(Register::R3), Word::from_int(0));
+    assert_eq!(cpu.read_gpr(Register::R3), Word::from(0));
 
     // Vérifier les flags
     let flags = cpu.read_flags();
-    assert_eq!(flags.zf, true); // Le résultat est zéro
-    assert_eq!(flags.sf, false); // Le résultat n'est pas négatif
+    assert!(flags.zf); // Le résultat est zéro
+    assert!(!flags.sf); // Le résultat n'est pas négatif
 }
 
 #[test]
@@ -146,18 +195,18 @@ fn test_negative_flag() {
     let mut cpu = TestCpu::new();
 
     // Configurer les registres source pour obtenir un résultat négatif
-    cpu.write_gpr(Register::R1, Word::from_int(3));
-    cpu.write_gpr(Register::R2, Word::from_int(8));
+    cpu.write_gpr(Register::R1, Word::from(3));
+    cpu.write_gpr(Register::R2, Word::from(8));
 
     // Exécuter SUB R1, R2, R3 (3 - 8 = -5)
     cpu.execute_alu_reg(AluOp::Sub, Register::R1, Register::R2, Register::R3)
         .unwrap();
 
     // Vérifier le résultat
-    assert_eq!(cpu.read_gpr(Register::R3), Word::from_int(-5));
+    assert_eq!(cpu.read_gpr(Register::R3), Word::from(-5));
 
     // Vérifier les flags
     let flags = cpu.read_flags();
-    assert_eq!(flags.zf, false); // Le résultat n'est pas zéro
-    assert_eq!(flags.sf, true); // Le résultat est négatif
+    assert!(!flags.zf); // Le résultat n'est pas zéro
+    assert!(flags.sf); // Le résultat est négatif
 }
@@ -1,12 +1,14 @@
 // src/cpu/tests/execute_branch_tests.rs
 // Tests pour les instructions de branchement
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Tryte, Word};
 use crate::cpu::execute::ExecuteError;
-use crate::cpu::execute_branch::{BranchOperations, CpuState};
-use crate::cpu::isa::Condition;
-use crate::cpu::registers::{Flags, Register};
-use crate::memory::Memory;
+use crate::cpu::execute_branch::BranchOperations;
+use crate::cpu::state::CpuState;
+use crate::cpu::isa::BranchCondition;
+use crate::core::Address;
+use crate::cpu::registers::{Flags, PrivilegeLevel, Register, RegisterError, TrapCause};
+use crate::memory::MemoryError;
 
 // Structure de test qui implémente CpuState pour tester les opérations de branchement
 struct TestCpu {
@@ -18,8 +20,8 @@ struct TestCpu {
 impl TestCpu {
     fn new() -> Self {
         TestCpu {
-            pc: Word::from_int(0),
-            registers: [Word::from_int(0); 8],
+            pc: Word::from(0),
+            registers: [Word::from(0); 8],
             flags: Flags::new(),
         }
     }
@@ -53,6 +55,58 @@ impl CpuState for TestCpu {
     fn read_flags(&self) -> Flags {
         self.flags
     }
+
+    fn write_flags(&mut self, flags: Flags) {
+        self.flags = flags;
+    }
+
+    fn set_halted(&mut self, _halted: bool) {}
+
+    fn read_tryte(&self, _addr: Address) -> Result<Tryte, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds)) // Pas de mémoire dans ce test
+    }
+
+    fn write_tryte(&mut self, _addr: Address, _value: Tryte) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn read_word(&self, _addr: Address) -> Result<Word, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn write_word(&mut self, _addr: Address, _value: Word) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn state_read_csr(&self, _csr: i8) -> Result<Word, RegisterError> {
+        Err(RegisterError::InvalidIndex) // Pas de CSR dans ce test
+    }
+
+    fn state_write_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_set_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_clear_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_get_privilege(&self) -> PrivilegeLevel {
+        PrivilegeLevel::Machine
+    }
+
+    fn state_set_privilege(&mut self, _privilege: PrivilegeLevel) {}
+
+    fn state_get_previous_privilege(&self) -> PrivilegeLevel {
+        PrivilegeLevel::Machine
+    }
+
+    fn state_set_previous_privilege(&mut self, _privilege: PrivilegeLevel) {}
+
+    fn state_set_trap_cause(&mut self, _cause: TrapCause) {}
 }
 
 #[test]
@@ -61,26 +115,27 @@ fn test_branch_eq_taken() {
     let mut cpu = TestCpu::with_flags(true, false, false);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH EQ, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Eq, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::Zero, 10).unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 + 10 = 110)
-    assert_eq!(cpu.read_pc(), Word::from_int(110));
+    // Vérifier que le PC a été mis à jour (cible relative à l'instruction suivante:
+    // 0 + 4 + 10 * 4 = 44)
+    assert_eq!(cpu.read_pc(), Word::from(44));
 }
 
 #[test]
 fn test_branch_eq_not_taken() {
     // Condition EQ (ZF=1) - ne devrait pas prendre le branchement car ZF=0
     let mut cpu = TestCpu::with_flags(false, false, false);
-    let initial_pc = Word::from_int(0);
+    let initial_pc = Word::from(0);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH EQ, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Eq, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::Zero, 10).unwrap();
 
     // Vérifier que le PC n'a pas changé
     assert_eq!(cpu.read_pc(), initial_pc);
@@ -92,26 +147,27 @@ fn test_branch_ne_taken() {
     let mut cpu = TestCpu::with_flags(false, false, false);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH NE, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Ne, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::NonZero, 10).unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 + 10 = 110)
-    assert_eq!(cpu.read_pc(), Word::from_int(110));
+    // Vérifier que le PC a été mis à jour (cible relative à l'instruction suivante:
+    // 0 + 4 + 10 * 4 = 44)
+    assert_eq!(cpu.read_pc(), Word::from(44));
 }
 
 #[test]
 fn test_branch_ne_not_taken() {
     // Condition NE (ZF=0) - ne devrait pas prendre le branchement car ZF=1
     let mut cpu = TestCpu::with_flags(true, false, false);
-    let initial_pc = Word::from_int(0);
+    let initial_pc = Word::from(0);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH NE, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Ne, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::NonZero, 10).unwrap();
 
     // Vérifier que le PC n'a pas changé
     assert_eq!(cpu.read_pc(), initial_pc);
@@ -123,26 +179,27 @@ fn test_branch_lt_taken() {
     let mut cpu = TestCpu::with_flags(false, true, false);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH LT, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Lt, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::Negative, 10).unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 + 10 = 110)
-    assert_eq!(cpu.read_pc(), Word::from_int(110));
+    // Vérifier que le PC a été mis à jour (cible relative à l'instruction suivante:
+    // 0 + 4 + 10 * 4 = 44)
+    assert_eq!(cpu.read_pc(), Word::from(44));
 }
 
 #[test]
 fn test_branch_lt_not_taken() {
     // Condition LT (SF=1) - ne devrait pas prendre le branchement car SF=0
     let mut cpu = TestCpu::with_flags(false, false, false);
-    let initial_pc = Word::from_int(0);
+    let initial_pc = Word::from(0);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH LT, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Lt, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::Negative, 10).unwrap();
 
     // Vérifier que le PC n'a pas changé
     assert_eq!(cpu.read_pc(), initial_pc);
@@ -154,26 +211,27 @@ fn test_branch_ge_taken() {
     let mut cpu = TestCpu::with_flags(false, false, false);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH GE, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Ge, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::Positive, 10).unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 + 10 = 110)
-    assert_eq!(cpu.read_pc(), Word::from_int(110));
+    // Vérifier que le PC a été mis à jour (cible relative à l'instruction suivante:
+    // 0 + 4 + 10 * 4 = 44)
+    assert_eq!(cpu.read_pc(), Word::from(44));
 }
 
 #[test]
 fn test_branch_ge_not_taken() {
     // Condition GE (SF=0) - ne devrait pas prendre le branchement car SF=1
     let mut cpu = TestCpu::with_flags(false, true, false);
-    let initial_pc = Word::from_int(0);
+    let initial_pc = Word::from(0);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH GE, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Ge, 10).unwrap();
+    cpu.execute_branch(Register::R1, BranchCondition::Positive, 10).unwrap();
 
     // Vérifier que le PC n'a pas changé
     assert_eq!(cpu.read_pc(), initial_pc);
@@ -185,27 +243,28 @@ fn test_branch_special_taken() {
     let mut cpu = TestCpu::with_flags(false, false, true);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH Special, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Special, 10)
+    cpu.execute_branch(Register::R1, BranchCondition::XS, 10)
         .unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 + 10 = 110)
-    assert_eq!(cpu.read_pc(), Word::from_int(110));
+    // Vérifier que le PC a été mis à jour (cible relative à l'instruction suivante:
+    // 0 + 4 + 10 * 4 = 44)
+    assert_eq!(cpu.read_pc(), Word::from(44));
 }
 
 #[test]
 fn test_branch_special_not_taken() {
     // Condition Special (XF=1) - ne devrait pas prendre le branchement car XF=0
     let mut cpu = TestCpu::with_flags(false, false, false);
-    let initial_pc = Word::from_int(0);
+    let initial_pc = Word::from(0);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH Special, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Special, 10)
+    cpu.execute_branch(Register::R1, BranchCondition::XS, 10)
         .unwrap();
 
     // Vérifier que le PC n'a pas changé
@@ -218,14 +277,15 @@ fn test_branch_always_taken() {
     let mut cpu = TestCpu::with_flags(false, false, false);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
     // Exécuter BRANCH Always, R1, 10
-    cpu.execute_branch(Register::R1, Condition::Always, 10)
+    cpu.execute_branch(Register::R1, BranchCondition::True, 10)
         .unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 + 10 = 110)
-    assert_eq!(cpu.read_pc(), Word::from_int(110));
+    // Vérifier que le PC a été mis à jour (cible relative à l'instruction suivante:
+    // 0 + 4 + 10 * 4 = 44)
+    assert_eq!(cpu.read_pc(), Word::from(44));
 }
 
 #[test]
@@ -234,12 +294,42 @@ fn test_branch_negative_offset() {
     let mut cpu = TestCpu::with_flags(true, false, false);
 
     // Configurer le registre de base (R1) avec une adresse
-    cpu.write_gpr(Register::R1, Word::from_int(100));
+    cpu.write_gpr(Register::R1, Word::from(100));
 
-    // Exécuter BRANCH EQ, R1, -10
-    cpu.execute_branch(Register::R1, Condition::Eq, -10)
+    // Exécuter BRANCH EQ, R1, -10 depuis l'adresse 100
+    cpu.write_pc(Word::from(100));
+    cpu.execute_branch(Register::R1, BranchCondition::Zero, -10)
         .unwrap();
 
-    // Vérifier que le PC a été mis à jour (100 - 10 = 90)
-    assert_eq!(cpu.read_pc(), Word::from_int(90));
+    // Vérifier que le PC a été mis à jour (100 + 4 - 10 * 4 = 64)
+    assert_eq!(cpu.read_pc(), Word::from(64));
+
+    // Une cible avant le début de la mémoire est refusée
+    cpu.write_pc(Word::from(0));
+    assert_eq!(
+        cpu.execute_branch(Register::R1, BranchCondition::Zero, -10),
+        Err(ExecuteError::InvalidAddress)
+    );
+}
+
+#[test]
+fn test_jump_links_next_instruction() {
+    // JAL depuis 0x24: la cible et le lien valent 0x28, dont le tryte de poids faible
+    // (+13) serait pris pour NaN par l'ALU
+    let mut cpu = TestCpu::new();
+    cpu.write_pc(Word::from(0x24));
+    cpu.execute_jump(Register::R7, 0).unwrap();
+    assert_eq!(cpu.read_pc(), Word::from(0x28));
+    assert_eq!(cpu.read_gpr(Register::R7), Word::from(0x28));
+
+    // JALR R7, R7, 8: la base est lue avant l'écriture du lien
+    cpu.execute_jalr(Register::R7, Register::R7, 8).unwrap();
+    assert_eq!(cpu.read_pc(), Word::from(0x30));
+    assert_eq!(cpu.read_gpr(Register::R7), Word::from(0x2C));
+
+    // Cible non alignée: PC et lien inchangés
+    cpu.write_gpr(Register::R1, Word::from(0x102));
+    assert_eq!(cpu.execute_jalr(Register::R7, Register::R1, 0), Err(ExecuteError::UnalignedAddress));
+    assert_eq!(cpu.read_pc(), Word::from(0x30));
+    assert_eq!(cpu.read_gpr(Register::R7), Word::from(0x2C));
 }
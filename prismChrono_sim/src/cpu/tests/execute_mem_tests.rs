@@ -1,20 +1,14 @@
 // src/cpu/tests/execute_mem_tests.rs
 // Tests pour les instructions de mémoire (LOAD/STORE)
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Tryte, Word};
 use crate::cpu::execute::ExecuteError;
 use crate::cpu::execute_mem::MemoryOperations;
-use crate::cpu::registers::Register;
+use crate::core::Address;
+use crate::cpu::registers::{Flags, PrivilegeLevel, Register, RegisterError, TrapCause};
+use crate::cpu::state::CpuState;
 use crate::memory::Memory;
 
-// Trait CpuState pour les opérations mémoire
-pub trait CpuState {
-    fn read_gpr(&self, reg: Register) -> Word;
-    fn write_gpr(&mut self, reg: Register, value: Word);
-    fn memory(&self) -> &Memory;
-    fn memory_mut(&mut self) -> &mut Memory;
-}
-
 // Structure de test qui implémente CpuState pour tester les opérations mémoire
 struct TestCpu {
     registers: [Word; 8],
@@ -24,8 +18,8 @@ struct TestCpu {
 impl TestCpu {
     fn new() -> Self {
         TestCpu {
-            registers: [Word::from_int(0); 8],
-            memory: Memory::new(256), // Petite mémoire pour les tests
+            registers: [Word::from(0); 8],
+            memory: Memory::with_size(256), // Petite mémoire pour les tests
         }
     }
 }
@@ -39,13 +33,65 @@ impl CpuState for TestCpu {
         self.registers[reg.to_index()] = value;
     }
 
-    fn memory(&self) -> &Memory {
-        &self.memory
+    fn read_tryte(&self, addr: Address) -> Result<Tryte, ExecuteError> {
+        self.memory.read_tryte(addr).map_err(ExecuteError::from)
+    }
+
+    fn write_tryte(&mut self, addr: Address, value: Tryte) -> Result<(), ExecuteError> {
+        self.memory.write_tryte(addr, value).map_err(ExecuteError::from)
+    }
+
+    fn read_word(&self, addr: Address) -> Result<Word, ExecuteError> {
+        self.memory.read_word(addr).map_err(ExecuteError::from)
+    }
+
+    fn write_word(&mut self, addr: Address, value: Word) -> Result<(), ExecuteError> {
+        self.memory.write_word(addr, value).map_err(ExecuteError::from)
+    }
+
+    fn read_pc(&self) -> Word {
+        Word::zero()
     }
 
-    fn memory_mut(&mut self) -> &mut Memory {
-        &mut self.memory
+    fn write_pc(&mut self, _value: Word) {}
+
+    fn read_flags(&self) -> Flags {
+        Flags::new()
+    }
+
+    fn write_flags(&mut self, _flags: Flags) {}
+
+    fn set_halted(&mut self, _halted: bool) {}
+
+    fn state_read_csr(&self, _csr: i8) -> Result<Word, RegisterError> {
+        Err(RegisterError::InvalidIndex) // Pas de CSR dans ce test
     }
+
+    fn state_write_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_set_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_clear_csr(&mut self, _csr: i8, _value: Word) -> Result<(), RegisterError> {
+        Err(RegisterError::InvalidIndex)
+    }
+
+    fn state_get_privilege(&self) -> PrivilegeLevel {
+        PrivilegeLevel::Machine
+    }
+
+    fn state_set_privilege(&mut self, _privilege: PrivilegeLevel) {}
+
+    fn state_get_previous_privilege(&self) -> PrivilegeLevel {
+        PrivilegeLevel::Machine
+    }
+
+    fn state_set_previous_privilege(&mut self, _privilege: PrivilegeLevel) {}
+
+    fn state_set_trap_cause(&mut self, _cause: TrapCause) {}
 }
 
 #[test]
@@ -53,14 +99,14 @@ fn test_load_word() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à charger en mémoire
-    let test_word = Word::from_int(42);
-    let addr = 100;
+    let test_word = Word::from(42);
+    let addr = 104; // Aligné sur un Mot (8 trytes)
 
     // Écrire la valeur en mémoire
-    cpu.memory.write_word(addr, test_word.clone()).unwrap();
+    cpu.memory.write_word(addr, test_word).unwrap();
 
     // Configurer le registre d'adresse
-    cpu.write_gpr(Register::R1, Word::from_int(addr));
+    cpu.write_gpr(Register::R1, Word::from(addr as i32));
 
     // Exécuter LOAD R2, R1, 0
     cpu.execute_load(Register::R2, Register::R1, 0).unwrap();
@@ -74,17 +120,17 @@ fn test_load_with_offset() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à charger en mémoire
-    let test_word = Word::from_int(42);
+    let test_word = Word::from(42);
     let base_addr = 100;
     let offset = 4;
 
     // Écrire la valeur en mémoire
     cpu.memory
-        .write_word(base_addr + offset, test_word.clone())
+        .write_word(base_addr + offset, test_word)
         .unwrap();
 
     // Configurer le registre d'adresse
-    cpu.write_gpr(Register::R1, Word::from_int(base_addr));
+    cpu.write_gpr(Register::R1, Word::from(base_addr as i32));
 
     // Exécuter LOAD R2, R1, offset
     cpu.execute_load(Register::R2, Register::R1, offset as i8)
@@ -99,12 +145,12 @@ fn test_store_word() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à stocker
-    let test_word = Word::from_int(42);
-    let addr = 100;
+    let test_word = Word::from(42);
+    let addr = 104; // Aligné sur un Mot (8 trytes)
 
     // Configurer les registres
-    cpu.write_gpr(Register::R1, Word::from_int(addr)); // Adresse
-    cpu.write_gpr(Register::R2, test_word.clone()); // Valeur à stocker
+    cpu.write_gpr(Register::R1, Word::from(addr as i32)); // Adresse
+    cpu.write_gpr(Register::R2, test_word); // Valeur à stocker
 
     // Exécuter STORE R1, R2, 0
     cpu.execute_store(Register::R1, Register::R2, 0).unwrap();
@@ -119,13 +165,13 @@ fn test_store_with_offset() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à stocker
-    let test_word = Word::from_int(42);
+    let test_word = Word::from(42);
     let base_addr = 100;
     let offset = 4;
 
     // Configurer les registres
-    cpu.write_gpr(Register::R1, Word::from_int(base_addr)); // Adresse de base
-    cpu.write_gpr(Register::R2, test_word.clone()); // Valeur à stocker
+    cpu.write_gpr(Register::R1, Word::from(base_addr as i32)); // Adresse de base
+    cpu.write_gpr(Register::R2, test_word); // Valeur à stocker
 
     // Exécuter STORE R1, R2, offset
     cpu.execute_store(Register::R1, Register::R2, offset as i8)
@@ -141,14 +187,14 @@ fn test_load_tryte() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à charger en mémoire
-    let test_tryte = Tryte::from_int(9); // Valeur 9 en ternaire équilibré
+    let test_tryte = Tryte::from_bal3(9).unwrap(); // Valeur 9 en ternaire équilibré
     let addr = 100;
 
     // Écrire la valeur en mémoire
-    cpu.memory.write_tryte(addr, test_tryte.clone()).unwrap();
+    cpu.memory.write_tryte(addr, test_tryte).unwrap();
 
     // Configurer le registre d'adresse
-    cpu.write_gpr(Register::R1, Word::from_int(addr));
+    cpu.write_gpr(Register::R1, Word::from(addr as i32));
 
     // Exécuter LOAD.T R2, R1, 0
     cpu.execute_load_tryte(Register::R2, Register::R1, 0)
@@ -162,10 +208,10 @@ fn test_load_tryte() {
 
     // Vérifier que les autres trytes sont des extensions de signe
     // (tous identiques au signe du premier tryte)
-    let sign_tryte = if test_tryte.is_negative() {
-        Tryte::from_int(-13) // Tryte négatif maximal (-1 en décimal)
+    let sign_tryte = if test_tryte.bal3_value() < 0 {
+        Tryte::from_bal3(-13).unwrap() // Tryte négatif maximal (-1 en décimal)
     } else {
-        Tryte::from_int(0) // Tryte zéro
+        Tryte::from_bal3(0).unwrap() // Tryte zéro
     };
 
     for i in 1..8 {
@@ -178,30 +224,31 @@ fn test_load_tryte_unsigned() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à charger en mémoire
-    let test_tryte = Tryte::from_int(9); // Valeur 9 en ternaire équilibré
+    let test_tryte = Tryte::from_bal3(9).unwrap(); // Valeur 9 en ternaire équilibré
     let addr = 100;
 
     // Écrire la valeur en mémoire
-    cpu.memory.write_tryte(addr, test_tryte.clone()).unwrap();
+    cpu.memory.write_tryte(addr, test_tryte).unwrap();
 
     // Configurer le registre d'adresse
-    cpu.write_gpr(Register::R1, Word::from_int(addr));
+    cpu.write_gpr(Register::R1, Word::from(addr as i32));
 
     // Exécuter LOAD.TU R2, R1, 0
     cpu.execute_load_tryte_unsigned(Register::R2, Register::R1, 0)
         .unwrap();
 
-    // Vérifier que la valeur a été chargée correctement (sans extension de signe)
+    // Vérifier que la valeur a été chargée sans extension de signe: indice du chiffre 9 (22)
     let result = cpu.read_gpr(Register::R2);
+    assert_eq!(result.to_i64(), 22);
 
-    // Vérifier que le premier tryte est correct
-    assert_eq!(result.tryte(0).unwrap(), &test_tryte);
-
-    // Vérifier que les autres trytes sont zéro (pas d'extension de signe)
-    let zero_tryte = Tryte::from_int(0);
-    for i in 1..8 {
-        assert_eq!(result.tryte(i).unwrap(), &zero_tryte);
-    }
+    // Un tryte négatif donne aussi son indice, là où LOADT garde le signe
+    cpu.memory.write_tryte(addr, Tryte::from_bal3(-5).unwrap()).unwrap();
+    cpu.execute_load_tryte_unsigned(Register::R2, Register::R1, 0)
+        .unwrap();
+    assert_eq!(cpu.read_gpr(Register::R2).to_i64(), 8);
+    cpu.execute_load_tryte(Register::R2, Register::R1, 0)
+        .unwrap();
+    assert_eq!(cpu.read_gpr(Register::R2).to_i64(), -5);
 }
 
 #[test]
@@ -209,12 +256,12 @@ fn test_store_tryte() {
     let mut cpu = TestCpu::new();
 
     // Préparer une valeur à stocker
-    let test_word = Word::from_int(42);
+    let test_word = Word::from(42);
     let addr = 100;
 
     // Configurer les registres
-    cpu.write_gpr(Register::R1, Word::from_int(addr)); // Adresse
-    cpu.write_gpr(Register::R2, test_word.clone()); // Valeur à stocker
+    cpu.write_gpr(Register::R1, Word::from(addr as i32)); // Adresse
+    cpu.write_gpr(Register::R2, test_word); // Valeur à stocker
 
     // Exécuter STORE.T R1, R2, 0
     cpu.execute_store_tryte(Register::R1, Register::R2, 0)
@@ -1,10 +1,14 @@
 // src/cpu/tests/execute_system_tests.rs
 // Tests pour les instructions système et CSR
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Address, Trit, Tryte, Word};
+use crate::memory::MemoryError;
 use crate::cpu::execute::ExecuteError;
-use crate::cpu::execute_system::{CpuState, CsrOperations, SystemOperations};
-use crate::cpu::registers::{PrivilegeLevel, Register, TrapCause};
+use crate::cpu::state::CpuState;
+use crate::cpu::execute_alu::AluOperations;
+use crate::cpu::execute_system::{CsrOperations, SystemOperations};
+use crate::cpu::isa::AluOp;
+use crate::cpu::registers::{PrivilegeLevel, Register, TrapCause, flag_trit};
 
 // Structure de test qui implémente CpuState pour tester les opérations système
 struct TestCpu {
@@ -20,8 +24,8 @@ struct TestCpu {
 impl TestCpu {
     fn new() -> Self {
         TestCpu {
-            pc: Word::from_int(0),
-            registers: [Word::from_int(0); 8],
+            pc: Word::from(0),
+            registers: [Word::from(0); 8],
             halted: false,
             privilege: PrivilegeLevel::Machine,
             previous_privilege: PrivilegeLevel::User,
@@ -59,8 +63,8 @@ impl CpuState for TestCpu {
     }
 
     fn state_read_csr(&self, csr: i8) -> Result<Word, crate::cpu::registers::RegisterError> {
-        if csr >= 0 && csr < 10 {
-            Ok(self.csrs[csr as usize].clone())
+        if (0..10).contains(&csr) {
+            Ok(self.csrs[csr as usize])
         } else {
             Err(crate::cpu::registers::RegisterError::InvalidIndex)
         }
@@ -71,7 +75,7 @@ impl CpuState for TestCpu {
         csr: i8,
         value: Word,
     ) -> Result<(), crate::cpu::registers::RegisterError> {
-        if csr >= 0 && csr < 10 {
+        if (0..10).contains(&csr) {
             self.csrs[csr as usize] = value;
             Ok(())
         } else {
@@ -84,20 +88,19 @@ impl CpuState for TestCpu {
         csr: i8,
         value: Word,
     ) -> Result<(), crate::cpu::registers::RegisterError> {
-        if csr >= 0 && csr < 10 {
-            let current = self.csrs[csr as usize].clone();
-            let mut result = current.clone();
+        if (0..10).contains(&csr) {
+            let current = self.csrs[csr as usize];
+            let mut result = current;
 
             // Effectuer un OR bit à bit entre les trytes
             for i in 0..8 {
                 if let (Some(current_tryte), Some(value_tryte)) = (current.tryte(i), value.tryte(i))
-                {
-                    if let (Tryte::Digit(current_val), Tryte::Digit(value_val)) =
+                    && let (Tryte::Digit(current_val), Tryte::Digit(value_val)) =
                         (current_tryte, value_tryte)
                     {
                         // Convertir en valeurs Bal3
-                        let current_bal3 = (*current_val as i8) - 13;
-                        let value_bal3 = (*value_val as i8) - 13;
+                        let _current_bal3 = (*current_val as i8) - 13;
+                        let _value_bal3 = (*value_val as i8) - 13;
 
                         // Effectuer l'opération OR sur les trits individuels
                         let mut result_trits = [Trit::Z; 3];
@@ -120,7 +123,6 @@ impl CpuState for TestCpu {
                             *tryte = result_tryte;
                         }
                     }
-                }
             }
 
             self.csrs[csr as usize] = result;
@@ -162,6 +164,47 @@ impl CpuState for TestCpu {
     fn read_flags(&self) -> crate::cpu::registers::Flags {
         crate::cpu::registers::Flags::new() // Simplifié pour les tests
     }
+
+    fn write_flags(&mut self, _flags: crate::cpu::registers::Flags) {
+        // Flags ignorés dans ce test
+    }
+
+    fn read_tryte(&self, _addr: Address) -> Result<Tryte, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds)) // Pas de mémoire dans ce test
+    }
+
+    fn write_tryte(&mut self, _addr: Address, _value: Tryte) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn read_word(&self, _addr: Address) -> Result<Word, ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn write_word(&mut self, _addr: Address, _value: Word) -> Result<(), ExecuteError> {
+        Err(ExecuteError::MemoryError(MemoryError::OutOfBounds))
+    }
+
+    fn state_clear_csr(
+        &mut self,
+        csr: i8,
+        value: Word,
+    ) -> Result<(), crate::cpu::registers::RegisterError> {
+        if (0..10).contains(&csr) {
+            // AND ternaire avec la négation: min(a, inv(b))
+            let current = self.csrs[csr as usize];
+            let mut result = Word::zero();
+            for i in 0..24 {
+                let a = current.get_trit(i);
+                let b = value.get_trit(i).inv();
+                result.set_trit(i, if a.value() < b.value() { a } else { b });
+            }
+            self.csrs[csr as usize] = result;
+            Ok(())
+        } else {
+            Err(crate::cpu::registers::RegisterError::InvalidIndex)
+        }
+    }
 }
 
 #[test]
@@ -193,12 +236,12 @@ fn test_ecall_from_machine_mode() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::Machine);
 
     // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
+    let trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, trap_handler).unwrap();
 
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
 
     // Exécuter ECALL (func = 2)
     cpu.execute_system(2).unwrap();
@@ -222,12 +265,12 @@ fn test_ecall_from_user_mode() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
 
     // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
+    let trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, trap_handler).unwrap();
 
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
 
     // Exécuter ECALL (func = 2)
     cpu.execute_system(2).unwrap();
@@ -254,8 +297,8 @@ fn test_mret_instruction() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::Machine);
 
     // Configurer mepc_t (adresse de retour)
-    let return_addr = Word::from_int(0x200);
-    cpu.state_write_csr(2, return_addr.clone()).unwrap();
+    let return_addr = Word::from(0x200);
+    cpu.state_write_csr(2, return_addr).unwrap();
 
     // Configurer le niveau de privilège précédent
     cpu.state_set_previous_privilege(PrivilegeLevel::User);
@@ -276,12 +319,12 @@ fn test_mret_from_user_mode() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
 
     // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
+    let trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, trap_handler).unwrap();
 
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
 
     // Exécuter MRET depuis le mode User (devrait déclencher une exception)
     let result = cpu.execute_mret();
@@ -307,19 +350,14 @@ fn test_lui_instruction() {
     // Exécuter LUI R1, 42
     cpu.execute_lui(Register::R1, 42).unwrap();
 
-    // Vérifier que R1 contient la valeur 42 dans les trytes supérieurs
+    // Vérifier que R1 contient 42 décalé de 5 trits (42 × 243)
     let r1_value = cpu.read_gpr(Register::R1);
+    assert_eq!(r1_value, Word::from(42 * 243));
 
-    // Vérifier que les trytes inférieurs sont à zéro
-    for i in 0..4 {
-        assert_eq!(r1_value.tryte(i), Some(&Tryte::Digit(13))); // 13 = 0 en ternaire équilibré
-    }
-
-    // Vérifier que les trytes supérieurs contiennent la valeur 42
-    // Note: Cette vérification est simplifiée car la conversion exacte dépend de l'implémentation
-    // On vérifie simplement que les trytes supérieurs ne sont pas tous à zéro
-    let all_upper_zero = (4..7).all(|i| r1_value.tryte(i) == Some(&Tryte::Digit(13)));
-    assert!(!all_upper_zero);
+    // LUI suivi de ADDI reconstruit une constante de 12 trits
+    cpu.execute_lui(Register::R2, -5).unwrap();
+    cpu.execute_alu_imm(AluOp::Add, Register::R2, Register::R2, 100).unwrap();
+    assert_eq!(cpu.read_gpr(Register::R2), Word::from(-5 * 243 + 100));
 }
 
 #[test]
@@ -327,7 +365,7 @@ fn test_auipc_instruction() {
     let mut cpu = TestCpu::new();
 
     // Configurer PC à 0x100
-    let initial_pc = Word::from_int(0x100);
+    let initial_pc = Word::from(0x100);
     cpu.write_pc(initial_pc);
 
     // Exécuter AUIPC R1, 42
@@ -349,12 +387,12 @@ fn test_csrrw_instruction() {
     let mut cpu = TestCpu::new();
 
     // Configurer un CSR avec une valeur initiale
-    let initial_csr_value = Word::from_int(0x42);
-    cpu.state_write_csr(0, initial_csr_value.clone()).unwrap();
+    let initial_csr_value = Word::from(0x42);
+    cpu.state_write_csr(0, initial_csr_value).unwrap();
 
     // Configurer R1 avec une nouvelle valeur
-    let new_value = Word::from_int(0x24);
-    cpu.write_gpr(Register::R1, new_value.clone());
+    let new_value = Word::from(0x24);
+    cpu.write_gpr(Register::R1, new_value);
 
     // Exécuter CSRRW R2, 0, R1
     cpu.execute_csrrw(Register::R2, 0, Register::R1).unwrap();
@@ -372,11 +410,11 @@ fn test_csrrs_instruction() {
     let mut cpu = TestCpu::new();
 
     // Configurer un CSR avec une valeur initiale
-    let initial_csr_value = Word::from_int(0x42); // 0b1000010 en binaire
-    cpu.state_write_csr(0, initial_csr_value.clone()).unwrap();
+    let initial_csr_value = Word::from(0x42); // 0b1000010 en binaire
+    cpu.state_write_csr(0, initial_csr_value).unwrap();
 
-    // Configurer R1 avec un masque de bits à définir
-    let mask = Word::from_int(0x24); // 0b100100 en binaire
+    // Configurer R1 avec un masque de trits à lever (trit 0, nul dans le CSR)
+    let mask = Word::from(1);
     cpu.write_gpr(Register::R1, mask);
 
     // Exécuter CSRRS R2, 0, R1
@@ -397,8 +435,8 @@ fn test_csrrs_with_r0() {
     let mut cpu = TestCpu::new();
 
     // Configurer un CSR avec une valeur initiale
-    let initial_csr_value = Word::from_int(0x42);
-    cpu.state_write_csr(0, initial_csr_value.clone()).unwrap();
+    let initial_csr_value = Word::from(0x42);
+    cpu.state_write_csr(0, initial_csr_value).unwrap();
 
     // Exécuter CSRRS R2, 0, R0 (ne devrait pas modifier le CSR)
     cpu.execute_csrrs(Register::R2, 0, Register::R0).unwrap();
@@ -416,16 +454,16 @@ fn test_trap_delegation() {
     let mut cpu = TestCpu::new();
     
     // Configurer mtvec_t (adresse de traitement des exceptions en mode Machine)
-    let machine_trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
+    let machine_trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, machine_trap_handler).unwrap();
     
     // Configurer stvec_t (adresse de traitement des exceptions en mode Supervisor)
-    let supervisor_trap_handler = Word::from_int(0x2000);
-    cpu.state_write_csr(5, supervisor_trap_handler.clone()).unwrap();
+    let supervisor_trap_handler = Word::from(0x2000);
+    cpu.state_write_csr(5, supervisor_trap_handler).unwrap();
     
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
     
     // Cas 1: Sans délégation, un trap depuis le mode User va en mode Machine
     cpu.state_set_privilege(PrivilegeLevel::User);
@@ -437,9 +475,9 @@ fn test_trap_delegation() {
     
     // Réinitialiser pour le prochain test
     cpu = TestCpu::new();
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
-    cpu.state_write_csr(5, supervisor_trap_handler.clone()).unwrap();
-    cpu.write_pc(initial_pc.clone());
+    cpu.state_write_csr(1, machine_trap_handler).unwrap();
+    cpu.state_write_csr(5, supervisor_trap_handler).unwrap();
+    cpu.write_pc(initial_pc);
     
     // Configurer medeleg_t pour déléguer EcallU au mode Supervisor
     // Activer le bit correspondant à EcallU (code 0) dans medeleg_t
@@ -465,12 +503,12 @@ fn test_trap_delegation_from_supervisor() {
     let mut cpu = TestCpu::new();
     
     // Configurer mtvec_t (adresse de traitement des exceptions en mode Machine)
-    let machine_trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
+    let machine_trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, machine_trap_handler).unwrap();
     
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
     
     // Configurer medeleg_t pour déléguer EcallS au mode Supervisor
     let mut medeleg = Word::zero();
@@ -496,8 +534,8 @@ fn test_sret_instruction() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::Supervisor);
 
     // Configurer sepc_t (adresse de retour)
-    let return_addr = Word::from_int(0x200);
-    cpu.state_write_csr(6, return_addr.clone()).unwrap();
+    let return_addr = Word::from(0x200);
+    cpu.state_write_csr(6, return_addr).unwrap();
 
     // Exécuter SRET
     cpu.execute_sret().unwrap();
@@ -515,161 +553,12 @@ fn test_sret_from_user_mode() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
 
     // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
+    let trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, trap_handler).unwrap();
 
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
-
-    // Exécuter SRET depuis le mode User (devrait déclencher une exception)
-    let result = cpu.execute_sret();
-
-    // Vérifier que l'exécution a réussi (car elle déclenche un trap)
-    assert!(result.is_ok());
-
-    // Vérifier que:
-    // 1. La cause est IllegalInstr
-    assert_eq!(cpu.trap_cause, Some(TrapCause::IllegalInstr));
-
-    // 2. Le PC a été mis à jour avec l'adresse du gestionnaire de trap
-    assert_eq!(cpu.read_pc(), trap_handler);
-
-    // 3. Le niveau de privilège est passé à Machine
-    assert_eq!(cpu.state_get_privilege(), PrivilegeLevel::Machine);
-}
-
-#[test]
-fn test_ebreak_instruction() {
-    let mut cpu = TestCpu::new();
-
-    // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
-
-    // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
-
-    // Exécuter EBREAK (func = 3)
-    cpu.execute_system(3).unwrap();
-
-    // Vérifier que:
-    // 1. PC a été sauvegardé dans mepc_t
-    assert_eq!(cpu.state_read_csr(2).unwrap(), initial_pc);
-
-    // 2. La cause est BreakPoint
-    assert_eq!(cpu.trap_cause, Some(TrapCause::BreakPoint));
-
-    // 3. Le PC a été mis à jour avec l'adresse du gestionnaire de trap
-    assert_eq!(cpu.read_pc(), trap_handler);
-}
-
-#[test]
-fn test_ebreak_delegation() {
-    let mut cpu = TestCpu::new();
-    
-    // Configurer mtvec_t (adresse de traitement des exceptions en mode Machine)
-    let machine_trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
-    
-    // Configurer stvec_t (adresse de traitement des exceptions en mode Supervisor)
-    let supervisor_trap_handler = Word::from_int(0x2000);
-    cpu.state_write_csr(5, supervisor_trap_handler.clone()).unwrap();
-    
-    // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
-    
-    // Configurer medeleg_t pour déléguer BreakPoint au mode Supervisor
-    // Activer le bit correspondant à BreakPoint (code 6) dans medeleg_t
-    let mut medeleg = Word::zero();
-    if let Some(tryte) = medeleg.tryte_mut(2) { // 6 / 3 = 2 (index du tryte)
-        let mut trits = [Trit::Z; 3];
-        trits[0] = Trit::P; // 6 % 3 = 0 (index du trit)
-        *tryte = Tryte::from_trits(trits);
-    }
-    cpu.state_write_csr(8, medeleg).unwrap();
-    
-    // Exécuter EBREAK depuis le mode User avec délégation
-    cpu.state_set_privilege(PrivilegeLevel::User);
-    cpu.execute_system(3).unwrap(); // EBREAK (func = 3)
-    
-    // Vérifier que le trap a été délégué au mode Supervisor
-    assert_eq!(cpu.state_get_privilege(), PrivilegeLevel::Supervisor);
-    assert_eq!(cpu.read_pc(), supervisor_trap_handler);
-}
-
-#[test]
-fn test_illegal_csr_access() {
-    let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
-
-    // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
-
-    // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
-
-    // Tenter d'accéder à un CSR Machine depuis le mode User
-    // Exécuter CSRRW R2, 0, R1 (accès au CSR 0 - mstatus_t)
-    let result = cpu.execute_csrrw(Register::R2, 0, Register::R1);
-
-    // Vérifier que l'exécution a réussi (car elle déclenche un trap)
-    assert!(result.is_ok());
-
-    // Vérifier que:
-    // 1. La cause est IllegalInstr
-    assert_eq!(cpu.trap_cause, Some(TrapCause::IllegalInstr));
-
-    // 2. Le PC a été mis à jour avec l'adresse du gestionnaire de trap
-    assert_eq!(cpu.read_pc(), trap_handler);
-
-    // 3. Le niveau de privilège est passé à Machine
-    assert_eq!(cpu.state_get_privilege(), PrivilegeLevel::Machine);
-}
-
-#[test]
-fn test_supervisor_csr_access() {
-    // Test d'accès aux CSR Supervisor depuis le mode Supervisor
-    let mut cpu = TestCpu::with_privilege(PrivilegeLevel::Supervisor);
-
-    // Configurer une valeur initiale pour sstatus_t (CSR 4)
-    let initial_csr_value = Word::from_int(0x42);
-    cpu.state_write_csr(4, initial_csr_value.clone()).unwrap();
-
-    // Configurer R1 avec une nouvelle valeur
-    let new_value = Word::from_int(0x24);
-    cpu.write_gpr(Register::R1, new_value.clone());
-
-    // Exécuter CSRRW R2, 4, R1 (accès au CSR 4 - sstatus_t)
-    cpu.execute_csrrw(Register::R2, 4, Register::R1).unwrap();
-
-    // Vérifier que:
-    // 1. Le CSR a été mis à jour avec la valeur de R1
-    assert_eq!(cpu.state_read_csr(4).unwrap(), new_value);
-
-    // 2. R2 contient l'ancienne valeur du CSR
-    assert_eq!(cpu.read_gpr(Register::R2), initial_csr_value);
-
-    // 3. Le niveau de privilège est toujours Supervisor
-    assert_eq!(cpu.state_get_privilege(), PrivilegeLevel::Supervisor);
-}
-
-
-
-#[test]
-fn test_sret_from_user_mode() {
-    let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
-
-    // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
-
-    // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
 
     // Exécuter SRET depuis le mode User (devrait déclencher une exception)
     let result = cpu.execute_sret();
@@ -693,12 +582,12 @@ fn test_ebreak_instruction() {
     let mut cpu = TestCpu::new();
 
     // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
+    let trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, trap_handler).unwrap();
 
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
 
     // Exécuter EBREAK (func = 3)
     cpu.execute_system(3).unwrap();
@@ -719,25 +608,21 @@ fn test_ebreak_delegation() {
     let mut cpu = TestCpu::new();
     
     // Configurer mtvec_t (adresse de traitement des exceptions en mode Machine)
-    let machine_trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, machine_trap_handler.clone()).unwrap();
+    let machine_trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, machine_trap_handler).unwrap();
     
     // Configurer stvec_t (adresse de traitement des exceptions en mode Supervisor)
-    let supervisor_trap_handler = Word::from_int(0x2000);
-    cpu.state_write_csr(5, supervisor_trap_handler.clone()).unwrap();
+    let supervisor_trap_handler = Word::from(0x2000);
+    cpu.state_write_csr(5, supervisor_trap_handler).unwrap();
     
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
     
     // Configurer medeleg_t pour déléguer BreakPoint au mode Supervisor
-    // Activer le bit correspondant à BreakPoint (code 6) dans medeleg_t
+    // Activer le drapeau de BreakPoint (code 6, trit 9) dans medeleg_t
     let mut medeleg = Word::zero();
-    if let Some(tryte) = medeleg.tryte_mut(2) { // 6 / 3 = 2 (index du tryte)
-        let mut trits = [Trit::Z; 3];
-        trits[0] = Trit::P; // 6 % 3 = 0 (index du trit)
-        *tryte = Tryte::from_trits(trits);
-    }
+    medeleg.set_trit(flag_trit(TrapCause::BreakPoint.to_code()).unwrap(), Trit::P);
     cpu.state_write_csr(8, medeleg).unwrap();
     
     // Exécuter EBREAK depuis le mode User avec délégation
@@ -754,12 +639,12 @@ fn test_illegal_csr_access() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::User);
 
     // Configurer mtvec_t (adresse de traitement des exceptions)
-    let trap_handler = Word::from_int(0x1000);
-    cpu.state_write_csr(1, trap_handler.clone()).unwrap();
+    let trap_handler = Word::from(0x1000);
+    cpu.state_write_csr(1, trap_handler).unwrap();
 
     // Configurer PC
-    let initial_pc = Word::from_int(0x100);
-    cpu.write_pc(initial_pc.clone());
+    let initial_pc = Word::from(0x100);
+    cpu.write_pc(initial_pc);
 
     // Tenter d'accéder à un CSR Machine depuis le mode User
     // Exécuter CSRRW R2, 0, R1 (accès au CSR 0 - mstatus_t)
@@ -785,12 +670,12 @@ fn test_supervisor_csr_access() {
     let mut cpu = TestCpu::with_privilege(PrivilegeLevel::Supervisor);
 
     // Configurer une valeur initiale pour sstatus_t (CSR 4)
-    let initial_csr_value = Word::from_int(0x42);
-    cpu.state_write_csr(4, initial_csr_value.clone()).unwrap();
+    let initial_csr_value = Word::from(0x42);
+    cpu.state_write_csr(4, initial_csr_value).unwrap();
 
     // Configurer R1 avec une nouvelle valeur
-    let new_value = Word::from_int(0x24);
-    cpu.write_gpr(Register::R1, new_value.clone());
+    let new_value = Word::from(0x24);
+    cpu.write_gpr(Register::R1, new_value);
 
     // Exécuter CSRRW R2, 4, R1 (accès au CSR 4 - sstatus_t)
     cpu.execute_csrrw(Register::R2, 4, Register::R1).unwrap();
@@ -3,133 +3,151 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{Trit, Word};
-    use crate::memory::SimpleMemory;
-    use crate::cpu::CPU;
+    use crate::core::{Trit, Word};
+    use crate::cpu::execute::Cpu;
+    use crate::cpu::registers::Register;
+    use crate::cpu::state::CpuState;
     use crate::cpu::isa_extensions::TernaryOp;
     use crate::cpu::execute_ternary::ExecuteTernary;
 
     #[test]
     fn test_tmin_instruction() {
-        let mut memory = SimpleMemory::new(1024);
-        let mut cpu = CPU::new(memory);
+        let mut cpu = Cpu::with_memory_size(1024);
         
         // Initialiser les registres
-        let a = Word::from_i32(5);
-        let b = Word::from_i32(3);
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(5);
+        let b = Word::from(3);
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TMIN
-        cpu.execute_ternary_instruction(TernaryOp::TMIN, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TMIN, Register::R1, Register::R2, Register::R3).unwrap();
         
-        // Vérifier le résultat
-        let result = cpu.registers.read(3);
-        assert_eq!(result.to_i32(), 3); // min(5, 3) = 3
+        // Vérifier le résultat, trit à trit: min(+--, 0+0) = 0--
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(result.to_i64(), -4);
     }
 
     #[test]
     fn test_tmax_instruction() {
-        let mut memory = SimpleMemory::new(1024);
-        let mut cpu = CPU::new(memory);
+        let mut cpu = Cpu::with_memory_size(1024);
         
         // Initialiser les registres
-        let a = Word::from_i32(5);
-        let b = Word::from_i32(3);
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(5);
+        let b = Word::from(3);
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TMAX
-        cpu.execute_ternary_instruction(TernaryOp::TMAX, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TMAX, Register::R1, Register::R2, Register::R3).unwrap();
         
-        // Vérifier le résultat
-        let result = cpu.registers.read(3);
-        assert_eq!(result.to_i32(), 5); // max(5, 3) = 5
+        // Vérifier le résultat, trit à trit: max(+--, 0+0) = ++0
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(result.to_i64(), 12);
     }
 
     #[test]
     fn test_tsum_instruction() {
-        let mut memory = SimpleMemory::new(1024);
-        let mut cpu = CPU::new(memory);
+        let mut cpu = Cpu::with_memory_size(1024);
         
         // Cas 1: Somme simple
-        let a = Word::from_i32(1);  // Représentation ternaire: ...001
-        let b = Word::from_i32(1);  // Représentation ternaire: ...001
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(1);  // Représentation ternaire: ...001
+        let b = Word::from(1);  // Représentation ternaire: ...001
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TSUM
-        cpu.execute_ternary_instruction(TernaryOp::TSUM, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TSUM, Register::R1, Register::R2, Register::R3).unwrap();
         
         // Vérifier le résultat (sans propagation: 1+1=2 -> trit P)
-        let result = cpu.registers.read(3);
-        assert_eq!(result.to_i32(), 1); // Le résultat devrait être 1 (P) car TSUM fait la somme sans propagation
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(result.to_i64(), 1); // Le résultat devrait être 1 (P) car TSUM fait la somme sans propagation
         
         // Cas 2: Somme avec valeurs négatives
-        let a = Word::from_i32(-1); // Représentation ternaire: ...00N
-        let b = Word::from_i32(-1); // Représentation ternaire: ...00N
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(-1); // Représentation ternaire: ...00N
+        let b = Word::from(-1); // Représentation ternaire: ...00N
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TSUM
-        cpu.execute_ternary_instruction(TernaryOp::TSUM, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TSUM, Register::R1, Register::R2, Register::R3).unwrap();
         
         // Vérifier le résultat (sans propagation: -1+-1=-2 -> trit N)
-        let result = cpu.registers.read(3);
-        assert_eq!(result.to_i32(), -1); // Le résultat devrait être -1 (N) car TSUM fait la somme sans propagation
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(result.to_i64(), -1); // Le résultat devrait être -1 (N) car TSUM fait la somme sans propagation
     }
 
     #[test]
     fn test_tcmp3_instruction() {
-        let mut memory = SimpleMemory::new(1024);
-        let mut cpu = CPU::new(memory);
+        let mut cpu = Cpu::with_memory_size(1024);
         
         // Cas 1: Premier opérande plus petit
-        let a = Word::from_i32(3);
-        let b = Word::from_i32(5);
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(3);
+        let b = Word::from(5);
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TCMP3
-        cpu.execute_ternary_instruction(TernaryOp::TCMP3, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TCMP3, Register::R1, Register::R2, Register::R3).unwrap();
         
-        // Vérifier le résultat (3 < 5 -> N)
-        let result = cpu.registers.read(3);
-        // Le résultat devrait avoir tous les trits à N (-1)
-        for i in 0..24 {
-            assert_eq!(result.get_trit(i), Trit::N);
-        }
+        // Vérifier le résultat, trit à trit: 3 = 0+0, 5 = +-- (trit de poids fort en tête)
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!([result.get_trit(2), result.get_trit(1), result.get_trit(0)], [Trit::N, Trit::P, Trit::P]);
+        assert_eq!(result.to_i64(), -5);
         
         // Cas 2: Opérandes égaux
-        let a = Word::from_i32(5);
-        let b = Word::from_i32(5);
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(5);
+        let b = Word::from(5);
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TCMP3
-        cpu.execute_ternary_instruction(TernaryOp::TCMP3, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TCMP3, Register::R1, Register::R2, Register::R3).unwrap();
         
         // Vérifier le résultat (5 = 5 -> Z)
-        let result = cpu.registers.read(3);
+        let result = cpu.read_gpr(Register::R3);
         // Le résultat devrait avoir tous les trits à Z (0)
         for i in 0..24 {
             assert_eq!(result.get_trit(i), Trit::Z);
         }
         
         // Cas 3: Premier opérande plus grand
-        let a = Word::from_i32(7);
-        let b = Word::from_i32(5);
-        cpu.registers.write(1, a);
-        cpu.registers.write(2, b);
+        let a = Word::from(7);
+        let b = Word::from(5);
+        cpu.write_gpr(Register::R1, a);
+        cpu.write_gpr(Register::R2, b);
         
         // Exécuter l'instruction TCMP3
-        cpu.execute_ternary_instruction(TernaryOp::TCMP3, 1, 2, 3).unwrap();
+        cpu.execute_ternary(TernaryOp::TCMP3, Register::R1, Register::R2, Register::R3).unwrap();
         
-        // Vérifier le résultat (7 > 5 -> P)
-        let result = cpu.registers.read(3);
-        // Le résultat devrait avoir tous les trits à P (1)
-        for i in 0..24 {
-            assert_eq!(result.get_trit(i), Trit::P);
+        // Vérifier le résultat, trit à trit: 7 = +-+ et 5 = +-- ne diffèrent que par le trit 0
+        let result = cpu.read_gpr(Register::R3);
+        assert_eq!(result.to_i64(), 1);
+    }
+
+    #[test]
+    fn test_extensions_dispatch_from_decode() {
+        use crate::cpu::decode::decode;
+        use crate::cpu::isa::Instruction;
+
+        let mut cpu = Cpu::with_memory_size(1024);
+        cpu.write_gpr(Register::R1, Word::from(1));
+        cpu.write_gpr(Register::R2, Word::from(-1));
+
+        // TMIN R3, R1, R2: minimum trit à trit de 1 (0..0+) et -1 (0..0-)
+        let tmin = Instruction::TernaryOp { op: TernaryOp::TMIN, rs1: Register::R1, rs2: Register::R2, rd: Register::R3 };
+        let decoded = decode(prismchrono_isa::encode(&tmin).unwrap()).unwrap();
+        cpu.execute(decoded).unwrap();
+        assert_eq!(cpu.read_gpr(Register::R3).to_i64(), -1);
+
+        // BRANCH3 sur une valeur négative, nulle puis positive
+        let branch3 = Instruction::Branch3 { rs1: Register::R5, offset_neg: 1, offset_zero: 4, offset_pos: 6 };
+        let decoded = decode(prismchrono_isa::encode(&branch3).unwrap()).unwrap();
+        for (value, expected_pc) in [(-2, 4), (0, 16), (9, 24)] {
+            cpu.write_pc(Word::from(0));
+            cpu.write_gpr(Register::R5, Word::from(value));
+            cpu.execute(decoded.clone()).unwrap();
+            assert_eq!(cpu.read_pc().to_i64(), expected_pc, "BRANCH3 avec {}", value);
         }
     }
-}
\ No newline at end of file
+}
@@ -13,6 +13,12 @@ pub struct TSHA3State {
     state: [Word; TSHA3_STATE_SIZE],
 }
 
+impl Default for TSHA3State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TSHA3State {
     /// Crée un nouvel état TSHA3 initialisé à zéro
     pub fn new() -> Self {
@@ -80,24 +86,24 @@ fn tsha3_permutation(state: &mut TSHA3State) {
     let mut d = [Word::default_zero(); 5];
     
     // Calcul des parités de colonnes
-    for x in 0..5 {
+    for (x, parity) in c.iter_mut().enumerate() {
         for y in 0..5 {
             if let Some(word) = state.get(x, y) {
-                c[x] = ternary_xor(c[x], word);
+                *parity = ternary_xor(*parity, word);
             }
         }
     }
     
     // Calcul des différences
-    for x in 0..5 {
-        d[x] = ternary_xor(c[(x + 4) % 5], rotate_left(c[(x + 1) % 5], 1));
+    for (x, difference) in d.iter_mut().enumerate() {
+        *difference = ternary_xor(c[(x + 4) % 5], rotate_left(c[(x + 1) % 5], 1));
     }
     
     // Application des différences
-    for x in 0..5 {
+    for (x, &difference) in d.iter().enumerate() {
         for y in 0..5 {
             if let Some(word) = state.get(x, y) {
-                state.set(x, y, ternary_xor(word, d[x]));
+                state.set(x, y, ternary_xor(word, difference));
             }
         }
     }
@@ -134,7 +140,7 @@ fn tsha3_permutation(state: &mut TSHA3State) {
     
     // Étape ι (iota) - ajout de constantes rondes
     // Simplifié pour cette implémentation
-    let round_constant = Word::from_i32(TSHA3_ROUNDS as i32);
+    let round_constant = Word::from(TSHA3_ROUNDS as i32);
     state.state[0] = ternary_xor(state.state[0], round_constant);
 }
 
@@ -315,7 +321,7 @@ impl TAES {
     /// Dérive une sous-clé de ronde
     fn derive_round_key(&self, round: usize) -> Word {
         // Dérivation simple basée sur la clé principale et le numéro de ronde
-        let round_constant = Word::from_i32(round as i32 + 1);
+        let round_constant = Word::from(round as i32 + 1);
         ternary_xor(self.key, round_constant)
     }
     
@@ -346,8 +352,7 @@ impl TAES {
                 let mut inverse = 0;
                 
                 // Recherche de l'inverse par force brute (simplifié)
-                for j in 0..27 {
-                    let j_val = j as i32;
+                for j_val in 0..27i32 {
                     if (j_val.wrapping_mul(5).wrapping_add(3)) % 27 == value as i32 {
                         inverse = j_val;
                         break;
@@ -368,9 +373,9 @@ impl TAES {
         // Permutation simple des trytes
         let permutation = [1, 5, 2, 6, 3, 7, 4, 0];
         
-        for i in 0..8 {
+        for (i, &source) in permutation.iter().enumerate() {
             if let (Some(tryte), Some(tryte_result)) = 
-                (word.tryte(permutation[i]), result.tryte_mut(i)) {
+                (word.tryte(source), result.tryte_mut(i)) {
                 *tryte_result = *tryte;
             }
         }
@@ -385,9 +390,9 @@ impl TAES {
         // Permutation inverse
         let inverse_permutation = [7, 0, 2, 4, 6, 1, 3, 5];
         
-        for i in 0..8 {
+        for (i, &source) in inverse_permutation.iter().enumerate() {
             if let (Some(tryte), Some(tryte_result)) = 
-                (word.tryte(inverse_permutation[i]), result.tryte_mut(i)) {
+                (word.tryte(source), result.tryte_mut(i)) {
                 *tryte_result = *tryte;
             }
         }
@@ -457,20 +462,6 @@ impl TAES {
     }
 }
 
-/// Génère un nouveau tableau de substitution
-fn generate_sbox() -> [Tryte; 27] {
-    let mut trytes = [Tryte::Digit(0); 27];
-    
-    // Génération d'une boîte de substitution simplifiée
-    for i in 0..27 {
-        let value = (i as i32 * 5 + 3) % 27;
-        let tryte = &Tryte::from_i8(value as i8);
-        trytes[i as usize] = *tryte; // Dereference pour copier la valeur
-    }
-    
-    trytes
-}
-
 /// Déchiffre un message avec l'algorithme du chiffre de Hill
 pub fn decrypt_hill_cipher(ciphertext: Word, key: Word) -> Word {
     let mut result = Word::zero();
@@ -490,8 +481,8 @@ pub fn decrypt_hill_cipher(ciphertext: Word, key: Word) -> Word {
     // Calculer l'inverse modulaire du déterminant
     let mut det_inv = 0;
     for j in 0..27 {
-        if (det * j as i32) % 27 == 1 {
-            det_inv = j as i32;
+        if (det * j) % 27 == 1 {
+            det_inv = j;
             break;
         }
     }
@@ -581,12 +572,7 @@ pub fn inverse_permutation(perm: &[u8]) -> Vec<u8> {
 
 /// Trouve l'inverse modulaire d'un nombre dans le groupe Z27
 pub fn modular_inverse(value: i32, modulus: i32) -> Option<i32> {
-    for j in 0..modulus {
-        if ((j as i32).wrapping_mul(5).wrapping_add(3)) % modulus == value {
-            return Some(j as i32);
-        }
-    }
-    None
+    (0..modulus).find(|&j| (j.wrapping_mul(5).wrapping_add(3)) % modulus == value)
 }
 
 /// Applique une transformation linéaire aux trytes
@@ -688,9 +674,9 @@ fn permutate(word: Word) -> Word {
     // Dans une implémentation réelle, cette table serait optimisée
     let perm: [usize; 8] = [2, 0, 3, 4, 6, 1, 7, 5];
     
-    for i in 0..8 {
+    for (i, &source) in perm.iter().enumerate() {
         if let (Some(tryte), Some(tryte_result)) = 
-            (word.tryte(perm[i]), result.tryte_mut(i)) {
+            (word.tryte(source), result.tryte_mut(i)) {
             *tryte_result = *tryte;
         }
     }
@@ -706,11 +692,17 @@ pub struct TernaryHomomorphicEncryption {
     priv_key: Option<Word>,
 }
 
+impl Default for TernaryHomomorphicEncryption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TernaryHomomorphicEncryption {
     /// Crée une nouvelle instance avec génération de clés
     pub fn new() -> Self {
         // Génération simplifiée de clés
-        let mut rng = TRNG::new(Word::from_i32(0x12345678));
+        let mut rng = TRNG::new(Word::from(0x12345678));
         let priv_key = rng.generate();
         let pub_key = ternary_xor(priv_key, rng.generate());
         
@@ -801,7 +793,7 @@ impl TRNG {
     pub fn generate(&mut self) -> Word {
         // Incrémenter le compteur
         self.counter = self.counter.wrapping_add(1);
-        let counter_word = Word::from_i32(self.counter as i32);
+        let counter_word = Word::from(self.counter as i32);
         
         // Mélanger l'état avec le compteur
         self.state = ternary_xor(self.state, counter_word);
@@ -824,7 +816,7 @@ impl TRNG {
             self.state = new_state;
             
             // XOR avec une constante dérivée du compteur
-            let constant = Word::from_i32((self.counter.wrapping_mul(0x9E3779B9)) as i32);
+            let constant = Word::from((self.counter.wrapping_mul(0x9E3779B9)) as i32);
             self.state = ternary_xor(self.state, constant);
         }
         
@@ -0,0 +1,651 @@
+// src/debugger.rs
+// Débogueur interactif (REPL) du simulateur PrismChrono: points d'arrêt,
+// points de surveillance mémoire, exécution pas à pas et inspection de l'état
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use prismchrono_isa::isa_defs::csr_name;
+
+use crate::core::{Address, Trit, Tryte, Word};
+use crate::cpu::decode::decode;
+use crate::cpu::execute::{Cpu, ExecuteError};
+use crate::cpu::execute_core::word_to_address;
+use crate::cpu::isa::Instruction;
+use crate::cpu::registers::Register;
+use crate::disasm::{Symbols, format_instruction};
+use crate::snapshot::SnapshotError;
+
+/// Chiffres utilisés pour l'affichage d'un tryte en base 24
+const BASE24_DIGITS: &[u8; 24] = b"0123456789ABCDEFGHIJKLMN";
+
+/// Invite affichée par le REPL
+const PROMPT: &str = "prismchrono> ";
+
+/// Raison pour laquelle le débogueur a rendu la main
+#[derive(Debug, PartialEq, Eq)]
+pub enum DebugStop {
+    /// Une instruction a été exécutée normalement
+    Stepped,
+    /// Le PC a atteint un point d'arrêt posé par l'utilisateur
+    Breakpoint(Address),
+    /// Un tryte surveillé a changé de valeur
+    Watchpoint { addr: Address, old: Tryte, new: Tryte },
+    /// Instruction EBREAK exécutée à l'adresse donnée (le PC est déjà avancé)
+    EBreak(Address),
+    /// Le programme a exécuté HALT
+    Halted,
+    /// Erreur d'exécution
+    Error(ExecuteError),
+}
+
+/// Débogueur interactif encapsulant un CPU
+pub struct Debugger {
+    pub cpu: Cpu,
+    breakpoints: BTreeSet<Address>,
+    watchpoints: BTreeMap<Address, Tryte>, // Adresse -> dernière valeur observée
+    last_command: Option<String>,          // Répétée lorsque l'utilisateur envoie une ligne vide
+    pub symbols: Symbols,                  // Noms des cibles de branchement dans le désassemblage
+}
+
+impl Debugger {
+    /// Crée un débogueur pour un CPU déjà chargé
+    pub fn new(cpu: Cpu) -> Self {
+        Debugger {
+            cpu,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeMap::new(),
+            last_command: None,
+            symbols: Symbols::new(),
+        }
+    }
+
+    /// Pose un point d'arrêt; retourne false s'il existait déjà
+    pub fn add_breakpoint(&mut self, addr: Address) -> bool {
+        self.breakpoints.insert(addr)
+    }
+
+    /// Supprime un point d'arrêt; retourne false s'il n'existait pas
+    pub fn remove_breakpoint(&mut self, addr: Address) -> bool {
+        self.breakpoints.remove(&addr)
+    }
+
+    /// Surveille un tryte mémoire
+    pub fn add_watchpoint(&mut self, addr: Address) -> Result<(), ExecuteError> {
+        let value = self.cpu.memory.read_tryte(addr)?;
+        self.watchpoints.insert(addr, value);
+        Ok(())
+    }
+
+    /// Arrête la surveillance d'un tryte; retourne false s'il n'était pas surveillé
+    pub fn remove_watchpoint(&mut self, addr: Address) -> bool {
+        self.watchpoints.remove(&addr).is_some()
+    }
+
+    /// Restaure un instantané (voir snapshot.rs); les valeurs surveillées sont relues
+    /// pour qu'un changement dû à la restauration ne déclenche pas de surveillance
+    pub fn load_snapshot(&mut self, path: &Path) -> Result<(), SnapshotError> {
+        self.cpu.load_snapshot(path)?;
+        for (addr, value) in self.watchpoints.iter_mut() {
+            if let Ok(new) = self.cpu.memory.read_tryte(*addr) {
+                *value = new;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adresse courante du PC
+    pub fn pc(&self) -> Result<Address, ExecuteError> {
+        word_to_address(&self.cpu.state.read_pc())
+    }
+
+    /// Exécute une seule instruction
+    /// Un EBREAK est intercepté: le PC est avancé pour que l'exécution puisse reprendre
+    pub fn step(&mut self) -> DebugStop {
+        let pc = match self.pc() {
+            Ok(pc) => pc,
+            Err(e) => return DebugStop::Error(e),
+        };
+
+        match self.cpu.step() {
+            Ok(()) if self.cpu.halted => return DebugStop::Halted,
+            Ok(()) => {}
+            Err(ExecuteError::Halted) => {
+                self.cpu.halted = true;
+                return DebugStop::Halted;
+            }
+            Err(ExecuteError::Breakpoint) => {
+                self.cpu.advance_pc();
+                return DebugStop::EBreak(pc);
+            }
+            Err(e) => return DebugStop::Error(e),
+        }
+
+        self.check_watchpoints()
+    }
+
+    /// Exécute l'instruction courante en passant par-dessus les appels
+    /// (JAL/CALL/JALR avec registre de lien): s'arrête au retour à PC + 4
+    pub fn step_over(&mut self) -> DebugStop {
+        let pc = match self.pc() {
+            Ok(pc) => pc,
+            Err(e) => return DebugStop::Error(e),
+        };
+
+        let is_call = match self.cpu.fetch_at(pc).ok().and_then(|trits| decode(trits).ok()) {
+            Some(Instruction::Jump { rd, .. })
+            | Some(Instruction::Call { rd, .. })
+            | Some(Instruction::Jalr { rd, .. }) => rd != Register::R0,
+            _ => false,
+        };
+
+        let stop = self.step();
+        if !is_call || stop != DebugStop::Stepped {
+            return stop;
+        }
+
+        // Continuer jusqu'au retour de l'appel (ou un autre arrêt)
+        self.run_until(|dbg| dbg.pc().ok() == Some(pc + 4))
+    }
+
+    /// Reprend l'exécution jusqu'au prochain arrêt
+    pub fn cont(&mut self) -> DebugStop {
+        self.run_until(|_| false)
+    }
+
+    /// Exécute des instructions jusqu'à un arrêt, un point d'arrêt ou la condition donnée
+    fn run_until(&mut self, done: impl Fn(&Self) -> bool) -> DebugStop {
+        loop {
+            let stop = self.step();
+            if stop != DebugStop::Stepped {
+                return stop;
+            }
+            if done(self) {
+                return DebugStop::Stepped;
+            }
+            if let Ok(pc) = self.pc()
+                && self.breakpoints.contains(&pc)
+            {
+                return DebugStop::Breakpoint(pc);
+            }
+        }
+    }
+
+    /// Compare les trytes surveillés avec leur dernière valeur connue
+    fn check_watchpoints(&mut self) -> DebugStop {
+        for (addr, old) in self.watchpoints.iter_mut() {
+            if let Ok(new) = self.cpu.memory.read_tryte(*addr)
+                && new != *old
+            {
+                let stop = DebugStop::Watchpoint { addr: *addr, old: *old, new };
+                *old = new;
+                return stop;
+            }
+        }
+        DebugStop::Stepped
+    }
+
+    /// Désassemble l'instruction à une adresse
+    pub fn disassemble_at(&self, addr: Address) -> String {
+        match self.cpu.fetch_at(addr) {
+            Ok(trits) => match decode(trits) {
+                Ok(instruction) => format_instruction(&instruction, addr, &self.symbols),
+                Err(e) => format!("<invalide: {:?}>", e),
+            },
+            Err(e) => format!("<illisible: {:?}>", e),
+        }
+    }
+
+    /// Boucle interactive: lit les commandes sur `input` et écrit les réponses sur `output`
+    pub fn run_repl<R: BufRead, W: Write>(&mut self, input: R, output: &mut W) -> io::Result<()> {
+        self.print_location(output)?;
+        write!(output, "{}", PROMPT)?;
+        output.flush()?;
+
+        for line in input.lines() {
+            if !self.execute_command(&line?, output)? {
+                return Ok(());
+            }
+            write!(output, "{}", PROMPT)?;
+            output.flush()?;
+        }
+
+        writeln!(output)?;
+        Ok(())
+    }
+
+    /// Exécute une commande du REPL; retourne false si l'utilisateur a demandé à quitter
+    pub fn execute_command<W: Write>(&mut self, line: &str, out: &mut W) -> io::Result<bool> {
+        // Une ligne vide répète la dernière commande (pratique pour step/next)
+        let line = match (line.trim(), &self.last_command) {
+            ("", Some(last)) => last.clone(),
+            ("", None) => return Ok(true),
+            (line, _) => {
+                self.last_command = Some(line.to_string());
+                line.to_string()
+            }
+        };
+
+        let mut parts = line.split_whitespace();
+        let command = parts.next().unwrap_or_default();
+        let args: Vec<&str> = parts.collect();
+
+        match command {
+            "break" | "b" => match parse_address_arg(&args, 0) {
+                Ok(addr) => {
+                    if self.add_breakpoint(addr) {
+                        writeln!(out, "Point d'arrêt posé à {:04X}", addr)?;
+                    } else {
+                        writeln!(out, "Point d'arrêt déjà présent à {:04X}", addr)?;
+                    }
+                }
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            "delete" | "d" => match parse_address_arg(&args, 0) {
+                Ok(addr) if self.remove_breakpoint(addr) => {
+                    writeln!(out, "Point d'arrêt supprimé à {:04X}", addr)?
+                }
+                Ok(addr) => writeln!(out, "Aucun point d'arrêt à {:04X}", addr)?,
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            "watch" | "w" => match parse_address_arg(&args, 0) {
+                Ok(addr) => match self.add_watchpoint(addr) {
+                    Ok(()) => writeln!(out, "Surveillance du tryte {:04X}", addr)?,
+                    Err(e) => writeln!(out, "Impossible de surveiller {:04X}: {:?}", addr, e)?,
+                },
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            "unwatch" => match parse_address_arg(&args, 0) {
+                Ok(addr) if self.remove_watchpoint(addr) => {
+                    writeln!(out, "Surveillance supprimée à {:04X}", addr)?
+                }
+                Ok(addr) => writeln!(out, "Aucune surveillance à {:04X}", addr)?,
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            "info" => self.print_points(out)?,
+            "step" | "s" => match parse_count_arg(&args, 0, 1) {
+                Ok(count) => {
+                    for _ in 0..count {
+                        let stop = self.step();
+                        if stop != DebugStop::Stepped {
+                            self.print_stop(&stop, out)?;
+                            break;
+                        }
+                    }
+                    self.print_location(out)?;
+                }
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            "next" | "n" => {
+                let stop = self.step_over();
+                self.print_stop(&stop, out)?;
+                self.print_location(out)?;
+            }
+            "continue" | "c" => {
+                let stop = self.cont();
+                self.print_stop(&stop, out)?;
+                self.print_location(out)?;
+            }
+            "regs" | "r" => self.print_registers(out)?,
+            "csr" => self.print_csrs(out)?,
+            "flags" | "f" => self.print_flags(out)?,
+            "x" => match (parse_address_arg(&args, 0), parse_count_arg(&args, 1, 8)) {
+                (Ok(addr), Ok(count)) => self.print_trytes(addr, count, out)?,
+                (Err(e), _) | (_, Err(e)) => writeln!(out, "{}", e)?,
+            },
+            "xw" => match (parse_address_arg(&args, 0), parse_count_arg(&args, 1, 1)) {
+                (Ok(addr), Ok(count)) => self.print_words(addr, count, out)?,
+                (Err(e), _) | (_, Err(e)) => writeln!(out, "{}", e)?,
+            },
+            "disas" | "disasm" => {
+                let addr = match args.first() {
+                    Some(_) => parse_address_arg(&args, 0),
+                    None => self.pc().map_err(|e| format!("PC invalide: {:?}", e)),
+                };
+                match (addr, parse_count_arg(&args, 1, 1)) {
+                    (Ok(addr), Ok(count)) => {
+                        for i in 0..count {
+                            let addr = addr + i * 4;
+                            writeln!(out, "{:04X}: {}", addr, self.disassemble_at(addr))?;
+                        }
+                    }
+                    (Err(e), _) | (_, Err(e)) => writeln!(out, "{}", e)?,
+                }
+            }
+            "save" => match args.first() {
+                Some(path) => match self.cpu.save_snapshot(Path::new(path)) {
+                    Ok(()) => writeln!(out, "Instantané enregistré dans {}", path)?,
+                    Err(e) => writeln!(out, "Impossible d'enregistrer {}: {}", path, e)?,
+                },
+                None => writeln!(out, "fichier manquant")?,
+            },
+            "load" => match args.first() {
+                Some(path) => match self.load_snapshot(Path::new(path)) {
+                    Ok(()) => {
+                        writeln!(out, "Instantané {} restauré", path)?;
+                        self.print_location(out)?;
+                    }
+                    Err(e) => writeln!(out, "Impossible de restaurer {}: {}", path, e)?,
+                },
+                None => writeln!(out, "fichier manquant")?,
+            },
+            "help" | "h" | "?" => print_help(out)?,
+            "quit" | "q" | "exit" => return Ok(false),
+            other => writeln!(out, "Commande inconnue '{}' (tapez 'help')", other)?,
+        }
+
+        Ok(true)
+    }
+
+    /// Affiche l'adresse courante et l'instruction qui s'y trouve
+    fn print_location<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        match self.pc() {
+            Ok(pc) => writeln!(out, "=> {:04X}: {}", pc, self.disassemble_at(pc)),
+            Err(e) => writeln!(out, "=> PC invalide ({}): {:?}", self.cpu.state.read_pc(), e),
+        }
+    }
+
+    /// Décrit la raison d'un arrêt
+    fn print_stop<W: Write>(&self, stop: &DebugStop, out: &mut W) -> io::Result<()> {
+        match stop {
+            DebugStop::Stepped => Ok(()),
+            DebugStop::Breakpoint(addr) => writeln!(out, "Point d'arrêt atteint à {:04X}", addr),
+            DebugStop::Watchpoint { addr, old, new } => {
+                writeln!(out, "Tryte {:04X} modifié: {} -> {}", addr, old, new)
+            }
+            DebugStop::EBreak(addr) => writeln!(out, "EBREAK à {:04X}", addr),
+            DebugStop::Halted => writeln!(out, "Programme arrêté (HALT)"),
+            DebugStop::Error(e) => writeln!(out, "Erreur d'exécution: {:?}", e),
+        }
+    }
+
+    /// Affiche les registres généraux, le PC et le SP
+    fn print_registers<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for i in 0..8 {
+            if let Ok(reg) = Register::from_index(i) {
+                writeln!(out, "{:<3} {}", reg.to_string(), format_word(&self.cpu.state.read_gpr(reg)))?;
+            }
+        }
+        writeln!(out, "PC  {}", format_word(&self.cpu.state.read_pc()))?;
+        writeln!(out, "SP  {}", format_word(&self.cpu.state.read_sp()))
+    }
+
+    /// Affiche les registres de contrôle et d'état
+    fn print_csrs<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for code in 0..=9 {
+            if let (Some(name), Ok(value)) = (csr_name(code), self.cpu.state.read_csr(code)) {
+                writeln!(out, "{:<10} {}", name, format_word(&value))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Affiche les flags
+    fn print_flags<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let flags = self.cpu.state.read_flags();
+        writeln!(
+            out,
+            "ZF={} SF={} XF={} CF={} OF={}",
+            flags.zf as u8, flags.sf as u8, flags.xf as u8, flags.cf as u8, flags.of as u8
+        )
+    }
+
+    /// Affiche les points d'arrêt et de surveillance actifs
+    fn print_points<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        if self.breakpoints.is_empty() && self.watchpoints.is_empty() {
+            return writeln!(out, "Aucun point d'arrêt ni de surveillance");
+        }
+        for addr in &self.breakpoints {
+            writeln!(out, "break {:04X}", addr)?;
+        }
+        for (addr, value) in &self.watchpoints {
+            writeln!(out, "watch {:04X} (valeur {})", addr, value)?;
+        }
+        Ok(())
+    }
+
+    /// Examine `count` trytes à partir d'une adresse
+    fn print_trytes<W: Write>(&self, addr: Address, count: usize, out: &mut W) -> io::Result<()> {
+        for a in addr..addr + count {
+            match self.cpu.memory.read_tryte(a) {
+                Ok(tryte) => writeln!(
+                    out,
+                    "{:04X}: {} | {} | {}",
+                    a,
+                    format_tryte_ternary(&tryte),
+                    format_tryte_base24(&tryte),
+                    format_tryte_decimal(&tryte)
+                )?,
+                Err(e) => return writeln!(out, "{:04X}: {:?}", a, e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Examine `count` mots à partir d'une adresse (sans contrainte d'alignement)
+    fn print_words<W: Write>(&self, addr: Address, count: usize, out: &mut W) -> io::Result<()> {
+        for i in 0..count {
+            let base = addr + i * 8;
+            let mut word = Word::zero();
+            for t in 0..8 {
+                match self.cpu.memory.read_tryte(base + t) {
+                    Ok(tryte) => word.set_tryte(t, tryte),
+                    Err(e) => return writeln!(out, "{:04X}: {:?}", base, e),
+                }
+            }
+            writeln!(out, "{:04X}: {}", base, format_word(&word))?;
+        }
+        Ok(())
+    }
+}
+
+/// Affiche l'aide du REPL
+fn print_help<W: Write>(out: &mut W) -> io::Result<()> {
+    writeln!(out, "Commandes (adresses en hexadécimal):")?;
+    writeln!(out, "  break|b <addr>       poser un point d'arrêt")?;
+    writeln!(out, "  delete|d <addr>      supprimer un point d'arrêt")?;
+    writeln!(out, "  watch|w <addr>       surveiller un tryte mémoire")?;
+    writeln!(out, "  unwatch <addr>       arrêter la surveillance")?;
+    writeln!(out, "  info                 lister les points d'arrêt et de surveillance")?;
+    writeln!(out, "  step|s [n]           exécuter n instructions")?;
+    writeln!(out, "  next|n               exécuter en passant par-dessus les appels")?;
+    writeln!(out, "  continue|c           reprendre jusqu'au prochain arrêt")?;
+    writeln!(out, "  regs|r               registres (ternaire | base 24 | décimal)")?;
+    writeln!(out, "  csr                  registres de contrôle et d'état")?;
+    writeln!(out, "  flags|f              flags")?;
+    writeln!(out, "  x <addr> [n]         examiner n trytes")?;
+    writeln!(out, "  xw <addr> [n]        examiner n mots")?;
+    writeln!(out, "  disas [addr] [n]     désassembler n instructions (PC par défaut)")?;
+    writeln!(out, "  save <fichier>       enregistrer un instantané de la machine")?;
+    writeln!(out, "  load <fichier>       restaurer un instantané")?;
+    writeln!(out, "  quit|q               quitter")
+}
+
+/// Analyse une adresse hexadécimale (avec ou sans préfixe 0x)
+pub fn parse_address(s: &str) -> Result<Address, String> {
+    let digits = s.trim_start_matches("0x").trim_start_matches("0X");
+    Address::from_str_radix(digits, 16).map_err(|e| format!("adresse invalide '{}': {}", s, e))
+}
+
+/// Lit l'argument d'adresse obligatoire à la position donnée
+fn parse_address_arg(args: &[&str], index: usize) -> Result<Address, String> {
+    match args.get(index) {
+        Some(arg) => parse_address(arg),
+        None => Err("adresse manquante".to_string()),
+    }
+}
+
+/// Lit un compteur décimal optionnel à la position donnée
+fn parse_count_arg(args: &[&str], index: usize, default: usize) -> Result<usize, String> {
+    match args.get(index) {
+        Some(arg) => arg.parse().map_err(|_| format!("nombre invalide '{}'", arg)),
+        None => Ok(default),
+    }
+}
+
+/// Formate un mot dans les trois représentations: ternaire | base 24 | décimal
+pub fn format_word(word: &Word) -> String {
+    format!("{} | {} | {}", format_ternary(word), format_base24(word), format_decimal(word))
+}
+
+/// Représentation ternaire équilibrée (+, 0, -), trit de poids fort en tête, groupée par tryte
+pub fn format_ternary(word: &Word) -> String {
+    (0..8)
+        .rev()
+        .map(|i| format_tryte_ternary(&word.0[i]))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Représentation en base 24 (0-9A-N), tryte de poids fort en tête
+pub fn format_base24(word: &Word) -> String {
+    (0..8)
+        .rev()
+        .map(|i| format_tryte_base24(&word.0[i]))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Valeur décimale signée, ou marqueur si le mot contient un état spécial
+pub fn format_decimal(word: &Word) -> String {
+    if word.trytes().iter().any(|t| !matches!(t, Tryte::Digit(_))) {
+        "<spécial>".to_string()
+    } else {
+        word.to_i64().to_string()
+    }
+}
+
+/// Trois trits d'un tryte, poids fort en tête (ou nom de l'état spécial)
+pub fn format_tryte_ternary(tryte: &Tryte) -> String {
+    match tryte {
+        Tryte::Digit(_) => tryte
+            .to_trits()
+            .iter()
+            .rev()
+            .map(|t| match t {
+                Trit::P => '+',
+                Trit::Z => '0',
+                Trit::N => '-',
+            })
+            .collect(),
+        special => special.to_string(),
+    }
+}
+
+/// Chiffre base 24 d'un tryte (ou nom de l'état spécial)
+fn format_tryte_base24(tryte: &Tryte) -> String {
+    match tryte {
+        Tryte::Digit(d) if (*d as usize) < BASE24_DIGITS.len() => {
+            (BASE24_DIGITS[*d as usize] as char).to_string()
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Valeur décimale équilibrée d'un tryte (ou nom de l'état spécial)
+fn format_tryte_decimal(tryte: &Tryte) -> String {
+    match tryte {
+        Tryte::Digit(d) => (*d as i32 - 13).to_string(),
+        special => special.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::instruction_to_trytes;
+
+    /// Crée un débogueur dont la mémoire contient le programme donné à partir de l'adresse 0
+    fn debugger_with(program: &[Instruction]) -> Debugger {
+        let mut cpu = Cpu::with_memory_size(256);
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                cpu.memory.write_tryte(i * 4 + j, *tryte).unwrap();
+            }
+        }
+        cpu.state.write_pc(Word::zero());
+        Debugger::new(cpu)
+    }
+
+    #[test]
+    fn test_ebreak_resume_and_breakpoint() {
+        let mut dbg = debugger_with(&[Instruction::Nop, Instruction::EBreak, Instruction::Halt]);
+        dbg.add_breakpoint(8);
+
+        // EBREAK intercepté: le PC passe à l'instruction suivante
+        assert_eq!(dbg.cont(), DebugStop::EBreak(4));
+        assert_eq!(dbg.pc(), Ok(8));
+
+        // Le point d'arrêt est déjà sous le PC: continuer exécute HALT
+        assert_eq!(dbg.cont(), DebugStop::Halted);
+        assert!(dbg.cpu.halted);
+    }
+
+    #[test]
+    fn test_breakpoint_stops_before_instruction() {
+        let mut dbg = debugger_with(&[Instruction::Nop, Instruction::Nop, Instruction::Halt]);
+        dbg.add_breakpoint(8);
+
+        assert_eq!(dbg.cont(), DebugStop::Breakpoint(8));
+        assert_eq!(dbg.step(), DebugStop::Halted);
+    }
+
+    #[test]
+    fn test_watchpoint() {
+        let store = Instruction::Store { rs1: Register::R1, rs2: Register::R2, offset: 0 };
+        let mut dbg = debugger_with(&[store, Instruction::Halt]);
+        dbg.cpu.state.write_gpr(Register::R1, Word::from(16));
+        dbg.cpu.state.write_gpr(Register::R2, Word::from(5));
+        dbg.add_watchpoint(16).unwrap();
+        let old = dbg.cpu.memory.read_tryte(16).unwrap();
+
+        assert_eq!(
+            dbg.cont(),
+            DebugStop::Watchpoint { addr: 16, old, new: Tryte::Digit(18) }
+        );
+        assert_eq!(dbg.cont(), DebugStop::Halted);
+    }
+
+    #[test]
+    fn test_repl_commands() {
+        let mut dbg = debugger_with(&[Instruction::Nop, Instruction::Halt]);
+        let mut out = Vec::new();
+        dbg.run_repl("b 4\nc\ndisas\nstep\n\nq\n".as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("Point d'arrêt posé à 0004"));
+        assert!(out.contains("Point d'arrêt atteint à 0004"));
+        assert!(out.contains("0004: HALT"));
+        assert!(out.contains("Programme arrêté (HALT)"));
+    }
+
+    #[test]
+    fn test_repl_save_and_load_snapshot() {
+        let path = std::env::temp_dir().join(format!("prismchrono_debugger_{}.snap", std::process::id()));
+        let mut dbg = debugger_with(&[Instruction::Nop, Instruction::Nop, Instruction::Halt]);
+        let mut out = Vec::new();
+        let commands = format!("b 8\nc\nsave {0}\nc\nload {0}\nload\nq\n", path.display());
+        dbg.run_repl(commands.as_bytes(), &mut out).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        // Retour au point d'arrêt après HALT
+        assert!(out.contains("Programme arrêté (HALT)"));
+        assert!(out.contains("restauré\n=> 0008: HALT"));
+        assert!(out.contains("fichier manquant"));
+        assert!(!dbg.cpu.halted);
+        assert_eq!(dbg.cpu.instructions_executed, 2);
+    }
+
+    #[test]
+    fn test_format_word() {
+        let word = Word::from(5);
+        assert_eq!(format_ternary(&word), "000 000 000 000 000 000 000 +--");
+        assert_eq!(format_base24(&word), "D:D:D:D:D:D:D:I");
+        assert_eq!(format_decimal(&word), "5");
+        assert_eq!(format_decimal(&Word::from(-40)), "-40");
+        assert_eq!(format_decimal(&Word::undefined()), "<spécial>");
+    }
+}
@@ -0,0 +1,11 @@
+// src/devices/mod.rs
+// Périphériques projetés en mémoire (voir `memory::Device`)
+
+pub mod uart; // Console série (cf. rtl/io/uart_controller.vhd)
+pub mod timer; // Timer machine (cf. rtl/timer/timer_unit.vhd)
+pub mod plic; // Contrôleur d'interruptions externes (cf. rtl/intc/plic_simple.vhd)
+
+// Ré-exporte les périphériques pour un accès plus facile
+pub use uart::{UART_BASE, UART_IRQ, Uart};
+pub use timer::{TIMER_BASE, Timer};
+pub use plic::{PLIC_BASE, Plic};
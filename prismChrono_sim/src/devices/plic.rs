@@ -0,0 +1,257 @@
+// src/devices/plic.rs
+// Modèle du contrôleur d'interruptions externes projeté en mémoire (cf. rtl/intc/plic_simple.vhd)
+//
+// Le contrôleur reçoit les demandes des périphériques raccordés (`Memory::map_device_with_irq`)
+// et lève l'interruption externe du processeur tant qu'une source autorisée, en attente
+// et de priorité strictement supérieure au seuil existe. La source 0 est réservée:
+// CLAIM la retourne quand aucune interruption n'est à traiter.
+//
+// Registres, un Mot (8 trytes) chacun:
+//   +8*i  PRIORITY[i]  priorité de la source i (1 <= i < PLIC_SOURCES, 0 = jamais transmise)
+//   +64   ENABLE       trit i = P: source i autorisée
+//   +72   PENDING      lecture seule, trit i = P: source i en attente
+//   +80   THRESHOLD    seuil de priorité
+//   +88   CLAIM        lecture: réclame la source la plus prioritaire (la plus petite en cas
+//                      d'égalité) et l'efface de PENDING; écriture: fin de traitement
+//                      (complete) de la source écrite, qui peut à nouveau être signalée
+//
+// Une source réclamée n'est plus signalée avant son complete, même si le périphérique
+// maintient sa demande.
+
+use crate::core::{Address, Trit, Word};
+use crate::memory::{Device, InterruptLine};
+
+// Adresse de base par défaut (tryte par tryte, un chiffre)
+pub const PLIC_BASE: Address = 0x20800;
+
+// Nombre de sources, source 0 (réservée) comprise
+pub const PLIC_SOURCES: usize = 8;
+
+// Offsets des registres
+pub const PRIORITY: Address = 0;
+pub const ENABLE: Address = 64;
+pub const PENDING: Address = 72;
+pub const THRESHOLD: Address = 80;
+pub const CLAIM: Address = 88;
+
+// Taille de la plage réclamée
+const PLIC_SIZE: usize = 96;
+
+pub struct Plic {
+    priorities: [i64; PLIC_SOURCES], // Priorité de chaque source
+    enabled: [bool; PLIC_SOURCES],   // Sources autorisées
+    pending: [bool; PLIC_SOURCES],   // Demandes en attente
+    claimed: [bool; PLIC_SOURCES],   // Sources en cours de traitement (réclamées, sans complete)
+    threshold: i64,                  // Seuil de priorité
+}
+
+impl Plic {
+    // Crée un contrôleur au reset: tout est désactivé
+    pub fn new() -> Self {
+        Plic {
+            priorities: [0; PLIC_SOURCES],
+            enabled: [false; PLIC_SOURCES],
+            pending: [false; PLIC_SOURCES],
+            claimed: [false; PLIC_SOURCES],
+            threshold: 0,
+        }
+    }
+
+    // Source à transmettre au processeur: autorisée, en attente, au-dessus du seuil
+    fn best_source(&self) -> Option<usize> {
+        (1..PLIC_SOURCES)
+            .filter(|&source| {
+                self.enabled[source] && self.pending[source] && self.priorities[source] > self.threshold
+            })
+            .fold(None, |best: Option<usize>, source| match best {
+                Some(current) if self.priorities[current] >= self.priorities[source] => Some(current),
+                _ => Some(source),
+            })
+    }
+
+    // Construit un Mot dont le trit i vaut P si flags[i]
+    fn flags_word(flags: &[bool; PLIC_SOURCES]) -> Word {
+        let mut word = Word::zero();
+        for (source, &flag) in flags.iter().enumerate() {
+            if flag {
+                word.set_trit(source, Trit::P);
+            }
+        }
+        word
+    }
+
+    // Relit les drapeaux d'un Mot construit par `flags_word`
+    fn word_flags(word: &Word) -> [bool; PLIC_SOURCES] {
+        std::array::from_fn(|source| word.get_trit(source) == Trit::P)
+    }
+}
+
+impl Default for Plic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Device for Plic {
+    fn name(&self) -> &str {
+        "plic"
+    }
+
+    fn size(&self) -> usize {
+        PLIC_SIZE
+    }
+
+    fn read(&mut self, offset: Address) -> Word {
+        match offset {
+            PRIORITY..ENABLE => Word::from_i64(self.priorities[offset / 8]),
+            ENABLE => Self::flags_word(&self.enabled),
+            PENDING => Self::flags_word(&self.pending),
+            THRESHOLD => Word::from_i64(self.threshold),
+            CLAIM => match self.best_source() {
+                Some(source) => {
+                    self.pending[source] = false;
+                    self.claimed[source] = true;
+                    Word::from(source as i32)
+                }
+                None => Word::zero(),
+            },
+            _ => Word::zero(),
+        }
+    }
+
+    fn write(&mut self, offset: Address, value: Word) {
+        match offset {
+            // La priorité de la source réservée reste nulle
+            PRIORITY..ENABLE if offset > PRIORITY => self.priorities[offset / 8] = value.to_i64(),
+            ENABLE => {
+                for source in 1..PLIC_SOURCES {
+                    self.enabled[source] = value.get_trit(source) == Trit::P;
+                }
+            }
+            THRESHOLD => self.threshold = value.to_i64(),
+            CLAIM => {
+                let source = value.to_i64();
+                if (1..PLIC_SOURCES as i64).contains(&source) {
+                    self.claimed[source as usize] = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn cpu_interrupt(&self) -> Option<InterruptLine> {
+        self.best_source().map(|_| InterruptLine::External)
+    }
+
+    fn update_sources(&mut self, sources: &[bool]) {
+        for (source, &level) in sources.iter().enumerate().take(PLIC_SOURCES).skip(1) {
+            if level && !self.claimed[source] {
+                self.pending[source] = true;
+            }
+        }
+    }
+
+    // Priorités, puis sources autorisées, en attente et réclamées, puis le seuil
+    fn save_state(&self) -> Vec<Word> {
+        let mut state: Vec<Word> = self.priorities.iter().map(|&priority| Word::from_i64(priority)).collect();
+        state.push(Self::flags_word(&self.enabled));
+        state.push(Self::flags_word(&self.pending));
+        state.push(Self::flags_word(&self.claimed));
+        state.push(Word::from_i64(self.threshold));
+        state
+    }
+
+    fn restore_state(&mut self, state: &[Word]) -> bool {
+        if state.len() != PLIC_SOURCES + 4 {
+            return false;
+        }
+        let (priorities, rest) = state.split_at(PLIC_SOURCES);
+        for (priority, word) in self.priorities.iter_mut().zip(priorities) {
+            *priority = word.to_i64();
+        }
+        self.enabled = Self::word_flags(&rest[0]);
+        self.pending = Self::word_flags(&rest[1]);
+        self.claimed = Self::word_flags(&rest[2]);
+        self.threshold = rest[3].to_i64();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::Memory;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // Périphérique dont la demande d'interruption est pilotée par le test
+    struct IrqLine(Rc<Cell<bool>>);
+
+    impl Device for IrqLine {
+        fn name(&self) -> &str {
+            "irq"
+        }
+        fn size(&self) -> usize {
+            8
+        }
+        fn read(&mut self, _offset: Address) -> Word {
+            Word::zero()
+        }
+        fn write(&mut self, _offset: Address, _value: Word) {}
+        fn irq_request(&self) -> bool {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn test_plic_priority_claim_complete() {
+        let mut memory = Memory::with_size(64);
+        memory.map_device(PLIC_BASE, Box::new(Plic::new())).unwrap();
+        let lines: Vec<Rc<Cell<bool>>> = (0..3).map(|_| Rc::new(Cell::new(false))).collect();
+        for (index, line) in lines.iter().enumerate() {
+            let base = 0x100 + index * 8;
+            memory.map_device_with_irq(base, Box::new(IrqLine(line.clone())), index + 1).unwrap();
+        }
+        let write = |memory: &mut Memory, offset: Address, value: i32| {
+            memory.write_word(PLIC_BASE + offset, Word::from(value)).unwrap()
+        };
+
+        // Sources 1 et 2 de priorités 1 et 3, source 3 de priorité 2 mais non autorisée
+        write(&mut memory, PRIORITY + 8, 1);
+        write(&mut memory, PRIORITY + 16, 3);
+        write(&mut memory, PRIORITY + 24, 2);
+        let mut enable = Word::zero();
+        enable.set_trit(1, Trit::P);
+        enable.set_trit(2, Trit::P);
+        memory.write_word(PLIC_BASE + ENABLE, enable).unwrap();
+        assert_eq!(memory.read_word(PLIC_BASE + PRIORITY + 16), Ok(Word::from(3)));
+
+        for line in &lines {
+            line.set(true);
+        }
+        memory.tick(1);
+        assert!(memory.interrupt_pending(InterruptLine::External));
+        let pending = memory.read_word(PLIC_BASE + PENDING).unwrap();
+        assert_eq!((1..4).map(|i| pending.get_trit(i)).collect::<Vec<_>>(), vec![Trit::P; 3]);
+
+        // Claim: la plus prioritaire d'abord, puis 0 une fois toutes réclamées
+        let claim = |memory: &mut Memory| memory.read_word(PLIC_BASE + CLAIM).unwrap().to_i64();
+        assert_eq!(claim(&mut memory), 2);
+        assert_eq!(claim(&mut memory), 1);
+        assert_eq!(claim(&mut memory), 0);
+        assert!(!memory.interrupt_pending(InterruptLine::External));
+
+        // Une source réclamée n'est pas re-signalée avant son complete
+        memory.tick(1);
+        assert!(!memory.interrupt_pending(InterruptLine::External));
+        write(&mut memory, CLAIM, 2);
+        memory.tick(1);
+        assert_eq!(claim(&mut memory), 2);
+
+        // Le seuil masque les priorités inférieures ou égales
+        write(&mut memory, CLAIM, 1);
+        write(&mut memory, THRESHOLD, 1);
+        memory.tick(1);
+        assert!(!memory.interrupt_pending(InterruptLine::External));
+    }
+}
@@ -0,0 +1,123 @@
+// src/devices/timer.rs
+// Modèle du timer machine projeté en mémoire (cf. rtl/timer/timer_unit.vhd)
+//
+// Registres, un Mot (8 trytes) chacun:
+//   +0   MTIME     compteur de cycles simulés (écriture: recharge le compteur)
+//   +8   MTIMECMP  valeur de comparaison
+//
+// L'interruption timer reste levée tant que MTIME >= MTIMECMP: le gestionnaire
+// l'acquitte en reprogrammant MTIMECMP. Comme dans le VHDL, MTIMECMP vaut la plus
+// grande valeur d'un Mot au reset, de sorte qu'aucune interruption n'est levée
+// avant que le logiciel ne le programme.
+
+use crate::core::{Address, WORD_MAX, Word};
+use crate::memory::{Device, InterruptLine};
+
+// Adresse de base par défaut, juste après la plage de l'UART (tryte par tryte, un chiffre)
+pub const TIMER_BASE: Address = 0x20100;
+
+// Offsets des registres
+pub const MTIME: Address = 0;
+pub const MTIMECMP: Address = 8;
+
+// Taille de la plage réclamée (2 registres)
+const TIMER_SIZE: usize = 16;
+
+pub struct Timer {
+    mtime: i64,    // Compteur de cycles
+    mtimecmp: i64, // Seuil de l'interruption
+}
+
+impl Timer {
+    // Crée un timer à zéro, interruption désactivée
+    pub fn new() -> Self {
+        Timer {
+            mtime: 0,
+            mtimecmp: WORD_MAX,
+        }
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Device for Timer {
+    fn name(&self) -> &str {
+        "timer"
+    }
+
+    fn size(&self) -> usize {
+        TIMER_SIZE
+    }
+
+    fn read(&mut self, offset: Address) -> Word {
+        match offset {
+            MTIME => Word::from_i64(self.mtime),
+            MTIMECMP => Word::from_i64(self.mtimecmp),
+            _ => Word::zero(),
+        }
+    }
+
+    fn write(&mut self, offset: Address, value: Word) {
+        match offset {
+            MTIME => self.mtime = value.to_i64(),
+            MTIMECMP => self.mtimecmp = value.to_i64(),
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        self.mtime = self.mtime.saturating_add(cycles as i64).min(WORD_MAX);
+    }
+
+    fn cpu_interrupt(&self) -> Option<InterruptLine> {
+        (self.mtime >= self.mtimecmp).then_some(InterruptLine::Timer)
+    }
+
+    fn save_state(&self) -> Vec<Word> {
+        vec![Word::from_i64(self.mtime), Word::from_i64(self.mtimecmp)]
+    }
+
+    fn restore_state(&mut self, state: &[Word]) -> bool {
+        let [mtime, mtimecmp] = state else {
+            return false;
+        };
+        self.mtime = mtime.to_i64();
+        self.mtimecmp = mtimecmp.to_i64();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::Memory;
+
+    #[test]
+    fn test_timer_compare() {
+        let mut memory = Memory::with_size(64);
+        memory.map_device(TIMER_BASE, Box::new(Timer::new())).unwrap();
+
+        // Au reset, MTIMECMP est au maximum: pas d'interruption
+        memory.tick(1000);
+        assert_eq!(memory.read_word(TIMER_BASE + MTIME), Ok(Word::from(1000)));
+        assert!(!memory.interrupt_pending(InterruptLine::Timer));
+
+        memory.write_word(TIMER_BASE + MTIMECMP, Word::from(1010)).unwrap();
+        memory.tick(9);
+        assert!(!memory.interrupt_pending(InterruptLine::Timer));
+        memory.tick(1);
+        assert!(memory.interrupt_pending(InterruptLine::Timer));
+
+        // Reprogrammer MTIMECMP acquitte l'interruption
+        memory.write_word(TIMER_BASE + MTIMECMP, Word::from(2000)).unwrap();
+        assert!(!memory.interrupt_pending(InterruptLine::Timer));
+
+        // MTIME est rechargeable
+        memory.write_word(TIMER_BASE + MTIME, Word::from(2000)).unwrap();
+        assert!(memory.interrupt_pending(InterruptLine::Timer));
+    }
+}
@@ -0,0 +1,256 @@
+// src/devices/uart.rs
+// Modèle de l'UART projeté en mémoire (cf. rtl/io/uart_controller.vhd)
+//
+// Les registres reprennent l'ordre du contrôleur VHDL, un Mot (8 trytes) chacun:
+//   +0   TX_DATA   écriture: envoie l'octet (valeur modulo 256) sur la sortie hôte
+//   +8   RX_DATA   lecture: consomme le prochain octet reçu, -1 si aucun
+//   +16  STATUS    lecture seule: trit i = P si le flag i est levé
+//                  (0: TX_READY, 1: TX_BUSY, 2: RX_READY, 3: RX_ERROR)
+//   +24  CONTROL   registre libre, relu tel qu'écrit (sans effet dans le modèle)
+//
+// L'émission est instantanée: TX_READY est toujours levé et TX_BUSY jamais.
+// La réception lit l'entrée hôte octet par octet; sur stdin, lire STATUS ou RX_DATA
+// bloque donc jusqu'à ce qu'un caractère (ou la fin de l'entrée) soit disponible.
+//
+// Raccordé au contrôleur d'interruptions, l'UART demande une interruption tant qu'un
+// octet reçu attend d'être lu. Seule une entrée scriptée (fichier) est relevée à chaque
+// cycle: relever stdin bloquerait la simulation.
+
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::path::Path;
+
+use crate::core::{Address, Trit, Word};
+use crate::memory::Device;
+
+// Adresse de base par défaut: atteignable en une séquence LUI+ADDI (`LA`/`MOVI`), et dont
+// chaque tryte reste un chiffre (les valeurs Bal3 11 à 13 codent les états spéciaux)
+pub const UART_BASE: Address = 0x20000;
+
+// Offsets des registres
+pub const TX_DATA: Address = 0;
+pub const RX_DATA: Address = 8;
+pub const STATUS: Address = 16;
+pub const CONTROL: Address = 24;
+
+// Position des flags dans le registre STATUS
+pub const STATUS_TX_READY: usize = 0;
+pub const STATUS_TX_BUSY: usize = 1;
+pub const STATUS_RX_READY: usize = 2;
+pub const STATUS_RX_ERROR: usize = 3;
+
+// Source du contrôleur d'interruptions à laquelle l'UART est raccordé par défaut
+pub const UART_IRQ: usize = 1;
+
+// Taille de la plage réclamée (4 registres)
+const UART_SIZE: usize = 32;
+
+pub struct Uart {
+    input: Option<Box<dyn Read>>, // Source de réception (None une fois épuisée)
+    output: Box<dyn Write>,       // Destination de l'émission
+    pending: Option<u8>,          // Octet reçu, pas encore lu par le logiciel
+    rx_error: bool,               // Erreur d'entrée/sortie côté réception
+    control: Word,                // Registre CONTROL
+    scripted: bool,               // Entrée relevée à chaque cycle (ne bloque pas)
+}
+
+impl Uart {
+    // Crée un UART lisant `input` et écrivant sur `output`
+    pub fn new(input: Box<dyn Read>, output: Box<dyn Write>) -> Self {
+        Uart {
+            input: Some(input),
+            output,
+            pending: None,
+            rx_error: false,
+            control: Word::zero(),
+            scripted: false,
+        }
+    }
+
+    // UART relié à l'entrée et à la sortie standard de l'hôte
+    pub fn stdio() -> Self {
+        Self::new(Box::new(io::stdin()), Box::new(io::stdout()))
+    }
+
+    // UART dont la réception est scriptée par le contenu d'un fichier
+    pub fn with_input_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let mut uart = Self::new(Box::new(BufReader::new(file)), Box::new(io::stdout()));
+        uart.scripted = true;
+        Ok(uart)
+    }
+
+    // UART sans réception (RX_READY jamais levé)
+    pub fn output_only() -> Self {
+        Self::new(Box::new(io::empty()), Box::new(io::stdout()))
+    }
+
+    // Tente de recevoir un octet si aucun n'est en attente
+    fn poll_input(&mut self) {
+        if self.pending.is_some() {
+            return;
+        }
+        let Some(input) = self.input.as_mut() else {
+            return;
+        };
+
+        let mut byte = [0u8; 1];
+        match input.read(&mut byte) {
+            Ok(1) => self.pending = Some(byte[0]),
+            Ok(_) => self.input = None, // Fin de l'entrée
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(_) => {
+                self.rx_error = true;
+                self.input = None;
+            }
+        }
+    }
+
+    // Construit la valeur du registre STATUS
+    fn status(&mut self) -> Word {
+        self.poll_input();
+
+        let mut status = Word::zero();
+        status.set_trit(STATUS_TX_READY, Trit::P);
+        if self.pending.is_some() {
+            status.set_trit(STATUS_RX_READY, Trit::P);
+        }
+        if self.rx_error {
+            status.set_trit(STATUS_RX_ERROR, Trit::P);
+        }
+        status
+    }
+}
+
+impl Device for Uart {
+    fn name(&self) -> &str {
+        "uart"
+    }
+
+    fn size(&self) -> usize {
+        UART_SIZE
+    }
+
+    fn read(&mut self, offset: Address) -> Word {
+        match offset {
+            RX_DATA => {
+                self.poll_input();
+                match self.pending.take() {
+                    Some(byte) => Word::from(byte as i32),
+                    None => Word::from(-1),
+                }
+            }
+            STATUS => self.status(),
+            CONTROL => self.control,
+            _ => Word::zero(),
+        }
+    }
+
+    fn write(&mut self, offset: Address, value: Word) {
+        match offset {
+            TX_DATA => {
+                let byte = value.to_i64().rem_euclid(256) as u8;
+                // La sortie hôte est au mieux: une erreur d'écriture ne doit pas arrêter le programme
+                let _ = self.output.write_all(&[byte]).and_then(|_| self.output.flush());
+            }
+            CONTROL => self.control = value,
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self, _cycles: u64) {
+        if self.scripted {
+            self.poll_input();
+        }
+    }
+
+    fn irq_request(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    // Octet reçu en attente (-1: aucun), erreur de réception, CONTROL
+    // La source de réception appartient à l'hôte: elle n'est pas sauvegardée
+    fn save_state(&self) -> Vec<Word> {
+        vec![
+            Word::from(self.pending.map_or(-1, i32::from)),
+            Word::from(self.rx_error as i32),
+            self.control,
+        ]
+    }
+
+    fn restore_state(&mut self, state: &[Word]) -> bool {
+        let [pending, rx_error, control] = state else {
+            return false;
+        };
+        self.pending = u8::try_from(pending.to_i64()).ok();
+        self.rx_error = rx_error.to_i64() != 0;
+        self.control = *control;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::Memory;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Sortie partagée pour relire ce que l'UART a émis
+    #[derive(Clone, Default)]
+    struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn memory_with_uart(input: &'static [u8]) -> (Memory, SharedOutput) {
+        let output = SharedOutput::default();
+        let mut memory = Memory::with_size(64);
+        let uart = Uart::new(Box::new(input), Box::new(output.clone()));
+        memory.map_device(UART_BASE, Box::new(uart)).unwrap();
+        (memory, output)
+    }
+
+    #[test]
+    fn test_uart_transmit() {
+        let (mut memory, output) = memory_with_uart(b"");
+
+        for byte in b"OK\n" {
+            memory.write_word(UART_BASE + TX_DATA, Word::from(*byte as i32)).unwrap();
+        }
+        // Valeur négative: seul l'octet de poids faible (modulo 256) est émis
+        memory.write_word(UART_BASE + TX_DATA, Word::from(-191)).unwrap();
+
+        assert_eq!(*output.0.borrow(), b"OK\nA".to_vec());
+    }
+
+    #[test]
+    fn test_uart_receive_and_status() {
+        let (mut memory, _) = memory_with_uart(b"hi");
+        let status = |memory: &Memory| memory.read_word(UART_BASE + STATUS).unwrap();
+
+        assert_eq!(status(&memory).get_trit(STATUS_TX_READY), Trit::P);
+        assert_eq!(status(&memory).get_trit(STATUS_TX_BUSY), Trit::Z);
+        assert_eq!(status(&memory).get_trit(STATUS_RX_READY), Trit::P);
+
+        // Lire STATUS ne consomme rien
+        assert_eq!(memory.read_word(UART_BASE + RX_DATA), Ok(Word::from(b'h' as i32)));
+        assert_eq!(memory.read_word(UART_BASE + RX_DATA), Ok(Word::from(b'i' as i32)));
+
+        // Entrée épuisée
+        assert_eq!(status(&memory).get_trit(STATUS_RX_READY), Trit::Z);
+        assert_eq!(status(&memory).get_trit(STATUS_RX_ERROR), Trit::Z);
+        assert_eq!(memory.read_word(UART_BASE + RX_DATA), Ok(Word::from(-1)));
+
+        // CONTROL est relu tel qu'écrit
+        memory.write_word(UART_BASE + CONTROL, Word::from(5)).unwrap();
+        assert_eq!(memory.read_word(UART_BASE + CONTROL), Ok(Word::from(5)));
+    }
+}
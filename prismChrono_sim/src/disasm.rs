@@ -0,0 +1,437 @@
+// src/disasm.rs
+// Désassembleur PrismChrono: produit un texte source réassemblable à partir
+// d'instructions standard (12 trits), compactes (8 trits) ou d'une image mémoire.
+//
+// Les cibles de JAL/CALL/BRANCH sont calculées comme le fait l'assembleur
+// (offset en instructions relatif à l'adresse suivante), celles de BRANCH3
+// relativement à l'adresse de l'instruction elle-même.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use prismchrono_isa::isa_defs::{
+    alu_imm_mnemonic, alu_op_mnemonic, branch_condition_name, csr_name, system_func_name,
+};
+
+use crate::core::{Address, Trit, Tryte};
+use crate::cpu::compact_format::{CompactInstruction, decode_compact};
+use crate::cpu::decode::{DecodeError, decode};
+use crate::cpu::isa::{BranchCondition, Instruction};
+use crate::cpu::registers::Register;
+use crate::loader::Segment;
+use crate::memory::Memory;
+
+/// Taille d'une instruction standard en trytes
+const INSTRUCTION_TRYTES: Address = 4;
+
+/// Table des symboles (adresse -> nom) utilisée pour nommer les cibles de branchement
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Symbols {
+    by_address: BTreeMap<Address, String>,
+}
+
+impl Symbols {
+    /// Crée une table vide
+    pub fn new() -> Self {
+        Symbols::default()
+    }
+
+    /// Analyse un fichier de symboles: une ligne `AAAA nom` (adresse hexadécimale) par symbole
+    /// Les lignes vides et les commentaires `#` sont ignorés
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut symbols = Symbols::new();
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let (Some(addr), Some(name), None) = (parts.next(), parts.next(), parts.next()) else {
+                return Err(format!("ligne {}: attendu 'ADRESSE NOM'", index + 1));
+            };
+            let addr = Address::from_str_radix(addr.trim_start_matches("0x"), 16)
+                .map_err(|_| format!("ligne {}: adresse invalide '{}'", index + 1, addr))?;
+            symbols.insert(addr, name);
+        }
+        Ok(symbols)
+    }
+
+    /// Associe un nom à une adresse (le premier nom défini est conservé)
+    pub fn insert(&mut self, addr: Address, name: &str) {
+        self.by_address.entry(addr).or_insert_with(|| name.to_string());
+    }
+
+    /// Nom du symbole défini à une adresse
+    pub fn name(&self, addr: Address) -> Option<&str> {
+        self.by_address.get(&addr).map(String::as_str)
+    }
+
+    /// Nom à utiliser comme opérande pour une adresse (symbole ou label synthétique)
+    pub fn label_for(&self, addr: Address) -> String {
+        match self.name(addr) {
+            Some(name) => name.to_string(),
+            None => synthetic_label(addr),
+        }
+    }
+}
+
+/// Label généré pour une cible sans symbole
+fn synthetic_label(addr: Address) -> String {
+    format!("L_{:04X}", addr)
+}
+
+/// Adresse atteinte par un offset en instructions relatif à `base`
+fn offset_target(base: Address, offset: i16) -> Option<Address> {
+    let target = base as i64 + offset as i64 * INSTRUCTION_TRYTES as i64;
+    (target >= 0).then_some(target as Address)
+}
+
+/// Cibles de branchement d'une instruction située à `address`
+pub fn branch_targets(instruction: &Instruction, address: Address) -> Vec<Address> {
+    let next = address + INSTRUCTION_TRYTES;
+    match instruction {
+        Instruction::Branch { offset, .. }
+        | Instruction::Jump { offset, .. }
+        | Instruction::Call { offset, .. } => offset_target(next, *offset).into_iter().collect(),
+        Instruction::Branch3 { offset_neg, offset_zero, offset_pos, .. } => [offset_neg, offset_zero, offset_pos]
+            .iter()
+            .filter_map(|offset| offset_target(address, **offset))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Opérande de cible: label si l'adresse est calculable, offset brut sinon
+fn target_operand(base: Address, offset: i16, symbols: &Symbols) -> String {
+    match offset_target(base, offset) {
+        Some(target) => symbols.label_for(target),
+        None => offset.to_string(),
+    }
+}
+
+/// Nom d'un CSR, ou son numéro s'il n'a pas de nom assembleur
+fn csr_operand(csr: i8) -> String {
+    csr_name(csr).map_or_else(|| csr.to_string(), str::to_string)
+}
+
+/// Formate une instruction standard en syntaxe assembleur
+pub fn format_instruction(instruction: &Instruction, address: Address, symbols: &Symbols) -> String {
+    let next = address + INSTRUCTION_TRYTES;
+    match instruction {
+        Instruction::Nop => "NOP".to_string(),
+        Instruction::Halt => "HALT".to_string(),
+        Instruction::EBreak => "EBREAK".to_string(),
+        Instruction::MRet => "MRET_T".to_string(),
+        Instruction::System { func } => match system_func_name(*func) {
+            Some(name) => name.to_string(),
+            None => format!("SYSTEM {}", func),
+        },
+
+        Instruction::AluReg { op, rs1, rs2, rd } => {
+            let mnemonic = alu_op_mnemonic(op).unwrap_or("ALU?");
+            format!("{} {}, {}, {}", mnemonic, rd, rs1, rs2)
+        }
+        Instruction::AluImm { op, rs1, rd, imm } => {
+            let mnemonic = alu_imm_mnemonic(op).unwrap_or("ALUI?");
+            format!("{} {}, {}, {}", mnemonic, rd, rs1, imm)
+        }
+        Instruction::Load { rd, rs1, offset } => format!("LOADW {}, {}, {}", rd, rs1, offset),
+        Instruction::Store { rs1, rs2, offset } => format!("STOREW {}, {}, {}", rs1, rs2, offset),
+        Instruction::LoadT { rd, rs1, offset } => format!("LOADT {}, {}, {}", rd, rs1, offset),
+        Instruction::LoadTU { rd, rs1, offset } => format!("LOADTU {}, {}, {}", rd, rs1, offset),
+        Instruction::StoreT { rs1, rs2, offset } => format!("STORET {}, {}, {}", rs1, rs2, offset),
+        Instruction::Branch { rs1, cond, offset } => format!(
+            "BRANCH {}, {}, {}, {}",
+            rs1,
+            Register::R0, // rs2 n'est pas encodé: la condition porte sur les flags
+            branch_condition_name(*cond),
+            target_operand(next, *offset, symbols)
+        ),
+        Instruction::Jump { rd, offset } => format!("JAL {}, {}", rd, target_operand(next, *offset, symbols)),
+        Instruction::Call { rd, offset } => format!("CALL {}, {}", rd, target_operand(next, *offset, symbols)),
+        Instruction::Lui { rd, imm } => format!("LUI {}, {}", rd, imm),
+        Instruction::Auipc { rd, imm } => format!("AUIPC {}, {}", rd, imm),
+        Instruction::Jalr { rd, rs1, offset } => format!("JALR {}, {}, {}", rd, rs1, offset),
+
+        Instruction::CsrRw { rd, csr, rs1 } => format!("CSRRW_T {}, {}, {}", rd, csr_operand(*csr), rs1),
+        Instruction::CsrRs { rd, csr, rs1 } => format!("CSRRS_T {}, {}, {}", rd, csr_operand(*csr), rs1),
+        Instruction::CsrRc { rd, rs1, csr } => format!("CSRRC_T {}, {}, {}", rd, csr_operand(*csr as i8), rs1),
+        Instruction::Csr { csr, rs1, offset } => format!("CSRI_T {}, {}, {}", csr_operand(*csr), rs1, offset),
+
+        Instruction::TernaryOp { op, rs1, rs2, rd } => format!("{:?} {}, {}, {}", op, rd, rs1, rs2),
+        Instruction::TernaryShift { op, rs1, rd, imm } => format!("{:?} {}, {}, {}", op, rd, rs1, imm),
+        Instruction::Branch3 { rs1, offset_neg, offset_zero, offset_pos } => format!(
+            "BRANCH3 {}, {}, {}, {}",
+            rs1,
+            target_operand(address, *offset_neg, symbols),
+            target_operand(address, *offset_zero, symbols),
+            target_operand(address, *offset_pos, symbols)
+        ),
+        Instruction::LoadT3 { rd, rs1, offset } => format!("LOADT3 {}, {}, {}", rd, rs1, offset),
+        Instruction::StoreT3 { rs1, rs2, offset } => format!("STORET3 {}, {}, {}", rs1, rs2, offset),
+        Instruction::TMemCpy { rd, rs1, rs2 } => format!("TMEMCPY {}, {}, {}", rd, rs1, rs2),
+        Instruction::TMemSet { rd, rs1, rs2 } => format!("TMEMSET {}, {}, {}", rd, rs1, rs2),
+        Instruction::MAddW { rd, rs1, rs2, rs3 } => format!("MADDW {}, {}, {}, {}", rd, rs1, rs2, rs3),
+        Instruction::MSubW { rd, rs1, rs2, rs3 } => format!("MSUBW {}, {}, {}, {}", rd, rs1, rs2, rs3),
+        Instruction::TSel { rd, rs1, rs2, rs3 } => format!("TSEL {}, {}, {}, {}", rd, rs1, rs2, rs3),
+        Instruction::SpecialStateOp { op, rs1, rd } => format!("{:?} {}, {}", op, rd, rs1),
+        Instruction::Base24Op { op, rs1, rs2, rd } => format!("{:?} {}, {}, {}", op, rd, rs1, rs2),
+
+        Instruction::VectorOp { op, vd, vs1, vs2 } => format!("{:?} {}, {}, {}", op, vd, vs1, vs2),
+        Instruction::VectorDot { rd, vs1, vs2 } => format!("TVDOT {}, {}, {}", rd, vs1, vs2),
+        Instruction::VectorReduce { op, rd, vs } => format!("{:?} {}, {}", op, rd, vs),
+        Instruction::VectorLoad { vd, rs1 } => format!("TVLOAD {}, {}", vd, rs1),
+        Instruction::VectorStore { vs, rs1 } => format!("TVSTORE {}, {}", vs, rs1),
+    }
+}
+
+/// Mnémonique d'une instruction, sans ses opérandes
+pub fn mnemonic(instruction: &Instruction) -> String {
+    let text = format_instruction(instruction, 0, &Symbols::new());
+    match text.split_once(' ') {
+        Some((mnemonic, _)) => mnemonic.to_string(),
+        None => text,
+    }
+}
+
+/// Formate une instruction compacte (8 trits) en syntaxe assembleur
+pub fn format_compact(instruction: &CompactInstruction) -> String {
+    match instruction {
+        CompactInstruction::CMov { rd, rs } => format!("CMOV {}, {}", rd, rs),
+        CompactInstruction::CAdd { rd, rs } => format!("CADD {}, {}", rd, rs),
+        CompactInstruction::CSub { rd, rs } => format!("CSUB {}, {}", rd, rs),
+        CompactInstruction::CBranch { cond, offset } => {
+            let cond = BranchCondition::from_index(*cond).map_or("??", branch_condition_name);
+            format!("CBRANCH {}, {}", cond, offset)
+        }
+    }
+}
+
+/// Désassemble une instruction de 12 trits (standard) ou 8 trits (compacte)
+pub fn disassemble_trits(trits: &[Trit], address: Address, symbols: &Symbols) -> Result<String, DecodeError> {
+    if trits.len() == 8 {
+        Ok(format_compact(&decode_compact(trits)?))
+    } else {
+        Ok(format_instruction(&decode(trits)?, address, symbols))
+    }
+}
+
+/// Lit les 12 trits de l'instruction stockée à une adresse
+fn read_instruction(memory: &Memory, address: Address) -> Option<[Trit; 12]> {
+    let mut trits = [Trit::Z; 12];
+    for i in 0..INSTRUCTION_TRYTES {
+        let tryte = memory.read_tryte(address + i).ok()?;
+        trits[i * 3..i * 3 + 3].copy_from_slice(&tryte.to_trits());
+    }
+    Some(trits)
+}
+
+/// Trits d'une instruction au format des fichiers .tobj (ex: `ZZP ZZZ ZZZ ZZZ`)
+fn format_raw(trits: &[Trit; 12]) -> String {
+    trits
+        .chunks(3)
+        .map(|chunk| chunk.iter().map(|t| t.to_string()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Une ligne de listing: instruction décodée ou donnée
+enum Item {
+    Instruction { address: Address, trits: Option<[Trit; 12]> },
+    Data { address: Address, tryte: Tryte },
+}
+
+impl Item {
+    fn address(&self) -> Address {
+        match self {
+            Item::Instruction { address, .. } | Item::Data { address, .. } => *address,
+        }
+    }
+
+    fn size(&self) -> Address {
+        match self {
+            Item::Instruction { .. } => INSTRUCTION_TRYTES,
+            Item::Data { .. } => 1,
+        }
+    }
+}
+
+/// Désassemble un programme chargé par `loader` (instructions et données)
+pub fn disassemble_program(memory: &Memory, segments: &[Segment], symbols: &Symbols) -> String {
+    let mut items = Vec::new();
+    for segment in segments {
+        match *segment {
+            Segment::Instruction(address) => items.push(Item::Instruction {
+                address,
+                trits: read_instruction(memory, address),
+            }),
+            Segment::Data { address, len } => {
+                for a in address..address + len {
+                    let tryte = memory.read_tryte(a).unwrap_or(Tryte::Undefined);
+                    items.push(Item::Data { address: a, tryte });
+                }
+            }
+        }
+    }
+    render(&items, symbols)
+}
+
+/// Désassemble `count` instructions d'une image mémoire à partir d'une adresse
+pub fn disassemble_range(memory: &Memory, start: Address, count: usize, symbols: &Symbols) -> String {
+    let items: Vec<Item> = (0..count)
+        .map(|i| start + i * INSTRUCTION_TRYTES)
+        .take_while(|address| address + INSTRUCTION_TRYTES <= memory.size())
+        .map(|address| Item::Instruction { address, trits: read_instruction(memory, address) })
+        .collect();
+    render(&items, symbols)
+}
+
+/// Produit le texte source: directives `.org`, labels, instructions et données
+fn render(items: &[Item], symbols: &Symbols) -> String {
+    // 1. Nommer toutes les cibles de branchement (symbole fourni ou label synthétique)
+    let mut labels = symbols.clone();
+    let mut targets = BTreeSet::new();
+    for item in items {
+        if let Item::Instruction { address, trits: Some(trits) } = item
+            && let Ok(instruction) = decode(trits)
+        {
+            for target in branch_targets(&instruction, *address) {
+                labels.insert(target, &synthetic_label(target));
+                targets.insert(target);
+            }
+        }
+    }
+
+    // 2. Émettre le listing
+    let mut out = String::new();
+    let mut defined = BTreeSet::new();
+    let mut next_address = None;
+    for item in items {
+        let address = item.address();
+        if next_address != Some(address) {
+            let _ = writeln!(out, ".org 0x{:04X}", address);
+        }
+        if let Some(name) = labels.name(address) {
+            let _ = writeln!(out, "{}:", name);
+            defined.insert(address);
+        }
+        let _ = match item {
+            Item::Instruction { trits: Some(trits), .. } => match decode(trits) {
+                Ok(instruction) => writeln!(
+                    out,
+                    "    {:<32}# {:04X}: {}",
+                    format_instruction(&instruction, address, &labels),
+                    address,
+                    format_raw(trits)
+                ),
+                Err(e) => writeln!(out, "    # {:04X}: {} ({})", address, format_raw(trits), e),
+            },
+            Item::Instruction { trits: None, .. } => writeln!(out, "    # {:04X}: hors mémoire", address),
+            Item::Data { tryte: Tryte::Digit(d), .. } => {
+                writeln!(out, "    {:<32}# {:04X}", format!(".tryte {}", *d as i32 - 13), address)
+            }
+            Item::Data { tryte, .. } => writeln!(out, "    # {:04X}: {} (état spécial)", address, tryte),
+        };
+        next_address = Some(address + item.size());
+    }
+
+    // 3. Définir les labels dont la cible est hors du listing
+    for target in targets.difference(&defined) {
+        let _ = writeln!(out, ".org 0x{:04X}", target);
+        let _ = writeln!(out, "{}:", labels.label_for(*target));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::isa::{AluOp, VectorReduceOp};
+    use crate::cpu::registers::VectorRegister;
+    use crate::loader::instruction_to_trytes;
+
+    fn write_program(memory: &mut Memory, base: Address, program: &[Instruction]) {
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            for (j, tryte) in instruction_to_trytes(&trits).iter().enumerate() {
+                memory.write_tryte(base + i * 4 + j, *tryte).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_instruction() {
+        let symbols = Symbols::new();
+        let cases = [
+            (Instruction::AluImm { op: AluOp::Add, rs1: Register::R0, rd: Register::R1, imm: -5 }, "ADDI R1, R0, -5"),
+            (Instruction::AluReg { op: AluOp::Sub, rs1: Register::R2, rs2: Register::R3, rd: Register::R1 }, "SUB R1, R2, R3"),
+            (Instruction::Store { rs1: Register::R2, rs2: Register::R5, offset: 8 }, "STOREW R2, R5, 8"),
+            (Instruction::CsrRw { rd: Register::R1, csr: 2, rs1: Register::R3 }, "CSRRW_T R1, MEPC_T, R3"),
+            (Instruction::System { func: 2 }, "ECALL"),
+            (Instruction::Branch { rs1: Register::R1, cond: BranchCondition::Zero, offset: -2 }, "BRANCH R1, R0, EQ, L_0030"),
+            (Instruction::Jump { rd: Register::R7, offset: 3 }, "JAL R7, L_0044"),
+            (Instruction::VectorLoad { vd: VectorRegister::V1, rs1: Register::R2 }, "TVLOAD V1, R2"),
+            (Instruction::VectorReduce { op: VectorReduceOp::TVSUM, rd: Register::R3, vs: VectorRegister::V1 }, "TVSUM R3, V1"),
+        ];
+        for (instruction, expected) in cases {
+            assert_eq!(format_instruction(&instruction, 0x34, &symbols), expected);
+        }
+    }
+
+    #[test]
+    fn test_symbols_name_targets() {
+        let symbols = Symbols::parse("# table\n0044 loop\n0x0030 exit\n").unwrap();
+        let jump = Instruction::Jump { rd: Register::R7, offset: 3 };
+        assert_eq!(format_instruction(&jump, 0x34, &symbols), "JAL R7, loop");
+
+        let branch3 = Instruction::Branch3 { rs1: Register::R1, offset_neg: -1, offset_zero: 1, offset_pos: 4 };
+        assert_eq!(format_instruction(&branch3, 0x34, &symbols), "BRANCH3 R1, exit, L_0038, loop");
+
+        assert!(Symbols::parse("zz loop").is_err());
+    }
+
+    #[test]
+    fn test_disassemble_compact() {
+        // CMOV R1, R2: op=[N,N] (-4), rd=[Z,P] (1), rs=[Z,Z,P,N] (2)
+        let trits = [Trit::N, Trit::N, Trit::Z, Trit::P, Trit::Z, Trit::Z, Trit::P, Trit::N];
+        assert_eq!(disassemble_trits(&trits, 0, &Symbols::new()).unwrap(), "CMOV R1, R2");
+    }
+
+    #[test]
+    fn test_disassemble_program_labels_and_data() {
+        let mut memory = Memory::with_size(64);
+        // 0x00: JAL R7, +1 -> 0x08; 0x04: NOP; 0x08: HALT; données à 0x10
+        write_program(
+            &mut memory,
+            0,
+            &[Instruction::Jump { rd: Register::R7, offset: 1 }, Instruction::Nop, Instruction::Halt],
+        );
+        memory.write_tryte(0x10, Tryte::Digit(18)).unwrap();
+        let segments = [
+            Segment::Instruction(0),
+            Segment::Instruction(4),
+            Segment::Instruction(8),
+            Segment::Data { address: 0x10, len: 1 },
+        ];
+        let mut symbols = Symbols::new();
+        symbols.insert(0, "start");
+
+        let text = disassemble_program(&memory, &segments, &symbols);
+        let lines: Vec<&str> = text.lines().map(|l| l.split('#').next().unwrap().trim()).collect();
+        assert_eq!(
+            lines,
+            [".org 0x0000", "start:", "JAL R7, L_0008", "NOP", "L_0008:", "HALT", ".org 0x0010", ".tryte 5"]
+        );
+    }
+
+    #[test]
+    fn test_disassemble_range() {
+        let mut memory = Memory::with_size(16);
+        write_program(&mut memory, 0, &[Instruction::Nop, Instruction::EBreak]);
+        let text = disassemble_range(&memory, 0, 10, &Symbols::new());
+        // La mémoire ne contient que 4 instructions complètes
+        assert_eq!(text.lines().filter(|l| l.starts_with("    ")).count(), 4);
+        assert!(text.contains("EBREAK"));
+    }
+}
@@ -4,32 +4,52 @@
 pub mod alu;
 pub mod core;
 pub mod cpu;
+pub mod debugger; // Débogueur interactif (REPL)
+pub mod devices; // Périphériques projetés en mémoire (UART, timer, PLIC)
+pub mod disasm; // Désassembleur (texte source réassemblable)
+pub mod loader; // Chargement des programmes .tbin/.tobj
 pub mod memory;
+pub mod metrics; // Métriques d'exécution au format JSON (scripts de benchmark)
+pub mod mmu; // MMU paginée Sv18_T et TLB (cf. rtl/mmu/mmu_t.vhd)
+pub mod preload; // Préchargement de registres et de données avant l'exécution
+pub mod semihost; // Semihosting: ECALL servis par l'hôte (sortie, console, fichiers)
+pub mod snapshot; // Instantanés de l'état complet de la machine (sauvegarde, reprise)
+pub mod trace; // Traces d'exécution (formats texte et binaire, comparaison)
 pub mod ternary_instructions; // Nouveau module pour les instructions ternaires spécialisées
 
 // Réexporte les types et fonctions importantes pour faciliter l'accès
 pub use crate::alu::{ternary_full_adder, trit_inv_word, trit_max_word, trit_min_word};
-pub use crate::core::{Address, Trit, Tryte, Word, is_valid_address};
+pub use crate::core::{Address, Trit, Tryte, Word, address_to_word, is_valid_address};
 pub use crate::cpu::registers::RegisterError;
 pub use crate::cpu::isa::{AluOp, Condition, Instruction, InstructionFormat, Opcode};
 pub use crate::cpu::decode::{DecodeError, decode};
-pub use crate::cpu::execute::{Cpu, ExecuteError};
-pub use crate::cpu::registers::{Flags, ProcessorState, Register};
-pub use crate::memory::{Memory, MemoryError};
+pub use crate::cpu::execute::{Cpu, ExecuteError, StopReason};
+pub use crate::debugger::{DebugStop, Debugger};
+pub use crate::loader::{LoadError, LoadedProgram, Segment};
+pub use crate::cpu::registers::{Flags, Interrupt, ProcessorState, Register, VectorRegister};
+pub use crate::memory::{Device, InterruptLine, Memory, MemoryError, MemoryWrite};
+pub use crate::mmu::{AccessType, Mmu, MmuError, PageTableEntry, Satp};
+pub use crate::devices::{Plic, Timer, Uart};
+pub use crate::snapshot::{Snapshot, SnapshotError};
+pub use crate::trace::{TraceFormat, TraceRecord, TraceSink, TraceWriter};
 
 // Nouveaux modules pour les améliorations avancées
 pub mod tvpu;            // Unité de traitement vectoriel ternaire
+pub mod tvpu_hardware;   // Modèle matériel de la TVPU (latences, accès mémoire)
+pub mod tvpu_astro;      // Coordonnées astronomiques et navigation sur la TVPU
+pub mod tvpu_base60;     // Calculs sexagésimaux (temps, angles) sur la TVPU
 pub mod branch_predictor; // Prédicteur de branchement ternaire avancé
 pub mod crypto;           // Instructions cryptographiques ternaires
-pub mod pipeline;         // Pipeline superscalaire ternaire
+pub mod pipeline;         // Modèle temporel du pipeline 5 étages
 pub mod cache;            // Cache prédictif ternaire
 pub mod neural;           // Support pour l'intelligence artificielle
 
 // Réexporte les nouvelles fonctionnalités
 pub use crate::tvpu::TernaryVector;
-pub use crate::branch_predictor::TernaryBranchPredictor;
-pub use crate::pipeline::SuperscalarPipeline;
-pub use crate::cache::TernaryPredictiveCache;
+pub use crate::tvpu_hardware::{TVPUError, TVPUHardware, TVPUOperation};
+pub use crate::branch_predictor::{BranchPredictor, BranchProfiler, PredictorKind, TernaryBranchPredictor};
+pub use crate::pipeline::{PipelineModel, PipelineStats};
+pub use crate::cache::{CacheConfig, CacheStats, MemoryCaches, TernaryPredictiveCache, WritePolicy};
 pub use crate::neural::TernaryMatrix;
 
 // Réexporte les instructions ternaires spécialisées
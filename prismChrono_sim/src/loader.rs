@@ -0,0 +1,475 @@
+// src/loader.rs
+// Chargement des programmes produits par prismchrono_asm (.tbin et .tobj) en mémoire
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::core::{Address, Trit, Tryte};
+use crate::memory::{Memory, MemoryError};
+
+/// Signature en tête des fichiers binaires .tbin
+const TBIN_MAGIC: &[u8; 4] = b"TBIN";
+/// Plus ancienne version du format .tbin supportée (sans symboles globaux)
+const TBIN_MIN_VERSION: u32 = 1;
+/// Version courante du format .tbin (v2: table des symboles globaux, v3: point d'entrée)
+const TBIN_VERSION: u32 = 3;
+/// Point d'entrée .tbin v3 signifiant « non spécifié »
+const NO_ENTRY: u32 = u32::MAX;
+/// Symbole global utilisé comme point d'entrée s'il est exporté
+pub const ENTRY_SYMBOL: &str = "_start";
+/// Type d'entrée .tbin: instruction (12 trits)
+const ENTRY_INSTRUCTION: u8 = 0;
+/// Type d'entrée .tbin: données (trytes)
+const ENTRY_DATA: u8 = 1;
+
+/// Erreurs possibles lors du chargement d'un programme
+#[derive(Debug, PartialEq, Eq)]
+pub enum LoadError {
+    Io(String),                  // Erreur de lecture du fichier
+    InvalidFormat(String),       // Contenu mal formé
+    UnsupportedVersion(u32),     // Version .tbin inconnue
+    MemoryError(MemoryError),    // Écriture hors de la mémoire
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(msg) => write!(f, "Erreur d'E/S: {}", msg),
+            LoadError::InvalidFormat(msg) => write!(f, "Format invalide: {}", msg),
+            LoadError::UnsupportedVersion(v) => write!(f, "Version TBIN non supportée: {}", v),
+            LoadError::MemoryError(e) => write!(f, "Erreur mémoire: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<MemoryError> for LoadError {
+    fn from(error: MemoryError) -> Self {
+        LoadError::MemoryError(error)
+    }
+}
+
+/// Élément placé en mémoire par le chargeur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment {
+    /// Instruction de 12 trits (4 trytes) à l'adresse donnée
+    Instruction(Address),
+    /// Suite de `len` trytes de données à partir de l'adresse donnée
+    Data { address: Address, len: usize },
+}
+
+/// Résumé d'un programme chargé en mémoire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedProgram {
+    /// Point d'entrée (celui du .tbin v3 s'il est spécifié, sinon `_start` s'il est exporté,
+    /// sinon la première instruction du fichier)
+    pub entry: Option<Address>,
+    /// Nombre d'instructions chargées
+    pub instructions: usize,
+    /// Nombre total de trytes écrits en mémoire
+    pub trytes: usize,
+    /// Instructions et données dans l'ordre du fichier (utilisé par le désassembleur)
+    pub segments: Vec<Segment>,
+    /// Symboles exportés par `.global`, dans l'ordre du fichier
+    pub globals: Vec<(String, Address)>,
+}
+
+impl LoadedProgram {
+    fn new() -> Self {
+        LoadedProgram {
+            entry: None,
+            instructions: 0,
+            trytes: 0,
+            segments: Vec::new(),
+            globals: Vec::new(),
+        }
+    }
+
+    /// Enregistre un symbole global; `_start` devient le point d'entrée
+    fn add_global(&mut self, name: String, address: Address) {
+        if name == ENTRY_SYMBOL {
+            self.entry = Some(address);
+        }
+        self.globals.push((name, address));
+    }
+
+    /// Adresse d'un symbole global
+    pub fn global(&self, name: &str) -> Option<Address> {
+        self.globals.iter().find(|(global, _)| global == name).map(|(_, address)| *address)
+    }
+
+    /// Écrit une suite de trytes à partir de l'adresse donnée
+    fn place(&mut self, memory: &mut Memory, address: Address, trytes: &[Tryte]) -> Result<(), LoadError> {
+        for (i, tryte) in trytes.iter().enumerate() {
+            memory.write_tryte(address + i, *tryte)?;
+        }
+        self.trytes += trytes.len();
+        Ok(())
+    }
+
+    /// Place une suite de trytes de données en mémoire
+    fn place_data(&mut self, memory: &mut Memory, address: Address, trytes: &[Tryte]) -> Result<(), LoadError> {
+        self.place(memory, address, trytes)?;
+        self.segments.push(Segment::Data { address, len: trytes.len() });
+        Ok(())
+    }
+
+    /// Place une instruction de 12 trits (4 trytes) en mémoire
+    fn place_instruction(
+        &mut self,
+        memory: &mut Memory,
+        address: Address,
+        trits: &[Trit; 12],
+    ) -> Result<(), LoadError> {
+        let trytes = instruction_to_trytes(trits);
+        self.place(memory, address, &trytes)?;
+        self.instructions += 1;
+        self.segments.push(Segment::Instruction(address));
+        if self.entry.is_none() {
+            self.entry = Some(address);
+        }
+        Ok(())
+    }
+}
+
+/// Regroupe les 12 trits d'une instruction en 4 trytes (ordre inverse de `Cpu::fetch`)
+pub fn instruction_to_trytes(trits: &[Trit; 12]) -> [Tryte; 4] {
+    let mut trytes = [Tryte::default(); 4];
+    for (i, tryte) in trytes.iter_mut().enumerate() {
+        *tryte = Tryte::from_trits([trits[i * 3], trits[i * 3 + 1], trits[i * 3 + 2]]);
+    }
+    trytes
+}
+
+/// Charge un fichier programme en détectant son format (.tbin binaire ou .tobj texte)
+pub fn load_file<P: AsRef<Path>>(path: P, memory: &mut Memory) -> Result<LoadedProgram, LoadError> {
+    let path = path.as_ref();
+    let bytes = fs::read(path)
+        .map_err(|e| LoadError::Io(format!("{}: {}", path.display(), e)))?;
+
+    if bytes.starts_with(TBIN_MAGIC) {
+        load_tbin(&bytes, memory)
+    } else {
+        let text = String::from_utf8(bytes)
+            .map_err(|_| LoadError::InvalidFormat("fichier ni TBIN ni texte .tobj".to_string()))?;
+        load_tobj(&text, memory)
+    }
+}
+
+/// Curseur de lecture sur le contenu d'un fichier .tbin
+struct TbinReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> TbinReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], LoadError> {
+        if self.pos + n > self.bytes.len() {
+            return Err(LoadError::InvalidFormat(format!(
+                "fin de fichier inattendue à l'octet {}",
+                self.pos
+            )));
+        }
+        let slice = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, LoadError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, LoadError> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+}
+
+/// Charge le contenu d'un fichier .tbin (voir `prismchrono_asm::output::write_tbin`)
+pub fn load_tbin(bytes: &[u8], memory: &mut Memory) -> Result<LoadedProgram, LoadError> {
+    let mut reader = TbinReader { bytes, pos: 0 };
+
+    if reader.take(4)? != TBIN_MAGIC {
+        return Err(LoadError::InvalidFormat("signature TBIN absente".to_string()));
+    }
+    let version = reader.u32()?;
+    if !(TBIN_MIN_VERSION..=TBIN_VERSION).contains(&version) {
+        return Err(LoadError::UnsupportedVersion(version));
+    }
+    let num_entries = reader.u32()?;
+
+    let mut program = LoadedProgram::new();
+    for _ in 0..num_entries {
+        let address = reader.u32()? as Address;
+        let entry_type = reader.u8()?;
+        let size = reader.u8()? as usize;
+
+        match entry_type {
+            ENTRY_INSTRUCTION => {
+                // Une instruction occupe 4 trytes, stockés sous forme de 12 trits (0=N, 1=Z, 2=P)
+                if size != 4 {
+                    return Err(LoadError::InvalidFormat(format!(
+                        "instruction de {} trytes à l'adresse {:04X}",
+                        size, address
+                    )));
+                }
+                let mut trits = [Trit::Z; 12];
+                for (trit, byte) in trits.iter_mut().zip(reader.take(12)?) {
+                    *trit = match byte {
+                        0 => Trit::N,
+                        1 => Trit::Z,
+                        2 => Trit::P,
+                        _ => {
+                            return Err(LoadError::InvalidFormat(format!(
+                                "trit invalide {} à l'adresse {:04X}",
+                                byte, address
+                            )));
+                        }
+                    };
+                }
+                program.place_instruction(memory, address, &trits)?;
+            }
+            ENTRY_DATA => {
+                // Chaque tryte est stocké par sa valeur Bal3 (-13 à +13)
+                let mut trytes = Vec::with_capacity(size);
+                for byte in reader.take(size)? {
+                    let tryte = Tryte::from_bal3(*byte as i8).ok_or_else(|| {
+                        LoadError::InvalidFormat(format!(
+                            "tryte invalide {} à l'adresse {:04X}",
+                            *byte as i8, address
+                        ))
+                    })?;
+                    trytes.push(tryte);
+                }
+                program.place_data(memory, address, &trytes)?;
+            }
+            other => {
+                return Err(LoadError::InvalidFormat(format!(
+                    "type d'entrée inconnu {} à l'adresse {:04X}",
+                    other, address
+                )));
+            }
+        }
+    }
+
+    // Table des symboles globaux (version 2 et suivantes)
+    if version >= 2 {
+        let num_globals = reader.u32()?;
+        for _ in 0..num_globals {
+            let address = reader.u32()? as Address;
+            let len = reader.u8()? as usize;
+            let name = std::str::from_utf8(reader.take(len)?)
+                .map_err(|_| LoadError::InvalidFormat(format!("nom de symbole global invalide à l'adresse {:04X}", address)))?;
+            program.add_global(name.to_string(), address);
+        }
+    }
+
+    // Point d'entrée explicite (version 3 et suivantes, fixé par l'éditeur de liens)
+    if version >= 3 {
+        let entry = reader.u32()?;
+        if entry != NO_ENTRY {
+            program.entry = Some(entry as Address);
+        }
+    }
+
+    Ok(program)
+}
+
+/// Charge le contenu textuel d'un fichier .tobj
+/// Format d'une ligne: `AAAA: ZZP ZZZ ZZZ ZZZ # Instruction`, `AAAA: 13 14 # Data`
+/// ou `.global NOM AAAA` pour un symbole exporté
+pub fn load_tobj(text: &str, memory: &mut Memory) -> Result<LoadedProgram, LoadError> {
+    let mut program = LoadedProgram::new();
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        // Ignorer les commentaires et les lignes vides
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(global) = line.strip_prefix(".global") {
+            let (name, addr_str) = global.trim().split_once(char::is_whitespace).ok_or_else(|| {
+                LoadError::InvalidFormat(format!("ligne {}: symbole global mal formé", line_number))
+            })?;
+            let address = Address::from_str_radix(addr_str.trim(), 16).map_err(|_| {
+                LoadError::InvalidFormat(format!("ligne {}: adresse invalide '{}'", line_number, addr_str.trim()))
+            })?;
+            program.add_global(name.to_string(), address);
+            continue;
+        }
+
+        let (addr_str, content) = line.split_once(':').ok_or_else(|| {
+            LoadError::InvalidFormat(format!("ligne {}: adresse manquante", line_number))
+        })?;
+        let address = Address::from_str_radix(addr_str.trim(), 16).map_err(|_| {
+            LoadError::InvalidFormat(format!("ligne {}: adresse invalide '{}'", line_number, addr_str.trim()))
+        })?;
+
+        let tokens: Vec<&str> = content.split_whitespace().collect();
+        let is_instruction = tokens.len() == 4
+            && tokens.iter().all(|t| t.len() == 3 && t.chars().all(|c| matches!(c, 'N' | 'Z' | 'P')));
+
+        if is_instruction {
+            let mut trits = [Trit::Z; 12];
+            for (i, c) in tokens.concat().chars().enumerate() {
+                trits[i] = match c {
+                    'N' => Trit::N,
+                    'P' => Trit::P,
+                    _ => Trit::Z,
+                };
+            }
+            program.place_instruction(memory, address, &trits)?;
+        } else {
+            let mut trytes = Vec::with_capacity(tokens.len());
+            for token in tokens {
+                trytes.push(parse_tobj_tryte(token).ok_or_else(|| {
+                    LoadError::InvalidFormat(format!("ligne {}: tryte invalide '{}'", line_number, token))
+                })?);
+            }
+            program.place_data(memory, address, &trytes)?;
+        }
+    }
+
+    Ok(program)
+}
+
+/// Analyse un tryte tel qu'affiché dans un .tobj (chiffre 0-23 ou état spécial)
+fn parse_tobj_tryte(token: &str) -> Option<Tryte> {
+    match token {
+        "UND" => Some(Tryte::Undefined),
+        "NUL" => Some(Tryte::Null),
+        "NaN" => Some(Tryte::NaN),
+        _ => match token.parse::<u8>() {
+            Ok(d) if d <= 23 => Some(Tryte::Digit(d)),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Construit une image .tbin contenant une instruction et une donnée
+    fn sample_tbin() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"TBIN");
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        // Instruction à 0x10: N Z P N Z P N Z P N Z P
+        bytes.extend_from_slice(&0x10u32.to_le_bytes());
+        bytes.extend_from_slice(&[0, 4]);
+        bytes.extend_from_slice(&[0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2]);
+        // Donnée à 0x20: trytes -13 et +5
+        bytes.extend_from_slice(&0x20u32.to_le_bytes());
+        bytes.extend_from_slice(&[1, 2]);
+        bytes.extend_from_slice(&[(-13i8) as u8, 5]);
+        bytes
+    }
+
+    #[test]
+    fn test_load_tbin() {
+        let mut memory = Memory::with_size(64);
+        let program = load_tbin(&sample_tbin(), &mut memory).unwrap();
+
+        assert_eq!(program.entry, Some(0x10));
+        assert_eq!(program.instructions, 1);
+        assert_eq!(program.trytes, 6);
+        assert_eq!(
+            program.segments,
+            vec![Segment::Instruction(0x10), Segment::Data { address: 0x20, len: 2 }]
+        );
+
+        let expected = Tryte::from_trits([Trit::N, Trit::Z, Trit::P]);
+        for i in 0..4 {
+            assert_eq!(memory.read_tryte(0x10 + i).unwrap(), expected);
+        }
+        assert_eq!(memory.read_tryte(0x20).unwrap(), Tryte::Digit(0));
+        assert_eq!(memory.read_tryte(0x21).unwrap(), Tryte::Digit(18));
+    }
+
+    #[test]
+    fn test_load_tbin_rejects_bad_header() {
+        let mut memory = Memory::with_size(64);
+        let mut bytes = sample_tbin();
+        bytes[4] = 4; // Version 4
+        assert_eq!(load_tbin(&bytes, &mut memory), Err(LoadError::UnsupportedVersion(4)));
+
+        let truncated = &sample_tbin()[..20];
+        assert!(matches!(load_tbin(truncated, &mut memory), Err(LoadError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_load_tbin_v2_globals() {
+        let mut bytes = sample_tbin();
+        bytes[4] = 2; // Version 2
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&0x20u32.to_le_bytes());
+        bytes.push(6);
+        bytes.extend_from_slice(b"_start");
+
+        let mut memory = Memory::with_size(64);
+        let program = load_tbin(&bytes, &mut memory).unwrap();
+        assert_eq!(program.globals, vec![("_start".to_string(), 0x20)]);
+        assert_eq!(program.entry, Some(0x20));
+    }
+
+    #[test]
+    fn test_load_tbin_v3_entry() {
+        // Le point d'entrée explicite prime sur `_start`
+        let mut bytes = sample_tbin();
+        bytes[4] = 3; // Version 3
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&0x20u32.to_le_bytes());
+        bytes.push(6);
+        bytes.extend_from_slice(b"_start");
+        let mut with_entry = bytes.clone();
+        with_entry.extend_from_slice(&0x14u32.to_le_bytes());
+        bytes.extend_from_slice(&NO_ENTRY.to_le_bytes());
+
+        let mut memory = Memory::with_size(64);
+        assert_eq!(load_tbin(&with_entry, &mut memory).unwrap().entry, Some(0x14));
+        assert_eq!(load_tbin(&bytes, &mut memory).unwrap().entry, Some(0x20));
+    }
+
+    #[test]
+    fn test_load_tobj() {
+        let text = "0004: NZP NZP NZP NZP # Instruction\n\n0008: 0 18 UND # Data\n";
+        let mut memory = Memory::with_size(64);
+        let program = load_tobj(text, &mut memory).unwrap();
+
+        assert_eq!(program.entry, Some(4));
+        assert_eq!(program.instructions, 1);
+        assert_eq!(memory.read_tryte(4).unwrap(), Tryte::from_trits([Trit::N, Trit::Z, Trit::P]));
+        assert_eq!(memory.read_tryte(8).unwrap(), Tryte::Digit(0));
+        assert_eq!(memory.read_tryte(9).unwrap(), Tryte::Digit(18));
+        assert_eq!(memory.read_tryte(10).unwrap(), Tryte::Undefined);
+    }
+
+    #[test]
+    fn test_load_tobj_globals() {
+        let text = ".global _start 0008\n.global helper 0004\n0004: NZP NZP NZP NZP # Instruction\n0008: ZZZ ZZZ ZZZ ZZZ # Instruction\n";
+        let mut memory = Memory::with_size(64);
+        let program = load_tobj(text, &mut memory).unwrap();
+
+        assert_eq!(program.entry, Some(8));
+        assert_eq!(program.global("helper"), Some(4));
+        assert_eq!(program.globals.len(), 2);
+    }
+
+    #[test]
+    fn test_instruction_round_trip_through_fetch_order() {
+        // Les trytes placés doivent redonner les mêmes trits dans l'ordre de `Cpu::fetch`
+        let trits = [
+            Trit::P, Trit::N, Trit::Z, Trit::Z, Trit::P, Trit::N,
+            Trit::N, Trit::N, Trit::P, Trit::Z, Trit::Z, Trit::P,
+        ];
+        let trytes = instruction_to_trytes(&trits);
+        let back: Vec<Trit> = trytes.iter().flat_map(|t| t.to_trits()).collect();
+        assert_eq!(back, trits.to_vec());
+    }
+}
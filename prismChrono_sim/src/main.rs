@@ -1,196 +1,690 @@
 // src/main.rs
+// Point d'entrée du simulateur PrismChrono: charge un programme .tbin/.tobj et l'exécute
+// le débogue dans un REPL interactif ou le désassemble
 
-// Déclare les modules que nous allons utiliser
-mod alu;
-mod core;
-mod cpu;
-mod memory;
-
-// Importe les types et fonctions nécessaires depuis nos modules
-use crate::core::{Address, Trit, Tryte, Word, is_valid_address};
- // Importe RegisterError depuis le module registers
-use crate::cpu::{Flags, ProcessorState, Register}; // Importe les types du CPU
-use crate::memory::Memory; // Importe Memory et son type d'erreur // Importe les fonctions de l'ALU
-
-fn main() {
-    // Affiche un message de démarrage sympa avec le nom de l'architecture
-    println!("🏳️\u{200d}🌈 Architecture PrismChrono Simulator - Starting!");
-    println!("---------------------------------------------");
-    println!("Architecture: Logic GPR Base-24 Ternary +");
-    println!("Word Size: 24 Trits (8 Trytes)");
-    println!(
-        "Addressable Memory: 16 MTrytes ({} trytes)",
-        core::MAX_ADDRESS
-    );
-    println!("Endianness: Little-Endian");
-    println!("---------------------------------------------");
-
-    // --- Section de Test des Types (Optionnel, mais utile au début) ---
-    println!("\n[Type Sanity Checks]");
-    let trit_n = Trit::N;
-    let trit_p = trit_n.inv();
-    println!("  Trit N: {}, Inverted: {}", trit_n, trit_p);
-    println!("  Trit P value: {}", trit_p.value());
-
-    let tryte_5 = Tryte::Digit(5);
-    let tryte_undef = Tryte::Undefined;
-    println!("  Tryte 5: {}, Bal3: {}", tryte_5, tryte_5.bal3_value());
-    println!(
-        "  Tryte UNDEF: {}, Bal3: {}",
-        tryte_undef,
-        tryte_undef.bal3_value()
-    );
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+
+use prismchrono_sim::branch_predictor::{BranchProfiler, DEFAULT_PREDICTOR_SEED, PredictorKind};
+use prismchrono_sim::cache::{CacheConfig, MemoryCaches, WritePolicy};
+use prismchrono_sim::disasm::{self, Symbols};
+use prismchrono_sim::loader;
+use prismchrono_sim::metrics::{OpcodeCounts, metrics_json};
+use prismchrono_sim::preload::{Preload, parse_preload_file};
+use prismchrono_sim::semihost::Semihost;
+use prismchrono_sim::trace::{self, TraceFormat, TraceWriter};
+use prismchrono_sim::mmu::DEFAULT_TLB_ENTRIES;
+use prismchrono_sim::devices::{PLIC_BASE, Plic, TIMER_BASE, Timer, UART_BASE, UART_IRQ, Uart};
+use prismchrono_sim::{Address, Cpu, Debugger, ExecuteError, LoadedProgram, Mmu, PipelineModel, StopReason, Trit, address_to_word};
+
+/// Arguments de ligne de commande du simulateur
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Forme courte: `prismchrono_sim prog.tbin` équivaut à `prismchrono_sim run prog.tbin`
+    #[command(flatten)]
+    run: Option<RunArgs>,
+}
+
+/// Sous-commandes du simulateur
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Charge un programme et l'exécute jusqu'à HALT ou EBREAK
+    Run(Box<RunArgs>),
+    /// Charge un programme et le débogue dans un REPL interactif
+    Debug(DebugArgs),
+    /// Désassemble un programme ou une instruction en texte source réassemblable
+    Disasm(DisasmArgs),
+    /// Affiche une trace d'exécution (texte ou binaire) au format texte
+    TraceShow(TraceShowArgs),
+    /// Compare deux traces d'exécution (texte ou binaire) et signale la première divergence
+    TraceDiff(TraceDiffArgs),
+}
+
+/// Options d'exécution d'un programme
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// Programme à exécuter (.tbin ou .tobj), facultatif avec --resume
+    // Le groupe "RunArgs" indique à clap que la forme courte est utilisée: comme RunArgs
+    // contient des options aplaties, ses champs n'y sont pas ajoutés automatiquement
+    #[arg(value_name = "PROGRAM", required_unless_present = "resume", group = "RunArgs")]
+    program: Option<PathBuf>,
+
+    /// Adresse de départ en hexadécimal (par défaut: première instruction du fichier)
+    #[arg(short, long, value_name = "ADDR", value_parser = parse_hex_address)]
+    entry: Option<Address>,
+
+    /// Nombre maximal d'instructions à exécuter
+    #[arg(long, value_name = "N")]
+    max_steps: Option<u64>,
+
+    /// Nombre d'entrées du TLB de la MMU (0: parcours des tables à chaque accès)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_TLB_ENTRIES)]
+    tlb_entries: usize,
+
+    /// Modéliser le pipeline 5 étages (CPI, bulles, forwarding) sans changer l'exécution
+    #[arg(long)]
+    pipeline: bool,
+
+    /// Évaluer un prédicteur de branchement sur chaque branchement exécuté (static, bimodal, gshare, hybrid)
+    #[arg(long, value_name = "PREDICTEUR")]
+    predictor: Option<PredictorKind>,
+
+    /// Graine du générateur pseudo-aléatoire du prédicteur (résultats reproductibles)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_PREDICTOR_SEED)]
+    predictor_seed: u64,
+
+    /// Enregistrer la trace d'exécution (PC, instruction, registres, mémoire, CSRs) dans ce fichier
+    #[arg(long, value_name = "FILE")]
+    trace: Option<PathBuf>,
+
+    /// Format de la trace: text (lisible, comparable ligne à ligne) ou binary (compact)
+    #[arg(long, value_name = "FORMAT", default_value = "text", requires = "trace")]
+    trace_format: TraceFormat,
+
+    /// Reprendre l'exécution depuis un instantané (l'éventuel programme est chargé avant)
+    #[arg(long, value_name = "FILE", group = "RunArgs")]
+    resume: Option<PathBuf>,
+
+    /// Enregistrer un instantané de la machine dans ce fichier (par défaut: à la fin de l'exécution)
+    #[arg(long, value_name = "FILE")]
+    checkpoint: Option<PathBuf>,
+
+    /// Enregistrer l'instantané après N instructions exécutées, puis continuer
+    #[arg(long, value_name = "N", requires = "checkpoint", conflicts_with = "checkpoint_on_break")]
+    checkpoint_at: Option<u64>,
+
+    /// Enregistrer l'instantané au premier EBREAK (PC après l'EBREAK), puis continuer
+    #[arg(long, requires = "checkpoint")]
+    checkpoint_on_break: bool,
+
+    /// Précharger un registre ou des Mots avant l'exécution: R1=5, SP=100 ou ADRESSE=V1,V2,... (répétable)
+    #[arg(long, value_name = "CIBLE=VALEURS")]
+    preload: Vec<Preload>,
+
+    /// Précharger les affectations d'un fichier de données, une par ligne (répétable)
+    #[arg(long, value_name = "FILE")]
+    preload_file: Vec<PathBuf>,
+
+    /// Servir les ECALL par l'hôte (sortie, console, compteurs): EXIT donne le code de sortie du simulateur
+    #[arg(long)]
+    semihost: bool,
+
+    /// Répertoire des fichiers accessibles par le semihosting (implique --semihost)
+    #[arg(long, value_name = "DIR")]
+    semihost_dir: Option<PathBuf>,
+
+    /// N'afficher que les métriques, au format JSON (pour les scripts de benchmark)
+    #[arg(short, long)]
+    metrics: bool,
+
+    /// Afficher l'état des registres à la fin de l'exécution
+    #[arg(short, long)]
+    dump_state: bool,
+
+    #[command(flatten)]
+    devices: DeviceArgs,
+
+    #[command(flatten)]
+    caches: CacheArgs,
+}
+
+/// Options des caches L1 (modèle temporel: cycles d'attente et statistiques)
+#[derive(Args, Debug)]
+struct CacheArgs {
+    /// Activer le cache d'instructions, géométrie ENSEMBLES:VOIES:MOTS[:PRÉCHARGEMENT] (défaut 16:2:4:4)
+    #[arg(long, value_name = "GEOMETRIE", num_args = 0..=1, default_missing_value = "16:2:4:4")]
+    icache: Option<CacheConfig>,
+
+    /// Activer le cache de données, géométrie ENSEMBLES:VOIES:MOTS[:PRÉCHARGEMENT] (défaut 16:2:4:4)
+    #[arg(long, value_name = "GEOMETRIE", num_args = 0..=1, default_missing_value = "16:2:4:4")]
+    dcache: Option<CacheConfig>,
+
+    /// Politique d'écriture du cache de données (write-back ou write-through)
+    #[arg(long, value_name = "POLITIQUE", default_value = "write-back")]
+    write_policy: WritePolicy,
+
+    /// Cycles d'attente pour transférer une ligne depuis ou vers la mémoire
+    #[arg(long, value_name = "CYCLES", default_value_t = 10)]
+    miss_penalty: u64,
+}
+
+impl CacheArgs {
+    /// Caches configurés par la ligne de commande
+    fn build(&self) -> MemoryCaches {
+        let configure = |config: CacheConfig| CacheConfig {
+            policy: self.write_policy,
+            miss_penalty: self.miss_penalty,
+            ..config
+        };
+        MemoryCaches::with_config(self.icache.map(configure), self.dcache.map(configure))
+    }
+}
+
+/// Options des périphériques projetés en mémoire
+#[derive(Args, Debug)]
+struct DeviceArgs {
+    /// Projeter la console UART (émission sur stdout, réception sur stdin)
+    #[arg(long)]
+    uart: bool,
+
+    /// Fichier dont le contenu est reçu par l'UART à la place de stdin (implique --uart)
+    #[arg(long, value_name = "FILE")]
+    uart_input: Option<PathBuf>,
+
+    /// Adresse de base de l'UART en hexadécimal (par défaut: 20000)
+    #[arg(long, value_name = "ADDR", value_parser = parse_hex_address)]
+    uart_base: Option<Address>,
+
+    /// Projeter le timer machine (MTIME/MTIMECMP) à l'adresse 20100
+    #[arg(long)]
+    timer: bool,
+
+    /// Projeter le contrôleur d'interruptions externes à l'adresse 20800 (l'UART y est raccordé, source 1)
+    #[arg(long)]
+    plic: bool,
+}
+
+/// Options du débogueur interactif
+#[derive(Args, Debug)]
+struct DebugArgs {
+    /// Programme à déboguer (.tbin ou .tobj)
+    #[arg(value_name = "PROGRAM")]
+    program: PathBuf,
+
+    /// Adresse de départ en hexadécimal (par défaut: première instruction du fichier)
+    #[arg(short, long, value_name = "ADDR", value_parser = parse_hex_address)]
+    entry: Option<Address>,
+
+    /// Fichier de symboles (`ADRESSE NOM` par ligne) pour nommer les cibles
+    #[arg(short, long, value_name = "FILE")]
+    symbols: Option<PathBuf>,
+
+    #[command(flatten)]
+    devices: DeviceArgs,
+}
+
+/// Options d'affichage d'une trace
+#[derive(Args, Debug)]
+struct TraceShowArgs {
+    /// Trace à afficher
+    #[arg(value_name = "TRACE")]
+    trace: PathBuf,
+}
+
+/// Options de comparaison de traces
+#[derive(Args, Debug)]
+struct TraceDiffArgs {
+    /// Trace de référence (ex: produite par un banc de test VHDL)
+    #[arg(value_name = "LEFT")]
+    left: PathBuf,
+
+    /// Trace à comparer
+    #[arg(value_name = "RIGHT")]
+    right: PathBuf,
+}
+
+/// Options du désassembleur
+#[derive(Args, Debug)]
+struct DisasmArgs {
+    /// Programme à désassembler (.tbin ou .tobj)
+    #[arg(value_name = "PROGRAM", required_unless_present = "raw")]
+    program: Option<PathBuf>,
+
+    /// Fichier de symboles (`ADRESSE NOM` par ligne) pour nommer les cibles
+    #[arg(short, long, value_name = "FILE")]
+    symbols: Option<PathBuf>,
+
+    /// Désassembler l'image mémoire à partir de cette adresse (hexadécimal) au lieu du contenu du fichier
+    #[arg(long, value_name = "ADDR", value_parser = parse_hex_address)]
+    start: Option<Address>,
+
+    /// Nombre d'instructions à désassembler avec --start
+    #[arg(short = 'n', long, value_name = "N", default_value_t = 16)]
+    count: usize,
+
+    /// Instruction isolée sous forme de trits N/Z/P (12 trits standard ou 8 trits compacts)
+    #[arg(long, value_name = "TRITS", conflicts_with = "program")]
+    raw: Option<String>,
+}
+
+/// Analyse une adresse hexadécimale (avec ou sans préfixe 0x)
+fn parse_hex_address(s: &str) -> Result<Address, String> {
+    prismchrono_sim::debugger::parse_address(s)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match (cli.command, cli.run) {
+        (Some(Command::Run(args)), _) => run_program(&args),
+        (None, Some(args)) => run_program(&args),
+        (Some(Command::Debug(args)), _) => debug_program(&args),
+        (Some(Command::Disasm(args)), _) => disasm_program(&args),
+        (Some(Command::TraceShow(args)), _) => show_trace(&args),
+        (Some(Command::TraceDiff(args)), _) => diff_trace_files(&args),
+        (None, None) => {
+            eprintln!("Aucun programme spécifié (voir --help)");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Projette les périphériques demandés dans la mémoire du CPU
+///
+/// `stdin_free` indique si l'entrée standard peut servir de réception à l'UART
+/// (le débogueur la réserve à ses commandes).
+fn map_devices(cpu: &mut Cpu, args: &DeviceArgs, stdin_free: bool) -> Result<(), String> {
+    if args.timer {
+        cpu.memory
+            .map_device(TIMER_BASE, Box::new(Timer::new()))
+            .map_err(|e| format!("Impossible de projeter le timer à {:04X}: {:?}", TIMER_BASE, e))?;
+    }
+    if args.plic {
+        cpu.memory
+            .map_device(PLIC_BASE, Box::new(Plic::new()))
+            .map_err(|e| format!("Impossible de projeter le PLIC à {:04X}: {:?}", PLIC_BASE, e))?;
+    }
+
+    if !args.uart && args.uart_input.is_none() {
+        return Ok(());
+    }
+
+    let uart = match &args.uart_input {
+        Some(path) => Uart::with_input_file(path)
+            .map_err(|e| format!("Erreur de lecture de {}: {}", path.display(), e))?,
+        None if stdin_free => Uart::stdio(),
+        None => Uart::output_only(),
+    };
+    let base = args.uart_base.unwrap_or(UART_BASE);
+    let mapped = if args.plic {
+        cpu.memory.map_device_with_irq(base, Box::new(uart), UART_IRQ)
+    } else {
+        cpu.memory.map_device(base, Box::new(uart))
+    };
+    mapped.map_err(|e| format!("Impossible de projeter l'UART à {:04X}: {:?}", base, e))
+}
+
+/// Charge un programme en mémoire et positionne le PC sur son point d'entrée
+fn load_program(
+    cpu: &mut Cpu,
+    path: &Path,
+    entry: Option<Address>,
+) -> Result<(LoadedProgram, Address), String> {
+    // 1. Charger le programme en mémoire
+    let program = loader::load_file(path, &mut cpu.memory)
+        .map_err(|e| format!("Erreur de chargement de {}: {}", path.display(), e))?;
+
+    // 2. Positionner le PC sur le point d'entrée
+    let entry = entry.or(program.entry).ok_or_else(|| {
+        format!("Aucune instruction dans {} et aucun point d'entrée fourni", path.display())
+    })?;
+    let pc = address_to_word(entry).map_err(|e| format!("Point d'entrée invalide: {}", e))?;
+    cpu.state.write_pc(pc);
+
+    Ok((program, entry))
+}
+
+/// Lit une trace (texte ou binaire) sous forme de lignes texte
+fn read_trace_lines(path: &Path) -> Result<Vec<String>, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Impossible de lire {}: {}", path.display(), e))?;
+    trace::trace_lines(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Affiche une trace au format texte
+fn show_trace(args: &TraceShowArgs) -> ExitCode {
+    match read_trace_lines(&args.trace) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Compare deux traces; code de sortie non nul si elles divergent
+fn diff_trace_files(args: &TraceDiffArgs) -> ExitCode {
+    let (left, right) = match (read_trace_lines(&args.left), read_trace_lines(&args.right)) {
+        (Ok(left), Ok(right)) => (left, right),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match trace::diff_traces(&left, &right) {
+        None => {
+            println!("Traces identiques ({} enregistrements)", left.len());
+            ExitCode::SUCCESS
+        }
+        Some(divergence) => {
+            println!("Première divergence à l'enregistrement {}:", divergence.index);
+            println!("< {}", divergence.left.as_deref().unwrap_or("(fin de trace)"));
+            println!("> {}", divergence.right.as_deref().unwrap_or("(fin de trace)"));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Charge et exécute un programme, puis affiche les métriques
+fn run_program(args: &RunArgs) -> ExitCode {
+    let mut cpu = Cpu::new();
+    cpu.mmu = Mmu::with_tlb_entries(args.tlb_entries);
+    cpu.caches = args.caches.build();
+    if args.pipeline {
+        cpu.pipeline = Some(PipelineModel::new());
+    }
+    if let Some(kind) = args.predictor {
+        cpu.branch_profiler = Some(BranchProfiler::new(kind.build(args.predictor_seed)));
+    }
+    if args.metrics {
+        cpu.opcode_counts = Some(OpcodeCounts::new());
+    }
+    if args.semihost || args.semihost_dir.is_some() {
+        cpu.semihost = Some(Semihost::stdio(args.semihost_dir.clone()));
+    }
+    if let Err(e) = map_devices(&mut cpu, &args.devices, true) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    if let Some(path) = &args.trace {
+        match fs::File::create(path) {
+            Ok(file) => {
+                cpu.trace = Some(Box::new(TraceWriter::new(io::BufWriter::new(file), args.trace_format)));
+            }
+            Err(e) => {
+                eprintln!("Impossible de créer la trace {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // 1-2. Charger le programme et positionner le PC
+    let mut code_size = 0;
+    if let Some(path) = &args.program {
+        let (program, entry) = match load_program(&mut cpu, path, args.entry) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        code_size = program.trytes;
+
+        if !args.metrics {
+            println!(
+                "Programme {} chargé: {} instructions, {} trytes, entrée à {:04X}",
+                path.display(),
+                program.instructions,
+                program.trytes,
+                entry
+            );
+        }
+    }
+
+    // Reprise: l'instantané remplace l'état chargé (mémoire, registres, compteurs)
+    if let Some(path) = &args.resume {
+        if let Err(e) = cpu.load_snapshot(path) {
+            eprintln!("Impossible de reprendre depuis {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        if !args.metrics {
+            println!(
+                "Reprise depuis {}: {} instructions déjà exécutées, PC à {:04X}",
+                path.display(),
+                cpu.instructions_executed,
+                cpu.state.read_pc().to_i64()
+            );
+        }
+    }
+
+    // Données d'entrée, écrites par-dessus le programme ou l'instantané
+    if let Err(e) = preload(&mut cpu, args) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    // 3. Exécuter jusqu'à l'arrêt
+    let result = execute(&mut cpu, args);
+
+    if !args.metrics {
+        match &result {
+            Ok(StopReason::Halted) => println!("Arrêt: HALT"),
+            Ok(StopReason::Exited(status)) => println!("Arrêt: EXIT (code {})", status),
+            Ok(StopReason::Breakpoint) => println!("Arrêt: EBREAK (point d'arrêt)"),
+            Ok(StopReason::StepLimit) => println!("Arrêt: limite d'instructions atteinte"),
+            Err(_) => {}
+        }
+    }
+    if let Err(e) = &result {
+        eprintln!("{}", e);
+    }
+
+    // 4. Afficher les métriques
+    if args.metrics {
+        println!("{}", metrics_json(&cpu, code_size, result.as_ref().ok().copied()));
+    } else {
+        cpu.report_metrics();
+    }
+    if args.dump_state {
+        cpu.dump_state();
+    }
+    if let Some(mut trace) = cpu.trace.take()
+        && let Err(e) = trace.finish()
+    {
+        eprintln!("Erreur d'écriture de la trace: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    match result {
+        // Code de sortie du processus: octet de poids faible, comme pour exit(3)
+        Ok(StopReason::Exited(status)) => ExitCode::from(status.rem_euclid(256) as u8),
+        Ok(_) => ExitCode::SUCCESS,
+        Err(_) => ExitCode::FAILURE,
+    }
+}
+
+/// Applique les fichiers de données puis les affectations de la ligne de commande
+fn preload(cpu: &mut Cpu, args: &RunArgs) -> Result<(), String> {
+    for path in &args.preload_file {
+        let text = fs::read_to_string(path).map_err(|e| format!("Erreur de lecture de {}: {}", path.display(), e))?;
+        for preload in parse_preload_file(&text).map_err(|e| format!("Fichier de données {}: {}", path.display(), e))? {
+            preload.apply(cpu).map_err(|e| format!("Fichier de données {}: {}", path.display(), e))?;
+        }
+    }
+    for preload in &args.preload {
+        preload.apply(cpu).map_err(|e| format!("Préchargement impossible: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Exécute le programme chargé et enregistre l'instantané demandé par --checkpoint
+fn execute(cpu: &mut Cpu, args: &RunArgs) -> Result<StopReason, String> {
+    let execution_error =
+        |cpu: &Cpu, e: ExecuteError| format!("Erreur d'exécution à PC={}: {:?}", cpu.state.read_pc().to_i64(), e);
+    let Some(path) = &args.checkpoint else {
+        return cpu.run(args.max_steps).map_err(|e| execution_error(cpu, e));
+    };
+
+    // Sans point de reprise explicite, l'instantané est pris à l'arrêt
+    if args.checkpoint_at.is_none() && !args.checkpoint_on_break {
+        let reason = cpu.run(args.max_steps).map_err(|e| execution_error(cpu, e))?;
+        save_checkpoint(cpu, path, args.metrics)?;
+        return Ok(reason);
+    }
+
+    // Exécution instruction par instruction pour s'arrêter exactement au point de reprise
+    let mut saved = false;
+    let mut steps: u64 = 0;
+    let reason = loop {
+        if !saved && args.checkpoint_at == Some(steps) {
+            save_checkpoint(cpu, path, args.metrics)?;
+            saved = true;
+        }
+        if args.max_steps.is_some_and(|max| steps >= max) {
+            break StopReason::StepLimit;
+        }
+        match cpu.run(Some(1)) {
+            Ok(StopReason::StepLimit) => {}
+            Ok(StopReason::Breakpoint) if args.checkpoint_on_break && !saved => {
+                // Reprendre après l'EBREAK, comme le débogueur
+                cpu.advance_pc();
+                save_checkpoint(cpu, path, args.metrics)?;
+                saved = true;
+            }
+            Ok(reason) => break reason,
+            Err(e) => return Err(execution_error(cpu, e)),
+        }
+        steps += 1;
+    };
+    if !saved {
+        return Err(format!("Point de reprise non atteint: aucun instantané enregistré dans {}", path.display()));
+    }
+    Ok(reason)
+}
+
+/// Enregistre un instantané de la machine
+fn save_checkpoint(cpu: &Cpu, path: &Path, quiet: bool) -> Result<(), String> {
+    cpu.save_snapshot(path)
+        .map_err(|e| format!("Impossible d'enregistrer l'instantané {}: {}", path.display(), e))?;
+    if !quiet {
+        println!(
+            "Instantané enregistré dans {} ({} instructions exécutées)",
+            path.display(),
+            cpu.instructions_executed
+        );
+    }
+    Ok(())
+}
+
+/// Charge un programme et lance le débogueur interactif sur l'entrée standard
+fn debug_program(args: &DebugArgs) -> ExitCode {
+    let mut cpu = Cpu::new();
+    if let Err(e) = map_devices(&mut cpu, &args.devices, false) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    let (program, entry) = match load_program(&mut cpu, &args.program, args.entry) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    let trits_for_5 = tryte_5.to_trits();
-    // Affichage T2:T1:T0
     println!(
-        "  Trits for 5 (T2:T1:T0): {}:{}:{}",
-        trits_for_5[2], trits_for_5[1], trits_for_5[0]
+        "Programme {} chargé: {} instructions, entrée à {:04X} (tapez 'help' pour l'aide)",
+        args.program.display(),
+        program.instructions,
+        entry
     );
-    let tryte_from_trits = Tryte::from_trits(trits_for_5);
-    println!("  Tryte reconstructed from trits: {}", tryte_from_trits);
-
-    let word_zero = Word::zero();
-    let mut word_default = Word::default(); // Devrait être 8x UNDEF
-    println!("  Word Zero (8 Trytes 'D'=13): {}", word_zero);
-    println!("  Word Default (8 Trytes 'UND'): {}", word_default);
-
-    if let Some(tryte) = word_default.tryte_mut(0) {
-        // Accès au Tryte de poids faible (index 0)
-        *tryte = Tryte::Digit(23); // 'N'
-    }
-    if let Some(tryte) = word_default.tryte_mut(7) {
-        // Accès au Tryte de poids fort (index 7)
-        *tryte = Tryte::Digit(0); // '0'
-    }
-    println!("  Word Modified (T7='0', T0='N'): {}", word_default);
-
-    let addr1: Address = 100;
-    let addr2: Address = 20_000_000; // Adresse hors limites
-    println!("  Address {} valid: {}", addr1, is_valid_address(addr1));
-    println!("  Address {} valid: {}", addr2, is_valid_address(addr2));
-    println!("---------------------------------------------");
-
-    // --- Section de Test de la Mémoire ---
-    println!("\n[Memory Subsystem Tests]");
-    // Crée une petite mémoire pour les tests (plus rapide qu'utiliser MAX_ADDRESS)
-    let mut mem = Memory::with_size(1024); // 1K trytes
-    println!("  Created memory with {} trytes", mem.size());
-
-    // Test d'écriture/lecture de trytes
-    let test_addr: Address = 42;
-    let test_tryte = Tryte::Digit(7);
-    match mem.write_tryte(test_addr, test_tryte) {
-        Ok(_) => println!("  Write tryte at address {} succeeded", test_addr),
-        Err(e) => println!("  Write tryte failed: {:?}", e),
-    }
-
-    match mem.read_tryte(test_addr) {
-        Ok(tryte) => println!("  Read tryte from address {}: {}", test_addr, tryte),
-        Err(e) => println!("  Read tryte failed: {:?}", e),
-    }
-
-    // Test d'écriture/lecture de mots
-    let word_addr: Address = 64; // Doit être multiple de 8 pour l'alignement
-    let test_word = Word([
-        Tryte::Digit(1),
-        Tryte::Digit(2),
-        Tryte::Digit(3),
-        Tryte::Digit(4),
-        Tryte::Digit(5),
-        Tryte::Digit(6),
-        Tryte::Digit(7),
-        Tryte::Digit(8),
-    ]);
-
-    match mem.write_word(word_addr, test_word) {
-        Ok(_) => println!("  Write word at address {} succeeded", word_addr),
-        Err(e) => println!("  Write word failed: {:?}", e),
-    }
-
-    match mem.read_word(word_addr) {
-        Ok(word) => println!("  Read word from address {}: {}", word_addr, word),
-        Err(e) => println!("  Read word failed: {:?}", e),
-    }
-
-    // Test d'erreur d'alignement
-    let misaligned_addr: Address = 66; // Non multiple de 8
-    match mem.read_word(misaligned_addr) {
-        Ok(_) => println!(
-            "  Read word from misaligned address {} succeeded (unexpected)",
-            misaligned_addr
-        ),
-        Err(e) => println!(
-            "  Read word from misaligned address {} failed as expected: {:?}",
-            misaligned_addr, e
-        ),
-    }
-
-    // Test d'erreur de limite
-    let out_of_bounds_addr: Address = 2000; // Au-delà de la taille (1024)
-    match mem.read_tryte(out_of_bounds_addr) {
-        Ok(_) => println!(
-            "  Read tryte from OOB address {} succeeded (unexpected)",
-            out_of_bounds_addr
-        ),
-        Err(e) => println!(
-            "  Read tryte from OOB address {} failed as expected: {:?}",
-            out_of_bounds_addr, e
-        ),
-    }
-
-    // --- Section de Test du Processeur ---
-    println!("\n[Processor State Tests]");
-    // Crée un nouvel état de processeur
-    let mut proc_state = ProcessorState::new();
-    println!("  Created new processor state");
-
-    // Test des registres généraux
-    let r3 = Register::R3;
-    let test_reg_value = Word([Tryte::Digit(5); 8]);
-    proc_state.write_gpr(r3, test_reg_value.clone());
-    println!("  Write to register {}: {}", r3, test_reg_value);
-
-    let read_value = proc_state.read_gpr(r3);
-    println!("  Read from register {}: {}", r3, read_value);
-
-    // Test du PC et SP
-    let pc_value = Word([Tryte::Digit(10); 8]);
-    proc_state.write_pc(pc_value.clone());
-    println!("  Set PC to: {}", pc_value);
-    println!("  Current PC: {}", proc_state.read_pc());
-
-    let sp_value = Word([Tryte::Digit(20); 8]);
-    proc_state.write_sp(sp_value.clone());
-    println!("  Set SP to: {}", sp_value);
-    println!("  Current SP: {}", proc_state.read_sp());
-
-    // Test des flags
-    let mut flags = Flags::new();
-    println!("  Initial flags: {}", flags);
-
-    flags.zf = true;
-    flags.sf = false;
-    flags.xf = true;
-    proc_state.write_flags(flags);
-    println!("  Updated flags: {}", proc_state.read_flags());
-
-    proc_state.reset_flags();
-    println!("  After reset flags: {}", proc_state.read_flags());
-
-    // Test de conversion d'index de registre
-    println!("\n  Register index conversions:");
-    for i in 0..8 {
-        match Register::from_index(i) {
-            Ok(reg) => println!("    Index {} -> Register {}", i, reg),
-            Err(e) => println!("    Index {} -> Error: {:?}", i, e),
-        }
-    }
-    match Register::from_index(8) {
-        Ok(reg) => println!("    Index 8 -> Register {} (unexpected)", reg),
-        Err(e) => println!("    Index 8 -> Error: {:?} (expected)", e),
-    }
-
-    println!("---------------------------------------------");
-    println!("[Simulator Setup Complete - Ready for CPU/ALU implementation]");
+
+    let mut debugger = Debugger::new(cpu);
+    match read_symbols(args.symbols.as_deref()) {
+        Ok(symbols) => debugger.symbols = with_globals(symbols, &program),
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    match debugger.run_repl(io::stdin().lock(), &mut io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Erreur d'entrée/sortie: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Lit un fichier de symboles optionnel
+fn read_symbols(path: Option<&Path>) -> Result<Symbols, String> {
+    let Some(path) = path else {
+        return Ok(Symbols::new());
+    };
+    let text = fs::read_to_string(path).map_err(|e| format!("Erreur de lecture de {}: {}", path.display(), e))?;
+    Symbols::parse(&text).map_err(|e| format!("Fichier de symboles {}: {}", path.display(), e))
+}
+
+/// Complète les symboles lus avec les symboles globaux exportés par le programme
+fn with_globals(mut symbols: Symbols, program: &LoadedProgram) -> Symbols {
+    for (name, address) in &program.globals {
+        symbols.insert(*address, name);
+    }
+    symbols
+}
+
+/// Désassemble un programme, une zone mémoire ou une instruction isolée
+fn disasm_program(args: &DisasmArgs) -> ExitCode {
+    let mut symbols = match read_symbols(args.symbols.as_deref()) {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Instruction isolée donnée en trits
+    if let Some(raw) = &args.raw {
+        let trits: Option<Vec<Trit>> = raw
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                'N' | '-' => Some(Trit::N),
+                'Z' | '0' => Some(Trit::Z),
+                'P' | '+' => Some(Trit::P),
+                _ => None,
+            })
+            .collect();
+        return match trits {
+            Some(trits) if trits.len() == 8 || trits.len() == 12 => {
+                match disasm::disassemble_trits(&trits, args.start.unwrap_or(0), &symbols) {
+                    Ok(text) => {
+                        println!("{}", text);
+                        ExitCode::SUCCESS
+                    }
+                    Err(e) => {
+                        eprintln!("Instruction invalide: {}", e);
+                        ExitCode::FAILURE
+                    }
+                }
+            }
+            _ => {
+                eprintln!("Attendu 8 ou 12 trits N/Z/P, trouvé '{}'", raw);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let Some(path) = &args.program else {
+        eprintln!("Aucun programme spécifié (voir --help)");
+        return ExitCode::FAILURE;
+    };
+    let mut cpu = Cpu::new();
+    let program = match loader::load_file(path, &mut cpu.memory) {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("Erreur de chargement de {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    symbols = with_globals(symbols, &program);
+
+    let text = match args.start {
+        Some(start) => disasm::disassemble_range(&cpu.memory, start, args.count, &symbols),
+        None => disasm::disassemble_program(&cpu.memory, &program.segments, &symbols),
+    };
+    print!("{}", text);
+    ExitCode::SUCCESS
 }
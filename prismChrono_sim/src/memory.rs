@@ -1,66 +1,403 @@
 // src/memory.rs
 
 use crate::core::{Address, MAX_ADDRESS, Tryte, Word, is_valid_address}; // Importe les types nécessaires
-use std::vec::Vec; // Utilise le vecteur dynamique de Rust pour stocker les trytes
+use std::cell::RefCell; // Les lectures de registres de périphérique ont des effets de bord
+use std::vec::Vec; // Utilise le vecteur dynamique de Rust pour la table des pages
+
+// Stockage de la RAM
+//
+// La RAM est découpée en pages de STORE_PAGE_SIZE trytes, allouées à la première écriture
+// d'une valeur définie: une page jamais écrite se lit Undefined sans occuper de mémoire.
+// Chaque tryte y occupe un octet (représentation compacte, voir `pack_tryte`) au lieu des
+// deux octets de l'énumération Tryte. Ces pages n'ont aucun rapport avec celles de la MMU.
+
+// Taille d'une page du stockage, en trytes (multiple de 8: un Mot aligné ne chevauche
+// jamais deux pages)
+pub const STORE_PAGE_SIZE: usize = 4096;
+
+// Codes des états spéciaux dans la représentation compacte (les chiffres sont codés
+// par leur valeur). Les traces et instantanés binaires utilisent la même représentation.
+pub const PACKED_UNDEFINED: u8 = 0xFD;
+pub const PACKED_NULL: u8 = 0xFE;
+pub const PACKED_NAN: u8 = 0xFF;
+
+// Représentation compacte d'un tryte sur un octet
+pub fn pack_tryte(tryte: Tryte) -> u8 {
+    match tryte {
+        Tryte::Digit(digit) => digit,
+        Tryte::Undefined => PACKED_UNDEFINED,
+        Tryte::Null => PACKED_NULL,
+        Tryte::NaN => PACKED_NAN,
+    }
+}
+
+// Tryte représenté par un octet (None pour un code qui n'est ni un chiffre ni un état spécial)
+pub fn unpack_tryte(byte: u8) -> Option<Tryte> {
+    match byte {
+        0..=26 => Some(Tryte::Digit(byte)),
+        PACKED_UNDEFINED => Some(Tryte::Undefined),
+        PACKED_NULL => Some(Tryte::Null),
+        PACKED_NAN => Some(Tryte::NaN),
+        _ => None,
+    }
+}
+
+// Table de décodage de la représentation compacte (lecture sans branchement)
+// Les codes invalides, jamais écrits dans le stockage, se lisent Undefined
+const UNPACKED: [Tryte; 256] = {
+    let mut table = [Tryte::Undefined; 256];
+    let mut digit = 0;
+    while digit <= 26 {
+        table[digit as usize] = Tryte::Digit(digit);
+        digit += 1;
+    }
+    table[PACKED_NULL as usize] = Tryte::Null;
+    table[PACKED_NAN as usize] = Tryte::NaN;
+    table
+};
+
+// Page du stockage, en représentation compacte
+type StorePage = Box<[u8; STORE_PAGE_SIZE]>;
 
 // Erreurs possibles lors de l'accès mémoire
 #[derive(Debug, PartialEq, Eq)]
 pub enum MemoryError {
-    OutOfBounds, // Adresse en dehors de la plage [0, MAX_ADDRESS-1]
-    Misaligned,  // Tentative d'accès Mot (Word) à une adresse non multiple de 8
+    OutOfBounds,    // Adresse en dehors de la plage [0, MAX_ADDRESS-1]
+    Misaligned,     // Tentative d'accès Mot (Word) à une adresse non multiple de 8
+    DeviceAccess,   // Accès Tryte à un registre de périphérique (accès Mot uniquement)
+    DeviceConflict, // Plage de périphérique chevauchant une plage déjà réclamée
+}
+
+// Ligne d'interruption d'un périphérique vers le processeur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptLine {
+    Timer,    // Interruption timer (cf. rtl/timer/timer_unit.vhd)
+    External, // Sortie du contrôleur d'interruptions externes (cf. rtl/intc/plic_simple.vhd)
+}
+
+// Périphérique projeté en mémoire (MMIO)
+//
+// Un périphérique réclame une plage d'adresses alignée sur 8 et expose des registres
+// d'un Mot chacun, accessibles par LOADW/STOREW. Les offsets passés à `read` et `write`
+// sont relatifs au début de la plage et toujours multiples de 8.
+//
+// Les méthodes d'interruption ont une implémentation par défaut: un périphérique
+// ordinaire n'a pas d'horloge et ne lève aucune interruption.
+pub trait Device {
+    // Nom du périphérique (pour les messages)
+    fn name(&self) -> &str;
+    // Taille de la plage réclamée, en trytes (multiple de 8)
+    fn size(&self) -> usize;
+    // Lit le registre à l'offset donné (peut avoir des effets de bord, ex: file de réception)
+    fn read(&mut self, offset: Address) -> Word;
+    // Écrit le registre à l'offset donné
+    fn write(&mut self, offset: Address, value: Word);
+    // Avance l'horloge du périphérique de `cycles` cycles simulés
+    fn tick(&mut self, _cycles: u64) {}
+    // Ligne d'interruption vers le processeur actuellement levée (timer, contrôleur)
+    fn cpu_interrupt(&self) -> Option<InterruptLine> {
+        None
+    }
+    // Demande d'interruption, transmise au contrôleur si le périphérique y est raccordé
+    fn irq_request(&self) -> bool {
+        false
+    }
+    // Contrôleur d'interruptions: niveau des sources raccordées (indice = numéro de source)
+    fn update_sources(&mut self, _sources: &[bool]) {}
+    // État interne conservé par les instantanés (voir snapshot.rs), en Mots
+    fn save_state(&self) -> Vec<Word> {
+        Vec::new()
+    }
+    // Restaure un état produit par `save_state`; false si l'état n'est pas reconnu
+    fn restore_state(&mut self, state: &[Word]) -> bool {
+        state.is_empty()
+    }
+}
+
+// Écriture observée par le journal d'écritures (traces d'exécution)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryWrite {
+    Tryte(Address, Tryte), // Écriture d'un tryte
+    Word(Address, Word),   // Écriture d'un Mot (RAM ou registre de périphérique)
+}
+
+// Périphérique projeté sur le bus, avec sa plage d'adresses
+struct MappedDevice {
+    base: Address,
+    size: usize,
+    irq_source: Option<usize>, // Source du contrôleur d'interruptions à laquelle il est raccordé
+    device: RefCell<Box<dyn Device>>,
 }
 
 pub struct Memory {
-    trytes: Vec<Tryte>, // Le stockage principal
+    size: usize,                  // Taille de la RAM, en trytes
+    pages: Vec<Option<StorePage>>, // Pages du stockage (None: jamais écrite, tout Undefined)
+    devices: Vec<MappedDevice>,   // Périphériques prioritaires sur la RAM dans leur plage
+    write_log: Option<Vec<MemoryWrite>>, // Journal des écritures, s'il est ouvert
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Memory {
     // Crée une nouvelle mémoire de taille MAX_ADDRESS, initialisée à Undefined
     pub fn new() -> Self {
-        Memory {
-            trytes: vec![Tryte::Undefined; MAX_ADDRESS],
-        }
+        Self::with_size(MAX_ADDRESS)
     }
 
     // Crée une nouvelle mémoire d'une taille spécifique (utile pour tests)
     pub fn with_size(size: usize) -> Self {
         Memory {
-            trytes: vec![Tryte::Undefined; size],
+            size,
+            pages: (0..size.div_ceil(STORE_PAGE_SIZE)).map(|_| None).collect(),
+            devices: Vec::new(),
+            write_log: None,
         }
     }
 
     // Retourne la taille totale de la mémoire en trytes
     pub fn size(&self) -> usize {
-        self.trytes.len()
+        self.size
+    }
+
+    // Nombre de pages du stockage allouées (occupation réelle: ce nombre × STORE_PAGE_SIZE octets)
+    pub fn allocated_pages(&self) -> usize {
+        self.pages.iter().filter(|page| page.is_some()).count()
+    }
+
+    // Lit un tryte de la RAM (adresse déjà vérifiée)
+    fn load(&self, addr: Address) -> Tryte {
+        match &self.pages[addr / STORE_PAGE_SIZE] {
+            Some(page) => UNPACKED[page[addr % STORE_PAGE_SIZE] as usize],
+            None => Tryte::Undefined,
+        }
+    }
+
+    // Écrit des trytes en RAM à partir de `addr`, dans une même page (adresses déjà
+    // vérifiées); écrire uniquement des Undefined dans une page absente ne l'alloue pas
+    fn store(&mut self, addr: Address, trytes: &[Tryte]) {
+        let slot = &mut self.pages[addr / STORE_PAGE_SIZE];
+        if slot.is_none() && trytes.iter().all(|tryte| *tryte == Tryte::Undefined) {
+            return;
+        }
+        let page = slot.get_or_insert_with(|| Box::new([PACKED_UNDEFINED; STORE_PAGE_SIZE]));
+        let offset = addr % STORE_PAGE_SIZE;
+        for (byte, tryte) in page[offset..offset + trytes.len()].iter_mut().zip(trytes) {
+            *byte = pack_tryte(*tryte);
+        }
+    }
+
+    // Projette un périphérique à l'adresse `base`: les accès à sa plage lui sont
+    // transmis au lieu d'atteindre la RAM. La plage peut dépasser la taille de la RAM
+    // mais doit rester dans l'espace adressable et ne pas chevaucher un autre périphérique.
+    pub fn map_device(&mut self, base: Address, device: Box<dyn Device>) -> Result<(), MemoryError> {
+        self.map(base, device, None)
+    }
+
+    // Projette un périphérique dont la demande d'interruption est raccordée à la source
+    // `source` du contrôleur d'interruptions (s'il y en a un de projeté)
+    pub fn map_device_with_irq(
+        &mut self,
+        base: Address,
+        device: Box<dyn Device>,
+        source: usize,
+    ) -> Result<(), MemoryError> {
+        self.map(base, device, Some(source))
+    }
+
+    fn map(&mut self, base: Address, device: Box<dyn Device>, irq_source: Option<usize>) -> Result<(), MemoryError> {
+        let size = device.size();
+        if !Self::is_word_aligned(base) || !Self::is_word_aligned(size) {
+            return Err(MemoryError::Misaligned);
+        }
+        if size == 0 || base.checked_add(size).is_none_or(|end| end > MAX_ADDRESS) {
+            return Err(MemoryError::OutOfBounds);
+        }
+        if self
+            .devices
+            .iter()
+            .any(|mapped| base < mapped.base + mapped.size && mapped.base < base + size)
+        {
+            return Err(MemoryError::DeviceConflict);
+        }
+
+        self.devices.push(MappedDevice {
+            base,
+            size,
+            irq_source,
+            device: RefCell::new(device),
+        });
+        Ok(())
+    }
+
+    // Avance l'horloge des périphériques de `cycles` cycles simulés, puis transmet
+    // au contrôleur d'interruptions le niveau des sources raccordées
+    pub fn tick(&mut self, cycles: u64) {
+        let source_count = self
+            .devices
+            .iter()
+            .filter_map(|mapped| mapped.irq_source)
+            .max()
+            .map_or(0, |source| source + 1);
+        let mut sources = vec![false; source_count];
+
+        for mapped in &mut self.devices {
+            let device = mapped.device.get_mut();
+            device.tick(cycles);
+            if let Some(source) = mapped.irq_source {
+                sources[source] |= device.irq_request();
+            }
+        }
+        for mapped in &mut self.devices {
+            mapped.device.get_mut().update_sources(&sources);
+        }
+    }
+
+    // Indique si un périphérique lève la ligne d'interruption `line` vers le processeur
+    pub fn interrupt_pending(&self, line: InterruptLine) -> bool {
+        self.devices
+            .iter()
+            .any(|mapped| mapped.device.borrow().cpu_interrupt() == Some(line))
+    }
+
+    // Liste les périphériques projetés: (adresse de base, taille, nom)
+    pub fn devices(&self) -> Vec<(Address, usize, String)> {
+        self.devices
+            .iter()
+            .map(|mapped| (mapped.base, mapped.size, mapped.device.borrow().name().to_string()))
+            .collect()
+    }
+
+    // Périphérique dont la plage contient `addr`, avec l'offset relatif
+    fn device_at(&self, addr: Address) -> Option<(&MappedDevice, Address)> {
+        self.devices
+            .iter()
+            .find(|mapped| addr >= mapped.base && addr < mapped.base + mapped.size)
+            .map(|mapped| (mapped, addr - mapped.base))
+    }
+
+    // État des périphériques projetés: (adresse de base, nom, état sauvegardé)
+    pub fn device_states(&self) -> Vec<(Address, String, Vec<Word>)> {
+        self.devices
+            .iter()
+            .map(|mapped| {
+                let device = mapped.device.borrow();
+                (mapped.base, device.name().to_string(), device.save_state())
+            })
+            .collect()
+    }
+
+    // Restaure l'état du périphérique projeté à `base`; false s'il n'y en a pas,
+    // s'il porte un autre nom ou s'il ne reconnaît pas l'état
+    pub fn restore_device_state(&mut self, base: Address, name: &str, state: &[Word]) -> bool {
+        self.devices
+            .iter_mut()
+            .find(|mapped| mapped.base == base)
+            .map(|mapped| mapped.device.get_mut())
+            .is_some_and(|device| device.name() == name && device.restore_state(state))
+    }
+
+    // Plages contiguës de trytes définis (autres que Undefined): (adresse, contenu)
+    pub fn defined_ranges(&self) -> Vec<(Address, Vec<Tryte>)> {
+        let mut ranges: Vec<(Address, Vec<Tryte>)> = Vec::new();
+        for (index, page) in self.pages.iter().enumerate() {
+            let Some(page) = page else {
+                continue;
+            };
+            for (offset, &byte) in page.iter().enumerate() {
+                let tryte = UNPACKED[byte as usize];
+                if tryte == Tryte::Undefined {
+                    continue;
+                }
+                let addr = index * STORE_PAGE_SIZE + offset;
+                match ranges.last_mut() {
+                    Some((start, trytes)) if *start + trytes.len() == addr => trytes.push(tryte),
+                    _ => ranges.push((addr, vec![tryte])),
+                }
+            }
+        }
+        ranges
+    }
+
+    // Remet toute la RAM à Undefined (les périphériques restent projetés)
+    pub fn clear(&mut self) {
+        self.pages.iter_mut().for_each(|page| *page = None);
+    }
+
+    // Copie des trytes en RAM à partir de `addr`, sans passer par les périphériques
+    // ni par le journal des écritures (chargement d'une image mémoire)
+    pub fn load_trytes(&mut self, addr: Address, trytes: &[Tryte]) -> Result<(), MemoryError> {
+        let end = addr.checked_add(trytes.len()).ok_or(MemoryError::OutOfBounds)?;
+        if end > self.size() {
+            return Err(MemoryError::OutOfBounds);
+        }
+        // Découpage aux frontières des pages du stockage
+        let mut addr = addr;
+        let mut rest = trytes;
+        while !rest.is_empty() {
+            let len = rest.len().min(STORE_PAGE_SIZE - addr % STORE_PAGE_SIZE);
+            self.store(addr, &rest[..len]);
+            addr += len;
+            rest = &rest[len..];
+        }
+        Ok(())
+    }
+
+    // Ouvre (ou vide) le journal des écritures: chaque écriture réussie y est ajoutée
+    pub fn start_write_log(&mut self) {
+        self.write_log = Some(Vec::new());
+    }
+
+    // Ferme le journal et retourne les écritures enregistrées depuis son ouverture
+    pub fn take_write_log(&mut self) -> Vec<MemoryWrite> {
+        self.write_log.take().unwrap_or_default()
+    }
+
+    fn log_write(&mut self, write: MemoryWrite) {
+        if let Some(log) = self.write_log.as_mut() {
+            log.push(write);
+        }
+    }
+
+    // Indique si `addr` appartient à la plage d'un périphérique (accès non mis en cache)
+    pub fn is_device(&self, addr: Address) -> bool {
+        self.device_at(addr).is_some()
     }
 
     // Lit un Tryte à une adresse donnée
     pub fn read_tryte(&self, addr: Address) -> Result<Tryte, MemoryError> {
+        if self.device_at(addr).is_some() {
+            return Err(MemoryError::DeviceAccess);
+        }
         if !is_valid_address(addr) || addr >= self.size() {
             // Vérifie les limites
             Err(MemoryError::OutOfBounds)
         } else {
-            // Accès direct via indexation, Rust garantit que l'index est valide ici
-            // .clone() est nécessaire car Vec::get retourne une référence,
-            // mais Tryte est Copy donc le clonage est très peu coûteux.
-            Ok(self.trytes[addr].clone())
+            Ok(self.load(addr))
         }
     }
 
     // Écrit un Tryte à une adresse donnée
     pub fn write_tryte(&mut self, addr: Address, data: Tryte) -> Result<(), MemoryError> {
+        if self.device_at(addr).is_some() {
+            return Err(MemoryError::DeviceAccess);
+        }
         if !is_valid_address(addr) || addr >= self.size() {
             // Vérifie les limites
             Err(MemoryError::OutOfBounds)
         } else {
-            self.trytes[addr] = data; // Écrit la donnée
+            self.store(addr, &[data]); // Écrit la donnée
+            self.log_write(MemoryWrite::Tryte(addr, data));
             Ok(())
         }
     }
 
     // Vérifie si une adresse est alignée pour un accès Mot (multiple de 8)
     fn is_word_aligned(addr: Address) -> bool {
-        addr % 8 == 0
+        addr.is_multiple_of(8)
     }
 
     // Lit un Mot (Word = 8 Trytes) à une adresse donnée (doit être alignée)
@@ -68,22 +405,29 @@ impl Memory {
         if !Self::is_word_aligned(addr) {
             return Err(MemoryError::Misaligned);
         }
+        // Registre de périphérique (plage alignée: le mot y est entièrement contenu)
+        if let Some((mapped, offset)) = self.device_at(addr) {
+            return Ok(mapped.device.borrow_mut().read(offset));
+        }
         // Vérifie si l'adresse + la taille du mot sont dans les limites
         if !is_valid_address(addr)
             || addr
                 .checked_add(7)
-                .map_or(true, |end_addr| end_addr >= self.size())
+                .is_none_or(|end_addr| end_addr >= self.size())
         {
             return Err(MemoryError::OutOfBounds);
         }
 
-        // Crée un buffer temporaire pour le mot
-        let mut word_trytes = [Tryte::Undefined; 8];
+        // Un Mot aligné est entièrement contenu dans une page du stockage
+        let Some(page) = &self.pages[addr / STORE_PAGE_SIZE] else {
+            return Ok(Word([Tryte::Undefined; 8]));
+        };
+        let offset = addr % STORE_PAGE_SIZE;
+
         // Lecture Little-Endian : Tryte 0 à addr, Tryte 1 à addr+1, ...
-        for i in 0..8 {
-            // read_tryte gère déjà les limites individuellement, mais la vérif globale est plus propre.
-            // On peut utiliser un accès direct ici car on a déjà vérifié les bornes globales.
-            word_trytes[i] = self.trytes[addr + i].clone();
+        let mut word_trytes = [Tryte::Undefined; 8];
+        for (tryte, &byte) in word_trytes.iter_mut().zip(&page[offset..offset + 8]) {
+            *tryte = UNPACKED[byte as usize];
         }
 
         Ok(Word(word_trytes)) // Retourne le mot construit
@@ -94,21 +438,24 @@ impl Memory {
         if !Self::is_word_aligned(addr) {
             return Err(MemoryError::Misaligned);
         }
+        if let Some((mapped, offset)) = self.device_at(addr) {
+            mapped.device.borrow_mut().write(offset, word_data);
+            self.log_write(MemoryWrite::Word(addr, word_data));
+            return Ok(());
+        }
         // Vérifie si l'adresse + la taille du mot sont dans les limites
         if !is_valid_address(addr)
             || addr
                 .checked_add(7)
-                .map_or(true, |end_addr| end_addr >= self.size())
+                .is_none_or(|end_addr| end_addr >= self.size())
         {
             return Err(MemoryError::OutOfBounds);
         }
 
         // Écriture Little-Endian : Tryte 0 à addr, Tryte 1 à addr+1, ...
-        let source_trytes = word_data.trytes(); // Récupère les trytes du mot à écrire
-        for i in 0..8 {
-            // Accès direct car les bornes globales sont vérifiées.
-            self.trytes[addr + i] = source_trytes[i].clone();
-        }
+        // Un Mot aligné est entièrement contenu dans une page du stockage
+        self.store(addr, word_data.trytes());
+        self.log_write(MemoryWrite::Word(addr, word_data));
 
         Ok(())
     }
@@ -201,7 +548,7 @@ mod tests {
 
         // Adresse de début valide mais fin hors limites
         let addr_near_end: Address = 96; // 96 est aligné. 96+7 = 103. Taille = 100.
-        assert!(addr_near_end % 8 == 0);
+        assert!(addr_near_end.is_multiple_of(8));
         assert_eq!(mem.read_word(addr_near_end), Err(MemoryError::OutOfBounds));
         assert_eq!(
             mem.write_word(addr_near_end, word_val),
@@ -210,11 +557,92 @@ mod tests {
 
         // Adresse de début hors limites
         let addr_out: Address = 104; // 104 est aligné
-        assert!(addr_out % 8 == 0);
+        assert!(addr_out.is_multiple_of(8));
         assert_eq!(mem.read_word(addr_out), Err(MemoryError::OutOfBounds));
         assert_eq!(
             mem.write_word(addr_out, word_val),
             Err(MemoryError::OutOfBounds)
         );
     }
+
+    #[test]
+    fn test_sparse_pages() {
+        let mut mem = Memory::new();
+        assert_eq!(mem.allocated_pages(), 0);
+
+        // Lire ou écrire Undefined n'alloue aucune page
+        assert_eq!(mem.read_word(MAX_ADDRESS - 8), Ok(Word([Tryte::Undefined; 8])));
+        assert_eq!(mem.write_tryte(5, Tryte::Undefined), Ok(()));
+        assert_eq!(mem.allocated_pages(), 0);
+
+        // Une page par zone écrite; tous les états d'un tryte sont conservés
+        let last = Word([Tryte::Digit(0), Tryte::Digit(23), Tryte::Null, Tryte::NaN, Tryte::Undefined,
+            Tryte::Digit(13), Tryte::Digit(1), Tryte::Digit(22)]);
+        assert_eq!(mem.write_word(MAX_ADDRESS - 8, last), Ok(()));
+        assert_eq!(mem.write_tryte(STORE_PAGE_SIZE, Tryte::NaN), Ok(()));
+        assert_eq!(mem.allocated_pages(), 2);
+        assert_eq!(mem.read_word(MAX_ADDRESS - 8), Ok(last));
+        assert_eq!(mem.read_tryte(STORE_PAGE_SIZE), Ok(Tryte::NaN));
+        assert_eq!(mem.read_tryte(STORE_PAGE_SIZE + 1), Ok(Tryte::Undefined));
+        assert_eq!(mem.defined_ranges().len(), 3);
+
+        mem.clear();
+        assert_eq!(mem.allocated_pages(), 0);
+        assert_eq!(mem.read_tryte(STORE_PAGE_SIZE), Ok(Tryte::Undefined));
+        assert!((0..=255).filter_map(unpack_tryte).all(|tryte| unpack_tryte(pack_tryte(tryte)) == Some(tryte)));
+    }
+
+    // Périphérique de test: deux registres, le second compte ses lectures
+    struct TestDevice {
+        value: Word,
+        reads: i32,
+    }
+
+    impl Device for TestDevice {
+        fn name(&self) -> &str {
+            "test"
+        }
+        fn size(&self) -> usize {
+            16
+        }
+        fn read(&mut self, offset: Address) -> Word {
+            if offset == 0 {
+                self.value
+            } else {
+                self.reads += 1;
+                Word::from(self.reads)
+            }
+        }
+        fn write(&mut self, offset: Address, value: Word) {
+            if offset == 0 {
+                self.value = value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_device_mapping() {
+        let mut mem = Memory::with_size(100);
+        let device = || Box::new(TestDevice { value: Word::zero(), reads: 0 });
+
+        // La plage peut être au-delà de la RAM mais doit être alignée et libre
+        assert_eq!(mem.map_device(200, device()), Ok(()));
+        assert_eq!(mem.map_device(212, device()), Err(MemoryError::Misaligned));
+        assert_eq!(mem.map_device(208, device()), Err(MemoryError::DeviceConflict));
+        assert_eq!(mem.map_device(MAX_ADDRESS - 8, device()), Err(MemoryError::OutOfBounds));
+        assert_eq!(mem.devices(), vec![(200, 16, "test".to_string())]);
+
+        // Les accès Mot sont transmis au périphérique
+        assert_eq!(mem.write_word(200, Word::from(42)), Ok(()));
+        assert_eq!(mem.read_word(200), Ok(Word::from(42)));
+        assert_eq!(mem.read_word(208), Ok(Word::from(1)));
+        assert_eq!(mem.read_word(208), Ok(Word::from(2)));
+
+        // Les accès Tryte sont refusés, la RAM reste accessible hors de la plage
+        assert_eq!(mem.read_tryte(203), Err(MemoryError::DeviceAccess));
+        assert_eq!(mem.write_tryte(200, Tryte::Null), Err(MemoryError::DeviceAccess));
+        assert_eq!(mem.read_word(216), Err(MemoryError::OutOfBounds));
+        assert_eq!(mem.write_word(0, Word::from(7)), Ok(()));
+        assert_eq!(mem.read_word(0), Ok(Word::from(7)));
+    }
 }
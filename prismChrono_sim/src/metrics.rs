@@ -0,0 +1,281 @@
+// src/metrics.rs
+// Métriques d'exécution au format JSON, pour les scripts de benchmark
+//
+// `metrics_json` produit un objet dont les clés de premier niveau sont celles de
+// benchmarks/scripts/config.json (instruction_count, code_size, memory_reads,
+// memory_writes, branches, branches_taken), complétées par les compteurs des modèles
+// optionnels. Un modèle désactivé (cache, prédicteur, pipeline, comptage par mnémonique)
+// a la valeur null: la présence des clés ne dépend pas de la ligne de commande.
+//
+// {
+//   "schema_version": 1,
+//   "stop_reason": "halted" | "exited" | "breakpoint" | "step_limit" | "error",
+//   "instruction_count": N, "code_size": N, "cycles": N,
+//   "memory_reads": N, "memory_writes": N, "branches": N, "branches_taken": N,
+//   "opcodes": { "<MNÉMONIQUE>": N, ... } | null,
+//   "icache": <cache> | null, "dcache": <cache> | null,
+//   "predictor": { "name", "executed", "correct", "taken", "accuracy" } | null,
+//   "tlb": { "hits", "misses", "page_walks" },
+//   "tvpu": { "operations", "cycles" },
+//   "pipeline": { "cycles", "cpi", "bubbles", "load_use_stalls", ... } | null
+// }
+// avec <cache> = { "reads", "writes", "hits", "misses", "hit_rate", "prefetches",
+// "prefetch_hits", "writebacks", "stall_cycles" }
+
+use crate::cache::CacheStats;
+use crate::cpu::execute::{Cpu, StopReason};
+use crate::cpu::isa::Instruction;
+use crate::disasm::mnemonic;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+/// Version du schéma JSON (à incrémenter si une clé change de sens ou disparaît)
+pub const METRICS_SCHEMA_VERSION: u64 = 1;
+
+/// Nombre d'instructions exécutées par mnémonique (les instructions décodées que compte
+/// `Cpu::instructions_executed`, y compris HALT, EBREAK et celles qui lèvent un trap)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpcodeCounts {
+    counts: BTreeMap<String, u64>,
+}
+
+impl OpcodeCounts {
+    /// Compteurs vides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compte une instruction exécutée
+    pub fn record(&mut self, instruction: &Instruction) {
+        *self.counts.entry(mnemonic(instruction)).or_insert(0) += 1;
+    }
+
+    /// Nombre d'exécutions d'un mnémonique
+    pub fn get(&self, mnemonic: &str) -> u64 {
+        self.counts.get(mnemonic).copied().unwrap_or(0)
+    }
+
+    /// Mnémoniques exécutés et leur nombre d'exécutions, par ordre alphabétique
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.counts.iter().map(|(name, count)| (name.as_str(), *count))
+    }
+}
+
+/// Valeur JSON (sous-ensemble utile aux métriques)
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Number(u64),
+    Float(f64),
+    Str(String),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Objet construit à partir de paires (clé, valeur)
+    fn object<'a>(fields: impl IntoIterator<Item = (&'a str, Json)>) -> Json {
+        Json::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+
+    /// Écrit la valeur, indentée de deux espaces par niveau d'imbrication
+    fn write(&self, out: &mut String, depth: usize) -> fmt::Result {
+        match self {
+            Json::Null => out.write_str("null"),
+            Json::Number(value) => write!(out, "{}", value),
+            // JSON n'a ni NaN ni infini
+            Json::Float(value) if !value.is_finite() => out.write_str("null"),
+            Json::Float(value) => write!(out, "{:.4}", value),
+            Json::Str(text) => write_string(out, text),
+            Json::Object(fields) if fields.is_empty() => out.write_str("{}"),
+            Json::Object(fields) => {
+                out.write_str("{\n")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    write!(out, "{:width$}", "", width = 2 * (depth + 1))?;
+                    write_string(out, key)?;
+                    out.write_str(": ")?;
+                    value.write(out, depth + 1)?;
+                    out.write_str(if index + 1 < fields.len() { ",\n" } else { "\n" })?;
+                }
+                write!(out, "{:width$}}}", "", width = 2 * depth)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.write(&mut out, 0)?;
+        f.write_str(&out)
+    }
+}
+
+/// Écrit une chaîne JSON entre guillemets, caractères spéciaux échappés
+fn write_string(out: &mut String, text: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+/// Statistiques d'un cache, null s'il est désactivé
+fn cache_json(stats: Option<CacheStats>) -> Json {
+    let Some(stats) = stats else {
+        return Json::Null;
+    };
+    Json::object([
+        ("reads", Json::Number(stats.reads)),
+        ("writes", Json::Number(stats.writes)),
+        ("hits", Json::Number(stats.hits)),
+        ("misses", Json::Number(stats.misses)),
+        ("hit_rate", Json::Float(stats.hit_rate())),
+        ("prefetches", Json::Number(stats.prefetches)),
+        ("prefetch_hits", Json::Number(stats.prefetch_hits)),
+        ("writebacks", Json::Number(stats.writebacks)),
+        ("stall_cycles", Json::Number(stats.stall_cycles)),
+    ])
+}
+
+/// Métriques d'exécution du CPU au format JSON (voir le schéma en tête de fichier)
+///
+/// `code_size` est la taille du programme chargé, en trytes; `stop` la raison de l'arrêt
+/// (None si l'exécution s'est terminée sur une erreur).
+pub fn metrics_json(cpu: &Cpu, code_size: usize, stop: Option<StopReason>) -> String {
+    let stop_reason = match stop {
+        Some(StopReason::Halted) => "halted",
+        Some(StopReason::Exited(_)) => "exited",
+        Some(StopReason::Breakpoint) => "breakpoint",
+        Some(StopReason::StepLimit) => "step_limit",
+        None => "error",
+    };
+    let opcodes = match &cpu.opcode_counts {
+        Some(counts) => Json::object(counts.iter().map(|(name, count)| (name, Json::Number(count)))),
+        None => Json::Null,
+    };
+    let predictor = match &cpu.branch_profiler {
+        Some(profiler) => {
+            let totals = profiler.totals();
+            Json::object([
+                ("name", Json::Str(profiler.name().to_string())),
+                ("executed", Json::Number(totals.executed)),
+                ("correct", Json::Number(totals.correct)),
+                ("taken", Json::Number(totals.taken)),
+                ("accuracy", Json::Float(totals.accuracy())),
+            ])
+        }
+        None => Json::Null,
+    };
+    let (tlb_hits, tlb_misses, page_walks) = cpu.mmu.stats();
+    let (icache, dcache) = cpu.caches.stats();
+    let pipeline = match &cpu.pipeline {
+        Some(pipeline) => {
+            let stats = pipeline.stats();
+            Json::object([
+                ("cycles", Json::Number(pipeline.cycles())),
+                ("cpi", Json::Float(pipeline.cpi())),
+                ("bubbles", Json::Number(stats.bubbles())),
+                ("load_use_stalls", Json::Number(stats.load_use_stalls)),
+                ("multicycle_stalls", Json::Number(stats.multicycle_stalls)),
+                ("memory_stalls", Json::Number(stats.memory_stalls)),
+                ("flush_bubbles", Json::Number(stats.flush_bubbles)),
+                ("branches", Json::Number(stats.branches)),
+                ("mispredictions", Json::Number(stats.mispredictions)),
+                ("jumps", Json::Number(stats.jumps)),
+                ("traps", Json::Number(stats.traps)),
+                ("forwards_ex_mem", Json::Number(stats.forwards_ex_mem)),
+                ("forwards_mem_wb", Json::Number(stats.forwards_mem_wb)),
+            ])
+        }
+        None => Json::Null,
+    };
+
+    Json::object([
+        ("schema_version", Json::Number(METRICS_SCHEMA_VERSION)),
+        ("stop_reason", Json::Str(stop_reason.to_string())),
+        ("instruction_count", Json::Number(cpu.instructions_executed)),
+        ("code_size", Json::Number(code_size as u64)),
+        ("cycles", Json::Number(cpu.cycles)),
+        ("memory_reads", Json::Number(cpu.memory_reads)),
+        ("memory_writes", Json::Number(cpu.memory_writes)),
+        ("branches", Json::Number(cpu.branches_total)),
+        ("branches_taken", Json::Number(cpu.branches_taken)),
+        ("opcodes", opcodes),
+        ("icache", cache_json(icache)),
+        ("dcache", cache_json(dcache)),
+        ("predictor", predictor),
+        (
+            "tlb",
+            Json::object([
+                ("hits", Json::Number(tlb_hits)),
+                ("misses", Json::Number(tlb_misses)),
+                ("page_walks", Json::Number(page_walks)),
+            ]),
+        ),
+        (
+            "tvpu",
+            Json::object([
+                ("operations", Json::Number(cpu.tvpu.operation_count)),
+                ("cycles", Json::Number(cpu.tvpu.cycle_count)),
+            ]),
+        ),
+        ("pipeline", pipeline),
+    ])
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Word;
+    use crate::cpu::isa::AluOp;
+    use crate::cpu::registers::Register;
+    use crate::loader::instruction_to_trytes;
+
+    #[test]
+    fn test_json_format() {
+        let value = Json::object([
+            ("a", Json::Number(1)),
+            ("b", Json::Str("x\"y\n".to_string())),
+            ("c", Json::object([("d", Json::Float(0.5)), ("e", Json::Null)])),
+            ("f", Json::object([])),
+            ("g", Json::Float(f64::NAN)),
+        ]);
+        assert_eq!(
+            value.to_string(),
+            "{\n  \"a\": 1,\n  \"b\": \"x\\\"y\\n\",\n  \"c\": {\n    \"d\": 0.5000,\n    \"e\": null\n  },\n  \"f\": {},\n  \"g\": null\n}"
+        );
+    }
+
+    #[test]
+    fn test_metrics_json_counts_opcodes() {
+        // ADDI R1, R1, 1 deux fois, STOREW R1 à l'adresse 0 puis HALT, à l'adresse 16
+        let addi = Instruction::AluImm { op: AluOp::Add, rs1: Register::R1, rd: Register::R1, imm: 1 };
+        let store = Instruction::Store { rs1: Register::R4, rs2: Register::R1, offset: 0 };
+        let mut cpu = Cpu::with_memory_size(1024);
+        for (i, instruction) in [addi.clone(), addi, store, Instruction::Halt].iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            cpu.memory.load_trytes(16 + 4 * i, &instruction_to_trytes(&trits)).unwrap();
+        }
+        cpu.state.write_pc(Word::from(16));
+        cpu.state.write_gpr(Register::R1, Word::from(2));
+        cpu.state.write_gpr(Register::R4, Word::from(0));
+        cpu.opcode_counts = Some(OpcodeCounts::new());
+        let stop = cpu.run(None).unwrap();
+        assert_eq!(cpu.opcode_counts.as_ref().unwrap().get("ADDI"), 2);
+
+        let json = metrics_json(&cpu, 16, Some(stop));
+        assert!(json.starts_with("{\n  \"schema_version\": 1,\n  \"stop_reason\": \"halted\",\n"));
+        assert!(json.contains("\"instruction_count\": 4,\n  \"code_size\": 16,"));
+        assert!(json.contains("\"memory_reads\": 0,\n  \"memory_writes\": 1,"));
+        assert!(json.contains("\"opcodes\": {\n    \"ADDI\": 2,\n    \"HALT\": 1,\n    \"STOREW\": 1\n  },"));
+        assert!(json.contains("\"icache\": null,"));
+        assert!(json.ends_with("\"pipeline\": null\n}"));
+    }
+}
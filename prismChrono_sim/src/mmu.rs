@@ -0,0 +1,477 @@
+// src/mmu.rs
+// Unité de gestion mémoire paginée (cf. rtl/mmu/mmu_t.vhd et rtl/mmu/tlb.vhd)
+//
+// Mode Sv18_T: adresses virtuelles de 18 trits (3^18 trytes, couvre tout l'espace physique)
+//   - page de 3^6 = 729 trytes (27^2, 6 trits d'offset comme dans le VHDL)
+//   - 3 niveaux de tables de 81 entrées (4 trits d'indice par niveau), une table tient dans une page
+//   - VPN = adresse / 729, indice au niveau n = (VPN / 81^n) mod 81
+//
+// satp_t (CSR 12): tryte 0 = mode (0 = Bare, 1 = Sv18_T), trytes 1 à 7 = PPN de la table racine
+// (valeur = PPN * 24 + mode). En mode Bare, ou en mode Machine, les adresses sont physiques.
+//
+// Entrée de table (PTE_T, un Mot): trits 0 à 5 (trytes 0 et 1) = flags (P = levé), trytes 2 à 7
+// = PPN (valeur = PPN * 24^2 + valeur des trytes de flags)
+//   trit 0 V (valide), 1 R (lecture), 2 W (écriture), 3 X (exécution), 4 U (accessible en mode User),
+//   5 G (global, réservé)
+// Une entrée valide sans R, W ni X pointe vers la table du niveau inférieur. Une feuille aux niveaux
+// 1 ou 2 projette une super-page (81 ou 6561 pages), dont le PPN doit être aligné.
+//
+// Les pages U ne sont accessibles qu'en mode User, les autres qu'en mode Supervisor.
+// Le TLB (entièrement associatif, remplacement FIFO) est vidé à chaque écriture de satp_t.
+
+use std::cell::RefCell;
+
+use crate::core::{Address, Trit, Tryte, Word, is_valid_address};
+use crate::cpu::registers::PrivilegeLevel;
+use crate::memory::{Memory, MemoryError};
+
+// Taille d'une page (trytes)
+pub const PAGE_SIZE: Address = 729;
+
+// Nombre d'entrées par table et nombre de niveaux
+pub const PTE_PER_TABLE: usize = 81;
+pub const LEVELS: usize = 3;
+
+// Taille d'une entrée de table (un Mot)
+pub const PTE_SIZE: Address = 8;
+
+// Nombre d'entrées du TLB par défaut (comme tlb.vhd)
+pub const DEFAULT_TLB_ENTRIES: usize = 8;
+
+// Position des flags dans une entrée de table
+pub const PTE_V: usize = 0;
+pub const PTE_R: usize = 1;
+pub const PTE_W: usize = 2;
+pub const PTE_X: usize = 3;
+pub const PTE_U: usize = 4;
+pub const PTE_G: usize = 5;
+
+// Poids du PPN dans une entrée: les deux trytes de poids faible portent les flags
+const PTE_PPN_WEIGHT: i64 = 24 * 24;
+
+// Modes de pagination (tryte 0 de satp_t)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagingMode {
+    Bare,  // Pas de traduction
+    Sv18T, // Pagination à 3 niveaux
+}
+
+// Type d'accès traduit (détermine la permission requise et la cause de la faute)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessType {
+    Fetch, // Lecture d'instruction (X)
+    Load,  // Lecture de donnée (R)
+    Store, // Écriture de donnée (W)
+}
+
+// Erreurs de traduction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MmuError {
+    PageFault(AccessType),  // Page absente ou permission refusée
+    Memory(AccessType),     // Entrée de table hors de la mémoire physique
+}
+
+// Contenu décodé de satp_t
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Satp {
+    pub mode: PagingMode,
+    pub root_ppn: i64, // Page de la table racine
+}
+
+impl Satp {
+    // Décode satp_t; un mode inconnu est traité comme Bare
+    pub fn from_word(word: &Word) -> Self {
+        let mode_value = word.tryte(0).map_or(0, |tryte| tryte.bal3_value() as i64);
+        let mode = match mode_value {
+            1 => PagingMode::Sv18T,
+            _ => PagingMode::Bare,
+        };
+        Satp {
+            mode,
+            root_ppn: (word.to_i64() - mode_value) / 24,
+        }
+    }
+
+    // Encode satp_t
+    pub fn to_word(&self) -> Word {
+        let mode = match self.mode {
+            PagingMode::Bare => 0,
+            PagingMode::Sv18T => 1,
+        };
+        Word::from_i64(self.root_ppn * 24 + mode)
+    }
+}
+
+// Permissions d'une page traduite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PagePermissions {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+    pub user: bool,
+}
+
+impl PagePermissions {
+    // Vérifie qu'un accès est autorisé au niveau de privilège donné
+    fn allows(&self, access: AccessType, privilege: PrivilegeLevel) -> bool {
+        let allowed = match access {
+            AccessType::Fetch => self.execute,
+            AccessType::Load => self.read,
+            AccessType::Store => self.write,
+        };
+        allowed && self.user == (privilege == PrivilegeLevel::User)
+    }
+}
+
+// Entrée de table de pages (PTE_T)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageTableEntry {
+    pub ppn: i64,
+    pub valid: bool,
+    pub permissions: PagePermissions,
+}
+
+impl PageTableEntry {
+    // Décode une entrée lue en mémoire
+    pub fn from_word(word: &Word) -> Self {
+        let flag = |index: usize| word.get_trit(index) == Trit::P;
+        let flags_value = word.0[0].bal3_value() as i64 + 24 * word.0[1].bal3_value() as i64;
+        PageTableEntry {
+            ppn: (word.to_i64() - flags_value) / PTE_PPN_WEIGHT,
+            valid: flag(PTE_V),
+            permissions: PagePermissions {
+                read: flag(PTE_R),
+                write: flag(PTE_W),
+                execute: flag(PTE_X),
+                user: flag(PTE_U),
+            },
+        }
+    }
+
+    // Encode une entrée (pour construire des tables de pages)
+    pub fn to_word(&self) -> Word {
+        let mut word = Word::from_i64(self.ppn * PTE_PPN_WEIGHT);
+        let flags = [
+            (PTE_V, self.valid),
+            (PTE_R, self.permissions.read),
+            (PTE_W, self.permissions.write),
+            (PTE_X, self.permissions.execute),
+            (PTE_U, self.permissions.user),
+        ];
+        for (index, set) in flags {
+            if set {
+                word.set_trit(index, Trit::P);
+            }
+        }
+        word
+    }
+
+    // Une feuille donne des droits; sinon l'entrée pointe vers la table suivante
+    fn is_leaf(&self) -> bool {
+        self.permissions.read || self.permissions.write || self.permissions.execute
+    }
+}
+
+// Entrée du TLB: traduction d'une page
+#[derive(Debug, Clone, Copy)]
+struct TlbEntry {
+    vpn: usize,
+    ppn: usize,
+    permissions: PagePermissions,
+}
+
+// TLB entièrement associatif, remplacement FIFO
+#[derive(Debug, Clone)]
+struct Tlb {
+    entries: Vec<Option<TlbEntry>>,
+    replace: usize, // Prochaine entrée remplacée
+    hits: u64,
+    misses: u64,
+}
+
+impl Tlb {
+    fn new(capacity: usize) -> Self {
+        Tlb {
+            entries: vec![None; capacity],
+            replace: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn lookup(&mut self, vpn: usize) -> Option<TlbEntry> {
+        let found = self.entries.iter().flatten().find(|entry| entry.vpn == vpn).copied();
+        if found.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        found
+    }
+
+    fn insert(&mut self, entry: TlbEntry) {
+        if self.entries.is_empty() {
+            return;
+        }
+        self.entries[self.replace] = Some(entry);
+        self.replace = (self.replace + 1) % self.entries.len();
+    }
+
+    fn flush(&mut self) {
+        self.entries.iter_mut().for_each(|entry| *entry = None);
+        self.replace = 0;
+    }
+}
+
+// Unité de gestion mémoire: parcours des tables de pages et TLB
+// Le TLB est mis à jour lors des lectures, qui ne prennent qu'une référence partagée
+#[derive(Debug, Clone)]
+pub struct Mmu {
+    tlb: RefCell<Tlb>,
+    walks: RefCell<u64>, // Nombre de parcours de tables
+}
+
+impl Mmu {
+    // MMU avec un TLB de taille par défaut
+    pub fn new() -> Self {
+        Self::with_tlb_entries(DEFAULT_TLB_ENTRIES)
+    }
+
+    // MMU avec un TLB de `entries` entrées (0: chaque accès parcourt les tables)
+    pub fn with_tlb_entries(entries: usize) -> Self {
+        Mmu {
+            tlb: RefCell::new(Tlb::new(entries)),
+            walks: RefCell::new(0),
+        }
+    }
+
+    // Invalide tout le TLB (écriture de satp_t)
+    pub fn flush(&mut self) {
+        self.tlb.get_mut().flush();
+    }
+
+    // Statistiques: (succès TLB, échecs TLB, parcours de tables)
+    pub fn stats(&self) -> (u64, u64, u64) {
+        let tlb = self.tlb.borrow();
+        (tlb.hits, tlb.misses, *self.walks.borrow())
+    }
+
+    // Indique si les accès du niveau `privilege` sont traduits
+    pub fn is_active(satp: &Word, privilege: PrivilegeLevel) -> bool {
+        privilege != PrivilegeLevel::Machine && Satp::from_word(satp).mode != PagingMode::Bare
+    }
+
+    // Traduit une adresse virtuelle en adresse physique
+    pub fn translate(
+        &self,
+        memory: &Memory,
+        satp: &Word,
+        privilege: PrivilegeLevel,
+        addr: Address,
+        access: AccessType,
+    ) -> Result<Address, MmuError> {
+        if !Self::is_active(satp, privilege) {
+            return Ok(addr);
+        }
+        if !is_valid_address(addr) {
+            return Err(MmuError::PageFault(access));
+        }
+
+        let vpn = addr / PAGE_SIZE;
+        let offset = addr % PAGE_SIZE;
+        let cached = self.tlb.borrow_mut().lookup(vpn);
+        let entry = match cached {
+            Some(entry) => entry,
+            None => {
+                let entry = self.walk(memory, Satp::from_word(satp).root_ppn, vpn, access)?;
+                self.tlb.borrow_mut().insert(entry);
+                entry
+            }
+        };
+
+        if !entry.permissions.allows(access, privilege) {
+            return Err(MmuError::PageFault(access));
+        }
+        Ok(entry.ppn * PAGE_SIZE + offset)
+    }
+
+    // Traduit les `len` trytes à partir de `addr`: adresse physique de chacun
+    // La plage peut chevaucher deux pages (une page n'est pas un multiple de la taille d'un Mot)
+    pub fn translate_span(
+        &self,
+        memory: &Memory,
+        satp: &Word,
+        privilege: PrivilegeLevel,
+        addr: Address,
+        len: usize,
+        access: AccessType,
+    ) -> Result<Vec<Address>, MmuError> {
+        let last = addr + len.saturating_sub(1);
+        let first_pa = self.translate(memory, satp, privilege, addr, access)?;
+        let last_pa = self.translate(memory, satp, privilege, last, access)?;
+        let boundary = (addr / PAGE_SIZE + 1) * PAGE_SIZE;
+
+        Ok((addr..addr + len)
+            .map(|va| if va < boundary { first_pa + (va - addr) } else { last_pa - (last - va) })
+            .collect())
+    }
+
+    // Parcours des tables de pages depuis la racine
+    fn walk(&self, memory: &Memory, root_ppn: i64, vpn: usize, access: AccessType) -> Result<TlbEntry, MmuError> {
+        *self.walks.borrow_mut() += 1;
+        let fault = MmuError::PageFault(access);
+
+        let mut table = root_ppn;
+        for level in (0..LEVELS).rev() {
+            let span = PTE_PER_TABLE.pow(level as u32); // Pages couvertes par une entrée
+            let index = (vpn / span) % PTE_PER_TABLE;
+            if table < 0 {
+                return Err(fault);
+            }
+            let pte_addr = table as Address * PAGE_SIZE + index * PTE_SIZE;
+            let pte = PageTableEntry::from_word(&read_pte(memory, pte_addr).map_err(|_| MmuError::Memory(access))?);
+
+            if !pte.valid {
+                return Err(fault);
+            }
+            if pte.is_leaf() {
+                // Super-page: le PPN doit être aligné sur la taille couverte
+                if pte.ppn < 0 || !(pte.ppn as usize).is_multiple_of(span) {
+                    return Err(fault);
+                }
+                return Ok(TlbEntry {
+                    vpn,
+                    ppn: pte.ppn as usize + vpn % span,
+                    permissions: pte.permissions,
+                });
+            }
+            table = pte.ppn;
+        }
+
+        // Entrée de niveau 0 sans droits
+        Err(fault)
+    }
+}
+
+impl Default for Mmu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Lit une entrée de table tryte par tryte (une table commence en début de page,
+// donc pas forcément à une adresse alignée sur 8)
+fn read_pte(memory: &Memory, addr: Address) -> Result<Word, MemoryError> {
+    let mut trytes = [Tryte::Digit(13); 8];
+    for (i, tryte) in trytes.iter_mut().enumerate() {
+        *tryte = memory.read_tryte(addr + i)?;
+    }
+    Ok(Word(trytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Entrée de table: feuille si des droits sont donnés
+    fn pte(ppn: i64, read: bool, write: bool, execute: bool, user: bool) -> Word {
+        PageTableEntry {
+            ppn,
+            valid: true,
+            permissions: PagePermissions { read, write, execute, user },
+        }
+        .to_word()
+    }
+
+    fn write_pte(memory: &mut Memory, table: i64, index: usize, entry: Word) {
+        let addr = table as Address * PAGE_SIZE + index * PTE_SIZE;
+        for (i, tryte) in entry.trytes().iter().enumerate() {
+            memory.write_tryte(addr + i, *tryte).unwrap();
+        }
+    }
+
+    // Tables: racine en page 1, niveau 1 en page 2, niveau 0 en page 3
+    // VPN 5 -> page 20 (RW, U), VPN 6 -> page 21 (X, S), VPN 7 -> page 22 (R, U)
+    fn memory_with_tables() -> (Memory, Word) {
+        let mut memory = Memory::with_size(30 * PAGE_SIZE);
+        write_pte(&mut memory, 1, 0, pte(2, false, false, false, false));
+        write_pte(&mut memory, 2, 0, pte(3, false, false, false, false));
+        write_pte(&mut memory, 3, 5, pte(20, true, true, false, true));
+        write_pte(&mut memory, 3, 6, pte(21, false, false, true, false));
+        write_pte(&mut memory, 3, 7, pte(22, true, false, false, true));
+        let satp = Satp { mode: PagingMode::Sv18T, root_ppn: 1 }.to_word();
+        (memory, satp)
+    }
+
+    #[test]
+    fn test_pte_and_satp_encoding() {
+        let entry = PageTableEntry::from_word(&pte(1234, true, false, true, true));
+        assert_eq!(entry.ppn, 1234);
+        assert!(entry.valid && entry.permissions.read && entry.permissions.execute && entry.permissions.user);
+        assert!(!entry.permissions.write);
+
+        let satp = Satp { mode: PagingMode::Sv18T, root_ppn: 42 };
+        assert_eq!(Satp::from_word(&satp.to_word()), satp);
+        assert_eq!(Satp::from_word(&Word::zero()).mode, PagingMode::Bare);
+    }
+
+    #[test]
+    fn test_translate_and_permissions() {
+        let (memory, satp) = memory_with_tables();
+        let mmu = Mmu::new();
+        let user = PrivilegeLevel::User;
+        let va = 5 * PAGE_SIZE + 17;
+
+        assert_eq!(mmu.translate(&memory, &satp, user, va, AccessType::Load), Ok(20 * PAGE_SIZE + 17));
+        assert_eq!(mmu.translate(&memory, &satp, user, va, AccessType::Store), Ok(20 * PAGE_SIZE + 17));
+        assert_eq!(
+            mmu.translate(&memory, &satp, user, va, AccessType::Fetch),
+            Err(MmuError::PageFault(AccessType::Fetch))
+        );
+        // Page en lecture seule, page non projetée
+        assert_eq!(
+            mmu.translate(&memory, &satp, user, 7 * PAGE_SIZE, AccessType::Store),
+            Err(MmuError::PageFault(AccessType::Store))
+        );
+        assert_eq!(
+            mmu.translate(&memory, &satp, user, 8 * PAGE_SIZE, AccessType::Load),
+            Err(MmuError::PageFault(AccessType::Load))
+        );
+
+        // Isolation U/S: page superviseur refusée en mode User, page U refusée en mode Supervisor
+        let supervisor = PrivilegeLevel::Supervisor;
+        assert!(mmu.translate(&memory, &satp, user, 6 * PAGE_SIZE, AccessType::Fetch).is_err());
+        assert_eq!(
+            mmu.translate(&memory, &satp, supervisor, 6 * PAGE_SIZE, AccessType::Fetch),
+            Ok(21 * PAGE_SIZE)
+        );
+        assert!(mmu.translate(&memory, &satp, supervisor, va, AccessType::Load).is_err());
+
+        // Le mode Machine n'est jamais traduit
+        assert_eq!(mmu.translate(&memory, &satp, PrivilegeLevel::Machine, va, AccessType::Load), Ok(va));
+    }
+
+    #[test]
+    fn test_tlb_and_page_straddling() {
+        let (mut memory, satp) = memory_with_tables();
+        let mut mmu = Mmu::with_tlb_entries(2);
+        let user = PrivilegeLevel::User;
+
+        // Un Mot à cheval sur les pages 5 et 6 virtuelles -> pages 20 et 22 physiques
+        write_pte(&mut memory, 3, 6, pte(22, true, false, false, true));
+        let span = mmu.translate_span(&memory, &satp, user, 6 * PAGE_SIZE - 3, 8, AccessType::Load).unwrap();
+        assert_eq!(span[2], 21 * PAGE_SIZE - 1);
+        assert_eq!(span[3], 22 * PAGE_SIZE);
+        assert_eq!(mmu.stats(), (0, 2, 2));
+
+        // Les traductions suivantes sont servies par le TLB, même si la table change
+        write_pte(&mut memory, 3, 5, pte(25, true, true, false, true));
+        assert_eq!(mmu.translate(&memory, &satp, user, 5 * PAGE_SIZE, AccessType::Load), Ok(20 * PAGE_SIZE));
+        assert_eq!(mmu.stats(), (1, 2, 2));
+
+        // Vider le TLB fait relire les tables
+        mmu.flush();
+        assert_eq!(mmu.translate(&memory, &satp, user, 5 * PAGE_SIZE, AccessType::Load), Ok(25 * PAGE_SIZE));
+        assert_eq!(mmu.stats(), (1, 3, 3));
+    }
+}
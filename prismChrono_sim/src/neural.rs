@@ -59,12 +59,11 @@ impl TernaryMatrix {
     
     /// Accède à un élément de la matrice
     pub fn get(&self, row: usize, col: usize) -> Option<Word> {
-        if row < self.rows && col < self.cols {
-            if let Some(row_vec) = self.row(row) {
-                if let Some(word) = row_vec.word(col) {
-                    return Some(*word);
-                }
-            }
+        if row < self.rows && col < self.cols
+            && let Some(row_vec) = self.row(row)
+            && let Some(word) = row_vec.word(col)
+        {
+            return Some(*word);
         }
         
         None
@@ -72,13 +71,12 @@ impl TernaryMatrix {
     
     /// Modifie un élément de la matrice
     pub fn set(&mut self, row: usize, col: usize, value: Word) -> bool {
-        if row < self.rows && col < self.cols {
-            if let Some(row_vec) = self.row_mut(row) {
-                if let Some(word) = row_vec.word_mut(col) {
-                    *word = value;
-                    return true;
-                }
-            }
+        if row < self.rows && col < self.cols
+            && let Some(row_vec) = self.row_mut(row)
+            && let Some(word) = row_vec.word_mut(col)
+        {
+            *word = value;
+            return true;
         }
         
         false
@@ -115,8 +113,8 @@ pub fn ternary_relu(input: Word) -> Word {
 /// Fonction d'activation ternaire Sigmoid
 /// Approximation de la fonction sigmoid pour les valeurs ternaires
 pub fn ternary_sigmoid(input: Word) -> Word {
-    // Convertir l'entrée en valeur entière en utilisant la méthode to_i32 existante
-    let input_value = input.to_i32();
+    // Convertir l'entrée en valeur entière
+    let input_value = input.to_i64();
     
     // Appliquer une approximation de sigmoid
     // Sigmoid(x) ≈ 0 pour x << 0, 0.5 pour x ≈ 0, 1 pour x >> 0
@@ -131,14 +129,14 @@ pub fn ternary_sigmoid(input: Word) -> Word {
     };
     
     // Convertir en mot ternaire
-    Word::from_int(sigmoid_value)
+    Word::from(sigmoid_value)
 }
 
 /// Fonction d'activation ternaire Tanh
 /// Approximation de la fonction tanh pour les valeurs ternaires
 pub fn ternary_tanh(input: Word) -> Word {
-    // Convertir l'entrée en valeur entière en utilisant la méthode to_i32 existante
-    let input_value = input.to_i32();
+    // Convertir l'entrée en valeur entière
+    let input_value = input.to_i64();
     
     // Appliquer une approximation de tanh
     // tanh(x) ≈ -1 pour x << 0, 0 pour x ≈ 0, 1 pour x >> 0
@@ -153,7 +151,7 @@ pub fn ternary_tanh(input: Word) -> Word {
     };
     
     // Convertir en mot ternaire
-    Word::from_int(tanh_value)
+    Word::from(tanh_value)
 }
 
 /// Calcul d'un neurone ternaire (TNEURON)
@@ -282,7 +280,7 @@ pub fn tattn(query: &TernaryMatrix, key: &TernaryMatrix, value: &TernaryMatrix)
                 let score = crate::tvpu::tvdot(query_row, key_row);
                 
                 // Normaliser par sqrt(d_k)
-                let scale_factor = Word::from_int((d_k as f32).sqrt() as i32);
+                let scale_factor = Word::from((d_k as f32).sqrt() as i32);
                 let scaled_score = crate::alu::div_words(score, scale_factor).0;
                 
                 // Stocker le score
@@ -331,16 +329,16 @@ pub fn tattn(query: &TernaryMatrix, key: &TernaryMatrix, value: &TernaryMatrix)
         }
         
         // Normaliser par la somme
-        for j in 0..seq_len {
+        for (j, &score) in exp_scores.iter().enumerate() {
             if sum != Word::zero() {
-                let weight = crate::alu::div_words(exp_scores[j], sum).0;
+                let weight = crate::alu::div_words(score, sum).0;
                 attention_weights.set(i, j, weight);
             } else {
                 // Éviter la division par zéro
                 // Utiliser une valeur uniforme pour tous les poids
                 // On utilise directement une valeur de 1 pour chaque poids
                 // Cette approche est plus stable que de calculer 1/seq_len qui pourrait donner 0
-                let uniform_weight = Word::from_int(1);
+                let uniform_weight = Word::from(1);
                 attention_weights.set(i, j, uniform_weight);
             }
         }
@@ -369,8 +367,8 @@ pub fn tattn(query: &TernaryMatrix, key: &TernaryMatrix, value: &TernaryMatrix)
 
 /// Approximation de la fonction exponentielle pour les valeurs ternaires
 fn ternary_exp(input: Word) -> Word {
-    // Convertir l'entrée en valeur entière en utilisant la méthode to_i32 existante
-    let input_value = input.to_i32();
+    // Convertir l'entrée en valeur entière
+    let input_value = input.to_i64();
     
     // Approximation simple de exp(x)
     // exp(x) ≈ 1 pour x ≈ 0, grandit rapidement pour x > 0, tend vers 0 pour x < 0
@@ -389,7 +387,7 @@ fn ternary_exp(input: Word) -> Word {
     };
     
     // Convertir en mot ternaire
-    Word::from_int(exp_value)
+    Word::from(exp_value)
 }
 
 /// Quantification ternaire d'une valeur
@@ -419,7 +417,7 @@ pub fn quantize_vector(values: &[f32]) -> TernaryVector {
     let mut result = TernaryVector::new();
     
     // Traiter les valeurs par groupes de 24 (taille d'un mot)
-    for chunk_idx in 0..(values.len() + 23) / 24 {
+    for chunk_idx in 0..values.len().div_ceil(24) {
         let mut word = Word::zero();
         
         for i in 0..24 {
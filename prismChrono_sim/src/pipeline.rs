@@ -1,528 +1,348 @@
 // src/pipeline.rs
-// Implémentation du pipeline superscalaire ternaire
-
-use crate::core::{Trit, Tryte, Word};
-use crate::cpu::Register;
-use crate::branch_predictor::TernaryBranchPredictor;
-
-/// Taille maximale de la fenêtre d'instructions
-const INSTRUCTION_WINDOW_SIZE: usize = 16;
-
-/// États possibles d'une instruction dans le pipeline
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum InstructionState {
-    /// Instruction en attente d'émission
-    Waiting,
-    /// Instruction émise mais en attente d'exécution
-    Issued,
-    /// Instruction en cours d'exécution
-    Executing,
-    /// Instruction exécutée mais en attente de complétion
-    Executed,
-    /// Instruction complétée
-    Completed,
-    /// Instruction annulée (suite à une mauvaise prédiction)
-    Cancelled,
-}
+// Modèle temporel du pipeline 5 étages (cf. rtl/core/pipelined_core.vhd)
+//
+// Le modèle observe les instructions exécutées par `Cpu::step` (mode temporel optionnel,
+// `Cpu::pipeline`) et calcule les cycles qu'elles auraient pris dans le pipeline
+// IF, ID, EX, MEM, WB du VHDL; il n'influence jamais l'exécution fonctionnelle.
+//
+// Une instruction entre en EX au cycle suivant la précédente, sauf:
+//   - aléa load-use (hazard_detection_unit.vhd): la valeur d'un chargement n'est disponible
+//     qu'à la fin de MEM, l'instruction suivante qui la lit attend une bulle
+//   - exécution multi-cycle: MUL/DIV/MOD et MADDW/MSUBW occupent EX un cycle de plus,
+//     une opération vectorielle toute sa latence TVPU
+//   - attente mémoire: les cycles d'attente des caches gèlent tout le pipeline
+//   - redirection du flux: un branchement mal prédit est résolu en EX (IF et ID vidés, 2 bulles),
+//     un JAL/CALL en ID (1 bulle), un saut indirect (JALR, MRET...) en EX (2 bulles), un trap
+//     est signalé en MEM (IF, ID et EX vidés, 3 bulles)
+// Les autres dépendances sont couvertes par le forwarding (forwarding_unit.vhd) depuis
+// EX/MEM (distance 1) ou MEM/WB (distance 2).
+//
+// Prédiction statique: un branchement est prédit pris s'il saute en arrière (boucle),
+// un BRANCH3 est prédit vers sa voie zéro.
+
+use crate::cpu::execute_branch::{branch3_target_address, relative_target_address};
+use crate::cpu::isa::{AluOp, Instruction};
+use crate::cpu::registers::Register;
+
+/// Bulles d'un branchement mal prédit (résolu en EX)
+pub const BRANCH_PENALTY: u64 = 2;
+
+/// Bulle d'un saut direct (cible calculée en ID)
+pub const JUMP_PENALTY: u64 = 1;
+
+/// Bulles d'un saut indirect ou d'un retour de trap (cible connue en EX)
+pub const INDIRECT_PENALTY: u64 = 2;
+
+/// Bulles d'un trap (faute signalée en MEM, interruption prise à la place d'une instruction)
+pub const TRAP_PENALTY: u64 = 3;
 
-/// Structure représentant une instruction dans le pipeline
-pub struct PipelineInstruction {
-    /// Adresse de l'instruction
-    pub address: u32,
-    /// Opcode de l'instruction
-    pub opcode: [Trit; 3],
-    /// Registre destination (si applicable)
-    pub rd: Option<usize>,
-    /// Premier registre source (si applicable)
-    pub rs1: Option<usize>,
-    /// Second registre source (si applicable)
-    pub rs2: Option<usize>,
-    /// Valeur immédiate (si applicable)
-    pub imm: Option<i32>,
-    /// État actuel de l'instruction dans le pipeline
-    pub state: InstructionState,
-    /// Résultat de l'exécution (si disponible)
-    pub result: Option<Word>,
-    /// Indique si l'instruction modifie le PC
-    pub modifies_pc: bool,
-    /// Nouvelle valeur du PC (si l'instruction modifie le PC)
-    pub new_pc: Option<u32>,
-    /// Indique si l'instruction accède à la mémoire
-    pub memory_access: bool,
-    /// Adresse mémoire accédée (si applicable)
-    pub memory_address: Option<u32>,
-    /// Indique si l'instruction est une écriture mémoire
-    pub memory_write: bool,
-    /// Données à écrire en mémoire (si applicable)
-    pub memory_data: Option<Word>,
+/// Cycles supplémentaires en EX pour l'unité MUL/DIV
+const MUL_DIV_EXTRA_CYCLES: u64 = 1;
+
+/// Cycle où la première instruction entre en EX (IF au cycle 1, ID au cycle 2)
+const FIRST_EX_CYCLE: u64 = 3;
+
+/// Nombre de registres généraux
+const GPR_COUNT: usize = 8;
+
+/// Compteurs du pipeline
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PipelineStats {
+    /// Instructions sorties du pipeline
+    pub instructions: u64,
+    /// Cycles de gel dus aux aléas load-use
+    pub load_use_stalls: u64,
+    /// Cycles supplémentaires des exécutions multi-cycles
+    pub multicycle_stalls: u64,
+    /// Cycles d'attente des caches
+    pub memory_stalls: u64,
+    /// Bulles injectées par les vidages (mauvaises prédictions, sauts, traps)
+    pub flush_bubbles: u64,
+    /// Branchements conditionnels (BRANCH, BRANCH3)
+    pub branches: u64,
+    /// Branchements mal prédits
+    pub mispredictions: u64,
+    /// Sauts inconditionnels et indirects
+    pub jumps: u64,
+    /// Traps et interruptions
+    pub traps: u64,
+    /// Opérandes transmis depuis EX/MEM
+    pub forwards_ex_mem: u64,
+    /// Opérandes transmis depuis MEM/WB
+    pub forwards_mem_wb: u64,
 }
 
-impl PipelineInstruction {
-    /// Crée une nouvelle instruction dans le pipeline
-    pub fn new(address: u32, opcode: [Trit; 3]) -> Self {
-        PipelineInstruction {
-            address,
-            opcode,
-            rd: None,
-            rs1: None,
-            rs2: None,
-            imm: None,
-            state: InstructionState::Waiting,
-            result: None,
-            modifies_pc: false,
-            new_pc: None,
-            memory_access: false,
-            memory_address: None,
-            memory_write: false,
-            memory_data: None,
-        }
+impl PipelineStats {
+    /// Nombre total de bulles (gels et vidages)
+    pub fn bubbles(&self) -> u64 {
+        self.load_use_stalls + self.multicycle_stalls + self.memory_stalls + self.flush_bubbles
     }
 }
 
-/// Structure représentant une entrée dans la table de renommage de registres
-pub struct RegisterRenameEntry {
-    /// Indice du registre architectural
-    pub arch_reg: usize,
-    /// Indice du registre physique
-    pub phys_reg: usize,
-    /// Indique si le registre est prêt (valeur disponible)
-    pub ready: bool,
-    /// Instruction qui produit la valeur (si pas prête)
-    pub producer: Option<usize>,
+/// Dernier producteur d'un registre
+#[derive(Debug, Clone, Copy)]
+struct Producer {
+    /// Dernier cycle de l'instruction en EX
+    ex_end: u64,
+    /// La valeur vient de la mémoire (disponible à la fin de MEM)
+    load: bool,
 }
 
-/// Pipeline superscalaire ternaire
-pub struct SuperscalarPipeline {
-    /// Fenêtre d'instructions
-    instruction_window: Vec<PipelineInstruction>,
-    /// Capacité maximale d'émission par cycle
-    issue_width: usize,
-    /// Capacité maximale d'exécution par cycle
-    execute_width: usize,
-    /// Capacité maximale de complétion par cycle
-    complete_width: usize,
-    /// Table de renommage de registres
-    rename_table: Vec<RegisterRenameEntry>,
-    /// Registres physiques
-    physical_registers: Vec<Word>,
-    /// File de réordonnancement
-    reorder_buffer: Vec<usize>,
-    /// Prédicteur de branchement
-    branch_predictor: TernaryBranchPredictor,
-    /// Compteur de programme
-    pc: u32,
-    /// Indique si le pipeline est bloqué
-    stalled: bool,
+/// Modèle du pipeline 5 étages
+#[derive(Debug, Clone)]
+pub struct PipelineModel {
+    stats: PipelineStats,
+    /// Premier cycle où l'instruction suivante peut entrer en EX
+    next_ex: u64,
+    /// Dernier cycle en EX de la dernière instruction
+    last_ex_end: u64,
+    /// Producteur en vol de chaque registre général
+    producers: [Option<Producer>; GPR_COUNT],
 }
 
-impl SuperscalarPipeline {
-    /// Crée un nouveau pipeline superscalaire
-    pub fn new(num_arch_regs: usize, num_phys_regs: usize) -> Self {
-        // Initialiser la table de renommage
-        let mut rename_table = Vec::with_capacity(num_arch_regs);
-        for i in 0..num_arch_regs {
-            rename_table.push(RegisterRenameEntry {
-                arch_reg: i,
-                phys_reg: i,
-                ready: true,
-                producer: None,
-            });
-        }
-        
-        // Initialiser les registres physiques
-        let mut physical_registers = Vec::with_capacity(num_phys_regs);
-        for _ in 0..num_phys_regs {
-            physical_registers.push(Word::default_zero());
-        }
-        
-        SuperscalarPipeline {
-            instruction_window: Vec::with_capacity(INSTRUCTION_WINDOW_SIZE),
-            issue_width: 2,
-            execute_width: 2,
-            complete_width: 2,
-            rename_table,
-            physical_registers,
-            reorder_buffer: Vec::new(),
-            branch_predictor: TernaryBranchPredictor::new(64),
-            pc: 0,
-            stalled: false,
-        }
-    }
-    
-    /// Récupère des instructions depuis la mémoire d'instructions
-    pub fn fetch(&mut self, instruction_memory: &[Word], fetch_width: usize) {
-        // Si le pipeline est bloqué, ne pas récupérer de nouvelles instructions
-        if self.stalled {
-            return;
-        }
-        
-        // Vérifier s'il y a de la place dans la fenêtre d'instructions
-        let available_slots = INSTRUCTION_WINDOW_SIZE - self.instruction_window.len();
-        let fetch_count = std::cmp::min(fetch_width, available_slots);
-        
-        for _ in 0..fetch_count {
-            // Calculer l'adresse de l'instruction dans la mémoire
-            let mem_index = (self.pc / 4) as usize;
-            if mem_index >= instruction_memory.len() {
-                break;
-            }
-            
-            // Récupérer l'instruction
-            let instruction_word = instruction_memory[mem_index];
-            
-            // Extraire l'opcode (3 premiers trits)
-            let mut opcode = [Trit::Z; 3];
-            if let Some(tryte) = instruction_word.tryte(0) {
-                let trits = tryte.to_trits();
-                opcode.copy_from_slice(&trits);
-            }
-            
-            // Créer une nouvelle instruction dans le pipeline
-            let mut pipeline_instruction = PipelineInstruction::new(self.pc, opcode);
-            
-            // Décoder l'instruction (simplifié)
-            self.decode_instruction(&instruction_word, &mut pipeline_instruction);
-            
-            // Ajouter l'instruction à la fenêtre
-            self.instruction_window.push(pipeline_instruction);
-            
-            // Mettre à jour le PC (simplifié)
-            self.pc += 4;
+impl PipelineModel {
+    /// Crée un pipeline vide
+    pub fn new() -> Self {
+        PipelineModel {
+            stats: PipelineStats::default(),
+            next_ex: FIRST_EX_CYCLE,
+            last_ex_end: 0,
+            producers: [None; GPR_COUNT],
         }
     }
-    
-    /// Décode une instruction
-    fn decode_instruction(&self, instruction_word: &Word, pipeline_instruction: &mut PipelineInstruction) {
-        // Extraction des champs de l'instruction (simplifié)
-        // Dans une implémentation complète, cela dépendrait du format d'instruction
-        
-        // Exemple pour le format R: opcode[2:0] | func[2:0] | rs2[2:0] | rs1[2:0] | rd[2:0]
-        if let (Some(tryte0), Some(tryte1), Some(tryte2)) = 
-            (instruction_word.tryte(0), instruction_word.tryte(1), instruction_word.tryte(2)) {
-            
-            let trits0 = tryte0.to_trits();
-            let trits1 = tryte1.to_trits();
-            let trits2 = tryte2.to_trits();
-            
-            // Extraire rd (3 derniers trits du tryte2)
-            let rd_value = trits_to_value(&trits2);
-            pipeline_instruction.rd = Some(rd_value as usize);
-            
-            // Extraire rs1 (3 premiers trits du tryte2)
-            let rs1_value = trits_to_value(&trits1);
-            pipeline_instruction.rs1 = Some(rs1_value as usize);
-            
-            // Extraire rs2 (3 derniers trits du tryte1)
-            let rs2_value = trits_to_value(&trits0);
-            pipeline_instruction.rs2 = Some(rs2_value as usize);
-            
-            // Déterminer si l'instruction est un branchement ou accède à la mémoire
-            // Cela dépendrait de l'opcode spécifique
-            // Simplifié pour cet exemple
-            let opcode = pipeline_instruction.opcode;
-            
-            // Exemple: si l'opcode commence par N, c'est un accès mémoire
-            if opcode[0] == Trit::N {
-                pipeline_instruction.memory_access = true;
-                // Déterminer si c'est une lecture ou une écriture
-                pipeline_instruction.memory_write = opcode[2] == Trit::P;
-            }
-            
-            // Exemple: si l'opcode commence par P et finit par N, c'est un branchement
-            if opcode[0] == Trit::P && opcode[2] == Trit::N {
-                pipeline_instruction.modifies_pc = true;
-            }
-        }
+
+    /// Compteurs du pipeline
+    pub fn stats(&self) -> &PipelineStats {
+        &self.stats
     }
-    
-    /// Émet des instructions pour exécution
-    pub fn issue(&mut self) {
-        // Si le pipeline est bloqué, ne pas émettre d'instructions
-        if self.stalled {
-            return;
-        }
-        
-        let mut issued_count = 0;
-        
-        // Parcourir la fenêtre d'instructions
-        for i in 0..self.instruction_window.len() {
-            if issued_count >= self.issue_width {
-                break;
-            }
-            
-            if self.instruction_window[i].state == InstructionState::Waiting {
-                // Vérifier les dépendances
-                let mut can_issue = true;
-                
-                // Vérifier la disponibilité des registres sources
-                if let Some(rs1) = self.instruction_window[i].rs1 {
-                    let _phys_reg = self.rename_table[rs1].phys_reg;
-                    if !self.rename_table[rs1].ready {
-                        can_issue = false;
-                    }
-                }
-                
-                if let Some(rs2) = self.instruction_window[i].rs2 {
-                    let _phys_reg = self.rename_table[rs2].phys_reg;
-                    if !self.rename_table[rs2].ready {
-                        can_issue = false;
-                    }
-                }
-                
-                if can_issue {
-                    // Renommer le registre destination
-                    if let Some(rd) = self.instruction_window[i].rd {
-                        // Trouver un registre physique libre
-                        let free_phys_reg = self.find_free_physical_register();
-                        if let Some(phys_reg) = free_phys_reg {
-                            // Mettre à jour la table de renommage
-                            self.rename_table[rd].phys_reg = phys_reg;
-                            self.rename_table[rd].ready = false;
-                            self.rename_table[rd].producer = Some(i);
-                        } else {
-                            // Pas de registre physique disponible, ne pas émettre
-                            can_issue = false;
-                        }
-                    }
-                    
-                    if can_issue {
-                        // Émettre l'instruction
-                        self.instruction_window[i].state = InstructionState::Issued;
-                        issued_count += 1;
-                        
-                        // Ajouter à la file de réordonnancement
-                        self.reorder_buffer.push(i);
-                    }
-                }
-            }
+
+    /// Cycles écoulés jusqu'à la sortie (WB) de la dernière instruction
+    pub fn cycles(&self) -> u64 {
+        if self.stats.instructions == 0 {
+            0
+        } else {
+            self.last_ex_end + 2
         }
     }
-    
-    /// Exécute les instructions émises
-    pub fn execute(&mut self, register_file: &Register, memory: &mut [Word]) {
-        let mut executed_count = 0;
-        
-        // Parcourir la fenêtre d'instructions
-        for i in 0..self.instruction_window.len() {
-            if executed_count >= self.execute_width {
-                break;
-            }
-            
-            if self.instruction_window[i].state == InstructionState::Issued {
-                // Exécuter l'instruction
-                self.execute_instruction(i, register_file, memory);
-                executed_count += 1;
-            }
+
+    /// Cycles par instruction (0 si aucune instruction)
+    pub fn cpi(&self) -> f64 {
+        if self.stats.instructions == 0 {
+            0.0
+        } else {
+            self.cycles() as f64 / self.stats.instructions as f64
         }
     }
-    
-    /// Exécute une instruction spécifique
-    fn execute_instruction(&mut self, index: usize, register_file: &Register, memory: &mut [Word]) {
-        let instruction = &mut self.instruction_window[index];
-        
-        // Récupérer les valeurs des registres sources
-        let rs1_value = if let Some(rs1) = instruction.rs1 {
-            let _phys_reg = self.rename_table[rs1].phys_reg;
-            self.physical_registers[self.rename_table[rs1].phys_reg]
-        } else {
-            Word::default_zero()
-        };
-        
-        let rs2_value = if let Some(rs2) = instruction.rs2 {
-            let _phys_reg = self.rename_table[rs2].phys_reg;
-            self.physical_registers[self.rename_table[rs2].phys_reg]
-        } else {
-            Word::default_zero()
-        };
-        
-        // Exécuter l'opération en fonction de l'opcode (simplifié)
-        let opcode = instruction.opcode;
-        let mut result = Word::default_zero();
-        
-        // Exemple d'exécution (très simplifié)
-        // Dans une implémentation complète, cela dépendrait de l'opcode spécifique
-        if opcode[0] == Trit::Z && opcode[1] == Trit::P && opcode[2] == Trit::Z {
-            // Exemple: Addition (R-type)
-            result = crate::alu::add_words(rs1_value, rs2_value, false).0;
-        } else if opcode[0] == Trit::Z && opcode[1] == Trit::P && opcode[2] == Trit::P {
-            // Exemple: Soustraction (R-type)
-            result = crate::alu::sub_words(rs1_value, rs2_value, false).0;
-        } else if opcode[0] == Trit::P && opcode[2] == Trit::N {
-            // Exemple: Branchement
-            // Calculer la cible du branchement
-            let offset = if let Some(imm) = instruction.imm {
-                imm
-            } else {
-                0
-            };
-            
-            let target_pc = instruction.address.wrapping_add((offset * 4) as u32);
-            instruction.new_pc = Some(target_pc);
-            
-            // Vérifier la prédiction
-            let prediction = self.branch_predictor.predict(instruction.address);
-            let condition = if let Some(tryte) = rs1_value.tryte(7) {
-                match tryte {
-                    Tryte::Digit(_) => {
-                        // Utiliser la méthode get_trit pour accéder au trit de poids fort
-                        tryte.get_trit(2) // Trit de poids fort
-                    },
-                    _ => Trit::Z, // Valeur par défaut pour les états spéciaux
-                }
-            } else {
-                Trit::Z // Valeur par défaut
-            };
-            
-            // Mettre à jour le prédicteur
-            self.branch_predictor.update(instruction.address, condition);
-        } else if opcode[0] == Trit::N && opcode[1] == Trit::Z {
-            // Exemple: Chargement mémoire
-            if let Some(imm) = instruction.imm {
-                let address = rs1_value.to_i32().wrapping_add(imm) as u32;
-                instruction.memory_address = Some(address);
-                
-                // Accéder à la mémoire
-                let mem_index = (address / 4) as usize;
-                if mem_index < memory.len() {
-                    result = memory[mem_index];
-                }
-            }
-        } else if opcode[0] == Trit::N && opcode[1] == Trit::P {
-            // Exemple: Stockage mémoire
-            if let Some(imm) = instruction.imm {
-                let address = rs1_value.to_i32().wrapping_add(imm) as u32;
-                instruction.memory_address = Some(address);
-                instruction.memory_data = Some(rs2_value);
-                
-                // Accéder à la mémoire
-                let mem_index = (address / 4) as usize;
-                if mem_index < memory.len() {
-                    memory[mem_index] = rs2_value;
+
+    /// Fait passer une instruction exécutée dans le pipeline
+    /// `pc` et `next_pc` encadrent l'exécution, `ex_cycles` est la latence de l'unité
+    /// (TVPU, 0 pour l'ALU) et `memory_stall` les cycles d'attente des caches
+    pub fn retire(&mut self, pc: i64, instruction: &Instruction, next_pc: i64, ex_cycles: u64, memory_stall: u64) {
+        let (sources, destination) = registers(instruction);
+
+        // Aléas de données: attendre les chargements en vol
+        let mut ex_start = self.next_ex;
+        for source in sources.iter().flatten() {
+            if let Some(producer) = self.producers[source.to_index()] {
+                let ready = producer.ex_end + if producer.load { 2 } else { 1 };
+                if ready > ex_start {
+                    self.stats.load_use_stalls += ready - ex_start;
+                    ex_start = ready;
                 }
             }
         }
-        
-        // Stocker le résultat
-        instruction.result = Some(result);
-        
-        // Mettre à jour l'état de l'instruction
-        instruction.state = InstructionState::Executed;
-    }
-    
-    /// Complète les instructions exécutées
-    pub fn complete(&mut self) {
-        let mut completed_count = 0;
-        
-        // Parcourir la file de réordonnancement
-        let mut i = 0;
-        while i < self.reorder_buffer.len() && completed_count < self.complete_width {
-            let instr_index = self.reorder_buffer[i];
-            
-            if self.instruction_window[instr_index].state == InstructionState::Executed {
-                // Compléter l'instruction
-                self.instruction_window[instr_index].state = InstructionState::Completed;
-                
-                // Mettre à jour le registre destination
-                if let Some(rd) = self.instruction_window[instr_index].rd {
-                    let _phys_reg = self.rename_table[rd].phys_reg;
-                    if let Some(result) = self.instruction_window[instr_index].result {
-                        self.physical_registers[self.rename_table[rd].phys_reg] = result;
-                        self.rename_table[rd].ready = true;
-                        self.rename_table[rd].producer = None;
-                    }
-                }
-                
-                // Gérer les branchements
-                if self.instruction_window[instr_index].modifies_pc {
-                    if let Some(new_pc) = self.instruction_window[instr_index].new_pc {
-                        // Vérifier si la prédiction était correcte
-                        if new_pc != self.pc {
-                            // Mauvaise prédiction, vider le pipeline
-                            self.flush_pipeline(instr_index);
-                            self.pc = new_pc;
-                        }
-                    }
+        let mut forwarded = [false; GPR_COUNT];
+        for source in sources.iter().flatten() {
+            let index = source.to_index();
+            if let (Some(producer), false) = (self.producers[index], forwarded[index]) {
+                match ex_start - producer.ex_end {
+                    1 => self.stats.forwards_ex_mem += 1,
+                    2 => self.stats.forwards_mem_wb += 1,
+                    _ => {}
                 }
-                
-                // Retirer de la file de réordonnancement
-                self.reorder_buffer.remove(i);
-                completed_count += 1;
-            } else {
-                i += 1;
+                forwarded[index] = true;
             }
         }
-    }
-    
-    /// Vide le pipeline suite à une mauvaise prédiction
-    fn flush_pipeline(&mut self, branch_index: usize) {
-        // Annuler toutes les instructions après le branchement
-        for i in 0..self.instruction_window.len() {
-            if i > branch_index {
-                self.instruction_window[i].state = InstructionState::Cancelled;
-            }
+
+        // Exécution, éventuellement multi-cycle, puis attente mémoire
+        let extra = ex_cycles.saturating_sub(1) + if is_mul_div(instruction) { MUL_DIV_EXTRA_CYCLES } else { 0 };
+        self.stats.multicycle_stalls += extra;
+        self.stats.memory_stalls += memory_stall;
+        let ex_end = ex_start + extra + memory_stall;
+
+        if let Some(rd) = destination {
+            self.producers[rd.to_index()] = Some(Producer { ex_end, load: is_load(instruction) });
         }
-        
-        // Nettoyer la file de réordonnancement
-        self.reorder_buffer.retain(|&idx| idx <= branch_index);
-        
-        // Restaurer la table de renommage
-        // Dans une implémentation complète, il faudrait sauvegarder et restaurer
-        // l'état de la table de renommage à chaque branchement
-        
-        // Indiquer que le pipeline n'est plus bloqué
-        self.stalled = false;
-    }
-    
-    /// Trouve un registre physique libre
-    fn find_free_physical_register(&self) -> Option<usize> {
-        // Rechercher un registre physique qui n'est pas utilisé
-        // Dans une implémentation complète, il faudrait une gestion plus sophistiquée
-        
-        // Pour cet exemple, on considère que les registres au-delà des registres architecturaux sont libres
-        let arch_regs_count = self.rename_table.len();
-        
-        for i in arch_regs_count..self.physical_registers.len() {
-            let mut is_used = false;
-            
-            // Vérifier si ce registre physique est utilisé dans la table de renommage
-            for entry in &self.rename_table {
-                if entry.phys_reg == i && !entry.ready {
-                    is_used = true;
-                    break;
+
+        // Aléas de contrôle
+        let sequential = next_pc == pc + 4;
+        let penalty = match instruction {
+            Instruction::Branch { .. } | Instruction::Branch3 { .. } => {
+                self.stats.branches += 1;
+                if next_pc != predicted_next_pc(pc, instruction) {
+                    self.stats.mispredictions += 1;
+                    BRANCH_PENALTY
+                } else {
+                    0
                 }
             }
-            
-            if !is_used {
-                return Some(i);
+            Instruction::Jump { .. } | Instruction::Call { .. } => {
+                self.stats.jumps += 1;
+                JUMP_PENALTY
             }
-        }
-        
-        None
+            _ if !sequential => {
+                self.stats.jumps += 1;
+                INDIRECT_PENALTY
+            }
+            _ => 0,
+        };
+        self.stats.flush_bubbles += penalty;
+
+        self.stats.instructions += 1;
+        self.last_ex_end = ex_end;
+        self.next_ex = ex_end + 1 + penalty;
     }
-    
-    /// Nettoie les instructions complétées ou annulées
-    pub fn cleanup(&mut self) {
-        self.instruction_window.retain(|instr| {
-            instr.state != InstructionState::Completed && instr.state != InstructionState::Cancelled
-        });
+
+    /// Prend en compte un trap ou une interruption: le pipeline est vidé et le gestionnaire
+    /// chargé; l'instruction fautive ne sort pas du pipeline
+    pub fn trap(&mut self, memory_stall: u64) {
+        self.stats.traps += 1;
+        self.stats.flush_bubbles += TRAP_PENALTY;
+        self.stats.memory_stalls += memory_stall;
+        self.next_ex = self.next_ex.max(self.last_ex_end + 1) + TRAP_PENALTY + memory_stall;
     }
 }
 
-/// Convertit un tableau de trits en valeur entière
-fn trits_to_value(trits: &[Trit]) -> i32 {
-    let mut value = 0;
-    let mut power = 1;
-    
-    for &trit in trits {
-        let trit_value = match trit {
-            Trit::N => -1,
-            Trit::Z => 0,
-            Trit::P => 1,
-        };
-        
-        value += trit_value * power;
-        power *= 3;
+impl Default for PipelineModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adresse de l'instruction chargée après un branchement selon la prédiction statique
+fn predicted_next_pc(pc: i64, instruction: &Instruction) -> i64 {
+    match instruction {
+        Instruction::Branch { offset, .. } if *offset < 0 => relative_target_address(pc, *offset as i64),
+        Instruction::Branch3 { offset_zero, .. } => branch3_target_address(pc, *offset_zero as i64),
+        _ => pc + 4,
+    }
+}
+
+/// Instructions dont le résultat vient de la mémoire
+fn is_load(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Load { .. } | Instruction::LoadT { .. } | Instruction::LoadTU { .. } | Instruction::LoadT3 { .. }
+    )
+}
+
+/// Instructions exécutées par l'unité MUL/DIV
+fn is_mul_div(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::AluReg { op: AluOp::Mul | AluOp::Div | AluOp::Mod, .. }
+            | Instruction::AluImm { op: AluOp::Mul | AluOp::Div | AluOp::Mod, .. }
+            | Instruction::MAddW { .. }
+            | Instruction::MSubW { .. }
+    )
+}
+
+/// Registres généraux lus (au plus 3) et écrit par une instruction
+/// Les flags lus par BRANCH sont toujours transmis à temps: ils ne créent pas d'aléa
+fn registers(instruction: &Instruction) -> ([Option<Register>; 3], Option<Register>) {
+    use Instruction::*;
+    match *instruction {
+        AluReg { rs1, rs2, rd, .. }
+        | TernaryOp { rs1, rs2, rd, .. }
+        | Base24Op { rs1, rs2, rd, .. } => ([Some(rs1), Some(rs2), None], Some(rd)),
+        AluImm { rs1, rd, .. }
+        | Load { rd, rs1, .. }
+        | LoadT { rd, rs1, .. }
+        | LoadTU { rd, rs1, .. }
+        | LoadT3 { rd, rs1, .. }
+        | Jalr { rd, rs1, .. }
+        | CsrRw { rd, rs1, .. }
+        | CsrRs { rd, rs1, .. }
+        | CsrRc { rd, rs1, .. }
+        | TernaryShift { rs1, rd, .. }
+        | SpecialStateOp { rs1, rd, .. } => ([Some(rs1), None, None], Some(rd)),
+        Store { rs1, rs2, .. } | StoreT { rs1, rs2, .. } | StoreT3 { rs1, rs2, .. } => {
+            ([Some(rs1), Some(rs2), None], None)
+        }
+        TMemCpy { rd, rs1, rs2 } | TMemSet { rd, rs1, rs2 } => ([Some(rd), Some(rs1), Some(rs2)], None),
+        MAddW { rd, rs1, rs2, rs3 } | MSubW { rd, rs1, rs2, rs3 } | TSel { rd, rs1, rs2, rs3 } => {
+            ([Some(rs1), Some(rs2), Some(rs3)], Some(rd))
+        }
+        Jump { rd, .. } | Call { rd, .. } | Lui { rd, .. } | Auipc { rd, .. } => ([None; 3], Some(rd)),
+        Branch3 { rs1, .. }
+        | Csr { rs1, .. }
+        | VectorLoad { rs1, .. }
+        | VectorStore { rs1, .. } => ([Some(rs1), None, None], None),
+        VectorDot { rd, .. } | VectorReduce { rd, .. } => ([None; 3], Some(rd)),
+        Nop | Halt | EBreak | Branch { .. } | MRet | System { .. } | VectorOp { .. } => ([None; 3], None),
     }
-    
-    value
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::isa::BranchCondition;
+
+    fn add(rd: Register, rs1: Register, rs2: Register) -> Instruction {
+        Instruction::AluReg { op: AluOp::Add, rs1, rs2, rd }
+    }
+
+    #[test]
+    fn test_hazards_and_forwarding() {
+        let mut pipeline = PipelineModel::new();
+        let load = Instruction::Load { rd: Register::R1, rs1: Register::R2, offset: 0 };
+
+        // Chargement puis usage immédiat: une bulle, opérande transmis depuis MEM/WB
+        pipeline.retire(0, &load, 4, 0, 0);
+        pipeline.retire(4, &add(Register::R3, Register::R1, Register::R1), 8, 0, 0);
+        assert_eq!(pipeline.stats().load_use_stalls, 1);
+        assert_eq!(pipeline.stats().forwards_mem_wb, 1);
+
+        // Dépendance ALU -> ALU: transmis depuis EX/MEM sans bulle
+        pipeline.retire(8, &add(Register::R4, Register::R3, Register::R0), 12, 0, 0);
+        assert_eq!(pipeline.stats().forwards_ex_mem, 1);
+        assert_eq!(pipeline.stats().load_use_stalls, 1);
+
+        // MUL occupe EX un cycle de plus, l'attente mémoire gèle le pipeline
+        let mul = Instruction::AluReg { op: AluOp::Mul, rs1: Register::R4, rs2: Register::R4, rd: Register::R5 };
+        pipeline.retire(12, &mul, 16, 0, 10);
+        assert_eq!(pipeline.stats().multicycle_stalls, 1);
+        assert_eq!(pipeline.stats().memory_stalls, 10);
+
+        // 4 instructions + 4 cycles de remplissage + 12 bulles
+        assert_eq!(pipeline.cycles(), 4 + 4 + 12);
+        assert_eq!(pipeline.stats().bubbles(), 12);
+    }
+
+    #[test]
+    fn test_control_hazards() {
+        let mut pipeline = PipelineModel::new();
+        let backward = Instruction::Branch { rs1: Register::R0, cond: BranchCondition::NonZero, offset: -2 };
+
+        // Boucle: prédite prise (vers 8 + 4 - 2 × 4), la sortie de boucle est mal prédite
+        pipeline.retire(8, &backward, 4, 0, 0);
+        pipeline.retire(8, &backward, 12, 0, 0);
+        assert_eq!((pipeline.stats().branches, pipeline.stats().mispredictions), (2, 1));
+        assert_eq!(pipeline.stats().flush_bubbles, BRANCH_PENALTY);
+
+        // Sauts direct et indirect, puis trap
+        pipeline.retire(12, &Instruction::Jump { rd: Register::R0, offset: 10 }, 52, 0, 0);
+        pipeline.retire(52, &Instruction::Jalr { rd: Register::R0, rs1: Register::R7, offset: 0 }, 100, 0, 0);
+        pipeline.trap(0);
+        assert_eq!(pipeline.stats().jumps, 2);
+        assert_eq!(pipeline.stats().traps, 1);
+        assert_eq!(
+            pipeline.stats().flush_bubbles,
+            BRANCH_PENALTY + JUMP_PENALTY + INDIRECT_PENALTY + TRAP_PENALTY
+        );
+
+        // Le gestionnaire entre en EX après les bulles du trap
+        pipeline.retire(400, &Instruction::Nop, 404, 0, 0);
+        assert_eq!(pipeline.cycles(), 5 + 4 + pipeline.stats().bubbles());
+    }
+}
@@ -0,0 +1,206 @@
+// src/preload.rs
+// Préchargement de registres et de zones mémoire avant l'exécution (données d'entrée
+// des benchmarks, voir benchmarks/prismchrono/*.data)
+//
+// Une affectation s'écrit CIBLE=VALEURS:
+//   R0..R7, SP   registre, une seule valeur
+//   ADRESSE      Mots consécutifs à partir de l'adresse (hexadécimale, alignée sur 8)
+// Les valeurs sont des entiers décimaux signés, des nombres ternaires équilibrés (0t+-0+),
+// des trytes B24 (13:14, poids fort en tête) ou les états spéciaux NaN, NULL et UNDEF
+// (Mot entier dans cet état), séparés par des virgules ou des espaces.
+//
+// Un fichier de données contient une affectation par ligne. Une ligne sans `=` prolonge
+// l'affectation mémoire précédente; les lignes vides et les commentaires `#` sont ignorés.
+//   # Tableau de sum_array
+//   R2 = 100
+//   1000 = 1, 2, 3, 4
+//          5, 6, 7, 8
+
+use crate::core::{Address, Word};
+use crate::cpu::execute::Cpu;
+use crate::cpu::registers::Register;
+use crate::debugger::parse_address;
+use std::str::FromStr;
+
+/// Cible d'une affectation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreloadTarget {
+    Register(Register),
+    StackPointer,
+    Memory(Address),
+}
+
+/// Affectation de valeurs à un registre ou à une zone mémoire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preload {
+    pub target: PreloadTarget,
+    pub values: Vec<Word>,
+}
+
+impl Preload {
+    /// Applique l'affectation au CPU (les Mots passent par le bus: un périphérique projeté
+    /// dans la zone reçoit les écritures)
+    pub fn apply(&self, cpu: &mut Cpu) -> Result<(), String> {
+        match self.target {
+            PreloadTarget::Register(reg) => cpu.state.write_gpr(reg, self.values[0]),
+            PreloadTarget::StackPointer => cpu.state.write_sp(self.values[0]),
+            PreloadTarget::Memory(base) => {
+                for (i, value) in self.values.iter().enumerate() {
+                    let addr = base + 8 * i;
+                    cpu.memory
+                        .write_word(addr, *value)
+                        .map_err(|e| format!("écriture du Mot à {:04X} impossible: {:?}", addr, e))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PreloadTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("sp") {
+            return Ok(PreloadTarget::StackPointer);
+        }
+        if let Some(index) = s.strip_prefix(['R', 'r'])
+            && let Ok(index) = index.parse::<usize>()
+        {
+            return Register::from_index(index)
+                .map(PreloadTarget::Register)
+                .map_err(|_| format!("registre inconnu '{}'", s));
+        }
+        let addr = parse_address(s)?;
+        if addr % 8 != 0 {
+            return Err(format!("adresse {:04X} non alignée sur un Mot", addr));
+        }
+        Ok(PreloadTarget::Memory(addr))
+    }
+}
+
+/// Analyse une valeur (forme acceptée par `Word::from_str`)
+fn parse_value(s: &str) -> Result<Word, String> {
+    s.parse().map_err(|e| format!("'{}': {}", s, e))
+}
+
+/// Analyse une liste de valeurs séparées par des virgules ou des espaces
+fn parse_values(s: &str) -> Result<Vec<Word>, String> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(parse_value)
+        .collect()
+}
+
+impl FromStr for Preload {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (target, values) = s
+            .split_once('=')
+            .ok_or_else(|| format!("affectation CIBLE=VALEURS attendue, trouvé '{}'", s))?;
+        let target: PreloadTarget = target.trim().parse()?;
+        let values = parse_values(values)?;
+        match (target, values.len()) {
+            (_, 0) => Err(format!("aucune valeur pour {}", target_name(target))),
+            (PreloadTarget::Register(_) | PreloadTarget::StackPointer, n) if n > 1 => {
+                Err(format!("une seule valeur attendue pour {}", target_name(target)))
+            }
+            _ => Ok(Preload { target, values }),
+        }
+    }
+}
+
+/// Nom d'une cible dans les messages d'erreur
+fn target_name(target: PreloadTarget) -> String {
+    match target {
+        PreloadTarget::Register(reg) => reg.to_string(),
+        PreloadTarget::StackPointer => "SP".to_string(),
+        PreloadTarget::Memory(addr) => format!("{:04X}", addr),
+    }
+}
+
+/// Analyse un fichier de données (voir le format en tête de fichier)
+pub fn parse_preload_file(text: &str) -> Result<Vec<Preload>, String> {
+    let mut preloads: Vec<Preload> = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let parsed = if line.contains('=') {
+            line.parse().map(|preload| preloads.push(preload))
+        } else {
+            match preloads.last_mut() {
+                Some(Preload { target: PreloadTarget::Memory(_), values }) => {
+                    parse_values(line).map(|more| values.extend(more))
+                }
+                _ => Err("suite de valeurs sans affectation mémoire qui la précède".to_string()),
+            }
+        };
+        parsed.map_err(|e| format!("ligne {}: {}", number + 1, e))?;
+    }
+    Ok(preloads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Tryte;
+
+    #[test]
+    fn test_parse_assignments() {
+        assert_eq!(
+            "R3=-5".parse(),
+            Ok(Preload { target: PreloadTarget::Register(Register::R3), values: vec![Word::from(-5)] })
+        );
+        assert_eq!(
+            " sp = 1 ".parse(),
+            Ok(Preload { target: PreloadTarget::StackPointer, values: vec![Word::from(1)] })
+        );
+        assert_eq!(
+            "0x100=1, 2 NaN".parse(),
+            Ok(Preload {
+                target: PreloadTarget::Memory(0x100),
+                values: vec![Word::from(1), Word::from(2), Word([Tryte::NaN; 8])],
+            })
+        );
+
+        assert_eq!(
+            "R2=0t+-0+".parse(),
+            Ok(Preload { target: PreloadTarget::Register(Register::R2), values: vec![Word::from(19)] })
+        );
+
+        assert!("R8=1".parse::<Preload>().is_err());
+        assert!("R1=1,2".parse::<Preload>().is_err());
+        assert!("104=1".parse::<Preload>().unwrap_err().contains("non alignée"));
+        assert!("100=".parse::<Preload>().is_err());
+        assert!("100=abc".parse::<Preload>().is_err());
+        assert!("100=141214768241".parse::<Preload>().unwrap_err().contains("hors de la plage"));
+        assert!("R1".parse::<Preload>().is_err());
+    }
+
+    #[test]
+    fn test_preload_file_applies_to_cpu() {
+        let text = "# Données\nR1 = 3\n\n20 = 1, 2   # deux Mots\n     -1 NULL\nSP=7\n";
+        let preloads = parse_preload_file(text).unwrap();
+        assert_eq!(preloads.len(), 3);
+        assert_eq!(preloads[1].values.len(), 4);
+
+        let mut cpu = Cpu::with_memory_size(1024);
+        for preload in &preloads {
+            preload.apply(&mut cpu).unwrap();
+        }
+        assert_eq!(cpu.state.read_gpr(Register::R1), Word::from(3));
+        assert_eq!(cpu.state.read_sp(), Word::from(7));
+        assert_eq!(cpu.memory.read_word(0x20), Ok(Word::from(1)));
+        assert_eq!(cpu.memory.read_word(0x38), Ok(Word([Tryte::Null; 8])));
+
+        assert_eq!(
+            parse_preload_file("R1=1\n2 3"),
+            Err("ligne 2: suite de valeurs sans affectation mémoire qui la précède".to_string())
+        );
+        let outside = parse_preload_file("3F8 = 1 2").unwrap();
+        assert!(outside[0].apply(&mut cpu).unwrap_err().contains("400"));
+    }
+}
@@ -0,0 +1,382 @@
+// src/semihost.rs
+// Semihosting: appels système de l'invité servis par l'hôte (mode optionnel)
+//
+// Quand le semihosting est activé, ECALL n'est plus un trap vers le système invité: l'hôte
+// exécute la fonction dont le code est dans R1, avec les arguments R2, R3 et R4, et place
+// le résultat dans R1 (valeur négative en cas d'erreur). Les autres registres ne changent
+// pas et l'exécution reprend après l'ECALL.
+//
+//   Code  Fonction  Arguments                       Résultat
+//   1     EXIT      R2 = code de sortie             (arrêt de l'exécution)
+//   2     PUTC      R2 = octet                      1 (octet écrit)
+//   3     GETC      -                               octet lu, -1 en fin d'entrée
+//   4     OPEN      R2 = chemin, R3 = longueur,     descripteur (>= 3)
+//                   R4 = mode (0 lecture, 1 écriture, 2 ajout)
+//   5     CLOSE     R2 = descripteur                0
+//   6     READ      R2 = descripteur, R3 = tampon,  octets lus, 0 en fin de fichier
+//                   R4 = taille maximale
+//   7     WRITE     R2 = descripteur, R3 = tampon,  octets écrits
+//                   R4 = longueur
+//   8     CYCLES    -                               cycles simulés
+//   9     INSTRET   -                               instructions exécutées
+//
+// Les descripteurs 0, 1 et 2 sont l'entrée, la sortie et la sortie d'erreur de la console.
+// Une chaîne en mémoire (chemin, tampon) occupe un Mot par octet, de valeur 0 à 255, comme
+// les registres TX_DATA et RX_DATA de l'UART: une chaîne de N octets occupe 8*N trytes à
+// partir d'une adresse alignée sur un Mot. Un octet écrit est pris modulo 256.
+//
+// Les fichiers de l'hôte sont confinés dans un répertoire: un chemin doit être relatif et
+// ne désigner que des sous-répertoires (ni `..`, ni racine). Sans répertoire, OPEN échoue.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::core::{Address, MAX_ADDRESS, WORD_MAX, Word};
+use crate::cpu::execute::{Cpu, ExecuteError};
+use crate::cpu::execute_core::word_to_address;
+use crate::cpu::registers::Register;
+use crate::cpu::state::CpuState;
+
+/// Codes des fonctions (valeur de R1 à l'ECALL)
+pub const SYS_EXIT: i64 = 1;
+pub const SYS_PUTC: i64 = 2;
+pub const SYS_GETC: i64 = 3;
+pub const SYS_OPEN: i64 = 4;
+pub const SYS_CLOSE: i64 = 5;
+pub const SYS_READ: i64 = 6;
+pub const SYS_WRITE: i64 = 7;
+pub const SYS_CYCLES: i64 = 8;
+pub const SYS_INSTRET: i64 = 9;
+
+/// Modes d'ouverture de OPEN
+pub const OPEN_READ: i64 = 0;
+pub const OPEN_WRITE: i64 = 1;
+pub const OPEN_APPEND: i64 = 2;
+
+/// Premier descripteur attribué à un fichier ouvert
+const FIRST_FILE_FD: i64 = 3;
+
+/// Résultat d'un appel qui a échoué
+const FAILURE: i64 = -1;
+
+/// État de l'hôte pour le semihosting: console, fichiers ouverts, code de sortie
+pub struct Semihost {
+    input: Box<dyn Read>,        // Entrée de la console (descripteur 0, GETC)
+    output: Box<dyn Write>,      // Sortie de la console (descripteur 1, PUTC)
+    error: Box<dyn Write>,       // Sortie d'erreur de la console (descripteur 2)
+    root: Option<PathBuf>,       // Répertoire des fichiers accessibles (None: aucun)
+    files: BTreeMap<i64, File>,  // Fichiers ouverts, par descripteur
+    next_fd: i64,                // Prochain descripteur attribué
+    exit_status: Option<i64>,    // Code passé à EXIT
+}
+
+impl Semihost {
+    /// Semihosting lisant `input`, écrivant sur `output` et `error`, et donnant accès aux
+    /// fichiers de `root`
+    pub fn new(input: Box<dyn Read>, output: Box<dyn Write>, error: Box<dyn Write>, root: Option<PathBuf>) -> Self {
+        Semihost {
+            input,
+            output,
+            error,
+            root,
+            files: BTreeMap::new(),
+            next_fd: FIRST_FILE_FD,
+            exit_status: None,
+        }
+    }
+
+    /// Semihosting relié à la console de l'hôte
+    pub fn stdio(root: Option<PathBuf>) -> Self {
+        Self::new(Box::new(io::stdin()), Box::new(io::stdout()), Box::new(io::stderr()), root)
+    }
+
+    /// Code de sortie passé à EXIT, None tant que le programme n'a pas appelé EXIT
+    pub fn exit_status(&self) -> Option<i64> {
+        self.exit_status
+    }
+
+    /// Sert l'appel système du CPU (ECALL): lit la fonction et ses arguments, écrit le résultat
+    /// Un code de fonction inconnu est une opération invalide (trap IllegalInstr)
+    pub fn call(&mut self, cpu: &mut Cpu) -> Result<(), ExecuteError> {
+        let arg = |reg: Register| cpu.state.read_gpr(reg).to_i64();
+        let (function, a, b, c) = (arg(Register::R1), arg(Register::R2), arg(Register::R3), arg(Register::R4));
+
+        let result = match function {
+            SYS_EXIT => {
+                self.exit_status = Some(a);
+                // Une erreur d'écriture de la console n'empêche pas l'arrêt
+                let _ = self.output.flush();
+                let _ = self.error.flush();
+                cpu.halted = true;
+                return Ok(());
+            }
+            SYS_PUTC => self.write_fd(1, &[a.rem_euclid(256) as u8]),
+            SYS_GETC => {
+                let mut byte = [0u8; 1];
+                match self.read_fd(0, &mut byte) {
+                    1 => byte[0] as i64,
+                    _ => FAILURE,
+                }
+            }
+            SYS_OPEN => {
+                let name = read_bytes(cpu, a, b)?;
+                self.open(&name, c)
+            }
+            SYS_CLOSE => match self.files.remove(&a) {
+                Some(_) => 0,
+                None => FAILURE,
+            },
+            SYS_READ => match usize::try_from(c) {
+                Ok(len) => {
+                    let buffer = word_to_address(&Word::from_i64(b))?;
+                    // Un tampon ne peut pas dépasser l'espace d'adressage
+                    let mut bytes = vec![0u8; len.min(MAX_ADDRESS / 8)];
+                    let count = self.read_fd(a, &mut bytes);
+                    if count > 0 {
+                        write_bytes(cpu, buffer, &bytes[..count as usize])?;
+                    }
+                    count
+                }
+                Err(_) => FAILURE,
+            },
+            SYS_WRITE => {
+                let bytes = read_bytes(cpu, b, c)?;
+                self.write_fd(a, &bytes)
+            }
+            SYS_CYCLES => (cpu.cycles as i64).min(WORD_MAX),
+            SYS_INSTRET => (cpu.instructions_executed as i64).min(WORD_MAX),
+            _ => return Err(ExecuteError::InvalidOperation),
+        };
+        cpu.state.write_gpr(Register::R1, Word::from_i64(result));
+        Ok(())
+    }
+
+    /// Ouvre un fichier du répertoire confiné; retourne son descripteur
+    fn open(&mut self, name: &[u8], mode: i64) -> i64 {
+        let Some(path) = self.sandboxed_path(name) else {
+            return FAILURE;
+        };
+        let mut options = OpenOptions::new();
+        match mode {
+            OPEN_READ => options.read(true),
+            OPEN_WRITE => options.write(true).create(true).truncate(true),
+            OPEN_APPEND => options.append(true).create(true),
+            _ => return FAILURE,
+        };
+        match options.open(path) {
+            Ok(file) => {
+                let fd = self.next_fd;
+                self.next_fd += 1;
+                self.files.insert(fd, file);
+                fd
+            }
+            Err(_) => FAILURE,
+        }
+    }
+
+    /// Chemin de l'hôte d'un nom de fichier de l'invité, None s'il sort du répertoire confiné
+    fn sandboxed_path(&self, name: &[u8]) -> Option<PathBuf> {
+        let root = self.root.as_ref()?;
+        let name = std::str::from_utf8(name).ok()?;
+        let relative = Path::new(name);
+        let confined = relative.components().all(|component| matches!(component, Component::Normal(_)));
+        if name.is_empty() || name.contains('\0') || !confined {
+            return None;
+        }
+        Some(root.join(relative))
+    }
+
+    /// Lit au plus `buffer.len()` octets d'un descripteur; retourne le nombre d'octets lus
+    fn read_fd(&mut self, fd: i64, buffer: &mut [u8]) -> i64 {
+        let reader: &mut dyn Read = match fd {
+            0 => &mut self.input,
+            fd => match self.files.get_mut(&fd) {
+                Some(file) => file,
+                None => return FAILURE,
+            },
+        };
+        let mut count = 0;
+        while count < buffer.len() {
+            match reader.read(&mut buffer[count..]) {
+                Ok(0) => break,
+                Ok(n) => count += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) if count > 0 => break,
+                Err(_) => return FAILURE,
+            }
+            // La console rend la main à chaque ligne, sans attendre de remplir le tampon
+            if fd == 0 {
+                break;
+            }
+        }
+        count as i64
+    }
+
+    /// Écrit des octets sur un descripteur; retourne le nombre d'octets écrits
+    fn write_fd(&mut self, fd: i64, bytes: &[u8]) -> i64 {
+        let written = match fd {
+            1 => self.output.write_all(bytes).and_then(|()| self.output.flush()),
+            2 => self.error.write_all(bytes).and_then(|()| self.error.flush()),
+            fd => match self.files.get_mut(&fd) {
+                Some(file) => file.write_all(bytes),
+                None => return FAILURE,
+            },
+        };
+        match written {
+            Ok(()) => bytes.len() as i64,
+            Err(_) => FAILURE,
+        }
+    }
+}
+
+/// Lit une chaîne de `len` octets à l'adresse `addr` (un Mot par octet)
+fn read_bytes(cpu: &Cpu, addr: i64, len: i64) -> Result<Vec<u8>, ExecuteError> {
+    let len = usize::try_from(len).map_err(|_| ExecuteError::InvalidOperation)?;
+    let base = word_to_address(&Word::from_i64(addr))?;
+    (0..len)
+        .map(|i| {
+            let word = cpu.read_word(string_address(base, i)?)?;
+            Ok(word.to_i64().rem_euclid(256) as u8)
+        })
+        .collect()
+}
+
+/// Écrit une chaîne d'octets à l'adresse `base` (un Mot par octet)
+fn write_bytes(cpu: &mut Cpu, base: Address, bytes: &[u8]) -> Result<(), ExecuteError> {
+    for (i, &byte) in bytes.iter().enumerate() {
+        cpu.write_word(string_address(base, i)?, Word::from(byte as i32))?;
+    }
+    Ok(())
+}
+
+/// Adresse du Mot du i-ième octet d'une chaîne
+fn string_address(base: Address, index: usize) -> Result<Address, ExecuteError> {
+    index
+        .checked_mul(8)
+        .and_then(|offset| base.checked_add(offset))
+        .ok_or(ExecuteError::InvalidAddress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::execute::StopReason;
+    use crate::cpu::isa::Instruction;
+    use crate::cpu::registers::TrapCause;
+    use crate::loader::instruction_to_trytes;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Sortie partagée pour relire ce que le programme a écrit
+    #[derive(Clone, Default)]
+    struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // CPU dont le programme est un ECALL à l'adresse 16, semihosting activé
+    fn cpu_with_semihost(input: &'static [u8], root: Option<PathBuf>) -> (Cpu, SharedOutput) {
+        let output = SharedOutput::default();
+        let mut cpu = Cpu::with_memory_size(1024);
+        let trits = prismchrono_isa::encode(&Instruction::System { func: 2 }).unwrap();
+        cpu.memory.load_trytes(16, &instruction_to_trytes(&trits)).unwrap();
+        cpu.semihost = Some(Semihost::new(Box::new(input), Box::new(output.clone()), Box::new(io::sink()), root));
+        (cpu, output)
+    }
+
+    // Exécute l'ECALL avec les arguments R1 à R4; retourne le résultat (R1)
+    fn ecall(cpu: &mut Cpu, args: [i64; 4]) -> Result<i64, ExecuteError> {
+        for (index, value) in args.into_iter().enumerate() {
+            cpu.state.write_gpr(Register::from_index(index + 1).unwrap(), Word::from_i64(value));
+        }
+        cpu.state.write_pc(Word::from(16));
+        cpu.step()?;
+        Ok(cpu.state.read_gpr(Register::R1).to_i64())
+    }
+
+    // Place une chaîne en mémoire, un Mot par octet
+    fn store_string(cpu: &mut Cpu, addr: Address, text: &str) {
+        for (i, byte) in text.bytes().enumerate() {
+            cpu.memory.write_word(addr + 8 * i, Word::from(byte as i32)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_console_counters_and_exit() {
+        let (mut cpu, output) = cpu_with_semihost(b"hi", None);
+
+        assert_eq!(ecall(&mut cpu, [SYS_PUTC, 79, 0, 0]), Ok(1));
+        assert_eq!(ecall(&mut cpu, [SYS_PUTC, -181, 0, 0]), Ok(1)); // 'K' modulo 256
+        store_string(&mut cpu, 128, "ab");
+        assert_eq!(ecall(&mut cpu, [SYS_WRITE, 1, 128, 2]), Ok(2));
+        assert_eq!(*output.0.borrow(), b"OKab".to_vec());
+        assert_eq!(cpu.state.read_pc(), Word::from(20));
+
+        assert_eq!(ecall(&mut cpu, [SYS_GETC, 0, 0, 0]), Ok(104));
+        assert_eq!(ecall(&mut cpu, [SYS_READ, 0, 128, 4]), Ok(1));
+        assert_eq!(cpu.memory.read_word(128), Ok(Word::from(105)));
+        assert_eq!(ecall(&mut cpu, [SYS_GETC, 0, 0, 0]), Ok(-1));
+        assert_eq!(ecall(&mut cpu, [SYS_INSTRET, 0, 0, 0]), Ok(7));
+        assert_eq!(ecall(&mut cpu, [SYS_CYCLES, 0, 0, 0]), Ok(7));
+
+        // Fonction inconnue: instruction illégale, trap vers mtvec_t (nul: adresse 0)
+        assert_eq!(ecall(&mut cpu, [42, 0, 0, 0]), Ok(42));
+        assert_eq!(cpu.state.read_pc(), Word::zero());
+        assert_eq!(cpu.state.get_trap_cause(), Some(TrapCause::IllegalInstr));
+        assert_eq!(ecall(&mut cpu, [SYS_WRITE, 5, 128, 1]), Ok(-1));
+
+        cpu.state.write_gpr(Register::R1, Word::from_i64(SYS_EXIT));
+        cpu.state.write_gpr(Register::R2, Word::from(-2));
+        cpu.state.write_pc(Word::from(16));
+        assert_eq!(cpu.run(None), Ok(StopReason::Exited(-2)));
+        assert!(cpu.halted);
+    }
+
+    #[test]
+    fn test_sandboxed_files() {
+        let root = std::env::temp_dir().join(format!("prismchrono_semihost_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        let (mut cpu, _) = cpu_with_semihost(b"", Some(root.clone()));
+
+        // Écriture d'un fichier du répertoire confiné
+        store_string(&mut cpu, 128, "dat");
+        let fd = ecall(&mut cpu, [SYS_OPEN, 128, 3, OPEN_WRITE]).unwrap();
+        assert_eq!(fd, 3);
+        store_string(&mut cpu, 256 + 8, "tab");
+        assert_eq!(ecall(&mut cpu, [SYS_WRITE, fd, 264, 3]), Ok(3));
+        assert_eq!(ecall(&mut cpu, [SYS_CLOSE, fd, 0, 0]), Ok(0));
+        assert_eq!(ecall(&mut cpu, [SYS_CLOSE, fd, 0, 0]), Ok(-1));
+        assert_eq!(std::fs::read(root.join("dat")).unwrap(), b"tab".to_vec());
+
+        // Relecture jusqu'à la fin du fichier
+        let fd = ecall(&mut cpu, [SYS_OPEN, 128, 3, OPEN_READ]).unwrap();
+        assert_eq!(ecall(&mut cpu, [SYS_READ, fd, 512, 8]), Ok(3));
+        assert_eq!(cpu.memory.read_word(512 + 16), Ok(Word::from(98)));
+        assert_eq!(ecall(&mut cpu, [SYS_READ, fd, 512, 8]), Ok(0));
+
+        // Chemins qui sortent du répertoire, mode inconnu, fichier absent
+        for path in ["../dat", "/dat", "sub/../dat"] {
+            store_string(&mut cpu, 128, path);
+            assert_eq!(ecall(&mut cpu, [SYS_OPEN, 128, path.len() as i64, OPEN_READ]), Ok(-1), "{}", path);
+        }
+        store_string(&mut cpu, 128, "dat");
+        assert_eq!(ecall(&mut cpu, [SYS_OPEN, 128, 3, 7]), Ok(-1));
+        assert_eq!(ecall(&mut cpu, [SYS_OPEN, 128, 2, OPEN_READ]), Ok(-1));
+        std::fs::remove_dir_all(&root).unwrap();
+
+        // Sans répertoire, aucun fichier n'est accessible
+        let (mut cpu, _) = cpu_with_semihost(b"", None);
+        store_string(&mut cpu, 128, "dat");
+        assert_eq!(ecall(&mut cpu, [SYS_OPEN, 128, 3, OPEN_READ]), Ok(-1));
+    }
+}
@@ -0,0 +1,419 @@
+// src/snapshot.rs
+// Instantanés de l'état complet de la machine (sauvegarde et reprise d'une exécution)
+//
+// Un instantané contient tout ce qui détermine la suite de l'exécution: registres
+// généraux, PC, SP, flags, niveau de privilège, CSRs, registres vectoriels, état d'arrêt,
+// compteurs d'exécution, contenu de la RAM et état des périphériques projetés. Les
+// modèles d'observation (caches, TLB, pipeline, prédicteur, trace) n'en font pas partie:
+// ils repartent à froid après une restauration, sans effet sur les résultats.
+//
+// Format binaire (entiers en LEB128, trytes et Mots comme dans les traces binaires):
+//   SNAPSHOT_MAGIC, version
+//   R0-R7, PC, SP (Mots), flags (un octet: ZF SF XF OF CF), privilège (un octet)
+//   nombre de CSRs, CSRs (Mots, codes 0 à n-1)
+//   nombre de registres vectoriels, puis leurs 8 Mots chacun
+//   état d'arrêt (un octet), nombre de compteurs, compteurs
+//   taille de la RAM, nombre de plages, puis pour chaque plage: adresse, longueur, trytes
+//   nombre de périphériques, puis pour chacun: base, nom, nombre de Mots d'état, Mots
+// La RAM est codée par plages de trytes définis: les trytes Undefined ne coûtent rien.
+
+use crate::core::{Address, Tryte, Word};
+use crate::cpu::execute::Cpu;
+use crate::cpu::registers::{Flags, PrivilegeLevel, Register};
+use crate::memory::pack_tryte;
+use crate::trace::{Reader, TraceError, push_varint, push_word};
+use std::fmt;
+
+/// En-tête des instantanés
+pub const SNAPSHOT_MAGIC: &[u8; 6] = b"PCSNAP";
+
+/// Version du format
+pub const SNAPSHOT_VERSION: u8 = 1;
+
+/// Nombre de CSRs sauvegardés (codes 0 à 12)
+const SNAPSHOT_CSRS: usize = 13;
+
+/// Erreurs de lecture ou de restauration d'un instantané
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    Io(String),                                        // Erreur d'accès au fichier
+    BadMagic,                                          // En-tête absent (pas un instantané)
+    UnsupportedVersion(u8),                            // Version du format inconnue
+    Truncated,                                         // Fin de fichier prématurée
+    InvalidData(String),                               // Champ invalide
+    MemorySize { expected: usize, found: usize },      // Taille de RAM différente de celle du CPU
+    DeviceMismatch(String),                            // Périphériques projetés différents
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(error) => write!(f, "{}", error),
+            SnapshotError::BadMagic => write!(f, "en-tête d'instantané invalide"),
+            SnapshotError::UnsupportedVersion(version) => write!(f, "version d'instantané {} non supportée", version),
+            SnapshotError::Truncated => write!(f, "instantané tronqué"),
+            SnapshotError::InvalidData(what) => write!(f, "instantané invalide: {}", what),
+            SnapshotError::MemorySize { expected, found } => {
+                write!(f, "l'instantané attend {} trytes de RAM, le CPU en a {}", expected, found)
+            }
+            SnapshotError::DeviceMismatch(what) => write!(f, "périphériques différents de l'instantané: {}", what),
+        }
+    }
+}
+
+// Les erreurs du lecteur binaire partagé avec les traces
+impl From<TraceError> for SnapshotError {
+    fn from(error: TraceError) -> Self {
+        match error {
+            TraceError::Truncated => SnapshotError::Truncated,
+            TraceError::InvalidData(what) => SnapshotError::InvalidData(what),
+            other => SnapshotError::InvalidData(other.to_string()),
+        }
+    }
+}
+
+/// État complet de la machine à un instant donné
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    registers: Vec<Word>,
+    pc: Word,
+    sp: Word,
+    flags: Flags,
+    privilege: PrivilegeLevel,
+    csrs: Vec<Word>,
+    vector_registers: Vec<Vec<Word>>,
+    halted: bool,
+    counters: Vec<u64>,
+    memory_size: usize,
+    memory: Vec<(Address, Vec<Tryte>)>,
+    devices: Vec<(Address, String, Vec<Word>)>,
+}
+
+impl Snapshot {
+    /// Capture l'état courant du CPU
+    pub fn capture(cpu: &Cpu) -> Self {
+        let state = &cpu.state;
+        Snapshot {
+            registers: (0..8)
+                .filter_map(|index| Register::from_index(index).ok())
+                .map(|register| state.read_gpr(register))
+                .collect(),
+            pc: state.read_pc(),
+            sp: state.read_sp(),
+            flags: state.read_flags(),
+            privilege: state.current_privilege,
+            csrs: (0..SNAPSHOT_CSRS as i8).map(|code| state.read_csr(code).unwrap_or_default()).collect(),
+            vector_registers: state
+                .vector_registers()
+                .iter()
+                .map(|vector| (0..8).filter_map(|index| vector.word(index).copied()).collect())
+                .collect(),
+            halted: cpu.halted,
+            counters: vec![
+                cpu.cycles,
+                cpu.instructions_executed,
+                cpu.memory_reads,
+                cpu.memory_writes,
+                cpu.branches_total,
+                cpu.branches_taken,
+                cpu.tvpu.operation_count,
+                cpu.tvpu.cycle_count,
+            ],
+            memory_size: cpu.memory.size(),
+            memory: cpu.memory.defined_ranges(),
+            devices: cpu.memory.device_states(),
+        }
+    }
+
+    /// Remplace l'état du CPU par celui de l'instantané
+    ///
+    /// La RAM du CPU doit avoir la même taille et les mêmes périphériques doivent y être
+    /// projetés (mêmes adresses, mêmes noms): l'instantané restaure leur état, pas leur
+    /// configuration. Les caches sont invalidés et le TLB vidé.
+    pub fn restore(&self, cpu: &mut Cpu) -> Result<(), SnapshotError> {
+        if cpu.memory.size() != self.memory_size {
+            return Err(SnapshotError::MemorySize { expected: self.memory_size, found: cpu.memory.size() });
+        }
+        let mapped: Vec<(Address, String)> = cpu.memory.devices().into_iter().map(|(base, _, name)| (base, name)).collect();
+        let saved: Vec<(Address, String)> = self.devices.iter().map(|(base, name, _)| (*base, name.clone())).collect();
+        if mapped != saved {
+            let describe = |devices: &[(Address, String)]| {
+                let names: Vec<String> = devices.iter().map(|(base, name)| format!("{}@{:X}", name, base)).collect();
+                if names.is_empty() { "aucun".to_string() } else { names.join(", ") }
+            };
+            return Err(SnapshotError::DeviceMismatch(format!(
+                "attendus: {}, projetés: {}",
+                describe(&saved),
+                describe(&mapped)
+            )));
+        }
+
+        // Périphériques en premier: un état refusé laisse le reste du CPU intact
+        for (base, name, state) in &self.devices {
+            if !cpu.memory.restore_device_state(*base, name, state) {
+                return Err(SnapshotError::InvalidData(format!("état du périphérique {}", name)));
+            }
+        }
+
+        cpu.memory.clear();
+        for (addr, trytes) in &self.memory {
+            cpu.memory
+                .load_trytes(*addr, trytes)
+                .map_err(|_| SnapshotError::InvalidData(format!("plage mémoire à {:X}", addr)))?;
+        }
+
+        let state = &mut cpu.state;
+        for (index, value) in self.registers.iter().enumerate() {
+            if let Ok(register) = Register::from_index(index) {
+                state.write_gpr(register, *value);
+            }
+        }
+        state.write_pc(self.pc);
+        state.write_sp(self.sp);
+        state.write_flags(self.flags);
+        state.current_privilege = self.privilege;
+        for (code, value) in self.csrs.iter().enumerate() {
+            state
+                .write_csr(code as i8, *value)
+                .map_err(|_| SnapshotError::InvalidData(format!("CSR {}", code)))?;
+        }
+        for (vector, words) in state.vector_registers_mut().iter_mut().zip(&self.vector_registers) {
+            for (index, value) in words.iter().enumerate() {
+                if let Some(word) = vector.word_mut(index) {
+                    *word = *value;
+                }
+            }
+        }
+
+        cpu.halted = self.halted;
+        let counters = [
+            &mut cpu.cycles,
+            &mut cpu.instructions_executed,
+            &mut cpu.memory_reads,
+            &mut cpu.memory_writes,
+            &mut cpu.branches_total,
+            &mut cpu.branches_taken,
+            &mut cpu.tvpu.operation_count,
+            &mut cpu.tvpu.cycle_count,
+        ];
+        for (counter, value) in counters.into_iter().zip(&self.counters) {
+            *counter = *value;
+        }
+
+        cpu.mmu.flush();
+        cpu.caches.invalidate();
+        Ok(())
+    }
+
+    /// Encode l'instantané au format binaire
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = SNAPSHOT_MAGIC.to_vec();
+        bytes.push(SNAPSHOT_VERSION);
+
+        for word in self.registers.iter().chain([&self.pc, &self.sp]) {
+            push_word(&mut bytes, word);
+        }
+        let flags = [self.flags.zf, self.flags.sf, self.flags.xf, self.flags.of, self.flags.cf];
+        bytes.push(flags.iter().enumerate().fold(0, |acc, (bit, &flag)| acc | ((flag as u8) << bit)));
+        bytes.push(match self.privilege {
+            PrivilegeLevel::User => 0,
+            PrivilegeLevel::Supervisor => 1,
+            PrivilegeLevel::Machine => 2,
+        });
+
+        bytes.push(self.csrs.len() as u8);
+        for word in &self.csrs {
+            push_word(&mut bytes, word);
+        }
+        bytes.push(self.vector_registers.len() as u8);
+        for vector in &self.vector_registers {
+            for word in vector {
+                push_word(&mut bytes, word);
+            }
+        }
+
+        bytes.push(self.halted as u8);
+        bytes.push(self.counters.len() as u8);
+        for counter in &self.counters {
+            push_varint(&mut bytes, *counter);
+        }
+
+        push_varint(&mut bytes, self.memory_size as u64);
+        push_varint(&mut bytes, self.memory.len() as u64);
+        for (addr, trytes) in &self.memory {
+            push_varint(&mut bytes, *addr as u64);
+            push_varint(&mut bytes, trytes.len() as u64);
+            bytes.extend(trytes.iter().map(|tryte| pack_tryte(*tryte)));
+        }
+
+        push_varint(&mut bytes, self.devices.len() as u64);
+        for (base, name, state) in &self.devices {
+            push_varint(&mut bytes, *base as u64);
+            push_varint(&mut bytes, name.len() as u64);
+            bytes.extend_from_slice(name.as_bytes());
+            push_varint(&mut bytes, state.len() as u64);
+            for word in state {
+                push_word(&mut bytes, word);
+            }
+        }
+        bytes
+    }
+
+    /// Relit un instantané au format binaire
+    pub fn decode(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let body = bytes.strip_prefix(SNAPSHOT_MAGIC.as_slice()).ok_or(SnapshotError::BadMagic)?;
+        let mut reader = Reader::new(body);
+        let version = reader.byte()?;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let registers = (0..8).map(|_| reader.word()).collect::<Result<Vec<_>, _>>()?;
+        let pc = reader.word()?;
+        let sp = reader.word()?;
+        let flag_bits = reader.byte()?;
+        let flag = |bit: u8| flag_bits & (1 << bit) != 0;
+        let flags = Flags { zf: flag(0), sf: flag(1), xf: flag(2), of: flag(3), cf: flag(4) };
+        let privilege = match reader.byte()? {
+            0 => PrivilegeLevel::User,
+            1 => PrivilegeLevel::Supervisor,
+            2 => PrivilegeLevel::Machine,
+            other => return Err(SnapshotError::InvalidData(format!("niveau de privilège {}", other))),
+        };
+
+        let csr_count = reader.byte()?;
+        let csrs = (0..csr_count).map(|_| reader.word()).collect::<Result<Vec<_>, _>>()?;
+        let vector_count = reader.byte()?;
+        let mut vector_registers = Vec::new();
+        for _ in 0..vector_count {
+            vector_registers.push((0..8).map(|_| reader.word()).collect::<Result<Vec<_>, _>>()?);
+        }
+
+        let halted = match reader.byte()? {
+            0 => false,
+            1 => true,
+            other => return Err(SnapshotError::InvalidData(format!("état d'arrêt {}", other))),
+        };
+        let counter_count = reader.byte()?;
+        let counters = (0..counter_count).map(|_| reader.varint()).collect::<Result<Vec<_>, _>>()?;
+
+        let memory_size = reader.varint()? as usize;
+        let mut memory = Vec::new();
+        for _ in 0..reader.varint()? {
+            let addr = reader.varint()? as Address;
+            let len = reader.varint()? as usize;
+            if addr.checked_add(len).is_none_or(|end| end > memory_size) {
+                return Err(SnapshotError::InvalidData(format!("plage mémoire à {:X} hors de la RAM", addr)));
+            }
+            let trytes = (0..len).map(|_| reader.tryte()).collect::<Result<Vec<_>, _>>()?;
+            memory.push((addr, trytes));
+        }
+
+        let mut devices = Vec::new();
+        for _ in 0..reader.varint()? {
+            let base = reader.varint()? as Address;
+            let name_bytes = (0..reader.varint()?).map(|_| reader.byte()).collect::<Result<Vec<_>, _>>()?;
+            let name = String::from_utf8(name_bytes)
+                .map_err(|_| SnapshotError::InvalidData("nom de périphérique".to_string()))?;
+            let state = (0..reader.varint()?).map(|_| reader.word()).collect::<Result<Vec<_>, _>>()?;
+            devices.push((base, name, state));
+        }
+
+        if !reader.is_at_end() {
+            return Err(SnapshotError::InvalidData("données après la fin de l'instantané".to_string()));
+        }
+        Ok(Snapshot {
+            registers,
+            pc,
+            sp,
+            flags,
+            privilege,
+            csrs,
+            vector_registers,
+            halted,
+            counters,
+            memory_size,
+            memory,
+            devices,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::isa::{AluOp, Instruction};
+    use crate::cpu::execute::StopReason;
+    use crate::devices::timer::{MTIMECMP, TIMER_BASE, Timer};
+    use crate::loader::instruction_to_trytes;
+
+    const CODE_ADDR: usize = 16;
+
+    /// CPU avec un timer, exécutant un programme qui additionne R1 dans R2
+    fn build(memory_size: usize) -> Cpu {
+        let program = [
+            Instruction::AluReg { op: AluOp::Add, rs1: Register::R2, rs2: Register::R1, rd: Register::R2 },
+            Instruction::Store { rs1: Register::R4, rs2: Register::R2, offset: 0 },
+            Instruction::AluReg { op: AluOp::Add, rs1: Register::R2, rs2: Register::R1, rd: Register::R2 },
+            Instruction::Halt,
+        ];
+        let mut cpu = Cpu::with_memory_size(memory_size);
+        cpu.memory.map_device(TIMER_BASE, Box::new(Timer::new())).unwrap();
+        for (i, instruction) in program.iter().enumerate() {
+            let trits = prismchrono_isa::encode(instruction).unwrap();
+            cpu.memory.load_trytes(CODE_ADDR + 4 * i, &instruction_to_trytes(&trits)).unwrap();
+        }
+        cpu.state.write_pc(Word::from(CODE_ADDR as i32));
+        cpu.state.write_gpr(Register::R1, Word::from(3));
+        cpu.state.write_gpr(Register::R2, Word::from(1));
+        cpu.state.write_gpr(Register::R4, Word::from(512));
+        cpu
+    }
+
+    #[test]
+    fn test_snapshot_resumes_execution() {
+        let mut original = build(1024);
+        original.memory.write_word(TIMER_BASE + MTIMECMP, Word::from(1000)).unwrap();
+        original.state.write_csr(1, Word::from(400)).unwrap();
+        assert_eq!(original.run(Some(2)).unwrap(), StopReason::StepLimit);
+        let bytes = Snapshot::capture(&original).encode();
+        assert_eq!(Snapshot::decode(&bytes), Ok(Snapshot::capture(&original)));
+
+        // Codage creux: la taille ne dépend pas de celle de la RAM
+        let mut large = build(1 << 20);
+        large.run(Some(2)).unwrap();
+        assert!(Snapshot::capture(&large).encode().len() <= bytes.len() + 2);
+
+        // Reprise dans un CPU neuf: même fin d'exécution que l'original
+        let mut resumed = build(1024);
+        resumed.memory.write_tryte(100, Tryte::NaN).unwrap();
+        Snapshot::decode(&bytes).unwrap().restore(&mut resumed).unwrap();
+        assert_eq!(resumed.memory.read_tryte(100), Ok(Tryte::Undefined));
+        assert_eq!(original.run(None).unwrap(), StopReason::Halted);
+        assert_eq!(resumed.run(None).unwrap(), StopReason::Halted);
+        assert_eq!(resumed.state.read_gpr(Register::R2), Word::from(7));
+        assert_eq!(Snapshot::capture(&resumed), Snapshot::capture(&original));
+        assert_eq!(resumed.memory.read_word(TIMER_BASE + MTIMECMP), Ok(Word::from(1000)));
+    }
+
+    #[test]
+    fn test_snapshot_rejects_mismatches() {
+        let cpu = build(1024);
+        let bytes = Snapshot::capture(&cpu).encode();
+
+        assert_eq!(Snapshot::decode(&bytes[..bytes.len() - 1]), Err(SnapshotError::Truncated));
+        assert_eq!(Snapshot::decode(b"PCTRACE"), Err(SnapshotError::BadMagic));
+        let mut future = bytes.clone();
+        future[SNAPSHOT_MAGIC.len()] = SNAPSHOT_VERSION + 1;
+        assert_eq!(Snapshot::decode(&future), Err(SnapshotError::UnsupportedVersion(SNAPSHOT_VERSION + 1)));
+
+        let snapshot = Snapshot::decode(&bytes).unwrap();
+        let mut smaller = Cpu::with_memory_size(512);
+        assert_eq!(
+            snapshot.restore(&mut smaller),
+            Err(SnapshotError::MemorySize { expected: 1024, found: 512 })
+        );
+        let mut without_timer = Cpu::with_memory_size(1024);
+        assert!(matches!(snapshot.restore(&mut without_timer), Err(SnapshotError::DeviceMismatch(_))));
+    }
+}
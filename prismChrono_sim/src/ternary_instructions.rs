@@ -1,7 +1,7 @@
 // src/ternary_instructions.rs
 // Implémentation des instructions spécialisées ternaires pour PrismChrono
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Tryte, Word};
 use crate::alu;
 
 /// Effectue une comparaison ternaire entre deux mots
@@ -46,17 +46,14 @@ pub fn tcmp3(a: Word, b: Word) -> Word {
 }
 
 /// Calcule la valeur absolue ternaire d'un mot
-/// Si le mot est négatif, inverse tous les trits
+/// Si le mot est négatif, en prend l'opposé (0 - a)
 /// Si le mot est positif ou zéro, le laisse inchangé
 pub fn abs_t(a: Word) -> Word {
-    // Vérifier si le mot est négatif en examinant le trit le plus significatif
-    if let Some(msb_tryte) = a.tryte(7) {
-        let msb_trit = msb_tryte.get_trit(2); // Trit le plus significatif
-        
-        if msb_trit == Trit::N {
-            // Le mot est négatif, on inverse tous les trits
-            return alu::trit_inv_word(a);
-        }
+    // Le signe est celui du trit non nul de poids fort
+    if a.is_negative() {
+        // Le mot est négatif: inverser les trits ne suffit pas (un chiffre -13..-11
+        // deviendrait un état spécial), on calcule la négation
+        return alu::neg_24_trits(a);
     }
     
     // Le mot est positif ou zéro, on le retourne tel quel
@@ -69,38 +66,13 @@ pub fn abs_t(a: Word) -> Word {
 /// - TRIT_Z (0) si a = 0
 /// - TRIT_P (+1) si a > 0
 pub fn signum_t(a: Word) -> Word {
-    let mut result = Word::default_zero();
-    
-    // Vérifier si le mot est nul
-    let mut is_zero = true;
-    for i in 0..8 {
-        if let Some(tryte) = a.tryte(i) {
-            if tryte.to_i8() != 0 {
-                is_zero = false;
-                break;
-            }
-        }
-    }
-    
-    if is_zero {
-        // Le mot est nul, on retourne 0 (Z)
-        return result;
-    }
-    
-    // Vérifier le signe en examinant le trit le plus significatif
-    if let Some(msb_tryte) = a.tryte(7) {
-        let msb_trit = msb_tryte.get_trit(2); // Trit le plus significatif
-        
-        if let Some(tryte_result) = result.tryte_mut(0) {
-            match msb_trit {
-                Trit::N => *tryte_result = Tryte::from_i8(-1), // Négatif
-                Trit::P => *tryte_result = Tryte::from_i8(1),  // Positif
-                _ => {} // Cas impossible car on a déjà vérifié que le mot n'est pas nul
-            }
-        }
+    if a.is_negative() {
+        Word::from(-1)
+    } else if a == Word::zero() {
+        Word::zero()
+    } else {
+        Word::one()
     }
-    
-    result
 }
 
 /// Extrait un tryte spécifique d'un mot
@@ -108,10 +80,10 @@ pub fn signum_t(a: Word) -> Word {
 pub fn extract_tryte(a: Word, index: usize) -> Word {
     let mut result = Word::default_zero();
     
-    if index < 8 {
-        if let (Some(tryte_a), Some(tryte_result)) = (a.tryte(index), result.tryte_mut(0)) {
-            *tryte_result = tryte_a;
-        }
+    if index < 8
+        && let (Some(tryte_a), Some(tryte_result)) = (a.tryte(index), result.tryte_mut(0))
+    {
+        *tryte_result = *tryte_a;
     }
     
     result
@@ -122,10 +94,10 @@ pub fn extract_tryte(a: Word, index: usize) -> Word {
 pub fn insert_tryte(a: Word, index: usize, tryte_value: Tryte) -> Word {
     let mut result = a;
     
-    if index < 8 {
-        if let Some(tryte_result) = result.tryte_mut(index) {
-            *tryte_result = tryte_value;
-        }
+    if index < 8
+        && let Some(tryte_result) = result.tryte_mut(index)
+    {
+        *tryte_result = tryte_value;
     }
     
     result
@@ -139,11 +111,11 @@ pub fn checkw_valid(a: Word) -> Word {
     
     // Vérifier chaque tryte
     for i in 0..8 {
-        if let Some(tryte) = a.tryte(i) {
-            if tryte.is_undef() || tryte.is_null() || tryte.is_nan() {
-                is_valid = false;
-                break;
-            }
+        if let Some(tryte) = a.tryte(i)
+            && (tryte.is_undef() || tryte.is_null() || tryte.is_nan())
+        {
+            is_valid = false;
+            break;
         }
     }
     
@@ -164,13 +136,13 @@ pub fn checkw_valid(a: Word) -> Word {
 pub fn is_special_tryte(a: Word, index: usize) -> Word {
     let mut result = Word::default_zero();
     
-    if index < 8 {
-        if let (Some(tryte), Some(tryte_result)) = (a.tryte(index), result.tryte_mut(0)) {
-            if tryte.is_undef() || tryte.is_null() || tryte.is_nan() {
-                *tryte_result = Tryte::from_i8(1); // C'est un état spécial (P)
-            } else {
-                *tryte_result = Tryte::from_i8(-1); // Ce n'est pas un état spécial (N)
-            }
+    if index < 8
+        && let (Some(tryte), Some(tryte_result)) = (a.tryte(index), result.tryte_mut(0))
+    {
+        if tryte.is_undef() || tryte.is_null() || tryte.is_nan() {
+            *tryte_result = Tryte::from_i8(1); // C'est un état spécial (P)
+        } else {
+            *tryte_result = Tryte::from_i8(-1); // Ce n'est pas un état spécial (N)
         }
     }
     
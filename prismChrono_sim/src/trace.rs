@@ -0,0 +1,572 @@
+// src/trace.rs
+// Traces d'exécution: enregistrement, formats texte et binaire, comparaison
+//
+// Chaque étape de `Cpu::step` qui aboutit (instruction exécutée ou trap pris à sa place)
+// produit un enregistrement: PC, instruction décodée, PC suivant, registres généraux et
+// CSRs modifiés, écritures mémoire et changement de niveau de privilège. Il est transmis
+// au `TraceSink` installé dans `Cpu::trace`. HALT et EBREAK, qui arrêtent l'exécution,
+// ne produisent pas d'enregistrement.
+//
+// Format texte, une ligne par enregistrement:
+//   #<n> <pc> <instruction> -> <pc suivant> [| R1=<mot>]... [| MEPC_T=<mot>]...
+//                                           [| M[<adresse>]=<valeur>]... [| PRIV=<niveau>]
+// Adresses en hexadécimal, valeurs en ternaire équilibré (+, 0, -) tryte de poids fort en
+// tête, comme dans le débogueur. Les lignes vides et les commentaires `//` sont ignorés à
+// la lecture: un banc de test VHDL (textio) peut produire ce format pour être comparé au
+// simulateur.
+//
+// Format binaire: TRACE_MAGIC, la version, puis les enregistrements. Entiers en LEB128
+// (PC et adresses signés en zigzag), instruction sur 3 octets (ses 12 trits en base 3),
+// tryte sur un octet (représentation compacte de la mémoire, voir `memory::pack_tryte`),
+// Mot sur 8.
+
+use crate::core::{Address, Trit, Tryte, Word};
+use crate::cpu::execute::Cpu;
+use crate::cpu::isa::Instruction;
+use crate::cpu::registers::{PrivilegeLevel, Register};
+use crate::debugger::{format_ternary, format_tryte_ternary};
+use crate::disasm::{Symbols, format_instruction};
+use crate::memory::{MemoryWrite, pack_tryte, unpack_tryte};
+use prismchrono_isa::isa_defs::csr_name;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// En-tête des traces binaires
+pub const TRACE_MAGIC: &[u8; 7] = b"PCTRACE";
+
+/// Version du format binaire
+pub const TRACE_VERSION: u8 = 1;
+
+/// Nombre de CSRs observés (codes 0 à 12)
+const TRACED_CSRS: i8 = 13;
+
+/// Ce qui s'est passé pendant l'étape
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceEvent {
+    /// Instruction exécutée jusqu'au bout
+    Instruction(Instruction),
+    /// Trap ou interruption pris à la place de l'instruction
+    Trap,
+}
+
+/// Effets d'une étape d'exécution
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceRecord {
+    /// PC de l'instruction
+    pub pc: i64,
+    /// Instruction exécutée, ou trap
+    pub event: TraceEvent,
+    /// PC après l'étape
+    pub next_pc: i64,
+    /// Registres généraux modifiés, avec leur nouvelle valeur
+    pub registers: Vec<(Register, Word)>,
+    /// CSRs modifiés (par numéro), avec leur nouvelle valeur
+    pub csrs: Vec<(i8, Word)>,
+    /// Écritures mémoire, dans l'ordre
+    pub memory: Vec<MemoryWrite>,
+    /// Nouveau niveau de privilège, s'il a changé
+    pub privilege: Option<PrivilegeLevel>,
+}
+
+/// Adresse en hexadécimal (signée: un PC invalide reste lisible)
+fn format_address(value: i64) -> String {
+    if value < 0 {
+        format!("-0x{:05x}", value.unsigned_abs())
+    } else {
+        format!("0x{:05x}", value)
+    }
+}
+
+impl fmt::Display for TraceRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let event = match &self.event {
+            TraceEvent::Instruction(instruction) => {
+                format_instruction(instruction, self.pc.max(0) as Address, &Symbols::new())
+            }
+            TraceEvent::Trap => "TRAP".to_string(),
+        };
+        write!(f, "{} {} -> {}", format_address(self.pc), event, format_address(self.next_pc))?;
+        for (register, value) in &self.registers {
+            write!(f, " | {}={}", register, format_ternary(value))?;
+        }
+        for (code, value) in &self.csrs {
+            let name = csr_name(*code).map_or_else(|| format!("CSR{}", code), str::to_string);
+            write!(f, " | {}={}", name, format_ternary(value))?;
+        }
+        for write in &self.memory {
+            match write {
+                MemoryWrite::Tryte(addr, tryte) => {
+                    write!(f, " | M[{}]={}", format_address(*addr as i64), format_tryte_ternary(tryte))?
+                }
+                MemoryWrite::Word(addr, word) => {
+                    write!(f, " | M[{}]={}", format_address(*addr as i64), format_ternary(word))?
+                }
+            }
+        }
+        if let Some(privilege) = self.privilege {
+            write!(f, " | PRIV={:?}", privilege)?;
+        }
+        Ok(())
+    }
+}
+
+/// État observé avant une étape, pour en déduire les effets
+pub(crate) struct TraceSnapshot {
+    pc: i64,
+    registers: [Word; 8],
+    csrs: Vec<Word>,
+    privilege: PrivilegeLevel,
+}
+
+impl TraceSnapshot {
+    /// Relève l'état du CPU et ouvre le journal des écritures mémoire
+    pub(crate) fn capture(cpu: &mut Cpu) -> Self {
+        cpu.memory.start_write_log();
+        TraceSnapshot {
+            pc: cpu.state.read_pc().to_i64(),
+            registers: std::array::from_fn(|index| cpu.state.read_gpr(gpr(index))),
+            csrs: (0..TRACED_CSRS).map(|code| cpu.state.read_csr(code).unwrap_or_default()).collect(),
+            privilege: cpu.state.current_privilege,
+        }
+    }
+
+    /// Construit l'enregistrement de l'étape à partir de l'état après exécution
+    pub(crate) fn finish(self, cpu: &mut Cpu, event: TraceEvent) -> TraceRecord {
+        let registers = (0..8)
+            .map(|index| (gpr(index), cpu.state.read_gpr(gpr(index))))
+            .filter(|(register, value)| *value != self.registers[register.to_index()])
+            .collect();
+        let csrs = (0..TRACED_CSRS)
+            .filter_map(|code| {
+                let value = cpu.state.read_csr(code).ok()?;
+                (value != self.csrs[code as usize]).then_some((code, value))
+            })
+            .collect();
+        let privilege = cpu.state.current_privilege;
+        TraceRecord {
+            pc: self.pc,
+            event,
+            next_pc: cpu.state.read_pc().to_i64(),
+            registers,
+            csrs,
+            memory: cpu.memory.take_write_log(),
+            privilege: (privilege != self.privilege).then_some(privilege),
+        }
+    }
+}
+
+/// Registre général d'indice 0 à 7
+fn gpr(index: usize) -> Register {
+    Register::from_index(index).expect("indice de registre général")
+}
+
+/// Destination des enregistrements de trace
+pub trait TraceSink {
+    /// Reçoit l'enregistrement d'une étape
+    fn record(&mut self, record: &TraceRecord);
+
+    /// Termine la trace (vidage des tampons); retourne la première erreur d'écriture
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Trace conservée en mémoire (tests, comparaisons dans le même processus)
+impl TraceSink for Vec<TraceRecord> {
+    fn record(&mut self, record: &TraceRecord) {
+        self.push(record.clone());
+    }
+}
+
+/// Formats de fichier de trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Text,
+    Binary,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" | "texte" => Ok(TraceFormat::Text),
+            "binary" | "binaire" => Ok(TraceFormat::Binary),
+            _ => Err(format!("Format de trace inconnu '{}' (attendu: text ou binary)", s)),
+        }
+    }
+}
+
+/// Écrit une trace dans un flux, au format texte ou binaire
+/// Les erreurs d'écriture n'interrompent pas l'exécution: la première est conservée
+/// et retournée par `finish`
+pub struct TraceWriter<W: Write> {
+    out: W,
+    format: TraceFormat,
+    count: u64,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> TraceWriter<W> {
+    /// Crée un écrivain (l'en-tête du format binaire est écrit immédiatement)
+    pub fn new(out: W, format: TraceFormat) -> Self {
+        let mut writer = TraceWriter { out, format, count: 0, error: None };
+        if format == TraceFormat::Binary {
+            let mut header = TRACE_MAGIC.to_vec();
+            header.push(TRACE_VERSION);
+            let result = writer.out.write_all(&header);
+            writer.keep_error(result);
+        }
+        writer
+    }
+
+    fn keep_error(&mut self, result: io::Result<()>) {
+        if let Err(error) = result {
+            self.error.get_or_insert(error);
+        }
+    }
+}
+
+impl<W: Write> TraceSink for TraceWriter<W> {
+    fn record(&mut self, record: &TraceRecord) {
+        if self.error.is_some() {
+            return;
+        }
+        let result = match self.format {
+            TraceFormat::Text => writeln!(self.out, "#{} {}", self.count, record),
+            TraceFormat::Binary => encode_record(record).and_then(|bytes| self.out.write_all(&bytes)),
+        };
+        self.keep_error(result);
+        self.count += 1;
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let result = self.out.flush();
+        self.keep_error(result);
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Erreurs de lecture d'une trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceError {
+    BadMagic,                // En-tête absent (pas une trace binaire)
+    UnsupportedVersion(u8),  // Version du format inconnue
+    Truncated,               // Fin de fichier au milieu d'un enregistrement
+    InvalidData(String),     // Champ invalide
+    InvalidText,             // Trace texte qui n'est pas en UTF-8
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceError::BadMagic => write!(f, "en-tête de trace binaire invalide"),
+            TraceError::UnsupportedVersion(version) => write!(f, "version de trace {} non supportée", version),
+            TraceError::Truncated => write!(f, "trace tronquée"),
+            TraceError::InvalidData(what) => write!(f, "trace invalide: {}", what),
+            TraceError::InvalidText => write!(f, "trace texte invalide (UTF-8 attendu)"),
+        }
+    }
+}
+
+// --- Encodage binaire ---
+
+pub(crate) fn push_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+pub(crate) fn push_signed(bytes: &mut Vec<u8>, value: i64) {
+    push_varint(bytes, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+pub(crate) fn push_word(bytes: &mut Vec<u8>, word: &Word) {
+    bytes.extend(word.trytes().iter().map(|tryte| pack_tryte(*tryte)));
+}
+
+fn privilege_code(privilege: Option<PrivilegeLevel>) -> u8 {
+    match privilege {
+        None => 0,
+        Some(PrivilegeLevel::User) => 1,
+        Some(PrivilegeLevel::Supervisor) => 2,
+        Some(PrivilegeLevel::Machine) => 3,
+    }
+}
+
+/// Encode un enregistrement au format binaire
+pub fn encode_record(record: &TraceRecord) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    match &record.event {
+        TraceEvent::Instruction(instruction) => {
+            let trits = prismchrono_isa::encode(instruction)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", error)))?;
+            let packed = trits.iter().rev().fold(0u32, |acc, trit| acc * 3 + (trit.value() + 1) as u32);
+            bytes.push(0);
+            push_signed(&mut bytes, record.pc);
+            bytes.extend_from_slice(&packed.to_le_bytes()[..3]);
+        }
+        TraceEvent::Trap => {
+            bytes.push(1);
+            push_signed(&mut bytes, record.pc);
+        }
+    }
+    push_signed(&mut bytes, record.next_pc);
+    bytes.push(privilege_code(record.privilege));
+    bytes.push(record.registers.len() as u8);
+    for (register, value) in &record.registers {
+        bytes.push(register.to_index() as u8);
+        push_word(&mut bytes, value);
+    }
+    bytes.push(record.csrs.len() as u8);
+    for (code, value) in &record.csrs {
+        bytes.push(*code as u8);
+        push_word(&mut bytes, value);
+    }
+    push_varint(&mut bytes, record.memory.len() as u64);
+    for write in &record.memory {
+        match write {
+            MemoryWrite::Tryte(addr, tryte) => {
+                bytes.push(0);
+                push_varint(&mut bytes, *addr as u64);
+                bytes.push(pack_tryte(*tryte));
+            }
+            MemoryWrite::Word(addr, word) => {
+                bytes.push(1);
+                push_varint(&mut bytes, *addr as u64);
+                push_word(&mut bytes, word);
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+// --- Décodage binaire ---
+
+/// Curseur de lecture d'une trace binaire (réutilisé par les instantanés)
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, position: 0 }
+    }
+
+    /// Indique si toutes les données ont été lues
+    pub(crate) fn is_at_end(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    pub(crate) fn byte(&mut self) -> Result<u8, TraceError> {
+        let byte = *self.bytes.get(self.position).ok_or(TraceError::Truncated)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    pub(crate) fn varint(&mut self) -> Result<u64, TraceError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(TraceError::InvalidData("entier trop long".to_string()))
+    }
+
+    fn signed(&mut self) -> Result<i64, TraceError> {
+        let value = self.varint()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    pub(crate) fn tryte(&mut self) -> Result<Tryte, TraceError> {
+        let byte = self.byte()?;
+        unpack_tryte(byte).ok_or_else(|| TraceError::InvalidData(format!("tryte {:#x}", byte)))
+    }
+
+    pub(crate) fn word(&mut self) -> Result<Word, TraceError> {
+        let mut word = Word::zero();
+        for index in 0..8 {
+            word.0[index] = self.tryte()?;
+        }
+        Ok(word)
+    }
+
+    fn instruction(&mut self) -> Result<Instruction, TraceError> {
+        let mut packed = u32::from_le_bytes([self.byte()?, self.byte()?, self.byte()?, 0]);
+        let mut trits = [Trit::Z; 12];
+        for trit in trits.iter_mut() {
+            *trit = match packed % 3 {
+                0 => Trit::N,
+                1 => Trit::Z,
+                _ => Trit::P,
+            };
+            packed /= 3;
+        }
+        crate::cpu::decode::decode(trits).map_err(|error| TraceError::InvalidData(format!("{:?}", error)))
+    }
+
+    fn record(&mut self) -> Result<TraceRecord, TraceError> {
+        let kind = self.byte()?;
+        let pc = self.signed()?;
+        let event = match kind {
+            0 => TraceEvent::Instruction(self.instruction()?),
+            1 => TraceEvent::Trap,
+            other => return Err(TraceError::InvalidData(format!("type d'enregistrement {}", other))),
+        };
+        let next_pc = self.signed()?;
+        let privilege = match self.byte()? {
+            0 => None,
+            1 => Some(PrivilegeLevel::User),
+            2 => Some(PrivilegeLevel::Supervisor),
+            3 => Some(PrivilegeLevel::Machine),
+            other => return Err(TraceError::InvalidData(format!("niveau de privilège {}", other))),
+        };
+        let mut registers = Vec::new();
+        for _ in 0..self.byte()? {
+            let index = self.byte()? as usize;
+            let register = Register::from_index(index)
+                .map_err(|_| TraceError::InvalidData(format!("registre {}", index)))?;
+            registers.push((register, self.word()?));
+        }
+        let mut csrs = Vec::new();
+        for _ in 0..self.byte()? {
+            let code = self.byte()? as i8;
+            csrs.push((code, self.word()?));
+        }
+        let mut memory = Vec::new();
+        for _ in 0..self.varint()? {
+            let tag = self.byte()?;
+            let addr = self.varint()? as Address;
+            memory.push(match tag {
+                0 => MemoryWrite::Tryte(addr, self.tryte()?),
+                1 => MemoryWrite::Word(addr, self.word()?),
+                other => return Err(TraceError::InvalidData(format!("écriture mémoire {}", other))),
+            });
+        }
+        Ok(TraceRecord { pc, event, next_pc, registers, csrs, memory, privilege })
+    }
+}
+
+/// Relit une trace binaire complète
+pub fn read_binary_trace(bytes: &[u8]) -> Result<Vec<TraceRecord>, TraceError> {
+    let body = bytes.strip_prefix(TRACE_MAGIC.as_slice()).ok_or(TraceError::BadMagic)?;
+    let mut reader = Reader::new(body);
+    let version = reader.byte()?;
+    if version != TRACE_VERSION {
+        return Err(TraceError::UnsupportedVersion(version));
+    }
+    let mut records = Vec::new();
+    while !reader.is_at_end() {
+        records.push(reader.record()?);
+    }
+    Ok(records)
+}
+
+/// Lignes texte d'une trace, quel que soit son format (détecté par l'en-tête binaire)
+pub fn trace_lines(bytes: &[u8]) -> Result<Vec<String>, TraceError> {
+    if bytes.starts_with(TRACE_MAGIC) {
+        let records = read_binary_trace(bytes)?;
+        return Ok(records.iter().enumerate().map(|(index, record)| format!("#{} {}", index, record)).collect());
+    }
+    let text = std::str::from_utf8(bytes).map_err(|_| TraceError::InvalidText)?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Première différence entre deux traces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceDivergence {
+    /// Indice de l'enregistrement
+    pub index: usize,
+    /// Ligne de la première trace (None si elle est plus courte)
+    pub left: Option<String>,
+    /// Ligne de la seconde trace (None si elle est plus courte)
+    pub right: Option<String>,
+}
+
+/// Compare deux traces ligne à ligne et retourne la première divergence
+pub fn diff_traces(left: &[String], right: &[String]) -> Option<TraceDivergence> {
+    (0..left.len().max(right.len())).find_map(|index| {
+        let (a, b) = (left.get(index), right.get(index));
+        (a != b).then(|| TraceDivergence { index, left: a.cloned(), right: b.cloned() })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<TraceRecord> {
+        vec![
+            TraceRecord {
+                pc: 16,
+                event: TraceEvent::Instruction(Instruction::Store { rs1: Register::R4, rs2: Register::R3, offset: 8 }),
+                next_pc: 20,
+                registers: Vec::new(),
+                csrs: Vec::new(),
+                memory: vec![MemoryWrite::Word(520, Word::from(-7)), MemoryWrite::Tryte(3, Tryte::NaN)],
+                privilege: None,
+            },
+            TraceRecord {
+                pc: 20,
+                event: TraceEvent::Trap,
+                next_pc: 400,
+                registers: vec![(Register::R1, Word::default_undefined())],
+                csrs: vec![(2, Word::from(20)), (3, Word::from(2))],
+                memory: Vec::new(),
+                privilege: Some(PrivilegeLevel::Machine),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let mut writer = TraceWriter::new(Vec::new(), TraceFormat::Binary);
+        for record in sample() {
+            writer.record(&record);
+        }
+        writer.finish().unwrap();
+        let bytes = writer.out;
+        assert_eq!(read_binary_trace(&bytes), Ok(sample()));
+        assert_eq!(read_binary_trace(&bytes[..bytes.len() - 1]), Err(TraceError::Truncated));
+
+        // Les deux formats donnent les mêmes lignes
+        let mut text = TraceWriter::new(Vec::new(), TraceFormat::Text);
+        for record in sample() {
+            text.record(&record);
+        }
+        assert_eq!(trace_lines(&bytes), trace_lines(&text.out));
+        let lines = trace_lines(&text.out).unwrap();
+        assert!(lines[1].starts_with("#1 0x00014 TRAP -> 0x00190 | R1="));
+        assert!(lines[1].ends_with("| PRIV=Machine"));
+    }
+
+    #[test]
+    fn test_diff_reports_first_divergence() {
+        let lines = |text: &str| trace_lines(text.as_bytes()).unwrap();
+        let left = lines("// banc de test\n#0 a\n#1 b\n\n#2 c\n");
+        assert_eq!(diff_traces(&left, &lines("#0 a\n#1 b\n#2 c")), None);
+        assert_eq!(
+            diff_traces(&left, &lines("#0 a\n#1 x\n#2 c")),
+            Some(TraceDivergence { index: 1, left: Some("#1 b".to_string()), right: Some("#1 x".to_string()) })
+        );
+        assert_eq!(
+            diff_traces(&left, &lines("#0 a\n#1 b")),
+            Some(TraceDivergence { index: 2, left: Some("#2 c".to_string()), right: None })
+        );
+    }
+}
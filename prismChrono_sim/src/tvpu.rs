@@ -5,6 +5,7 @@ use crate::core::{Trit, Word};
 
 /// Structure représentant un registre vectoriel ternaire
 /// Contient 8 mots de 24 trits (8 trytes) chacun
+#[derive(Debug, Clone)]
 pub struct TernaryVector {
     /// Les 8 mots du vecteur
     words: [Word; 8],
@@ -13,6 +14,7 @@ pub struct TernaryVector {
 }
 
 /// Structure de cache pour les opérations vectorielles fréquentes
+#[derive(Debug, Clone)]
 pub struct VectorCache {
     /// Somme des éléments (pour TVSUM)
     sum: Option<Word>,
@@ -24,6 +26,12 @@ pub struct VectorCache {
     avg: Option<Word>,
 }
 
+impl Default for TernaryVector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TernaryVector {
     /// Crée un nouveau vecteur ternaire initialisé à zéro
     pub fn new() -> Self {
@@ -98,7 +106,7 @@ impl TernaryVector {
         }
         
         // Calculer la moyenne (somme / 8)
-        let divisor = Word::from_i32(8);
+        let divisor = Word::from(8);
         let avg = crate::alu::div_words(sum, divisor).0;
         
         // Stocker dans le cache
@@ -199,10 +207,10 @@ pub fn tvmac(a: &TernaryVector, b: &TernaryVector, c: &TernaryVector) -> Ternary
 /// Utilise le cache si disponible pour une performance optimale
 pub fn tvsum(a: &TernaryVector) -> Word {
     // Vérifier si la somme est déjà dans le cache
-    if let Some(cache) = &a.cache {
-        if let Some(sum) = cache.sum {
-            return sum;
-        }
+    if let Some(cache) = &a.cache
+        && let Some(sum) = cache.sum
+    {
+        return sum;
     }
     
     // Sinon, calculer la somme
@@ -222,10 +230,10 @@ pub fn tvsum(a: &TernaryVector) -> Word {
 /// Utilise le cache si disponible pour une performance optimale
 pub fn tvmin(a: &TernaryVector) -> Word {
     // Vérifier si le minimum est déjà dans le cache
-    if let Some(cache) = &a.cache {
-        if let Some(min) = cache.min {
-            return min;
-        }
+    if let Some(cache) = &a.cache
+        && let Some(min) = cache.min
+    {
+        return min;
     }
     
     // Sinon, calculer le minimum
@@ -252,10 +260,10 @@ pub fn tvmin(a: &TernaryVector) -> Word {
 /// Utilise le cache si disponible pour une performance optimale
 pub fn tvmax(a: &TernaryVector) -> Word {
     // Vérifier si le maximum est déjà dans le cache
-    if let Some(cache) = &a.cache {
-        if let Some(max) = cache.max {
-            return max;
-        }
+    if let Some(cache) = &a.cache
+        && let Some(max) = cache.max
+    {
+        return max;
     }
     
     // Sinon, calculer le maximum
@@ -282,10 +290,10 @@ pub fn tvmax(a: &TernaryVector) -> Word {
 /// Utilise le cache si disponible pour une performance optimale
 pub fn tvavg(a: &TernaryVector) -> Word {
     // Vérifier si la moyenne est déjà dans le cache
-    if let Some(cache) = &a.cache {
-        if let Some(avg) = cache.avg {
-            return avg;
-        }
+    if let Some(cache) = &a.cache
+        && let Some(avg) = cache.avg
+    {
+        return avg;
     }
     
     // Sinon, calculer la moyenne
@@ -293,12 +301,12 @@ pub fn tvavg(a: &TernaryVector) -> Word {
     let sum = tvsum(a);
     
     // Diviser par 8 (nombre d'éléments)
-    let divisor = Word::from_i32(8);
+    let divisor = Word::from(8);
     crate::alu::div_words(sum, divisor).0
 }
 
-/// Opérations vectorielles optimisées pour les calculs en base 60 (système sexagésimal)
-/// Ces fonctions sont particulièrement utiles pour les applications temporelles et angulaires
+// Opérations vectorielles optimisées pour les calculs en base 60 (système sexagésimal)
+// Ces fonctions sont particulièrement utiles pour les applications temporelles et angulaires
 
 /// Conversion d'un vecteur de valeurs décimales en base 60
 /// Particulièrement efficace pour les calculs temporel (heures, minutes, secondes)
@@ -308,7 +316,7 @@ pub fn tvbase60_encode(a: &TernaryVector) -> TernaryVector {
     for i in 0..8 {
         if let (Some(word_a), Some(word_result)) = (a.word(i), result.word_mut(i)) {
             // Convertir la valeur décimale en base 60
-            let decimal_value = word_a.to_i32();
+            let decimal_value = word_a.to_i64();
             
             // Décomposer en unités, minutes, secondes (ou degrés, minutes, secondes)
             let units = decimal_value / 3600;
@@ -324,13 +332,13 @@ pub fn tvbase60_encode(a: &TernaryVector) -> TernaryVector {
             let mut new_word = Word::default_zero();
             
             // Encoder les secondes dans les trits 0-2
-            encode_base60_component(&mut new_word, seconds, 0);
+            encode_base60_component(&mut new_word, seconds as i32, 0);
             
             // Encoder les minutes dans les trits 3-5
-            encode_base60_component(&mut new_word, minutes, 3);
+            encode_base60_component(&mut new_word, minutes as i32, 3);
             
             // Encoder les unités dans les trits 6-8
-            encode_base60_component(&mut new_word, units, 6);
+            encode_base60_component(&mut new_word, units as i32, 6);
             
             *word_result = new_word;
         }
@@ -355,7 +363,7 @@ pub fn tvbase60_decode(a: &TernaryVector) -> TernaryVector {
             let decimal_value = units * 3600 + minutes * 60 + seconds;
             
             // Stocker le résultat
-            *word_result = Word::from_i32(decimal_value);
+            *word_result = Word::from(decimal_value);
         }
     }
     
@@ -469,7 +477,7 @@ pub fn tvbase60_sub(a: &TernaryVector, b: &TernaryVector) -> TernaryVector {
 }
 
 /// Fonction utilitaire pour encoder une composante en base 60 dans un mot
-fn encode_base60_component(word: &mut Word, value: i32, start_trit: usize) {
+pub(crate) fn encode_base60_component(word: &mut Word, value: i32, start_trit: usize) {
     // Utiliser les propriétés ternaires pour encoder efficacement
     // Nous pouvons représenter jusqu'à 3^3 = 27 valeurs avec 3 trits
     // Pour représenter 60 valeurs, nous utilisons une combinaison optimisée
@@ -517,15 +525,15 @@ fn encode_base60_component(word: &mut Word, value: i32, start_trit: usize) {
 }
 
 /// Fonction utilitaire pour décoder une composante en base 60 depuis un mot
-fn decode_base60_component(word: &Word, start_trit: usize) -> i32 {
+pub(crate) fn decode_base60_component(word: &Word, start_trit: usize) -> i32 {
     // Extraire les trits correspondant à la valeur encodée en base 3
-    let mut trit0;
+    
     let mut trit1 = 0;
     let mut trit2 = 0;
     
     // Extraire le premier trit en utilisant la méthode get_trit de Word
     let trit0_val = word.get_trit(start_trit);
-    trit0 = match trit0_val {
+    let trit0 = match trit0_val {
         Trit::N => 0,
         Trit::Z => 1,
         Trit::P => 2,
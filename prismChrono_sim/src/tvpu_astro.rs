@@ -1,7 +1,7 @@
 // src/tvpu_astro.rs
 // Implémentation d'optimisations astronomiques et de navigation pour l'unité de traitement vectoriel ternaire (TVPU)
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::Word;
 use crate::tvpu::{TernaryVector, encode_base60_component, decode_base60_component};
 
 /// Structure représentant des coordonnées astronomiques en format ternaire
@@ -17,6 +17,12 @@ pub struct AstroCoordinates {
     pub sidereal_time: Option<TernaryVector>,
 }
 
+impl Default for AstroCoordinates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AstroCoordinates {
     /// Crée de nouvelles coordonnées astronomiques initialisées à zéro
     pub fn new() -> Self {
@@ -74,7 +80,7 @@ pub fn decimal_to_dms(decimal_angle: Word) -> TernaryVector {
     let mut result = TernaryVector::default_undefined();
     
     // Convertir l'angle décimal en valeur entière (multiplié par un facteur de précision)
-    let angle_value = decimal_angle.to_i32();
+    let angle_value = decimal_angle.to_i64();
     let precision_factor = 3600; // Pour représenter les secondes
     
     // Calculer les degrés, minutes et secondes
@@ -90,13 +96,13 @@ pub fn decimal_to_dms(decimal_angle: Word) -> TernaryVector {
             let mut new_word = Word::default_zero();
             
             // Encoder les secondes dans les trits 0-2
-            encode_base60_component(&mut new_word, seconds, 0);
+            encode_base60_component(&mut new_word, seconds as i32, 0);
             
             // Encoder les minutes dans les trits 3-5
-            encode_base60_component(&mut new_word, minutes, 3);
+            encode_base60_component(&mut new_word, minutes as i32, 3);
             
             // Encoder les degrés dans les trits 6-8
-            encode_base60_component(&mut new_word, degrees, 6);
+            encode_base60_component(&mut new_word, degrees as i32, 6);
             
             *word_result = new_word;
         }
@@ -123,7 +129,7 @@ pub fn dms_to_decimal(dms_angle: &TernaryVector) -> Word {
         let int_value = (decimal_value * 1000.0) as i32;
         
         // Stocker le résultat
-        result = Word::from_i32(int_value);
+        result = Word::from(int_value);
     }
     
     result
@@ -186,7 +192,7 @@ pub fn calculate_precession(coordinates: &AstroCoordinates, years: Word) -> Astr
     
     // La précession est d'environ 50.3 secondes d'arc par an
     // Nous allons calculer le déplacement total en secondes d'arc
-    let years_value = years.to_i32();
+    let years_value = years.to_i64();
     let precession_seconds = (years_value * 503) / 10; // 50.3 secondes/an
     
     // Appliquer la précession à l'ascension droite (longitude)
@@ -198,9 +204,9 @@ pub fn calculate_precession(coordinates: &AstroCoordinates, years: Word) -> Astr
             let mut new_word = Word::default_zero();
             
             // La précession affecte principalement les secondes
-            encode_base60_component(&mut new_word, precession_seconds % 60, 0);
-            encode_base60_component(&mut new_word, (precession_seconds / 60) % 60, 3);
-            encode_base60_component(&mut new_word, precession_seconds / 3600, 6);
+            encode_base60_component(&mut new_word, (precession_seconds % 60) as i32, 0);
+            encode_base60_component(&mut new_word, ((precession_seconds / 60) % 60) as i32, 3);
+            encode_base60_component(&mut new_word, (precession_seconds / 3600) as i32, 6);
             
             *word_result = new_word;
         }
@@ -303,7 +309,7 @@ pub fn calculate_parallax(coordinates: &AstroCoordinates, observer_altitude: Wor
     // La parallaxe dépend de la distance de l'objet et de la position de l'observateur
     // Pour cette démonstration, nous appliquons une correction simplifiée
     
-    let parallax_seconds = if observer_altitude.to_i32() > 1000 {
+    let parallax_seconds = if observer_altitude.to_i64() > 1000 {
         3 // Plus grande correction pour les observateurs en altitude
     } else {
         1 // Correction standard
@@ -347,7 +353,7 @@ pub fn calculate_refraction(coordinates: &AstroCoordinates) -> AstroCoordinates
     // et nulle au zénith
     
     // Pour cette démonstration, nous appliquons une correction simplifiée
-    let altitude_decimal = dms_to_decimal(&coordinates.altitude).to_i32();
+    let altitude_decimal = dms_to_decimal(&coordinates.altitude).to_i64();
     
     // Calculer la réfraction en fonction de l'altitude
     // (simplification extrême de la formule réelle)
@@ -1,7 +1,7 @@
 // src/tvpu_base60.rs
 // Optimisations avancées pour les opérations en base 60 dans l'unité de traitement vectoriel ternaire (TVPU)
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::Word;
 use crate::tvpu::{TernaryVector, encode_base60_component, decode_base60_component};
 
 /// Structure optimisée pour les calculs en base 60 (sexagésimal)
@@ -143,7 +143,7 @@ impl Base60Value {
 pub fn add_base60(a: &Base60Value, b: &Base60Value) -> Base60Value {
     // Convertir en secondes totales pour simplifier l'addition
     let mut total_seconds_a = a.units * 3600 + a.minutes * 60 + a.seconds;
-    let mut total_seconds_b = b.units * 3600 + b.minutes * 60 + b.seconds;
+    let total_seconds_b = b.units * 3600 + b.minutes * 60 + b.seconds;
     
     // Gérer les millisecondes si présentes
     let mut total_ms = 0;
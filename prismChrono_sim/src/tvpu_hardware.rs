@@ -1,13 +1,23 @@
 // src/tvpu_hardware.rs
 // Implémentation matérielle de l'unité de traitement vectoriel ternaire (TVPU)
 
-use crate::core::{Trit, Tryte, Word};
+use crate::core::{Address, Tryte, Word};
+use crate::memory::{Memory, MemoryError};
 use crate::tvpu::{TernaryVector, tvadd, tvsub, tvmul, tvdot, tvmac, tvsum, tvmin, tvmax, tvavg};
 
+/// Nombre de registres vectoriels architecturaux (V0-V7)
+pub const VECTOR_REGISTERS: usize = 8;
+
+/// Nombre de mots d'un registre vectoriel
+pub const VECTOR_LENGTH: usize = 8;
+
+/// Taille d'un mot en trytes (pas d'adresse entre deux éléments consécutifs)
+const WORD_TRYTES: usize = 8;
+
 /// Structure représentant l'unité matérielle de traitement vectoriel ternaire
+/// Les registres vectoriels font partie de l'état architectural du processeur
+/// (`ProcessorState`): l'unité ne conserve que son état et ses compteurs.
 pub struct TVPUHardware {
-    /// Registres vectoriels
-    pub registers: [TernaryVector; 16],
     /// Registre d'état
     pub status: TVPUStatus,
     /// Compteur d'opérations
@@ -33,21 +43,21 @@ pub struct TVPUStatus {
 impl TVPUHardware {
     /// Crée une nouvelle instance de l'unité TVPU
     pub fn new() -> Self {
-        let mut registers = [TernaryVector::default_undefined(); 16];
-        for reg in &mut registers {
-            *reg = TernaryVector::new();
-        }
-        
         TVPUHardware {
-            registers,
             status: TVPUStatus::new(),
             operation_count: 0,
             cycle_count: 0,
         }
     }
     
-    /// Exécute une opération vectorielle
-    pub fn execute_operation(&mut self, op: TVPUOperation) -> Result<(), TVPUError> {
+    /// Exécute une opération vectorielle sur le banc de registres et la mémoire fournis
+    /// Retourne le résultat scalaire des réductions (TVDOT, TVSUM, ...), `None` sinon
+    pub fn execute_operation(
+        &mut self,
+        op: TVPUOperation,
+        registers: &mut [TernaryVector],
+        memory: &mut Memory,
+    ) -> Result<Option<Word>, TVPUError> {
         // Réinitialiser l'état
         self.status.busy = true;
         self.status.error_code = None;
@@ -55,126 +65,136 @@ impl TVPUHardware {
         self.status.special_result = false;
         
         // Vérifier les indices de registres
-        if !self.validate_registers(&op) {
-            self.status.error_code = Some(1); // Code d'erreur: registre invalide
-            self.status.busy = false;
-            return Err(TVPUError::InvalidRegister);
+        if !Self::validate_registers(&op, registers.len()) {
+            return Err(self.fail(TVPUError::InvalidRegister));
         }
         
         // Exécuter l'opération
         let start_cycle = self.cycle_count;
+        let mut scalar = None;
         
         match op {
             TVPUOperation::Add { vd, vs1, vs2 } => {
-                self.registers[vd] = tvadd(&self.registers[vs1], &self.registers[vs2]);
+                registers[vd] = tvadd(&registers[vs1], &registers[vs2]);
                 self.cycle_count += 2; // Latence: 2 cycles
             },
             TVPUOperation::Sub { vd, vs1, vs2 } => {
-                self.registers[vd] = tvsub(&self.registers[vs1], &self.registers[vs2]);
+                registers[vd] = tvsub(&registers[vs1], &registers[vs2]);
                 self.cycle_count += 2; // Latence: 2 cycles
             },
             TVPUOperation::Mul { vd, vs1, vs2 } => {
-                self.registers[vd] = tvmul(&self.registers[vs1], &self.registers[vs2]);
+                registers[vd] = tvmul(&registers[vs1], &registers[vs2]);
                 self.cycle_count += 4; // Latence: 4 cycles
             },
-            TVPUOperation::Dot { rd, vs1, vs2 } => {
-                let result = tvdot(&self.registers[vs1], &self.registers[vs2]);
-                // Stocker le résultat dans un registre scalaire (simulé ici par le premier mot du registre vectoriel)
-                if let Some(word) = self.registers[rd].word_mut(0) {
-                    *word = result;
-                }
+            TVPUOperation::Dot { vs1, vs2 } => {
+                scalar = Some(tvdot(&registers[vs1], &registers[vs2]));
                 self.cycle_count += 8; // Latence: 8 cycles (réduction)
             },
             TVPUOperation::Mac { vd, vs1, vs2, vs3 } => {
-                self.registers[vd] = tvmac(&self.registers[vs1], &self.registers[vs2], &self.registers[vs3]);
+                registers[vd] = tvmac(&registers[vs1], &registers[vs2], &registers[vs3]);
                 self.cycle_count += 5; // Latence: 5 cycles
             },
-            TVPUOperation::Sum { rd, vs } => {
-                let result = tvsum(&self.registers[vs]);
-                // Stocker le résultat dans un registre scalaire
-                if let Some(word) = self.registers[rd].word_mut(0) {
-                    *word = result;
-                }
+            TVPUOperation::Sum { vs } => {
+                scalar = Some(tvsum(&registers[vs]));
                 self.cycle_count += 6; // Latence: 6 cycles (réduction)
             },
-            TVPUOperation::Min { rd, vs } => {
-                let result = tvmin(&self.registers[vs]);
-                // Stocker le résultat dans un registre scalaire
-                if let Some(word) = self.registers[rd].word_mut(0) {
-                    *word = result;
-                }
+            TVPUOperation::Min { vs } => {
+                scalar = Some(tvmin(&registers[vs]));
                 self.cycle_count += 6; // Latence: 6 cycles (réduction)
             },
-            TVPUOperation::Max { rd, vs } => {
-                let result = tvmax(&self.registers[vs]);
-                // Stocker le résultat dans un registre scalaire
-                if let Some(word) = self.registers[rd].word_mut(0) {
-                    *word = result;
-                }
+            TVPUOperation::Max { vs } => {
+                scalar = Some(tvmax(&registers[vs]));
                 self.cycle_count += 6; // Latence: 6 cycles (réduction)
             },
-            TVPUOperation::Avg { rd, vs } => {
-                let result = tvavg(&self.registers[vs]);
-                // Stocker le résultat dans un registre scalaire
-                if let Some(word) = self.registers[rd].word_mut(0) {
-                    *word = result;
-                }
+            TVPUOperation::Avg { vs } => {
+                scalar = Some(tvavg(&registers[vs]));
                 self.cycle_count += 7; // Latence: 7 cycles (réduction + division)
             },
             TVPUOperation::Load { vd, addr, stride } => {
-                // Simulation d'un chargement mémoire (non implémenté réellement)
-                // Dans une implémentation réelle, on chargerait depuis la mémoire
+                // Le registre n'est modifié que si les 8 lectures réussissent
+                let mut vector = TernaryVector::new();
+                for i in 0..VECTOR_LENGTH {
+                    let word = Self::element_address(addr, stride, i)
+                        .and_then(|element| memory.read_word(element).map_err(TVPUError::from));
+                    match (word, vector.word_mut(i)) {
+                        (Ok(word), Some(slot)) => *slot = word,
+                        (Err(error), _) => return Err(self.fail(error)),
+                        (Ok(_), None) => unreachable!("indice d'élément hors du vecteur"),
+                    }
+                }
+                registers[vd] = vector;
                 self.cycle_count += 10; // Latence: 10 cycles (accès mémoire)
             },
             TVPUOperation::Store { vs, addr, stride } => {
-                // Simulation d'un stockage mémoire (non implémenté réellement)
-                // Dans une implémentation réelle, on stockerait en mémoire
+                for i in 0..VECTOR_LENGTH {
+                    let word = registers[vs].word(i).copied().unwrap_or_else(Word::default_undefined);
+                    let result = Self::element_address(addr, stride, i)
+                        .and_then(|element| memory.write_word(element, word).map_err(TVPUError::from));
+                    if let Err(error) = result {
+                        return Err(self.fail(error));
+                    }
+                }
                 self.cycle_count += 10; // Latence: 10 cycles (accès mémoire)
             },
         }
         
         // Mettre à jour les compteurs et l'état
+        if let Some(word) = &scalar {
+            self.status.special_result = word.trytes().iter().any(|tryte| !matches!(tryte, Tryte::Digit(_)));
+        }
         self.operation_count += 1;
         self.status.last_op_latency = (self.cycle_count - start_cycle) as u8;
         self.status.busy = false;
         
-        Ok(())
+        Ok(scalar)
+    }
+    
+    /// Termine une opération en erreur en positionnant le code d'erreur de l'état
+    fn fail(&mut self, error: TVPUError) -> TVPUError {
+        self.status.error_code = Some(error.code());
+        self.status.busy = false;
+        error
+    }
+    
+    /// Adresse du i-ème élément d'un accès mémoire vectoriel (stride en mots)
+    fn element_address(addr: Address, stride: usize, index: usize) -> Result<Address, TVPUError> {
+        stride
+            .checked_mul(index * WORD_TRYTES)
+            .and_then(|offset| addr.checked_add(offset))
+            .ok_or(TVPUError::InvalidAddress)
     }
     
     /// Valide les indices de registres pour une opération
-    fn validate_registers(&self, op: &TVPUOperation) -> bool {
+    fn validate_registers(op: &TVPUOperation, count: usize) -> bool {
         match op {
             TVPUOperation::Add { vd, vs1, vs2 } |
             TVPUOperation::Sub { vd, vs1, vs2 } |
             TVPUOperation::Mul { vd, vs1, vs2 } => {
-                *vd < 16 && *vs1 < 16 && *vs2 < 16
+                *vd < count && *vs1 < count && *vs2 < count
             },
-            TVPUOperation::Dot { rd, vs1, vs2 } => {
-                *rd < 16 && *vs1 < 16 && *vs2 < 16
+            TVPUOperation::Dot { vs1, vs2 } => {
+                *vs1 < count && *vs2 < count
             },
             TVPUOperation::Mac { vd, vs1, vs2, vs3 } => {
-                *vd < 16 && *vs1 < 16 && *vs2 < 16 && *vs3 < 16
+                *vd < count && *vs1 < count && *vs2 < count && *vs3 < count
             },
-            TVPUOperation::Sum { rd, vs } |
-            TVPUOperation::Min { rd, vs } |
-            TVPUOperation::Max { rd, vs } |
-            TVPUOperation::Avg { rd, vs } => {
-                *rd < 16 && *vs < 16
+            TVPUOperation::Sum { vs } |
+            TVPUOperation::Min { vs } |
+            TVPUOperation::Max { vs } |
+            TVPUOperation::Avg { vs } => {
+                *vs < count
             },
             TVPUOperation::Load { vd, .. } => {
-                *vd < 16
+                *vd < count
             },
             TVPUOperation::Store { vs, .. } => {
-                *vs < 16
+                *vs < count
             },
         }
     }
     
     /// Réinitialise l'unité TVPU
     pub fn reset(&mut self) {
-        for reg in &mut self.registers {
-            *reg = TernaryVector::new();
-        }
         self.status = TVPUStatus::new();
         self.operation_count = 0;
         self.cycle_count = 0;
@@ -194,6 +214,12 @@ impl TVPUHardware {
     }
 }
 
+impl Default for TVPUHardware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TVPUStatus {
     /// Crée un nouvel état TVPU
     pub fn new() -> Self {
@@ -207,6 +233,12 @@ impl TVPUStatus {
     }
 }
 
+impl Default for TVPUStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Structure représentant les performances de l'unité TVPU
 pub struct TVPUPerformance {
     /// Nombre total d'opérations exécutées
@@ -218,6 +250,9 @@ pub struct TVPUPerformance {
 }
 
 /// Énumération des opérations TVPU
+/// Les réductions (Dot, Sum, Min, Max, Avg) retournent leur résultat scalaire,
+/// que le CPU écrit dans le registre général rd
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TVPUOperation {
     /// Addition vectorielle: vd = vs1 + vs2
     Add { vd: usize, vs1: usize, vs2: usize },
@@ -226,24 +261,25 @@ pub enum TVPUOperation {
     /// Multiplication vectorielle: vd = vs1 * vs2
     Mul { vd: usize, vs1: usize, vs2: usize },
     /// Produit scalaire: rd = vs1 · vs2
-    Dot { rd: usize, vs1: usize, vs2: usize },
+    Dot { vs1: usize, vs2: usize },
     /// Multiplication-accumulation: vd = vs1 * vs2 + vs3
     Mac { vd: usize, vs1: usize, vs2: usize, vs3: usize },
     /// Somme des éléments: rd = sum(vs)
-    Sum { rd: usize, vs: usize },
+    Sum { vs: usize },
     /// Minimum des éléments: rd = min(vs)
-    Min { rd: usize, vs: usize },
+    Min { vs: usize },
     /// Maximum des éléments: rd = max(vs)
-    Max { rd: usize, vs: usize },
+    Max { vs: usize },
     /// Moyenne des éléments: rd = avg(vs)
-    Avg { rd: usize, vs: usize },
-    /// Chargement vectoriel: vd = mem[addr:addr+stride*8]
-    Load { vd: usize, addr: u32, stride: u8 },
-    /// Stockage vectoriel: mem[addr:addr+stride*8] = vs
-    Store { vs: usize, addr: u32, stride: u8 },
+    Avg { vs: usize },
+    /// Chargement vectoriel: vd[i] = mem[addr + i*stride] (stride en mots)
+    Load { vd: usize, addr: Address, stride: usize },
+    /// Stockage vectoriel: mem[addr + i*stride] = vs[i] (stride en mots)
+    Store { vs: usize, addr: Address, stride: usize },
 }
 
 /// Énumération des erreurs TVPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TVPUError {
     /// Indice de registre invalide
     InvalidRegister,
@@ -257,24 +293,46 @@ pub enum TVPUError {
     Overflow,
 }
 
+impl TVPUError {
+    /// Code d'erreur reporté dans le registre d'état
+    pub fn code(&self) -> u8 {
+        match self {
+            TVPUError::InvalidRegister => 1,
+            TVPUError::InvalidAddress => 2,
+            TVPUError::UnsupportedOperation => 3,
+            TVPUError::AlignmentError => 4,
+            TVPUError::Overflow => 5,
+        }
+    }
+}
+
+// Conversion des erreurs mémoire des accès vectoriels
+impl From<MemoryError> for TVPUError {
+    fn from(error: MemoryError) -> Self {
+        match error {
+            MemoryError::Misaligned => TVPUError::AlignmentError,
+            _ => TVPUError::InvalidAddress,
+        }
+    }
+}
+
 /// Implémentation de l'interface de débogage pour TVPUHardware
 impl std::fmt::Debug for TVPUHardware {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "TVPU Hardware State:")?;
         writeln!(f, "  Operations: {}", self.operation_count)?;
         writeln!(f, "  Cycles: {}", self.cycle_count)?;
-        writeln!(f, "  Status: {:?}", self.status)?;
-        writeln!(f, "  Registers:")?;
-        for (i, reg) in self.registers.iter().enumerate() {
-            if let Some(word) = reg.word(0) {
-                writeln!(f, "    V{}: {:?}", i, word)?;
-            }
-        }
-        Ok(())
+        writeln!(f, "  Status: {:?}", self.status)
     }
 }
 
 /// Implémentation de l'interface de débogage pour TVPUStatus
 impl std::fmt::Debug for TVPUStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Status {{ busy: {}, ", self
\ No newline at end of file
+        write!(f, "Status {{ busy: {}, ", self.busy)?;
+        write!(f, "error: {:?}, ", self.error_code)?;
+        write!(f, "overflow: {}, ", self.overflow)?;
+        write!(f, "special: {}, ", self.special_result)?;
+        write!(f, "latency: {} }}", self.last_op_latency)
+    }
+}
@@ -0,0 +1,456 @@
+// tests/alu_model.rs
+// Tests différentiels de l'ALU ternaire contre un modèle entier exact (proptest)
+//
+// Le modèle ne manipule que des i64/i128 et n'utilise ni Word::from_i64 ni Word::to_i64:
+// - un Mot numérique vaut la somme de ses chiffres B24 (Bal3 -13..+10) × 24^i, dans
+//   [WORD_MIN, WORD_MAX]; les résultats sortant de cette plage sont repliés modulo 24^8 et
+//   la retenue sortante est le nombre de tours perdus (-1, 0 ou +1);
+// - les opérations trit-à-trit et les décalages sont modélisées sur un tableau de 24 trits,
+//   où n'importe quel motif (y compris UNDEF/NULL/NaN) peut apparaître.
+//
+// Les mots sont générés sur toute la plage, en distinguant:
+// - les mots numériques (chiffres B24 uniquement),
+// - les mots quelconques (24 trits arbitraires, donc des trytes +11/+12/+13 possibles),
+// - les mots numériques dans lesquels on injecte des états spéciaux.
+// En cas d'échec, proptest réduit le contre-exemple (valeurs vers 0, moins d'états spéciaux).
+
+use prismchrono_sim::Flags;
+use prismchrono_sim::alu::{
+    add_24_trits, compare_24_trits, div_24_trits, div_words, mod_24_trits, mul_24_trits, mul_words,
+    neg_24_trits, shl_24_trits, shr_24_trits, sub_24_trits, trit_inv_word, trit_max_word,
+    trit_min_word,
+};
+use prismchrono_sim::core::{Trit, Tryte, WORD_MAX, WORD_MIN, Word};
+use proptest::prelude::*;
+
+// --- Modèle ---
+
+// 24^8
+const MODULUS: i128 = 24i128.pow(8);
+
+// Replie une valeur exacte dans la plage d'un Mot
+fn wrap(value: i128) -> i64 {
+    ((value - WORD_MIN as i128).rem_euclid(MODULUS) + WORD_MIN as i128) as i64
+}
+
+// Chiffres B24 d'une valeur de la plage, du poids faible au poids fort
+fn digits_of(value: i64) -> [i8; 8] {
+    let mut digits = [0; 8];
+    let mut remaining = value as i128;
+    for digit in digits.iter_mut() {
+        *digit = ((remaining + 13).rem_euclid(24) - 13) as i8;
+        remaining = (remaining - *digit as i128) / 24;
+    }
+    assert_eq!(remaining, 0, "{value} hors de la plage d'un Mot");
+    digits
+}
+
+// Mot attendu pour une valeur de la plage (un chiffre -13..+10 n'est jamais un état spécial)
+fn word_of(value: i64) -> Word {
+    Word(digits_of(value).map(|d| Tryte::Digit((d + 13) as u8)))
+}
+
+// Valeur d'un Mot numérique d'après ses chiffres
+fn value_of(word: &Word) -> i64 {
+    word.trytes().iter().rev().fold(0, |acc, tryte| match tryte {
+        Tryte::Digit(d) => acc * 24 + (*d as i64 - 13),
+        special => panic!("tryte spécial {special:?} dans un résultat numérique"),
+    })
+}
+
+// Trits d'un mot quelconque, d'après la décomposition Bal3 de chaque tryte
+fn trits_of(word: &Word) -> [i8; 24] {
+    let mut trits = [0; 24];
+    for (i, tryte) in word.trytes().iter().enumerate() {
+        let mut remaining = tryte.bal3_value();
+        for j in 0..3 {
+            trits[3 * i + j] = (remaining + 1).rem_euclid(3) - 1;
+            remaining = (remaining - trits[3 * i + j]) / 3;
+        }
+    }
+    trits
+}
+
+// Mot formé de 24 trits quelconques
+fn word_of_trits(trits: &[i8; 24]) -> Word {
+    Word(std::array::from_fn(|i| {
+        let bal3 = trits[3 * i] + 3 * trits[3 * i + 1] + 9 * trits[3 * i + 2];
+        match bal3 {
+            11 => Tryte::Undefined,
+            12 => Tryte::Null,
+            13 => Tryte::NaN,
+            _ => Tryte::Digit((bal3 + 13) as u8),
+        }
+    }))
+}
+
+fn trit_of(value: i128) -> Trit {
+    Trit::from_value(value as i8).unwrap()
+}
+
+fn special_rank(tryte: Tryte) -> u8 {
+    match tryte {
+        Tryte::NaN => 3,
+        Tryte::Null => 2,
+        Tryte::Undefined => 1,
+        Tryte::Digit(_) => 0,
+    }
+}
+
+// Valeur arithmétique d'un mot: ses trytes spéciaux comptent pour zéro
+fn digits_value(word: &Word) -> i64 {
+    let mut digits = *word;
+    for tryte in digits.trytes_mut() {
+        if special_rank(*tryte) > 0 {
+            *tryte = Tryte::Digit(13);
+        }
+    }
+    value_of(&digits)
+}
+
+// État spécial dominant (NaN > NULL > UNDEF) des trytes de deux mots
+fn dominant_special(a: &Word, b: &Word) -> Option<Tryte> {
+    a.trytes()
+        .iter()
+        .chain(b.trytes())
+        .copied()
+        .filter(|t| special_rank(*t) > 0)
+        .max_by_key(|t| special_rank(*t))
+}
+
+// Flags attendus d'une opération arithmétique sans état spécial
+fn arithmetic_flags(exact: i128, carry: i128) -> Flags {
+    let wrapped = wrap(exact);
+    Flags {
+        zf: wrapped == 0,
+        sf: exact < 0,
+        xf: false,
+        of: wrapped as i128 != exact,
+        cf: carry == 1,
+    }
+}
+
+fn special_flags() -> Flags {
+    Flags {
+        xf: true,
+        ..Flags::new()
+    }
+}
+
+// Modèle de ADD/SUB: (résultat, retenue ou emprunt sortant, flags)
+fn model_add_sub(a: &Word, b: &Word, carry_in: i8, subtract: bool) -> (Word, Trit, Flags) {
+    let sign: i128 = if subtract { -1 } else { 1 };
+    let exact = digits_value(a) as i128 + sign * (digits_value(b) as i128 + carry_in as i128);
+    let wrapped = wrap(exact);
+    let mut result = word_of(wrapped);
+
+    // Les trytes spéciaux des opérandes sont recopiés à leur position
+    let mut has_special = false;
+    for i in 0..8 {
+        let (ta, tb) = (a.trytes()[i], b.trytes()[i]);
+        let special = if special_rank(ta) >= special_rank(tb) {
+            ta
+        } else {
+            tb
+        };
+        if special_rank(special) > 0 {
+            result.trytes_mut()[i] = special;
+            has_special = true;
+        }
+    }
+    if has_special {
+        return (result, Trit::Z, special_flags());
+    }
+
+    // Retenue: tours perdus au repli; l'emprunt d'une soustraction en est l'opposé
+    let carry = sign * (exact - wrapped as i128) / MODULUS;
+    (result, trit_of(carry), arithmetic_flags(exact, carry))
+}
+
+// Modèle des décalages: les trits avancent de `shift` positions vers les poids forts (vers
+// les poids faibles si négatif), les trits entrants valent Z
+fn model_shift(trits: &[i8; 24], shift: i64) -> Word {
+    word_of_trits(&std::array::from_fn(|i| {
+        let source = i as i64 - shift;
+        if (0..24).contains(&source) {
+            trits[source as usize]
+        } else {
+            0
+        }
+    }))
+}
+
+// --- Générateurs ---
+
+// Valeur quelconque de la plage d'un Mot
+fn any_value() -> impl Strategy<Value = i64> {
+    WORD_MIN..=WORD_MAX
+}
+
+// Petites valeurs (retenues et cas limites fréquents)
+fn small_value() -> impl Strategy<Value = i64> {
+    -1000i64..=1000
+}
+
+// Valeurs dont un chiffre est à une borne (-13, +10) ou juste avant
+fn boundary_value() -> impl Strategy<Value = i64> {
+    prop::array::uniform8(prop_oneof![Just(-13i8), Just(-12), Just(9), Just(10), -13i8..=10])
+        .prop_map(|digits| digits.iter().rev().fold(0, |acc, d| acc * 24 + *d as i64))
+}
+
+fn numeric_operand() -> impl Strategy<Value = i64> {
+    prop_oneof![
+        any_value(),
+        small_value(),
+        boundary_value(),
+        Just(0),
+        Just(WORD_MAX),
+        Just(WORD_MIN)
+    ]
+}
+
+fn any_trit() -> impl Strategy<Value = Trit> {
+    prop_oneof![Just(Trit::Z), Just(Trit::P), Just(Trit::N)]
+}
+
+// 24 trits quelconques
+fn any_trits() -> impl Strategy<Value = [i8; 24]> {
+    prop::array::uniform24(-1i8..=1)
+}
+
+fn special_tryte() -> impl Strategy<Value = Tryte> {
+    prop_oneof![Just(Tryte::Undefined), Just(Tryte::Null), Just(Tryte::NaN)]
+}
+
+// Mot numérique dans lequel on injecte des états spéciaux à des positions quelconques
+fn special_word() -> impl Strategy<Value = Word> {
+    (
+        numeric_operand(),
+        prop::collection::vec((0usize..8, special_tryte()), 0..4),
+    )
+        .prop_map(|(value, specials)| {
+            let mut word = word_of(value);
+            for (index, tryte) in specials {
+                word.set_tryte(index, tryte);
+            }
+            word
+        })
+}
+
+// --- Conversions Word/Tryte ---
+
+#[test]
+fn tryte_conversions_match_model() {
+    for bal3 in -13i8..=13 {
+        let tryte = Tryte::from_bal3(bal3).unwrap();
+        let trits = tryte.to_trits();
+        let expected = match bal3 {
+            11 => Tryte::Undefined,
+            12 => Tryte::Null,
+            13 => Tryte::NaN,
+            _ => Tryte::Digit((bal3 + 13) as u8),
+        };
+
+        assert_eq!(tryte, expected);
+        assert_eq!(tryte.bal3_value(), bal3);
+        assert_eq!(tryte.to_i8(), bal3);
+        assert_eq!(Tryte::from_i8(bal3), tryte);
+        assert_eq!(
+            trits[0].value() + 3 * trits[1].value() + 9 * trits[2].value(),
+            bal3
+        );
+        assert_eq!(Tryte::from_trits(trits), tryte);
+        for (index, trit) in trits.iter().enumerate() {
+            assert_eq!(tryte.get_trit(index), *trit);
+        }
+    }
+    assert_eq!(Tryte::from_bal3(14), None);
+    assert_eq!(Tryte::from_bal3(-14), None);
+}
+
+#[test]
+fn word_conversions_at_bounds() {
+    for value in [0, 1, -1, 10, 11, 13, 14, -13, -14, WORD_MAX, WORD_MIN, WORD_MAX - 1, WORD_MIN + 1] {
+        assert_eq!(
+            Word::from_i64(value),
+            word_of(value),
+            "Word::from_i64({value})"
+        );
+        assert_eq!(Word::from_i64(value).to_i64(), value);
+    }
+    // Les bornes n'ont que des chiffres extrêmes, jamais d'état spécial
+    assert_eq!(Word::from_i64(WORD_MAX), Word([Tryte::Digit(23); 8]));
+    assert_eq!(Word::from_i64(WORD_MIN), Word([Tryte::Digit(0); 8]));
+    // Repli modulo 24^8, y compris aux bornes de i64
+    assert_eq!(Word::from_i64(WORD_MAX + 1), word_of(WORD_MIN));
+    assert_eq!(Word::from_i64(WORD_MIN - 1), word_of(WORD_MAX));
+    assert_eq!(Word::from_i64(i64::MAX), word_of(wrap(i64::MAX as i128)));
+    assert_eq!(Word::from_i64(i64::MIN), word_of(wrap(i64::MIN as i128)));
+    assert!(Word::try_from(WORD_MAX + 1).is_err());
+    assert!(Word::try_from(WORD_MIN - 1).is_err());
+}
+
+proptest! {
+    #[test]
+    fn word_from_i64_wraps_like_model(value in any::<i64>()) {
+        prop_assert_eq!(Word::from_i64(value), word_of(wrap(value as i128)));
+    }
+
+    #[test]
+    fn word_to_i64_round_trips(value in numeric_operand()) {
+        let word = word_of(value);
+        prop_assert_eq!(word.to_i64(), value);
+        prop_assert_eq!(i64::from(word), value);
+        prop_assert_eq!(Word::from_i64(value), word);
+        prop_assert_eq!(Word::try_from(value), Ok(word));
+        prop_assert_eq!(Word::from_trits(word.to_trits()), word);
+        prop_assert_eq!(word.is_negative(), value < 0);
+        prop_assert_eq!(word == Word::zero(), value == 0);
+    }
+
+    #[test]
+    fn word_i32_conversions_match_model(value in any::<i32>()) {
+        let word = Word::from(value);
+        prop_assert_eq!(word, word_of(value as i64));
+        prop_assert_eq!(i32::try_from(word), Ok(value));
+        if let Ok(small) = i16::try_from(value) {
+            prop_assert_eq!(Word::from(small), word);
+        }
+    }
+
+    #[test]
+    fn word_text_round_trips(value in numeric_operand()) {
+        let word = word_of(value);
+        prop_assert_eq!(value.to_string().parse::<Word>(), Ok(word));
+        prop_assert_eq!(word.to_string().parse::<Word>(), Ok(word));
+        prop_assert_eq!(format!("{:#}", word.balanced()).parse::<Word>(), Ok(word));
+    }
+
+    #[test]
+    fn word_trit_access_matches_model(trits in any_trits(), index in 0usize..24, trit in any_trit()) {
+        let mut word = word_of_trits(&trits);
+        prop_assert_eq!(trits_of(&word), trits);
+        prop_assert_eq!(word.get_trit(index).value(), trits[index]);
+        prop_assert_eq!(Word::from_trits(word.to_trits()), word);
+
+        word.set_trit(index, trit);
+        let mut expected = trits;
+        expected[index] = trit.value();
+        prop_assert_eq!(word, word_of_trits(&expected));
+    }
+
+    // --- Logique trit-à-trit ---
+
+    #[test]
+    fn trit_logic_matches_model(ta in any_trits(), tb in any_trits()) {
+        let (a, b) = (word_of_trits(&ta), word_of_trits(&tb));
+        let inv = word_of_trits(&ta.map(|t| -t));
+        let min = word_of_trits(&std::array::from_fn(|i| ta[i].min(tb[i])));
+        let max = word_of_trits(&std::array::from_fn(|i| ta[i].max(tb[i])));
+
+        prop_assert_eq!(trit_inv_word(a), inv);
+        prop_assert_eq!(trit_inv_word(trit_inv_word(a)), a);
+        prop_assert_eq!(trit_min_word(a, b), min);
+        prop_assert_eq!(trit_max_word(a, b), max);
+    }
+
+    // --- Décalages ---
+
+    #[test]
+    fn shifts_match_model(trits in any_trits(), shift in -30i64..=30) {
+        let a = word_of_trits(&trits);
+        prop_assert_eq!(shl_24_trits(a, word_of(shift)), model_shift(&trits, shift));
+        prop_assert_eq!(shr_24_trits(a, word_of(shift)), model_shift(&trits, -shift));
+    }
+
+    #[test]
+    fn large_shifts_clear_the_word(trits in any_trits(), shift in any_value()) {
+        let a = word_of_trits(&trits);
+        prop_assert_eq!(shl_24_trits(a, word_of(shift)), model_shift(&trits, shift));
+        prop_assert_eq!(shr_24_trits(a, word_of(shift)), model_shift(&trits, -shift));
+    }
+
+    // Décaler de 3 trits un mot numérique déplace ses chiffres B24 d'un rang
+    #[test]
+    fn tryte_shifts_scale_by_24(a in numeric_operand()) {
+        let digits = digits_of(a);
+        let dropped = digits[0] as i64;
+        prop_assert_eq!(shl_24_trits(word_of(a), word_of(3)), word_of(wrap(a as i128 * 24)));
+        prop_assert_eq!(shr_24_trits(word_of(a), word_of(3)), word_of((a - dropped) / 24));
+    }
+
+    // --- Addition, soustraction, comparaison ---
+
+    #[test]
+    fn add_matches_model(a in numeric_operand(), b in numeric_operand(), cin in any_trit()) {
+        let (wa, wb) = (word_of(a), word_of(b));
+        let expected = model_add_sub(&wa, &wb, cin.value(), false);
+        prop_assert_eq!(add_24_trits(wa, wb, cin), expected);
+        prop_assert_eq!(value_of(&expected.0), wrap(a as i128 + b as i128 + cin.value() as i128));
+    }
+
+    #[test]
+    fn sub_and_compare_match_model(a in numeric_operand(), b in numeric_operand(), bin in any_trit()) {
+        let (wa, wb) = (word_of(a), word_of(b));
+        let expected = model_add_sub(&wa, &wb, bin.value(), true);
+        prop_assert_eq!(sub_24_trits(wa, wb, bin), expected);
+        prop_assert_eq!(value_of(&expected.0), wrap(a as i128 - b as i128 - bin.value() as i128));
+        prop_assert_eq!(compare_24_trits(wa, wb), model_add_sub(&wa, &wb, 0, true).2);
+        prop_assert_eq!(neg_24_trits(wa), word_of(wrap(-(a as i128))));
+    }
+
+    #[test]
+    fn add_sub_propagate_special_states(a in special_word(), b in special_word(), cin in any_trit()) {
+        prop_assert_eq!(add_24_trits(a, b, cin), model_add_sub(&a, &b, cin.value(), false));
+        prop_assert_eq!(sub_24_trits(a, b, cin), model_add_sub(&a, &b, cin.value(), true));
+    }
+
+    // --- Multiplication, division, modulo ---
+
+    #[test]
+    fn mul_matches_model(a in numeric_operand(), b in numeric_operand()) {
+        let exact = a as i128 * b as i128;
+        let (product, overflow) = mul_words(word_of(a), word_of(b));
+        prop_assert_eq!(product, word_of(wrap(exact)));
+        prop_assert_eq!(overflow, wrap(exact) as i128 != exact);
+        prop_assert_eq!(mul_24_trits(word_of(a), word_of(b)), product);
+    }
+
+    #[test]
+    fn div_mod_match_model(a in numeric_operand(), b in numeric_operand()) {
+        // Division tronquée vers zéro, reste du signe du dividende; zéro si b = 0
+        // (WORD_MIN / -1 sort de la plage et se replie)
+        let (quotient, remainder) = if b == 0 {
+            (0, 0)
+        } else {
+            (wrap(a as i128 / b as i128), a % b)
+        };
+        prop_assert_eq!(div_24_trits(word_of(a), word_of(b)), word_of(quotient));
+        prop_assert_eq!(mod_24_trits(word_of(a), word_of(b)), word_of(remainder));
+        prop_assert_eq!(div_words(word_of(a), word_of(b)), (word_of(quotient), word_of(remainder)));
+    }
+
+    #[test]
+    fn mul_div_propagate_special_states(a in special_word(), b in special_word()) {
+        let expected = match dominant_special(&a, &b) {
+            Some(special) => Word([special; 8]),
+            None => return Ok(()),
+        };
+        prop_assert_eq!(mul_24_trits(a, b), expected);
+        prop_assert_eq!(div_24_trits(a, b), expected);
+        prop_assert_eq!(mod_24_trits(a, b), expected);
+    }
+}
+
+// Cas signalés: aucune somme ne doit produire un tryte spécial
+#[test]
+fn sums_never_alias_special_states() {
+    let ten = word_of(10);
+    let (result, carry, flags) = add_24_trits(ten, word_of(3), Trit::Z);
+    assert_eq!(value_of(&result), 13);
+    assert_eq!((carry, flags.xf), (Trit::Z, false));
+
+    let (result, _, flags) = add_24_trits(word_of(13), word_of(1), Trit::Z);
+    assert_eq!(value_of(&result), 14);
+    assert!(!flags.xf);
+}
@@ -0,0 +1,162 @@
+// tests/benchmarks.rs
+// Exécution des programmes de benchmarks/prismchrono comme le fait
+// benchmarks/scripts/run_prismchrono.sh: assemblage, données d'entrée du fichier .data
+// (--preload-file), exécution instrumentée puis métriques JSON
+//
+// Chaque benchmark doit s'arrêter sur HALT (stop_reason "halted") dans la limite
+// d'instructions; les résultats vérifiables sont relus dans les registres.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use prismchrono_asm::assemble_source;
+use prismchrono_asm::output::write_tobj;
+use prismchrono_sim::metrics::{OpcodeCounts, metrics_json};
+use prismchrono_sim::preload::parse_preload_file;
+use prismchrono_sim::{Cpu, Register, StopReason, Word, loader};
+
+// Limite d'instructions par benchmark (une boucle infinie est un échec)
+const MAX_STEPS: u64 = 200_000;
+
+fn benchmark_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../benchmarks/prismchrono")
+}
+
+// Assemble, charge et exécute un benchmark; rend le CPU à l'arrêt et ses métriques JSON
+fn run_benchmark(name: &str) -> (Cpu, String) {
+    let dir = benchmark_dir();
+    let source = fs::read_to_string(dir.join(format!("{}.s", name)))
+        .unwrap_or_else(|e| panic!("{}.s: {}", name, e));
+    let assembled = assemble_source(&source).unwrap_or_else(|e| panic!("{}.s: assemblage: {}", name, e));
+    let object = std::env::temp_dir().join(format!("prismchrono_benchmark_{}_{}.tobj", std::process::id(), name));
+    write_tobj(&object, &assembled.encoded_data, &assembled.globals).unwrap();
+
+    let mut cpu = Cpu::new();
+    let loaded = loader::load_file(&object, &mut cpu.memory);
+    let _ = fs::remove_file(&object);
+    let program = loaded.unwrap_or_else(|e| panic!("{}: chargement: {}", name, e));
+    cpu.state.write_pc(Word::from(program.entry.expect("aucune instruction") as i32));
+    cpu.opcode_counts = Some(OpcodeCounts::new());
+
+    let data = dir.join(format!("{}.data", name));
+    if data.exists() {
+        let text = fs::read_to_string(&data).unwrap();
+        for preload in parse_preload_file(&text).unwrap_or_else(|e| panic!("{}.data: {}", name, e)) {
+            preload.apply(&mut cpu).unwrap_or_else(|e| panic!("{}.data: {}", name, e));
+        }
+    }
+
+    let result = cpu.run(Some(MAX_STEPS));
+    let pc = cpu.state.read_pc().to_i64();
+    assert_eq!(result, Ok(StopReason::Halted), "{}: arrêt inattendu à {:#x}", name, pc);
+    let metrics = metrics_json(&cpu, program.trytes, result.ok());
+    assert!(metrics.contains("\"stop_reason\": \"halted\""), "{}: {}", name, metrics);
+    (cpu, metrics)
+}
+
+fn register(cpu: &Cpu, register: Register) -> i64 {
+    cpu.state.read_gpr(register).to_i64()
+}
+
+#[test]
+fn tvpu_operations_halts() {
+    let (cpu, metrics) = run_benchmark("tvpu_operations");
+
+    // Partie scalaire: produit scalaire et somme de A + B sur les 16 éléments
+    assert_eq!(register(&cpu, Register::R4), 330);
+    assert_eq!(register(&cpu, Register::R5), 168);
+    assert!(metrics.contains("\"TVDOT\": 2"), "{}", metrics);
+}
+
+fn word_at(cpu: &Cpu, address: usize) -> Word {
+    cpu.memory.read_word(address).unwrap()
+}
+
+#[test]
+fn branch3_decision_halts() {
+    const DATA_ADDR: usize = 0x1000;
+    const RESULT_ADDR: usize = 0x1200;
+    const DECISIONS: usize = 30;
+    let (cpu, _) = run_benchmark("branch3_decision");
+
+    // BRANCH3 et les branchements binaires rendent la même décision pour chaque donnée
+    for index in 0..DECISIONS {
+        let value = word_at(&cpu, DATA_ADDR + 8 * index).to_i64();
+        let expected = Word::from(20 + 10 * value.signum() as i32);
+        assert_eq!(word_at(&cpu, RESULT_ADDR + 8 * index), expected, "partie 1, donnée {}", index);
+        assert_eq!(word_at(&cpu, RESULT_ADDR + 8 * (DECISIONS + index)), expected, "partie 2, donnée {}", index);
+    }
+}
+
+#[test]
+fn ternary_logic_halts() {
+    const RESULT_ADDR: usize = 0x1400;
+    let (cpu, metrics) = run_benchmark("ternary_logic");
+
+    // 16 votes positifs, 15 négatifs et 19 abstentions: le vote est accepté
+    let results: Vec<i64> = (0..4).map(|i| word_at(&cpu, RESULT_ADDR + 8 * i).to_i64()).collect();
+    assert_eq!(results, [1, 16, 15, 19]);
+    assert!(metrics.contains("\"BRANCH3\": 50"), "{}", metrics);
+}
+
+#[test]
+fn special_states_halts() {
+    const RESULT_ADDR: usize = 0x1200;
+    let (cpu, _) = run_benchmark("special_states");
+
+    // Les deux parties comptent 4 NULL, 3 NaN, 3 UNDEF et somment les 40 valeurs valides
+    for part in 0..2 {
+        let block = RESULT_ADDR + 32 * part;
+        let counts: Vec<i64> = (0..4).map(|i| word_at(&cpu, block + 8 * i).to_i64()).collect();
+        assert_eq!(counts, [4, 3, 3, 1275 - 275], "partie {}", part + 1);
+    }
+}
+
+#[test]
+fn sum_array_halts() {
+    let (cpu, metrics) = run_benchmark("sum_array");
+    assert_eq!(word_at(&cpu, 0x1400).to_i64(), 5050);
+    assert!(metrics.contains("\"LOADW\": 100"), "{}", metrics);
+}
+
+#[test]
+fn memcpy_halts() {
+    let (cpu, _) = run_benchmark("memcpy");
+    for index in 0..100 {
+        assert_eq!(word_at(&cpu, 0x1400 + 8 * index).to_i64(), index as i64 + 1);
+    }
+}
+
+#[test]
+fn factorial_halts() {
+    let (cpu, _) = run_benchmark("factorial");
+    assert_eq!(word_at(&cpu, 0x1000).to_i64(), 3_628_800);
+}
+
+#[test]
+fn predictive_cache_halts() {
+    const RESULT_ADDR: usize = 0x3000;
+    let (cpu, _) = run_benchmark("predictive_cache");
+
+    // Parcours séquentiel puis à pas de 8 Mots; la descente repart de la racine (valeur 1)
+    for index in 0..100 {
+        assert_eq!(word_at(&cpu, RESULT_ADDR + 8 * index).to_i64(), index as i64 + 1);
+        assert_eq!(word_at(&cpu, RESULT_ADDR + 8 * (100 + index)).to_i64(), 8 * index as i64 + 1);
+    }
+    assert_eq!(word_at(&cpu, RESULT_ADDR + 8 * 200).to_i64(), 1);
+}
+
+#[test]
+fn optimized_memory_halts() {
+    const SRC_ADDR: usize = 0x1000;
+    const DST_ADDR: usize = 0x1400;
+    let (cpu, metrics) = run_benchmark("optimized_memory");
+
+    // Les quatre copies laissent à DST_ADDR les 12 Mots du bloc source
+    for index in 0..12 {
+        assert_eq!(word_at(&cpu, DST_ADDR + 8 * index), word_at(&cpu, SRC_ADDR + 8 * index), "Mot {}", index);
+        assert_eq!(word_at(&cpu, DST_ADDR + 8 * index).to_i64(), index as i64 + 1);
+    }
+    assert!(metrics.contains("\"STORET3\": 32"), "{}", metrics);
+    assert!(metrics.contains("\"TMEMCPY\": 1"), "{}", metrics);
+}
@@ -0,0 +1,130 @@
+// tests/conformance.rs
+// Suite de conformité architecturale: assemble et exécute chaque programme de
+// tests/conformance/*.s sur le simulateur
+//
+// Chaque programme se vérifie lui-même et rend son verdict par l'appel EXIT du
+// semihosting: code 0 si toutes ses vérifications passent, sinon le numéro de la
+// première vérification en échec. HALT, EBREAK, une erreur d'exécution ou la limite
+// d'instructions sont des échecs.
+//
+// Un programme qui échoue à cause d'un défaut connu du simulateur le déclare en tête:
+//   # xfail: raison
+// Il doit alors échouer: un programme marqué qui passe fait échouer la suite, pour que la
+// marque soit retirée avec la correction.
+//
+// cargo test --test conformance -- --nocapture affiche le verdict de chaque programme.
+
+use std::cell::RefCell;
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use prismchrono_asm::assemble_source;
+use prismchrono_asm::output::write_tobj;
+use prismchrono_sim::semihost::Semihost;
+use prismchrono_sim::{Cpu, StopReason, Word, loader};
+
+// Limite d'instructions par programme (une boucle infinie est un échec)
+const MAX_STEPS: u64 = 10_000;
+
+// Sortie de la console du programme, relue pour le rapport
+#[derive(Clone, Default)]
+struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Programmes de la suite, par ordre alphabétique
+fn programs() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/conformance");
+    let mut programs: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("Impossible de lire {}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "s"))
+        .collect();
+    programs.sort();
+    programs
+}
+
+// Raison de l'échec attendu déclarée par `# xfail:`, None si le programme doit passer
+fn expected_failure(source: &str) -> Option<String> {
+    source
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# xfail:"))
+        .map(|reason| reason.trim().to_string())
+}
+
+// Assemble un programme et le charge dans un CPU prêt à l'exécuter
+fn load(path: &Path, source: &str) -> Result<Cpu, String> {
+    let assembled = assemble_source(source).map_err(|e| format!("assemblage: {}", e))?;
+    let name = path.file_stem().unwrap().to_string_lossy();
+    let object = std::env::temp_dir().join(format!("prismchrono_conformance_{}_{}.tobj", std::process::id(), name));
+    write_tobj(&object, &assembled.encoded_data, &assembled.globals).map_err(|e| format!("écriture: {}", e))?;
+
+    let mut cpu = Cpu::new();
+    let loaded = loader::load_file(&object, &mut cpu.memory);
+    let _ = fs::remove_file(&object);
+    let entry = loaded.map_err(|e| format!("chargement: {}", e))?.entry.ok_or("aucune instruction")?;
+    cpu.state.write_pc(Word::from(entry as i32));
+    Ok(cpu)
+}
+
+// Exécute un programme; Ok si toutes ses vérifications passent, sinon la cause de l'échec
+fn run(path: &Path, source: &str) -> Result<(), String> {
+    let mut cpu = load(path, source)?;
+    let output = SharedOutput::default();
+    cpu.semihost = Some(Semihost::new(Box::new(io::empty()), Box::new(output.clone()), Box::new(io::sink()), None));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| cpu.run(Some(MAX_STEPS))));
+    let console = String::from_utf8_lossy(&output.0.borrow()).trim_end().to_string();
+    let pc = cpu.state.read_pc().to_i64();
+    let failure = match result {
+        Ok(Ok(StopReason::Exited(0))) => return Ok(()),
+        Ok(Ok(StopReason::Exited(check))) => format!("vérification {} en échec", check),
+        Ok(Ok(StopReason::Halted)) => format!("HALT à {:#x} sans EXIT", pc),
+        Ok(Ok(StopReason::Breakpoint)) => format!("EBREAK à {:#x}", pc),
+        Ok(Ok(StopReason::StepLimit)) => format!("limite de {} instructions atteinte (PC {:#x})", MAX_STEPS, pc),
+        Ok(Err(e)) => format!("erreur d'exécution à {:#x}: {:?}", pc, e),
+        Err(_) => format!("panique du simulateur (PC {:#x})", pc),
+    };
+    if console.is_empty() {
+        Err(failure)
+    } else {
+        Err(format!("{} [console: {}]", failure, console))
+    }
+}
+
+#[test]
+fn conformance_suite() {
+    let programs = programs();
+    assert!(!programs.is_empty(), "aucun programme dans tests/conformance");
+
+    let mut unexpected = Vec::new();
+    for path in &programs {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let source = fs::read_to_string(path).unwrap();
+        let xfail = expected_failure(&source);
+        match (run(path, &source), xfail) {
+            (Ok(()), None) => println!("ok     {}", name),
+            (Err(failure), Some(reason)) => println!("xfail  {}: {} ({})", name, failure, reason),
+            (Err(failure), None) => {
+                println!("ÉCHEC  {}: {}", name, failure);
+                unexpected.push(format!("{}: {}", name, failure));
+            }
+            (Ok(()), Some(reason)) => {
+                println!("XPASS  {}: passe malgré « xfail: {} »", name, reason);
+                unexpected.push(format!("{}: passe, retirer la marque xfail", name));
+            }
+        }
+    }
+    assert!(unexpected.is_empty(), "programmes de conformité inattendus:\n{}", unexpected.join("\n"));
+}
@@ -0,0 +1,52 @@
+# Arithmétique entière: ADD, SUB, ADDI, SUBI, MUL, DIV, MOD (valeurs signées)
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R1, 7
+    MOVI R2, -3
+
+    ADD R3, R1, R2
+    CHECKI R3, 4, 1
+    SUB R3, R1, R2
+    CHECKI R3, 10, 2
+    SUB R3, R2, R1
+    CHECKI R3, -10, 3
+    ADDI R3, R1, -20
+    CHECKI R3, -13, 4
+    SUBI R3, R1, 8
+    CHECKI R3, -1, 5
+    MUL R3, R1, R2
+    CHECKI R3, -21, 6
+
+    # Division tronquée vers zéro, le reste a le signe du dividende
+    DIV R3, R1, R2
+    CHECKI R3, -2, 7
+    MOD R3, R1, R2
+    CHECKI R3, 1, 8
+    MOVI R4, -21
+    DIV R3, R4, R1
+    CHECKI R3, -3, 9
+
+    # Grandes valeurs: 204398² dépasse l'immédiat de MOVI mais tient dans un Mot
+    MOVI R4, 204398
+    MUL R5, R4, R4
+    DIV R3, R5, R4
+    CHECKI R3, 204398, 10
+    SUB R3, R5, R5
+    CHECKI R3, 0, 11
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
@@ -0,0 +1,78 @@
+# Flags de CMP et des opérations ALU (ZF, SF, OF, XF) et conditions de BRANCH
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# Le branchement \cond doit être pris
+.macro TAKEN cond, num
+    BRANCH \cond, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+# Le branchement \cond ne doit pas être pris
+.macro NOT_TAKEN cond, num
+    BRANCH \cond, bad\@
+    J ok\@
+bad\@:
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R1, 3
+    MOVI R2, 5
+
+    CMP R1, R1              # égalité
+    TAKEN eq, 1
+    NOT_TAKEN ne, 2
+    TAKEN le, 3
+    TAKEN ge, 4
+    NOT_TAKEN lt, 5
+    NOT_TAKEN gt, 6
+
+    CMP R1, R2              # 3 < 5
+    TAKEN lt, 7
+    TAKEN ne, 8
+    TAKEN le, 9
+    NOT_TAKEN ge, 10
+    NOT_TAKEN gt, 11
+
+    CMP R2, R1              # 5 > 3
+    TAKEN gt, 12
+    TAKEN ge, 13
+    NOT_TAKEN lt, 14
+    NOT_TAKEN le, 15
+
+    TAKEN al, 16
+    NOT_TAKEN nv, 17
+
+    # Résultat nul ou négatif d'une opération ALU
+    SUB R3, R1, R1
+    TAKEN eq, 18
+    SUB R3, R1, R2
+    TAKEN lt, 19
+    NOT_TAKEN of, 20
+
+    # Débordement: le trit 23 seul (9 × 24^7) tient dans un Mot (WORD_MAX = 47858832250),
+    # pas son double
+    MOVI R4, 23
+    MOVI R5, 1
+    SHL R5, R5, R4
+    ADD R3, R5, R0
+    NOT_TAKEN of, 21
+    ADD R3, R5, R5
+    TAKEN of, 22
+
+    # État spécial: XF levé par une opérande NaN
+    SETNAN R4, R4
+    ADD R3, R4, R1
+    TAKEN xs, 23
+    ADD R3, R1, R2
+    TAKEN xn, 24
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
@@ -0,0 +1,41 @@
+# CSRRS/CSRRC: rd reçoit l'ancienne valeur, CSRRS lève à P les trits positifs de rs1,
+# CSRRC remet à zéro les trits positifs de rs1, les autres trits sont conservés
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R1, 0
+    CSRRW_T R0, MIE_T, R1
+
+    MOVI R1, 4                  # Trits 0 et 1
+    CSRRS_T R3, MIE_T, R1
+    CHECKI R3, 0, 1
+    MOVI R1, 24                 # Trit 3 (premier trit du tryte 1)
+    CSRRS_T R3, MIE_T, R1
+    CHECKI R3, 4, 2
+    CSRRS_T R3, MIE_T, R0
+    CHECKI R3, 28, 3
+
+    MOVI R1, 1                  # Trit 0
+    CSRRC_T R3, MIE_T, R1
+    CHECKI R3, 28, 4
+    MOVI R1, 24                 # Trit 3
+    CSRRC_T R3, MIE_T, R1
+    CHECKI R3, 27, 5
+    CSRRS_T R3, MIE_T, R0
+    CHECKI R3, 3, 6
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1                  # EXIT, code dans R2
+    ECALL
@@ -0,0 +1,55 @@
+# CSR et état de trap: CSRRW rend l'ancienne valeur, un trap empile MIE dans MPIE et
+# mémorise le privilège dans MPP, MRET restaure MIE
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+#
+# mstatus_t: MPP = trits 0-1 (P,0: Machine), MIE = trit 3 (24), MPIE = trit 4 (72)
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    # CSRRW échange, CSRRS avec R0 lit sans écrire
+    MOVI R1, 100
+    CSRRW_T R0, MEPC_T, R1
+    MOVI R1, 190
+    CSRRW_T R3, MEPC_T, R1
+    CHECKI R3, 100, 1
+    CSRRS_T R3, MEPC_T, R0
+    CHECKI R3, 190, 2
+
+    # Trap depuis le mode Machine avec MIE levé
+    LA R1, handler
+    CSRRW_T R0, MTVEC_T, R1
+    MOVI R1, 24                 # MIE = P, MPIE = 0, MPP = User
+    CSRRW_T R0, MSTATUS_T, R1
+    MOVI R1, 0
+    MOVI R5, -1                 # Cause relevée par le gestionnaire (-1: aucun trap)
+    DIV R4, R1, R1
+    CHECKI R5, 8, 3
+    CHECKI R3, 73, 4            # Dans le gestionnaire: MIE = 0, MPIE = P, MPP = Machine
+
+    # Après MRET: MIE = MPIE, MPIE = P, MPP inchangé
+    CSRRS_T R3, MSTATUS_T, R0
+    CHECKI R3, 97, 5
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1                  # EXIT, code dans R2
+    ECALL
+
+# Relève la cause (R5) et mstatus_t (R3), puis reprend à l'instruction suivante
+handler:
+    CSRRS_T R5, MCAUSE_T, R0
+    CSRRS_T R3, MSTATUS_T, R0
+    CSRRS_T R7, MEPC_T, R0
+    ADDI R7, R7, 4
+    CSRRW_T R0, MEPC_T, R7
+    MRET_T
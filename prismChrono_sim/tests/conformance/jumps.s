@@ -0,0 +1,74 @@
+# Sauts: JAL/J vers l'avant et l'arrière, CALL/RET et JALR, adresse de retour PC+4
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+# \ra doit contenir l'adresse de \label
+.macro CHECKA ra, label, num
+    LA R6, \label
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R5, 0              # Trace des passages
+
+    # Saut vers l'avant: les instructions sautées ne s'exécutent pas
+    J forward
+    MOVI R2, 1
+    J exit
+forward:
+    # Saut vers l'arrière, puis retour par un saut vers l'avant
+    J backward_target
+after_backward:
+    CHECKI R5, 1, 2
+
+    # JAL range l'adresse de l'instruction suivante
+    JAL R4, link_target
+link_return:
+    MOVI R2, 3
+    J exit
+link_target:
+    CHECKA R4, link_return, 4
+
+    # CALL/RET: la fonction revient juste après l'appel
+    CALL function
+call_return:
+    CHECKI R5, 2, 5
+    CHECKA R7, call_return, 6   # R7 reste l'adresse de retour (RA)
+
+    # JALR: saut indirect avec offset, adresse de retour dans rd
+    LA R3, indirect_base
+    JALR R4, R3, 8          # indirect_base + 8 = indirect_target
+jalr_return:
+    MOVI R2, 7
+    J exit
+indirect_base:
+    MOVI R2, 8              # 2 instructions sautées par l'offset
+indirect_target:
+    CHECKA R4, jalr_return, 9
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
+
+backward_target:
+    ADDI R5, R5, 1
+    J after_backward
+
+function:
+    ADDI R5, R5, 1
+    RET
@@ -0,0 +1,58 @@
+# Accès mémoire: STOREW/LOADW (Mot), STORET/LOADT/LOADTU (tryte), offsets signés et
+# mémoire jamais écrite (UND)
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R3, 0x1000         # Zone de données, alignée sur un Mot
+
+    # Mots: valeurs signées et grande amplitude, offsets positif et négatif
+    MOVI R1, -123456
+    STOREW R3, R1, 0
+    MOVI R1, 97531
+    STOREW R3, R1, 8
+    LOADW R4, R3, 0
+    CHECKI R4, -123456, 1
+    ADDI R5, R3, 16
+    LOADW R4, R5, -8
+    CHECKI R4, 97531, 2
+
+    # Un STOREW remplace le Mot entier
+    MOVI R1, 4
+    STOREW R3, R1, 0
+    LOADW R4, R3, 0
+    CHECKI R4, 4, 3
+
+    # Trytes: STORET n'écrit que le tryte de poids faible, LOADT garde le signe
+    MOVI R1, -5
+    STORET R3, R1, 9        # Deuxième tryte du Mot à 0x1008
+    LOADT R4, R3, 9
+    CHECKI R4, -5, 4
+    MOVI R1, 7
+    STORET R3, R1, 10
+    LOADTU R4, R3, 10       # LOADTU rend l'indice du chiffre (0 à 23): 7 + 13
+    CHECKI R4, 20, 5
+    LOADTU R4, R3, 9        # -5 est signé pour LOADT, indice 8 pour LOADTU
+    CHECKI R4, 8, 6
+    LOADT R4, R3, 8         # Tryte de poids faible de 97531 (97531 = 4064 × 24 - 5)
+    CHECKI R4, -5, 7
+
+    # Mémoire jamais écrite: Mot UND
+    LOADW R4, R3, 64
+    ISUNDEF R5, R4
+    CHECKI R5, 1, 8
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
@@ -0,0 +1,58 @@
+# Opérations trit à trit et décalages: TRITMIN, TRITMAX, TRITINV (NEG), SHL, SHR,
+# ABS_T, SIGNUM_T
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R1, 8              # +0- (trits de poids faible à fort: -, 0, +)
+    MOVI R2, -2             # -+  (trits: +, -, 0)
+
+    TRITMIN R3, R1, R2      # -, -, 0
+    CHECKI R3, -4, 1
+    TRITMAX R3, R1, R2      # +, 0, +
+    CHECKI R3, 10, 2
+    TRITINV R3, R1
+    CHECKI R3, -8, 3
+    NEG R3, R2
+    CHECKI R3, 2, 4
+
+    # Décalages de trits: un trit déplace les trits à l'intérieur des trytes, trois trits
+    # déplacent les chiffres B24 d'un rang (multiplication par 24)
+    MOVI R4, 1
+    SHL R3, R1, R4          # 0, -, 0 | + : -3 + 24
+    CHECKI R3, 21, 5
+    SHR R3, R3, R4
+    CHECKI R3, 8, 6
+    SHR R3, R2, R4          # -+ devient -
+    CHECKI R3, -1, 7
+    MOVI R4, 3
+    SHL R3, R2, R4
+    CHECKI R3, -48, 8
+
+    MOVI R4, -8
+    ABS_T R3, R4
+    CHECKI R3, 8, 9
+    ABS_T R3, R1
+    CHECKI R3, 8, 10
+    SIGNUM_T R3, R4
+    CHECKI R3, -1, 11
+    SIGNUM_T R3, R1
+    CHECKI R3, 1, 12
+    MOVI R4, 0
+    SIGNUM_T R3, R4
+    CHECKI R3, 0, 13
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
@@ -0,0 +1,72 @@
+# Privilèges: MRET vers le mode User (mstatus_t.MPP), puis instructions réservées au
+# mode Machine exécutées en mode User: accès aux CSR et MRET lèvent IllegalInstr
+# (cause 3), le trap repasse en mode Machine avec MPP = User
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+# Le dernier trap doit venir de l'instruction \label (mepc_t relevé dans R7)
+.macro CHECKPC label, num
+    LA R6, \label
+    CMP R7, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    LA R1, handler
+    CSRRW_T R0, MTVEC_T, R1
+    MOVI R1, 0
+    CSRRW_T R0, MSTATUS_T, R1   # MPP = User, interruptions masquées
+    LA R1, user
+    CSRRW_T R0, MEPC_T, R1
+    MRET_T
+    MOVI R2, 1                  # MRET doit quitter ce flot
+    J exit
+
+user:
+    MOVI R5, -1                 # Cause relevée par le gestionnaire (-1: aucun trap)
+user_csr_read:
+    CSRRS_T R4, MSTATUS_T, R0
+    CHECKI R5, 3, 2
+    CHECKPC user_csr_read, 3
+    CHECKI R3, 0, 4             # Le trap venait du mode User (MPP relevé par le gestionnaire)
+
+    MOVI R5, -1
+    MOVI R1, 0
+user_csr_write:
+    CSRRW_T R0, MTVEC_T, R1
+    CHECKI R5, 3, 5
+    CHECKPC user_csr_write, 6
+
+    MOVI R5, -1
+user_mret:
+    MRET_T
+    CHECKI R5, 3, 7
+    CHECKPC user_mret, 8
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1                  # EXIT, code dans R2
+    ECALL
+
+# Relève la cause (R5), l'instruction fautive (R7) et mstatus_t (R3), puis reprend à
+# l'instruction suivante dans le mode d'origine
+handler:
+    CSRRS_T R5, MCAUSE_T, R0
+    CSRRS_T R7, MEPC_T, R0
+    CSRRS_T R3, MSTATUS_T, R0
+    ADDI R6, R7, 4
+    CSRRW_T R0, MEPC_T, R6
+    MRET_T
@@ -0,0 +1,7 @@
+# Semihosting: EXIT rend son code au simulateur (programme minimal de la suite)
+.org 0x10
+_start:
+    MOVI R1, 1          # Fonction EXIT
+    MOVI R2, 0          # Code 0: succès
+    ECALL
+    HALT                # Jamais atteint
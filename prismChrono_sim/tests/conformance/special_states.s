@@ -0,0 +1,64 @@
+# États spéciaux: SETNAN/SETNULL/SETUNDEF, tests ISNAN/ISNULL/ISUNDEF et propagation
+# par l'ALU (NaN l'emporte sur NULL, qui l'emporte sur UND)
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    MOVI R1, 5
+
+    # Un nombre n'est dans aucun état spécial
+    ISNAN R4, R1
+    CHECKI R4, 0, 1
+    ISNULL R4, R1
+    CHECKI R4, 0, 2
+    ISUNDEF R4, R1
+    CHECKI R4, 0, 3
+
+    # Chaque SET* se reconnaît par le test correspondant, et seulement par lui
+    SETNAN R3, R1
+    ISNAN R4, R3
+    CHECKI R4, 1, 4
+    ISNULL R4, R3
+    CHECKI R4, 0, 5
+    SETNULL R3, R1
+    ISNULL R4, R3
+    CHECKI R4, 1, 6
+    ISUNDEF R4, R3
+    CHECKI R4, 0, 7
+    SETUNDEF R3, R1
+    ISUNDEF R4, R3
+    CHECKI R4, 1, 8
+    ISNAN R4, R3
+    CHECKI R4, 0, 9
+
+    # Propagation par l'ALU
+    SETUNDEF R3, R1
+    ADD R5, R3, R1
+    ISUNDEF R4, R5
+    CHECKI R4, 1, 10
+    SETNULL R3, R1
+    ADD R5, R5, R3          # UND + NULL = NULL
+    ISNULL R4, R5
+    CHECKI R4, 1, 11
+    SETNAN R3, R1
+    SUB R5, R5, R3          # NULL - NaN = NaN
+    ISNAN R4, R5
+    CHECKI R4, 1, 12
+    MUL R5, R1, R3
+    ISNAN R4, R5
+    CHECKI R4, 1, 13
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
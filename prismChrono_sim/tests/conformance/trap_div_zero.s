@@ -0,0 +1,63 @@
+# Trap de division par zéro: DIV et MOD par zéro lèvent DivByZero (cause 8) sans
+# modifier la destination
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+# Le dernier trap doit venir de l'instruction \label (mepc_t relevé dans R7)
+.macro CHECKPC label, num
+    LA R6, \label
+    CMP R7, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    LA R1, handler
+    CSRRW_T R0, MTVEC_T, R1
+    MOVI R3, 0
+    MOVI R4, 9
+
+    MOVI R5, -1             # Cause relevée par le gestionnaire (-1: aucun trap)
+divide:
+    DIV R4, R4, R3
+    CHECKI R5, 8, 1
+    CHECKPC divide, 2
+    CHECKI R4, 9, 3         # Destination inchangée
+
+    MOVI R5, -1
+modulo:
+    MOD R4, R4, R3
+    CHECKI R5, 8, 4
+    CHECKPC modulo, 5
+    CHECKI R4, 9, 6
+
+    # Dividende nul, diviseur non nul: pas de trap
+    MOVI R5, -1
+    DIV R4, R3, R4
+    CHECKI R5, -1, 7
+    CHECKI R4, 0, 8
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
+
+# Relève la cause (R5) et l'instruction fautive (R7), puis reprend à l'instruction suivante
+handler:
+    CSRRS_T R5, MCAUSE_T, R0
+    CSRRS_T R7, MEPC_T, R0
+    ADDI R6, R7, 4
+    CSRRW_T R0, MEPC_T, R6
+    MRET_T
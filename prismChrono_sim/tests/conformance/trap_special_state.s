@@ -0,0 +1,62 @@
+# Trap d'état spécial: une adresse ou un diviseur NaN/UNDEF lève SpecialState (cause 9)
+# Verdict par EXIT: 0 si tout passe, sinon le numéro de la vérification en échec
+
+# \ra doit valoir \imm (R6 sert de registre de travail)
+.macro CHECKI ra, imm, num
+    MOVI R6, \imm
+    CMP \ra, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+# Le dernier trap doit venir de l'instruction \label (mepc_t relevé dans R7)
+.macro CHECKPC label, num
+    LA R6, \label
+    CMP R7, R6
+    BRANCH eq, ok\@
+    MOVI R2, \num
+    J exit
+ok\@:
+.endm
+
+.org 0x10
+_start:
+    LA R1, handler
+    CSRRW_T R0, MTVEC_T, R1
+    MOVI R4, 9
+
+    SETNAN R3, R3
+    MOVI R5, -1             # Cause relevée par le gestionnaire (-1: aucun trap)
+load:
+    LOADW R4, R3, 0         # Adresse NaN
+    CHECKI R5, 9, 1
+    CHECKPC load, 2
+
+    SETUNDEF R3, R3
+    MOVI R5, -1
+store:
+    STOREW R3, R4, 0        # Adresse UNDEF
+    CHECKI R5, 9, 3
+    CHECKPC store, 4
+
+    MOVI R5, -1
+divide:
+    DIV R4, R4, R3          # Diviseur UNDEF
+    CHECKI R5, 9, 5
+    CHECKPC divide, 6
+    CHECKI R4, 9, 7
+
+    MOVI R2, 0
+exit:
+    MOVI R1, 1              # EXIT, code dans R2
+    ECALL
+
+# Relève la cause (R5) et l'instruction fautive (R7), puis reprend à l'instruction suivante
+handler:
+    CSRRS_T R5, MCAUSE_T, R0
+    CSRRS_T R7, MEPC_T, R0
+    ADDI R6, R7, 4
+    CSRRW_T R0, MEPC_T, R6
+    MRET_T
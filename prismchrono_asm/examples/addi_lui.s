@@ -4,7 +4,7 @@
 .org 0x100  # Commencer à l'adresse 0x100
 
 start:
-    LUI R1, 42     # Charger la valeur 42 dans le registre R1
-    ADDI R2, R1, 10  # R2 = R1 + 10 = 52
+    LUI R1, 42     # R1 = 42 × 3^5 = 10206
+    ADDI R2, R1, 10  # R2 = R1 + 10 = 10216
     NOP             # Ne rien faire
     HALT            # Arrêter le processeur
\ No newline at end of file
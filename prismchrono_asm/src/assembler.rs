@@ -4,20 +4,147 @@
 //! - Passe 1 : Calcul des adresses et construction de la table des symboles
 //! - Passe 2 : Résolution des références et encodage des instructions
 
-use crate::ast::{AstNode, Directive, Instruction, Program, SourceLine};
+use crate::ast::{AstNode, Directive, Instruction, Program};
 use crate::core_types::Address;
-use crate::encoder::{self, EncodedData, encode_nop, encode_halt, encode_addi, encode_lui, encode_jal, encode_tryte, encode_word, encode_storew, encode_storet, encode_branch, encode_add, encode_sub, encode_ecall, encode_ebreak, encode_mret, encode_csrrw, encode_csrrs};
+use crate::encoder::{EncodedData, encode_nop, encode_halt, encode_addi, encode_lui, encode_jal, encode_tryte, encode_word, encode_storew, encode_storet, encode_branch, encode_add, encode_sub, encode_ecall, encode_ebreak, encode_mret, encode_csrrw, encode_csrrs};
+use crate::encoder::{encode_alu, encode_alu_imm, encode_loadw, encode_loadt, encode_loadtu, encode_jalr, encode_auipc, encode_call, encode_csrrc, encode_csri};
+use crate::encoder::{encode_ternary, encode_tshift, encode_branch3, encode_loadt3, encode_storet3, encode_tmemcpy, encode_tmemset, encode_maddw, encode_msubw, encode_tsel, encode_special_state, encode_base24};
+use crate::encoder::{encode_vector, encode_vector_dot, encode_vector_reduce, encode_vector_load, encode_vector_store};
 use crate::error::AssemblerError;
-use crate::isa_defs::{INSTRUCTION_SIZE_BYTES, cond};
-use crate::operand::calculate_jal_offset;
+use crate::isa_defs::{INSTRUCTION_SIZE_BYTES, branch_condition_from_name, branch_condition_to_trits, imm_limits, split_upper_immediate};
+use crate::operand::{calculate_branch3_offset, calculate_jal_offset};
 use crate::symbol::SymbolTable;
+use prismchrono_isa::object::{ObjectFile, ObjectSection, ObjectSymbol, Relocation, RelocationKind, SymbolBinding};
+use std::collections::{HashMap, HashSet};
 
 /// Structure représentant le résultat de l'assemblage
 pub struct AssemblyResult {
-    /// Données encodées avec leurs adresses
+    /// Données encodées avec leurs adresses, triées par adresse
     pub encoded_data: Vec<(Address, EncodedData)>,
     /// Table des symboles
     pub symbol_table: SymbolTable,
+    /// Symboles exportés par `.global`, dans l'ordre de déclaration
+    pub globals: Vec<(String, Address)>,
+}
+
+/// Nom de la section par défaut (active avant toute directive `.section`)
+pub const DEFAULT_SECTION: &str = ".text";
+
+/// Alignement minimal de l'adresse de base d'une section
+const SECTION_ALIGNMENT: Address = 8;
+
+/// Section en cours de disposition
+struct Section {
+    /// Nom de la section (ex: ".text")
+    name: String,
+    /// Compteur d'adresse relatif au début de la section
+    offset: Address,
+    /// Taille occupée (plus grande adresse relative atteinte)
+    size: Address,
+    /// Plus grand alignement demandé dans la section
+    alignment: Address,
+    /// Adresse de base, fixée une fois toutes les sections dimensionnées
+    base: Address,
+}
+
+impl Section {
+    fn new(name: &str) -> Self {
+        Section {
+            name: name.to_string(),
+            offset: 0,
+            size: 0,
+            alignment: SECTION_ALIGNMENT,
+            base: 0,
+        }
+    }
+
+    /// Avance le compteur d'adresse de la section
+    fn advance(&mut self, bytes: Address) {
+        self.offset += bytes;
+        self.size = self.size.max(self.offset);
+    }
+}
+
+/// Arrondit `address` au multiple supérieur de `align`
+fn align_up(address: Address, align: Address) -> Address {
+    if align > 0 {
+        address.div_ceil(align) * align
+    } else {
+        address
+    }
+}
+
+/// Disposition du programme en mémoire
+struct Layout {
+    /// Adresse absolue de chaque ligne du programme
+    addresses: Vec<Address>,
+    /// Index dans `sections` de la section de chaque ligne
+    line_sections: Vec<usize>,
+    /// Sections, dans l'ordre de leur première apparition
+    sections: Vec<Section>,
+}
+
+/// Résolution des labels pendant la passe 2
+///
+/// En mode relogeable, les références qui ne peuvent pas être calculées dans le
+/// fichier (symboles externes, autres sections, adresses absolues) sont encodées
+/// avec un champ nul et enregistrées comme relocations.
+struct Resolver<'a> {
+    /// Table des symboles de la passe 1
+    symbol_table: &'a SymbolTable,
+    /// Section de chaque label défini (mode relogeable uniquement)
+    label_sections: Option<HashMap<String, usize>>,
+    /// Section de la ligne en cours d'encodage
+    current_section: usize,
+    /// Relocations émises: section, adresse de l'élément, type, symbole
+    relocations: Vec<(usize, Address, RelocationKind, String)>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(symbol_table: &'a SymbolTable, label_sections: Option<HashMap<String, usize>>) -> Self {
+        Resolver {
+            symbol_table,
+            label_sections,
+            current_section: 0,
+            relocations: Vec::new(),
+        }
+    }
+
+    /// Adresse cible d'une référence PC-relative, ou None si elle est relogée
+    fn pc_relative(&mut self, label: &str, address: Address, kind: RelocationKind) -> Result<Option<Address>, AssemblerError> {
+        if !self.in_current_section(label) {
+            self.relocations.push((self.current_section, address, kind, label.to_string()));
+            return Ok(None);
+        }
+        self.symbol_table.resolve(label).map(Some)
+    }
+
+    /// Adresse absolue d'un label, ou None si elle est relogée
+    fn absolute(&mut self, label: &str, address: Address, kind: RelocationKind) -> Result<Option<Address>, AssemblerError> {
+        if self.label_sections.is_some() {
+            self.relocations.push((self.current_section, address, kind, label.to_string()));
+            return Ok(None);
+        }
+        self.symbol_table.resolve(label).map(Some)
+    }
+
+    /// Adresse d'un label qui doit appartenir à la section courante (BRANCH3 n'a pas de relocation)
+    fn same_section(&self, label: &str) -> Result<Address, AssemblerError> {
+        if !self.in_current_section(label) {
+            return Err(AssemblerError::SymbolError(format!(
+                "BRANCH3 ne peut cibler qu'un label de la même section: {}",
+                label
+            )));
+        }
+        self.symbol_table.resolve(label)
+    }
+
+    /// Vérifie qu'un label est défini dans la section courante (toujours vrai hors mode relogeable)
+    fn in_current_section(&self, label: &str) -> bool {
+        self.label_sections
+            .as_ref()
+            .is_none_or(|label_sections| label_sections.get(label) == Some(&self.current_section))
+    }
 }
 
 /// Structure de l'assembleur
@@ -38,123 +165,269 @@ impl Assembler {
         let symbol_table = self.run_pass1()?;
 
         // Passe 2 : Résolution des références et encodage des instructions
-        let encoded_data = self.run_pass2(&symbol_table)?;
+        let mut resolver = Resolver::new(&symbol_table, None);
+        let mut encoded_data: Vec<(Address, EncodedData)> = self
+            .run_pass2(&mut resolver)?
+            .into_iter()
+            .map(|(_, address, data)| (address, data))
+            .collect();
+        encoded_data.sort_by_key(|(address, _)| *address);
+
+        let globals = self.collect_globals(&symbol_table)?;
 
         Ok(AssemblyResult {
             encoded_data,
             symbol_table,
+            globals,
         })
     }
 
+    /// Assemble le programme en objet relogeable (voir `prismchrono_isa::object`)
+    ///
+    /// Les adresses de l'objet sont relatives au début de chaque section. Les
+    /// références PC-relatives internes à une section sont résolues directement; les
+    /// autres deviennent des relocations, appliquées par `prismchrono_ld`. Un `.global`
+    /// sur un symbole non défini déclare un symbole externe.
+    pub fn assemble_object(&self) -> Result<ObjectFile, AssemblerError> {
+        let layout = self.layout();
+        let symbol_table = self.run_pass1()?;
+
+        let mut label_sections = HashMap::new();
+        for (line, &section) in self.program.lines.iter().zip(&layout.line_sections) {
+            if let AstNode::Label(label) = &line.node {
+                label_sections.insert(label.clone(), section);
+            }
+        }
+
+        let mut resolver = Resolver::new(&symbol_table, Some(label_sections));
+        let encoded_data = self.run_pass2(&mut resolver)?;
+
+        let mut object = ObjectFile::new();
+        object.sections = layout
+            .sections
+            .iter()
+            .map(|section| ObjectSection {
+                name: section.name.clone(),
+                alignment: section.alignment,
+                size: section.size,
+                chunks: Vec::new(),
+            })
+            .collect();
+        for (section, address, data) in encoded_data {
+            let chunk = match data {
+                EncodedData::Instruction(trits) => prismchrono_isa::object::Chunk::Instruction(trits),
+                EncodedData::Data(trytes) => prismchrono_isa::object::Chunk::Data(trytes),
+            };
+            object.sections[section].chunks.push((address - layout.sections[section].base, chunk));
+        }
+        for section in &mut object.sections {
+            section.chunks.sort_by_key(|(offset, _)| *offset);
+        }
+
+        let globals: HashSet<&str> = self
+            .program
+            .lines
+            .iter()
+            .filter_map(|line| match &line.node {
+                AstNode::Directive(Directive::Global(name)) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        for ((line, &section), &address) in self.program.lines.iter().zip(&layout.line_sections).zip(&layout.addresses) {
+            if let AstNode::Label(label) = &line.node {
+                let section = &layout.sections[section];
+                object.symbols.push(ObjectSymbol {
+                    name: label.clone(),
+                    section: section.name.clone(),
+                    offset: address - section.base,
+                    binding: if globals.contains(label.as_str()) {
+                        SymbolBinding::Global
+                    } else {
+                        SymbolBinding::Local
+                    },
+                });
+            }
+        }
+
+        object.relocations = resolver
+            .relocations
+            .into_iter()
+            .map(|(section, address, kind, symbol)| {
+                let section = &layout.sections[section];
+                Relocation {
+                    section: section.name.clone(),
+                    offset: address - section.base,
+                    kind,
+                    symbol,
+                    addend: 0,
+                }
+            })
+            .collect();
+
+        Ok(object)
+    }
+
+    /// Calcule l'adresse absolue de chaque ligne du programme
+    ///
+    /// Chaque section possède son propre compteur d'adresse; `.org` est relatif au
+    /// début de la section courante. La section `.text` commence à l'adresse 0 et les
+    /// autres sections sont placées à la suite, dans l'ordre de leur première
+    /// apparition, chacune alignée sur au moins 8 octets.
+    fn layout(&self) -> Layout {
+        let mut sections = vec![Section::new(DEFAULT_SECTION)];
+        let mut current = 0;
+        let mut placements = Vec::with_capacity(self.program.lines.len());
+
+        for line in &self.program.lines {
+            if let AstNode::Directive(Directive::Section(name)) = &line.node {
+                current = match sections.iter().position(|section| &section.name == name) {
+                    Some(index) => index,
+                    None => {
+                        sections.push(Section::new(name));
+                        sections.len() - 1
+                    }
+                };
+            }
+
+            let section = &mut sections[current];
+            placements.push((current, section.offset));
+
+            match &line.node {
+                AstNode::Directive(directive) => match directive {
+                    Directive::Org(address) => {
+                        section.offset = *address;
+                        section.size = section.size.max(section.offset);
+                    }
+                    Directive::Align(alignment) => {
+                        let align = *alignment as Address;
+                        section.alignment = section.alignment.max(align);
+                        let aligned = align_up(section.offset, align);
+                        section.advance(aligned - section.offset);
+                    }
+                    // Un tryte occupe 1 octet
+                    Directive::Tryte(_) => section.advance(1),
+                    // Un mot occupe 8 trytes = 8 octets
+                    Directive::Word(_) | Directive::WordLabel(_) => section.advance(8),
+                    Directive::Equ { .. } | Directive::Section(_) | Directive::Global(_) => {}
+                },
+                // Chaque instruction occupe INSTRUCTION_SIZE_BYTES octets
+                AstNode::Instruction(_) => section.advance(INSTRUCTION_SIZE_BYTES),
+                AstNode::Label(_) | AstNode::Empty => {}
+            }
+        }
+
+        // Placer les sections les unes à la suite des autres
+        let mut next_base = 0;
+        for section in &mut sections {
+            section.base = align_up(next_base, section.alignment);
+            next_base = section.base + section.size;
+        }
+
+        let (line_sections, addresses) = placements
+            .into_iter()
+            .map(|(section, offset)| (section, sections[section].base + offset))
+            .unzip();
+        Layout {
+            addresses,
+            line_sections,
+            sections,
+        }
+    }
+
     /// Exécute la première passe de l'assemblage
     fn run_pass1(&self) -> Result<SymbolTable, AssemblerError> {
         let mut symbol_table = SymbolTable::new();
-        let mut current_address: Address = 0;
+        let addresses = self.layout().addresses;
 
-        for line in &self.program.lines {
+        for (line, &current_address) in self.program.lines.iter().zip(&addresses) {
+            if let AstNode::Label(label) = &line.node {
+                // Définir le label avec l'adresse courante
+                symbol_table.define(label, current_address).map_err(|e| {
+                    AssemblerError::Pass1Error(format!(
+                        "Ligne {}: {}",
+                        line.line_number, e
+                    ))
+                })?;
+            }
+        }
+
+        Ok(symbol_table)
+    }
+
+    /// Exécute la deuxième passe de l'assemblage
+    ///
+    /// Retourne les données encodées avec l'index de leur section et leur adresse
+    fn run_pass2(&self, resolver: &mut Resolver) -> Result<Vec<(usize, Address, EncodedData)>, AssemblerError> {
+        let mut encoded_data = Vec::new();
+        let layout = self.layout();
+
+        for ((line, &current_address), &section) in self.program.lines.iter().zip(&layout.addresses).zip(&layout.line_sections) {
+            resolver.current_section = section;
             match &line.node {
-                AstNode::Label(label) => {
-                    // Définir le label avec l'adresse courante
-                    symbol_table.define(label, current_address).map_err(|e| {
-                        AssemblerError::Pass1Error(format!(
+                AstNode::Instruction(instruction) => {
+                    // Encoder l'instruction
+                    let encoded = self.encode_instruction(instruction, current_address, resolver, line.line_number)?;
+                    encoded_data.push((section, current_address, encoded));
+                }
+                AstNode::Directive(Directive::Tryte(value)) => {
+                    // Encoder un tryte
+                    let trytes = encode_tryte(*value).map_err(|e| {
+                        AssemblerError::Pass2Error(format!(
                             "Ligne {}: {}",
                             line.line_number, e
                         ))
                     })?;
+                    encoded_data.push((section, current_address, EncodedData::Data(trytes)));
                 }
-                AstNode::Directive(directive) => {
-                    // Traiter les directives qui affectent l'adresse
-                    match directive {
-                        Directive::Org(address) => {
-                            current_address = *address;
-                        }
-                        Directive::Align(alignment) => {
-                            // Aligner l'adresse courante
-                            let align = *alignment as Address;
-                            if align > 0 {
-                                current_address = (current_address + align - 1) / align * align;
-                            }
-                        }
-                        Directive::Tryte(_) => {
-                            // Un tryte occupe 1 octet
-                            current_address += 1;
-                        }
-                        Directive::Word(_) => {
-                            // Un mot occupe 8 trytes = 8 octets
-                            current_address += 8;
-                        }
-                    }
+                AstNode::Directive(Directive::Word(value)) => {
+                    // Encoder un mot
+                    let trytes = encode_word(*value).map_err(|e| {
+                        AssemblerError::Pass2Error(format!(
+                            "Ligne {}: {}",
+                            line.line_number, e
+                        ))
+                    })?;
+                    encoded_data.push((section, current_address, EncodedData::Data(trytes)));
                 }
-                AstNode::Instruction(_) => {
-                    // Chaque instruction occupe INSTRUCTION_SIZE_BYTES octets
-                    current_address += INSTRUCTION_SIZE_BYTES;
+                AstNode::Directive(Directive::WordLabel(label)) => {
+                    // Encoder l'adresse du label (relogée en mode objet)
+                    let address = resolver
+                        .absolute(label, current_address, RelocationKind::Word)
+                        .map_err(|e| AssemblerError::Pass2Error(format!("Ligne {}: {}", line.line_number, e)))?;
+                    let trytes = encode_word(address.unwrap_or(0) as i32).map_err(|e| {
+                        AssemblerError::Pass2Error(format!(
+                            "Ligne {}: {}",
+                            line.line_number, e
+                        ))
+                    })?;
+                    encoded_data.push((section, current_address, EncodedData::Data(trytes)));
                 }
-                AstNode::Empty => {
-                    // Les lignes vides n'affectent pas l'adresse
+                AstNode::Directive(_) | AstNode::Label(_) | AstNode::Empty => {
+                    // Les labels et les directives de placement ont déjà été traités par la disposition
                 }
             }
         }
 
-        Ok(symbol_table)
+        Ok(encoded_data)
     }
 
-    /// Exécute la deuxième passe de l'assemblage
-    fn run_pass2(&self, symbol_table: &SymbolTable) -> Result<Vec<(Address, EncodedData)>, AssemblerError> {
-        let mut encoded_data = Vec::new();
-        let mut current_address: Address = 0;
+    /// Résout les symboles exportés par `.global`
+    fn collect_globals(&self, symbol_table: &SymbolTable) -> Result<Vec<(String, Address)>, AssemblerError> {
+        let mut globals: Vec<(String, Address)> = Vec::new();
 
         for line in &self.program.lines {
-            match &line.node {
-                AstNode::Instruction(instruction) => {
-                    // Encoder l'instruction
-                    let encoded = self.encode_instruction(instruction, current_address, symbol_table, line.line_number)?;
-                    encoded_data.push((current_address, encoded));
-                    current_address += INSTRUCTION_SIZE_BYTES;
-                }
-                AstNode::Directive(directive) => {
-                    // Traiter les directives
-                    match directive {
-                        Directive::Org(address) => {
-                            current_address = *address;
-                        }
-                        Directive::Align(alignment) => {
-                            // Aligner l'adresse courante
-                            let align = *alignment as Address;
-                            if align > 0 {
-                                current_address = (current_address + align - 1) / align * align;
-                            }
-                        }
-                        Directive::Tryte(value) => {
-                            // Encoder un tryte
-                            let trytes = encode_tryte(*value).map_err(|e| {
-                                AssemblerError::Pass2Error(format!(
-                                    "Ligne {}: {}",
-                                    line.line_number, e
-                                ))
-                            })?;
-                            encoded_data.push((current_address, EncodedData::Data(trytes)));
-                            current_address += 1;
-                        }
-                        Directive::Word(value) => {
-                            // Encoder un mot
-                            let trytes = encode_word(*value).map_err(|e| {
-                                AssemblerError::Pass2Error(format!(
-                                    "Ligne {}: {}",
-                                    line.line_number, e
-                                ))
-                            })?;
-                            encoded_data.push((current_address, EncodedData::Data(trytes)));
-                            current_address += 8;
-                        }
-                    }
-                }
-                AstNode::Label(_) | AstNode::Empty => {
-                    // Les labels et les lignes vides ont déjà été traités dans la passe 1
+            if let AstNode::Directive(Directive::Global(name)) = &line.node {
+                if globals.iter().any(|(global, _)| global == name) {
+                    continue;
                 }
+                let address = symbol_table.resolve(name).map_err(|e| {
+                    AssemblerError::SymbolError(format!("Ligne {}: symbole global: {}", line.line_number, e))
+                })?;
+                globals.push((name.clone(), address));
             }
         }
 
-        Ok(encoded_data)
+        Ok(globals)
     }
 
     /// Encode une instruction
@@ -162,7 +435,7 @@ impl Assembler {
         &self,
         instruction: &Instruction,
         current_address: Address,
-        symbol_table: &SymbolTable,
+        resolver: &mut Resolver,
         line_number: usize,
     ) -> Result<EncodedData, AssemblerError> {
         match instruction {
@@ -190,19 +463,53 @@ impl Assembler {
                 })?;
                 Ok(EncodedData::Instruction(trits))
             }
-            Instruction::Jal { rd, label } => {
-                // Résoudre l'adresse du label
-                let target_address = symbol_table.resolve(label).map_err(|e| {
+            Instruction::LuiHi { rd, label } | Instruction::AddiLo { rd, label, .. } => {
+                // Adresse absolue du label, découpée en parties haute (LUI) et basse (ADDI)
+                let kind = if matches!(instruction, Instruction::LuiHi { .. }) {
+                    RelocationKind::UHi
+                } else {
+                    RelocationKind::ILo
+                };
+                let target_address = resolver.absolute(label, current_address, kind).map_err(|e| {
                     AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
                 })?;
+                let (high, low) = split_upper_immediate(target_address.unwrap_or(0) as i64).ok_or_else(|| {
+                    AssemblerError::Pass2Error(format!(
+                        "Ligne {}: Adresse de {} hors de portée de LUI/ADDI (max {})",
+                        line_number, label, imm_limits::LUI_ADDI_MAX
+                    ))
+                })?;
 
-                // Calculer l'offset pour JAL
-                let offset = calculate_jal_offset(target_address, current_address).map_err(|e| {
+                let trits = match instruction {
+                    Instruction::AddiLo { rd, rs1, .. } => encode_addi(*rd, *rs1, low, line_number),
+                    _ => encode_lui(*rd, high, line_number),
+                }
+                .map_err(|e| {
+                    AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
+                })?;
+                Ok(EncodedData::Instruction(trits))
+            }
+            Instruction::Jal { rd, label } | Instruction::Call { rd, label } => {
+                // Résoudre l'adresse du label (offset nul si la référence est relogée)
+                let target_address = resolver.pc_relative(label, current_address, RelocationKind::J).map_err(|e| {
                     AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
                 })?;
 
-                // Encoder l'instruction JAL
-                let trits = encode_jal(*rd, offset, line_number).map_err(|e| {
+                // Calculer l'offset (JAL et CALL partagent le format J)
+                let offset = match target_address {
+                    Some(target_address) => calculate_jal_offset(target_address, current_address).map_err(|e| {
+                        AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
+                    })?,
+                    None => 0,
+                };
+
+                // Encoder l'instruction
+                let trits = if matches!(instruction, Instruction::Call { .. }) {
+                    encode_call(*rd, offset, line_number)
+                } else {
+                    encode_jal(*rd, offset, line_number)
+                }
+                .map_err(|e| {
                     AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
                 })?;
 
@@ -221,39 +528,38 @@ impl Assembler {
                 Ok(EncodedData::Instruction(trits))
             }
             Instruction::Branch { rs1, rs2, condition, label } => {
-                // Résoudre l'adresse du label
-                let target_address = symbol_table.resolve(label).map_err(|e| {
+                // Résoudre l'adresse du label (offset nul si la référence est relogée)
+                let target_address = resolver.pc_relative(label, current_address, RelocationKind::B).map_err(|e| {
                     AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
                 })?;
 
                 // Calculer l'offset pour BRANCH (similaire à JAL mais avec des limites différentes)
-                let pc_after_branch = current_address + 4;
-                let diff = target_address as i64 - pc_after_branch as i64;
-                
-                // Vérifier si la différence est un multiple de 4 (alignement des instructions)
-                if diff % 4 != 0 {
-                    return Err(AssemblerError::Pass2Error(format!(
-                        "Ligne {}: L'adresse cible n'est pas alignée sur 4 octets: 0x{:X}",
-                        line_number, target_address
-                    )));
-                }
-                
-                // Convertir en nombre d'instructions (diviser par 4)
-                let offset = (diff / 4) as i32;
+                let offset = match target_address {
+                    Some(target_address) => {
+                        let pc_after_branch = current_address + 4;
+                        let diff = target_address as i64 - pc_after_branch as i64;
+
+                        // Vérifier si la différence est un multiple de 4 (alignement des instructions)
+                        if diff % 4 != 0 {
+                            return Err(AssemblerError::Pass2Error(format!(
+                                "Ligne {}: L'adresse cible n'est pas alignée sur 4 octets: 0x{:X}",
+                                line_number, target_address
+                            )));
+                        }
+
+                        // Convertir en nombre d'instructions (diviser par 4)
+                        (diff / 4) as i32
+                    }
+                    None => 0,
+                };
                 
                 // Déterminer la condition de branchement
-                let condition_trits = match condition.as_str() {
-                    "eq" => cond::EQ,
-                    "ne" => cond::NE,
-                    "lt" => cond::LT,
-                    "ge" => cond::GE,
-                    "gt" => cond::GT,
-                    "le" => cond::LE,
-                    _ => return Err(AssemblerError::Pass2Error(format!(
+                let condition_trits = branch_condition_from_name(condition)
+                    .map(branch_condition_to_trits)
+                    .ok_or_else(|| AssemblerError::Pass2Error(format!(
                         "Ligne {}: Condition de branchement invalide: {}",
                         line_number, condition
-                    ))),
-                };
+                    )))?;
 
                 // Encoder l'instruction BRANCH
                 let trits = encode_branch(*rs1, *rs2, condition_trits, offset, line_number).map_err(|e| {
@@ -293,13 +599,36 @@ impl Assembler {
                 Ok(EncodedData::Instruction(trits))
             }
             Instruction::Csrrw { rd, csr_code, rs1 } => {
-                let trits = encode_csrrw(*rd, &csr_code, *rs1, line_number).map_err(|e| {
+                let trits = encode_csrrw(*rd, csr_code, *rs1, line_number).map_err(|e| {
                     AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
                 })?;
                 Ok(EncodedData::Instruction(trits))
             }
             Instruction::Csrrs { rd, csr_code, rs1 } => {
-                let trits = encode_csrrs(*rd, &csr_code, *rs1, line_number).map_err(|e| {
+                let trits = encode_csrrs(*rd, csr_code, *rs1, line_number).map_err(|e| {
+                    AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
+                })?;
+                Ok(EncodedData::Instruction(trits))
+            }
+            Instruction::Branch3 { rs1, label_neg, label_zero, label_pos } => {
+                // Résoudre les trois cibles en offsets relatifs au BRANCH3
+                let mut offsets = [0; 3];
+                for (offset, label) in offsets.iter_mut().zip([label_neg, label_zero, label_pos]) {
+                    let target_address = resolver.same_section(label).map_err(|e| {
+                        AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
+                    })?;
+                    *offset = calculate_branch3_offset(target_address, current_address).map_err(|e| {
+                        AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
+                    })?;
+                }
+
+                let trits = encode_branch3(*rs1, offsets[0], offsets[1], offsets[2], line_number).map_err(|e| {
+                    AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
+                })?;
+                Ok(EncodedData::Instruction(trits))
+            }
+            _ => {
+                let trits = encode_extension(instruction, line_number).map_err(|e| {
                     AssemblerError::Pass2Error(format!("Ligne {}: {}", line_number, e))
                 })?;
                 Ok(EncodedData::Instruction(trits))
@@ -308,10 +637,45 @@ impl Assembler {
     }
 }
 
+/// Encode une instruction qui ne référence pas de label
+fn encode_extension(instruction: &Instruction, line: usize) -> Result<[crate::core_types::Trit; 12], AssemblerError> {
+    match instruction {
+        Instruction::Alu { op, rd, rs1, rs2 } => encode_alu(op.clone(), *rd, *rs1, *rs2, line),
+        Instruction::AluImm { op, rd, rs1, imm } => encode_alu_imm(op.clone(), *rd, *rs1, *imm, line),
+        Instruction::Loadw { rd, rs1, imm } => encode_loadw(*rd, *rs1, *imm, line),
+        Instruction::Loadt { rd, rs1, imm } => encode_loadt(*rd, *rs1, *imm, line),
+        Instruction::Loadtu { rd, rs1, imm } => encode_loadtu(*rd, *rs1, *imm, line),
+        Instruction::Jalr { rd, rs1, imm } => encode_jalr(*rd, *rs1, *imm, line),
+        Instruction::Auipc { rd, imm } => encode_auipc(*rd, *imm, line),
+        Instruction::Csrrc { rd, csr_code, rs1 } => encode_csrrc(*rd, csr_code, *rs1, line),
+        Instruction::Csri { csr_code, rs1, imm } => encode_csri(csr_code, *rs1, *imm, line),
+        Instruction::Ternary { op, rd, rs1, rs2 } => encode_ternary(*op, *rd, *rs1, *rs2, line),
+        Instruction::TernaryShift { op, rd, rs1, imm } => encode_tshift(*op, *rd, *rs1, *imm, line),
+        Instruction::Loadt3 { rd, rs1, imm } => encode_loadt3(*rd, *rs1, *imm, line),
+        Instruction::Storet3 { rs1, rs2, imm } => encode_storet3(*rs1, *rs2, *imm, line),
+        Instruction::Tmemcpy { rd, rs1, rs2 } => encode_tmemcpy(*rd, *rs1, *rs2, line),
+        Instruction::Tmemset { rd, rs1, rs2 } => encode_tmemset(*rd, *rs1, *rs2, line),
+        Instruction::Maddw { rd, rs1, rs2, rs3 } => encode_maddw(*rd, *rs1, *rs2, *rs3, line),
+        Instruction::Msubw { rd, rs1, rs2, rs3 } => encode_msubw(*rd, *rs1, *rs2, *rs3, line),
+        Instruction::Tsel { rd, rs1, rs2, rs3 } => encode_tsel(*rd, *rs1, *rs2, *rs3, line),
+        Instruction::SpecialState { op, rd, rs1 } => encode_special_state(*op, *rd, *rs1, line),
+        Instruction::Base24 { op, rd, rs1, rs2 } => encode_base24(*op, *rd, *rs1, *rs2, line),
+        Instruction::Vector { op, vd, vs1, vs2 } => encode_vector(*op, *vd, *vs1, *vs2, line),
+        Instruction::VectorDot { rd, vs1, vs2 } => encode_vector_dot(*rd, *vs1, *vs2, line),
+        Instruction::VectorReduce { op, rd, vs } => encode_vector_reduce(*op, *rd, *vs, line),
+        Instruction::VectorLoad { vd, rs1 } => encode_vector_load(*vd, *rs1, line),
+        Instruction::VectorStore { vs, rs1 } => encode_vector_store(*vs, *rs1, line),
+        _ => Err(AssemblerError::EncodeError {
+            line,
+            message: format!("Instruction non prise en charge: {:?}", instruction),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{AstNode, Directive, Instruction, Program, SourceLine};
+    use crate::ast::{AstNode, Directive, Instruction, Program};
     use crate::core_types::Address;
 
     #[test]
@@ -368,4 +732,152 @@ mod tests {
         assert_eq!(result.encoded_data[0].0, 0); // Adresse de NOP
         assert_eq!(result.encoded_data[1].0, 4); // Adresse de HALT
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_pass2_branch3() {
+        // BRANCH3 R1, neg, zero, pos avec des cibles relatives au BRANCH3
+        let mut program = Program::new();
+        program.add_line(1, AstNode::Instruction(Instruction::Branch3 {
+            rs1: 1,
+            label_neg: "neg".to_string(),
+            label_zero: "zero".to_string(),
+            label_pos: "pos".to_string(),
+        }));
+        program.add_line(2, AstNode::Label("neg".to_string()));
+        program.add_line(3, AstNode::Instruction(Instruction::Nop));
+        program.add_line(4, AstNode::Label("zero".to_string()));
+        program.add_line(5, AstNode::Instruction(Instruction::Nop));
+        program.add_line(6, AstNode::Instruction(Instruction::Nop));
+        program.add_line(7, AstNode::Label("pos".to_string()));
+        program.add_line(8, AstNode::Instruction(Instruction::Halt));
+
+        let assembler = Assembler::new(program);
+        let result = assembler.assemble().unwrap();
+
+        let EncodedData::Instruction(trits) = &result.encoded_data[0].1 else {
+            panic!("BRANCH3 doit être encodé comme une instruction");
+        };
+        assert_eq!(
+            prismchrono_isa::decode(trits),
+            Ok(prismchrono_isa::Instruction::Branch3 {
+                rs1: prismchrono_isa::Register::R1,
+                offset_neg: 1,
+                offset_zero: 2,
+                offset_pos: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_sections_and_globals() {
+        // Les données déclarées avant le code sont placées après la section .text
+        let mut program = Program::new();
+        program.add_line(1, AstNode::Directive(Directive::Section(".data".to_string())));
+        program.add_line(2, AstNode::Label("value".to_string()));
+        program.add_line(3, AstNode::Directive(Directive::Word(7)));
+        program.add_line(4, AstNode::Directive(Directive::Section(".text".to_string())));
+        program.add_line(5, AstNode::Directive(Directive::Global("_start".to_string())));
+        program.add_line(6, AstNode::Label("_start".to_string()));
+        program.add_line(7, AstNode::Instruction(Instruction::Nop));
+        program.add_line(8, AstNode::Instruction(Instruction::Nop));
+        program.add_line(9, AstNode::Instruction(Instruction::Halt));
+
+        let assembler = Assembler::new(program);
+        let result = assembler.assemble().unwrap();
+
+        assert_eq!(result.symbol_table.resolve("_start").unwrap(), 0);
+        assert_eq!(result.symbol_table.resolve("value").unwrap(), 16); // 12 octets de code alignés sur 8
+        assert_eq!(result.globals, vec![("_start".to_string(), 0)]);
+        let addresses: Vec<Address> = result.encoded_data.iter().map(|(address, _)| *address).collect();
+        assert_eq!(addresses, vec![0, 4, 8, 16]);
+    }
+
+    #[test]
+    fn test_undefined_global() {
+        let mut program = Program::new();
+        program.add_line(1, AstNode::Directive(Directive::Global("absent".to_string())));
+        program.add_line(2, AstNode::Instruction(Instruction::Halt));
+
+        let assembler = Assembler::new(program);
+        assert!(assembler.assemble().is_err());
+    }
+
+    #[test]
+    fn test_pass1_sizes_after_macro_and_pseudo_expansion() {
+        // Macros et pseudo-instructions sont développées avant la passe 1
+        let source = "\
+.macro CHARGE reg, valeur
+    MOVI \\reg, \\valeur
+.endm
+debut: CHARGE R1, 4096   # LUI + ADDI
+    CHARGE R2, 486        # LUI seul (partie basse nulle)
+    BEQZ R1, fin          # ADDI + BRANCH
+fin: HALT";
+        let expanded = crate::macros::expand_macros(source).unwrap();
+        let mut lexer = crate::lexer::Lexer::with_line_map(&expanded.text, expanded.line_map);
+        let mut parser = crate::parser::Parser::new(lexer.tokenize().unwrap());
+        let assembler = Assembler::new(parser.parse().unwrap());
+
+        let symbol_table = assembler.run_pass1().unwrap();
+        assert_eq!(symbol_table.resolve("debut").unwrap(), 0);
+        assert_eq!(symbol_table.resolve("fin").unwrap(), 20);
+
+        let result = assembler.assemble().unwrap();
+        assert_eq!(result.encoded_data.len(), 6);
+    }
+
+    #[test]
+    fn test_assemble_object_relocations() {
+        let source = "\
+.global _start
+.global afficher
+_start:
+    CALL afficher         # externe: relocation J
+    LA R1, message        # adresse absolue: relocations UHI et ILO
+boucle:
+    BEQZ R1, boucle       # même section: résolu directement
+    HALT
+.section .data
+message: .word _start     # relocation WORD";
+        let expanded = crate::macros::expand_macros(source).unwrap();
+        let mut lexer = crate::lexer::Lexer::with_line_map(&expanded.text, expanded.line_map);
+        let mut parser = crate::parser::Parser::new(lexer.tokenize().unwrap());
+        let assembler = Assembler::new(parser.parse().unwrap());
+        let object = assembler.assemble_object().unwrap();
+
+        let sizes: Vec<(&str, u32)> = object.sections.iter().map(|s| (s.name.as_str(), s.size)).collect();
+        assert_eq!(sizes, vec![(".text", 24), (".data", 8)]);
+
+        let symbols: Vec<(&str, &str, u32, SymbolBinding)> = object
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.section.as_str(), symbol.offset, symbol.binding))
+            .collect();
+        assert_eq!(
+            symbols,
+            vec![
+                ("_start", ".text", 0, SymbolBinding::Global),
+                ("boucle", ".text", 12, SymbolBinding::Local),
+                ("message", ".data", 0, SymbolBinding::Local),
+            ]
+        );
+
+        let relocations: Vec<(&str, u32, RelocationKind, &str)> = object
+            .relocations
+            .iter()
+            .map(|r| (r.section.as_str(), r.offset, r.kind, r.symbol.as_str()))
+            .collect();
+        assert_eq!(
+            relocations,
+            vec![
+                (".text", 0, RelocationKind::J, "afficher"),
+                (".text", 4, RelocationKind::UHi, "message"),
+                (".text", 8, RelocationKind::ILo, "message"),
+                (".data", 0, RelocationKind::Word, "_start"),
+            ]
+        );
+
+        // Un assemblage absolu n'accepte pas de symbole externe
+        assert!(assembler.assemble().is_err());
+    }
+}
@@ -4,6 +4,7 @@
 //! du code assembleur après l'analyse syntaxique.
 
 use crate::core_types::Address;
+use prismchrono_isa::{AluOp, Base24Op, SpecialStateOp, TernaryOp, TernaryShiftOp, VectorOp, VectorReduceOp};
 
 /// Représente un opérande dans une instruction
 #[derive(Debug, Clone, PartialEq)]
@@ -34,6 +35,17 @@ pub enum Instruction {
         rd: u8,
         imm: i32,
     },
+    /// Partie haute d'une adresse: LUI rd, %hi(label) (produit par LA)
+    LuiHi {
+        rd: u8,
+        label: String,
+    },
+    /// Partie basse d'une adresse: ADDI rd, rs1, %lo(label) (produit par LA)
+    AddiLo {
+        rd: u8,
+        rs1: u8,
+        label: String,
+    },
     /// Jump And Link: JAL rd, label
     Jal {
         rd: u8,
@@ -70,6 +82,54 @@ pub enum Instruction {
         rs1: u8,
         rs2: u8,
     },
+    /// Opération ALU registre-registre: MUL/DIV/MOD/TRITINV/.../IS_SPECIAL_TRYTE rd, rs1, rs2
+    Alu {
+        op: AluOp,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+    },
+    /// Opération ALU avec immédiat: SUBI/MINI/MAXI rd, rs1, imm
+    AluImm {
+        op: AluOp,
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Load Word: LOADW rd, rs1, imm
+    Loadw {
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Load Tryte (signé): LOADT rd, rs1, imm
+    Loadt {
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Load Tryte Unsigned: LOADTU rd, rs1, imm
+    Loadtu {
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Jump And Link Register: JALR rd, rs1, imm
+    Jalr {
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Add Upper Immediate to PC: AUIPC rd, imm
+    Auipc {
+        rd: u8,
+        imm: i32,
+    },
+    /// Appel de sous-programme: CALL rd, label
+    Call {
+        rd: u8,
+        label: String,
+    },
     /// Environment Call: ECALL
     Ecall,
     /// Environment Break: EBREAK
@@ -87,7 +147,127 @@ pub enum Instruction {
         rd: u8,
         csr_code: String,
         rs1: u8,
-    }
+    },
+    /// CSR Read & Clear: CSRRC_T rd, csr_code, rs1
+    Csrrc {
+        rd: u8,
+        csr_code: String,
+        rs1: u8,
+    },
+    /// Opération CSR avec immédiat: CSRI_T csr_code, rs1, imm
+    Csri {
+        csr_code: String,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Opération trit à trit: TMIN/TMAX/TSUM/TCMP3 rd, rs1, rs2
+    Ternary {
+        op: TernaryOp,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+    },
+    /// Rotation/décalage ternaire: TROTL/TROTR/TSHIFTL/TSHIFTR rd, rs1, imm
+    TernaryShift {
+        op: TernaryShiftOp,
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Branchement ternaire: BRANCH3 rs1, label_neg, label_zero, label_pos
+    Branch3 {
+        rs1: u8,
+        label_neg: String,
+        label_zero: String,
+        label_pos: String,
+    },
+    /// Load 3 Trytes: LOADT3 rd, rs1, imm
+    Loadt3 {
+        rd: u8,
+        rs1: u8,
+        imm: i32,
+    },
+    /// Store 3 Trytes: STORET3 rs1, rs2, imm
+    Storet3 {
+        rs1: u8,
+        rs2: u8,
+        imm: i32,
+    },
+    /// Copie mémoire: TMEMCPY rd, rs1, rs2 (rs2 = nombre de trytes)
+    Tmemcpy {
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+    },
+    /// Initialisation mémoire: TMEMSET rd, rs1, rs2 (rs2 = nombre de trytes)
+    Tmemset {
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+    },
+    /// Multiplication-addition: MADDW rd, rs1, rs2, rs3
+    Maddw {
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        rs3: u8,
+    },
+    /// Multiplication-soustraction: MSUBW rd, rs1, rs2, rs3
+    Msubw {
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        rs3: u8,
+    },
+    /// Sélection ternaire: TSEL rd, rs1, rs2, rs3
+    Tsel {
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        rs3: u8,
+    },
+    /// États spéciaux: ISNULL/ISNAN/ISUNDEF/SETNULL/SETNAN/SETUNDEF rd, rs1
+    SpecialState {
+        op: SpecialStateOp,
+        rd: u8,
+        rs1: u8,
+    },
+    /// Arithmétique base 24: ADDB24/SUBB24/MULB24/DIVB24/CVTB24/CVTFRB24 rd, rs1, rs2
+    Base24 {
+        op: Base24Op,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+    },
+    /// Opération vectorielle: TVADD/TVSUB/TVMUL/TVMAC vd, vs1, vs2 (TVMAC accumule dans vd)
+    Vector {
+        op: VectorOp,
+        vd: u8,
+        vs1: u8,
+        vs2: u8,
+    },
+    /// Produit scalaire: TVDOT rd, vs1, vs2
+    VectorDot {
+        rd: u8,
+        vs1: u8,
+        vs2: u8,
+    },
+    /// Réduction vectorielle: TVSUM/TVMIN/TVMAX/TVAVG rd, vs
+    VectorReduce {
+        op: VectorReduceOp,
+        rd: u8,
+        vs: u8,
+    },
+    /// Chargement vectoriel: TVLOAD vd, rs1 (8 mots consécutifs à l'adresse rs1)
+    VectorLoad {
+        vd: u8,
+        rs1: u8,
+    },
+    /// Stockage vectoriel: TVSTORE vs, rs1 (8 mots consécutifs à l'adresse rs1)
+    VectorStore {
+        vs: u8,
+        rs1: u8,
+    },
 }
 
 /// Représente une directive assembleur
@@ -101,6 +281,14 @@ pub enum Directive {
     Tryte(i32),
     /// .word <value> - Définit un mot (8 trytes)
     Word(i32),
+    /// .word <label> - Définit un mot contenant l'adresse d'un label
+    WordLabel(String),
+    /// .equ <nom>, <valeur> - Définit une constante symbolique
+    Equ { name: String, value: i32 },
+    /// .section <nom> - Bascule vers une section (compteur d'adresse propre)
+    Section(String),
+    /// .global <symbole> - Exporte un symbole dans le fichier de sortie
+    Global(String),
 }
 
 /// Représente un nœud dans l'AST
@@ -132,6 +320,12 @@ pub struct Program {
     pub lines: Vec<SourceLine>,
 }
 
+impl Default for Program {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Program {
     /// Crée un nouveau programme vide
     pub fn new() -> Self {
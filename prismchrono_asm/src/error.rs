@@ -4,7 +4,6 @@
 //! pendant le processus d'assemblage.
 
 use std::fmt;
-use std::io;
 use thiserror::Error;
 
 /// Erreur principale de l'assembleur
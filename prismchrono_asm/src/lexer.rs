@@ -3,7 +3,7 @@
 //! Ce module est responsable de la tokenisation du code source assembleur
 //! en une séquence de tokens qui seront ensuite analysés par le parser.
 
-use crate::error::{AssemblerError, LexerError};
+use crate::error::AssemblerError;
 
 /// Types de tokens reconnus par le lexer
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +12,8 @@ pub enum TokenType {
     Mnemonic(String),
     /// Registre (ex: R0, R1, R2)
     Register(u8),
+    /// Registre vectoriel de la TVPU (ex: V0, V1, V2)
+    VectorRegister(u8),
     /// Nombre (décimal ou ternaire)
     Number(i32),
     /// Définition de label (ex: "label:")
@@ -51,17 +53,37 @@ pub struct Lexer {
     current_column: usize,
     /// Tokens générés
     tokens: Vec<Token>,
+    /// Numéro de ligne d'origine (0-based) de chaque ligne, après expansion des macros
+    line_map: Vec<usize>,
 }
 
 impl Lexer {
     /// Crée un nouveau lexer à partir du code source
     pub fn new(source: &str) -> Self {
         let lines: Vec<String> = source.lines().map(|s| s.to_string()).collect();
+        let line_map = (0..lines.len()).collect();
         Lexer {
             lines,
             current_line: 0,
             current_column: 0,
             tokens: Vec::new(),
+            line_map,
+        }
+    }
+
+    /// Crée un lexer sur un source déjà développé (macros), chaque ligne étant
+    /// rattachée à sa ligne d'origine pour les messages d'erreur
+    pub fn with_line_map(source: &str, line_map: Vec<usize>) -> Self {
+        let mut lexer = Lexer::new(source);
+        lexer.line_map = line_map;
+        lexer
+    }
+
+    /// Numéro de ligne d'origine (0-based) de la ligne courante
+    fn source_line(&self) -> usize {
+        match self.line_map.get(self.current_line) {
+            Some(&line) => line,
+            None => self.line_map.last().map_or(self.current_line, |&line| line + 1),
         }
     }
 
@@ -76,7 +98,7 @@ impl Lexer {
         // Ajouter un token EOF à la fin
         self.tokens.push(Token {
             token_type: TokenType::EOF,
-            line: self.current_line,
+            line: self.source_line(),
             column: 0,
         });
 
@@ -88,6 +110,7 @@ impl Lexer {
         // Cloner la ligne pour éviter les problèmes d'emprunt
         let line_content = self.lines[self.current_line].clone();
         let mut chars = line_content.chars().peekable();
+        let line_start = self.tokens.len();
 
         while let Some(&c) = chars.peek() {
             match c {
@@ -103,7 +126,7 @@ impl Lexer {
                     let comment: String = chars.collect();
                     self.tokens.push(Token {
                         token_type: TokenType::Comment(comment.trim().to_string()),
-                        line: self.current_line,
+                        line: self.source_line(),
                         column: self.current_column,
                     });
                     self.current_column = line_content.len();
@@ -115,7 +138,7 @@ impl Lexer {
                     chars.next();
                     self.tokens.push(Token {
                         token_type: TokenType::Comma,
-                        line: self.current_line,
+                        line: self.source_line(),
                         column: self.current_column,
                     });
                     self.current_column += 1;
@@ -128,7 +151,7 @@ impl Lexer {
                     let directive = self.read_identifier(&mut chars);
                     self.tokens.push(Token {
                         token_type: TokenType::Directive(directive),
-                        line: self.current_line,
+                        line: self.source_line(),
                         column: start_column,
                     });
                 }
@@ -139,7 +162,7 @@ impl Lexer {
                     let number = self.read_number(&mut chars)?;
                     self.tokens.push(Token {
                         token_type: TokenType::Number(number),
-                        line: self.current_line,
+                        line: self.source_line(),
                         column: start_column,
                     });
                 }
@@ -154,40 +177,56 @@ impl Lexer {
                         chars.next(); // Consommer le ':'
                         self.tokens.push(Token {
                             token_type: TokenType::LabelDef(identifier),
-                            line: self.current_line,
+                            line: self.source_line(),
                             column: start_column,
                         });
                         self.current_column += 1;
-                    } else if identifier.starts_with('R') && identifier.len() > 1 {
-                        // Registre (ex: R0, R1, ...)
-                        if let Ok(reg_num) = identifier[1..].parse::<u8>() {
-                            if reg_num <= 7 { // PrismChrono a 8 registres (R0-R7)
-                                self.tokens.push(Token {
-                                    token_type: TokenType::Register(reg_num),
-                                    line: self.current_line,
-                                    column: start_column,
-                                });
-                            } else {
-                                return Err(AssemblerError::LexerError {
-                                    line: self.current_line + 1,
-                                    message: format!("Registre invalide: R{}", reg_num),
-                                });
-                            }
+                    } else if let Some(reg_num) = Self::register_number(&identifier) {
+                        // Registre (ex: R0, R1, ... ou r0, r1, ...)
+                        if reg_num <= 7 { // PrismChrono a 8 registres (R0-R7)
+                            self.tokens.push(Token {
+                                token_type: TokenType::Register(reg_num),
+                                line: self.source_line(),
+                                column: start_column,
+                            });
                         } else {
-                            // Considérer comme un label si ce n'est pas un registre valide
+                            return Err(AssemblerError::LexerError {
+                                line: self.source_line() + 1,
+                                message: format!("Registre invalide: R{}", reg_num),
+                            });
+                        }
+                    } else if let Some(reg_num) = Self::vector_register_number(&identifier) {
+                        // Registre vectoriel (ex: V0, V1, ... ou v0, v1, ...)
+                        if reg_num <= 7 { // La TVPU a 8 registres vectoriels (V0-V7)
                             self.tokens.push(Token {
-                                token_type: TokenType::LabelRef(identifier),
-                                line: self.current_line,
+                                token_type: TokenType::VectorRegister(reg_num),
+                                line: self.source_line(),
                                 column: start_column,
                             });
+                        } else {
+                            return Err(AssemblerError::LexerError {
+                                line: self.source_line() + 1,
+                                message: format!("Registre vectoriel invalide: V{}", reg_num),
+                            });
                         }
                     } else {
                         // Vérifier si c'est un mnémonique ou une référence à un label
                         let upper_id = identifier.to_uppercase();
+                        let statement_start = self.tokens[line_start..]
+                            .iter()
+                            .all(|token| matches!(token.token_type, TokenType::LabelDef(_)));
                         match upper_id.as_str() {
                             // Instructions de base
                             "NOP" | "HALT" | "ADDI" | "LUI" | "JAL" | "STOREW" | "STORET" | "BRANCH" | "ADD" | "SUB" | 
-                            "ECALL" | "EBREAK" | "MRET_T" | "CSRRW_T" | "CSRRS_T" |
+                            "ECALL" | "EBREAK" | "MRET_T" | "CSRRW_T" | "CSRRS_T" | "CSRRC_T" | "CSRI_T" |
+
+                            // Instructions ALU (format R et I)
+                            "MUL" | "DIV" | "MOD" | "TRITINV" | "TRITMIN" | "TRITMAX" | "AND" | "OR" | "XOR" |
+                            "SHL" | "SHR" | "CMP" | "CMP3" | "ABS_T" | "SIGNUM_T" | "EXTRACT_TRYTE" | "INSERT_TRYTE" |
+                            "CHECKW_VALID" | "IS_SPECIAL_TRYTE" | "SUBI" | "MINI" | "MAXI" |
+
+                            // Chargements, sauts indirects et adressage relatif au PC
+                            "LOADW" | "LOADT" | "LOADTU" | "JALR" | "AUIPC" | "CALL" |
                             
                             // Instructions de manipulation de trits
                             "TMIN" | "TMAX" | "TSUM" | "TCMP3" | "TROTL" | "TROTR" | "TSHIFTL" | "TSHIFTR" |
@@ -213,6 +252,7 @@ impl Lexer {
                             
                             // Instructions vectorielles ternaires (TVPU)
                             "TVADD" | "TVSUB" | "TVMUL" | "TVDOT" | "TVMAC" | "TVSUM" | "TVMIN" | "TVMAX" | "TVAVG" |
+                            "TVLOAD" | "TVSTORE" |
                             
                             // Instructions cryptographiques ternaires
                             "TSHA3" | "TAES" | "TRNG" | "THE_ADD" | "THE_MUL" |
@@ -221,7 +261,18 @@ impl Lexer {
                             "TCOMPRESS" | "TDECOMPRESS" => {
                                 self.tokens.push(Token {
                                     token_type: TokenType::Mnemonic(upper_id),
-                                    line: self.current_line,
+                                    line: self.source_line(),
+                                    column: start_column,
+                                });
+                            }
+
+                            // Pseudo-instructions (développées par le parser), reconnues seulement en
+                            // tête d'instruction pour que `j`, `neg`, ... restent utilisables comme symboles
+                            "MOVI" | "LI" | "LA" | "MV" | "MOV" | "NEG" | "NOT" | "J" | "RET" |
+                            "BEQZ" | "BNEZ" | "BLTZ" | "BGEZ" | "BGTZ" | "BLEZ" if statement_start => {
+                                self.tokens.push(Token {
+                                    token_type: TokenType::Mnemonic(upper_id),
+                                    line: self.source_line(),
                                     column: start_column,
                                 });
                             }
@@ -229,7 +280,7 @@ impl Lexer {
                                 // Si ce n'est pas un mnémonique reconnu, c'est une référence à un label
                                 self.tokens.push(Token {
                                     token_type: TokenType::LabelRef(identifier),
-                                    line: self.current_line,
+                                    line: self.source_line(),
                                     column: start_column,
                                 });
                             }
@@ -240,7 +291,7 @@ impl Lexer {
                 // Caractère non reconnu
                 _ => {
                     return Err(AssemblerError::LexerError {
-                        line: self.current_line + 1,
+                        line: self.source_line() + 1,
                         message: format!("Caractère non reconnu: {}", c),
                     });
                 }
@@ -250,13 +301,23 @@ impl Lexer {
         // Ajouter un token EOL à la fin de chaque ligne
         self.tokens.push(Token {
             token_type: TokenType::EOL,
-            line: self.current_line,
+            line: self.source_line(),
             column: line_content.len(),
         });
 
         Ok(())
     }
 
+    /// Numéro de registre d'un identifiant de la forme `R<n>` ou `r<n>`
+    fn register_number(identifier: &str) -> Option<u8> {
+        identifier.strip_prefix(['R', 'r'])?.parse::<u8>().ok()
+    }
+
+    /// Numéro de registre vectoriel d'un identifiant de la forme `V<n>` ou `v<n>`
+    fn vector_register_number(identifier: &str) -> Option<u8> {
+        identifier.strip_prefix(['V', 'v'])?.parse::<u8>().ok()
+    }
+
     /// Lit un identifiant (mnémonique, registre ou label)
     fn read_identifier<I>(&mut self, chars: &mut std::iter::Peekable<I>) -> String
     where
@@ -284,12 +345,12 @@ impl Lexer {
         let mut is_hex = false;
 
         // Gérer le signe
-        if let Some(&c) = chars.peek() {
-            if c == '-' || c == '+' {
-                number_str.push(c);
-                chars.next();
-                self.current_column += 1;
-            }
+        if let Some(&c) = chars.peek()
+            && (c == '-' || c == '+')
+        {
+            number_str.push(c);
+            chars.next();
+            self.current_column += 1;
         }
 
         // Vérifier si c'est un nombre hexadécimal
@@ -308,7 +369,7 @@ impl Lexer {
 
         // Lire les chiffres
         while let Some(&c) = chars.peek() {
-            if (is_hex && c.is_digit(16)) || (!is_hex && c.is_digit(10)) {
+            if (is_hex && c.is_ascii_hexdigit()) || (!is_hex && c.is_ascii_digit()) {
                 number_str.push(c);
                 chars.next();
                 self.current_column += 1;
@@ -321,14 +382,14 @@ impl Lexer {
         if is_hex {
             i32::from_str_radix(&number_str[2..], 16).map_err(|_| {
                 AssemblerError::LexerError {
-                    line: self.current_line + 1,
+                    line: self.source_line() + 1,
                     message: format!("Nombre hexadécimal invalide: {}", number_str),
                 }
             })
         } else {
             number_str.parse::<i32>().map_err(|_| {
                 AssemblerError::LexerError {
-                    line: self.current_line + 1,
+                    line: self.source_line() + 1,
                     message: format!("Nombre décimal invalide: {}", number_str),
                 }
             })
@@ -424,13 +485,13 @@ mod tests {
         assert_eq!(tokens[5].token_type, TokenType::Register(3));
         assert_eq!(tokens[6].token_type, TokenType::EOL);
         
-        // Vérifier TVADD V1, V2, V3 (les registres vectoriels sont traités comme des labels pour l'instant)
+        // Vérifier TVADD V1, V2, V3
         assert_eq!(tokens[7].token_type, TokenType::Mnemonic("TVADD".to_string()));
-        assert_eq!(tokens[8].token_type, TokenType::LabelRef("V1".to_string()));
+        assert_eq!(tokens[8].token_type, TokenType::VectorRegister(1));
         assert_eq!(tokens[9].token_type, TokenType::Comma);
-        assert_eq!(tokens[10].token_type, TokenType::LabelRef("V2".to_string()));
+        assert_eq!(tokens[10].token_type, TokenType::VectorRegister(2));
         assert_eq!(tokens[11].token_type, TokenType::Comma);
-        assert_eq!(tokens[12].token_type, TokenType::LabelRef("V3".to_string()));
+        assert_eq!(tokens[12].token_type, TokenType::VectorRegister(3));
         assert_eq!(tokens[13].token_type, TokenType::EOL);
         
         // Vérifier BRANCH3 R1, 10, 20, 30
@@ -445,4 +506,21 @@ mod tests {
         assert_eq!(tokens[22].token_type, TokenType::EOL);
         assert_eq!(tokens[23].token_type, TokenType::EOF);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_tokenize_lowercase_registers_and_pseudo_instructions() {
+        let source = "movi r1, 5\nj: JAL R2, j\nret";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+
+        assert_eq!(tokens[0].token_type, TokenType::Mnemonic("MOVI".to_string()));
+        assert_eq!(tokens[1].token_type, TokenType::Register(1));
+        // Un nom de pseudo-instruction hors tête d'instruction reste un symbole
+        assert_eq!(tokens[5].token_type, TokenType::LabelDef("j".to_string()));
+        assert_eq!(tokens[6].token_type, TokenType::Mnemonic("JAL".to_string()));
+        assert_eq!(tokens[9].token_type, TokenType::LabelRef("j".to_string()));
+        assert_eq!(tokens[11].token_type, TokenType::Mnemonic("RET".to_string()));
+
+        assert!(Lexer::new("ADD r8, r1, r2").tokenize().is_err());
+    }
+}
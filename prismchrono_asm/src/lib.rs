@@ -0,0 +1,31 @@
+//! PrismChrono Assembleur - Bibliothèque
+//!
+//! Expose les étapes de l'assemblage (macros, lexer, parser, deux passes, sortie)
+//! au programme `prismchrono_asm` et aux outils qui assemblent du code à la volée,
+//! comme la suite de conformité du simulateur.
+
+pub mod core_types;
+pub mod error;
+pub mod lexer;
+pub mod parser;
+pub mod ast;
+pub mod symbol;
+pub mod assembler;
+pub mod encoder;
+pub mod operand;
+pub mod output;
+pub mod isa_defs;
+pub mod pseudo;
+pub mod macros;
+
+use assembler::{Assembler, AssemblyResult};
+use error::AssemblerError;
+
+/// Assemble un texte source complet: expansion des macros, tokenisation, analyse
+/// syntaxique puis assemblage en deux passes
+pub fn assemble_source(source: &str) -> Result<AssemblyResult, AssemblerError> {
+    let expanded = macros::expand_macros(source)?;
+    let tokens = lexer::Lexer::with_line_map(&expanded.text, expanded.line_map).tokenize()?;
+    let program = parser::Parser::new(tokens).parse()?;
+    Assembler::new(program).assemble()
+}
@@ -0,0 +1,316 @@
+//! Module d'expansion des macros pour l'assembleur PrismChrono
+//!
+//! Les macros sont développées au niveau du texte source, avant la tokenisation,
+//! afin que la première passe de l'assembleur ne voie que des instructions
+//! finales et calcule des tailles exactes.
+//!
+//! Syntaxe:
+//!
+//! ```text
+//! .macro INCR reg, n
+//! boucle\@:
+//!     ADDI \reg, \reg, \n
+//! .endm
+//!
+//!     INCR R1, 5
+//! ```
+//!
+//! Dans le corps, `\param` est remplacé par l'argument correspondant et `\@` par
+//! un compteur propre à chaque expansion, ce qui permet de définir des labels
+//! locaux. Une macro peut en invoquer une autre (profondeur limitée).
+
+use crate::error::AssemblerError;
+use std::collections::HashMap;
+
+/// Profondeur maximale d'expansion (protège contre les macros récursives)
+const MAX_EXPANSION_DEPTH: usize = 32;
+
+/// Source développé: le texte et, pour chaque ligne, sa ligne d'origine (0-based)
+#[derive(Debug, Clone)]
+pub struct ExpandedSource {
+    /// Texte source sans définitions de macros, invocations développées
+    pub text: String,
+    /// Ligne d'origine de chaque ligne de `text`
+    pub line_map: Vec<usize>,
+}
+
+/// Définition d'une macro
+#[derive(Debug, Clone)]
+struct MacroDef {
+    /// Noms des paramètres, dans l'ordre
+    params: Vec<String>,
+    /// Lignes du corps, non développées
+    body: Vec<String>,
+}
+
+/// Expanseur de macros
+struct MacroExpander {
+    /// Macros définies, indexées par nom en majuscules
+    macros: HashMap<String, MacroDef>,
+    /// Nombre d'expansions effectuées (valeur de `\@`)
+    expansions: usize,
+    /// Résultat en cours de construction
+    output: ExpandedSource,
+}
+
+/// Développe les macros de `source`
+pub fn expand_macros(source: &str) -> Result<ExpandedSource, AssemblerError> {
+    let mut expander = MacroExpander {
+        macros: HashMap::new(),
+        expansions: 0,
+        output: ExpandedSource {
+            text: String::new(),
+            line_map: Vec::new(),
+        },
+    };
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut index = 0;
+    while index < lines.len() {
+        let code = strip_comment(lines[index]).trim();
+        let keyword = first_word(code).to_lowercase();
+
+        if keyword == ".macro" {
+            index = expander.define(&lines, index)?;
+        } else if keyword == ".endm" {
+            return Err(macro_error(index, ".endm sans .macro correspondant".to_string()));
+        } else {
+            expander.emit(lines[index], index, 0)?;
+            index += 1;
+        }
+    }
+
+    Ok(expander.output)
+}
+
+impl MacroExpander {
+    /// Enregistre la macro définie à partir de la ligne `start` et retourne l'index
+    /// de la ligne qui suit son `.endm`
+    fn define(&mut self, lines: &[&str], start: usize) -> Result<usize, AssemblerError> {
+        let header = strip_comment(lines[start]).trim();
+        let rest = header[".macro".len()..].trim();
+        let name = first_word(rest);
+        if name.is_empty() || !is_identifier(name) {
+            return Err(macro_error(start, format!("Nom de macro invalide: '{}'", name)));
+        }
+
+        let params: Vec<String> = split_arguments(&rest[name.len()..])
+            .into_iter()
+            .flat_map(|param| param.split_whitespace().map(str::to_string).collect::<Vec<_>>())
+            .collect();
+        for (position, param) in params.iter().enumerate() {
+            if !is_identifier(param) {
+                return Err(macro_error(start, format!("Paramètre de macro invalide: '{}'", param)));
+            }
+            if params[..position].contains(param) {
+                return Err(macro_error(start, format!("Paramètre de macro dupliqué: {}", param)));
+            }
+        }
+
+        let mut body = Vec::new();
+        let mut index = start + 1;
+        loop {
+            let Some(line) = lines.get(index) else {
+                return Err(macro_error(start, format!("Macro {} sans .endm", name)));
+            };
+            let keyword = first_word(strip_comment(line).trim()).to_lowercase();
+            if keyword == ".endm" {
+                break;
+            }
+            if keyword == ".macro" {
+                return Err(macro_error(index, "Définition de macro imbriquée".to_string()));
+            }
+            body.push(line.to_string());
+            index += 1;
+        }
+
+        if self
+            .macros
+            .insert(name.to_uppercase(), MacroDef { params, body })
+            .is_some()
+        {
+            return Err(macro_error(start, format!("Macro déjà définie: {}", name)));
+        }
+
+        Ok(index + 1)
+    }
+
+    /// Émet une ligne dans la sortie, en développant une éventuelle invocation de macro
+    fn emit(&mut self, line: &str, origin: usize, depth: usize) -> Result<(), AssemblerError> {
+        let code = strip_comment(line).trim();
+
+        // Un label peut précéder l'invocation (`debut: INCR R1, 1`)
+        let (label, statement) = match code.split_once(':') {
+            Some((label, statement)) if is_identifier(label.trim()) => (Some(label.trim()), statement.trim()),
+            _ => (None, code),
+        };
+
+        let name = first_word(statement);
+        let Some(definition) = self.macros.get(&name.to_uppercase()).cloned() else {
+            self.push_line(line, origin);
+            return Ok(());
+        };
+
+        if depth >= MAX_EXPANSION_DEPTH {
+            return Err(macro_error(
+                origin,
+                format!("Expansion de macro trop profonde (macro récursive ?): {}", name),
+            ));
+        }
+
+        let arguments = split_arguments(&statement[name.len()..]);
+        if arguments.len() != definition.params.len() {
+            return Err(macro_error(
+                origin,
+                format!(
+                    "La macro {} attend {} argument(s), {} fourni(s)",
+                    name,
+                    definition.params.len(),
+                    arguments.len()
+                ),
+            ));
+        }
+
+        if let Some(label) = label {
+            self.push_line(&format!("{}:", label), origin);
+        }
+
+        let expansion = self.expansions;
+        self.expansions += 1;
+        for body_line in &definition.body {
+            let substituted = substitute(body_line, &definition.params, &arguments, expansion)
+                .map_err(|message| macro_error(origin, format!("Dans la macro {}: {}", name, message)))?;
+            self.emit(&substituted, origin, depth + 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Ajoute une ligne à la sortie en conservant sa ligne d'origine
+    fn push_line(&mut self, line: &str, origin: usize) {
+        self.output.text.push_str(line);
+        self.output.text.push('\n');
+        self.output.line_map.push(origin);
+    }
+}
+
+/// Remplace `\param` par l'argument correspondant et `\@` par le numéro d'expansion
+fn substitute(line: &str, params: &[String], arguments: &[String], expansion: usize) -> Result<String, String> {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        if let Some(&(_, '@')) = chars.peek() {
+            chars.next();
+            result.push_str(&expansion.to_string());
+            continue;
+        }
+
+        let mut name = String::new();
+        while let Some(&(_, next)) = chars.peek() {
+            if next.is_alphanumeric() || next == '_' {
+                name.push(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        match params.iter().position(|param| *param == name) {
+            Some(position) => result.push_str(&arguments[position]),
+            None => return Err(format!("Paramètre inconnu: \\{}", name)),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Sépare les arguments d'une invocation (séparés par des virgules)
+fn split_arguments(text: &str) -> Vec<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+    text.split(',').map(|argument| argument.trim().to_string()).collect()
+}
+
+/// Retire le commentaire (`# ...`) d'une ligne
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(position) => &line[..position],
+        None => line,
+    }
+}
+
+/// Premier mot d'une ligne (jusqu'au premier espace)
+fn first_word(text: &str) -> &str {
+    text.split_whitespace().next().unwrap_or("")
+}
+
+/// Vérifie qu'un texte est un identifiant valide (lettres, chiffres, '_')
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Construit une erreur d'expansion rattachée à une ligne source (0-based)
+fn macro_error(line: usize, message: String) -> AssemblerError {
+    AssemblerError::ParserError { line: line + 1, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_macro_with_params_and_local_labels() {
+        let source = "\
+.macro DECR reg, n   # décrémente jusqu'à zéro
+boucle\\@:
+    SUBI \\reg, \\reg, \\n
+    BNEZ \\reg, boucle\\@
+.endm
+debut: DECR R1, 1
+    decr r2, 2
+    HALT";
+        let expanded = expand_macros(source).unwrap();
+        let lines: Vec<&str> = expanded.text.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "debut:",
+                "boucle0:",
+                "    SUBI R1, R1, 1",
+                "    BNEZ R1, boucle0",
+                "boucle1:",
+                "    SUBI r2, r2, 2",
+                "    BNEZ r2, boucle1",
+                "    HALT",
+            ]
+        );
+        // Chaque ligne développée est rattachée à la ligne de son invocation
+        assert_eq!(expanded.line_map, vec![5, 5, 5, 5, 6, 6, 6, 7]);
+    }
+
+    #[test]
+    fn test_expand_macro_errors() {
+        let cases = [
+            ".macro M a\nNOP",                   // .endm manquant
+            ".endm",                             // .endm orphelin
+            ".macro M a\n.endm\nM R1, R2",       // mauvais nombre d'arguments
+            ".macro M a\nADDI \\b, \\b, 1\n.endm\nM R1", // paramètre inconnu
+            ".macro M\nM\n.endm\nM",             // récursion infinie
+            ".macro M\n.endm\n.macro m\n.endm",  // redéfinition
+        ];
+        for source in cases {
+            assert!(expand_macros(source).is_err(), "{:?} devrait être rejeté", source);
+        }
+    }
+}
@@ -5,23 +5,11 @@
 
 use clap::Parser;
 use std::fs::File;
-use std::io::{self, Read, Write};
+use std::io::Read;
 use std::path::PathBuf;
 
-// Modules internes
-mod core_types;
-mod error;
-mod lexer;
-mod parser;
-mod ast;
-mod symbol;
-mod assembler;
-mod encoder;
-mod operand;
-mod output;
-mod isa_defs;
-
-use error::AssemblerError;
+use prismchrono_asm::error::AssemblerError;
+use prismchrono_asm::{assembler, lexer, macros, output, parser};
 
 /// Structure pour les arguments de ligne de commande
 #[derive(Parser, Debug)]
@@ -31,7 +19,7 @@ struct Args {
     #[arg(value_name = "INPUT")]
     input: PathBuf,
 
-    /// Fichier de sortie (.tobj ou .tbin)
+    /// Fichier de sortie (.tobj, .tbin ou .tro)
     #[arg(short, long, value_name = "OUTPUT")]
     output: Option<PathBuf>,
 
@@ -39,6 +27,14 @@ struct Args {
     #[arg(short = 'b', long)]
     binary: bool,
 
+    /// Générer un objet relogeable (.tro) à lier avec prismchrono_ld
+    #[arg(short = 'c', long, conflicts_with = "binary")]
+    relocatable: bool,
+
+    /// Écrire la table des symboles (`ADRESSE NOM` par ligne) pour le désassembleur
+    #[arg(short, long, value_name = "SYMBOLS", conflicts_with = "relocatable")]
+    symbols: Option<PathBuf>,
+
     /// Afficher des informations de débogage
     #[arg(short, long)]
     verbose: bool,
@@ -57,13 +53,16 @@ fn main() -> Result<(), AssemblerError> {
     let (output_path, binary_output) = match args.output {
         Some(path) => {
             // Utiliser l'extension du fichier spécifié ou l'option --binary
-            let is_binary = args.binary || path.extension().map_or(false, |ext| ext == "tbin");
+            let is_binary = args.binary || path.extension().is_some_and(|ext| ext == "tbin");
             (path, is_binary)
         },
         None => {
             let mut path = args.input.clone();
             // Si l'option --binary est activée, utiliser l'extension .tbin
-            if args.binary {
+            if args.relocatable {
+                path.set_extension("tro");
+                (path, false)
+            } else if args.binary {
                 path.set_extension("tbin");
                 (path, true)
             } else {
@@ -82,11 +81,16 @@ fn main() -> Result<(), AssemblerError> {
     
     // Processus d'assemblage
     if args.verbose {
-        println!("1. Tokenisation du code source...");
+        println!("1. Expansion des macros et tokenisation du code source...");
     }
     
-    // 1. Tokeniser le code source (lexer)
-    let mut lexer = lexer::Lexer::new(&source);
+    // 1. Développer les macros puis tokeniser le code source (lexer)
+    let expanded = macros::expand_macros(&source)
+        .map_err(|e| {
+            eprintln!("Erreur de macro: {}", e);
+            e
+        })?;
+    let mut lexer = lexer::Lexer::with_line_map(&expanded.text, expanded.line_map);
     let tokens = lexer.tokenize()
         .map_err(|e| {
             eprintln!("Erreur de lexer: {}", e);
@@ -111,6 +115,33 @@ fn main() -> Result<(), AssemblerError> {
     
     // 3 & 4. Assemblage en deux passes
     let assembler = assembler::Assembler::new(program);
+
+    // Objet relogeable: les adresses définitives seront fixées par prismchrono_ld
+    if args.relocatable {
+        let object = assembler.assemble_object()
+            .map_err(|e| {
+                eprintln!("Erreur d'assemblage: {}", e);
+                e
+            })?;
+
+        if args.verbose {
+            println!("5. Écriture de l'objet relogeable...");
+            println!("Nombre de symboles: {}", object.symbols.len());
+            println!("Nombre de relocations: {}", object.relocations.len());
+        }
+
+        output::write_object(&output_path, &object)
+            .map_err(|e| {
+                eprintln!("Erreur d'écriture de l'objet: {}", e);
+                e
+            })?;
+
+        if args.verbose {
+            println!("Assemblage terminé. Fichier objet: {}", output_path.display());
+        }
+        return Ok(());
+    }
+
     let assembly_result = assembler.assemble()
         .map_err(|e| {
             eprintln!("Erreur d'assemblage: {}", e);
@@ -126,7 +157,7 @@ fn main() -> Result<(), AssemblerError> {
     // 5. Écrire le fichier de sortie (output)
     if binary_output {
         // Écrire au format binaire (.tbin)
-        output::write_tbin(&output_path, &assembly_result.encoded_data)
+        output::write_tbin(&output_path, &assembly_result.encoded_data, &assembly_result.globals, None)
             .map_err(|e| {
                 eprintln!("Erreur d'écriture du fichier binaire: {}", e);
                 e
@@ -137,7 +168,7 @@ fn main() -> Result<(), AssemblerError> {
         }
     } else {
         // Écrire au format texte (.tobj)
-        output::write_tobj(&output_path, &assembly_result.encoded_data)
+        output::write_tobj(&output_path, &assembly_result.encoded_data, &assembly_result.globals)
             .map_err(|e| {
                 eprintln!("Erreur d'écriture du fichier texte: {}", e);
                 e
@@ -148,6 +179,19 @@ fn main() -> Result<(), AssemblerError> {
         }
     }
     
+    // 6. Écrire la table des symboles si demandée
+    if let Some(symbols_path) = &args.symbols {
+        output::write_symbols(symbols_path, &assembly_result.symbol_table)
+            .map_err(|e| {
+                eprintln!("Erreur d'écriture de la table des symboles: {}", e);
+                e
+            })?;
+
+        if args.verbose {
+            println!("Table des symboles écrite dans {}.", symbols_path.display());
+        }
+    }
+    
     if args.verbose {
         println!("Assemblage terminé. Fichier de sortie: {}", output_path.display());
     }
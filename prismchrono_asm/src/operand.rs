@@ -3,7 +3,6 @@
 //! Ce module est responsable du parsing et de la validation des opérandes
 //! dans les instructions assembleur.
 
-use crate::ast::Operand;
 use crate::error::AssemblerError;
 use crate::isa_defs::imm_limits;
 
@@ -21,7 +20,7 @@ pub fn validate_register(reg: u8) -> Result<u8, AssemblerError> {
 
 /// Valide une valeur immédiate pour le format I
 pub fn validate_i_immediate(imm: i32) -> Result<i32, AssemblerError> {
-    if imm >= imm_limits::I_MIN && imm <= imm_limits::I_MAX {
+    if (imm_limits::I_MIN..=imm_limits::I_MAX).contains(&imm) {
         Ok(imm)
     } else {
         Err(AssemblerError::EncodeError {
@@ -36,7 +35,7 @@ pub fn validate_i_immediate(imm: i32) -> Result<i32, AssemblerError> {
 
 /// Valide une valeur immédiate pour le format U
 pub fn validate_u_immediate(imm: i32) -> Result<i32, AssemblerError> {
-    if imm >= imm_limits::U_MIN && imm <= imm_limits::U_MAX {
+    if (imm_limits::U_MIN..=imm_limits::U_MAX).contains(&imm) {
         Ok(imm)
     } else {
         Err(AssemblerError::EncodeError {
@@ -51,7 +50,7 @@ pub fn validate_u_immediate(imm: i32) -> Result<i32, AssemblerError> {
 
 /// Valide un offset pour le format J
 pub fn validate_j_offset(offset: i32) -> Result<i32, AssemblerError> {
-    if offset >= imm_limits::J_MIN && offset <= imm_limits::J_MAX {
+    if (imm_limits::J_MIN..=imm_limits::J_MAX).contains(&offset) {
         Ok(offset)
     } else {
         Err(AssemblerError::EncodeError {
@@ -66,7 +65,7 @@ pub fn validate_j_offset(offset: i32) -> Result<i32, AssemblerError> {
 
 /// Valide un offset pour le format B
 pub fn validate_b_offset(offset: i32) -> Result<i32, AssemblerError> {
-    if offset >= imm_limits::B_MIN && offset <= imm_limits::B_MAX {
+    if (imm_limits::B_MIN..=imm_limits::B_MAX).contains(&offset) {
         Ok(offset)
     } else {
         Err(AssemblerError::EncodeError {
@@ -85,6 +84,57 @@ pub fn validate_s_immediate(imm: i32) -> Result<i32, AssemblerError> {
     validate_i_immediate(imm)
 }
 
+/// Valide le nombre de trits d'une rotation/d'un décalage ternaire (TSHIFT)
+pub fn validate_tshift_immediate(imm: i32) -> Result<i32, AssemblerError> {
+    if (imm_limits::TSHIFT_MIN..=imm_limits::TSHIFT_MAX).contains(&imm) {
+        Ok(imm)
+    } else {
+        Err(AssemblerError::EncodeError {
+            line: 0, // Sera mis à jour par l'appelant
+            message: format!(
+                "Valeur immédiate hors limites pour TSHIFT: {} (doit être entre {} et {})",
+                imm, imm_limits::TSHIFT_MIN, imm_limits::TSHIFT_MAX
+            ),
+        })
+    }
+}
+
+/// Valide un offset (en trytes) pour LOADT3/STORET3
+pub fn validate_t3_offset(offset: i32) -> Result<i32, AssemblerError> {
+    if (imm_limits::T3_OFFSET_MIN..=imm_limits::T3_OFFSET_MAX).contains(&offset) {
+        Ok(offset)
+    } else {
+        Err(AssemblerError::EncodeError {
+            line: 0, // Sera mis à jour par l'appelant
+            message: format!(
+                "Offset hors limites pour LOADT3/STORET3: {} (doit être entre {} et {})",
+                offset, imm_limits::T3_OFFSET_MIN, imm_limits::T3_OFFSET_MAX
+            ),
+        })
+    }
+}
+
+/// Calcule un offset de BRANCH3 (en instructions, relatif à l'adresse du BRANCH3)
+///
+/// Les limites dépendent de l'écart entre les trois cibles et sont vérifiées
+/// lors de l'encodage.
+pub fn calculate_branch3_offset(target_addr: u32, current_addr: u32) -> Result<i32, AssemblerError> {
+    let diff = target_addr as i64 - current_addr as i64;
+
+    // Vérifier si la différence est un multiple de 4 (alignement des instructions)
+    if diff % 4 != 0 {
+        return Err(AssemblerError::EncodeError {
+            line: 0, // Sera mis à jour par l'appelant
+            message: format!(
+                "L'adresse cible n'est pas alignée sur 4 octets: 0x{:X}",
+                target_addr
+            ),
+        });
+    }
+
+    Ok((diff / 4) as i32)
+}
+
 /// Calcule l'offset pour l'instruction JAL
 pub fn calculate_jal_offset(target_addr: u32, current_addr: u32) -> Result<i32, AssemblerError> {
     // L'offset est relatif à l'adresse après l'instruction JAL
@@ -141,7 +191,7 @@ mod tests {
     #[test]
     fn test_calculate_jal_offset() {
         // Offset positif
-        assert_eq!(calculate_jal_offset(0x100, 0x0).unwrap(), 64); // (0x100 - (0x0 + 4)) / 4 = 0xFC / 4 = 63
+        assert_eq!(calculate_jal_offset(0x100, 0x0).unwrap(), 63); // (0x100 - (0x0 + 4)) / 4 = 0xFC / 4 = 63
         
         // Offset négatif
         assert_eq!(calculate_jal_offset(0x0, 0x100).unwrap(), -65); // (0x0 - (0x100 + 4)) / 4 = -0x104 / 4 = -65
@@ -149,4 +199,12 @@ mod tests {
         // Adresse non alignée
         assert!(calculate_jal_offset(0x102, 0x100).is_err());
     }
+
+    #[test]
+    fn test_calculate_branch3_offset() {
+        // L'offset est relatif à l'adresse du BRANCH3 lui-même
+        assert_eq!(calculate_branch3_offset(0x104, 0x100).unwrap(), 1);
+        assert_eq!(calculate_branch3_offset(0xF0, 0x100).unwrap(), -4);
+        assert!(calculate_branch3_offset(0x106, 0x100).is_err());
+    }
 }
\ No newline at end of file
@@ -2,25 +2,44 @@
 //!
 //! Ce module est responsable de la génération du fichier de sortie .tobj
 //! qui représente le code machine ternaire dans un format texte lisible.
-//! Il gère également la génération du format binaire .tbin.
+//! Il gère également la génération du format binaire .tbin et de la table
+//! des symboles (.sym) utilisée par le désassembleur du simulateur.
 
 use std::fs::File;
-use std::io::{self, Write};
+use std::io::Write;
 use std::path::Path;
 
 use crate::core_types::{Trit, Tryte};
 use crate::encoder::EncodedData;
 use crate::error::AssemblerError;
+use crate::symbol::SymbolTable;
+use prismchrono_isa::object::ObjectFile;
+
+/// Version courante du format .tbin
+pub const TBIN_VERSION: u32 = 3;
+
+/// Point d'entrée .tbin signifiant « non spécifié » (le chargeur prend `_start`)
+pub const TBIN_NO_ENTRY: u32 = u32::MAX;
 
 /// Écrit les données encodées dans un fichier .tobj
+///
+/// Les symboles exportés par `.global` sont listés en tête sous la forme
+/// `.global NOM AAAA` (adresse hexadécimale).
 pub fn write_tobj<P: AsRef<Path>>(
     path: P,
     encoded_data: &[(u32, EncodedData)],
+    globals: &[(String, u32)],
 ) -> Result<(), AssemblerError> {
     let mut file = File::create(path).map_err(|e| {
         AssemblerError::IoError(format!("Impossible de créer le fichier de sortie: {}", e))
     })?;
 
+    for (name, address) in globals {
+        writeln!(file, ".global {} {:04X}", name, address).map_err(|e| {
+            AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+        })?;
+    }
+
     for (address, data) in encoded_data {
         match data {
             EncodedData::Instruction(trits) => {
@@ -41,16 +60,24 @@ pub fn write_tobj<P: AsRef<Path>>(
 /// 
 /// Format .tbin:
 /// - 4 octets: Signature "TBIN"
-/// - 4 octets: Version (1)
+/// - 4 octets: Version (3)
 /// - 4 octets: Nombre d'entrées
 /// - Pour chaque entrée:
 ///   - 4 octets: Adresse
 ///   - 1 octet: Type (0 = instruction, 1 = données)
 ///   - 1 octet: Taille en trytes
 ///   - N octets: Données (trits pour instructions, trytes pour données)
+/// - 4 octets: Nombre de symboles globaux (absent en version 1)
+/// - Pour chaque symbole global:
+///   - 4 octets: Adresse
+///   - 1 octet: Longueur du nom
+///   - N octets: Nom (UTF-8)
+/// - 4 octets: Point d'entrée (absent avant la version 3, `TBIN_NO_ENTRY` si non spécifié)
 pub fn write_tbin<P: AsRef<Path>>(
     path: P,
     encoded_data: &[(u32, EncodedData)],
+    globals: &[(String, u32)],
+    entry: Option<u32>,
 ) -> Result<(), AssemblerError> {
     let mut file = File::create(path).map_err(|e| {
         AssemblerError::IoError(format!("Impossible de créer le fichier binaire: {}", e))
@@ -61,8 +88,8 @@ pub fn write_tbin<P: AsRef<Path>>(
         AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
     })?;
     
-    // Version 1
-    file.write_all(&1u32.to_le_bytes()).map_err(|e| {
+    // Version 3 (table des symboles globaux et point d'entrée)
+    file.write_all(&TBIN_VERSION.to_le_bytes()).map_err(|e| {
         AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
     })?;
     
@@ -118,7 +145,7 @@ pub fn write_tbin<P: AsRef<Path>>(
                 // Écrire chaque tryte
                 for tryte in trytes {
                     // Convertir le tryte en valeur bal3 (-13 à +13)
-                    let tryte_value = tryte.bal3_value() as i8;
+                    let tryte_value = tryte.bal3_value();
                     file.write_all(&[tryte_value as u8]).map_err(|e| {
                         AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
                     })?;
@@ -126,7 +153,55 @@ pub fn write_tbin<P: AsRef<Path>>(
             }
         }
     }
-    
+
+    // Table des symboles globaux
+    file.write_all(&(globals.len() as u32).to_le_bytes()).map_err(|e| {
+        AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+    })?;
+    for (name, address) in globals {
+        let name_len = u8::try_from(name.len()).map_err(|_| {
+            AssemblerError::IoError(format!("Nom de symbole global trop long: {}", name))
+        })?;
+        file.write_all(&address.to_le_bytes()).map_err(|e| {
+            AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+        })?;
+        file.write_all(&[name_len]).map_err(|e| {
+            AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+        })?;
+        file.write_all(name.as_bytes()).map_err(|e| {
+            AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+        })?;
+    }
+
+    // Point d'entrée
+    file.write_all(&entry.unwrap_or(TBIN_NO_ENTRY).to_le_bytes()).map_err(|e| {
+        AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+    })?;
+
+    Ok(())
+}
+
+/// Écrit un objet relogeable dans un fichier .tro (voir `prismchrono_isa::object`)
+pub fn write_object<P: AsRef<Path>>(path: P, object: &ObjectFile) -> Result<(), AssemblerError> {
+    std::fs::write(path, object.to_text()).map_err(|e| {
+        AssemblerError::IoError(format!("Impossible d'écrire le fichier objet: {}", e))
+    })
+}
+
+/// Écrit la table des symboles dans un fichier .sym
+///
+/// Format: une ligne `AAAA nom` par symbole (adresse hexadécimale), triée par adresse
+pub fn write_symbols<P: AsRef<Path>>(path: P, symbol_table: &SymbolTable) -> Result<(), AssemblerError> {
+    let mut file = File::create(path).map_err(|e| {
+        AssemblerError::IoError(format!("Impossible de créer le fichier de symboles: {}", e))
+    })?;
+
+    for (address, name) in symbol_table.sorted_by_address() {
+        writeln!(file, "{:04X} {}", address, name).map_err(|e| {
+            AssemblerError::IoError(format!("Erreur d'écriture: {}", e))
+        })?;
+    }
+
     Ok(())
 }
 
@@ -3,10 +3,14 @@
 //! Ce module est responsable de l'analyse syntaxique des tokens générés par le lexer
 //! et de leur transformation en une structure AST (Abstract Syntax Tree).
 
-use crate::ast::{AstNode, Directive, Instruction, Operand, Program};
+use crate::ast::{AstNode, Directive, Instruction, Program};
 use crate::error::AssemblerError;
 use crate::lexer::{Token, TokenType};
 use crate::core_types::Address;
+use std::collections::HashMap;
+use crate::isa_defs::{alu_imm_from_mnemonic, alu_op_from_mnemonic, branch_condition_from_name};
+use crate::pseudo;
+use prismchrono_isa::{AluOp, Base24Op, SpecialStateOp, TernaryOp, TernaryShiftOp, VectorOp, VectorReduceOp};
 
 /// Structure du parser
 pub struct Parser {
@@ -14,6 +18,8 @@ pub struct Parser {
     tokens: Vec<Token>,
     /// Position courante dans le vecteur de tokens
     current: usize,
+    /// Constantes définies par `.equ`, utilisables partout où un immédiat est attendu
+    constants: HashMap<String, i32>,
 }
 
 impl Parser {
@@ -22,6 +28,7 @@ impl Parser {
         Parser {
             tokens,
             current: 0,
+            constants: HashMap::new(),
         }
     }
 
@@ -31,15 +38,17 @@ impl Parser {
 
         while !self.is_at_end() {
             let line_number = self.current_token().line + 1;
-            let node = self.parse_line()?;
-            program.add_line(line_number, node);
+            for node in self.parse_line()? {
+                program.add_line(line_number, node);
+            }
         }
 
         Ok(program)
     }
 
     /// Analyse une ligne de code
-    fn parse_line(&mut self) -> Result<AstNode, AssemblerError> {
+    /// Une ligne peut produire plusieurs nœuds (label suivi d'une instruction, pseudo-instruction)
+    fn parse_line(&mut self) -> Result<Vec<AstNode>, AssemblerError> {
         // Ignorer les commentaires
         while self.check_type(|t| matches!(t, TokenType::Comment(_))) {
             self.advance(); // Consommer le commentaire
@@ -48,7 +57,7 @@ impl Parser {
         // Ignorer les lignes vides
         if self.check(TokenType::EOL) {
             self.advance(); // Consommer EOL
-            return Ok(AstNode::Empty);
+            return Ok(vec![AstNode::Empty]);
         }
 
         // Vérifier s'il y a une définition de label
@@ -64,12 +73,13 @@ impl Parser {
             // Si la ligne ne contient que le label, retourner un nœud Label
             if self.check(TokenType::EOL) {
                 self.advance(); // Consommer EOL
-                return Ok(AstNode::Label(label_name));
+                return Ok(vec![AstNode::Label(label_name)]);
             }
 
-            // Sinon, traiter le reste de la ligne et retourner un nœud Label
-            let _node = self.parse_line()?;
-            return Ok(AstNode::Label(label_name));
+            // Sinon, le label précède les nœuds du reste de la ligne
+            let mut nodes = vec![AstNode::Label(label_name)];
+            nodes.extend(self.parse_line()?);
+            return Ok(nodes);
         }
 
         // Vérifier s'il y a une directive
@@ -82,12 +92,12 @@ impl Parser {
             }
             
             self.consume(TokenType::EOL, "Attendu fin de ligne après directive")?;
-            return Ok(AstNode::Directive(directive_node));
+            return Ok(vec![AstNode::Directive(directive_node)]);
         }
 
         // Vérifier s'il y a une instruction
         if let TokenType::Mnemonic(mnemonic) = &self.current_token().token_type {
-            let instruction_node = self.parse_instruction(mnemonic.clone())?;
+            let instructions = self.parse_instructions(mnemonic.clone())?;
             
             // Ignorer les commentaires après l'instruction
             while self.check_type(|t| matches!(t, TokenType::Comment(_))) {
@@ -95,7 +105,7 @@ impl Parser {
             }
             
             self.consume(TokenType::EOL, "Attendu fin de ligne après instruction")?;
-            return Ok(AstNode::Instruction(instruction_node));
+            return Ok(instructions.into_iter().map(AstNode::Instruction).collect());
         }
 
         // Si on arrive ici, c'est une erreur de syntaxe
@@ -132,10 +142,46 @@ impl Parser {
                 Ok(Directive::Tryte(value))
             }
             "word" => {
-                // .word <value>
+                // .word <value> ou .word <label> (adresse du label)
+                if self.at_label_operand() {
+                    return Ok(Directive::WordLabel(self.parse_label()?));
+                }
                 let value = self.parse_number()?;
                 Ok(Directive::Word(value))
             }
+            "equ" => {
+                // .equ <nom>, <valeur>
+                let line = self.current_token().line + 1;
+                let name = self.parse_label()?;
+                self.consume(TokenType::Comma, "Attendu ',' après le nom de la constante")?;
+                let value = self.parse_number()?;
+                if self.constants.insert(name.clone(), value).is_some() {
+                    return Err(AssemblerError::ParserError {
+                        line,
+                        message: format!("Constante déjà définie: {}", name),
+                    });
+                }
+                Ok(Directive::Equ { name, value })
+            }
+            "section" => {
+                // .section <nom> (ex: .section .text)
+                let name = match &self.current_token().token_type {
+                    TokenType::Directive(name) | TokenType::LabelRef(name) => format!(".{}", name),
+                    other => {
+                        return Err(AssemblerError::ParserError {
+                            line: self.current_token().line + 1,
+                            message: format!("Attendu un nom de section, trouvé: {:?}", other),
+                        });
+                    }
+                };
+                self.advance(); // Consommer le nom de section
+                Ok(Directive::Section(name))
+            }
+            "global" | "globl" => {
+                // .global <symbole>
+                let name = self.parse_label()?;
+                Ok(Directive::Global(name))
+            }
             _ => Err(AssemblerError::ParserError {
                 line: self.current_token().line + 1,
                 message: format!("Directive inconnue: .{}", directive),
@@ -143,6 +189,65 @@ impl Parser {
         }
     }
 
+    /// Analyse une instruction ou une pseudo-instruction
+    /// Les pseudo-instructions sont développées ici en instructions réelles (voir `pseudo`)
+    fn parse_instructions(&mut self, mnemonic: String) -> Result<Vec<Instruction>, AssemblerError> {
+        match mnemonic.as_str() {
+            "MOVI" | "LI" | "LA" => {
+                // MOVI rd, imm -> LUI rd, haut ; ADDI rd, rd, bas
+                // LA rd, label (ou MOVI rd, label) -> LUI rd, %hi(label) ; ADDI rd, rd, %lo(label)
+                self.advance(); // Consommer le mnémonique
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                if mnemonic == "LA" || self.at_label_operand() {
+                    let label = self.parse_label()?;
+                    return Ok(pseudo::expand_load_address(rd, label));
+                }
+                let line = self.current_token().line + 1;
+                let value = self.parse_number()?;
+                pseudo::expand_load_immediate(rd, value)
+                    .map_err(|message| AssemblerError::ParserError { line, message })
+            }
+            "MV" | "MOV" | "NEG" | "NOT" => {
+                // MV rd, rs -> ADDI rd, rs, 0 ; NOT rd, rs -> TRITINV rd, rs
+                // NEG rd, rs -> SUB rd, rs, rs ; SUB rd, rd, rs
+                self.advance(); // Consommer le mnémonique
+                let line = self.current_token().line + 1;
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs = self.parse_register()?;
+                match mnemonic.as_str() {
+                    "MV" | "MOV" => Ok(vec![pseudo::expand_move(rd, rs)]),
+                    "NOT" => Ok(vec![pseudo::expand_not(rd, rs)]),
+                    _ => pseudo::expand_negate(rd, rs)
+                        .map_err(|message| AssemblerError::ParserError { line, message }),
+                }
+            }
+            "J" => {
+                // J label -> JAL R0, label
+                self.advance(); // Consommer le mnémonique
+                let label = self.parse_label()?;
+                Ok(vec![pseudo::expand_jump(label)])
+            }
+            "RET" => {
+                // RET -> JALR R0, R7, 0
+                self.advance(); // Consommer le mnémonique
+                Ok(vec![pseudo::expand_return()])
+            }
+            _ => {
+                if let Some(condition) = pseudo::zero_branch_condition(&mnemonic) {
+                    // BEQZ rs, label -> ADDI rs, rs, 0 ; BRANCH EQ, label
+                    self.advance(); // Consommer le mnémonique
+                    let rs = self.parse_register()?;
+                    self.consume(TokenType::Comma, "Attendu ',' après rs")?;
+                    let label = self.parse_label()?;
+                    return Ok(pseudo::expand_zero_branch(condition, rs, label));
+                }
+                Ok(vec![self.parse_instruction(mnemonic)?])
+            }
+        }
+    }
+
     /// Analyse une instruction
     fn parse_instruction(&mut self, mnemonic: String) -> Result<Instruction, AssemblerError> {
         self.advance(); // Consommer le mnémonique
@@ -193,33 +298,35 @@ impl Parser {
             }
             "BRANCH" => {
                 // BRANCH rs1, rs2, condition, label (Format B)
-                let rs1 = self.parse_register()?;
-                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
-                let rs2 = self.parse_register()?;
-                self.consume(TokenType::Comma, "Attendu ',' après rs2")?;
-                
-                // Lire la condition (EQ, NE, LT, GE, etc.)
+                // ou BRANCH condition, label: la condition ne porte que sur les flags
+                let (rs1, rs2) = if self.check_type(|t| matches!(t, TokenType::Register(_))) {
+                    let rs1 = self.parse_register()?;
+                    self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                    let rs2 = self.parse_register()?;
+                    self.consume(TokenType::Comma, "Attendu ',' après rs2")?;
+                    (rs1, rs2)
+                } else {
+                    (0, 0)
+                };
+
+                // Lire la condition (EQ, NE, LT, GE, AL/ALWAYS, etc.)
                 if let TokenType::LabelRef(condition) = &self.current_token().token_type {
-                    let condition_str = condition.clone();
-                    self.advance(); // Consommer la condition
-                    
-                    // Vérifier que la condition est valide
-                    match condition_str.to_uppercase().as_str() {
-                        "EQ" | "NE" | "LT" | "LE" | "GT" | "GE" => {
-                            self.consume(TokenType::Comma, "Attendu ',' après condition")?;
-                            let label = self.parse_label()?;
-                            Ok(Instruction::Branch {
-                                rs1,
-                                rs2,
-                                condition: condition_str.to_uppercase(),
-                                label,
-                            })
-                        },
-                        _ => Err(AssemblerError::ParserError {
+                    let condition_str = condition.to_uppercase();
+                    if branch_condition_from_name(&condition_str).is_none() {
+                        return Err(AssemblerError::ParserError {
                             line: self.current_token().line + 1,
-                            message: format!("Condition de branchement invalide: {}", condition_str),
-                        }),
+                            message: format!("Condition de branchement invalide: {}", condition),
+                        });
                     }
+                    self.advance(); // Consommer la condition
+                    self.consume(TokenType::Comma, "Attendu ',' après condition")?;
+                    let label = self.parse_label()?;
+                    Ok(Instruction::Branch {
+                        rs1,
+                        rs2,
+                        condition: condition_str,
+                        label,
+                    })
                 } else {
                     Err(AssemblerError::ParserError {
                         line: self.current_token().line + 1,
@@ -261,7 +368,7 @@ impl Parser {
                 // CSRRW_T rd, csr_code, rs1 (Format CSR)
                 let rd = self.parse_register()?;
                 self.consume(TokenType::Comma, "Attendu ',' après rd")?;
-                let csr_code = self.parse_label()?;
+                let csr_code = self.parse_csr()?;
                 self.consume(TokenType::Comma, "Attendu ',' après csr_code")?;
                 let rs1 = self.parse_register()?;
                 Ok(Instruction::Csrrw { rd, csr_code, rs1 })
@@ -270,18 +377,287 @@ impl Parser {
                 // CSRRS_T rd, csr_code, rs1 (Format CSR)
                 let rd = self.parse_register()?;
                 self.consume(TokenType::Comma, "Attendu ',' après rd")?;
-                let csr_code = self.parse_label()?;
+                let csr_code = self.parse_csr()?;
                 self.consume(TokenType::Comma, "Attendu ',' après csr_code")?;
                 let rs1 = self.parse_register()?;
                 Ok(Instruction::Csrrs { rd, csr_code, rs1 })
             }
+            "CSRRC_T" => {
+                // CSRRC_T rd, csr_code, rs1 (Format CSR)
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let csr_code = self.parse_csr()?;
+                self.consume(TokenType::Comma, "Attendu ',' après csr_code")?;
+                let rs1 = self.parse_register()?;
+                Ok(Instruction::Csrrc { rd, csr_code, rs1 })
+            }
+            "CSRI_T" => {
+                // CSRI_T csr_code, rs1, imm
+                let csr_code = self.parse_csr()?;
+                self.consume(TokenType::Comma, "Attendu ',' après csr_code")?;
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let imm = self.parse_number()?;
+                Ok(Instruction::Csri { csr_code, rs1, imm })
+            }
+            "LOADW" | "LOADT" | "LOADTU" | "JALR" => {
+                // LOADW rd, rs1, imm (Format I)
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let imm = self.parse_number()?;
+                Ok(match mnemonic.as_str() {
+                    "LOADW" => Instruction::Loadw { rd, rs1, imm },
+                    "LOADT" => Instruction::Loadt { rd, rs1, imm },
+                    "LOADTU" => Instruction::Loadtu { rd, rs1, imm },
+                    _ => Instruction::Jalr { rd, rs1, imm },
+                })
+            }
+            "AUIPC" => {
+                // AUIPC rd, imm (Format U)
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let imm = self.parse_number()?;
+                Ok(Instruction::Auipc { rd, imm })
+            }
+            "CALL" => {
+                // CALL rd, label (Format J)
+                // ou pseudo-instruction CALL label, qui utilise le registre de lien R7
+                let rd = if self.check_type(|t| matches!(t, TokenType::Register(_))) {
+                    let rd = self.parse_register()?;
+                    self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                    rd
+                } else {
+                    pseudo::LINK_REGISTER
+                };
+                let label = self.parse_label()?;
+                Ok(Instruction::Call { rd, label })
+            }
+            "CMP" => {
+                // CMP rs1, rs2 (seuls les flags sont mis à jour, rd est ignoré)
+                // La forme à trois registres produite par le désassembleur est aussi acceptée
+                let first = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let second = self.parse_register()?;
+                let (rd, rs1, rs2) = if self.check(TokenType::Comma) {
+                    self.advance(); // Consommer la virgule
+                    (first, second, self.parse_register()?)
+                } else {
+                    (0, first, second)
+                };
+                Ok(Instruction::Alu { op: AluOp::Cmp, rd, rs1, rs2 })
+            }
+            "TRITINV" | "ABS_T" | "SIGNUM_T" | "CHECKW_VALID" => {
+                // TRITINV rd, rs1 (opération unaire, rs2 optionnel et ignoré)
+                let op = alu_op_from_mnemonic(&mnemonic).expect("opération ALU unaire");
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs1 = self.parse_register()?;
+                let rs2 = if self.check(TokenType::Comma) {
+                    self.advance(); // Consommer la virgule
+                    self.parse_register()?
+                } else {
+                    0
+                };
+                Ok(Instruction::Alu { op, rd, rs1, rs2 })
+            }
+            name if alu_op_from_mnemonic(name).is_some() => {
+                // MUL rd, rs1, rs2 (Format R)
+                let op = alu_op_from_mnemonic(name).expect("opération ALU");
+                let (rd, rs1, rs2) = self.parse_three_registers()?;
+                Ok(Instruction::Alu { op, rd, rs1, rs2 })
+            }
+            name if alu_imm_from_mnemonic(name).is_some() => {
+                // SUBI rd, rs1, imm (Format I)
+                let op = alu_imm_from_mnemonic(name).expect("opération ALU avec immédiat");
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let imm = self.parse_number()?;
+                Ok(Instruction::AluImm { op, rd, rs1, imm })
+            }
+            "TMIN" | "TMAX" | "TSUM" | "TCMP3" => {
+                // TMIN rd, rs1, rs2 (extension ternaire, format R)
+                let op = match mnemonic.as_str() {
+                    "TMIN" => TernaryOp::TMIN,
+                    "TMAX" => TernaryOp::TMAX,
+                    "TSUM" => TernaryOp::TSUM,
+                    _ => TernaryOp::TCMP3,
+                };
+                let (rd, rs1, rs2) = self.parse_three_registers()?;
+                Ok(Instruction::Ternary { op, rd, rs1, rs2 })
+            }
+            "TROTL" | "TROTR" | "TSHIFTL" | "TSHIFTR" => {
+                // TROTL rd, rs1, imm
+                let op = match mnemonic.as_str() {
+                    "TROTL" => TernaryShiftOp::TROTL,
+                    "TROTR" => TernaryShiftOp::TROTR,
+                    "TSHIFTL" => TernaryShiftOp::TSHIFTL,
+                    _ => TernaryShiftOp::TSHIFTR,
+                };
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let imm = self.parse_number()?;
+                Ok(Instruction::TernaryShift { op, rd, rs1, imm })
+            }
+            "BRANCH3" => {
+                // BRANCH3 rs1, label_neg, label_zero, label_pos
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let label_neg = self.parse_label()?;
+                self.consume(TokenType::Comma, "Attendu ',' après le label négatif")?;
+                let label_zero = self.parse_label()?;
+                self.consume(TokenType::Comma, "Attendu ',' après le label zéro")?;
+                let label_pos = self.parse_label()?;
+                Ok(Instruction::Branch3 { rs1, label_neg, label_zero, label_pos })
+            }
+            "LOADT3" => {
+                // LOADT3 rd, rs1, imm
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let imm = self.parse_number()?;
+                Ok(Instruction::Loadt3 { rd, rs1, imm })
+            }
+            "STORET3" => {
+                // STORET3 rs1, rs2, imm (même ordre que STOREW)
+                let rs1 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+                let rs2 = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs2")?;
+                let imm = self.parse_number()?;
+                Ok(Instruction::Storet3 { rs1, rs2, imm })
+            }
+            "TMEMCPY" => {
+                // TMEMCPY rd, rs1, rs2
+                let (rd, rs1, rs2) = self.parse_three_registers()?;
+                Ok(Instruction::Tmemcpy { rd, rs1, rs2 })
+            }
+            "TMEMSET" => {
+                // TMEMSET rd, rs1, rs2
+                let (rd, rs1, rs2) = self.parse_three_registers()?;
+                Ok(Instruction::Tmemset { rd, rs1, rs2 })
+            }
+            "MADDW" | "MSUBW" | "TSEL" => {
+                // MADDW rd, rs1, rs2, rs3 (format R4)
+                let (rd, rs1, rs2) = self.parse_three_registers()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rs2")?;
+                let rs3 = self.parse_register()?;
+                Ok(match mnemonic.as_str() {
+                    "MADDW" => Instruction::Maddw { rd, rs1, rs2, rs3 },
+                    "MSUBW" => Instruction::Msubw { rd, rs1, rs2, rs3 },
+                    _ => Instruction::Tsel { rd, rs1, rs2, rs3 },
+                })
+            }
+            "ISNULL" | "ISNAN" | "ISUNDEF" | "SETNULL" | "SETNAN" | "SETUNDEF" => {
+                // ISNULL rd, rs1
+                let op = match mnemonic.as_str() {
+                    "ISNULL" => SpecialStateOp::ISNULL,
+                    "ISNAN" => SpecialStateOp::ISNAN,
+                    "ISUNDEF" => SpecialStateOp::ISUNDEF,
+                    "SETNULL" => SpecialStateOp::SETNULL,
+                    "SETNAN" => SpecialStateOp::SETNAN,
+                    _ => SpecialStateOp::SETUNDEF,
+                };
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let rs1 = self.parse_register()?;
+                Ok(Instruction::SpecialState { op, rd, rs1 })
+            }
+            "ADDB24" | "SUBB24" | "MULB24" | "DIVB24" | "CVTB24" | "CVTFRB24" => {
+                // ADDB24 rd, rs1, rs2
+                let op = match mnemonic.as_str() {
+                    "ADDB24" => Base24Op::ADDB24,
+                    "SUBB24" => Base24Op::SUBB24,
+                    "MULB24" => Base24Op::MULB24,
+                    "DIVB24" => Base24Op::DIVB24,
+                    "CVTB24" => Base24Op::CVTB24,
+                    _ => Base24Op::CVTFRB24,
+                };
+                let (rd, rs1, rs2) = self.parse_three_registers()?;
+                Ok(Instruction::Base24 { op, rd, rs1, rs2 })
+            }
+            "TVADD" | "TVSUB" | "TVMUL" | "TVMAC" => {
+                // TVADD vd, vs1, vs2
+                let op = match mnemonic.as_str() {
+                    "TVADD" => VectorOp::TVADD,
+                    "TVSUB" => VectorOp::TVSUB,
+                    "TVMUL" => VectorOp::TVMUL,
+                    _ => VectorOp::TVMAC,
+                };
+                let vd = self.parse_vector_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après vd")?;
+                let vs1 = self.parse_vector_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après vs1")?;
+                let vs2 = self.parse_vector_register()?;
+                // Forme longue TVMAC vd, vs1, vs2, vs3: l'accumulateur doit être vd
+                if op == VectorOp::TVMAC && self.check(TokenType::Comma) {
+                    self.advance(); // Consommer la virgule
+                    let line = self.current_token().line + 1;
+                    let vs3 = self.parse_vector_register()?;
+                    if vs3 != vd {
+                        return Err(AssemblerError::ParserError {
+                            line,
+                            message: format!("TVMAC accumule dans vd: V{} attendu, trouvé V{}", vd, vs3),
+                        });
+                    }
+                }
+                Ok(Instruction::Vector { op, vd, vs1, vs2 })
+            }
+            "TVDOT" => {
+                // TVDOT rd, vs1, vs2
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let vs1 = self.parse_vector_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après vs1")?;
+                let vs2 = self.parse_vector_register()?;
+                Ok(Instruction::VectorDot { rd, vs1, vs2 })
+            }
+            "TVSUM" | "TVMIN" | "TVMAX" | "TVAVG" => {
+                // TVSUM rd, vs
+                let op = match mnemonic.as_str() {
+                    "TVSUM" => VectorReduceOp::TVSUM,
+                    "TVMIN" => VectorReduceOp::TVMIN,
+                    "TVMAX" => VectorReduceOp::TVMAX,
+                    _ => VectorReduceOp::TVAVG,
+                };
+                let rd = self.parse_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+                let vs = self.parse_vector_register()?;
+                Ok(Instruction::VectorReduce { op, rd, vs })
+            }
+            "TVLOAD" | "TVSTORE" => {
+                // TVLOAD vd, rs1 / TVSTORE vs, rs1
+                let vreg = self.parse_vector_register()?;
+                self.consume(TokenType::Comma, "Attendu ',' après le registre vectoriel")?;
+                let rs1 = self.parse_register()?;
+                Ok(match mnemonic.as_str() {
+                    "TVLOAD" => Instruction::VectorLoad { vd: vreg, rs1 },
+                    _ => Instruction::VectorStore { vs: vreg, rs1 },
+                })
+            }
             _ => Err(AssemblerError::ParserError {
                 line: self.current_token().line + 1,
-                message: format!("Instruction inconnue: {}", mnemonic),
+                message: format!("Instruction sans encodage dans l'ISA: {}", mnemonic),
             }),
         }
     }
 
+    /// Parse un registre de contrôle: nom symbolique (ex: mstatus_t) ou numéro
+    fn parse_csr(&mut self) -> Result<String, AssemblerError> {
+        if let TokenType::Number(num) = self.current_token().token_type {
+            self.advance(); // Consommer le numéro
+            Ok(num.to_string())
+        } else {
+            self.parse_label()
+        }
+    }
+
     /// Parse un registre
     fn parse_register(&mut self) -> Result<u8, AssemblerError> {
         if let TokenType::Register(reg) = self.current_token().token_type {
@@ -295,11 +671,43 @@ impl Parser {
         }
     }
 
+    /// Parse un registre vectoriel (V0-V7)
+    fn parse_vector_register(&mut self) -> Result<u8, AssemblerError> {
+        if let TokenType::VectorRegister(reg) = self.current_token().token_type {
+            self.advance(); // Consommer le registre
+            Ok(reg)
+        } else {
+            Err(AssemblerError::ParserError {
+                line: self.current_token().line + 1,
+                message: format!("Attendu un registre vectoriel, trouvé: {:?}", self.current_token().token_type),
+            })
+        }
+    }
+
+    /// Parse trois registres séparés par des virgules (rd, rs1, rs2)
+    fn parse_three_registers(&mut self) -> Result<(u8, u8, u8), AssemblerError> {
+        let rd = self.parse_register()?;
+        self.consume(TokenType::Comma, "Attendu ',' après rd")?;
+        let rs1 = self.parse_register()?;
+        self.consume(TokenType::Comma, "Attendu ',' après rs1")?;
+        let rs2 = self.parse_register()?;
+        Ok((rd, rs1, rs2))
+    }
+
     /// Parse un nombre
     fn parse_number(&mut self) -> Result<i32, AssemblerError> {
-        if let TokenType::Number(num) = self.current_token().token_type {
+        let token = self.current_token();
+        if let TokenType::Number(num) = token.token_type {
             self.advance(); // Consommer le nombre
             Ok(num)
+        } else if let TokenType::LabelRef(name) = &token.token_type {
+            // Une constante définie par .equ est acceptée partout où un nombre l'est
+            let value = *self.constants.get(name).ok_or_else(|| AssemblerError::ParserError {
+                line: token.line + 1,
+                message: format!("Constante non définie: {}", name),
+            })?;
+            self.advance(); // Consommer la constante
+            Ok(value)
         } else {
             Err(AssemblerError::ParserError {
                 line: self.current_token().line + 1,
@@ -308,6 +716,11 @@ impl Parser {
         }
     }
 
+    /// Vérifie si le token courant est une référence à un label (et non à une constante `.equ`)
+    fn at_label_operand(&self) -> bool {
+        matches!(&self.current_token().token_type, TokenType::LabelRef(name) if !self.constants.contains_key(name))
+    }
+
     /// Parse un label
     fn parse_label(&mut self) -> Result<String, AssemblerError> {
         if let TokenType::LabelRef(label) = &self.current_token().token_type {
@@ -327,12 +740,12 @@ impl Parser {
         if self.is_at_end() {
             return false;
         }
-        match (&self.current_token().token_type, &token_type) {
-            (TokenType::EOL, TokenType::EOL) => true,
-            (TokenType::EOF, TokenType::EOF) => true,
-            (TokenType::Comma, TokenType::Comma) => true,
-            _ => false,
-        }
+        matches!(
+            (&self.current_token().token_type, &token_type),
+            (TokenType::EOL, TokenType::EOL)
+                | (TokenType::EOF, TokenType::EOF)
+                | (TokenType::Comma, TokenType::Comma)
+        )
     }
 
     /// Vérifie si le token courant correspond à un prédicat donné
@@ -426,12 +839,15 @@ mod tests {
         let mut parser = Parser::new(tokens);
         let program = parser.parse().unwrap();
 
-        assert_eq!(program.lines.len(), 1);
+        // Le label est suivi de l'instruction de la même ligne
+        assert_eq!(program.lines.len(), 2);
         if let AstNode::Label(label) = &program.lines[0].node {
             assert_eq!(label, "start");
         } else {
             panic!("Expected label");
         }
+        assert!(matches!(program.lines[1].node, AstNode::Instruction(Instruction::Nop)));
+        assert_eq!(program.lines[1].line_number, 1);
     }
 
     #[test]
@@ -540,4 +956,237 @@ mod tests {
             panic!("Expected SUB instruction");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_ternary_extensions() {
+        let source = "TMIN R1, R2, R3\nTROTL R1, R2, -3\nMADDW R1, R2, R3, R4\nISNULL R5, R6\nADDB24 R7, R0, R1";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse().unwrap();
+
+        assert_eq!(program.lines.len(), 5);
+        assert_eq!(
+            program.lines[0].node,
+            AstNode::Instruction(Instruction::Ternary { op: TernaryOp::TMIN, rd: 1, rs1: 2, rs2: 3 })
+        );
+        assert_eq!(
+            program.lines[1].node,
+            AstNode::Instruction(Instruction::TernaryShift { op: TernaryShiftOp::TROTL, rd: 1, rs1: 2, imm: -3 })
+        );
+        assert_eq!(
+            program.lines[2].node,
+            AstNode::Instruction(Instruction::Maddw { rd: 1, rs1: 2, rs2: 3, rs3: 4 })
+        );
+        assert_eq!(
+            program.lines[3].node,
+            AstNode::Instruction(Instruction::SpecialState { op: SpecialStateOp::ISNULL, rd: 5, rs1: 6 })
+        );
+        assert_eq!(
+            program.lines[4].node,
+            AstNode::Instruction(Instruction::Base24 { op: Base24Op::ADDB24, rd: 7, rs1: 0, rs2: 1 })
+        );
+    }
+
+    #[test]
+    fn test_parse_vector_instructions() {
+        let source = "TVLOAD V1, R2
+TVMAC V0, V1, V2, V0
+TVMAC v3, v1, v2
+TVDOT R4, V1, V2
+TVAVG R5, V3
+TVSTORE V0, R6";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse().unwrap();
+
+        let nodes: Vec<&AstNode> = program.lines.iter().map(|line| &line.node).collect();
+        assert_eq!(
+            nodes,
+            vec![
+                &AstNode::Instruction(Instruction::VectorLoad { vd: 1, rs1: 2 }),
+                &AstNode::Instruction(Instruction::Vector { op: VectorOp::TVMAC, vd: 0, vs1: 1, vs2: 2 }),
+                &AstNode::Instruction(Instruction::Vector { op: VectorOp::TVMAC, vd: 3, vs1: 1, vs2: 2 }),
+                &AstNode::Instruction(Instruction::VectorDot { rd: 4, vs1: 1, vs2: 2 }),
+                &AstNode::Instruction(Instruction::VectorReduce { op: VectorReduceOp::TVAVG, rd: 5, vs: 3 }),
+                &AstNode::Instruction(Instruction::VectorStore { vs: 0, rs1: 6 }),
+            ]
+        );
+
+        // L'accumulateur de TVMAC est toujours vd, et les registres ne sont pas interchangeables
+        for source in ["TVMAC V0, V1, V2, V3", "TVADD V0, R1, V2", "TVSUM V1, V2", "TVLOAD V8, R1"] {
+            let parsed = Lexer::new(source).tokenize().and_then(|tokens| Parser::new(tokens).parse());
+            assert!(parsed.is_err(), "{:?} devrait être rejeté", source);
+        }
+    }
+
+    #[test]
+    fn test_parse_branch3() {
+        let source = "BRANCH3 R1, neg, zero, pos";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse().unwrap();
+
+        assert_eq!(program.lines.len(), 1);
+        if let AstNode::Instruction(Instruction::Branch3 { rs1, label_neg, label_zero, label_pos }) = &program.lines[0].node {
+            assert_eq!(*rs1, 1);
+            assert_eq!(label_neg, "neg");
+            assert_eq!(label_zero, "zero");
+            assert_eq!(label_pos, "pos");
+        } else {
+            panic!("Expected BRANCH3 instruction");
+        }
+    }
+
+    #[test]
+    fn test_parse_equ_section_global() {
+        let source = ".equ BASE, 0x100\n.equ NEG, -3\n.section .data\n.global _start\nADDI R1, R0, NEG\n.org BASE";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse().unwrap();
+
+        assert_eq!(
+            program.lines[0].node,
+            AstNode::Directive(Directive::Equ { name: "BASE".to_string(), value: 0x100 })
+        );
+        assert_eq!(program.lines[2].node, AstNode::Directive(Directive::Section(".data".to_string())));
+        assert_eq!(program.lines[3].node, AstNode::Directive(Directive::Global("_start".to_string())));
+        assert_eq!(
+            program.lines[4].node,
+            AstNode::Instruction(Instruction::Addi { rd: 1, rs1: 0, imm: -3 })
+        );
+        assert_eq!(program.lines[5].node, AstNode::Directive(Directive::Org(0x100)));
+    }
+
+    #[test]
+    fn test_parse_undefined_or_duplicate_constant() {
+        for source in ["ADDI R1, R0, INCONNUE", ".equ A, 1\n.equ A, 2"] {
+            let mut lexer = Lexer::new(source);
+            let tokens = lexer.tokenize().unwrap();
+            let mut parser = Parser::new(tokens);
+            assert!(parser.parse().is_err(), "{} devrait être rejeté", source);
+        }
+    }
+
+    #[test]
+    fn test_parse_full_mnemonics() {
+        let source = "MUL R1, R2, R3\nCMP R4, R5\nTRITINV R1, R2\nSUBI R1, R2, -5\nLOADTU R3, R4, 8\nCSRRC_T R1, mepc_t, R2\nCALL R1, cible";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse().unwrap();
+
+        let nodes: Vec<&AstNode> = program.lines.iter().map(|line| &line.node).collect();
+        assert_eq!(*nodes[0], AstNode::Instruction(Instruction::Alu { op: AluOp::Mul, rd: 1, rs1: 2, rs2: 3 }));
+        assert_eq!(*nodes[1], AstNode::Instruction(Instruction::Alu { op: AluOp::Cmp, rd: 0, rs1: 4, rs2: 5 }));
+        assert_eq!(*nodes[2], AstNode::Instruction(Instruction::Alu { op: AluOp::TritInv, rd: 1, rs1: 2, rs2: 0 }));
+        assert_eq!(*nodes[3], AstNode::Instruction(Instruction::AluImm { op: AluOp::Sub, rd: 1, rs1: 2, imm: -5 }));
+        assert_eq!(*nodes[4], AstNode::Instruction(Instruction::Loadtu { rd: 3, rs1: 4, imm: 8 }));
+        assert_eq!(
+            *nodes[5],
+            AstNode::Instruction(Instruction::Csrrc { rd: 1, csr_code: "mepc_t".to_string(), rs1: 2 })
+        );
+        assert_eq!(*nodes[6], AstNode::Instruction(Instruction::Call { rd: 1, label: "cible".to_string() }));
+
+        // Une opération binaire exige ses trois registres
+        let mut lexer = Lexer::new("MUL R1, R2");
+        let mut parser = Parser::new(lexer.tokenize().unwrap());
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn test_parse_pseudo_instructions() {
+        let source = "\
+.equ ADRESSE, 0x1000
+MOVI r1, ADRESSE
+LI R2, -5
+MV R3, R1
+NEG R4, R3
+NOT R6, R3
+J fin
+CALL sous_programme
+RET
+BEQZ R5, fin
+BRANCH GE, fin
+BRANCH ALWAYS, fin";
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse().unwrap();
+
+        let instructions: Vec<(usize, &Instruction)> = program
+            .lines
+            .iter()
+            .filter_map(|line| match &line.node {
+                AstNode::Instruction(instruction) => Some((line.line_number, instruction)),
+                _ => None,
+            })
+            .collect();
+        let branch = |rs1: u8, condition: &str| Instruction::Branch {
+            rs1,
+            rs2: 0,
+            condition: condition.to_string(),
+            label: "fin".to_string(),
+        };
+
+        // 0x1000 = 4096 = 17 × 243 - 35
+        assert_eq!(instructions[0], (2, &Instruction::Lui { rd: 1, imm: 17 }));
+        assert_eq!(instructions[1], (2, &Instruction::Addi { rd: 1, rs1: 1, imm: -35 }));
+        assert_eq!(instructions[2], (3, &Instruction::Lui { rd: 2, imm: 0 }));
+        assert_eq!(instructions[3], (3, &Instruction::Addi { rd: 2, rs1: 2, imm: -5 }));
+        assert_eq!(*instructions[4].1, Instruction::Addi { rd: 3, rs1: 1, imm: 0 });
+        assert_eq!(*instructions[5].1, Instruction::Sub { rd: 4, rs1: 3, rs2: 3 });
+        assert_eq!(*instructions[6].1, Instruction::Sub { rd: 4, rs1: 4, rs2: 3 });
+        assert_eq!(*instructions[7].1, Instruction::Alu { op: AluOp::TritInv, rd: 6, rs1: 3, rs2: 0 });
+        assert_eq!(*instructions[8].1, Instruction::Jal { rd: 0, label: "fin".to_string() });
+        assert_eq!(
+            *instructions[9].1,
+            Instruction::Call { rd: pseudo::LINK_REGISTER, label: "sous_programme".to_string() }
+        );
+        assert_eq!(*instructions[10].1, Instruction::Jalr { rd: 0, rs1: pseudo::LINK_REGISTER, imm: 0 });
+        assert_eq!(*instructions[11].1, Instruction::Addi { rd: 5, rs1: 5, imm: 0 });
+        assert_eq!(*instructions[12].1, branch(5, "EQ"));
+        assert_eq!(*instructions[13].1, branch(0, "GE"));
+        assert_eq!(*instructions[14].1, branch(0, "ALWAYS"));
+
+        // Constante hors de portée de LUI + ADDI, condition inconnue, NEG sans registre libre
+        for source in ["MOVI R1, 300000", "BRANCH PARFOIS, fin", "NEG R2, R2"] {
+            let mut lexer = Lexer::new(source);
+            let mut parser = Parser::new(lexer.tokenize().unwrap());
+            assert!(parser.parse().is_err(), "{} devrait être rejeté", source);
+        }
+    }
+
+    #[test]
+    fn test_parse_address_references() {
+        let source = "\
+.equ TAILLE, 8
+LA R1, tableau
+MOVI R2, tableau
+MOVI R3, TAILLE
+tableau: .word tableau";
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(lexer.tokenize().unwrap());
+        let program = parser.parse().unwrap();
+        let nodes: Vec<&AstNode> = program
+            .lines
+            .iter()
+            .map(|line| &line.node)
+            .filter(|node| !matches!(node, AstNode::Empty | AstNode::Directive(Directive::Equ { .. })))
+            .collect();
+
+        let label = || "tableau".to_string();
+        assert_eq!(*nodes[0], AstNode::Instruction(Instruction::LuiHi { rd: 1, label: label() }));
+        assert_eq!(*nodes[1], AstNode::Instruction(Instruction::AddiLo { rd: 1, rs1: 1, label: label() }));
+        assert_eq!(*nodes[2], AstNode::Instruction(Instruction::LuiHi { rd: 2, label: label() }));
+        assert_eq!(*nodes[3], AstNode::Instruction(Instruction::AddiLo { rd: 2, rs1: 2, label: label() }));
+        // Une constante .equ reste une valeur immédiate
+        assert_eq!(*nodes[4], AstNode::Instruction(Instruction::Lui { rd: 3, imm: 0 }));
+        assert_eq!(*nodes[5], AstNode::Instruction(Instruction::Addi { rd: 3, rs1: 3, imm: 8 }));
+        assert_eq!(*nodes[6], AstNode::Label(label()));
+        assert_eq!(*nodes[7], AstNode::Directive(Directive::WordLabel(label())));
+    }
+}
@@ -0,0 +1,144 @@
+//! Module des pseudo-instructions pour l'assembleur PrismChrono
+//!
+//! Les pseudo-instructions n'ont pas d'encodage propre: le parser les développe
+//! en une ou plusieurs instructions réelles avant la première passe, de sorte que
+//! le calcul des adresses voie directement les instructions finales.
+//!
+//! Convention d'appel: `R7` sert de registre de lien (RA) pour `CALL label` et `RET`.
+
+use crate::ast::Instruction;
+use crate::isa_defs::{imm_limits, split_upper_immediate};
+use prismchrono_isa::AluOp;
+
+/// Registre de lien utilisé par les pseudo-instructions `CALL label` et `RET`
+pub const LINK_REGISTER: u8 = 7;
+
+/// Développe `MOVI rd, valeur` (ou `LI`) en `LUI rd, haut` suivi de `ADDI rd, rd, bas`
+///
+/// `LUI` décale son immédiat de `U_SHIFT_TRITS` trits: la valeur est découpée en une
+/// partie basse ternaire équilibrée sur 5 trits et une partie haute sur 7 trits.
+/// Le `ADDI` est omis quand la partie basse est nulle.
+pub fn expand_load_immediate(rd: u8, value: i32) -> Result<Vec<Instruction>, String> {
+    let (high, low) = split_upper_immediate(value as i64).ok_or_else(|| {
+        format!(
+            "Constante hors plage pour MOVI/LI: {} (doit être entre {} et {})",
+            value,
+            imm_limits::LUI_ADDI_MIN,
+            imm_limits::LUI_ADDI_MAX
+        )
+    })?;
+
+    let mut instructions = vec![Instruction::Lui { rd, imm: high }];
+    if low != 0 {
+        instructions.push(Instruction::Addi { rd, rs1: rd, imm: low });
+    }
+    Ok(instructions)
+}
+
+/// Développe `LA rd, label` (ou `MOVI rd, label`) en `LUI rd, %hi(label)` suivi de
+/// `ADDI rd, rd, %lo(label)`: la séquence garde toujours deux instructions, l'adresse
+/// n'étant connue qu'en passe 2 (ou à l'édition de liens)
+pub fn expand_load_address(rd: u8, label: String) -> Vec<Instruction> {
+    vec![
+        Instruction::LuiHi { rd, label: label.clone() },
+        Instruction::AddiLo { rd, rs1: rd, label },
+    ]
+}
+
+/// Condition de branchement d'une pseudo-instruction de comparaison à zéro (BEQZ, BNEZ, ...)
+pub fn zero_branch_condition(mnemonic: &str) -> Option<&'static str> {
+    match mnemonic {
+        "BEQZ" => Some("EQ"),
+        "BNEZ" => Some("NE"),
+        "BLTZ" => Some("LT"),
+        "BGEZ" => Some("GE"),
+        "BGTZ" => Some("GT"),
+        "BLEZ" => Some("LE"),
+        _ => None,
+    }
+}
+
+/// Développe `BEQZ rs, label` (et variantes) en `ADDI rs, rs, 0` suivi de `BRANCH cond, label`
+///
+/// BRANCH ne teste que les flags: le `ADDI` nul les positionne d'après rs sans le modifier.
+pub fn expand_zero_branch(condition: &str, rs: u8, label: String) -> Vec<Instruction> {
+    vec![
+        Instruction::Addi { rd: rs, rs1: rs, imm: 0 },
+        Instruction::Branch {
+            rs1: rs,
+            rs2: 0,
+            condition: condition.to_string(),
+            label,
+        },
+    ]
+}
+
+/// `MV rd, rs` (ou `MOV`): copie de registre par `ADDI rd, rs, 0`
+pub fn expand_move(rd: u8, rs: u8) -> Instruction {
+    Instruction::Addi { rd, rs1: rs, imm: 0 }
+}
+
+/// `NOT rd, rs`: inversion trit à trit (`TRITINV rd, rs`)
+pub fn expand_not(rd: u8, rs: u8) -> Instruction {
+    Instruction::Alu { op: AluOp::TritInv, rd, rs1: rs, rs2: 0 }
+}
+
+/// `NEG rd, rs`: négation par `SUB rd, rs, rs` (rd = 0) suivi de `SUB rd, rd, rs`
+///
+/// L'inversion trit à trit n'est la négation que si aucun chiffre B24 ne vaut -13..-11 (il
+/// deviendrait un état spécial), et R0 n'est pas câblé à zéro: rd sert de zéro et doit donc
+/// différer de rs.
+pub fn expand_negate(rd: u8, rs: u8) -> Result<Vec<Instruction>, String> {
+    if rd == rs {
+        return Err(format!("NEG R{0}, R{0}: le registre destination doit différer de la source", rd));
+    }
+    Ok(vec![
+        Instruction::Sub { rd, rs1: rs, rs2: rs },
+        Instruction::Sub { rd, rs1: rd, rs2: rs },
+    ])
+}
+
+/// `J label`: saut sans sauvegarde de l'adresse de retour (`JAL R0, label`)
+pub fn expand_jump(label: String) -> Instruction {
+    Instruction::Jal { rd: 0, label }
+}
+
+/// `RET`: retour de sous-programme par le registre de lien (`JALR R0, R7, 0`)
+pub fn expand_return() -> Instruction {
+    Instruction::Jalr { rd: 0, rs1: LINK_REGISTER, imm: 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Valeur chargée par la séquence LUI/ADDI produite
+    fn loaded_value(instructions: &[Instruction]) -> i32 {
+        instructions.iter().fold(0, |acc, instruction| match instruction {
+            Instruction::Lui { imm, .. } => imm * 3i32.pow(imm_limits::U_SHIFT_TRITS),
+            Instruction::Addi { imm, .. } => acc + imm,
+            other => panic!("Instruction inattendue: {:?}", other),
+        })
+    }
+
+    #[test]
+    fn test_expand_load_immediate() {
+        for value in [0, 1, -1, 121, 122, -122, 243, 4096, 5120, -100_000, 265_720, -265_720] {
+            let instructions = expand_load_immediate(3, value).unwrap();
+            assert_eq!(loaded_value(&instructions), value, "valeur {}", value);
+            for instruction in &instructions {
+                match instruction {
+                    Instruction::Lui { imm, .. } => assert!(imm.abs() <= imm_limits::U_MAX),
+                    Instruction::Addi { imm, .. } => assert!(imm.abs() <= imm_limits::I_MAX),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        // Une partie basse nulle n'émet pas d'ADDI
+        assert_eq!(expand_load_immediate(1, 0), Ok(vec![Instruction::Lui { rd: 1, imm: 0 }]));
+        assert_eq!(expand_load_immediate(1, 486), Ok(vec![Instruction::Lui { rd: 1, imm: 2 }]));
+
+        assert!(expand_load_immediate(1, 265_721).is_err());
+    }
+}
@@ -13,6 +13,12 @@ pub struct SymbolTable {
     symbols: HashMap<String, Address>,
 }
 
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SymbolTable {
     /// Crée une nouvelle table des symboles vide
     pub fn new() -> Self {
@@ -61,6 +67,17 @@ impl SymbolTable {
     pub fn symbols(&self) -> &HashMap<String, Address> {
         &self.symbols
     }
+
+    /// Retourne les symboles triés par adresse puis par nom (ordre stable pour les fichiers .sym)
+    pub fn sorted_by_address(&self) -> Vec<(Address, &str)> {
+        let mut sorted: Vec<(Address, &str)> = self
+            .symbols
+            .iter()
+            .map(|(name, address)| (*address, name.as_str()))
+            .collect();
+        sorted.sort();
+        sorted
+    }
 }
 
 #[cfg(test)]
@@ -106,4 +123,17 @@ mod tests {
         assert!(symbol_table.is_defined("start"));
         assert!(!symbol_table.is_defined("end"));
     }
+
+    #[test]
+    fn test_sorted_by_address() {
+        let mut symbol_table = SymbolTable::new();
+        symbol_table.define("loop", 0x120).unwrap();
+        symbol_table.define("start", 0x100).unwrap();
+        symbol_table.define("entry", 0x100).unwrap();
+
+        assert_eq!(
+            symbol_table.sorted_by_address(),
+            vec![(0x100, "entry"), (0x100, "start"), (0x120, "loop")]
+        );
+    }
 }
\ No newline at end of file
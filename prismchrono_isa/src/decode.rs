@@ -0,0 +1,373 @@
+//! Décodage des instructions PrismChrono
+//!
+//! Ce module transforme une séquence de 12 trits en `Instruction`.
+//! Il utilise les mêmes tables (`isa_defs`) que l'encodeur, ce qui garantit
+//! que `decode(encode(i)) == i` pour toute instruction encodable.
+
+use std::fmt;
+
+use crate::isa::Instruction;
+use crate::isa_defs::{
+    self, csr_func, mem_t3_func, opcode, r4_func, system_func, ternary_func, vmem_func, INSTRUCTION_SIZE_TRITS,
+};
+use crate::register::{Register, VectorRegister};
+use crate::types::{Trit, trits_value};
+
+/// Erreurs possibles lors du décodage d'une instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    InvalidOpcode,          // Opcode invalide
+    InvalidFormat,          // Format d'instruction invalide
+    InvalidRegister,        // Registre invalide
+    InvalidAluOp,           // Opération ALU invalide
+    InvalidBranchCondition, // Condition de branchement invalide
+    InvalidInstruction,     // Instruction invalide (autre raison)
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            DecodeError::InvalidOpcode => "Opcode invalide",
+            DecodeError::InvalidFormat => "Format d'instruction invalide",
+            DecodeError::InvalidRegister => "Registre invalide",
+            DecodeError::InvalidAluOp => "Opération ALU invalide",
+            DecodeError::InvalidBranchCondition => "Condition de branchement invalide",
+            DecodeError::InvalidInstruction => "Instruction invalide",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Décode une instruction standard à partir d'une séquence d'au moins 12 trits
+pub fn decode(instr_trits: &[Trit]) -> Result<Instruction, DecodeError> {
+    if instr_trits.len() < INSTRUCTION_SIZE_TRITS {
+        return Err(DecodeError::InvalidFormat);
+    }
+    let t = &instr_trits[..INSTRUCTION_SIZE_TRITS];
+    let op = [t[0], t[1], t[2]];
+
+    match op {
+        opcode::NOP => Ok(Instruction::Nop),
+        opcode::HALT => Ok(Instruction::Halt),
+
+        opcode::R_TYPE => {
+            let op = isa_defs::func_to_alu_op([t[9], t[10], t[11]]).ok_or(DecodeError::InvalidAluOp)?;
+            Ok(Instruction::AluReg {
+                op,
+                rd: trits_to_register(&t[3..5])?,
+                rs1: trits_to_register(&t[5..7])?,
+                rs2: trits_to_register(&t[7..9])?,
+            })
+        }
+        opcode::ADDI | opcode::SUBI | opcode::MINI | opcode::MAXI => Ok(Instruction::AluImm {
+            // Les quatre opcodes ci-dessus ont toujours une opération associée
+            op: isa_defs::imm_opcode_to_alu_op(op).ok_or(DecodeError::InvalidOpcode)?,
+            rd: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            imm: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::LOADW => Ok(Instruction::Load {
+            rd: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            offset: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::LOADT => Ok(Instruction::LoadT {
+            rd: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            offset: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::LOADTU => Ok(Instruction::LoadTU {
+            rd: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            offset: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::JALR => Ok(Instruction::Jalr {
+            rd: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            offset: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::STOREW => Ok(Instruction::Store {
+            rs2: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            offset: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::STORET => Ok(Instruction::StoreT {
+            rs2: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            offset: trits_to_int(&t[7..12]) as i16,
+        }),
+        opcode::BRANCH => Ok(Instruction::Branch {
+            cond: isa_defs::trits_to_branch_condition([t[3], t[4], t[5]])
+                .ok_or(DecodeError::InvalidBranchCondition)?,
+            rs1: trits_to_register(&t[6..8])?,
+            offset: trits_to_int(&t[8..12]) as i16,
+        }),
+        opcode::LUI => Ok(Instruction::Lui {
+            rd: trits_to_register(&t[3..5])?,
+            imm: trits_to_int(&t[5..12]) as i16,
+        }),
+        opcode::AUIPC => Ok(Instruction::Auipc {
+            rd: trits_to_register(&t[3..5])?,
+            imm: trits_to_int(&t[5..12]) as i16,
+        }),
+        opcode::JAL => Ok(Instruction::Jump {
+            rd: trits_to_register(&t[3..5])?,
+            offset: trits_to_int(&t[5..12]) as i16,
+        }),
+        opcode::CALL => Ok(Instruction::Call {
+            rd: trits_to_register(&t[3..5])?,
+            offset: trits_to_int(&t[5..12]) as i16,
+        }),
+        opcode::SYSTEM => decode_system(t),
+        opcode::CSR => decode_csr(t),
+        opcode::CSRI => Ok(Instruction::Csr {
+            rs1: trits_to_register(&t[3..5])?,
+            csr: trits_to_int(&t[5..8]) as i8,
+            offset: trits_to_int(&t[8..12]) as i16,
+        }),
+        opcode::TERNARY => decode_ternary(t),
+        opcode::TSHIFT => Ok(Instruction::TernaryShift {
+            op: isa_defs::func_to_tshift_op([t[7], t[8]]).ok_or(DecodeError::InvalidInstruction)?,
+            rd: trits_to_register(&t[3..5])?,
+            rs1: trits_to_register(&t[5..7])?,
+            imm: trits_to_int(&t[9..12]) as i16,
+        }),
+        opcode::BRANCH3 => {
+            // Les cibles sont encodées en écarts successifs
+            let offset_neg = trits_to_int(&t[5..7]);
+            let offset_zero = offset_neg + trits_to_int(&t[7..9]);
+            let offset_pos = offset_zero + trits_to_int(&t[9..12]);
+            Ok(Instruction::Branch3 {
+                rs1: trits_to_register(&t[3..5])?,
+                offset_neg: offset_neg as i16,
+                offset_zero: offset_zero as i16,
+                offset_pos: offset_pos as i16,
+            })
+        }
+        opcode::R4_TYPE => {
+            let rd = trits_to_register(&t[3..5])?;
+            let rs1 = trits_to_register(&t[5..7])?;
+            let rs2 = trits_to_register(&t[7..9])?;
+            let rs3 = trits_to_register(&t[9..11])?;
+            Ok(match t[11] {
+                r4_func::MADDW => Instruction::MAddW { rd, rs1, rs2, rs3 },
+                r4_func::MSUBW => Instruction::MSubW { rd, rs1, rs2, rs3 },
+                _ => Instruction::TSel { rd, rs1, rs2, rs3 },
+            })
+        }
+        opcode::MEM_T3 => {
+            let reg = trits_to_register(&t[3..5])?;
+            let rs1 = trits_to_register(&t[5..7])?;
+            let offset = trits_to_int(&t[8..12]) as i16;
+            match t[7] {
+                mem_t3_func::LOADT3 => Ok(Instruction::LoadT3 { rd: reg, rs1, offset }),
+                mem_t3_func::STORET3 => Ok(Instruction::StoreT3 { rs1, rs2: reg, offset }),
+                _ => Err(DecodeError::InvalidInstruction),
+            }
+        }
+        _ => Err(DecodeError::InvalidOpcode), // Opcode réservé
+    }
+}
+
+/// Décode une instruction de l'extension ternaire (format R)
+/// [opcode(3t) | rd(2t) | rs1(2t) | rs2(2t) | func(3t)]
+fn decode_ternary(t: &[Trit]) -> Result<Instruction, DecodeError> {
+    let func = [t[9], t[10], t[11]];
+
+    // L'extension vectorielle interprète certains champs comme des registres V
+    if let Some(instr) = decode_vector(t, func)? {
+        return Ok(instr);
+    }
+
+    let rd = trits_to_register(&t[3..5])?;
+    let rs1 = trits_to_register(&t[5..7])?;
+
+    // Les opérations sur les états spéciaux n'utilisent pas rs2
+    if let Some(op) = isa_defs::func_to_special_state_op(func) {
+        return Ok(Instruction::SpecialStateOp { op, rs1, rd });
+    }
+
+    let rs2 = trits_to_register(&t[7..9])?;
+    if let Some(op) = isa_defs::func_to_ternary_op(func) {
+        return Ok(Instruction::TernaryOp { op, rs1, rs2, rd });
+    }
+    if let Some(op) = isa_defs::func_to_base24_op(func) {
+        return Ok(Instruction::Base24Op { op, rs1, rs2, rd });
+    }
+    match func {
+        ternary_func::TMEMCPY => Ok(Instruction::TMemCpy { rd, rs1, rs2 }),
+        ternary_func::TMEMSET => Ok(Instruction::TMemSet { rd, rs1, rs2 }),
+        _ => Err(DecodeError::InvalidInstruction), // Fonction réservée
+    }
+}
+
+/// Décode une instruction de l'extension vectorielle (TVPU)
+/// Retourne `None` si la fonction n'appartient pas à cette extension
+fn decode_vector(t: &[Trit], func: [Trit; 3]) -> Result<Option<Instruction>, DecodeError> {
+    if let Some(op) = isa_defs::func_to_vector_op(func) {
+        return Ok(Some(Instruction::VectorOp {
+            op,
+            vd: trits_to_vector_register(&t[3..5])?,
+            vs1: trits_to_vector_register(&t[5..7])?,
+            vs2: trits_to_vector_register(&t[7..9])?,
+        }));
+    }
+
+    let instr = match func {
+        ternary_func::TVDOT => Instruction::VectorDot {
+            rd: trits_to_register(&t[3..5])?,
+            vs1: trits_to_vector_register(&t[5..7])?,
+            vs2: trits_to_vector_register(&t[7..9])?,
+        },
+        ternary_func::TVRED => Instruction::VectorReduce {
+            op: isa_defs::func_to_vector_reduce_op([t[7], t[8]]).ok_or(DecodeError::InvalidInstruction)?,
+            rd: trits_to_register(&t[3..5])?,
+            vs: trits_to_vector_register(&t[5..7])?,
+        },
+        ternary_func::TVMEM => {
+            let vreg = trits_to_vector_register(&t[3..5])?;
+            let rs1 = trits_to_register(&t[5..7])?;
+            match [t[7], t[8]] {
+                vmem_func::TVLOAD => Instruction::VectorLoad { vd: vreg, rs1 },
+                vmem_func::TVSTORE => Instruction::VectorStore { vs: vreg, rs1 },
+                _ => return Err(DecodeError::InvalidInstruction),
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(instr))
+}
+
+/// Décode une instruction système
+/// [opcode(3t) | func(3t) | inutilisé(6t)]
+fn decode_system(t: &[Trit]) -> Result<Instruction, DecodeError> {
+    let func = trits_to_int(&t[3..6]) as i8;
+    Ok(match func {
+        system_func::EBREAK => Instruction::EBreak,
+        system_func::MRET_T => Instruction::MRet,
+        _ => Instruction::System { func },
+    })
+}
+
+/// Décode une instruction CSR registre
+/// [opcode(3t) | rd(2t) | rs1(2t) | csr(3t) | func(2t)]
+fn decode_csr(t: &[Trit]) -> Result<Instruction, DecodeError> {
+    let rd = trits_to_register(&t[3..5])?;
+    let rs1 = trits_to_register(&t[5..7])?;
+    let csr = trits_to_int(&t[7..10]) as i8;
+
+    match [t[10], t[11]] {
+        csr_func::CSRRW_T => Ok(Instruction::CsrRw { rd, csr, rs1 }),
+        csr_func::CSRRS_T => Ok(Instruction::CsrRs { rd, csr, rs1 }),
+        csr_func::CSRRC_T => {
+            let csr = u8::try_from(csr).map_err(|_| DecodeError::InvalidInstruction)?;
+            Ok(Instruction::CsrRc { rd, rs1, csr })
+        }
+        _ => Err(DecodeError::InvalidInstruction),
+    }
+}
+
+/// Convertit 2 trits en registre
+/// Les valeurs -4..2 désignent R0..R6, la valeur 4 désigne R7 et 3 est invalide
+pub fn trits_to_register(trits: &[Trit]) -> Result<Register, DecodeError> {
+    let index = match trits_to_int(trits) {
+        4 => 7,
+        value @ -4..=2 => (value + 4) as usize,
+        _ => return Err(DecodeError::InvalidRegister),
+    };
+    Register::from_index(index).map_err(|_| DecodeError::InvalidRegister)
+}
+
+/// Convertit 2 trits en registre vectoriel (même codage que les registres généraux)
+pub fn trits_to_vector_register(trits: &[Trit]) -> Result<VectorRegister, DecodeError> {
+    let reg = trits_to_register(trits)?;
+    VectorRegister::from_index(reg.to_index()).map_err(|_| DecodeError::InvalidRegister)
+}
+
+/// Convertit une séquence de trits (poids faible en premier) en entier
+/// Les champs d'instruction font au plus 12 trits et tiennent donc dans un i32
+pub fn trits_to_int(trits: &[Trit]) -> i32 {
+    trits_value(trits) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::isa::{AluOp, BranchCondition};
+
+    /// Construit une instruction à partir de ses champs (dans l'ordre des trits)
+    fn instr(fields: &[&[Trit]]) -> Vec<Trit> {
+        fields.iter().flat_map(|f| f.iter().copied()).collect()
+    }
+
+    #[test]
+    fn test_decode_nop_halt() {
+        assert_eq!(decode(&[Trit::Z; 12]), Ok(Instruction::Nop));
+
+        // Motif produit par l'assembleur pour HALT: 00+ suivi de zéros
+        let halt = instr(&[&opcode::HALT, &[Trit::Z; 9]]);
+        assert_eq!(decode(&halt), Ok(Instruction::Halt));
+    }
+
+    #[test]
+    fn test_decode_alu_reg() {
+        // ADD R3, R1, R2: rd=R3 (-1), rs1=R1 (-3), rs2=R2 (-2)
+        let trits = instr(&[
+            &opcode::R_TYPE,
+            &[Trit::N, Trit::Z],
+            &[Trit::Z, Trit::N],
+            &[Trit::P, Trit::N],
+            &isa_defs::func::ADD,
+        ]);
+        assert_eq!(
+            decode(&trits),
+            Ok(Instruction::AluReg { op: AluOp::Add, rs1: Register::R1, rs2: Register::R2, rd: Register::R3 })
+        );
+    }
+
+    #[test]
+    fn test_decode_branch() {
+        // BRANCH GT, R4, -4
+        let trits = instr(&[
+            &opcode::BRANCH,
+            &isa_defs::cond::GT,
+            &[Trit::Z, Trit::Z],
+            &[Trit::N, Trit::N, Trit::Z, Trit::Z],
+        ]);
+        assert_eq!(
+            decode(&trits),
+            Ok(Instruction::Branch { rs1: Register::R4, cond: BranchCondition::Greater, offset: -4 })
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // Opcode réservé
+        assert_eq!(decode(&instr(&[&opcode::SLTI, &[Trit::Z; 9]])), Err(DecodeError::InvalidOpcode));
+
+        // Registre invalide (valeur 3)
+        let trits = instr(&[&opcode::LOADW, &[Trit::Z, Trit::P], &[Trit::Z; 7]]);
+        assert_eq!(decode(&trits), Err(DecodeError::InvalidRegister));
+
+        // Fonction ALU réservée (SLT)
+        let trits = instr(&[&opcode::R_TYPE, &[Trit::Z; 6], &isa_defs::func::SLT]);
+        assert_eq!(decode(&trits), Err(DecodeError::InvalidAluOp));
+
+        // Condition de branchement réservée
+        let trits = instr(&[&opcode::BRANCH, &[Trit::N, Trit::N, Trit::N], &[Trit::Z; 6]]);
+        assert_eq!(decode(&trits), Err(DecodeError::InvalidBranchCondition));
+
+        // CSRRC avec un numéro de CSR négatif
+        let trits = instr(&[&opcode::CSR, &[Trit::Z; 4], &[Trit::N, Trit::Z, Trit::Z], &csr_func::CSRRC_T]);
+        assert_eq!(decode(&trits), Err(DecodeError::InvalidInstruction));
+
+        // Fonction réservée de l'extension ternaire
+        let trits = instr(&[&opcode::TERNARY, &[Trit::Z; 9]]);
+        assert_eq!(decode(&trits), Err(DecodeError::InvalidInstruction));
+
+        // Instruction trop courte
+        assert_eq!(decode(&[Trit::Z; 11]), Err(DecodeError::InvalidFormat));
+    }
+}
@@ -0,0 +1,565 @@
+//! Encodage des instructions PrismChrono
+//!
+//! Ce module transforme une `Instruction` décodée en sa représentation
+//! canonique sur 12 trits. C'est l'inverse exact de `decode::decode`.
+//!
+//! Dans chaque champ, les trits sont rangés du poids faible au poids fort.
+
+use std::fmt;
+
+use crate::isa::{AluOp, Instruction};
+use crate::isa_defs::{
+    self, csr_func, mem_t3_func, opcode, r4_func, system_func, ternary_func, vmem_func, INSTRUCTION_SIZE_TRITS,
+};
+use crate::register::{Register, VectorRegister};
+use crate::types::{Trit, balanced_trits};
+
+/// Erreurs possibles lors de l'encodage d'une instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// Valeur trop grande pour la largeur du champ
+    FieldOutOfRange {
+        field: &'static str,
+        value: i32,
+        min: i32,
+        max: i32,
+    },
+    /// Opération ALU sans encodage dans le format demandé
+    UnsupportedAluOp(AluOp),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::FieldOutOfRange { field, value, min, max } => write!(
+                f,
+                "Valeur {} hors limites pour le champ {} (doit être entre {} et {})",
+                value, field, min, max
+            ),
+            EncodeError::UnsupportedAluOp(op) => {
+                write!(f, "L'opération ALU {:?} n'a pas d'encodage dans ce format", op)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Encode une instruction en 12 trits
+pub fn encode(instr: &Instruction) -> Result<[Trit; 12], EncodeError> {
+    match instr {
+        Instruction::Nop => Ok([Trit::Z; INSTRUCTION_SIZE_TRITS]),
+        Instruction::Halt => Ok(with_opcode(opcode::HALT)),
+        Instruction::EBreak => assemble_system(system_func::EBREAK),
+        Instruction::MRet => assemble_system(system_func::MRET_T),
+        Instruction::System { func } => assemble_system(*func),
+
+        Instruction::AluReg { op, rs1, rs2, rd } => {
+            let func = isa_defs::alu_op_to_func(op).ok_or(EncodeError::UnsupportedAluOp(op.clone()))?;
+            Ok(assemble_r_format(opcode::R_TYPE, func, *rd, *rs1, *rs2))
+        }
+        Instruction::AluImm { op, rs1, rd, imm } => {
+            let op_trits = isa_defs::alu_op_to_imm_opcode(op).ok_or(EncodeError::UnsupportedAluOp(op.clone()))?;
+            assemble_i_format(op_trits, *rd, *rs1, *imm as i32)
+        }
+        Instruction::Load { rd, rs1, offset } => assemble_i_format(opcode::LOADW, *rd, *rs1, *offset as i32),
+        Instruction::LoadT { rd, rs1, offset } => assemble_i_format(opcode::LOADT, *rd, *rs1, *offset as i32),
+        Instruction::LoadTU { rd, rs1, offset } => assemble_i_format(opcode::LOADTU, *rd, *rs1, *offset as i32),
+        Instruction::Jalr { rd, rs1, offset } => assemble_i_format(opcode::JALR, *rd, *rs1, *offset as i32),
+        Instruction::Store { rs1, rs2, offset } => assemble_s_format(opcode::STOREW, *rs1, *rs2, *offset as i32),
+        Instruction::StoreT { rs1, rs2, offset } => assemble_s_format(opcode::STORET, *rs1, *rs2, *offset as i32),
+        Instruction::Branch { rs1, cond, offset } => assemble_b_format(
+            opcode::BRANCH,
+            isa_defs::branch_condition_to_trits(*cond),
+            *rs1,
+            *offset as i32,
+        ),
+        Instruction::Lui { rd, imm } => assemble_u_format(opcode::LUI, *rd, *imm as i32),
+        Instruction::Auipc { rd, imm } => assemble_u_format(opcode::AUIPC, *rd, *imm as i32),
+        Instruction::Jump { rd, offset } => assemble_j_format(opcode::JAL, *rd, *offset as i32),
+        Instruction::Call { rd, offset } => assemble_j_format(opcode::CALL, *rd, *offset as i32),
+
+        Instruction::CsrRw { rd, csr, rs1 } => assemble_csr_format(csr_func::CSRRW_T, *rd, *rs1, *csr as i32),
+        Instruction::CsrRs { rd, csr, rs1 } => assemble_csr_format(csr_func::CSRRS_T, *rd, *rs1, *csr as i32),
+        Instruction::CsrRc { rd, rs1, csr } => assemble_csr_format(csr_func::CSRRC_T, *rd, *rs1, *csr as i32),
+        Instruction::Csr { csr, rs1, offset } => {
+            let mut trits = with_opcode(opcode::CSRI);
+            trits[3..5].copy_from_slice(&register_to_trits(*rs1));
+            trits[5..8].copy_from_slice(&int_to_trits::<3>("csr", *csr as i32)?);
+            trits[8..12].copy_from_slice(&int_to_trits::<4>("offset", *offset as i32)?);
+            Ok(trits)
+        }
+
+        Instruction::TernaryOp { op, rs1, rs2, rd } => Ok(assemble_r_format(
+            opcode::TERNARY,
+            isa_defs::ternary_op_to_func(*op),
+            *rd,
+            *rs1,
+            *rs2,
+        )),
+        Instruction::Base24Op { op, rs1, rs2, rd } => Ok(assemble_r_format(
+            opcode::TERNARY,
+            isa_defs::base24_op_to_func(*op),
+            *rd,
+            *rs1,
+            *rs2,
+        )),
+        Instruction::TMemCpy { rd, rs1, rs2 } => {
+            Ok(assemble_r_format(opcode::TERNARY, ternary_func::TMEMCPY, *rd, *rs1, *rs2))
+        }
+        Instruction::TMemSet { rd, rs1, rs2 } => {
+            Ok(assemble_r_format(opcode::TERNARY, ternary_func::TMEMSET, *rd, *rs1, *rs2))
+        }
+        Instruction::SpecialStateOp { op, rs1, rd } => {
+            // Le champ rs2 n'est pas utilisé et reste à zéro
+            let mut trits = with_opcode(opcode::TERNARY);
+            trits[3..5].copy_from_slice(&register_to_trits(*rd));
+            trits[5..7].copy_from_slice(&register_to_trits(*rs1));
+            trits[9..12].copy_from_slice(&isa_defs::special_state_op_to_func(*op));
+            Ok(trits)
+        }
+        Instruction::VectorOp { op, vd, vs1, vs2 } => {
+            let mut trits = with_opcode(opcode::TERNARY);
+            trits[3..5].copy_from_slice(&vector_register_to_trits(*vd));
+            trits[5..7].copy_from_slice(&vector_register_to_trits(*vs1));
+            trits[7..9].copy_from_slice(&vector_register_to_trits(*vs2));
+            trits[9..12].copy_from_slice(&isa_defs::vector_op_to_func(*op));
+            Ok(trits)
+        }
+        Instruction::VectorDot { rd, vs1, vs2 } => {
+            let mut trits = with_opcode(opcode::TERNARY);
+            trits[3..5].copy_from_slice(&register_to_trits(*rd));
+            trits[5..7].copy_from_slice(&vector_register_to_trits(*vs1));
+            trits[7..9].copy_from_slice(&vector_register_to_trits(*vs2));
+            trits[9..12].copy_from_slice(&ternary_func::TVDOT);
+            Ok(trits)
+        }
+        Instruction::VectorReduce { op, rd, vs } => {
+            let mut trits = with_opcode(opcode::TERNARY);
+            trits[3..5].copy_from_slice(&register_to_trits(*rd));
+            trits[5..7].copy_from_slice(&vector_register_to_trits(*vs));
+            trits[7..9].copy_from_slice(&isa_defs::vector_reduce_op_to_func(*op));
+            trits[9..12].copy_from_slice(&ternary_func::TVRED);
+            Ok(trits)
+        }
+        Instruction::VectorLoad { vd, rs1 } => Ok(assemble_vector_mem(vmem_func::TVLOAD, *vd, *rs1)),
+        Instruction::VectorStore { vs, rs1 } => Ok(assemble_vector_mem(vmem_func::TVSTORE, *vs, *rs1)),
+        Instruction::TernaryShift { op, rs1, rd, imm } => {
+            let mut trits = with_opcode(opcode::TSHIFT);
+            trits[3..5].copy_from_slice(&register_to_trits(*rd));
+            trits[5..7].copy_from_slice(&register_to_trits(*rs1));
+            trits[7..9].copy_from_slice(&isa_defs::tshift_op_to_func(*op));
+            trits[9..12].copy_from_slice(&int_to_trits::<3>("imm", *imm as i32)?);
+            Ok(trits)
+        }
+        Instruction::Branch3 { rs1, offset_neg, offset_zero, offset_pos } => {
+            assemble_branch3(*rs1, *offset_neg as i32, *offset_zero as i32, *offset_pos as i32)
+        }
+        Instruction::LoadT3 { rd, rs1, offset } => {
+            assemble_mem_t3_format(mem_t3_func::LOADT3, *rd, *rs1, *offset as i32)
+        }
+        Instruction::StoreT3 { rs1, rs2, offset } => {
+            assemble_mem_t3_format(mem_t3_func::STORET3, *rs2, *rs1, *offset as i32)
+        }
+        Instruction::MAddW { rd, rs1, rs2, rs3 } => Ok(assemble_r4_format(r4_func::MADDW, *rd, *rs1, *rs2, *rs3)),
+        Instruction::MSubW { rd, rs1, rs2, rs3 } => Ok(assemble_r4_format(r4_func::MSUBW, *rd, *rs1, *rs2, *rs3)),
+        Instruction::TSel { rd, rs1, rs2, rs3 } => Ok(assemble_r4_format(r4_func::TSEL, *rd, *rs1, *rs2, *rs3)),
+    }
+}
+
+/// Assemble une instruction au format R
+/// [opcode(3t) | rd(2t) | rs1(2t) | rs2(2t) | func(3t)]
+pub fn assemble_r_format(op: [Trit; 3], func: [Trit; 3], rd: Register, rs1: Register, rs2: Register) -> [Trit; 12] {
+    let mut trits = with_opcode(op);
+    trits[3..5].copy_from_slice(&register_to_trits(rd));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7..9].copy_from_slice(&register_to_trits(rs2));
+    trits[9..12].copy_from_slice(&func);
+    trits
+}
+
+/// Assemble une instruction au format I
+/// [opcode(3t) | rd(2t) | rs1(2t) | imm(5t)]
+pub fn assemble_i_format(op: [Trit; 3], rd: Register, rs1: Register, imm: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(op);
+    trits[3..5].copy_from_slice(&register_to_trits(rd));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7..12].copy_from_slice(&int_to_trits::<5>("imm", imm)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction au format S
+/// [opcode(3t) | src=rs2(2t) | base=rs1(2t) | offset(5t)]
+pub fn assemble_s_format(op: [Trit; 3], rs1: Register, rs2: Register, offset: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(op);
+    trits[3..5].copy_from_slice(&register_to_trits(rs2));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7..12].copy_from_slice(&int_to_trits::<5>("offset", offset)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction au format B
+/// [opcode(3t) | cond(3t) | rs1(2t) | offset(4t)]
+pub fn assemble_b_format(op: [Trit; 3], cond: [Trit; 3], rs1: Register, offset: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(op);
+    trits[3..6].copy_from_slice(&cond);
+    trits[6..8].copy_from_slice(&register_to_trits(rs1));
+    trits[8..12].copy_from_slice(&int_to_trits::<4>("offset", offset)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction au format U
+/// [opcode(3t) | rd(2t) | imm(7t)]
+pub fn assemble_u_format(op: [Trit; 3], rd: Register, imm: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(op);
+    trits[3..5].copy_from_slice(&register_to_trits(rd));
+    trits[5..12].copy_from_slice(&int_to_trits::<7>("imm", imm)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction au format J
+/// [opcode(3t) | rd(2t) | offset(7t)]
+pub fn assemble_j_format(op: [Trit; 3], rd: Register, offset: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(op);
+    trits[3..5].copy_from_slice(&register_to_trits(rd));
+    trits[5..12].copy_from_slice(&int_to_trits::<7>("offset", offset)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction CSR registre
+/// [opcode(3t) | rd(2t) | rs1(2t) | csr(3t) | func(2t)]
+pub fn assemble_csr_format(func: [Trit; 2], rd: Register, rs1: Register, csr: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(opcode::CSR);
+    trits[3..5].copy_from_slice(&register_to_trits(rd));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7..10].copy_from_slice(&int_to_trits::<3>("csr", csr)?);
+    trits[10..12].copy_from_slice(&func);
+    Ok(trits)
+}
+
+/// Assemble un branchement ternaire à 3 voies
+/// [opcode(3t) | rs1(2t) | neg(2t) | zero - neg(2t) | pos - zero(3t)]
+///
+/// Les cibles sont encodées en écarts successifs: les trois cas d'un BRANCH3
+/// sont en général des blocs voisins, ce qui permet de les atteindre avec
+/// seulement 7 trits d'offset.
+pub fn assemble_branch3(
+    rs1: Register,
+    offset_neg: i32,
+    offset_zero: i32,
+    offset_pos: i32,
+) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(opcode::BRANCH3);
+    trits[3..5].copy_from_slice(&register_to_trits(rs1));
+    trits[5..7].copy_from_slice(&int_to_trits::<2>("offset_neg", offset_neg)?);
+    trits[7..9].copy_from_slice(&int_to_trits::<2>("offset_zero - offset_neg", offset_zero - offset_neg)?);
+    trits[9..12].copy_from_slice(&int_to_trits::<3>("offset_pos - offset_zero", offset_pos - offset_zero)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction au format R4 (trois registres sources)
+/// [opcode(3t) | rd(2t) | rs1(2t) | rs2(2t) | rs3(2t) | func(1t)]
+pub fn assemble_r4_format(func: Trit, rd: Register, rs1: Register, rs2: Register, rs3: Register) -> [Trit; 12] {
+    let mut trits = with_opcode(opcode::R4_TYPE);
+    trits[3..5].copy_from_slice(&register_to_trits(rd));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7..9].copy_from_slice(&register_to_trits(rs2));
+    trits[9..11].copy_from_slice(&register_to_trits(rs3));
+    trits[11] = func;
+    trits
+}
+
+/// Assemble un chargement/stockage de 3 trytes
+/// [opcode(3t) | rd/rs2(2t) | rs1(2t) | func(1t) | offset(4t)]
+pub fn assemble_mem_t3_format(func: Trit, reg: Register, rs1: Register, offset: i32) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(opcode::MEM_T3);
+    trits[3..5].copy_from_slice(&register_to_trits(reg));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7] = func;
+    trits[8..12].copy_from_slice(&int_to_trits::<4>("offset", offset)?);
+    Ok(trits)
+}
+
+/// Assemble une instruction système
+/// [opcode(3t) | func(3t) | inutilisé(6t)]
+fn assemble_system(func: i8) -> Result<[Trit; 12], EncodeError> {
+    let mut trits = with_opcode(opcode::SYSTEM);
+    trits[3..6].copy_from_slice(&int_to_trits::<3>("func", func as i32)?);
+    Ok(trits)
+}
+
+/// Crée une instruction vide (tous les trits à Z) avec l'opcode donné
+fn with_opcode(op: [Trit; 3]) -> [Trit; 12] {
+    let mut trits = [Trit::Z; INSTRUCTION_SIZE_TRITS];
+    trits[0..3].copy_from_slice(&op);
+    trits
+}
+
+/// Convertit un registre en 2 trits
+/// R0..R6 sont encodés de -4 à 2, R7 est encodé 4 (la valeur 3 est invalide)
+pub fn register_to_trits(reg: Register) -> [Trit; 2] {
+    let value = match reg.to_index() {
+        7 => 4,
+        index => index as i32 - 4,
+    };
+    // La valeur est toujours dans la plage d'un champ de 2 trits (-4 à 4)
+    int_to_trits::<2>("register", value).unwrap()
+}
+
+/// Convertit un registre vectoriel en 2 trits (même codage que les registres généraux)
+pub fn vector_register_to_trits(reg: VectorRegister) -> [Trit; 2] {
+    // Les deux bancs ont 8 registres: l'index est toujours valide
+    register_to_trits(Register::from_index(reg.to_index()).unwrap())
+}
+
+/// Assemble un accès mémoire vectoriel
+/// [opcode(3t) | vd/vs(2t) | rs1(2t) | func(2t) | TVMEM(3t)]
+fn assemble_vector_mem(func: [Trit; 2], vreg: VectorRegister, rs1: Register) -> [Trit; 12] {
+    let mut trits = with_opcode(opcode::TERNARY);
+    trits[3..5].copy_from_slice(&vector_register_to_trits(vreg));
+    trits[5..7].copy_from_slice(&register_to_trits(rs1));
+    trits[7..9].copy_from_slice(&func);
+    trits[9..12].copy_from_slice(&ternary_func::TVMEM);
+    trits
+}
+
+/// Convertit un entier en N trits (ternaire équilibré, poids faible en premier)
+/// Retourne une erreur si la valeur ne tient pas dans N trits
+pub fn int_to_trits<const N: usize>(field: &'static str, value: i32) -> Result<[Trit; N], EncodeError> {
+    balanced_trits::<N>(value as i64).map_err(|_| {
+        let max = (3i32.pow(N as u32) - 1) / 2;
+        EncodeError::FieldOutOfRange { field, value, min: -max, max }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::{decode, trits_to_int};
+
+    #[test]
+    fn test_int_to_trits_bounds() {
+        assert_eq!(int_to_trits::<3>("imm", 0).unwrap(), [Trit::Z, Trit::Z, Trit::Z]);
+        assert_eq!(int_to_trits::<3>("imm", 13).unwrap(), [Trit::P, Trit::P, Trit::P]);
+        assert_eq!(int_to_trits::<3>("imm", -13).unwrap(), [Trit::N, Trit::N, Trit::N]);
+        assert_eq!(int_to_trits::<2>("imm", 2).unwrap(), [Trit::N, Trit::P]);
+
+        for n in -121..=121 {
+            assert_eq!(trits_to_int(&int_to_trits::<5>("imm", n).unwrap()), n);
+        }
+
+        assert_eq!(
+            int_to_trits::<5>("imm", 122),
+            Err(EncodeError::FieldOutOfRange { field: "imm", value: 122, min: -121, max: 121 })
+        );
+    }
+
+    #[test]
+    fn test_register_encoding() {
+        assert_eq!(register_to_trits(Register::R0), [Trit::N, Trit::N]); // -4
+        assert_eq!(register_to_trits(Register::R4), [Trit::Z, Trit::Z]); // 0
+        assert_eq!(register_to_trits(Register::R7), [Trit::P, Trit::P]); // 4
+    }
+
+    #[test]
+    fn test_encode_halt_nop() {
+        assert_eq!(encode(&Instruction::Nop).unwrap(), [Trit::Z; 12]);
+
+        let halt = encode(&Instruction::Halt).unwrap();
+        assert_eq!(&halt[0..3], &opcode::HALT);
+        assert!(halt[3..].iter().all(|t| *t == Trit::Z));
+    }
+
+    #[test]
+    fn test_encode_field_layout() {
+        // ADD R1, R2, R3
+        let trits = encode(&Instruction::AluReg {
+            op: AluOp::Add,
+            rs1: Register::R2,
+            rs2: Register::R3,
+            rd: Register::R1,
+        })
+        .unwrap();
+        assert_eq!(&trits[0..3], &opcode::R_TYPE);
+        assert_eq!(&trits[3..5], &register_to_trits(Register::R1));
+        assert_eq!(&trits[5..7], &register_to_trits(Register::R2));
+        assert_eq!(&trits[7..9], &register_to_trits(Register::R3));
+        assert_eq!(&trits[9..12], &isa_defs::func::ADD);
+
+        // ADDI R1, R2, 10
+        let trits = encode(&Instruction::AluImm {
+            op: AluOp::Add,
+            rs1: Register::R2,
+            rd: Register::R1,
+            imm: 10,
+        })
+        .unwrap();
+        assert_eq!(&trits[0..3], &opcode::ADDI);
+        assert_eq!(trits_to_int(&trits[7..12]), 10);
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let result = encode(&Instruction::AluReg {
+            op: AluOp::Clamp,
+            rs1: Register::R0,
+            rs2: Register::R0,
+            rd: Register::R0,
+        });
+        assert_eq!(result, Err(EncodeError::UnsupportedAluOp(AluOp::Clamp)));
+
+        let result = encode(&Instruction::AluImm {
+            op: AluOp::Mul,
+            rs1: Register::R0,
+            rd: Register::R0,
+            imm: 1,
+        });
+        assert_eq!(result, Err(EncodeError::UnsupportedAluOp(AluOp::Mul)));
+
+        let result = encode(&Instruction::Branch {
+            rs1: Register::R0,
+            cond: crate::isa::BranchCondition::Zero,
+            offset: 41,
+        });
+        assert!(matches!(result, Err(EncodeError::FieldOutOfRange { value: 41, .. })));
+
+        let result = encode(&Instruction::Lui { rd: Register::R0, imm: -1094 });
+        assert!(matches!(result, Err(EncodeError::FieldOutOfRange { value: -1094, .. })));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        // Les instructions encodées doivent être décodées à l'identique
+        let instructions = [
+            Instruction::Nop,
+            Instruction::Halt,
+            Instruction::EBreak,
+            Instruction::MRet,
+            Instruction::System { func: system_func::HALT },
+            Instruction::System { func: system_func::NOP },
+            Instruction::System { func: system_func::ECALL },
+            Instruction::System { func: system_func::SRET_T },
+            Instruction::AluImm { op: AluOp::Sub, rs1: Register::R7, rd: Register::R0, imm: -121 },
+            Instruction::AluImm { op: AluOp::TritMin, rs1: Register::R3, rd: Register::R5, imm: 121 },
+            Instruction::AluImm { op: AluOp::TritMax, rs1: Register::R6, rd: Register::R2, imm: 0 },
+            Instruction::Load { rd: Register::R4, rs1: Register::R2, offset: 3 },
+            Instruction::Store { rs1: Register::R2, rs2: Register::R5, offset: -121 },
+            Instruction::LoadT { rd: Register::R1, rs1: Register::R3, offset: -7 },
+            Instruction::LoadTU { rd: Register::R6, rs1: Register::R0, offset: 121 },
+            Instruction::StoreT { rs1: Register::R4, rs2: Register::R1, offset: 1 },
+            Instruction::Jalr { rd: Register::R4, rs1: Register::R2, offset: -2 },
+            Instruction::Lui { rd: Register::R5, imm: 1093 },
+            Instruction::Auipc { rd: Register::R6, imm: -1093 },
+            Instruction::Jump { rd: Register::R7, offset: 42 },
+            Instruction::Call { rd: Register::R1, offset: -1093 },
+            Instruction::CsrRw { rd: Register::R1, csr: isa_defs::csr::MTVEC_T, rs1: Register::R2 },
+            Instruction::CsrRs { rd: Register::R3, csr: -13, rs1: Register::R4 },
+            Instruction::CsrRc { rd: Register::R5, rs1: Register::R6, csr: 13 },
+            Instruction::Csr { csr: isa_defs::csr::MEPC_T, rs1: Register::R7, offset: -40 },
+        ];
+        for instr in instructions {
+            let trits = encode(&instr).unwrap();
+            assert_eq!(decode(&trits), Ok(instr.clone()), "aller-retour de {:?}", instr);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_extensions() {
+        use crate::isa::{Base24Op, SpecialStateOp, TernaryOp, TernaryShiftOp, VectorOp, VectorReduceOp};
+        use crate::register::VectorRegister;
+
+        let mut instructions = vec![
+            Instruction::TMemCpy { rd: Register::R1, rs1: Register::R2, rs2: Register::R3 },
+            Instruction::TMemSet { rd: Register::R7, rs1: Register::R0, rs2: Register::R4 },
+            Instruction::MAddW { rd: Register::R1, rs1: Register::R2, rs2: Register::R3, rs3: Register::R7 },
+            Instruction::MSubW { rd: Register::R0, rs1: Register::R6, rs2: Register::R5, rs3: Register::R4 },
+            Instruction::TSel { rd: Register::R3, rs1: Register::R4, rs2: Register::R5, rs3: Register::R6 },
+            Instruction::LoadT3 { rd: Register::R2, rs1: Register::R1, offset: -40 },
+            Instruction::StoreT3 { rs1: Register::R1, rs2: Register::R7, offset: 40 },
+            Instruction::Branch3 { rs1: Register::R7, offset_neg: 1, offset_zero: 5, offset_pos: 9 },
+            Instruction::Branch3 { rs1: Register::R0, offset_neg: -4, offset_zero: -8, offset_pos: -21 },
+        ];
+        for op in [TernaryOp::TMIN, TernaryOp::TMAX, TernaryOp::TSUM, TernaryOp::TCMP3] {
+            instructions.push(Instruction::TernaryOp { op, rs1: Register::R1, rs2: Register::R2, rd: Register::R3 });
+        }
+        for op in [
+            Base24Op::ADDB24, Base24Op::SUBB24, Base24Op::MULB24,
+            Base24Op::DIVB24, Base24Op::CVTB24, Base24Op::CVTFRB24,
+        ] {
+            instructions.push(Instruction::Base24Op { op, rs1: Register::R5, rs2: Register::R6, rd: Register::R7 });
+        }
+        for op in [
+            SpecialStateOp::ISNULL, SpecialStateOp::ISNAN, SpecialStateOp::ISUNDEF,
+            SpecialStateOp::SETNULL, SpecialStateOp::SETNAN, SpecialStateOp::SETUNDEF,
+        ] {
+            instructions.push(Instruction::SpecialStateOp { op, rs1: Register::R2, rd: Register::R0 });
+        }
+        for op in [TernaryShiftOp::TROTL, TernaryShiftOp::TROTR, TernaryShiftOp::TSHIFTL, TernaryShiftOp::TSHIFTR] {
+            instructions.push(Instruction::TernaryShift { op, rs1: Register::R4, rd: Register::R1, imm: -13 });
+            instructions.push(Instruction::TernaryShift { op, rs1: Register::R4, rd: Register::R1, imm: 13 });
+        }
+        for op in [VectorOp::TVADD, VectorOp::TVSUB, VectorOp::TVMUL, VectorOp::TVMAC] {
+            instructions.push(Instruction::VectorOp {
+                op,
+                vd: VectorRegister::V7,
+                vs1: VectorRegister::V0,
+                vs2: VectorRegister::V3,
+            });
+        }
+        for op in [VectorReduceOp::TVSUM, VectorReduceOp::TVMIN, VectorReduceOp::TVMAX, VectorReduceOp::TVAVG] {
+            instructions.push(Instruction::VectorReduce { op, rd: Register::R7, vs: VectorRegister::V5 });
+        }
+        instructions.push(Instruction::VectorDot { rd: Register::R2, vs1: VectorRegister::V1, vs2: VectorRegister::V7 });
+        instructions.push(Instruction::VectorLoad { vd: VectorRegister::V6, rs1: Register::R3 });
+        instructions.push(Instruction::VectorStore { vs: VectorRegister::V0, rs1: Register::R7 });
+
+        for instr in instructions {
+            let trits = encode(&instr).unwrap();
+            assert_eq!(decode(&trits), Ok(instr.clone()), "aller-retour de {:?}", instr);
+        }
+    }
+
+    #[test]
+    fn test_encode_branch3_limits() {
+        // Écart négatif-nul hors de la plage de 2 trits
+        let instr = Instruction::Branch3 { rs1: Register::R1, offset_neg: 1, offset_zero: 6, offset_pos: 7 };
+        assert!(matches!(encode(&instr), Err(EncodeError::FieldOutOfRange { value: 5, .. })));
+
+        // Dernier écart sur 3 trits
+        let instr = Instruction::Branch3 { rs1: Register::R1, offset_neg: 1, offset_zero: 2, offset_pos: 15 };
+        assert!(encode(&instr).is_ok());
+        let instr = Instruction::Branch3 { rs1: Register::R1, offset_neg: 1, offset_zero: 2, offset_pos: 16 };
+        assert!(encode(&instr).is_err());
+    }
+
+    #[test]
+    fn test_roundtrip_alu_ops() {
+        let ops = [
+            AluOp::Add, AluOp::Sub, AluOp::Mul, AluOp::Div, AluOp::Mod,
+            AluOp::TritInv, AluOp::TritMin, AluOp::TritMax, AluOp::And, AluOp::Or, AluOp::Xor,
+            AluOp::Shl, AluOp::Shr, AluOp::Cmp, AluOp::Compare3, AluOp::Abs, AluOp::Signum,
+            AluOp::ExtractTryte, AluOp::InsertTryte, AluOp::CheckW, AluOp::IsSpecialTryte,
+        ];
+        for op in ops {
+            for reg in 0..8 {
+                let r = Register::from_index(reg).unwrap();
+                let instr = Instruction::AluReg { op: op.clone(), rs1: r, rs2: Register::R0, rd: Register::R7 };
+                assert_eq!(decode(&encode(&instr).unwrap()), Ok(instr));
+            }
+            // Le mnémonique de chaque opération doit redonner la même opération
+            let mnemonic = isa_defs::alu_op_mnemonic(&op).unwrap();
+            assert_eq!(isa_defs::alu_op_from_mnemonic(mnemonic), Some(op));
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_branch_conditions() {
+        for index in 0..12 {
+            let cond = crate::isa::BranchCondition::from_index(index).unwrap();
+            for offset in [-40, -1, 0, 1, 40] {
+                let instr = Instruction::Branch { rs1: Register::R3, cond, offset };
+                assert_eq!(decode(&encode(&instr).unwrap()), Ok(instr));
+            }
+        }
+    }
+}
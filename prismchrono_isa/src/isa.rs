@@ -0,0 +1,459 @@
+//! Définition des instructions de l'ISA PrismChrono
+//!
+//! Ce module contient la représentation décodée des instructions, partagée
+//! par l'encodeur de l'assembleur et le décodeur du simulateur.
+
+use crate::register::{Register, VectorRegister};
+
+/// Représente les différents formats d'instructions sur 12 trits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstructionFormat {
+    /// Format R: opérations registre-registre
+    /// [opcode(3t) | rd(2t) | rs1(2t) | rs2(2t) | func(3t)]
+    R,
+
+    /// Format I: opérations avec immédiat
+    /// [opcode(3t) | rd(2t) | rs1(2t) | immediate(5t)]
+    I,
+
+    /// Format S: opérations de stockage (store)
+    /// [opcode(3t) | src(2t) | base(2t) | offset(5t)]
+    S,
+
+    /// Format B: opérations de branchement
+    /// [opcode(3t) | cond(3t) | rs1(2t) | offset(4t)]
+    B,
+
+    /// Format U: opérations avec immédiat étendu
+    /// [opcode(3t) | rd(2t) | immediate(7t)]
+    U,
+
+    /// Format J: opérations de saut (jump)
+    /// [opcode(3t) | rd(2t) | offset(7t)]
+    J,
+
+    /// Format C: instructions compactes sur 8 trits
+    /// [op(2t) | rd/cond(2t) | rs/offset(4t)]
+    C,
+}
+
+/// Représente les différentes opérations de l'ALU
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AluOp {
+    // Opérations arithmétiques
+    Add, // Addition
+    Sub, // Soustraction
+    Mul, // Multiplication
+    Div, // Division
+    Mod, // Modulo
+
+    // Opérations logiques trit-à-trit
+    TritInv, // Inverseur logique
+    TritMin, // Minimum logique
+    TritMax, // Maximum logique
+    And,  // ET logique
+    Or,   // OU logique
+    Xor,  // OU exclusif
+
+    // Opérations de décalage
+    Shl, // Décalage à gauche
+    Shr, // Décalage à droite
+
+    // Opérations de comparaison
+    Cmp, // Comparaison (met à jour les flags)
+    // Instructions spécialisées ternaires
+    Compare3,       // Comparaison ternaire directe (-1,0,1)
+    Abs,            // Valeur absolue
+    Signum,         // Extraction du signe
+    Clamp,          // Limitation de plage
+    TernaryMux,     // Multiplexeur ternaire
+    TestState,      // Test d'état global
+    IsSpecialTryte, // Test d'un tryte spécial
+    CheckW,         // Validation d'un mot
+    SelectValid,    // Sélection conditionnelle
+    ExtractTryte,   // Extraction d'un tryte
+    InsertTryte,    // Insertion d'un tryte
+    ValidateB24,    // Validation Base 24
+}
+
+/// Représente les différentes conditions de branchement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchCondition {
+    Zero,       // Égal à zéro
+    NonZero,    // Différent de zéro
+    Negative,   // Valeur négative
+    Positive,   // Valeur positive
+    Overflow,   // Dépassement (overflow)
+    Carry,      // Retenue (carry)
+    XS,         // Flag spécial activé (XF=1)
+    XN,         // Flag spécial désactivé (XF=0)
+    True,       // Toujours vrai
+    False,      // Toujours faux
+    Greater,    // Strictement positif (SF=0 et ZF=0)
+    LessEqual,  // Négatif ou nul (SF=1 ou ZF=1)
+}
+
+impl BranchCondition {
+    /// Convertit un index en condition de branchement
+    pub fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(BranchCondition::Zero),
+            1 => Some(BranchCondition::NonZero),
+            2 => Some(BranchCondition::Negative),
+            3 => Some(BranchCondition::Positive),
+            4 => Some(BranchCondition::Overflow),
+            5 => Some(BranchCondition::Carry),
+            6 => Some(BranchCondition::True),
+            7 => Some(BranchCondition::False),
+            8 => Some(BranchCondition::XS),
+            9 => Some(BranchCondition::XN),
+            10 => Some(BranchCondition::Greater),
+            11 => Some(BranchCondition::LessEqual),
+            _ => None,
+        }
+    }
+}
+
+/// Opérations ternaires spécialisées (extension ternaire)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TernaryOp {
+    TMIN,  // Minimum ternaire (par trit)
+    TMAX,  // Maximum ternaire (par trit)
+    TSUM,  // Somme ternaire (par trit)
+    TCMP3, // Comparaison ternaire à 3 états
+}
+
+/// Opérations de rotation et décalage ternaires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TernaryShiftOp {
+    TROTL,   // Rotation ternaire à gauche
+    TROTR,   // Rotation ternaire à droite
+    TSHIFTL, // Décalage ternaire à gauche
+    TSHIFTR, // Décalage ternaire à droite
+}
+
+/// Opérations pour les états spéciaux
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialStateOp {
+    ISNULL,   // Teste si un registre contient NULL
+    ISNAN,    // Teste si un registre contient NaN
+    ISUNDEF,  // Teste si un registre contient UNDEF
+    SETNULL,  // Définit un registre à NULL
+    SETNAN,   // Définit un registre à NaN
+    SETUNDEF, // Définit un registre à UNDEF
+}
+
+/// Opérations arithmétiques en base 24
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base24Op {
+    ADDB24,   // Addition en base 24
+    SUBB24,   // Soustraction en base 24
+    MULB24,   // Multiplication en base 24
+    DIVB24,   // Division en base 24
+    CVTB24,   // Conversion en base 24
+    CVTFRB24, // Conversion depuis la base 24
+}
+
+/// Opérations vectorielles élément par élément de la TVPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorOp {
+    TVADD, // Addition vectorielle
+    TVSUB, // Soustraction vectorielle
+    TVMUL, // Multiplication vectorielle
+    TVMAC, // Multiplication-accumulation (vd = vs1 * vs2 + vd)
+}
+
+/// Réductions d'un registre vectoriel vers un registre général
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorReduceOp {
+    TVSUM, // Somme des éléments
+    TVMIN, // Minimum des éléments
+    TVMAX, // Maximum des éléments
+    TVAVG, // Moyenne des éléments
+}
+
+/// Représente les différentes conditions pour les tests et branchements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Eq,      // Equal
+    Ne,      // Not Equal
+    Lt,      // Less Than
+    Ge,      // Greater or Equal
+    Ltu,     // Less Than (Unsigned)
+    Geu,     // Greater or Equal (Unsigned)
+    Special, // Special condition
+    Always,  // Always true
+}
+
+/// Représente les différents opcodes de l'architecture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Opcode {
+    // Opérations ALU (format R)
+    Alu,
+
+    // Opérations avec immédiat (format I)
+    AluI,
+
+    // Opérations de chargement/stockage (formats I/S)
+    Load,
+    Store,
+
+    // Opérations de branchement (format B)
+    Branch,
+
+    // Opérations de saut (format J)
+    Jump,
+    Call,
+
+    // Opérations avec immédiat supérieur (format U)
+    Lui,   // Load Upper Immediate
+    Auipc, // Add Upper Immediate to PC
+
+    // Opérations CSR (format I)
+    Csr, // Control and Status Register operations
+
+    // Opérations de saut indirect (format I)
+    Jalr, // Jump And Link Register
+
+    // Opérations spéciales
+    System,
+}
+
+/// Représente une instruction décodée
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    // Instructions spéciales
+    Nop,
+    Halt,
+    EBreak,
+
+    // Format R: opérations registre-registre
+    AluReg {
+        op: AluOp,
+        rs1: Register,
+        rs2: Register,
+        rd: Register,
+    },
+
+    // Format I: opérations avec immédiat
+    AluImm {
+        op: AluOp,
+        rs1: Register,
+        rd: Register,
+        imm: i16, // Valeur immédiate (5 trits => -121 à +121)
+    },
+
+    // Format I: opérations de chargement
+    Load {
+        rd: Register,
+        rs1: Register,
+        offset: i16, // Offset d'adresse (5 trits => -121 à +121)
+    },
+
+    // Format I: chargement d'un tryte avec extension de signe
+    LoadT {
+        rd: Register,
+        rs1: Register,
+        offset: i16, // Offset d'adresse (5 trits => -121 à +121)
+    },
+
+    // Format I: chargement d'un tryte sans extension de signe
+    LoadTU {
+        rd: Register,
+        rs1: Register,
+        offset: i16, // Offset d'adresse (5 trits => -121 à +121)
+    },
+
+    // Format S: opérations de stockage
+    Store {
+        rs1: Register, // Adresse de base
+        rs2: Register, // Valeur à stocker
+        offset: i16,   // Offset d'adresse (5 trits => -121 à +121)
+    },
+
+    // Format S: stockage d'un tryte
+    StoreT {
+        rs1: Register, // Adresse de base
+        rs2: Register, // Valeur à stocker (tryte de poids faible)
+        offset: i16,   // Offset d'adresse (5 trits => -121 à +121)
+    },
+
+    // Format B: opérations de branchement
+    Branch {
+        rs1: Register,
+        cond: BranchCondition,
+        offset: i16, // Offset de branchement (4 trits => -40 à +40)
+    },
+
+    // Format J: opérations de saut
+    Jump {
+        rd: Register, // Registre de destination (pour sauvegarder PC+1)
+        offset: i16,  // Offset de saut (7 trits => -1093 à +1093)
+    },
+
+    // Format J: opérations d'appel
+    Call {
+        rd: Register, // Registre de destination (pour sauvegarder PC+1)
+        offset: i16,  // Offset d'appel (7 trits => -1093 à +1093)
+    },
+
+    // Format U: opérations avec immédiat supérieur
+    Lui {
+        rd: Register,
+        imm: i16, // Immédiat 7 trits
+    },
+
+    Auipc {
+        rd: Register,
+        imm: i16, // Immédiat 7 trits
+    },
+
+    // Format I: saut indirect
+    Jalr {
+        rd: Register,
+        rs1: Register,
+        offset: i16, // Offset 5 trits
+    },
+
+    // Opérations CSR
+    CsrRw {
+        rd: Register,
+        csr: i8,
+        rs1: Register,
+    }, // CSR Read & Write
+    CsrRs {
+        rd: Register,
+        csr: i8,
+        rs1: Register,
+    }, // CSR Read & Set
+
+    // Opérations de retour de trap
+    MRet, // Machine Return
+
+    // Format I: opérations système
+    System {
+        func: i8, // Code de fonction système
+    },
+    
+    // Format I: opérations de registres de contrôle/statut
+    Csr {
+        csr: i8,     // Numéro du registre CSR
+        rs1: Register, // Registre source
+        offset: i16, // Valeur immédiate (4 trits => -40 à +40)
+    },
+    CsrRc {
+        rd: Register,
+        rs1: Register,
+        csr: u8,     // Adresse du registre CSR (0-13)
+    },
+
+    // Extension ternaire, format R: opérations trit à trit
+    TernaryOp {
+        op: TernaryOp,
+        rs1: Register,
+        rs2: Register,
+        rd: Register,
+    },
+
+    // Extension ternaire: rotations et décalages par immédiat
+    TernaryShift {
+        op: TernaryShiftOp,
+        rs1: Register,
+        rd: Register,
+        imm: i16, // Nombre de trits (3 trits => -13 à +13)
+    },
+
+    // Extension ternaire: branchement à 3 voies selon le signe de rs1
+    // Les offsets sont en instructions, relatifs à l'adresse du BRANCH3
+    Branch3 {
+        rs1: Register,
+        offset_neg: i16,
+        offset_zero: i16,
+        offset_pos: i16,
+    },
+
+    // Extension ternaire: chargement/stockage de 3 trytes
+    LoadT3 {
+        rd: Register,
+        rs1: Register,
+        offset: i16, // Offset en trytes (4 trits => -40 à +40)
+    },
+    StoreT3 {
+        rs1: Register, // Adresse de base
+        rs2: Register, // Valeur à stocker
+        offset: i16,   // Offset en trytes (4 trits => -40 à +40)
+    },
+
+    // Extension ternaire: manipulation mémoire (rd = destination, rs2 = taille)
+    TMemCpy {
+        rd: Register,
+        rs1: Register,
+        rs2: Register,
+    },
+    TMemSet {
+        rd: Register,
+        rs1: Register,
+        rs2: Register,
+    },
+
+    // Extension ternaire, format R4: opérations à 3 sources
+    MAddW {
+        rd: Register,
+        rs1: Register,
+        rs2: Register,
+        rs3: Register,
+    },
+    MSubW {
+        rd: Register,
+        rs1: Register,
+        rs2: Register,
+        rs3: Register,
+    },
+    TSel {
+        rd: Register,
+        rs1: Register, // Sélecteur
+        rs2: Register,
+        rs3: Register,
+    },
+
+    // Extension ternaire: tests et affectations d'états spéciaux
+    SpecialStateOp {
+        op: SpecialStateOp,
+        rs1: Register,
+        rd: Register,
+    },
+
+    // Extension ternaire: arithmétique en base 24
+    Base24Op {
+        op: Base24Op,
+        rs1: Register,
+        rs2: Register,
+        rd: Register,
+    },
+
+    // Extension vectorielle (TVPU): 8 registres V0-V7 de 8 mots
+    VectorOp {
+        op: VectorOp,
+        vd: VectorRegister,
+        vs1: VectorRegister,
+        vs2: VectorRegister,
+    },
+    VectorDot {
+        rd: Register,
+        vs1: VectorRegister,
+        vs2: VectorRegister,
+    },
+    VectorReduce {
+        op: VectorReduceOp,
+        rd: Register,
+        vs: VectorRegister,
+    },
+    VectorLoad {
+        vd: VectorRegister,
+        rs1: Register, // Adresse du premier des 8 mots consécutifs
+    },
+    VectorStore {
+        vs: VectorRegister,
+        rs1: Register, // Adresse du premier des 8 mots consécutifs
+    },
+}
@@ -0,0 +1,765 @@
+//! Tables de l'ISA PrismChrono
+//!
+//! Ce module contient les constantes de l'ISA (opcodes, fonctions, conditions,
+//! codes CSR, limites des immédiats) utilisées à la fois par l'encodeur de
+//! l'assembleur et par le décodeur du simulateur.
+//!
+//! Convention: dans chaque champ, les trits sont rangés du poids faible au poids
+//! fort (`[t0, t1, t2]`, valeur = t0 + 3*t1 + 9*t2). Les commentaires donnent les
+//! trits dans cet ordre, suivis de la valeur ternaire équilibrée du champ.
+
+use crate::isa::{
+    AluOp, Base24Op, BranchCondition, SpecialStateOp, TernaryOp, TernaryShiftOp, VectorOp, VectorReduceOp,
+};
+use crate::types::Trit;
+
+/// Taille d'une instruction standard en trits
+pub const INSTRUCTION_SIZE_TRITS: usize = 12;
+
+/// Taille d'une instruction compacte en trits
+pub const COMPACT_INSTRUCTION_SIZE_TRITS: usize = 8;
+
+/// Taille d'une instruction en octets (pour le calcul des adresses)
+pub const INSTRUCTION_SIZE_BYTES: u32 = 4;
+
+/// Taille d'une instruction compacte en octets
+pub const COMPACT_INSTRUCTION_SIZE_BYTES: u32 = 3;
+
+/// OpCodes pour les différentes instructions (trits 0-2)
+pub mod opcode {
+    use crate::types::Trit;
+
+    // OpCodes pour les instructions de base
+    pub const NOP: [Trit; 3] = [Trit::Z, Trit::Z, Trit::Z]; // 000 (0)
+    pub const HALT: [Trit; 3] = [Trit::Z, Trit::Z, Trit::P]; // 00+ (9)
+
+    // Format R (ALU)
+    pub const R_TYPE: [Trit; 3] = [Trit::Z, Trit::P, Trit::Z]; // 0+0 (3)
+
+    // Format I (ALU avec immédiat, l'opération est donnée par l'opcode)
+    pub const ADDI: [Trit; 3] = [Trit::Z, Trit::P, Trit::N]; // 0+- (-6)
+    pub const SUBI: [Trit; 3] = [Trit::Z, Trit::P, Trit::P]; // 0++ (12)
+    pub const SLTI: [Trit; 3] = [Trit::P, Trit::Z, Trit::P]; // +0+ (10, réservé)
+    pub const MINI: [Trit; 3] = [Trit::P, Trit::P, Trit::Z]; // ++0 (4)
+    pub const MAXI: [Trit; 3] = [Trit::P, Trit::P, Trit::P]; // +++ (13)
+
+    // Format I (Loads)
+    pub const LOADW: [Trit; 3] = [Trit::N, Trit::Z, Trit::Z]; // -00 (-1)
+    pub const LOADT: [Trit; 3] = [Trit::N, Trit::Z, Trit::P]; // -0+ (8)
+    pub const LOADTU: [Trit; 3] = [Trit::N, Trit::P, Trit::Z]; // -+0 (2)
+
+    // Format I (Jump and Link Register)
+    pub const JALR: [Trit; 3] = [Trit::P, Trit::N, Trit::P]; // +-+ (7)
+
+    // Format S (Stores)
+    pub const STOREW: [Trit; 3] = [Trit::N, Trit::P, Trit::N]; // -+- (-7)
+    pub const STORET: [Trit; 3] = [Trit::N, Trit::P, Trit::P]; // -++ (11)
+
+    // Format B (Branches)
+    pub const BRANCH: [Trit; 3] = [Trit::N, Trit::N, Trit::Z]; // --0 (-4)
+
+    // Format U
+    pub const LUI: [Trit; 3] = [Trit::P, Trit::Z, Trit::N]; // +0- (-8)
+    pub const AUIPC: [Trit; 3] = [Trit::P, Trit::Z, Trit::Z]; // +00 (1)
+
+    // Format J
+    pub const JAL: [Trit; 3] = [Trit::P, Trit::N, Trit::N]; // +-- (-11)
+    pub const CALL: [Trit; 3] = [Trit::N, Trit::Z, Trit::N]; // -0- (-10)
+
+    // System & CSR
+    pub const SYSTEM: [Trit; 3] = [Trit::N, Trit::N, Trit::N]; // --- (-13)
+    pub const CSR: [Trit; 3] = [Trit::N, Trit::N, Trit::P]; // --+ (5)
+    pub const CSRI: [Trit; 3] = [Trit::Z, Trit::N, Trit::N]; // 0-- (-12)
+
+    // Extension ternaire (voir les modules ternary_func, tshift_func, r4_func et mem_t3_func)
+    pub const TERNARY: [Trit; 3] = [Trit::Z, Trit::Z, Trit::N]; // 00- (-9, format R)
+    pub const TSHIFT: [Trit; 3] = [Trit::P, Trit::P, Trit::N]; // ++- (-5)
+    pub const BRANCH3: [Trit; 3] = [Trit::Z, Trit::N, Trit::Z]; // 0-0 (-3)
+    pub const R4_TYPE: [Trit; 3] = [Trit::P, Trit::N, Trit::Z]; // +-0 (-2)
+    pub const MEM_T3: [Trit; 3] = [Trit::Z, Trit::N, Trit::P]; // 0-+ (6)
+
+    // Format C (Compact)
+    // Ces opcodes sont sur 2 trits au lieu de 3 (valeur = 3*op[0] + op[1])
+    pub mod compact {
+        use crate::types::Trit;
+
+        pub const CMOV: [Trit; 2] = [Trit::N, Trit::N]; // NN (-4)
+        pub const CADD: [Trit; 2] = [Trit::N, Trit::Z]; // NZ (-3)
+        pub const CSUB: [Trit; 2] = [Trit::N, Trit::P]; // NP (-2)
+        pub const CBRANCH: [Trit; 2] = [Trit::Z, Trit::N]; // ZN (-1)
+    }
+}
+
+/// Fonctions pour les instructions de format R (trits 9-11)
+pub mod func {
+    use crate::types::Trit;
+
+    // Fonctions arithmétiques et logiques de base
+    pub const ADD: [Trit; 3] = [Trit::Z, Trit::Z, Trit::Z]; // 000 (0)
+    pub const SUB: [Trit; 3] = [Trit::Z, Trit::Z, Trit::P]; // 00+ (9)
+    pub const AND: [Trit; 3] = [Trit::Z, Trit::P, Trit::Z]; // 0+0 (3)
+    pub const OR: [Trit; 3] = [Trit::Z, Trit::P, Trit::P]; // 0++ (12)
+    pub const XOR: [Trit; 3] = [Trit::P, Trit::Z, Trit::Z]; // +00 (1)
+    pub const MIN: [Trit; 3] = [Trit::P, Trit::Z, Trit::P]; // +0+ (10)
+    pub const MAX: [Trit; 3] = [Trit::P, Trit::P, Trit::Z]; // ++0 (4)
+    pub const SLT: [Trit; 3] = [Trit::P, Trit::P, Trit::P]; // +++ (13, réservé)
+    pub const INV: [Trit; 3] = [Trit::N, Trit::Z, Trit::Z]; // -00 (-1)
+    pub const SLL: [Trit; 3] = [Trit::N, Trit::Z, Trit::P]; // -0+ (8, Shift Left Logical)
+    pub const SRL: [Trit; 3] = [Trit::N, Trit::P, Trit::Z]; // -+0 (2, Shift Right Logical)
+    pub const SRA: [Trit; 3] = [Trit::N, Trit::P, Trit::P]; // -++ (11, Shift Right Arithmetic, réservé)
+
+    // Multiplication, division et comparaison
+    pub const MUL: [Trit; 3] = [Trit::N, Trit::N, Trit::N]; // --- (-13)
+    pub const DIV: [Trit; 3] = [Trit::Z, Trit::N, Trit::N]; // 0-- (-12)
+    pub const MOD: [Trit; 3] = [Trit::P, Trit::N, Trit::N]; // +-- (-11)
+    pub const CMP: [Trit; 3] = [Trit::N, Trit::Z, Trit::N]; // -0- (-10)
+
+    // Instructions ternaires spécialisées
+    pub const TCMP3: [Trit; 3] = [Trit::Z, Trit::Z, Trit::N]; // 00- (-9)
+    pub const ABS_T: [Trit; 3] = [Trit::P, Trit::Z, Trit::N]; // +0- (-8)
+    pub const SIGNUM_T: [Trit; 3] = [Trit::N, Trit::P, Trit::N]; // -+- (-7)
+    pub const EXTRACT_TRYTE: [Trit; 3] = [Trit::Z, Trit::P, Trit::N]; // 0+- (-6)
+    pub const INSERT_TRYTE: [Trit; 3] = [Trit::P, Trit::P, Trit::N]; // ++- (-5)
+    pub const CHECKW_VALID: [Trit; 3] = [Trit::N, Trit::N, Trit::Z]; // --0 (-4)
+    pub const IS_SPECIAL_TRYTE: [Trit; 3] = [Trit::Z, Trit::N, Trit::Z]; // 0-0 (-3)
+}
+
+/// Fonctions de l'opcode TERNARY (format R, trits 9-11)
+pub mod ternary_func {
+    use crate::types::Trit;
+
+    // Opérations trit à trit
+    pub const TMIN: [Trit; 3] = [Trit::P, Trit::Z, Trit::Z]; // +00 (1)
+    pub const TMAX: [Trit; 3] = [Trit::N, Trit::P, Trit::Z]; // -+0 (2)
+    pub const TSUM: [Trit; 3] = [Trit::Z, Trit::P, Trit::Z]; // 0+0 (3)
+    pub const TCMP3: [Trit; 3] = [Trit::P, Trit::P, Trit::Z]; // ++0 (4)
+
+    // Arithmétique en base 24
+    pub const ADDB24: [Trit; 3] = [Trit::N, Trit::N, Trit::P]; // --+ (5)
+    pub const SUBB24: [Trit; 3] = [Trit::Z, Trit::N, Trit::P]; // 0-+ (6)
+    pub const MULB24: [Trit; 3] = [Trit::P, Trit::N, Trit::P]; // +-+ (7)
+    pub const DIVB24: [Trit; 3] = [Trit::N, Trit::Z, Trit::P]; // -0+ (8)
+    pub const CVTB24: [Trit; 3] = [Trit::Z, Trit::Z, Trit::P]; // 00+ (9)
+    pub const CVTFRB24: [Trit; 3] = [Trit::P, Trit::Z, Trit::P]; // +0+ (10)
+
+    // Manipulation mémoire
+    pub const TMEMCPY: [Trit; 3] = [Trit::N, Trit::P, Trit::P]; // -++ (11)
+    pub const TMEMSET: [Trit; 3] = [Trit::Z, Trit::P, Trit::P]; // 0++ (12)
+
+    // États spéciaux (le champ rs2 est ignoré)
+    pub const ISNULL: [Trit; 3] = [Trit::N, Trit::Z, Trit::Z]; // -00 (-1)
+    pub const ISNAN: [Trit; 3] = [Trit::P, Trit::N, Trit::Z]; // +-0 (-2)
+    pub const ISUNDEF: [Trit; 3] = [Trit::Z, Trit::N, Trit::Z]; // 0-0 (-3)
+    pub const SETNULL: [Trit; 3] = [Trit::N, Trit::N, Trit::Z]; // --0 (-4)
+    pub const SETNAN: [Trit; 3] = [Trit::P, Trit::P, Trit::N]; // ++- (-5)
+    pub const SETUNDEF: [Trit; 3] = [Trit::Z, Trit::P, Trit::N]; // 0+- (-6)
+
+    // Extension vectorielle (TVPU): les champs rd/rs1/rs2 désignent des registres V
+    pub const TVADD: [Trit; 3] = [Trit::N, Trit::P, Trit::N]; // -+- (-7)
+    pub const TVSUB: [Trit; 3] = [Trit::P, Trit::Z, Trit::N]; // +0- (-8)
+    pub const TVMUL: [Trit; 3] = [Trit::Z, Trit::Z, Trit::N]; // 00- (-9)
+    pub const TVMAC: [Trit; 3] = [Trit::N, Trit::Z, Trit::N]; // -0- (-10)
+    pub const TVDOT: [Trit; 3] = [Trit::P, Trit::N, Trit::N]; // +-- (-11), rd est un registre R
+    pub const TVRED: [Trit; 3] = [Trit::Z, Trit::N, Trit::N]; // 0-- (-12), réduction choisie par vreduce_func
+    pub const TVMEM: [Trit; 3] = [Trit::N, Trit::N, Trit::N]; // --- (-13), accès choisi par vmem_func
+}
+
+/// Réductions vectorielles (opcode TERNARY, func TVRED, champ rs2 aux trits 7-8)
+/// [opcode(3t) | rd(2t) | vs(2t) | func(2t) | TVRED(3t)]
+pub mod vreduce_func {
+    use crate::types::Trit;
+
+    pub const TVSUM: [Trit; 2] = [Trit::Z, Trit::Z]; // 00 (0)
+    pub const TVMIN: [Trit; 2] = [Trit::N, Trit::Z]; // -0 (-1)
+    pub const TVMAX: [Trit; 2] = [Trit::P, Trit::Z]; // +0 (1)
+    pub const TVAVG: [Trit; 2] = [Trit::N, Trit::P]; // -+ (2)
+}
+
+/// Accès mémoire vectoriels (opcode TERNARY, func TVMEM, champ rs2 aux trits 7-8)
+/// [opcode(3t) | vd/vs(2t) | rs1(2t) | func(2t) | TVMEM(3t)]
+pub mod vmem_func {
+    use crate::types::Trit;
+
+    pub const TVLOAD: [Trit; 2] = [Trit::N, Trit::Z]; // -0 (-1)
+    pub const TVSTORE: [Trit; 2] = [Trit::P, Trit::Z]; // +0 (1)
+}
+
+/// Fonctions de l'opcode TSHIFT (trits 7-8)
+/// [opcode(3t) | rd(2t) | rs1(2t) | func(2t) | imm(3t)]
+pub mod tshift_func {
+    use crate::types::Trit;
+
+    pub const TROTL: [Trit; 2] = [Trit::P, Trit::Z]; // +0 (1)
+    pub const TROTR: [Trit; 2] = [Trit::N, Trit::Z]; // -0 (-1)
+    pub const TSHIFTL: [Trit; 2] = [Trit::N, Trit::P]; // -+ (2)
+    pub const TSHIFTR: [Trit; 2] = [Trit::P, Trit::N]; // +- (-2)
+}
+
+/// Fonctions de l'opcode R4_TYPE (trit 11)
+/// [opcode(3t) | rd(2t) | rs1(2t) | rs2(2t) | rs3(2t) | func(1t)]
+pub mod r4_func {
+    use crate::types::Trit;
+
+    pub const TSEL: Trit = Trit::Z;
+    pub const MADDW: Trit = Trit::P;
+    pub const MSUBW: Trit = Trit::N;
+}
+
+/// Fonctions de l'opcode MEM_T3 (trit 7)
+/// [opcode(3t) | rd/rs2(2t) | rs1(2t) | func(1t) | offset(4t)]
+pub mod mem_t3_func {
+    use crate::types::Trit;
+
+    pub const LOADT3: Trit = Trit::N;
+    pub const STORET3: Trit = Trit::P;
+}
+
+/// Conditions pour les instructions de branchement (trits 3-5)
+pub mod cond {
+    use crate::types::Trit;
+
+    pub const EQ: [Trit; 3] = [Trit::Z, Trit::Z, Trit::Z]; // 000 (Equal, ZF=1)
+    pub const NE: [Trit; 3] = [Trit::Z, Trit::Z, Trit::P]; // 00+ (Not Equal, ZF=0)
+    pub const LT: [Trit; 3] = [Trit::Z, Trit::P, Trit::Z]; // 0+0 (Less Than, SF=1)
+    pub const GE: [Trit; 3] = [Trit::Z, Trit::P, Trit::P]; // 0++ (Greater or Equal, SF=0)
+    pub const GT: [Trit; 3] = [Trit::P, Trit::Z, Trit::Z]; // +00 (Greater Than)
+    pub const LE: [Trit; 3] = [Trit::P, Trit::Z, Trit::P]; // +0+ (Less or Equal)
+    pub const OF: [Trit; 3] = [Trit::P, Trit::P, Trit::Z]; // ++0 (Overflow, OF=1)
+    pub const CF: [Trit; 3] = [Trit::P, Trit::P, Trit::P]; // +++ (Carry, CF=1)
+    pub const XS: [Trit; 3] = [Trit::N, Trit::Z, Trit::Z]; // -00 (État spécial, XF=1)
+    pub const XN: [Trit; 3] = [Trit::N, Trit::Z, Trit::P]; // -0+ (État normal, XF=0)
+    pub const AL: [Trit; 3] = [Trit::N, Trit::P, Trit::Z]; // -+0 (Toujours)
+    pub const NV: [Trit; 3] = [Trit::N, Trit::P, Trit::P]; // -++ (Jamais)
+}
+
+/// Fonctions pour les instructions système (trits 3-5, valeur ternaire équilibrée)
+pub mod system_func {
+    pub const HALT: i8 = 0;
+    pub const NOP: i8 = 1;
+    pub const ECALL: i8 = 2;
+    pub const EBREAK: i8 = 3;
+    pub const MRET_T: i8 = 4;
+    pub const SRET_T: i8 = 5;
+}
+
+/// Fonctions pour les instructions CSR (trits 10-11)
+pub mod csr_func {
+    use crate::types::Trit;
+
+    pub const CSRRW_T: [Trit; 2] = [Trit::Z, Trit::Z]; // 00 (0, CSR Read & Write)
+    pub const CSRRS_T: [Trit; 2] = [Trit::P, Trit::Z]; // +0 (1, CSR Read & Set)
+    pub const CSRRC_T: [Trit; 2] = [Trit::N, Trit::Z]; // -0 (-1, CSR Read & Clear)
+}
+
+/// Codes CSR (champ de 3 trits, numéro du registre de contrôle)
+pub mod csr {
+    // CSRs du mode Machine
+    pub const MSTATUS_T: i8 = 0;
+    pub const MTVEC_T: i8 = 1;
+    pub const MEPC_T: i8 = 2;
+    pub const MCAUSE_T: i8 = 3;
+
+    // CSRs du mode Supervisor
+    pub const SSTATUS_T: i8 = 4;
+    pub const STVEC_T: i8 = 5;
+    pub const SEPC_T: i8 = 6;
+    pub const SCAUSE_T: i8 = 7;
+
+    // Registres de délégation
+    pub const MEDELEG_T: i8 = 8;
+    pub const MIDELEG_T: i8 = 9;
+
+    // Registres d'interruption
+    pub const MIE_T: i8 = 10;
+    pub const MIP_T: i8 = 11;
+
+    // Traduction d'adresses (MMU)
+    pub const SATP_T: i8 = 12;
+}
+
+/// Limites pour les valeurs immédiates selon le format d'instruction
+pub mod imm_limits {
+    // Format I/S: 5 trits signés (-121 à +121)
+    pub const I_MIN: i32 = -121;
+    pub const I_MAX: i32 = 121;
+
+    // Format U: 7 trits signés (-1093 à +1093)
+    pub const U_MIN: i32 = -1093;
+    pub const U_MAX: i32 = 1093;
+
+    // LUI/AUIPC: l'immédiat U est décalé de la largeur d'un immédiat I (5 trits),
+    // de sorte que LUI + ADDI construisent toute constante de 12 trits (-265720 à +265720)
+    pub const U_SHIFT_TRITS: u32 = 5;
+    pub const LUI_ADDI_MIN: i32 = -265720;
+    pub const LUI_ADDI_MAX: i32 = 265720;
+
+    // Format J: 7 trits signés pour offset (-1093 à +1093)
+    pub const J_MIN: i32 = -1093;
+    pub const J_MAX: i32 = 1093;
+
+    // Format B: 4 trits signés pour offset (-40 à +40)
+    pub const B_MIN: i32 = -40;
+    pub const B_MAX: i32 = 40;
+
+    // CSR avec immédiat: 4 trits signés (-40 à +40)
+    pub const CSR_IMM_MIN: i32 = -40;
+    pub const CSR_IMM_MAX: i32 = 40;
+
+    // Format C: 4 trits signés pour offset (-40 à +40)
+    pub const C_OFFSET_MIN: i32 = -40;
+    pub const C_OFFSET_MAX: i32 = 40;
+
+    // Format C: 4 trits pour registre (0 à 7)
+    pub const C_REG_MIN: i32 = 0;
+    pub const C_REG_MAX: i32 = 7;
+
+    // TSHIFT: 3 trits signés pour le nombre de trits (-13 à +13)
+    pub const TSHIFT_MIN: i32 = -13;
+    pub const TSHIFT_MAX: i32 = 13;
+
+    // MEM_T3: 4 trits signés pour l'offset en trytes (-40 à +40)
+    pub const T3_OFFSET_MIN: i32 = -40;
+    pub const T3_OFFSET_MAX: i32 = 40;
+
+    // BRANCH3: les trois cibles sont encodées en écarts successifs
+    // cible négative - PC (2 trits), cible nulle - cible négative (2 trits),
+    // cible positive - cible nulle (3 trits)
+    pub const BRANCH3_STEP_MIN: i32 = -4;
+    pub const BRANCH3_STEP_MAX: i32 = 4;
+    pub const BRANCH3_LAST_STEP_MIN: i32 = -13;
+    pub const BRANCH3_LAST_STEP_MAX: i32 = 13;
+}
+
+/// Découpe une valeur en parties haute (immédiat de LUI) et basse (immédiat de ADDI)
+/// telles que `haute × 3^U_SHIFT_TRITS + basse = valeur`, la partie basse étant
+/// ternaire équilibrée sur 5 trits. Retourne None si la valeur dépasse 12 trits.
+pub fn split_upper_immediate(value: i64) -> Option<(i32, i32)> {
+    if !(imm_limits::LUI_ADDI_MIN as i64..=imm_limits::LUI_ADDI_MAX as i64).contains(&value) {
+        return None;
+    }
+    let value = value as i32;
+    let scale = 3i32.pow(imm_limits::U_SHIFT_TRITS);
+    let low = (value + imm_limits::I_MAX).rem_euclid(scale) - imm_limits::I_MAX;
+    Some(((value - low) / scale, low))
+}
+
+/// Retourne le code de fonction (format R) d'une opération ALU
+/// Les opérations sans encodage en format R retournent None
+pub fn alu_op_to_func(op: &AluOp) -> Option<[Trit; 3]> {
+    match op {
+        AluOp::Add => Some(func::ADD),
+        AluOp::Sub => Some(func::SUB),
+        AluOp::Mul => Some(func::MUL),
+        AluOp::Div => Some(func::DIV),
+        AluOp::Mod => Some(func::MOD),
+        AluOp::TritInv => Some(func::INV),
+        AluOp::TritMin => Some(func::MIN),
+        AluOp::TritMax => Some(func::MAX),
+        AluOp::And => Some(func::AND),
+        AluOp::Or => Some(func::OR),
+        AluOp::Xor => Some(func::XOR),
+        AluOp::Shl => Some(func::SLL),
+        AluOp::Shr => Some(func::SRL),
+        AluOp::Cmp => Some(func::CMP),
+        AluOp::Compare3 => Some(func::TCMP3),
+        AluOp::Abs => Some(func::ABS_T),
+        AluOp::Signum => Some(func::SIGNUM_T),
+        AluOp::ExtractTryte => Some(func::EXTRACT_TRYTE),
+        AluOp::InsertTryte => Some(func::INSERT_TRYTE),
+        AluOp::CheckW => Some(func::CHECKW_VALID),
+        AluOp::IsSpecialTryte => Some(func::IS_SPECIAL_TRYTE),
+        _ => None,
+    }
+}
+
+/// Retourne l'opération ALU correspondant à un code de fonction (format R)
+pub fn func_to_alu_op(func: [Trit; 3]) -> Option<AluOp> {
+    match func {
+        func::ADD => Some(AluOp::Add),
+        func::SUB => Some(AluOp::Sub),
+        func::MUL => Some(AluOp::Mul),
+        func::DIV => Some(AluOp::Div),
+        func::MOD => Some(AluOp::Mod),
+        func::INV => Some(AluOp::TritInv),
+        func::MIN => Some(AluOp::TritMin),
+        func::MAX => Some(AluOp::TritMax),
+        func::AND => Some(AluOp::And),
+        func::OR => Some(AluOp::Or),
+        func::XOR => Some(AluOp::Xor),
+        func::SLL => Some(AluOp::Shl),
+        func::SRL => Some(AluOp::Shr),
+        func::CMP => Some(AluOp::Cmp),
+        func::TCMP3 => Some(AluOp::Compare3),
+        func::ABS_T => Some(AluOp::Abs),
+        func::SIGNUM_T => Some(AluOp::Signum),
+        func::EXTRACT_TRYTE => Some(AluOp::ExtractTryte),
+        func::INSERT_TRYTE => Some(AluOp::InsertTryte),
+        func::CHECKW_VALID => Some(AluOp::CheckW),
+        func::IS_SPECIAL_TRYTE => Some(AluOp::IsSpecialTryte),
+        _ => None, // Code réservé
+    }
+}
+
+/// Retourne le mnémonique assembleur d'une opération ALU registre-registre (format R)
+///
+/// `Compare3` s'écrit `CMP3`: `TCMP3` désigne l'opération de l'extension ternaire.
+pub fn alu_op_mnemonic(op: &AluOp) -> Option<&'static str> {
+    match op {
+        AluOp::Add => Some("ADD"),
+        AluOp::Sub => Some("SUB"),
+        AluOp::Mul => Some("MUL"),
+        AluOp::Div => Some("DIV"),
+        AluOp::Mod => Some("MOD"),
+        AluOp::TritInv => Some("TRITINV"),
+        AluOp::TritMin => Some("TRITMIN"),
+        AluOp::TritMax => Some("TRITMAX"),
+        AluOp::And => Some("AND"),
+        AluOp::Or => Some("OR"),
+        AluOp::Xor => Some("XOR"),
+        AluOp::Shl => Some("SHL"),
+        AluOp::Shr => Some("SHR"),
+        AluOp::Cmp => Some("CMP"),
+        AluOp::Compare3 => Some("CMP3"),
+        AluOp::Abs => Some("ABS_T"),
+        AluOp::Signum => Some("SIGNUM_T"),
+        AluOp::ExtractTryte => Some("EXTRACT_TRYTE"),
+        AluOp::InsertTryte => Some("INSERT_TRYTE"),
+        AluOp::CheckW => Some("CHECKW_VALID"),
+        AluOp::IsSpecialTryte => Some("IS_SPECIAL_TRYTE"),
+        _ => None,
+    }
+}
+
+/// Retourne l'opération ALU registre-registre correspondant à un mnémonique
+pub fn alu_op_from_mnemonic(mnemonic: &str) -> Option<AluOp> {
+    match mnemonic {
+        "ADD" => Some(AluOp::Add),
+        "SUB" => Some(AluOp::Sub),
+        "MUL" => Some(AluOp::Mul),
+        "DIV" => Some(AluOp::Div),
+        "MOD" => Some(AluOp::Mod),
+        "TRITINV" => Some(AluOp::TritInv),
+        "TRITMIN" => Some(AluOp::TritMin),
+        "TRITMAX" => Some(AluOp::TritMax),
+        "AND" => Some(AluOp::And),
+        "OR" => Some(AluOp::Or),
+        "XOR" => Some(AluOp::Xor),
+        "SHL" => Some(AluOp::Shl),
+        "SHR" => Some(AluOp::Shr),
+        "CMP" => Some(AluOp::Cmp),
+        "CMP3" => Some(AluOp::Compare3),
+        "ABS_T" => Some(AluOp::Abs),
+        "SIGNUM_T" => Some(AluOp::Signum),
+        "EXTRACT_TRYTE" => Some(AluOp::ExtractTryte),
+        "INSERT_TRYTE" => Some(AluOp::InsertTryte),
+        "CHECKW_VALID" => Some(AluOp::CheckW),
+        "IS_SPECIAL_TRYTE" => Some(AluOp::IsSpecialTryte),
+        _ => None,
+    }
+}
+
+/// Retourne l'opération ALU avec immédiat correspondant à un mnémonique
+pub fn alu_imm_from_mnemonic(mnemonic: &str) -> Option<AluOp> {
+    match mnemonic {
+        "ADDI" => Some(AluOp::Add),
+        "SUBI" => Some(AluOp::Sub),
+        "MINI" => Some(AluOp::TritMin),
+        "MAXI" => Some(AluOp::TritMax),
+        _ => None,
+    }
+}
+
+/// Retourne le mnémonique assembleur d'une opération ALU avec immédiat (format I)
+pub fn alu_imm_mnemonic(op: &AluOp) -> Option<&'static str> {
+    match op {
+        AluOp::Add => Some("ADDI"),
+        AluOp::Sub => Some("SUBI"),
+        AluOp::TritMin => Some("MINI"),
+        AluOp::TritMax => Some("MAXI"),
+        _ => None,
+    }
+}
+
+/// Retourne l'opcode (format I) d'une opération ALU avec immédiat
+pub fn alu_op_to_imm_opcode(op: &AluOp) -> Option<[Trit; 3]> {
+    match op {
+        AluOp::Add => Some(opcode::ADDI),
+        AluOp::Sub => Some(opcode::SUBI),
+        AluOp::TritMin => Some(opcode::MINI),
+        AluOp::TritMax => Some(opcode::MAXI),
+        _ => None,
+    }
+}
+
+/// Retourne l'opération ALU correspondant à un opcode de format I
+pub fn imm_opcode_to_alu_op(op: [Trit; 3]) -> Option<AluOp> {
+    match op {
+        opcode::ADDI => Some(AluOp::Add),
+        opcode::SUBI => Some(AluOp::Sub),
+        opcode::MINI => Some(AluOp::TritMin),
+        opcode::MAXI => Some(AluOp::TritMax),
+        _ => None,
+    }
+}
+
+/// Retourne le code de fonction (opcode TERNARY) d'une opération ternaire
+pub fn ternary_op_to_func(op: TernaryOp) -> [Trit; 3] {
+    match op {
+        TernaryOp::TMIN => ternary_func::TMIN,
+        TernaryOp::TMAX => ternary_func::TMAX,
+        TernaryOp::TSUM => ternary_func::TSUM,
+        TernaryOp::TCMP3 => ternary_func::TCMP3,
+    }
+}
+
+/// Retourne l'opération ternaire correspondant à un code de fonction
+pub fn func_to_ternary_op(func: [Trit; 3]) -> Option<TernaryOp> {
+    match func {
+        ternary_func::TMIN => Some(TernaryOp::TMIN),
+        ternary_func::TMAX => Some(TernaryOp::TMAX),
+        ternary_func::TSUM => Some(TernaryOp::TSUM),
+        ternary_func::TCMP3 => Some(TernaryOp::TCMP3),
+        _ => None,
+    }
+}
+
+/// Retourne le code de fonction (opcode TERNARY) d'une opération en base 24
+pub fn base24_op_to_func(op: Base24Op) -> [Trit; 3] {
+    match op {
+        Base24Op::ADDB24 => ternary_func::ADDB24,
+        Base24Op::SUBB24 => ternary_func::SUBB24,
+        Base24Op::MULB24 => ternary_func::MULB24,
+        Base24Op::DIVB24 => ternary_func::DIVB24,
+        Base24Op::CVTB24 => ternary_func::CVTB24,
+        Base24Op::CVTFRB24 => ternary_func::CVTFRB24,
+    }
+}
+
+/// Retourne l'opération en base 24 correspondant à un code de fonction
+pub fn func_to_base24_op(func: [Trit; 3]) -> Option<Base24Op> {
+    match func {
+        ternary_func::ADDB24 => Some(Base24Op::ADDB24),
+        ternary_func::SUBB24 => Some(Base24Op::SUBB24),
+        ternary_func::MULB24 => Some(Base24Op::MULB24),
+        ternary_func::DIVB24 => Some(Base24Op::DIVB24),
+        ternary_func::CVTB24 => Some(Base24Op::CVTB24),
+        ternary_func::CVTFRB24 => Some(Base24Op::CVTFRB24),
+        _ => None,
+    }
+}
+
+/// Retourne le code de fonction (opcode TERNARY) d'une opération sur les états spéciaux
+pub fn special_state_op_to_func(op: SpecialStateOp) -> [Trit; 3] {
+    match op {
+        SpecialStateOp::ISNULL => ternary_func::ISNULL,
+        SpecialStateOp::ISNAN => ternary_func::ISNAN,
+        SpecialStateOp::ISUNDEF => ternary_func::ISUNDEF,
+        SpecialStateOp::SETNULL => ternary_func::SETNULL,
+        SpecialStateOp::SETNAN => ternary_func::SETNAN,
+        SpecialStateOp::SETUNDEF => ternary_func::SETUNDEF,
+    }
+}
+
+/// Retourne l'opération sur les états spéciaux correspondant à un code de fonction
+pub fn func_to_special_state_op(func: [Trit; 3]) -> Option<SpecialStateOp> {
+    match func {
+        ternary_func::ISNULL => Some(SpecialStateOp::ISNULL),
+        ternary_func::ISNAN => Some(SpecialStateOp::ISNAN),
+        ternary_func::ISUNDEF => Some(SpecialStateOp::ISUNDEF),
+        ternary_func::SETNULL => Some(SpecialStateOp::SETNULL),
+        ternary_func::SETNAN => Some(SpecialStateOp::SETNAN),
+        ternary_func::SETUNDEF => Some(SpecialStateOp::SETUNDEF),
+        _ => None,
+    }
+}
+
+/// Retourne le code de fonction (opcode TERNARY) d'une opération vectorielle
+pub fn vector_op_to_func(op: VectorOp) -> [Trit; 3] {
+    match op {
+        VectorOp::TVADD => ternary_func::TVADD,
+        VectorOp::TVSUB => ternary_func::TVSUB,
+        VectorOp::TVMUL => ternary_func::TVMUL,
+        VectorOp::TVMAC => ternary_func::TVMAC,
+    }
+}
+
+/// Retourne l'opération vectorielle correspondant à un code de fonction
+pub fn func_to_vector_op(func: [Trit; 3]) -> Option<VectorOp> {
+    match func {
+        ternary_func::TVADD => Some(VectorOp::TVADD),
+        ternary_func::TVSUB => Some(VectorOp::TVSUB),
+        ternary_func::TVMUL => Some(VectorOp::TVMUL),
+        ternary_func::TVMAC => Some(VectorOp::TVMAC),
+        _ => None,
+    }
+}
+
+/// Retourne le sous-code (champ rs2 de TVRED) d'une réduction vectorielle
+pub fn vector_reduce_op_to_func(op: VectorReduceOp) -> [Trit; 2] {
+    match op {
+        VectorReduceOp::TVSUM => vreduce_func::TVSUM,
+        VectorReduceOp::TVMIN => vreduce_func::TVMIN,
+        VectorReduceOp::TVMAX => vreduce_func::TVMAX,
+        VectorReduceOp::TVAVG => vreduce_func::TVAVG,
+    }
+}
+
+/// Retourne la réduction vectorielle correspondant à un sous-code
+pub fn func_to_vector_reduce_op(func: [Trit; 2]) -> Option<VectorReduceOp> {
+    match func {
+        vreduce_func::TVSUM => Some(VectorReduceOp::TVSUM),
+        vreduce_func::TVMIN => Some(VectorReduceOp::TVMIN),
+        vreduce_func::TVMAX => Some(VectorReduceOp::TVMAX),
+        vreduce_func::TVAVG => Some(VectorReduceOp::TVAVG),
+        _ => None,
+    }
+}
+
+/// Retourne le code de fonction (opcode TSHIFT) d'une rotation ou d'un décalage
+pub fn tshift_op_to_func(op: TernaryShiftOp) -> [Trit; 2] {
+    match op {
+        TernaryShiftOp::TROTL => tshift_func::TROTL,
+        TernaryShiftOp::TROTR => tshift_func::TROTR,
+        TernaryShiftOp::TSHIFTL => tshift_func::TSHIFTL,
+        TernaryShiftOp::TSHIFTR => tshift_func::TSHIFTR,
+    }
+}
+
+/// Retourne la rotation ou le décalage correspondant à un code de fonction
+pub fn func_to_tshift_op(func: [Trit; 2]) -> Option<TernaryShiftOp> {
+    match func {
+        tshift_func::TROTL => Some(TernaryShiftOp::TROTL),
+        tshift_func::TROTR => Some(TernaryShiftOp::TROTR),
+        tshift_func::TSHIFTL => Some(TernaryShiftOp::TSHIFTL),
+        tshift_func::TSHIFTR => Some(TernaryShiftOp::TSHIFTR),
+        _ => None,
+    }
+}
+
+/// Retourne le code (3 trits) d'une condition de branchement
+pub fn branch_condition_to_trits(condition: BranchCondition) -> [Trit; 3] {
+    match condition {
+        BranchCondition::Zero => cond::EQ,
+        BranchCondition::NonZero => cond::NE,
+        BranchCondition::Negative => cond::LT,
+        BranchCondition::Positive => cond::GE,
+        BranchCondition::Greater => cond::GT,
+        BranchCondition::LessEqual => cond::LE,
+        BranchCondition::Overflow => cond::OF,
+        BranchCondition::Carry => cond::CF,
+        BranchCondition::XS => cond::XS,
+        BranchCondition::XN => cond::XN,
+        BranchCondition::True => cond::AL,
+        BranchCondition::False => cond::NV,
+    }
+}
+
+/// Retourne la condition de branchement correspondant à un code de 3 trits
+pub fn trits_to_branch_condition(trits: [Trit; 3]) -> Option<BranchCondition> {
+    match trits {
+        cond::EQ => Some(BranchCondition::Zero),
+        cond::NE => Some(BranchCondition::NonZero),
+        cond::LT => Some(BranchCondition::Negative),
+        cond::GE => Some(BranchCondition::Positive),
+        cond::GT => Some(BranchCondition::Greater),
+        cond::LE => Some(BranchCondition::LessEqual),
+        cond::OF => Some(BranchCondition::Overflow),
+        cond::CF => Some(BranchCondition::Carry),
+        cond::XS => Some(BranchCondition::XS),
+        cond::XN => Some(BranchCondition::XN),
+        cond::AL => Some(BranchCondition::True),
+        cond::NV => Some(BranchCondition::False),
+        _ => None, // Condition réservée
+    }
+}
+
+/// Retourne la condition de branchement correspondant à son nom assembleur (EQ, NE, ...)
+/// `ALWAYS` et `NEVER` sont acceptés comme alias de `AL` et `NV`.
+pub fn branch_condition_from_name(name: &str) -> Option<BranchCondition> {
+    match name.to_uppercase().as_str() {
+        "EQ" => Some(BranchCondition::Zero),
+        "NE" => Some(BranchCondition::NonZero),
+        "LT" => Some(BranchCondition::Negative),
+        "GE" => Some(BranchCondition::Positive),
+        "GT" => Some(BranchCondition::Greater),
+        "LE" => Some(BranchCondition::LessEqual),
+        "OF" => Some(BranchCondition::Overflow),
+        "CF" => Some(BranchCondition::Carry),
+        "XS" => Some(BranchCondition::XS),
+        "XN" => Some(BranchCondition::XN),
+        "AL" | "ALWAYS" => Some(BranchCondition::True),
+        "NV" | "NEVER" => Some(BranchCondition::False),
+        _ => None,
+    }
+}
+
+/// Retourne le nom assembleur d'une condition de branchement (inverse de `branch_condition_from_name`)
+pub fn branch_condition_name(condition: BranchCondition) -> &'static str {
+    match condition {
+        BranchCondition::Zero => "EQ",
+        BranchCondition::NonZero => "NE",
+        BranchCondition::Negative => "LT",
+        BranchCondition::Positive => "GE",
+        BranchCondition::Greater => "GT",
+        BranchCondition::LessEqual => "LE",
+        BranchCondition::Overflow => "OF",
+        BranchCondition::Carry => "CF",
+        BranchCondition::XS => "XS",
+        BranchCondition::XN => "XN",
+        BranchCondition::True => "AL",
+        BranchCondition::False => "NV",
+    }
+}
+
+/// Retourne le numéro d'un CSR à partir de son nom assembleur (MSTATUS_T, ...)
+pub fn csr_from_name(name: &str) -> Option<i8> {
+    match name.to_uppercase().as_str() {
+        "MSTATUS_T" => Some(csr::MSTATUS_T),
+        "MTVEC_T" => Some(csr::MTVEC_T),
+        "MEPC_T" => Some(csr::MEPC_T),
+        "MCAUSE_T" => Some(csr::MCAUSE_T),
+        "SSTATUS_T" => Some(csr::SSTATUS_T),
+        "STVEC_T" => Some(csr::STVEC_T),
+        "SEPC_T" => Some(csr::SEPC_T),
+        "SCAUSE_T" => Some(csr::SCAUSE_T),
+        "MEDELEG_T" => Some(csr::MEDELEG_T),
+        "MIDELEG_T" => Some(csr::MIDELEG_T),
+        "MIE_T" => Some(csr::MIE_T),
+        "MIP_T" => Some(csr::MIP_T),
+        "SATP_T" => Some(csr::SATP_T),
+        _ => None,
+    }
+}
+
+/// Retourne le nom assembleur d'un CSR à partir de son numéro
+pub fn csr_name(code: i8) -> Option<&'static str> {
+    match code {
+        csr::MSTATUS_T => Some("MSTATUS_T"),
+        csr::MTVEC_T => Some("MTVEC_T"),
+        csr::MEPC_T => Some("MEPC_T"),
+        csr::MCAUSE_T => Some("MCAUSE_T"),
+        csr::SSTATUS_T => Some("SSTATUS_T"),
+        csr::STVEC_T => Some("STVEC_T"),
+        csr::SEPC_T => Some("SEPC_T"),
+        csr::SCAUSE_T => Some("SCAUSE_T"),
+        csr::MEDELEG_T => Some("MEDELEG_T"),
+        csr::MIDELEG_T => Some("MIDELEG_T"),
+        csr::MIE_T => Some("MIE_T"),
+        csr::MIP_T => Some("MIP_T"),
+        csr::SATP_T => Some("SATP_T"),
+        _ => None,
+    }
+}
+
+/// Retourne le mnémonique assembleur d'une fonction système
+pub fn system_func_name(func: i8) -> Option<&'static str> {
+    match func {
+        system_func::HALT => Some("HALT"),
+        system_func::NOP => Some("NOP"),
+        system_func::ECALL => Some("ECALL"),
+        system_func::EBREAK => Some("EBREAK"),
+        system_func::MRET_T => Some("MRET_T"),
+        system_func::SRET_T => Some("SRET_T"),
+        _ => None,
+    }
+}
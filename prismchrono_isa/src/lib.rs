@@ -0,0 +1,25 @@
+//! Définition partagée de l'ISA PrismChrono
+//!
+//! Cette bibliothèque est l'unique source de vérité pour l'architecture
+//! ternaire PrismChrono: types de base (trit, tryte, mot), registres,
+//! instructions, tables d'opcodes et encodage/décodage canonique sur 12 trits.
+//! L'assembleur l'utilise pour encoder et le simulateur pour décoder, de sorte
+//! que les deux outils ne peuvent plus diverger.
+
+pub mod decode;
+pub mod encode;
+pub mod isa;
+pub mod isa_defs;
+pub mod object;
+pub mod register;
+pub mod types;
+
+// Réexporter les types les plus utilisés
+pub use decode::{decode, DecodeError};
+pub use encode::{encode, EncodeError};
+pub use isa::{
+    AluOp, Base24Op, BranchCondition, Condition, Instruction, InstructionFormat, Opcode, SpecialStateOp,
+    TernaryOp, TernaryShiftOp, VectorOp, VectorReduceOp,
+};
+pub use register::{Register, RegisterError, VectorRegister};
+pub use types::{ConversionError, Trit, Tryte, WORD_MAX, WORD_MIN, WORD_MODULUS, Word};
@@ -0,0 +1,509 @@
+//! Format objet relogeable PrismChrono (.tro)
+//!
+//! Produit par `prismchrono_asm --relocatable` et consommé par `prismchrono_ld`.
+//! Un objet contient des sections dont les adresses sont relatives à leur début,
+//! une table des symboles (liaison locale ou globale) et les relocations à appliquer
+//! une fois les sections placées par l'éditeur de liens.
+//!
+//! Le format est textuel, une entrée par ligne (`#` introduit un commentaire):
+//!
+//! ```text
+//! TRO 1
+//! .section .text 8 0010           # nom, alignement, taille (hexadécimal)
+//! 0000: ZPN ZNZ NPZ NNZ            # instruction (offset relatif à la section)
+//! 0008: 13 14 UND                  # données (trytes)
+//! .symbol _start .text 0000 global
+//! .reloc .text 0004 J afficher 0   # section, offset, type, symbole, addend
+//! ```
+
+use crate::decode::decode;
+use crate::encode::encode;
+use crate::isa::{AluOp, Instruction};
+use crate::isa_defs::{imm_limits, split_upper_immediate};
+use crate::types::{Trit, Tryte, Word};
+use std::fmt;
+
+/// Mot magique en tête d'un fichier objet
+pub const OBJECT_MAGIC: &str = "TRO";
+
+/// Version courante du format objet
+pub const OBJECT_VERSION: u32 = 1;
+
+/// Contenu placé à un offset d'une section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Chunk {
+    /// Instruction encodée (12 trits)
+    Instruction([Trit; 12]),
+    /// Données (suite de trytes)
+    Data(Vec<Tryte>),
+}
+
+/// Section d'un fichier objet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectSection {
+    /// Nom de la section (ex: ".text")
+    pub name: String,
+    /// Alignement requis de l'adresse de base
+    pub alignment: u32,
+    /// Taille de la section en octets
+    pub size: u32,
+    /// Contenu, indexé par offset relatif au début de la section
+    pub chunks: Vec<(u32, Chunk)>,
+}
+
+/// Liaison d'un symbole
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolBinding {
+    /// Visible uniquement dans l'objet qui le définit
+    Local,
+    /// Exporté (`.global`), visible de tous les objets
+    Global,
+}
+
+/// Symbole défini par un fichier objet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectSymbol {
+    /// Nom du symbole
+    pub name: String,
+    /// Section contenant le symbole
+    pub section: String,
+    /// Offset relatif au début de la section
+    pub offset: u32,
+    /// Liaison du symbole
+    pub binding: SymbolBinding,
+}
+
+/// Type de relocation (champ à corriger une fois l'adresse du symbole connue)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationKind {
+    /// Offset PC-relatif du format J (JAL, CALL): (S + A - (P + 4)) / 4
+    J,
+    /// Offset PC-relatif du format B (BRANCH): (S + A - (P + 4)) / 4
+    B,
+    /// Partie haute d'une adresse absolue, immédiat de LUI
+    UHi,
+    /// Partie basse d'une adresse absolue, immédiat de ADDI
+    ILo,
+    /// Adresse absolue sur un mot de 8 trytes (`.word label`)
+    Word,
+}
+
+impl RelocationKind {
+    /// Nom du type dans le format texte
+    pub fn name(&self) -> &'static str {
+        match self {
+            RelocationKind::J => "J",
+            RelocationKind::B => "B",
+            RelocationKind::UHi => "UHI",
+            RelocationKind::ILo => "ILO",
+            RelocationKind::Word => "WORD",
+        }
+    }
+
+    /// Analyse un nom de type de relocation
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "J" => Some(RelocationKind::J),
+            "B" => Some(RelocationKind::B),
+            "UHI" => Some(RelocationKind::UHi),
+            "ILO" => Some(RelocationKind::ILo),
+            "WORD" => Some(RelocationKind::Word),
+            _ => None,
+        }
+    }
+}
+
+/// Relocation à appliquer à une instruction ou à un mot de données
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relocation {
+    /// Section contenant l'élément à corriger
+    pub section: String,
+    /// Offset de l'élément dans sa section
+    pub offset: u32,
+    /// Type de relocation
+    pub kind: RelocationKind,
+    /// Symbole référencé
+    pub symbol: String,
+    /// Constante ajoutée à l'adresse du symbole
+    pub addend: i32,
+}
+
+/// Erreur de lecture d'un objet ou d'application d'une relocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectError {
+    /// Ligne mal formée dans un fichier objet
+    Parse { line: usize, message: String },
+    /// Relocation impossible à appliquer
+    Relocation(String),
+}
+
+impl fmt::Display for ObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ObjectError::Parse { line, message } => write!(f, "ligne {}: {}", line, message),
+            ObjectError::Relocation(message) => write!(f, "relocation: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ObjectError {}
+
+/// Fichier objet relogeable
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectFile {
+    /// Sections, dans l'ordre de leur première apparition dans le source
+    pub sections: Vec<ObjectSection>,
+    /// Symboles définis (locaux et globaux)
+    pub symbols: Vec<ObjectSymbol>,
+    /// Relocations en attente
+    pub relocations: Vec<Relocation>,
+}
+
+impl ObjectFile {
+    /// Crée un objet vide
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retourne la section nommée `name`
+    pub fn section(&self, name: &str) -> Option<&ObjectSection> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Sérialise l'objet au format texte
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{} {}\n", OBJECT_MAGIC, OBJECT_VERSION);
+
+        for section in &self.sections {
+            text.push_str(&format!(
+                ".section {} {} {:04X}\n",
+                section.name, section.alignment, section.size
+            ));
+            for (offset, chunk) in &section.chunks {
+                let tokens: Vec<String> = match chunk {
+                    Chunk::Instruction(trits) => trits
+                        .chunks(3)
+                        .map(|group| group.iter().map(|trit| trit.to_string()).collect())
+                        .collect(),
+                    Chunk::Data(trytes) => trytes.iter().map(|tryte| tryte.to_string()).collect(),
+                };
+                text.push_str(&format!("{:04X}: {}\n", offset, tokens.join(" ")));
+            }
+        }
+
+        for symbol in &self.symbols {
+            let binding = match symbol.binding {
+                SymbolBinding::Local => "local",
+                SymbolBinding::Global => "global",
+            };
+            text.push_str(&format!(
+                ".symbol {} {} {:04X} {}\n",
+                symbol.name, symbol.section, symbol.offset, binding
+            ));
+        }
+
+        for relocation in &self.relocations {
+            text.push_str(&format!(
+                ".reloc {} {:04X} {} {} {}\n",
+                relocation.section,
+                relocation.offset,
+                relocation.kind.name(),
+                relocation.symbol,
+                relocation.addend
+            ));
+        }
+
+        text
+    }
+
+    /// Analyse un objet au format texte
+    pub fn parse(text: &str) -> Result<Self, ObjectError> {
+        let mut object = ObjectFile::new();
+        let mut header_seen = false;
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |message: String| ObjectError::Parse { line: line_number, message };
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            if !header_seen {
+                if fields.len() != 2 || fields[0] != OBJECT_MAGIC {
+                    return Err(error("en-tête TRO attendu".to_string()));
+                }
+                if fields[1] != OBJECT_VERSION.to_string() {
+                    return Err(error(format!("version d'objet non supportée: {}", fields[1])));
+                }
+                header_seen = true;
+                continue;
+            }
+
+            match fields[0] {
+                ".section" => {
+                    let [_, name, alignment, size] = fields[..] else {
+                        return Err(error("attendu: .section NOM ALIGNEMENT TAILLE".to_string()));
+                    };
+                    object.sections.push(ObjectSection {
+                        name: name.to_string(),
+                        alignment: alignment
+                            .parse()
+                            .map_err(|_| error(format!("alignement invalide: {}", alignment)))?,
+                        size: parse_hex(size).ok_or_else(|| error(format!("taille invalide: {}", size)))?,
+                        chunks: Vec::new(),
+                    });
+                }
+                ".symbol" => {
+                    let [_, name, section, offset, binding] = fields[..] else {
+                        return Err(error("attendu: .symbol NOM SECTION OFFSET local|global".to_string()));
+                    };
+                    object.symbols.push(ObjectSymbol {
+                        name: name.to_string(),
+                        section: section.to_string(),
+                        offset: parse_hex(offset).ok_or_else(|| error(format!("offset invalide: {}", offset)))?,
+                        binding: match binding {
+                            "local" => SymbolBinding::Local,
+                            "global" => SymbolBinding::Global,
+                            _ => return Err(error(format!("liaison invalide: {}", binding))),
+                        },
+                    });
+                }
+                ".reloc" => {
+                    let [_, section, offset, kind, symbol, addend] = fields[..] else {
+                        return Err(error("attendu: .reloc SECTION OFFSET TYPE SYMBOLE ADDEND".to_string()));
+                    };
+                    object.relocations.push(Relocation {
+                        section: section.to_string(),
+                        offset: parse_hex(offset).ok_or_else(|| error(format!("offset invalide: {}", offset)))?,
+                        kind: RelocationKind::from_name(kind)
+                            .ok_or_else(|| error(format!("type de relocation inconnu: {}", kind)))?,
+                        symbol: symbol.to_string(),
+                        addend: addend.parse().map_err(|_| error(format!("addend invalide: {}", addend)))?,
+                    });
+                }
+                _ => {
+                    let Some(section) = object.sections.last_mut() else {
+                        return Err(error("contenu hors de toute section".to_string()));
+                    };
+                    let offset = fields[0]
+                        .strip_suffix(':')
+                        .and_then(parse_hex)
+                        .ok_or_else(|| error(format!("offset invalide: {}", fields[0])))?;
+                    let chunk = parse_chunk(&fields[1..]).map_err(error)?;
+                    section.chunks.push((offset, chunk));
+                }
+            }
+        }
+
+        if !header_seen {
+            return Err(ObjectError::Parse { line: 0, message: "fichier objet vide".to_string() });
+        }
+
+        Ok(object)
+    }
+}
+
+/// Analyse un nombre hexadécimal (sans préfixe)
+fn parse_hex(text: &str) -> Option<u32> {
+    u32::from_str_radix(text, 16).ok()
+}
+
+/// Analyse le contenu d'une ligne: 4 groupes de 3 trits (instruction) ou des trytes (données)
+fn parse_chunk(tokens: &[&str]) -> Result<Chunk, String> {
+    let is_instruction = tokens.len() == 4
+        && tokens
+            .iter()
+            .all(|token| token.len() == 3 && token.chars().all(|c| matches!(c, 'N' | 'Z' | 'P')));
+
+    if is_instruction {
+        let mut trits = [Trit::Z; 12];
+        for (trit, c) in trits.iter_mut().zip(tokens.iter().flat_map(|token| token.chars())) {
+            *trit = match c {
+                'N' => Trit::N,
+                'P' => Trit::P,
+                _ => Trit::Z,
+            };
+        }
+        return Ok(Chunk::Instruction(trits));
+    }
+
+    if tokens.is_empty() {
+        return Err("contenu vide".to_string());
+    }
+    tokens
+        .iter()
+        .map(|token| parse_tryte(token).ok_or_else(|| format!("tryte invalide '{}'", token)))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Chunk::Data)
+}
+
+/// Analyse un tryte tel qu'affiché par `Display` (chiffre 0-23 ou état spécial)
+fn parse_tryte(token: &str) -> Option<Tryte> {
+    match token {
+        "UND" => Some(Tryte::Undefined),
+        "NUL" => Some(Tryte::Null),
+        "NaN" => Some(Tryte::NaN),
+        _ => match token.parse::<u8>() {
+            Ok(d) if d <= 23 => Some(Tryte::Digit(d)),
+            _ => None,
+        },
+    }
+}
+
+/// Applique une relocation à un élément placé à l'adresse `place`
+///
+/// `value` est l'adresse finale du symbole augmentée de l'addend (S + A). L'instruction
+/// est décodée, son champ immédiat remplacé puis réencodée, ce qui vérifie au passage
+/// que le type de relocation correspond bien à l'instruction.
+pub fn apply_relocation(chunk: &mut Chunk, kind: RelocationKind, place: u32, value: i64) -> Result<(), ObjectError> {
+    let error = |message: String| ObjectError::Relocation(message);
+
+    if kind == RelocationKind::Word {
+        let Chunk::Data(trytes) = chunk else {
+            return Err(error(format!("WORD à 0x{:X}: un mot de données est attendu", place)));
+        };
+        if trytes.len() != 8 {
+            return Err(error(format!("WORD à 0x{:X}: 8 trytes attendus, {} trouvés", place, trytes.len())));
+        }
+        let value = i32::try_from(value).map_err(|_| error(format!("adresse hors plage: {}", value)))?;
+        trytes.copy_from_slice(Word::from(value).trytes());
+        return Ok(());
+    }
+
+    let Chunk::Instruction(trits) = chunk else {
+        return Err(error(format!("{} à 0x{:X}: une instruction est attendue", kind.name(), place)));
+    };
+    let instruction = decode(trits).map_err(|e| error(format!("à 0x{:X}: {}", place, e)))?;
+
+    let pc_relative = || -> Result<i16, ObjectError> {
+        let diff = value - (place as i64 + 4);
+        if diff % 4 != 0 {
+            return Err(error(format!("cible 0x{:X} non alignée sur 4 octets", value)));
+        }
+        i16::try_from(diff / 4).map_err(|_| error(format!("cible 0x{:X} hors de portée", value)))
+    };
+    let split = || {
+        split_upper_immediate(value).ok_or_else(|| {
+            error(format!(
+                "adresse {} hors de portée de LUI/ADDI ({} à {})",
+                value,
+                imm_limits::LUI_ADDI_MIN,
+                imm_limits::LUI_ADDI_MAX
+            ))
+        })
+    };
+
+    let patched = match (kind, instruction) {
+        (RelocationKind::J, Instruction::Jump { rd, .. }) => Instruction::Jump { rd, offset: pc_relative()? },
+        (RelocationKind::J, Instruction::Call { rd, .. }) => Instruction::Call { rd, offset: pc_relative()? },
+        (RelocationKind::B, Instruction::Branch { rs1, cond, .. }) => Instruction::Branch { rs1, cond, offset: pc_relative()? },
+        (RelocationKind::UHi, Instruction::Lui { rd, .. }) => Instruction::Lui { rd, imm: split()?.0 as i16 },
+        (RelocationKind::ILo, Instruction::AluImm { op: AluOp::Add, rs1, rd, .. }) => Instruction::AluImm {
+            op: AluOp::Add,
+            rs1,
+            rd,
+            imm: split()?.1 as i16,
+        },
+        (kind, instruction) => {
+            return Err(error(format!(
+                "{} à 0x{:X}: instruction incompatible: {:?}",
+                kind.name(),
+                place,
+                instruction
+            )));
+        }
+    };
+
+    *trits = encode(&patched).map_err(|e| error(format!("à 0x{:X}: {}", place, e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::isa::BranchCondition;
+    use crate::register::Register;
+
+    fn instruction(instruction: Instruction) -> Chunk {
+        Chunk::Instruction(encode(&instruction).unwrap())
+    }
+
+    fn decoded(chunk: &Chunk) -> Instruction {
+        match chunk {
+            Chunk::Instruction(trits) => decode(trits).unwrap(),
+            Chunk::Data(_) => panic!("instruction attendue"),
+        }
+    }
+
+    #[test]
+    fn test_object_text_roundtrip() {
+        let object = ObjectFile {
+            sections: vec![
+                ObjectSection {
+                    name: ".text".to_string(),
+                    alignment: 8,
+                    size: 8,
+                    chunks: vec![
+                        (0, instruction(Instruction::Call { rd: Register::R7, offset: 0 })),
+                        (4, instruction(Instruction::Halt)),
+                    ],
+                },
+                ObjectSection {
+                    name: ".data".to_string(),
+                    alignment: 8,
+                    size: 9,
+                    chunks: vec![(0, Chunk::Data(Word::zero().trytes().to_vec())), (8, Chunk::Data(vec![Tryte::Null]))],
+                },
+            ],
+            symbols: vec![ObjectSymbol {
+                name: "_start".to_string(),
+                section: ".text".to_string(),
+                offset: 0,
+                binding: SymbolBinding::Global,
+            }],
+            relocations: vec![Relocation {
+                section: ".text".to_string(),
+                offset: 0,
+                kind: RelocationKind::J,
+                symbol: "afficher".to_string(),
+                addend: -4,
+            }],
+        };
+
+        assert_eq!(ObjectFile::parse(&object.to_text()), Ok(object));
+        assert!(ObjectFile::parse("TRO 2\n").is_err());
+        assert!(ObjectFile::parse("TRO 1\n0000: 13\n").is_err());
+    }
+
+    #[test]
+    fn test_apply_relocations() {
+        // JAL à 0x10 vers 0x40: (0x40 - 0x14) / 4 = 11
+        let mut jal = instruction(Instruction::Jump { rd: Register::R0, offset: 0 });
+        apply_relocation(&mut jal, RelocationKind::J, 0x10, 0x40).unwrap();
+        assert_eq!(decoded(&jal), Instruction::Jump { rd: Register::R0, offset: 11 });
+
+        let mut branch = instruction(Instruction::Branch { rs1: Register::R1, cond: BranchCondition::Zero, offset: 0 });
+        apply_relocation(&mut branch, RelocationKind::B, 0x10, 0x04).unwrap();
+        assert_eq!(decoded(&branch), Instruction::Branch { rs1: Register::R1, cond: BranchCondition::Zero, offset: -4 });
+        assert!(apply_relocation(&mut branch, RelocationKind::B, 0, 0x400).is_err());
+
+        // LUI/ADDI reconstruisent l'adresse
+        let mut lui = instruction(Instruction::Lui { rd: Register::R2, imm: 0 });
+        let mut addi = instruction(Instruction::AluImm { op: AluOp::Add, rs1: Register::R2, rd: Register::R2, imm: 0 });
+        apply_relocation(&mut lui, RelocationKind::UHi, 0, 5000).unwrap();
+        apply_relocation(&mut addi, RelocationKind::ILo, 4, 5000).unwrap();
+        let (Instruction::Lui { imm: high, .. }, Instruction::AluImm { imm: low, .. }) = (decoded(&lui), decoded(&addi)) else {
+            panic!("LUI/ADDI attendus");
+        };
+        assert_eq!(high as i32 * 243 + low as i32, 5000);
+
+        let mut word = Chunk::Data(vec![Tryte::Undefined; 8]);
+        apply_relocation(&mut word, RelocationKind::Word, 0, 0x1234).unwrap();
+        assert_eq!(word, Chunk::Data(Word::from(0x1234).trytes().to_vec()));
+
+        // Type de relocation incompatible avec l'instruction
+        assert!(apply_relocation(&mut lui, RelocationKind::J, 0, 0).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Registres généraux et vectoriels de PrismChrono
+//!
+//! Les 8 registres R0-R7 et les 8 registres vectoriels V0-V7 sont encodés sur
+//! 2 trits dans les instructions.
+
+use std::fmt;
+
+/// Énumération des registres généraux du processeur
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Register {
+    R0,
+    R1,
+    R2,
+    R3,
+    R4,
+    R5,
+    R6,
+    R7,
+}
+
+impl Register {
+    /// Convertit un registre en son index (0-7)
+    pub fn to_index(&self) -> usize {
+        match self {
+            Register::R0 => 0,
+            Register::R1 => 1,
+            Register::R2 => 2,
+            Register::R3 => 3,
+            Register::R4 => 4,
+            Register::R5 => 5,
+            Register::R6 => 6,
+            Register::R7 => 7,
+        }
+    }
+
+    /// Crée un registre à partir d'un index (0-7)
+    pub fn from_index(index: usize) -> Result<Self, RegisterError> {
+        match index {
+            0 => Ok(Register::R0),
+            1 => Ok(Register::R1),
+            2 => Ok(Register::R2),
+            3 => Ok(Register::R3),
+            4 => Ok(Register::R4),
+            5 => Ok(Register::R5),
+            6 => Ok(Register::R6),
+            7 => Ok(Register::R7),
+            _ => Err(RegisterError::InvalidIndex),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "R{}", self.to_index())
+    }
+}
+
+/// Registres vectoriels de la TVPU (8 mots ternaires chacun)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VectorRegister {
+    V0,
+    V1,
+    V2,
+    V3,
+    V4,
+    V5,
+    V6,
+    V7,
+}
+
+impl VectorRegister {
+    /// Convertit un registre vectoriel en son index (0-7)
+    pub fn to_index(&self) -> usize {
+        match self {
+            VectorRegister::V0 => 0,
+            VectorRegister::V1 => 1,
+            VectorRegister::V2 => 2,
+            VectorRegister::V3 => 3,
+            VectorRegister::V4 => 4,
+            VectorRegister::V5 => 5,
+            VectorRegister::V6 => 6,
+            VectorRegister::V7 => 7,
+        }
+    }
+
+    /// Crée un registre vectoriel à partir d'un index (0-7)
+    pub fn from_index(index: usize) -> Result<Self, RegisterError> {
+        match index {
+            0 => Ok(VectorRegister::V0),
+            1 => Ok(VectorRegister::V1),
+            2 => Ok(VectorRegister::V2),
+            3 => Ok(VectorRegister::V3),
+            4 => Ok(VectorRegister::V4),
+            5 => Ok(VectorRegister::V5),
+            6 => Ok(VectorRegister::V6),
+            7 => Ok(VectorRegister::V7),
+            _ => Err(RegisterError::InvalidIndex),
+        }
+    }
+}
+
+impl fmt::Display for VectorRegister {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "V{}", self.to_index())
+    }
+}
+
+/// Erreurs possibles lors de l'accès aux registres
+#[derive(Debug, PartialEq, Eq)]
+pub enum RegisterError {
+    InvalidIndex,       // Index de registre invalide (hors de la plage 0-7)
+    PrivilegeViolation, // Tentative d'accès à un registre privilégié depuis un mode non privilégié
+}
@@ -0,0 +1,755 @@
+//! Types ternaires fondamentaux de PrismChrono
+//!
+//! Trit, Tryte (3 trits) et Word (24 trits = 8 trytes), partagés par
+//! l'assembleur et le simulateur.
+//!
+//! Valeur numérique d'un Mot: ses 8 trytes sont des chiffres B24 de valeur Bal3 -13..+10,
+//! de poids 24^i (tryte de poids faible en tête). Les motifs Bal3 +11/+12/+13 sont réservés
+//! aux états spéciaux UNDEF/NULL/NaN: une valeur n'est jamais encodée avec eux, un chiffre
+//! qui dépasserait +10 est ramené dans -13..+10 avec une retenue sur le tryte suivant.
+//! La plage d'un Mot est donc [WORD_MIN, WORD_MAX] = [-13, +10] × (24^8 - 1) / 23.
+//!
+//! Conversions numériques canoniques:
+//! - `Word::try_from(i64)` (vérifiée) et `i64::from(Word)` (exacte, valeur des 8 trytes);
+//!   `Word::from(i32)` est toujours exacte, `Word::from_i64` réduit modulo 24^8.
+//! - `Tryte::try_from(i8)` / `i8::from(Tryte)` sur la valeur Bal3 -13..+13.
+//! - `Word::from_trits` / `Word::to_trits` / `Word::trits` (trit de poids faible en tête),
+//!   `balanced_trits` / `trits_value` pour un champ de N trits (immédiats, adresses).
+//! - Textes: `Word::from_str` accepte le décimal (`-42`), le ternaire équilibré préfixé
+//!   (`0t+-0+`), les chiffres B24 séparés par `:` (forme de `Display`, poids fort en tête)
+//!   et les états `NaN`, `NULL`, `UNDEF`. `Word::balanced` affiche la valeur en ternaire
+//!   équilibré.
+
+use std::fmt; // Pour implémenter l'affichage
+use std::str::FromStr;
+
+/// Nombre de trits d'un Mot
+pub const WORD_TRITS: usize = 24;
+
+/// Plus grande valeur d'un Mot: 8 chiffres B24 à +10, soit 10 × (24^8 - 1) / 23
+pub const WORD_MAX: i64 = 47_858_832_250;
+
+/// Plus petite valeur d'un Mot: 8 chiffres B24 à -13, soit -13 × (24^8 - 1) / 23
+pub const WORD_MIN: i64 = -62_216_481_925;
+
+/// Nombre de valeurs d'un Mot (24^8): les calculs qui débordent sont réduits modulo ce nombre
+pub const WORD_MODULUS: i64 = WORD_MAX - WORD_MIN + 1;
+
+/// Erreurs de conversion d'un nombre ou d'un texte vers un type ternaire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    /// Valeur non représentable sur le nombre de trits disponible
+    OutOfRange { value: i128, min: i64, max: i64 },
+    /// Texte vide
+    Empty,
+    /// Caractère inattendu dans un nombre ternaire équilibré
+    InvalidDigit(char),
+    /// Texte qui n'est ni un nombre ni un chiffre B24 ni un état spécial
+    InvalidNumber(String),
+    /// Plus de chiffres que le type n'en contient
+    TooManyDigits { count: usize, max: usize },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::OutOfRange { value, min, max } => {
+                write!(f, "valeur {} hors de la plage [{}, {}]", value, min, max)
+            }
+            ConversionError::Empty => write!(f, "valeur vide"),
+            ConversionError::InvalidDigit(c) => {
+                write!(f, "chiffre ternaire '{}' invalide (attendu '+', '0' ou '-')", c)
+            }
+            ConversionError::InvalidNumber(s) => write!(f, "valeur '{}' invalide", s),
+            ConversionError::TooManyDigits { count, max } => {
+                write!(f, "{} chiffres pour au plus {}", count, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Plus grande valeur représentable sur `n` trits: (3^n - 1) / 2
+fn max_value(n: usize) -> i64 {
+    (3i64.pow(n as u32) - 1) / 2
+}
+
+/// Représentation exacte de `value` sur N trits (trit de poids faible en tête)
+pub fn balanced_trits<const N: usize>(value: i64) -> Result<[Trit; N], ConversionError> {
+    let max = max_value(N);
+    if value < -max || value > max {
+        return Err(ConversionError::OutOfRange { value: value as i128, min: -max, max });
+    }
+
+    let mut trits = [Trit::Z; N];
+    let mut remaining = value;
+    for trit in trits.iter_mut() {
+        *trit = match remaining.rem_euclid(3) {
+            0 => Trit::Z,
+            1 => Trit::P,
+            _ => Trit::N, // 2 ≡ -1 (mod 3)
+        };
+        remaining = (remaining - trit.value() as i64) / 3;
+    }
+    Ok(trits)
+}
+
+/// Valeur d'une suite de trits (trit de poids faible en tête)
+pub fn trits_value(trits: &[Trit]) -> i64 {
+    trits.iter().rev().fold(0, |acc, trit| acc * 3 + trit.value() as i64)
+}
+
+// --- Trit ---
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)] // Dérivations utiles
+pub enum Trit {
+    N, // -1
+    Z, //  0
+    P, // +1
+}
+
+impl Trit {
+    // Conversion vers une valeur numérique pour les calculs
+    pub fn value(&self) -> i8 {
+        match self {
+            Trit::N => -1,
+            Trit::Z => 0,
+            Trit::P => 1,
+        }
+    }
+
+    // Conversion depuis une valeur numérique (utile pour l'ALU)
+    pub fn from_value(val: i8) -> Option<Trit> {
+        match val {
+            -1 => Some(Trit::N),
+            0 => Some(Trit::Z),
+            1 => Some(Trit::P),
+            _ => None, // Gérer les cas invalides
+        }
+    }
+
+    // Implémenter l'inverseur ternaire
+    pub fn inv(&self) -> Trit {
+        match self {
+            Trit::N => Trit::P,
+            Trit::Z => Trit::Z,
+            Trit::P => Trit::N,
+        }
+    }
+
+    // Symbole du trit dans un nombre ternaire équilibré ('-', '0', '+')
+    pub fn symbol(&self) -> char {
+        match self {
+            Trit::N => '-',
+            Trit::Z => '0',
+            Trit::P => '+',
+        }
+    }
+
+    // Trit correspondant à un symbole ternaire équilibré
+    pub fn from_symbol(c: char) -> Option<Trit> {
+        match c {
+            '-' => Some(Trit::N),
+            '0' => Some(Trit::Z),
+            '+' => Some(Trit::P),
+            _ => None,
+        }
+    }
+}
+
+// Affichage simple (N, Z, P)
+impl fmt::Display for Trit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Trit::N => 'N',
+                Trit::Z => 'Z',
+                Trit::P => 'P',
+            }
+        )
+    }
+}
+
+// --- Tryte (3 Trits) ---
+// Représente soit un chiffre B24 (via sa valeur 0-23) soit un état spécial
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Tryte {
+    Digit(u8), // Stocke 0-23
+    Undefined, // UNDEF (P,P,N => Bal3 +11)
+    Null,      // NULL  (P,P,Z => Bal3 +12)
+    NaN,       // NaN   (P,P,P => Bal3 +13)
+}
+
+impl Tryte {
+    // Valeur Bal3 (-13 à +10 pour Digit, +11/12/13 pour Spéciaux)
+    pub fn bal3_value(&self) -> i8 {
+        match self {
+            Tryte::Digit(d) => (*d as i8) - 13, // Convert 0-23 to -13 to +10
+            Tryte::Undefined => 11,
+            Tryte::Null => 12,
+            Tryte::NaN => 13,
+        }
+    }
+
+    // Créer un Tryte depuis une valeur Bal3 (-13 à +13)
+    pub fn from_bal3(val: i8) -> Option<Tryte> {
+        match val {
+            -13..=10 => Some(Tryte::Digit((val + 13) as u8)),
+            11 => Some(Tryte::Undefined),
+            12 => Some(Tryte::Null),
+            13 => Some(Tryte::NaN),
+            _ => None, // Valeur Bal3 invalide
+        }
+    }
+
+    // Convertir en 3 trits (pour stockage mémoire ou logique trit-à-trit)
+    pub fn to_trits(&self) -> [Trit; 3] {
+        let bal3 = self.bal3_value();
+        // Calcul classique Bal3 -> Trits (t0 = reste mod 3, t1 = (val/3) mod 3, ...)
+        // Attention à l'arithmétique ternaire équilibrée
+        let mut trits = [Trit::Z; 3];
+        let mut current_val = bal3;
+
+        for trit in &mut trits {
+            let remainder = (current_val + 1).rem_euclid(3) - 1; // Remainder in {-1, 0, +1}
+            *trit = Trit::from_value(remainder).unwrap_or(Trit::Z); // Should always unwrap
+            // Division par 3 en ternaire équilibré: (val - remainder) / 3
+            current_val = (current_val - remainder) / 3;
+        }
+        // Assurez-vous que l'ordre t0, t1, t2 est correct (ici t0 est trits[0])
+        trits
+    }
+
+    // Créer un Tryte depuis 3 trits
+    pub fn from_trits(trits: [Trit; 3]) -> Tryte {
+        let t0 = trits[0].value();
+        let t1 = trits[1].value();
+        let t2 = trits[2].value();
+        let bal3 = t2 * 9 + t1 * 3 + t0;
+        Tryte::from_bal3(bal3).unwrap_or(Tryte::NaN) // Retourner NaN si la combinaison est invalide (ne devrait pas arriver)
+    }
+
+    // Récupérer un trit spécifique (0, 1, ou 2) de ce Tryte
+    pub fn get_trit(&self, index: usize) -> Trit {
+        if index > 2 {
+            return Trit::Z; // Indice invalide, retourne zéro
+        }
+        
+        let trits = self.to_trits();
+        trits[index]
+    }
+
+    /// Convertit un Tryte en i8 pour les calculs arithmétiques
+    pub fn to_i8(&self) -> i8 {
+        match self {
+            Tryte::Digit(d) => (*d as i8) - 13, // Convertir 0-23 en -13 à +10
+            Tryte::Undefined => 11,
+            Tryte::Null => 12,
+            Tryte::NaN => 13,
+        }
+    }
+    
+    /// Crée un Tryte à partir d'une valeur i8
+    pub fn from_i8(val: i8) -> Tryte {
+        if (-13..=10).contains(&val) {
+            Tryte::Digit((val + 13) as u8)
+        } else if val == 11 {
+            Tryte::Undefined
+        } else if val == 12 {
+            Tryte::Null
+        } else if val == 13 {
+            Tryte::NaN
+        } else {
+            // Valeurs hors plage sont converties en NaN
+            Tryte::NaN
+        }
+    }
+
+    /// Vérifie si le tryte est une valeur NULL (représentation spécifique)
+    pub fn is_null(&self) -> bool {
+        matches!(self, Tryte::Null)
+    }
+    
+    /// Vérifie si le tryte est une valeur NaN (Not a Number, représentation spécifique)
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Tryte::NaN)
+    }
+    
+    /// Vérifie si le tryte est une valeur UNDEF (non définie, représentation spécifique)
+    pub fn is_undef(&self) -> bool {
+        matches!(self, Tryte::Undefined)
+    }
+}
+
+// Affichage (ex: chiffre B24 ou nom de l'état spécial)
+impl fmt::Display for Tryte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // Afficher A-N pour 10-23? Pour l'instant, juste les chiffres.
+            Tryte::Digit(d) => write!(f, "{}", d),
+            Tryte::Undefined => write!(f, "UND"),
+            Tryte::Null => write!(f, "NUL"),
+            Tryte::NaN => write!(f, "NaN"),
+        }
+    }
+}
+
+impl Default for Tryte {
+    fn default() -> Self {
+        Tryte::Digit(13) // 13 correspond à la valeur zéro en ternaire équilibré
+    }
+}
+
+// Conversion vérifiée depuis une valeur Bal3 (-13 à +13, états spéciaux compris)
+impl TryFrom<i8> for Tryte {
+    type Error = ConversionError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        Tryte::from_bal3(value).ok_or(ConversionError::OutOfRange { value: value as i128, min: -13, max: 13 })
+    }
+}
+
+impl From<Tryte> for i8 {
+    fn from(tryte: Tryte) -> Self {
+        tryte.bal3_value()
+    }
+}
+
+// Lecture de la forme affichée: chiffre B24 (0-23) ou UND/NUL/NaN
+impl FromStr for Tryte {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_ascii_uppercase().as_str() {
+            "" => return Err(ConversionError::Empty),
+            "UND" | "UNDEF" => return Ok(Tryte::Undefined),
+            "NUL" | "NULL" => return Ok(Tryte::Null),
+            "NAN" => return Ok(Tryte::NaN),
+            _ => {}
+        }
+        let digit: i128 = s.parse().map_err(|_| ConversionError::InvalidNumber(s.to_string()))?;
+        match u8::try_from(digit) {
+            Ok(d) if d <= 23 => Ok(Tryte::Digit(d)),
+            _ => Err(ConversionError::OutOfRange { value: digit, min: 0, max: 23 }),
+        }
+    }
+}
+
+// --- Word (24 Trits = 8 Trytes) ---
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Word(pub [Tryte; 8]); // Ajoute 'pub' devant le champ
+
+impl Word {
+    // Crée un mot avec une valeur par défaut (ex: Undefined)
+    pub fn default_undefined() -> Self {
+        Word([Tryte::Undefined; 8])
+    }
+    // Alias pour default_undefined pour compatibilité
+    pub fn undefined() -> Self {
+        Word([Tryte::Undefined; 8])
+    }
+    // Crée un mot zéro (tous trits Z, ce qui correspond à 8 trytes '13')
+    pub fn zero() -> Self {
+        Word([Tryte::Digit(13); 8]) // Tryte 13 a Bal3 = 0, donc (Z,Z,Z)
+    }
+    // Alias pour zero pour compatibilité
+    pub fn default_zero() -> Self {
+        Word::zero()
+    }
+    // Accès aux trytes individuels
+    pub fn tryte(&self, index: usize) -> Option<&Tryte> {
+        self.0.get(index)
+    }
+    pub fn tryte_mut(&mut self, index: usize) -> Option<&mut Tryte> {
+        self.0.get_mut(index)
+    }
+    // Accès direct au tableau (pour l'implémentation interne)
+    pub fn trytes(&self) -> &[Tryte; 8] {
+        &self.0
+    }
+    pub fn trytes_mut(&mut self) -> &mut [Tryte; 8] {
+        &mut self.0
+    }
+
+    // Crée un mot avec la valeur 1
+    pub fn one() -> Self {
+        let mut word = Word::zero();
+        if let Some(tryte) = word.tryte_mut(0) {
+            *tryte = Tryte::Digit(14); // 14 = 1 en ternaire équilibré (13+1=14)
+        }
+        word
+    }
+
+    // Crée un Word à partir d'une valeur i64 réduite modulo 24^8 (dans [WORD_MIN, WORD_MAX]);
+    // `Word::try_from` refuse au contraire les valeurs hors plage
+    pub fn from_i64(val: i64) -> Self {
+        let mut word = Word::zero();
+        // Repli dans la plage, calculé sans débordement même aux bornes de i64
+        let mut remaining = (val as i128 - WORD_MIN as i128).rem_euclid(WORD_MODULUS as i128) + WORD_MIN as i128;
+
+        for tryte in word.0.iter_mut() {
+            // Chiffre Bal3 dans -13..+10, le reste part en retenue sur le tryte suivant
+            let digit = (remaining + 13).rem_euclid(24) - 13;
+            *tryte = Tryte::Digit((digit + 13) as u8);
+            remaining = (remaining - digit) / 24;
+        }
+
+        word
+    }
+
+    // Crée un Word à partir de ses 24 trits (trit de poids faible en tête)
+    pub fn from_trits(trits: [Trit; WORD_TRITS]) -> Self {
+        let mut word = Word::zero();
+        for (tryte, chunk) in word.0.iter_mut().zip(trits.chunks_exact(3)) {
+            *tryte = Tryte::from_trits([chunk[0], chunk[1], chunk[2]]);
+        }
+        word
+    }
+
+    // Les 24 trits du mot (trit de poids faible en tête)
+    pub fn to_trits(&self) -> [Trit; WORD_TRITS] {
+        let mut trits = [Trit::Z; WORD_TRITS];
+        for (chunk, tryte) in trits.chunks_exact_mut(3).zip(self.0.iter()) {
+            chunk.copy_from_slice(&tryte.to_trits());
+        }
+        trits
+    }
+
+    // Itère sur les 24 trits du mot (trit de poids faible en tête)
+    pub fn trits(&self) -> impl Iterator<Item = Trit> + '_ {
+        self.0.iter().flat_map(|tryte| tryte.to_trits())
+    }
+
+    // Affichage de la valeur en ternaire équilibré (`+-0+`, préfixe `0t` avec `{:#}`)
+    pub fn balanced(&self) -> BalancedTernary<'_> {
+        BalancedTernary(self)
+    }
+
+    // Crée un Word à partir d'une valeur ternaire équilibrée (Bal3)
+    pub fn from_bal3(val: i8) -> Self {
+        let mut word = Word::zero();
+
+        // Placer la valeur dans le premier tryte
+        if let Some(tryte) = word.tryte_mut(0) {
+            *tryte = Tryte::from_bal3(val).unwrap_or(Tryte::Digit(13)); // 13 = 0 en ternaire équilibré
+        }
+
+        word
+    }
+
+    // Vérifie si le mot est négatif: son trit non nul de poids fort vaut N
+    pub fn is_negative(&self) -> bool {
+        (0..24)
+            .rev()
+            .map(|i| self.get_trit(i))
+            .find(|trit| *trit != Trit::Z)
+            == Some(Trit::N)
+    }
+    
+    // Convertit un mot ternaire en entier i64 (valeur exacte des 8 chiffres B24, états
+    // spéciaux compris: un tryte NaN compte +13)
+    pub fn to_i64(&self) -> i64 {
+        self.trytes().iter().rev().fold(0, |acc, tryte| acc * 24 + tryte.bal3_value() as i64)
+    }
+
+    // Récupère un trit spécifique à l'index donné dans le mot
+    pub fn get_trit(&self, index: usize) -> Trit {
+        let tryte_index = index / 3;
+        let trit_index = index % 3;
+        
+        if let Some(tryte) = self.tryte(tryte_index) {
+            tryte.get_trit(trit_index)
+        } else {
+            Trit::Z // Valeur par défaut
+        }
+    }
+
+    // Définit un trit spécifique à l'index donné dans le mot
+    pub fn set_trit(&mut self, index: usize, trit: Trit) {
+        let tryte_index = index / 3;
+        let trit_index = index % 3;
+        
+        if let Some(tryte_mut) = self.tryte_mut(tryte_index) {
+            // Récupérer les trits actuels
+            let mut trits = tryte_mut.to_trits();
+            // Modifier le trit spécifié
+            trits[trit_index] = trit;
+            // Recréer le tryte
+            *tryte_mut = Tryte::from_trits(trits);
+        }
+    }
+
+    /// Définit un tryte complet à l'index spécifié
+    pub fn set_tryte(&mut self, index: usize, tryte: Tryte) {
+        if index < 8 {
+            self.0[index] = tryte;
+        }
+    }
+    
+    /// Unwrap un Result<Word, E> ou retourne une valeur par défaut
+    pub fn unwrap_or_default<E>(result: Result<Word, E>) -> Word {
+        result.unwrap_or_default()
+    }
+}
+
+// Affichage (ex: séquence de 8 trytes)
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Affiche les trytes T7..T0 (MS Tryte first)
+        for i in (0..8).rev() {
+            write!(f, "{}", self.0[i])?;
+            if i > 0 {
+                write!(f, ":")?;
+            } // Séparateur
+        }
+        Ok(())
+    }
+}
+
+impl Default for Word {
+    fn default() -> Self {
+        Word::zero()
+    }
+}
+
+// Conversion vérifiée: la valeur doit tenir dans un Mot ([WORD_MIN, WORD_MAX]); ses trytes
+// sont alors toujours des chiffres, jamais des états spéciaux
+impl TryFrom<i64> for Word {
+    type Error = ConversionError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        if !(WORD_MIN..=WORD_MAX).contains(&value) {
+            return Err(ConversionError::OutOfRange { value: value as i128, min: WORD_MIN, max: WORD_MAX });
+        }
+        Ok(Word::from_i64(value))
+    }
+}
+
+// Un i32 tient toujours sur 24 trits
+impl From<i32> for Word {
+    fn from(value: i32) -> Self {
+        Word::from_i64(value as i64)
+    }
+}
+
+impl From<i16> for Word {
+    fn from(value: i16) -> Self {
+        Word::from_i64(value as i64)
+    }
+}
+
+impl From<Word> for i64 {
+    fn from(word: Word) -> Self {
+        word.to_i64()
+    }
+}
+
+impl TryFrom<Word> for i32 {
+    type Error = ConversionError;
+
+    fn try_from(word: Word) -> Result<Self, Self::Error> {
+        let value = word.to_i64();
+        i32::try_from(value).map_err(|_| ConversionError::OutOfRange {
+            value: value as i128,
+            min: i32::MIN as i64,
+            max: i32::MAX as i64,
+        })
+    }
+}
+
+// Lecture d'un Mot: décimal, ternaire équilibré `0t...`, trytes `d7:...:d0` ou état spécial
+impl FromStr for Word {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let special = match s.to_ascii_uppercase().as_str() {
+            "" => return Err(ConversionError::Empty),
+            "NAN" => Some(Tryte::NaN),
+            "NULL" => Some(Tryte::Null),
+            "UNDEF" => Some(Tryte::Undefined),
+            _ => None,
+        };
+        if let Some(tryte) = special {
+            return Ok(Word([tryte; 8]));
+        }
+
+        if let Some(digits) = s.strip_prefix("0t").or_else(|| s.strip_prefix("0T")) {
+            return parse_balanced(digits);
+        }
+
+        if s.contains(':') {
+            // Trytes de poids fort en tête; ceux qui manquent valent zéro
+            let fields: Vec<&str> = s.split(':').collect();
+            if fields.len() > 8 {
+                return Err(ConversionError::TooManyDigits { count: fields.len(), max: 8 });
+            }
+            let mut word = Word::zero();
+            for (i, field) in fields.iter().rev().enumerate() {
+                word.0[i] = field.parse()?;
+            }
+            return Ok(word);
+        }
+
+        let value: i128 = s.parse().map_err(|_| ConversionError::InvalidNumber(s.to_string()))?;
+        let out_of_range = ConversionError::OutOfRange { value, min: WORD_MIN, max: WORD_MAX };
+        let value = i64::try_from(value).map_err(|_| out_of_range.clone())?;
+        Word::try_from(value).map_err(|_| out_of_range)
+    }
+}
+
+// Valeur écrite en ternaire équilibré, poids fort en tête (sans le préfixe `0t`)
+fn parse_balanced(digits: &str) -> Result<Word, ConversionError> {
+    let trits = digits
+        .chars()
+        .filter(|c| *c != '_')
+        .map(|c| Trit::from_symbol(c).ok_or(ConversionError::InvalidDigit(c)))
+        .collect::<Result<Vec<Trit>, _>>()?;
+    if trits.is_empty() {
+        return Err(ConversionError::Empty);
+    }
+
+    // Les zéros de tête ne comptent pas dans la largeur
+    let significant = trits.iter().skip_while(|trit| **trit == Trit::Z).count();
+    if significant > WORD_TRITS {
+        return Err(ConversionError::TooManyDigits { count: significant, max: WORD_TRITS });
+    }
+
+    let value = trits.iter().fold(0i64, |acc, trit| acc * 3 + trit.value() as i64);
+    Word::try_from(value)
+}
+
+/// Affichage de la valeur d'un Mot en ternaire équilibré, créé par `Word::balanced`
+/// Poids fort en tête, sans zéros de tête (`0` pour zéro); `{:#}` ajoute le préfixe `0t`
+/// relu par `Word::from_str`. Un tryte spécial compte pour sa valeur Bal3, comme dans
+/// `Word::to_i64`.
+pub struct BalancedTernary<'a>(&'a Word);
+
+impl fmt::Display for BalancedTernary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // |valeur| <= 13 × (24^8 - 1) / 23 < (3^24 - 1) / 2: la valeur tient sur 24 trits
+        let trits = balanced_trits::<WORD_TRITS>(self.0.to_i64()).map_err(|_| fmt::Error)?;
+        let digits: String = trits
+            .iter()
+            .rev()
+            .skip_while(|trit| **trit == Trit::Z)
+            .map(Trit::symbol)
+            .collect();
+        if f.alternate() {
+            write!(f, "0t")?;
+        }
+        if digits.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", digits)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_conversions() {
+        for value in [0, 1, -1, 40, -1000, 16_777_216, WORD_MAX, WORD_MIN] {
+            let word = Word::try_from(value).unwrap();
+            assert_eq!(i64::from(word), value);
+            assert_eq!(word, Word::from_i64(value));
+        }
+        assert_eq!(Word::try_from(WORD_MAX), Ok(Word([Tryte::Digit(23); 8])));
+        assert_eq!(Word::try_from(WORD_MIN), Ok(Word([Tryte::Digit(0); 8])));
+        assert_eq!(
+            Word::try_from(WORD_MAX + 1),
+            Err(ConversionError::OutOfRange { value: (WORD_MAX + 1) as i128, min: WORD_MIN, max: WORD_MAX })
+        );
+        assert!(Word::try_from(WORD_MIN - 1).is_err());
+        assert!(Word::try_from(i64::MIN).is_err());
+        assert_eq!(Word::from_i64(WORD_MAX + 1), Word::try_from(WORD_MIN).unwrap());
+        assert_eq!(Word::from_i64(WORD_MIN - 1), Word::try_from(WORD_MAX).unwrap());
+
+        assert_eq!(Word::from(-7i32), Word::try_from(-7i64).unwrap());
+        assert_eq!(i32::try_from(Word::from(i32::MIN)), Ok(i32::MIN));
+        assert!(i32::try_from(Word::try_from(WORD_MAX).unwrap()).is_err());
+
+        assert_eq!(Tryte::try_from(-13), Ok(Tryte::Digit(0)));
+        assert_eq!(Tryte::try_from(13), Ok(Tryte::NaN));
+        assert!(Tryte::try_from(14).is_err());
+        assert_eq!(i8::from(Tryte::Null), 12);
+    }
+
+    #[test]
+    fn test_digit_boundaries_round_trip() {
+        // Un chiffre qui atteindrait +11..+13 (UNDEF/NULL/NaN) est encodé -13..-11 avec une
+        // retenue: aucune valeur de la plage ne produit d'état spécial, à aucun tryte
+        let mut weight = 1i64;
+        for index in 0..8 {
+            for digit in 9..=14 {
+                for value in [digit * weight, -digit * weight, digit * weight - 1, (digit + 1) * weight - 1] {
+                    if !(WORD_MIN..=WORD_MAX).contains(&value) {
+                        continue;
+                    }
+                    let word = Word::try_from(value).unwrap();
+                    assert!(word.trytes().iter().all(|t| matches!(t, Tryte::Digit(_))), "{} -> {}", value, word);
+                    assert_eq!(word.to_i64(), value, "tryte {}", index);
+                    assert_eq!(word.to_string().parse::<Word>(), Ok(word));
+                }
+            }
+            weight *= 24;
+        }
+
+        // 13 = 24 - 11: chiffre -11 et retenue +1
+        assert_eq!(Word::try_from(13i64).unwrap().to_string(), "13:13:13:13:13:13:14:2");
+        assert_eq!(Word::try_from(11i64).unwrap().tryte(0), Some(&Tryte::Digit(0)));
+    }
+
+    #[test]
+    fn test_trit_conversions() {
+        // -42 = -2 × 24 + 6: trytes 6 (Z,N,P) et -2 (P,N,Z)
+        let word = Word::from(-42);
+        let trits = word.to_trits();
+        assert_eq!(trits_value(&trits[0..3]), 6);
+        assert_eq!(trits_value(&trits[3..6]), -2);
+        assert_eq!(word.trits().collect::<Vec<_>>(), trits.to_vec());
+        assert_eq!(Word::from_trits(trits), word);
+
+        assert_eq!(balanced_trits::<4>(40), Ok([Trit::P; 4]));
+        assert!(balanced_trits::<4>(41).is_err());
+        assert_eq!(trits_value(&balanced_trits::<7>(-1093).unwrap()), -1093);
+    }
+
+    #[test]
+    fn test_text_conversions() {
+        // Ternaire équilibré: +-0+ = 27 - 9 + 1 = 19
+        let word = Word::from(19);
+        assert_eq!(word.balanced().to_string(), "+-0+");
+        assert_eq!(format!("{:#}", word.balanced()), "0t+-0+");
+        assert_eq!("0t+-0+".parse::<Word>(), Ok(word));
+        assert_eq!(Word::zero().balanced().to_string(), "0");
+        assert_eq!("0t".parse::<Word>(), Err(ConversionError::Empty));
+        assert_eq!("0t+x".parse::<Word>(), Err(ConversionError::InvalidDigit('x')));
+        assert!(format!("0t+{}", "0".repeat(24)).parse::<Word>().is_err());
+
+        // Décimal et chiffres B24 (forme de Display)
+        for value in [0, -1, 13, 40, 148, WORD_MIN, WORD_MAX] {
+            let word = Word::try_from(value).unwrap();
+            assert_eq!(value.to_string().parse::<Word>(), Ok(word));
+            assert_eq!(word.to_string().parse::<Word>(), Ok(word));
+            assert_eq!(format!("{:#}", word.balanced()).parse::<Word>(), Ok(word));
+        }
+        assert_eq!("14".parse::<Word>(), Ok(Word::from(14)));
+        assert_eq!("14:13".parse::<Word>(), Ok(Word::from(24)));
+        assert!("47858832251".parse::<Word>().unwrap_err().to_string().contains("hors de la plage"));
+        assert!(format!("0t+{}", "0".repeat(23)).parse::<Word>().is_err());
+        assert!("24:0".parse::<Word>().is_err());
+        assert!("abc".parse::<Word>().is_err());
+
+        // États spéciaux
+        assert_eq!("NaN".parse::<Word>(), Ok(Word([Tryte::NaN; 8])));
+        assert_eq!("undef".parse::<Word>(), Ok(Word::undefined()));
+        assert_eq!("13:NUL".parse::<Word>().unwrap().tryte(0), Some(&Tryte::Null));
+    }
+}
@@ -0,0 +1,38 @@
+//! Définitions des erreurs pour l'éditeur de liens PrismChrono
+//!
+//! Ce module définit les différents types d'erreurs qui peuvent survenir
+//! pendant la lecture des objets et l'édition de liens.
+
+use thiserror::Error;
+
+/// Erreur principale de l'éditeur de liens
+#[derive(Error, Debug)]
+pub enum LinkerError {
+    /// Erreur d'entrée/sortie
+    #[error("Erreur I/O: {0}")]
+    IoError(String),
+
+    /// Objet mal formé ou relocation impossible
+    #[error("{file}: {message}")]
+    ObjectError { file: String, message: String },
+
+    /// Erreur dans le script d'édition de liens
+    #[error("Erreur de script à la ligne {line}: {message}")]
+    ScriptError { line: usize, message: String },
+
+    /// Symbole global défini par plusieurs objets
+    #[error("Symbole global défini plusieurs fois: {name} ({first} et {second})")]
+    DuplicateSymbol { name: String, first: String, second: String },
+
+    /// Symbole référencé mais défini par aucun objet
+    #[error("{file}: symbole non défini: {name}")]
+    UndefinedSymbol { file: String, name: String },
+
+    /// Deux sections de sortie se chevauchent
+    #[error("Sections superposées: {first} et {second}")]
+    SectionOverlap { first: String, second: String },
+
+    /// Point d'entrée demandé mais non défini
+    #[error("Point d'entrée non défini: {0}")]
+    UndefinedEntry(String),
+}
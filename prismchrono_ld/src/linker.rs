@@ -0,0 +1,390 @@
+//! Module d'édition de liens pour PrismChrono
+//!
+//! L'édition de liens se déroule en trois étapes:
+//! - Disposition: les sections de même nom des objets sont concaténées (dans l'ordre
+//!   de la ligne de commande) puis placées selon le script
+//! - Symboles: chaque symbole reçoit son adresse finale; les globaux doivent être uniques
+//! - Relocations: les champs laissés nuls par l'assembleur sont corrigés
+
+use crate::error::LinkerError;
+use crate::script::LinkerScript;
+use prismchrono_isa::object::{apply_relocation, Chunk, ObjectFile, SymbolBinding};
+use std::collections::HashMap;
+
+/// Point d'entrée par défaut s'il est exporté par un objet
+pub const DEFAULT_ENTRY: &str = "_start";
+
+/// Objet d'entrée et nom du fichier dont il provient (pour les messages d'erreur)
+pub struct InputObject {
+    /// Nom du fichier objet
+    pub name: String,
+    /// Contenu de l'objet
+    pub object: ObjectFile,
+}
+
+/// Programme lié, prêt à être écrit en .tbin
+#[derive(Debug)]
+pub struct LinkedProgram {
+    /// Instructions et données à leur adresse finale, triées par adresse
+    pub chunks: Vec<(u32, Chunk)>,
+    /// Symboles globaux, dans l'ordre des objets
+    pub globals: Vec<(String, u32)>,
+    /// Tous les symboles (locaux compris), triés par adresse puis par nom
+    pub symbols: Vec<(u32, String)>,
+    /// Point d'entrée
+    pub entry: Option<u32>,
+}
+
+/// Section de sortie: regroupe les sections de même nom des objets d'entrée
+struct OutputSection {
+    /// Nom commun des sections
+    name: String,
+    /// Adresse de base
+    base: u32,
+    /// Taille totale
+    size: u32,
+    /// Plus grand alignement des sections regroupées
+    alignment: u32,
+    /// Offset de la section de chaque objet (index de l'objet, offset)
+    parts: Vec<(usize, u32)>,
+}
+
+/// Arrondit `address` au multiple supérieur de `align`
+fn align_up(address: u32, align: u32) -> u32 {
+    if align > 0 {
+        address.div_ceil(align) * align
+    } else {
+        address
+    }
+}
+
+/// Lie les objets d'entrée
+///
+/// Le point d'entrée est `entry` s'il est fourni, sinon celui du script, sinon
+/// `_start` s'il est exporté.
+pub fn link(inputs: &[InputObject], script: &LinkerScript, entry: Option<&str>) -> Result<LinkedProgram, LinkerError> {
+    let sections = layout(inputs, script)?;
+
+    // Adresse de base de la section de chaque objet
+    let mut bases: HashMap<(usize, &str), u32> = HashMap::new();
+    for section in &sections {
+        for &(input, offset) in &section.parts {
+            bases.insert((input, section.name.as_str()), section.base + offset);
+        }
+    }
+    let base_of = |input: usize, section: &str| {
+        bases.get(&(input, section)).copied().ok_or_else(|| LinkerError::ObjectError {
+            file: inputs[input].name.clone(),
+            message: format!("section inconnue: {}", section),
+        })
+    };
+
+    // Symboles: table propre à chaque objet, puis table globale
+    let mut locals: Vec<HashMap<&str, u32>> = Vec::with_capacity(inputs.len());
+    let mut globals: Vec<(String, u32)> = Vec::new();
+    let mut global_owners: HashMap<&str, usize> = HashMap::new();
+    let mut symbols = Vec::new();
+    for (index, input) in inputs.iter().enumerate() {
+        let mut table = HashMap::new();
+        for symbol in &input.object.symbols {
+            let address = base_of(index, &symbol.section)? + symbol.offset;
+            if table.insert(symbol.name.as_str(), address).is_some() {
+                return Err(LinkerError::ObjectError {
+                    file: input.name.clone(),
+                    message: format!("symbole défini plusieurs fois: {}", symbol.name),
+                });
+            }
+            if symbol.binding == SymbolBinding::Global {
+                if let Some(&owner) = global_owners.get(symbol.name.as_str()) {
+                    return Err(LinkerError::DuplicateSymbol {
+                        name: symbol.name.clone(),
+                        first: inputs[owner].name.clone(),
+                        second: input.name.clone(),
+                    });
+                }
+                global_owners.insert(symbol.name.as_str(), index);
+                globals.push((symbol.name.clone(), address));
+            }
+            symbols.push((address, symbol.name.clone()));
+        }
+        locals.push(table);
+    }
+    symbols.sort();
+    let resolve_global = |name: &str| globals.iter().find(|(global, _)| global == name).map(|(_, address)| *address);
+
+    // Contenu à son adresse finale; la clé retrouve l'élément visé par une relocation
+    let mut chunks: Vec<(u32, Chunk)> = Vec::new();
+    let mut chunk_index: HashMap<(usize, &str, u32), usize> = HashMap::new();
+    for (index, input) in inputs.iter().enumerate() {
+        for section in &input.object.sections {
+            let base = base_of(index, &section.name)?;
+            for (offset, chunk) in &section.chunks {
+                chunk_index.insert((index, section.name.as_str(), *offset), chunks.len());
+                chunks.push((base + offset, chunk.clone()));
+            }
+        }
+    }
+
+    // Relocations: symboles locaux de l'objet d'abord, puis symboles globaux
+    for (index, input) in inputs.iter().enumerate() {
+        for relocation in &input.object.relocations {
+            let target = locals[index]
+                .get(relocation.symbol.as_str())
+                .copied()
+                .or_else(|| resolve_global(&relocation.symbol))
+                .ok_or_else(|| LinkerError::UndefinedSymbol {
+                    file: input.name.clone(),
+                    name: relocation.symbol.clone(),
+                })?;
+            let place = base_of(index, &relocation.section)? + relocation.offset;
+            let &chunk = chunk_index
+                .get(&(index, relocation.section.as_str(), relocation.offset))
+                .ok_or_else(|| LinkerError::ObjectError {
+                    file: input.name.clone(),
+                    message: format!(
+                        "relocation sans contenu: {} +{:04X}",
+                        relocation.section, relocation.offset
+                    ),
+                })?;
+            apply_relocation(
+                &mut chunks[chunk].1,
+                relocation.kind,
+                place,
+                target as i64 + relocation.addend as i64,
+            )
+            .map_err(|e| LinkerError::ObjectError {
+                file: input.name.clone(),
+                message: format!("{} ({})", e, relocation.symbol),
+            })?;
+        }
+    }
+    chunks.sort_by_key(|(address, _)| *address);
+
+    let entry = match entry.or(script.entry.as_deref()) {
+        Some(name) => Some(resolve_global(name).ok_or_else(|| LinkerError::UndefinedEntry(name.to_string()))?),
+        None => resolve_global(DEFAULT_ENTRY),
+    };
+
+    Ok(LinkedProgram {
+        chunks,
+        globals,
+        symbols,
+        entry,
+    })
+}
+
+/// Regroupe et place les sections des objets d'entrée
+///
+/// Les sections nommées dans le script sont placées à l'adresse demandée, les
+/// autres à la suite, dans l'ordre de leur première apparition, chacune alignée
+/// sur le plus grand alignement de ses parties.
+fn layout(inputs: &[InputObject], script: &LinkerScript) -> Result<Vec<OutputSection>, LinkerError> {
+    // Noms des sections de sortie: celles du script d'abord
+    let mut names: Vec<&str> = script.sections.iter().map(|(name, _)| name.as_str()).collect();
+    for input in inputs {
+        for section in &input.object.sections {
+            if !names.contains(&section.name.as_str()) {
+                names.push(&section.name);
+            }
+        }
+    }
+
+    let mut sections = Vec::new();
+    for name in names {
+        let mut output = OutputSection {
+            name: name.to_string(),
+            base: 0,
+            size: 0,
+            alignment: 1,
+            parts: Vec::new(),
+        };
+        for (index, input) in inputs.iter().enumerate() {
+            for section in input.object.sections.iter().filter(|section| section.name == name) {
+                if output.parts.iter().any(|&(part, _)| part == index) {
+                    return Err(LinkerError::ObjectError {
+                        file: input.name.clone(),
+                        message: format!("section dupliquée: {}", name),
+                    });
+                }
+                let offset = align_up(output.size, section.alignment);
+                output.parts.push((index, offset));
+                output.size = offset + section.size;
+                output.alignment = output.alignment.max(section.alignment);
+            }
+        }
+        sections.push(output);
+    }
+
+    // Sections placées par le script, puis les autres à la suite
+    let mut next_base = 0;
+    for section in sections.iter_mut() {
+        if let Some(base) = script.base_of(&section.name) {
+            section.base = base;
+            next_base = next_base.max(base + section.size);
+        }
+    }
+    for section in sections.iter_mut() {
+        if script.base_of(&section.name).is_none() {
+            section.base = align_up(next_base, section.alignment);
+            next_base = section.base + section.size;
+        }
+    }
+
+    // Vérifier que les sections non vides ne se chevauchent pas
+    let mut placed: Vec<&OutputSection> = sections.iter().filter(|section| section.size > 0).collect();
+    placed.sort_by_key(|section| section.base);
+    for pair in placed.windows(2) {
+        if pair[0].base + pair[0].size > pair[1].base {
+            return Err(LinkerError::SectionOverlap {
+                first: pair[0].name.clone(),
+                second: pair[1].name.clone(),
+            });
+        }
+    }
+
+    Ok(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prismchrono_isa::object::{ObjectSection, ObjectSymbol, Relocation, RelocationKind};
+    use prismchrono_isa::{decode, encode, AluOp, Instruction, Register, Tryte, Word};
+
+    fn instruction(instruction: Instruction) -> Chunk {
+        Chunk::Instruction(encode(&instruction).unwrap())
+    }
+
+    fn decoded(program: &LinkedProgram, address: u32) -> Instruction {
+        match program.chunks.iter().find(|(at, _)| *at == address) {
+            Some((_, Chunk::Instruction(trits))) => decode(trits).unwrap(),
+            other => panic!("instruction attendue à 0x{:X}: {:?}", address, other),
+        }
+    }
+
+    fn symbol(name: &str, section: &str, offset: u32, binding: SymbolBinding) -> ObjectSymbol {
+        ObjectSymbol {
+            name: name.to_string(),
+            section: section.to_string(),
+            offset,
+            binding,
+        }
+    }
+
+    fn relocation(section: &str, offset: u32, kind: RelocationKind, symbol: &str) -> Relocation {
+        Relocation {
+            section: section.to_string(),
+            offset,
+            kind,
+            symbol: symbol.to_string(),
+            addend: 0,
+        }
+    }
+
+    /// main: CALL afficher ; LA R1, valeur ; HALT, plus un mot contenant l'adresse de afficher
+    fn main_object() -> InputObject {
+        let object = ObjectFile {
+            sections: vec![
+                ObjectSection {
+                    name: ".text".to_string(),
+                    alignment: 8,
+                    size: 16,
+                    chunks: vec![
+                        (0, instruction(Instruction::Call { rd: Register::R7, offset: 0 })),
+                        (4, instruction(Instruction::Lui { rd: Register::R1, imm: 0 })),
+                        (8, instruction(Instruction::AluImm { op: AluOp::Add, rs1: Register::R1, rd: Register::R1, imm: 0 })),
+                        (12, instruction(Instruction::Halt)),
+                    ],
+                },
+                ObjectSection {
+                    name: ".data".to_string(),
+                    alignment: 8,
+                    size: 16,
+                    chunks: vec![(0, Chunk::Data(vec![Tryte::Digit(13); 8])), (8, Chunk::Data(Word::from(42).trytes().to_vec()))],
+                },
+            ],
+            symbols: vec![
+                symbol("_start", ".text", 0, SymbolBinding::Global),
+                symbol("valeur", ".data", 8, SymbolBinding::Local),
+            ],
+            relocations: vec![
+                relocation(".text", 0, RelocationKind::J, "afficher"),
+                relocation(".text", 4, RelocationKind::UHi, "valeur"),
+                relocation(".text", 8, RelocationKind::ILo, "valeur"),
+                relocation(".data", 0, RelocationKind::Word, "afficher"),
+            ],
+        };
+        InputObject { name: "main.tro".to_string(), object }
+    }
+
+    /// lib: afficher: RET
+    fn lib_object() -> InputObject {
+        let object = ObjectFile {
+            sections: vec![ObjectSection {
+                name: ".text".to_string(),
+                alignment: 8,
+                size: 4,
+                chunks: vec![(0, instruction(Instruction::Jalr { rd: Register::R0, rs1: Register::R7, offset: 0 }))],
+            }],
+            symbols: vec![
+                symbol("afficher", ".text", 0, SymbolBinding::Global),
+                symbol("valeur", ".text", 0, SymbolBinding::Local),
+            ],
+            relocations: Vec::new(),
+        };
+        InputObject { name: "lib.tro".to_string(), object }
+    }
+
+    #[test]
+    fn test_link_two_objects() {
+        let script = LinkerScript::parse("SECTION .data 0x1000").unwrap();
+        let program = link(&[main_object(), lib_object()], &script, None).unwrap();
+
+        // .text de main (16 octets) puis .text de lib, .data à 0x1000
+        assert_eq!(program.globals, vec![("_start".to_string(), 0x1010), ("afficher".to_string(), 0x1020)]);
+        assert_eq!(program.entry, Some(0x1010));
+
+        // CALL à 0x1010 vers 0x1020: (0x1020 - 0x1014) / 4 = 3
+        assert_eq!(decoded(&program, 0x1010), Instruction::Call { rd: Register::R7, offset: 3 });
+
+        // LA R1, valeur charge 0x1008 (la définition locale de lib n'est pas visible)
+        let (Instruction::Lui { imm: high, .. }, Instruction::AluImm { imm: low, .. }) =
+            (decoded(&program, 0x1014), decoded(&program, 0x1018))
+        else {
+            panic!("LUI/ADDI attendus");
+        };
+        assert_eq!(high as i32 * 243 + low as i32, 0x1008);
+
+        let word = program.chunks.iter().find(|(address, _)| *address == 0x1000).unwrap();
+        assert_eq!(word.1, Chunk::Data(Word::from(0x1020).trytes().to_vec()));
+    }
+
+    #[test]
+    fn test_link_errors() {
+        let script = LinkerScript::default();
+
+        // afficher n'est défini par aucun objet
+        assert!(matches!(
+            link(&[main_object()], &script, None),
+            Err(LinkerError::UndefinedSymbol { name, .. }) if name == "afficher"
+        ));
+
+        // afficher exporté deux fois
+        assert!(matches!(
+            link(&[main_object(), lib_object(), lib_object()], &script, None),
+            Err(LinkerError::DuplicateSymbol { name, .. }) if name == "afficher"
+        ));
+
+        // .data placée au milieu de .text
+        let overlapping = LinkerScript::parse("SECTION .text 0\nSECTION .data 8").unwrap();
+        assert!(matches!(
+            link(&[main_object(), lib_object()], &overlapping, None),
+            Err(LinkerError::SectionOverlap { .. })
+        ));
+
+        assert!(matches!(
+            link(&[main_object(), lib_object()], &script, Some("main")),
+            Err(LinkerError::UndefinedEntry(_))
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+//! PrismChrono Éditeur de liens - Point d'entrée principal
+//!
+//! Ce fichier contient le point d'entrée de l'éditeur de liens PrismChrono:
+//! il lit les objets relogeables (.tro) produits par `prismchrono_asm --relocatable`,
+//! les lie selon un éventuel script et écrit le programme final (.tbin).
+
+use clap::Parser;
+use std::path::PathBuf;
+
+// Modules internes
+mod error;
+mod linker;
+mod output;
+mod script;
+
+use error::LinkerError;
+use linker::InputObject;
+use prismchrono_isa::object::ObjectFile;
+use script::LinkerScript;
+
+/// Structure pour les arguments de ligne de commande
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Fichiers objets relogeables (.tro), liés dans l'ordre donné
+    #[arg(value_name = "INPUT", required = true)]
+    inputs: Vec<PathBuf>,
+
+    /// Fichier de sortie (.tbin)
+    #[arg(short, long, value_name = "OUTPUT", default_value = "a.tbin")]
+    output: PathBuf,
+
+    /// Script d'édition de liens (adresses des sections, point d'entrée)
+    #[arg(short = 'T', long, value_name = "SCRIPT")]
+    script: Option<PathBuf>,
+
+    /// Symbole servant de point d'entrée (prioritaire sur le script, `_start` par défaut)
+    #[arg(short, long, value_name = "SYMBOL")]
+    entry: Option<String>,
+
+    /// Écrire la table des symboles (`ADRESSE NOM` par ligne) pour le désassembleur
+    #[arg(short, long, value_name = "SYMBOLS")]
+    symbols: Option<PathBuf>,
+
+    /// Afficher des informations de débogage
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+fn main() -> Result<(), LinkerError> {
+    let args = Args::parse();
+
+    let script = match &args.script {
+        Some(path) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| LinkerError::IoError(format!("Impossible de lire le script {}: {}", path.display(), e)))?;
+            LinkerScript::parse(&text).map_err(|e| {
+                eprintln!("Erreur de script: {}", e);
+                e
+            })?
+        }
+        None => LinkerScript::default(),
+    };
+
+    let mut inputs = Vec::with_capacity(args.inputs.len());
+    for path in &args.inputs {
+        let name = path.display().to_string();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| LinkerError::IoError(format!("Impossible de lire {}: {}", name, e)))?;
+        let object = ObjectFile::parse(&text).map_err(|e| {
+            eprintln!("Objet invalide: {}: {}", name, e);
+            LinkerError::ObjectError { file: name.clone(), message: e.to_string() }
+        })?;
+        if args.verbose {
+            println!(
+                "{}: {} section(s), {} symbole(s), {} relocation(s)",
+                name,
+                object.sections.len(),
+                object.symbols.len(),
+                object.relocations.len()
+            );
+        }
+        inputs.push(InputObject { name, object });
+    }
+
+    let program = linker::link(&inputs, &script, args.entry.as_deref()).map_err(|e| {
+        eprintln!("Erreur d'édition de liens: {}", e);
+        e
+    })?;
+
+    output::write_tbin(&args.output, &program).map_err(|e| {
+        eprintln!("Erreur d'écriture du fichier binaire: {}", e);
+        e
+    })?;
+
+    if let Some(symbols_path) = &args.symbols {
+        output::write_symbols(symbols_path, &program).map_err(|e| {
+            eprintln!("Erreur d'écriture de la table des symboles: {}", e);
+            e
+        })?;
+    }
+
+    if args.verbose {
+        match program.entry {
+            Some(entry) => println!("Point d'entrée: {:04X}", entry),
+            None => println!("Point d'entrée: première instruction"),
+        }
+        println!("Édition de liens terminée. Fichier de sortie: {}", args.output.display());
+    }
+
+    Ok(())
+}
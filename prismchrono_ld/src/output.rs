@@ -0,0 +1,82 @@
+//! Module de sortie pour l'éditeur de liens PrismChrono
+//!
+//! Écrit le programme lié au format binaire .tbin (version 3, avec point
+//! d'entrée) et, sur demande, la table des symboles (.sym) utilisée par le
+//! désassembleur du simulateur.
+
+use std::path::Path;
+
+use crate::error::LinkerError;
+use crate::linker::LinkedProgram;
+use prismchrono_isa::object::Chunk;
+use prismchrono_isa::Trit;
+
+/// Version du format .tbin produite
+pub const TBIN_VERSION: u32 = 3;
+
+/// Point d'entrée .tbin signifiant « non spécifié »
+const TBIN_NO_ENTRY: u32 = u32::MAX;
+
+/// Encode le programme lié au format .tbin
+///
+/// Même format que `prismchrono_asm` (voir `output::write_tbin`): en-tête,
+/// entrées (instructions en trits 0=N/1=Z/2=P, données en trytes Bal3), table des
+/// symboles globaux puis point d'entrée.
+pub fn tbin_bytes(program: &LinkedProgram) -> Result<Vec<u8>, LinkerError> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"TBIN");
+    bytes.extend_from_slice(&TBIN_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(program.chunks.len() as u32).to_le_bytes());
+
+    for (address, chunk) in &program.chunks {
+        bytes.extend_from_slice(&address.to_le_bytes());
+        match chunk {
+            Chunk::Instruction(trits) => {
+                bytes.extend_from_slice(&[0, 4]);
+                bytes.extend(trits.iter().map(|trit| match trit {
+                    Trit::N => 0u8,
+                    Trit::Z => 1u8,
+                    Trit::P => 2u8,
+                }));
+            }
+            Chunk::Data(trytes) => {
+                let size = u8::try_from(trytes.len()).map_err(|_| {
+                    LinkerError::IoError(format!("Bloc de données trop long à l'adresse {:04X}", address))
+                })?;
+                bytes.extend_from_slice(&[1, size]);
+                bytes.extend(trytes.iter().map(|tryte| tryte.bal3_value() as u8));
+            }
+        }
+    }
+
+    bytes.extend_from_slice(&(program.globals.len() as u32).to_le_bytes());
+    for (name, address) in &program.globals {
+        let name_len = u8::try_from(name.len())
+            .map_err(|_| LinkerError::IoError(format!("Nom de symbole global trop long: {}", name)))?;
+        bytes.extend_from_slice(&address.to_le_bytes());
+        bytes.push(name_len);
+        bytes.extend_from_slice(name.as_bytes());
+    }
+
+    bytes.extend_from_slice(&program.entry.unwrap_or(TBIN_NO_ENTRY).to_le_bytes());
+    Ok(bytes)
+}
+
+/// Écrit le programme lié dans un fichier .tbin
+pub fn write_tbin<P: AsRef<Path>>(path: P, program: &LinkedProgram) -> Result<(), LinkerError> {
+    std::fs::write(path, tbin_bytes(program)?)
+        .map_err(|e| LinkerError::IoError(format!("Impossible d'écrire le fichier binaire: {}", e)))
+}
+
+/// Écrit la table des symboles dans un fichier .sym
+///
+/// Format: une ligne `AAAA nom` par symbole (adresse hexadécimale), triée par adresse
+pub fn write_symbols<P: AsRef<Path>>(path: P, program: &LinkedProgram) -> Result<(), LinkerError> {
+    let text: String = program
+        .symbols
+        .iter()
+        .map(|(address, name)| format!("{:04X} {}\n", address, name))
+        .collect();
+    std::fs::write(path, text)
+        .map_err(|e| LinkerError::IoError(format!("Impossible d'écrire le fichier de symboles: {}", e)))
+}
@@ -0,0 +1,89 @@
+//! Script d'édition de liens pour PrismChrono
+//!
+//! Le script fixe l'adresse de base des sections et le point d'entrée:
+//!
+//! ```text
+//! # Programme en ROM, données en RAM
+//! ENTRY _start
+//! SECTION .text 0x0000
+//! SECTION .data 0x4000
+//! ```
+//!
+//! Les sections absentes du script sont placées à la suite des autres.
+
+use crate::error::LinkerError;
+
+/// Script d'édition de liens
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkerScript {
+    /// Symbole servant de point d'entrée
+    pub entry: Option<String>,
+    /// Adresse de base des sections, dans l'ordre du script
+    pub sections: Vec<(String, u32)>,
+}
+
+impl LinkerScript {
+    /// Analyse un script d'édition de liens
+    pub fn parse(text: &str) -> Result<Self, LinkerError> {
+        let mut script = LinkerScript::default();
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let error = |message: String| LinkerError::ScriptError { line: index + 1, message };
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                ["ENTRY", symbol] => {
+                    if script.entry.is_some() {
+                        return Err(error("ENTRY défini plusieurs fois".to_string()));
+                    }
+                    script.entry = Some(symbol.to_string());
+                }
+                ["SECTION", name, base] => {
+                    let base = parse_address(base).ok_or_else(|| error(format!("Adresse invalide: {}", base)))?;
+                    if script.base_of(name).is_some() {
+                        return Err(error(format!("Section déjà placée: {}", name)));
+                    }
+                    script.sections.push((name.to_string(), base));
+                }
+                _ => return Err(error(format!("Commande inconnue: {}", line))),
+            }
+        }
+
+        Ok(script)
+    }
+
+    /// Adresse de base imposée pour une section
+    pub fn base_of(&self, name: &str) -> Option<u32> {
+        self.sections.iter().find(|(section, _)| section == name).map(|(_, base)| *base)
+    }
+}
+
+/// Analyse une adresse décimale ou hexadécimale (préfixe `0x`)
+fn parse_address(text: &str) -> Option<u32> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_script() {
+        let script = LinkerScript::parse("# ROM puis RAM\nENTRY main\nSECTION .text 0x100\nSECTION .data 4096 # RAM\n").unwrap();
+        assert_eq!(script.entry, Some("main".to_string()));
+        assert_eq!(script.base_of(".text"), Some(0x100));
+        assert_eq!(script.base_of(".data"), Some(4096));
+        assert_eq!(script.base_of(".bss"), None);
+
+        for text in ["SECTION .text", "SECTION .text 0xZZ", "ENTRY a\nENTRY b", "SECTION .a 0\nSECTION .a 8", "MEMORY ram"] {
+            assert!(LinkerScript::parse(text).is_err(), "{:?} devrait être rejeté", text);
+        }
+    }
+}